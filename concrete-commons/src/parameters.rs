@@ -39,7 +39,21 @@ pub struct LweCiphertextRange(pub usize, pub usize);
 
 impl LweCiphertextRange {
     pub fn is_ordered(&self) -> bool {
-        self.1 <= self.0
+        self.0 <= self.1
+    }
+}
+
+/// The range of indices of multiple contiguous rows in an lwe keyswitch key.
+///
+/// A row of an lwe keyswitch key contains the encryptions of the decomposition of a single
+/// coefficient of the input key. The bounds of the range are indices of input key coefficients.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct LweKeyswitchKeyRowRange(pub usize, pub usize);
+
+impl LweKeyswitchKeyRowRange {
+    pub fn is_ordered(&self) -> bool {
+        self.0 <= self.1
     }
 }
 
@@ -87,6 +101,11 @@ impl LweDimension {
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 pub struct LwePublicKeyZeroEncryptionCount(pub usize);
 
+/// The number of GLWE encryptions of 0 in a GLWE public key.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct GlwePublicKeyZeroEncryptionCount(pub usize);
+
 /// The number of polynomials in a GLWE ciphertext, i.e. the number of polynomials in a GLWE mask
 /// plus one.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
@@ -208,3 +227,11 @@ pub struct FunctionalPackingKeyswitchKeyCount(pub usize);
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 #[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
 pub struct CiphertextModulusLog(pub usize);
+
+/// The exponent of a ring automorphism.
+///
+/// Assuming the automorphism $X \mapsto X^k$ of $\mathbb{Z}\[X\] / <X^N + 1>$, this type
+/// represents the $k$ value, which must be odd.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde_serialize", derive(Serialize, Deserialize))]
+pub struct AutomorphismExponent(pub usize);
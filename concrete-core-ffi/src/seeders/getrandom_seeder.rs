@@ -0,0 +1,62 @@
+//! Module providing the `C` FFI for the seeder based on the
+//! [getrandom](https://man7.org/linux/man-pages/man2/getrandom.2.html) system call.
+
+use crate::seeders::{SeederBuilder, SeederFactory};
+use crate::utils::*;
+use concrete_core::prelude::{GetrandomSeeder, Seeder};
+use std::os::raw::c_int;
+
+struct GetrandomSeederFactory {}
+
+impl SeederFactory for GetrandomSeederFactory {
+    fn create_seeder(&mut self) -> Result<Box<dyn Seeder>, String> {
+        Ok(Box::new(GetrandomSeeder {}))
+    }
+}
+
+/// Check if `GetrandomSeeder` is available on the current platform.
+///
+/// This function is [checked](crate#safety-checked-and-unchecked-functions).
+#[no_mangle]
+pub unsafe extern "C" fn getrandom_seeder_is_available(result: *mut bool) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+        *result = GetrandomSeeder::is_available();
+    })
+}
+
+/// [Unchecked](crate#safety-checked-and-unchecked-functions) version of
+/// [`getrandom_seeder_is_available`].
+#[no_mangle]
+pub unsafe extern "C" fn getrandom_seeder_is_available_unchecked(result: *mut bool) -> c_int {
+    catch_panic(|| {
+        *result = GetrandomSeeder::is_available();
+    })
+}
+
+/// Return a [`SeederBuilder`] which yields `GetrandomSeeder`s that can be passed to engine creation
+/// functions that require it.
+///
+/// This function is [checked](crate#safety-checked-and-unchecked-functions).
+#[no_mangle]
+pub unsafe extern "C" fn get_getrandom_seeder_builder(result: *mut *mut SeederBuilder) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+        let heap_allocated_seeder_builder =
+            Box::new(SeederBuilder::new(Box::new(GetrandomSeederFactory {})));
+        *result = Box::into_raw(heap_allocated_seeder_builder);
+    })
+}
+
+/// [Unchecked](crate#safety-checked-and-unchecked-functions) version of
+/// [`get_getrandom_seeder_builder`].
+#[no_mangle]
+pub unsafe extern "C" fn get_getrandom_seeder_builder_unchecked(
+    result: *mut *mut SeederBuilder,
+) -> c_int {
+    catch_panic(|| {
+        let heap_allocated_seeder_builder =
+            Box::new(SeederBuilder::new(Box::new(GetrandomSeederFactory {})));
+        *result = Box::into_raw(heap_allocated_seeder_builder);
+    })
+}
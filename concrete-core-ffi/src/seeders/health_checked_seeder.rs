@@ -0,0 +1,87 @@
+//! Module providing the `C` FFI to check the health of the entropy source of seeders.
+
+use crate::seeders::{SeederBuilder, SeederFactory};
+use crate::utils::*;
+use concrete_core::prelude::{HealthCheckedSeeder, Seed, Seeder, SeederError};
+use std::os::raw::c_int;
+
+// A seeder yielded by a [`SeederBuilder`], whose concrete type is not known.
+struct BuiltSeeder(Box<dyn Seeder>);
+
+impl Seeder for BuiltSeeder {
+    fn seed(&mut self) -> Seed {
+        self.0.seed()
+    }
+
+    fn try_seed(&mut self) -> Result<Seed, SeederError> {
+        self.0.try_seed()
+    }
+
+    fn is_available() -> bool {
+        // The seeder was already built, and the availability is checked before building it.
+        true
+    }
+}
+
+struct HealthCheckedSeederFactory {
+    seeder_builder: Box<SeederBuilder>,
+    min_entropy: f64,
+}
+
+impl SeederFactory for HealthCheckedSeederFactory {
+    fn create_seeder(&mut self) -> Result<Box<dyn Seeder>, String> {
+        let seeder = BuiltSeeder(self.seeder_builder.create_seeder()?);
+        HealthCheckedSeeder::new(seeder, self.min_entropy)
+            .map(|seeder| Box::new(seeder) as Box<dyn Seeder>)
+            .map_err(|error| error.to_string())
+    }
+}
+
+/// Return a [`SeederBuilder`] which yields the seeders of the passed `seeder_builder`, wrapped in
+/// `HealthCheckedSeeder`s running the NIST SP 800-90B health tests on their output. The
+/// `min_entropy` is the min-entropy per byte claimed for the wrapped seeders, in bits, and must
+/// lie in `]0, 8]`.
+///
+/// When this function succeeds, the passed `seeder_builder` is consumed, and must neither be used
+/// nor destroyed afterwards. The engine creation functions fail if the health tests do not pass.
+///
+/// This function is [checked](crate#safety-checked-and-unchecked-functions).
+#[no_mangle]
+pub unsafe extern "C" fn get_health_checked_seeder_builder(
+    seeder_builder: *mut SeederBuilder,
+    min_entropy: f64,
+    result: *mut *mut SeederBuilder,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(seeder_builder).unwrap();
+        check_ptr_is_non_null_and_aligned(result).unwrap();
+        assert!(
+            min_entropy > 0. && min_entropy <= 8.,
+            "The min-entropy per byte must lie in ]0, 8]."
+        );
+        let heap_allocated_seeder_builder =
+            Box::new(SeederBuilder::new(Box::new(HealthCheckedSeederFactory {
+                seeder_builder: Box::from_raw(seeder_builder),
+                min_entropy,
+            })));
+        *result = Box::into_raw(heap_allocated_seeder_builder);
+    })
+}
+
+/// [Unchecked](crate#safety-checked-and-unchecked-functions) version of
+/// [`get_health_checked_seeder_builder`].
+#[no_mangle]
+pub unsafe extern "C" fn get_health_checked_seeder_builder_unchecked(
+    seeder_builder: *mut SeederBuilder,
+    min_entropy: f64,
+    result: *mut *mut SeederBuilder,
+) -> c_int {
+    catch_panic(|| {
+        let heap_allocated_seeder_builder =
+            Box::new(SeederBuilder::new(Box::new(HealthCheckedSeederFactory {
+                seeder_builder: Box::from_raw(seeder_builder),
+                min_entropy,
+            })));
+        *result = Box::into_raw(heap_allocated_seeder_builder);
+    })
+}
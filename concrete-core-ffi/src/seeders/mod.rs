@@ -1,11 +1,17 @@
 //! Module to manage `concrete-core` compatible seeders across the FFI boundary.
 
+#[cfg(feature = "seeder_getrandom")]
+pub mod getrandom_seeder;
+pub mod health_checked_seeder;
 #[cfg(feature = "seeder_x86_64_rdseed")]
 pub mod rdseed_x86_64_seeder;
 pub mod seeder_builder;
 #[cfg(feature = "seeder_unix")]
 pub mod unix_seeder;
 
+#[cfg(feature = "seeder_getrandom")]
+pub use getrandom_seeder::*;
+pub use health_checked_seeder::*;
 #[cfg(feature = "seeder_x86_64_rdseed")]
 pub use rdseed_x86_64_seeder::*;
 pub use seeder_builder::*;
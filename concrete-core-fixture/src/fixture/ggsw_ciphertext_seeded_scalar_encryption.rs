@@ -0,0 +1,216 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGgswSeededCiphertext, PrototypesGlweCiphertext,
+    PrototypesGlweCiphertextGgswCiphertextExternalProduct, PrototypesGlweSecretKey,
+    PrototypesPlaintext, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGgswSeededCiphertext, SynthesizesGlweSecretKey, SynthesizesPlaintext,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use concrete_core::prelude::{
+    GgswCiphertextSeededScalarEncryptionEngine, GgswSeededCiphertextEntity, GlweSecretKeyEntity,
+    PlaintextEntity,
+};
+
+/// A fixture for the types implementing the `GgswCiphertextSeededScalarEncryptionEngine` trait.
+pub struct GgswCiphertextSeededScalarEncryptionFixture;
+
+#[derive(Debug)]
+pub struct GgswCiphertextSeededScalarEncryptionParameters {
+    pub ggsw_noise: Variance,
+    pub glwe_noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, Plaintext, SecretKey, SeededCiphertext>
+    Fixture<Precision, (KeyDistribution,), Engine, (Plaintext, SecretKey, SeededCiphertext)>
+    for GgswCiphertextSeededScalarEncryptionFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GgswCiphertextSeededScalarEncryptionEngine<SecretKey, Plaintext, SeededCiphertext>,
+    Plaintext: PlaintextEntity,
+    SecretKey: GlweSecretKeyEntity,
+    SeededCiphertext: GgswSeededCiphertextEntity,
+    Maker: SynthesizesPlaintext<Precision, Plaintext>
+        + SynthesizesGlweSecretKey<Precision, KeyDistribution, SecretKey>
+        + SynthesizesGgswSeededCiphertext<Precision, KeyDistribution, SeededCiphertext>
+        + PrototypesGlweCiphertextGgswCiphertextExternalProduct<Precision, KeyDistribution>,
+{
+    type Parameters = GgswCiphertextSeededScalarEncryptionParameters;
+    type RepetitionPrototypes =
+        (<Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,);
+    type SamplePrototypes = (
+        Precision::Raw,
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (SecretKey, Plaintext);
+    type PostExecutionContext = (SecretKey, Plaintext, SeededCiphertext);
+    type Criteria = ();
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GgswCiphertextSeededScalarEncryptionParameters {
+                    ggsw_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    glwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(512),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    decomposition_level_count: DecompositionLevelCount(4),
+                    message_bits: 3,
+                },
+                GgswCiphertextSeededScalarEncryptionParameters {
+                    ggsw_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    glwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(1024),
+                    decomposition_base_log: DecompositionBaseLog(6),
+                    decomposition_level_count: DecompositionLevelCount(4),
+                    message_bits: 3,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_bit = Precision::Raw::uniform_between(0..2);
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_bit);
+        // The output ciphertext is checked by selecting messages stored in the most significant
+        // bits of a GLWE ciphertext with an external product.
+        let raw_message_vector = Precision::Raw::uniform_n_msb_vec(
+            parameters.message_bits,
+            parameters.polynomial_size.0,
+        );
+        let proto_message_vector = maker.transform_raw_vec_to_plaintext_vector(&raw_message_vector);
+        let proto_glwe_ciphertext = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_message_vector,
+            parameters.glwe_noise,
+        );
+        (
+            raw_bit,
+            proto_plaintext,
+            proto_message_vector,
+            proto_glwe_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_secret_key,) = repetition_proto;
+        let (_, proto_plaintext, ..) = sample_proto;
+        (
+            maker.synthesize_glwe_secret_key(proto_secret_key),
+            maker.synthesize_plaintext(proto_plaintext),
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key, plaintext) = context;
+        let seeded_ciphertext = unsafe {
+            engine.encrypt_scalar_ggsw_seeded_ciphertext_unchecked(
+                &secret_key,
+                &plaintext,
+                parameters.ggsw_noise,
+                parameters.decomposition_level_count,
+                parameters.decomposition_base_log,
+            )
+        };
+        (secret_key, plaintext, seeded_ciphertext)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (proto_secret_key,) = repetition_proto;
+        let (raw_bit, _, proto_message_vector, proto_glwe_ciphertext) = sample_proto;
+        let (secret_key, plaintext, seeded_ciphertext) = context;
+        let proto_output_seeded_ciphertext =
+            maker.unsynthesize_ggsw_seeded_ciphertext(seeded_ciphertext);
+        maker.destroy_glwe_secret_key(secret_key);
+        maker.destroy_plaintext(plaintext);
+        let proto_output_ciphertext = maker
+            .transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext(&proto_output_seeded_ciphertext);
+        let proto_selected_ciphertext = maker
+            .compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+                proto_glwe_ciphertext,
+                &proto_output_ciphertext,
+            );
+        let proto_selected_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_secret_key,
+            &proto_selected_ciphertext,
+        );
+
+        // The external product with the output ciphertext keeps the messages when the encrypted
+        // bit is set, and zeroes them otherwise.
+        let shift = Precision::Raw::BITS - parameters.message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let expected_messages = maker
+            .transform_plaintext_vector_to_raw_vec(proto_message_vector)
+            .into_iter()
+            .map(|raw| (raw >> shift) * *raw_bit)
+            .collect();
+        let decoded_messages = maker
+            .transform_plaintext_vector_to_raw_vec(&proto_selected_vector)
+            .into_iter()
+            .map(|raw| raw.wrapping_add(rounding) >> shift)
+            .collect();
+        (expected_messages, decoded_messages)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
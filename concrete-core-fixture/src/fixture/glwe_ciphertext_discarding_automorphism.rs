@@ -0,0 +1,228 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweAutomorphismKey, PrototypesGlweCiphertext, PrototypesGlweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{SynthesizesGlweAutomorphismKey, SynthesizesGlweCiphertext};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    PolynomialSize,
+};
+use concrete_core::commons::math::polynomial::Polynomial;
+use concrete_core::prelude::{
+    GlweAutomorphismKeyEntity, GlweCiphertextDiscardingAutomorphismEngine, GlweCiphertextEntity,
+};
+
+/// A fixture for the types implementing the `GlweCiphertextDiscardingAutomorphismEngine` trait.
+pub struct GlweCiphertextDiscardingAutomorphismFixture;
+
+#[derive(Debug)]
+pub struct GlweCiphertextDiscardingAutomorphismParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub exponent: AutomorphismExponent,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, AutomorphismKey, InputCiphertext, OutputCiphertext>
+    Fixture<
+        Precision,
+        (KeyDistribution,),
+        Engine,
+        (AutomorphismKey, InputCiphertext, OutputCiphertext),
+    > for GlweCiphertextDiscardingAutomorphismFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GlweCiphertextDiscardingAutomorphismEngine<
+        AutomorphismKey,
+        InputCiphertext,
+        OutputCiphertext,
+    >,
+    AutomorphismKey: GlweAutomorphismKeyEntity,
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+    Maker: SynthesizesGlweAutomorphismKey<Precision, KeyDistribution, AutomorphismKey>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, InputCiphertext>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, OutputCiphertext>,
+{
+    type Parameters = GlweCiphertextDiscardingAutomorphismParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesGlweAutomorphismKey<Precision, KeyDistribution>>::GlweAutomorphismKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (AutomorphismKey, InputCiphertext, OutputCiphertext);
+    type PostExecutionContext = (AutomorphismKey, InputCiphertext, OutputCiphertext);
+    type Criteria = ();
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextDiscardingAutomorphismParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(256),
+                    decomposition_level_count: DecompositionLevelCount(5),
+                    decomposition_base_log: DecompositionBaseLog(4),
+                    exponent: AutomorphismExponent(5),
+                    message_bits: 4,
+                },
+                GlweCiphertextDiscardingAutomorphismParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(512),
+                    decomposition_level_count: DecompositionLevelCount(5),
+                    decomposition_base_log: DecompositionBaseLog(4),
+                    exponent: AutomorphismExponent(1023),
+                    message_bits: 4,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let proto_automorphism_key = maker.new_glwe_automorphism_key(
+            &proto_secret_key,
+            &[parameters.exponent],
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+            parameters.noise,
+        );
+        (proto_secret_key, proto_automorphism_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key, _) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_n_msb_vec(
+            parameters.message_bits,
+            parameters.polynomial_size.0,
+        );
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        let proto_input_ciphertext = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        let proto_output_ciphertext = maker.trivially_encrypt_zeros_to_glwe_ciphertext(
+            parameters.glwe_dimension,
+            parameters.polynomial_size,
+        );
+        (
+            proto_plaintext_vector,
+            proto_input_ciphertext,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_automorphism_key) = repetition_proto;
+        let (_, proto_input_ciphertext, proto_output_ciphertext) = sample_proto;
+        let synth_automorphism_key = maker.synthesize_glwe_automorphism_key(proto_automorphism_key);
+        let synth_input_ciphertext = maker.synthesize_glwe_ciphertext(proto_input_ciphertext);
+        let synth_output_ciphertext = maker.synthesize_glwe_ciphertext(proto_output_ciphertext);
+        (
+            synth_automorphism_key,
+            synth_input_ciphertext,
+            synth_output_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (automorphism_key, input_ciphertext, mut output_ciphertext) = context;
+        unsafe {
+            engine.discard_automorphism_glwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &automorphism_key,
+                parameters.exponent,
+            )
+        };
+        (automorphism_key, input_ciphertext, output_ciphertext)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (automorphism_key, input_ciphertext, output_ciphertext) = context;
+        let (proto_secret_key, _) = repetition_proto;
+        let (proto_plaintext_vector, ..) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(output_ciphertext);
+        maker.destroy_glwe_automorphism_key(automorphism_key);
+        maker.destroy_glwe_ciphertext(input_ciphertext);
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_secret_key,
+            &proto_output_ciphertext,
+        );
+
+        let shift = Precision::Raw::BITS - parameters.message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let raw_plaintext_vector =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector);
+        let mut raw_image = Polynomial::allocate(Precision::Raw::ZERO, parameters.polynomial_size);
+        raw_image.fill_with_automorphism(
+            &Polynomial::from_container(raw_plaintext_vector),
+            parameters.exponent,
+        );
+        let expected_messages = raw_image
+            .coefficient_iter()
+            .map(|raw| *raw >> shift)
+            .collect();
+        let decoded_messages = maker
+            .transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector)
+            .into_iter()
+            .map(|raw| raw.wrapping_add(rounding) >> shift)
+            .collect();
+        (expected_messages, decoded_messages)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
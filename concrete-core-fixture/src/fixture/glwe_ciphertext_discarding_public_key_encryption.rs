@@ -0,0 +1,194 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlwePublicKey, PrototypesGlweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweCiphertext, SynthesizesGlwePublicKey, SynthesizesPlaintextVector,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    GlweDimension, GlwePublicKeyZeroEncryptionCount, PolynomialSize,
+};
+use concrete_core::prelude::{
+    GlweCiphertextDiscardingPublicKeyEncryptionEngine, GlweCiphertextEntity, GlwePublicKeyEntity,
+    PlaintextVectorEntity,
+};
+
+/// A fixture for the types implementing the `GlweCiphertextDiscardingPublicKeyEncryptionEngine`
+/// trait.
+pub struct GlweCiphertextDiscardingPublicKeyEncryptionFixture;
+
+#[derive(Debug)]
+pub struct GlweCiphertextDiscardingPublicKeyEncryptionParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, PublicKey, PlaintextVector, Ciphertext>
+    Fixture<Precision, (KeyDistribution,), Engine, (PublicKey, PlaintextVector, Ciphertext)>
+    for GlweCiphertextDiscardingPublicKeyEncryptionFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine:
+        GlweCiphertextDiscardingPublicKeyEncryptionEngine<PublicKey, PlaintextVector, Ciphertext>,
+    PublicKey: GlwePublicKeyEntity,
+    PlaintextVector: PlaintextVectorEntity,
+    Ciphertext: GlweCiphertextEntity,
+    Maker: SynthesizesGlwePublicKey<Precision, KeyDistribution, PublicKey>
+        + SynthesizesPlaintextVector<Precision, PlaintextVector>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, Ciphertext>,
+{
+    type Parameters = GlweCiphertextDiscardingPublicKeyEncryptionParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesGlwePublicKey<Precision, KeyDistribution>>::GlwePublicKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (PublicKey, PlaintextVector, Ciphertext);
+    type PostExecutionContext = (PublicKey, PlaintextVector, Ciphertext);
+    type Criteria = ();
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweCiphertextDiscardingPublicKeyEncryptionParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(256),
+                    glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount(10),
+                    message_bits: 4,
+                },
+                GlweCiphertextDiscardingPublicKeyEncryptionParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(512),
+                    glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount(10),
+                    message_bits: 4,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let proto_public_key = maker.new_glwe_public_key(
+            &proto_secret_key,
+            parameters.noise,
+            parameters.glwe_public_key_zero_encryption_count,
+        );
+        (proto_secret_key, proto_public_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let raw_plaintext_vector = Precision::Raw::uniform_n_msb_vec(
+            parameters.message_bits,
+            parameters.polynomial_size.0,
+        );
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        let proto_ciphertext = maker.trivially_encrypt_zeros_to_glwe_ciphertext(
+            parameters.glwe_dimension,
+            parameters.polynomial_size,
+        );
+        (proto_plaintext_vector, proto_ciphertext)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_public_key) = repetition_proto;
+        let (proto_plaintext_vector, proto_ciphertext) = sample_proto;
+        (
+            maker.synthesize_glwe_public_key(proto_public_key),
+            maker.synthesize_plaintext_vector(proto_plaintext_vector),
+            maker.synthesize_glwe_ciphertext(proto_ciphertext),
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (public_key, plaintext_vector, mut ciphertext) = context;
+        unsafe {
+            engine.discard_encrypt_glwe_ciphertext_with_public_key_unchecked(
+                &public_key,
+                &mut ciphertext,
+                &plaintext_vector,
+                parameters.noise,
+            )
+        };
+        (public_key, plaintext_vector, ciphertext)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (proto_secret_key, _) = repetition_proto;
+        let (proto_plaintext_vector, _) = sample_proto;
+        let (public_key, plaintext_vector, ciphertext) = context;
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(ciphertext);
+        maker.destroy_glwe_public_key(public_key);
+        maker.destroy_plaintext_vector(plaintext_vector);
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_secret_key,
+            &proto_output_ciphertext,
+        );
+        let shift = Precision::Raw::BITS - parameters.message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let expected_messages = maker
+            .transform_plaintext_vector_to_raw_vec(proto_plaintext_vector)
+            .into_iter()
+            .map(|raw| raw >> shift)
+            .collect();
+        let decoded_messages = maker
+            .transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector)
+            .into_iter()
+            .map(|raw| raw.wrapping_add(rounding) >> shift)
+            .collect();
+        (expected_messages, decoded_messages)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
@@ -0,0 +1,247 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweRelinearizationKey, PrototypesGlweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweCiphertext, SynthesizesGlweRelinearizationKey,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, PolynomialSize,
+};
+use concrete_core::commons::math::polynomial::Polynomial;
+use concrete_core::prelude::{
+    GlweCiphertextDiscardingRelinearizationEngine, GlweCiphertextEntity,
+    GlweRelinearizationKeyEntity,
+};
+
+/// A fixture for the types implementing the `GlweCiphertextDiscardingRelinearizationEngine`
+/// trait.
+pub struct GlweCiphertextDiscardingRelinearizationFixture;
+
+#[derive(Debug)]
+pub struct GlweCiphertextDiscardingRelinearizationParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, RelinearizationKey, InputCiphertext, OutputCiphertext>
+    Fixture<
+        Precision,
+        (KeyDistribution,),
+        Engine,
+        (RelinearizationKey, InputCiphertext, OutputCiphertext),
+    > for GlweCiphertextDiscardingRelinearizationFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GlweCiphertextDiscardingRelinearizationEngine<
+        RelinearizationKey,
+        InputCiphertext,
+        OutputCiphertext,
+    >,
+    RelinearizationKey: GlweRelinearizationKeyEntity,
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+    Maker: SynthesizesGlweRelinearizationKey<Precision, KeyDistribution, RelinearizationKey>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, InputCiphertext>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, OutputCiphertext>,
+{
+    type Parameters = GlweCiphertextDiscardingRelinearizationParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesGlweRelinearizationKey<
+            Precision,
+            KeyDistribution,
+        >>::GlweRelinearizationKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (RelinearizationKey, InputCiphertext, OutputCiphertext);
+    type PostExecutionContext = (RelinearizationKey, InputCiphertext, OutputCiphertext);
+    type Criteria = ();
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![GlweCiphertextDiscardingRelinearizationParameters {
+                noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                glwe_dimension: GlweDimension(2),
+                polynomial_size: PolynomialSize(256),
+                decomposition_level_count: DecompositionLevelCount(3),
+                decomposition_base_log: DecompositionBaseLog(7),
+                message_bits: 2,
+            }]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let proto_relinearization_key = maker.new_glwe_relinearization_key(
+            &proto_secret_key,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+            parameters.noise,
+        );
+        (proto_secret_key, proto_relinearization_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key, _) = repetition_proto;
+        let raw_plaintext_vector1 = Precision::Raw::uniform_n_msb_vec(
+            parameters.message_bits,
+            parameters.polynomial_size.0,
+        );
+        let raw_plaintext_vector2 = Precision::Raw::uniform_n_msb_vec(
+            parameters.message_bits,
+            parameters.polynomial_size.0,
+        );
+        let proto_plaintext_vector1 =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector1);
+        let proto_plaintext_vector2 =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector2);
+        let proto_ciphertext1 = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector1,
+            parameters.noise,
+        );
+        let proto_ciphertext2 = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector2,
+            parameters.noise,
+        );
+        // The input of the relinearization is the tensor product of the two ciphertexts.
+        let delta_log = DeltaLog(Precision::Raw::BITS - parameters.message_bits);
+        let proto_input_ciphertext = maker.compute_tensor_product_glwe_ciphertext(
+            &proto_ciphertext1,
+            &proto_ciphertext2,
+            delta_log,
+        );
+        let proto_output_ciphertext = maker.trivially_encrypt_zeros_to_glwe_ciphertext(
+            parameters.glwe_dimension,
+            parameters.polynomial_size,
+        );
+        (
+            proto_plaintext_vector1,
+            proto_plaintext_vector2,
+            proto_input_ciphertext,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_relinearization_key) = repetition_proto;
+        let (_, _, proto_input_ciphertext, proto_output_ciphertext) = sample_proto;
+        let synth_relinearization_key =
+            maker.synthesize_glwe_relinearization_key(proto_relinearization_key);
+        let synth_input_ciphertext = maker.synthesize_glwe_ciphertext(proto_input_ciphertext);
+        let synth_output_ciphertext = maker.synthesize_glwe_ciphertext(proto_output_ciphertext);
+        (
+            synth_relinearization_key,
+            synth_input_ciphertext,
+            synth_output_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (relinearization_key, input_ciphertext, mut output_ciphertext) = context;
+        unsafe {
+            engine.discard_relinearize_glwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &relinearization_key,
+            )
+        };
+        (relinearization_key, input_ciphertext, output_ciphertext)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (relinearization_key, input_ciphertext, output_ciphertext) = context;
+        let (proto_secret_key, _) = repetition_proto;
+        let (proto_plaintext_vector1, proto_plaintext_vector2, ..) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(output_ciphertext);
+        maker.destroy_glwe_relinearization_key(relinearization_key);
+        maker.destroy_glwe_ciphertext(input_ciphertext);
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_secret_key,
+            &proto_output_ciphertext,
+        );
+
+        // The messages are multiplied as polynomials modulo X^N + 1 and 2^message_bits.
+        let shift = Precision::Raw::BITS - parameters.message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let raw_messages1 = maker
+            .transform_plaintext_vector_to_raw_vec(proto_plaintext_vector1)
+            .into_iter()
+            .map(|raw| raw >> shift)
+            .collect::<Vec<_>>();
+        let raw_plaintext_vector2 =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector2);
+        let mut raw_product =
+            Polynomial::allocate(Precision::Raw::ZERO, parameters.polynomial_size);
+        raw_product.fill_with_wrapping_mul(
+            &Polynomial::from_container(raw_messages1),
+            &Polynomial::from_container(raw_plaintext_vector2),
+        );
+        let expected_messages = raw_product
+            .coefficient_iter()
+            .map(|raw| *raw >> shift)
+            .collect();
+        let decoded_messages = maker
+            .transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector)
+            .into_iter()
+            .map(|raw| raw.wrapping_add(rounding) >> shift)
+            .collect();
+        (expected_messages, decoded_messages)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
@@ -0,0 +1,194 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweKeyswitchKey, PrototypesGlweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{SynthesizesGlweKeyswitchKey, SynthesizesGlweSecretKey};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use concrete_core::prelude::{
+    GlweKeyswitchKeyEntity, GlweKeyswitchKeyGenerationEngine, GlweSecretKeyEntity,
+};
+
+/// A fixture for the types implementing the `GlweKeyswitchKeyGenerationEngine` trait.
+pub struct GlweKeyswitchKeyGenerationFixture;
+
+#[derive(Debug)]
+pub struct GlweKeyswitchKeyGenerationParameters {
+    pub noise: Variance,
+    pub input_glwe_dimension: GlweDimension,
+    pub output_glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, InputSecretKey, OutputSecretKey, KeyswitchKey>
+    Fixture<Precision, (KeyDistribution,), Engine, (InputSecretKey, OutputSecretKey, KeyswitchKey)>
+    for GlweKeyswitchKeyGenerationFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GlweKeyswitchKeyGenerationEngine<InputSecretKey, OutputSecretKey, KeyswitchKey>,
+    InputSecretKey: GlweSecretKeyEntity,
+    OutputSecretKey: GlweSecretKeyEntity,
+    KeyswitchKey: GlweKeyswitchKeyEntity,
+    Maker: SynthesizesGlweKeyswitchKey<Precision, KeyDistribution, KeyswitchKey>
+        + SynthesizesGlweSecretKey<Precision, KeyDistribution, InputSecretKey>
+        + SynthesizesGlweSecretKey<Precision, KeyDistribution, OutputSecretKey>,
+{
+    type Parameters = GlweKeyswitchKeyGenerationParameters;
+    type RepetitionPrototypes = ();
+    type SamplePrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (InputSecretKey, OutputSecretKey);
+    type PostExecutionContext = (KeyswitchKey,);
+    type Criteria = ();
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlweKeyswitchKeyGenerationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    input_glwe_dimension: GlweDimension(2),
+                    output_glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(256),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    message_bits: 4,
+                },
+                GlweKeyswitchKeyGenerationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    input_glwe_dimension: GlweDimension(1),
+                    output_glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(512),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    message_bits: 4,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let proto_input_secret_key =
+            maker.new_glwe_secret_key(parameters.input_glwe_dimension, parameters.polynomial_size);
+        let proto_output_secret_key =
+            maker.new_glwe_secret_key(parameters.output_glwe_dimension, parameters.polynomial_size);
+        let raw_plaintext_vector = Precision::Raw::uniform_n_msb_vec(
+            parameters.message_bits,
+            parameters.polynomial_size.0,
+        );
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        let proto_ciphertext = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            &proto_input_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        (
+            proto_input_secret_key,
+            proto_output_secret_key,
+            proto_plaintext_vector,
+            proto_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_input_secret_key, proto_output_secret_key, ..) = sample_proto;
+        let synth_input_secret_key = maker.synthesize_glwe_secret_key(proto_input_secret_key);
+        let synth_output_secret_key = maker.synthesize_glwe_secret_key(proto_output_secret_key);
+        (synth_input_secret_key, synth_output_secret_key)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_secret_key, output_secret_key) = context;
+        let keyswitch_key = unsafe {
+            engine.generate_new_glwe_keyswitch_key_unchecked(
+                &input_secret_key,
+                &output_secret_key,
+                parameters.decomposition_level_count,
+                parameters.decomposition_base_log,
+                parameters.noise,
+            )
+        };
+        (keyswitch_key,)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (keyswitch_key,) = context;
+        let (_, proto_output_secret_key, proto_plaintext_vector, proto_ciphertext) = sample_proto;
+        // The generated key is checked by keyswitching a ciphertext and decrypting the result.
+        let proto_keyswitch_key = maker.unsynthesize_glwe_keyswitch_key(keyswitch_key);
+        let proto_output_ciphertext =
+            maker.keyswitch_glwe_ciphertext(proto_ciphertext, &proto_keyswitch_key);
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_output_secret_key,
+            &proto_output_ciphertext,
+        );
+        let shift = Precision::Raw::BITS - parameters.message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let expected_messages = maker
+            .transform_plaintext_vector_to_raw_vec(proto_plaintext_vector)
+            .into_iter()
+            .map(|raw| raw >> shift)
+            .collect();
+        let decoded_messages = maker
+            .transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector)
+            .into_iter()
+            .map(|raw| raw.wrapping_add(rounding) >> shift)
+            .collect();
+        (expected_messages, decoded_messages)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
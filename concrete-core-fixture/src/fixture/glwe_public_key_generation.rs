@@ -0,0 +1,171 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlwePublicKey, PrototypesGlweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{SynthesizesGlwePublicKey, SynthesizesGlweSecretKey};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    GlweDimension, GlwePublicKeyZeroEncryptionCount, PolynomialSize,
+};
+use concrete_core::prelude::{
+    GlwePublicKeyEntity, GlwePublicKeyGenerationEngine, GlweSecretKeyEntity,
+};
+
+/// A fixture for the types implementing the `GlwePublicKeyGenerationEngine` trait.
+pub struct GlwePublicKeyGenerationFixture;
+
+#[derive(Debug)]
+pub struct GlwePublicKeyGenerationParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, SecretKey, PublicKey>
+    Fixture<Precision, (KeyDistribution,), Engine, (SecretKey, PublicKey)>
+    for GlwePublicKeyGenerationFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GlwePublicKeyGenerationEngine<SecretKey, PublicKey>,
+    SecretKey: GlweSecretKeyEntity,
+    PublicKey: GlwePublicKeyEntity,
+    Maker: SynthesizesGlweSecretKey<Precision, KeyDistribution, SecretKey>
+        + SynthesizesGlwePublicKey<Precision, KeyDistribution, PublicKey>,
+{
+    type Parameters = GlwePublicKeyGenerationParameters;
+    type RepetitionPrototypes = ();
+    type SamplePrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+    );
+    type PreExecutionContext = (SecretKey,);
+    type PostExecutionContext = (SecretKey, PublicKey);
+    type Criteria = ();
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GlwePublicKeyGenerationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(256),
+                    glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount(10),
+                    message_bits: 4,
+                },
+                GlwePublicKeyGenerationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(512),
+                    glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount(10),
+                    message_bits: 4,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let raw_plaintext_vector = Precision::Raw::uniform_n_msb_vec(
+            parameters.message_bits,
+            parameters.polynomial_size.0,
+        );
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        (proto_secret_key, proto_plaintext_vector)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_secret_key, _) = sample_proto;
+        (maker.synthesize_glwe_secret_key(proto_secret_key),)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key,) = context;
+        let public_key = unsafe {
+            engine.generate_new_glwe_public_key_unchecked(
+                &secret_key,
+                parameters.noise,
+                parameters.glwe_public_key_zero_encryption_count,
+            )
+        };
+        (secret_key, public_key)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (secret_key, public_key) = context;
+        let (proto_secret_key, proto_plaintext_vector) = sample_proto;
+        maker.destroy_glwe_secret_key(secret_key);
+        // The generated key is checked by encrypting a plaintext vector with it, and decrypting
+        // the result with the secret key.
+        let proto_public_key = maker.unsynthesize_glwe_public_key(public_key);
+        let proto_ciphertext = maker.encrypt_plaintext_vector_to_glwe_ciphertext_with_public_key(
+            &proto_public_key,
+            proto_plaintext_vector,
+            parameters.noise,
+        );
+        let proto_output_plaintext_vector =
+            maker.decrypt_glwe_ciphertext_to_plaintext_vector(proto_secret_key, &proto_ciphertext);
+        let shift = Precision::Raw::BITS - parameters.message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let expected_messages = maker
+            .transform_plaintext_vector_to_raw_vec(proto_plaintext_vector)
+            .into_iter()
+            .map(|raw| raw >> shift)
+            .collect();
+        let decoded_messages = maker
+            .transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector)
+            .into_iter()
+            .map(|raw| raw.wrapping_add(rounding) >> shift)
+            .collect();
+        (expected_messages, decoded_messages)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
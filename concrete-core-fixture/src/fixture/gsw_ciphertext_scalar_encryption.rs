@@ -0,0 +1,194 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGswCiphertext, PrototypesLweCiphertext, PrototypesLweSecretKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGswCiphertext, SynthesizesLweSecretKey, SynthesizesPlaintext,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+use concrete_core::prelude::{
+    GswCiphertextEntity, GswCiphertextScalarEncryptionEngine, LweSecretKeyEntity, PlaintextEntity,
+};
+
+/// A fixture for the types implementing the `GswCiphertextScalarEncryptionEngine` trait.
+pub struct GswCiphertextScalarEncryptionFixture;
+
+#[derive(Debug)]
+pub struct GswCiphertextScalarEncryptionParameters {
+    pub gsw_noise: Variance,
+    pub lwe_noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, Plaintext, SecretKey, Ciphertext>
+    Fixture<Precision, (KeyDistribution,), Engine, (Plaintext, SecretKey, Ciphertext)>
+    for GswCiphertextScalarEncryptionFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: GswCiphertextScalarEncryptionEngine<SecretKey, Plaintext, Ciphertext>,
+    Plaintext: PlaintextEntity,
+    SecretKey: LweSecretKeyEntity,
+    Ciphertext: GswCiphertextEntity,
+    Maker: SynthesizesPlaintext<Precision, Plaintext>
+        + SynthesizesLweSecretKey<Precision, KeyDistribution, SecretKey>
+        + SynthesizesGswCiphertext<Precision, KeyDistribution, Ciphertext>,
+{
+    type Parameters = GswCiphertextScalarEncryptionParameters;
+    type RepetitionPrototypes =
+        (<Maker as PrototypesLweSecretKey<Precision, KeyDistribution>>::LweSecretKeyProto,);
+    type SamplePrototypes = (
+        Precision::Raw,
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertext<Precision, KeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (SecretKey, Plaintext);
+    type PostExecutionContext = (SecretKey, Plaintext, Ciphertext);
+    type Criteria = ();
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                GswCiphertextScalarEncryptionParameters {
+                    gsw_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    lwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    lwe_dimension: LweDimension(256),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    message_bits: 4,
+                },
+                GswCiphertextScalarEncryptionParameters {
+                    gsw_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    lwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    lwe_dimension: LweDimension(100),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    message_bits: 4,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_bit = Precision::Raw::uniform_between(0..2);
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_bit);
+        // The output ciphertext is checked by selecting a message stored in the most significant
+        // bits of an LWE ciphertext with an external product.
+        let raw_message = Precision::Raw::uniform_n_msb(parameters.message_bits);
+        let proto_message = maker.transform_raw_to_plaintext(&raw_message);
+        let proto_lwe_ciphertext = maker.encrypt_plaintext_to_lwe_ciphertext(
+            proto_secret_key,
+            &proto_message,
+            parameters.lwe_noise,
+        );
+        (
+            raw_bit,
+            proto_plaintext,
+            proto_message,
+            proto_lwe_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_secret_key,) = repetition_proto;
+        let (_, proto_plaintext, ..) = sample_proto;
+        (
+            maker.synthesize_lwe_secret_key(proto_secret_key),
+            maker.synthesize_plaintext(proto_plaintext),
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key, plaintext) = context;
+        let ciphertext = unsafe {
+            engine.encrypt_scalar_gsw_ciphertext_unchecked(
+                &secret_key,
+                &plaintext,
+                parameters.gsw_noise,
+                parameters.decomposition_level_count,
+                parameters.decomposition_base_log,
+            )
+        };
+        (secret_key, plaintext, ciphertext)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (proto_secret_key,) = repetition_proto;
+        let (raw_bit, _, proto_message, proto_lwe_ciphertext) = sample_proto;
+        let (secret_key, plaintext, ciphertext) = context;
+        let proto_output_ciphertext = maker.unsynthesize_gsw_ciphertext(ciphertext);
+        maker.destroy_lwe_secret_key(secret_key);
+        maker.destroy_plaintext(plaintext);
+        let proto_selected_ciphertext = maker
+            .compute_external_product_lwe_ciphertext_gsw_ciphertext(
+                proto_lwe_ciphertext,
+                &proto_output_ciphertext,
+            );
+        let proto_selected_message =
+            maker.decrypt_lwe_ciphertext_to_plaintext(proto_secret_key, &proto_selected_ciphertext);
+
+        // The external product with the output ciphertext keeps the message when the encrypted
+        // bit is set, and zeroes it otherwise.
+        let shift = Precision::Raw::BITS - parameters.message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let expected_message =
+            (maker.transform_plaintext_to_raw(proto_message) >> shift) * *raw_bit;
+        let decoded_message = maker
+            .transform_plaintext_to_raw(&proto_selected_message)
+            .wrapping_add(rounding)
+            >> shift;
+        (expected_message, decoded_message)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
@@ -0,0 +1,319 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweSecretKey, PrototypesLweBootstrapKey, PrototypesLweCiphertext,
+    PrototypesLweCiphertextVector, PrototypesLweKeyswitchKey, PrototypesLweSecretKey,
+    PrototypesPlaintext, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweBootstrapKey, SynthesizesLweCiphertext, SynthesizesLweCiphertextVector,
+    SynthesizesLweKeyswitchKey,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DeltaLog, ExtractedBitsCount, GlweDimension,
+    LweCiphertextCount, LweDimension, PolynomialSize,
+};
+use concrete_core::prelude::{
+    LweBootstrapKeyEntity, LweCiphertextDiscardingBitExtractEngine, LweCiphertextEntity,
+    LweCiphertextVectorEntity, LweKeyswitchKeyEntity,
+};
+
+/// A fixture for the types implementing the `LweCiphertextDiscardingBitExtractEngine` trait.
+pub struct LweCiphertextDiscardingBitExtractionFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextDiscardingBitExtractionParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub poly_size: PolynomialSize,
+    pub bsk_decomp_level_count: DecompositionLevelCount,
+    pub bsk_decomp_base_log: DecompositionBaseLog,
+    pub ksk_decomp_level_count: DecompositionLevelCount,
+    pub ksk_decomp_base_log: DecompositionBaseLog,
+    pub extracted_bits_count: ExtractedBitsCount,
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        BootstrapKey,
+        KeyswitchKey,
+        InputCiphertext,
+        OutputCiphertextVector,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (
+            BootstrapKey,
+            KeyswitchKey,
+            InputCiphertext,
+            OutputCiphertextVector,
+        ),
+    > for LweCiphertextDiscardingBitExtractionFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextDiscardingBitExtractEngine<
+        BootstrapKey,
+        KeyswitchKey,
+        InputCiphertext,
+        OutputCiphertextVector,
+    >,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertextVector: LweCiphertextVectorEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    Maker: SynthesizesLweBootstrapKey<
+            Precision,
+            OutputKeyDistribution,
+            InputKeyDistribution,
+            BootstrapKey,
+        > + SynthesizesLweKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            KeyswitchKey,
+        > + SynthesizesLweCiphertext<Precision, InputKeyDistribution, InputCiphertext>
+        + SynthesizesLweCiphertextVector<Precision, OutputKeyDistribution, OutputCiphertextVector>,
+{
+    type Parameters = LweCiphertextDiscardingBitExtractionParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesLweSecretKey<Precision, OutputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesLweBootstrapKey<
+            Precision,
+            OutputKeyDistribution,
+            InputKeyDistribution,
+        >>::LweBootstrapKeyProto,
+        <Maker as PrototypesLweKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweKeyswitchKeyProto,
+    );
+    type SamplePrototypes = (
+        Precision::Raw,
+        <Maker as PrototypesLweCiphertext<Precision, InputKeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            OutputKeyDistribution,
+        >>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (
+        BootstrapKey,
+        KeyswitchKey,
+        OutputCiphertextVector,
+        InputCiphertext,
+    );
+    type PostExecutionContext = (
+        BootstrapKey,
+        KeyswitchKey,
+        OutputCiphertextVector,
+        InputCiphertext,
+    );
+    type Criteria = ();
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![LweCiphertextDiscardingBitExtractionParameters {
+                noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
+                lwe_dimension: LweDimension(630),
+                glwe_dimension: GlweDimension(1),
+                poly_size: PolynomialSize(1024),
+                bsk_decomp_level_count: DecompositionLevelCount(3),
+                bsk_decomp_base_log: DecompositionBaseLog(7),
+                ksk_decomp_level_count: DecompositionLevelCount(7),
+                ksk_decomp_base_log: DecompositionBaseLog(4),
+                extracted_bits_count: ExtractedBitsCount(3),
+            }]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        // The input ciphertext is encrypted under the GLWE key seen as an LWE key, and the output
+        // ciphertexts under the small LWE key used as input of the bootstrap.
+        let proto_glwe_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            InputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker, parameters.glwe_dimension, parameters.poly_size
+        );
+        let proto_input_secret_key =
+            maker.transform_glwe_secret_key_to_lwe_secret_key(&proto_glwe_secret_key);
+        let proto_output_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_lwe_secret_key(
+            maker, parameters.lwe_dimension
+        );
+        let proto_bootstrap_key = maker.new_lwe_bootstrap_key(
+            &proto_output_secret_key,
+            &proto_glwe_secret_key,
+            parameters.bsk_decomp_level_count,
+            parameters.bsk_decomp_base_log,
+            parameters.noise,
+        );
+        let proto_keyswitch_key = maker.new_lwe_keyswitch_key(
+            &proto_input_secret_key,
+            &proto_output_secret_key,
+            parameters.ksk_decomp_level_count,
+            parameters.ksk_decomp_base_log,
+            parameters.noise,
+        );
+        (
+            proto_input_secret_key,
+            proto_output_secret_key,
+            proto_bootstrap_key,
+            proto_keyswitch_key,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_input_secret_key, ..) = repetition_proto;
+        // The message is stored in the most significant bits, with no bit of padding.
+        let raw_plaintext = Precision::Raw::uniform_n_msb(parameters.extracted_bits_count.0);
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_input_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_to_lwe_ciphertext(
+            maker,
+            proto_input_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+        );
+        let proto_output_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            OutputKeyDistribution,
+        >>::trivially_encrypt_zeros_to_lwe_ciphertext_vector(
+            maker,
+            parameters.lwe_dimension,
+            LweCiphertextCount(parameters.extracted_bits_count.0),
+        );
+        (
+            raw_plaintext,
+            proto_input_ciphertext,
+            proto_output_ciphertext_vector,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, _, proto_bootstrap_key, proto_keyswitch_key) = repetition_proto;
+        let (_, proto_input_ciphertext, proto_output_ciphertext_vector) = sample_proto;
+        let synth_bootstrap_key = maker.synthesize_lwe_bootstrap_key(proto_bootstrap_key);
+        let synth_keyswitch_key = maker.synthesize_lwe_keyswitch_key(proto_keyswitch_key);
+        let synth_input_ciphertext = maker.synthesize_lwe_ciphertext(proto_input_ciphertext);
+        let synth_output_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_output_ciphertext_vector);
+        (
+            synth_bootstrap_key,
+            synth_keyswitch_key,
+            synth_output_ciphertext_vector,
+            synth_input_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (bootstrap_key, keyswitch_key, mut output_ciphertext_vector, input_ciphertext) =
+            context;
+        let delta_log = DeltaLog(Precision::Raw::BITS - parameters.extracted_bits_count.0);
+        unsafe {
+            engine.discard_extract_bits_lwe_ciphertext_unchecked(
+                &mut output_ciphertext_vector,
+                &input_ciphertext,
+                &bootstrap_key,
+                &keyswitch_key,
+                parameters.extracted_bits_count,
+                delta_log,
+            )
+        };
+        (
+            bootstrap_key,
+            keyswitch_key,
+            output_ciphertext_vector,
+            input_ciphertext,
+        )
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (bootstrap_key, keyswitch_key, output_ciphertext_vector, input_ciphertext) = context;
+        let (_, proto_output_secret_key, ..) = repetition_proto;
+        let (raw_plaintext, ..) = sample_proto;
+        let proto_output_ciphertext_vector =
+            maker.unsynthesize_lwe_ciphertext_vector(output_ciphertext_vector);
+        let proto_output_plaintext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            OutputKeyDistribution,
+        >>::decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+            maker,
+            proto_output_secret_key,
+            &proto_output_ciphertext_vector,
+        );
+        maker.destroy_lwe_ciphertext(input_ciphertext);
+        maker.destroy_lwe_bootstrap_key(bootstrap_key);
+        maker.destroy_lwe_keyswitch_key(keyswitch_key);
+
+        // The output ciphertexts encrypt the bits of the message in their most significant bit,
+        // starting with the most significant bit of the message.
+        let bits_count = parameters.extracted_bits_count.0;
+        let message = *raw_plaintext >> (Precision::Raw::BITS - bits_count);
+        let expected_bits = (0..bits_count)
+            .rev()
+            .map(|i| (message >> i) & Precision::Raw::ONE)
+            .collect();
+        let rounding = Precision::Raw::ONE << (Precision::Raw::BITS - 2);
+        let decoded_bits = maker
+            .transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector)
+            .into_iter()
+            .map(|raw| raw.wrapping_add(rounding) >> (Precision::Raw::BITS - 1))
+            .collect();
+        (expected_bits, decoded_bits)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
@@ -275,9 +275,16 @@ where
         _maker: &mut Maker,
         _repetition_proto: &Self::RepetitionPrototypes,
     ) -> Self::Criteria {
+        // A ternary input key is bootstrapped with two cmuxes per key coefficient.
+        let cmux_count =
+            if TypeId::of::<InputKeyDistribution>() == TypeId::of::<TernaryKeyDistribution>() {
+                LweDimension(2 * parameters.lwe_dimension.0)
+            } else {
+                parameters.lwe_dimension
+            };
         let predicted_variance: Variance =
             fix_estimate_pbs_noise::<Precision::Raw, Variance, OutputKeyDistribution>(
-                parameters.lwe_dimension,
+                cmux_count,
                 parameters.poly_size,
                 parameters.glwe_dimension,
                 parameters.decomp_base_log,
@@ -0,0 +1,349 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGgswCiphertext, PrototypesGlweCiphertext,
+    PrototypesGlweCiphertextGgswCiphertextExternalProduct, PrototypesGlweSecretKey,
+    PrototypesLweBootstrapKey, PrototypesLweCiphertext,
+    PrototypesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys, PrototypesLweSecretKey,
+    PrototypesPlaintext, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGgswCiphertext, SynthesizesLweBootstrapKey, SynthesizesLweCiphertext,
+    SynthesizesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, LweDimension,
+    PolynomialSize,
+};
+use concrete_core::prelude::{
+    GgswCiphertextEntity, LweBootstrapKeyEntity,
+    LweCiphertextDiscardingCircuitBootstrapBooleanEngine, LweCiphertextEntity,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+};
+
+/// A fixture for the types implementing the `LweCiphertextDiscardingCircuitBootstrapBooleanEngine`
+/// trait.
+pub struct LweCiphertextDiscardingCircuitBootstrapBooleanFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextDiscardingCircuitBootstrapBooleanParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub poly_size: PolynomialSize,
+    pub bsk_decomp_level_count: DecompositionLevelCount,
+    pub bsk_decomp_base_log: DecompositionBaseLog,
+    pub pfpksk_decomp_level_count: DecompositionLevelCount,
+    pub pfpksk_decomp_base_log: DecompositionBaseLog,
+    pub cbs_decomp_level_count: DecompositionLevelCount,
+    pub cbs_decomp_base_log: DecompositionBaseLog,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        InputCiphertext,
+        OutputCiphertext,
+        BootstrapKey,
+        CircuitBootstrapFunctionalPackingKeyswitchKeys,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (
+            InputCiphertext,
+            OutputCiphertext,
+            BootstrapKey,
+            CircuitBootstrapFunctionalPackingKeyswitchKeys,
+        ),
+    > for LweCiphertextDiscardingCircuitBootstrapBooleanFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextDiscardingCircuitBootstrapBooleanEngine<
+        InputCiphertext,
+        OutputCiphertext,
+        BootstrapKey,
+        CircuitBootstrapFunctionalPackingKeyswitchKeys,
+    >,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: GgswCiphertextEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+    CircuitBootstrapFunctionalPackingKeyswitchKeys:
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    Maker: SynthesizesLweCiphertext<Precision, InputKeyDistribution, InputCiphertext>
+        + SynthesizesGgswCiphertext<Precision, OutputKeyDistribution, OutputCiphertext>
+        + SynthesizesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            BootstrapKey,
+        > + SynthesizesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+            Precision,
+            OutputKeyDistribution,
+            OutputKeyDistribution,
+            CircuitBootstrapFunctionalPackingKeyswitchKeys,
+        > + PrototypesGlweCiphertextGgswCiphertextExternalProduct<Precision, OutputKeyDistribution>,
+{
+    type Parameters = LweCiphertextDiscardingCircuitBootstrapBooleanParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, OutputKeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweBootstrapKeyProto,
+        <Maker as PrototypesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+            Precision,
+            OutputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto,
+    );
+    type SamplePrototypes = (
+        Precision::Raw,
+        <Maker as PrototypesLweCiphertext<Precision, InputKeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesGgswCiphertext<Precision, OutputKeyDistribution>>::GgswCiphertextProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, OutputKeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (
+        InputCiphertext,
+        OutputCiphertext,
+        BootstrapKey,
+        CircuitBootstrapFunctionalPackingKeyswitchKeys,
+    );
+    type PostExecutionContext = (
+        InputCiphertext,
+        OutputCiphertext,
+        BootstrapKey,
+        CircuitBootstrapFunctionalPackingKeyswitchKeys,
+    );
+    type Criteria = ();
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![LweCiphertextDiscardingCircuitBootstrapBooleanParameters {
+                noise: Variance(LogStandardDev::from_log_standard_dev(-40.).get_variance()),
+                lwe_dimension: LweDimension(10),
+                glwe_dimension: GlweDimension(1),
+                poly_size: PolynomialSize(512),
+                bsk_decomp_level_count: DecompositionLevelCount(3),
+                bsk_decomp_base_log: DecompositionBaseLog(10),
+                pfpksk_decomp_level_count: DecompositionLevelCount(3),
+                pfpksk_decomp_base_log: DecompositionBaseLog(10),
+                cbs_decomp_level_count: DecompositionLevelCount(2),
+                cbs_decomp_base_log: DecompositionBaseLog(8),
+                message_bits: 3,
+            }]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            InputKeyDistribution,
+        >>::new_lwe_secret_key(maker, parameters.lwe_dimension);
+        let proto_glwe_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker, parameters.glwe_dimension, parameters.poly_size
+        );
+        let proto_bootstrap_key = maker.new_lwe_bootstrap_key(
+            &proto_lwe_secret_key,
+            &proto_glwe_secret_key,
+            parameters.bsk_decomp_level_count,
+            parameters.bsk_decomp_base_log,
+            parameters.noise,
+        );
+        // The keyswitch keys take the ciphertexts output by the bootstrap, which are encrypted
+        // under the GLWE key seen as an LWE key.
+        let proto_bootstrap_output_secret_key =
+            maker.transform_glwe_secret_key_to_lwe_secret_key(&proto_glwe_secret_key);
+        let proto_cbs_pfpksk = maker.new_lwe_private_functional_packing_keyswitch_key(
+            &proto_bootstrap_output_secret_key,
+            &proto_glwe_secret_key,
+            parameters.pfpksk_decomp_base_log,
+            parameters.pfpksk_decomp_level_count,
+            parameters.noise,
+        );
+        (
+            proto_lwe_secret_key,
+            proto_glwe_secret_key,
+            proto_bootstrap_key,
+            proto_cbs_pfpksk,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_lwe_secret_key, proto_glwe_secret_key, ..) = repetition_proto;
+        // The bit is stored in the most significant bit, with no bit of padding.
+        let raw_bit = Precision::Raw::uniform_n_msb(1);
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_bit);
+        let proto_input_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_to_lwe_ciphertext(
+            maker,
+            proto_lwe_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+        );
+        let proto_output_ciphertext = <Maker as PrototypesGgswCiphertext<
+            Precision,
+            OutputKeyDistribution,
+        >>::trivially_encrypt_zero_to_ggsw_ciphertext(
+            maker,
+            parameters.glwe_dimension,
+            parameters.poly_size,
+            parameters.cbs_decomp_level_count,
+            parameters.cbs_decomp_base_log,
+        );
+        // The output ciphertext is checked by selecting messages stored in the most significant
+        // bits of a GLWE ciphertext with an external product.
+        let raw_message_vector =
+            Precision::Raw::uniform_n_msb_vec(parameters.message_bits, parameters.poly_size.0);
+        let proto_message_vector = maker.transform_raw_vec_to_plaintext_vector(&raw_message_vector);
+        let proto_glwe_ciphertext = <Maker as PrototypesGlweCiphertext<
+            Precision,
+            OutputKeyDistribution,
+        >>::encrypt_plaintext_vector_to_glwe_ciphertext(
+            maker,
+            proto_glwe_secret_key,
+            &proto_message_vector,
+            parameters.noise,
+        );
+        (
+            raw_bit,
+            proto_input_ciphertext,
+            proto_output_ciphertext,
+            proto_message_vector,
+            proto_glwe_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, _, proto_bootstrap_key, proto_cbs_pfpksk) = repetition_proto;
+        let (_, proto_input_ciphertext, proto_output_ciphertext, ..) = sample_proto;
+        let synth_input_ciphertext = maker.synthesize_lwe_ciphertext(proto_input_ciphertext);
+        let synth_output_ciphertext = maker.synthesize_ggsw_ciphertext(proto_output_ciphertext);
+        let synth_bootstrap_key = maker.synthesize_lwe_bootstrap_key(proto_bootstrap_key);
+        let synth_cbs_pfpksk = maker
+            .synthesize_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+                proto_cbs_pfpksk,
+            );
+        (
+            synth_input_ciphertext,
+            synth_output_ciphertext,
+            synth_bootstrap_key,
+            synth_cbs_pfpksk,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_ciphertext, mut output_ciphertext, bootstrap_key, cbs_pfpksk) = context;
+        let delta_log = DeltaLog(Precision::Raw::BITS - 1);
+        unsafe {
+            engine.discard_circuit_bootstrap_boolean_lwe_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                delta_log,
+                &bootstrap_key,
+                &cbs_pfpksk,
+            )
+        };
+        (
+            input_ciphertext,
+            output_ciphertext,
+            bootstrap_key,
+            cbs_pfpksk,
+        )
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_ciphertext, output_ciphertext, bootstrap_key, cbs_pfpksk) = context;
+        let (_, proto_glwe_secret_key, ..) = repetition_proto;
+        let (raw_bit, _, _, proto_message_vector, proto_glwe_ciphertext) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_ggsw_ciphertext(output_ciphertext);
+        maker.destroy_lwe_ciphertext(input_ciphertext);
+        maker.destroy_lwe_bootstrap_key(bootstrap_key);
+        maker.destroy_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(cbs_pfpksk);
+        let proto_selected_ciphertext = maker
+            .compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+                proto_glwe_ciphertext,
+                &proto_output_ciphertext,
+            );
+        let proto_selected_vector = <Maker as PrototypesGlweCiphertext<
+            Precision,
+            OutputKeyDistribution,
+        >>::decrypt_glwe_ciphertext_to_plaintext_vector(
+            maker,
+            proto_glwe_secret_key,
+            &proto_selected_ciphertext,
+        );
+
+        // The external product with the output ciphertext keeps the messages when the bit is set,
+        // and zeroes them otherwise.
+        let shift = Precision::Raw::BITS - parameters.message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let bit = *raw_bit >> (Precision::Raw::BITS - 1);
+        let expected_messages = maker
+            .transform_plaintext_vector_to_raw_vec(proto_message_vector)
+            .into_iter()
+            .map(|raw| (raw >> shift) * bit)
+            .collect();
+        let decoded_messages = maker
+            .transform_plaintext_vector_to_raw_vec(&proto_selected_vector)
+            .into_iter()
+            .map(|raw| raw.wrapping_add(rounding) >> shift)
+            .collect();
+        (expected_messages, decoded_messages)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
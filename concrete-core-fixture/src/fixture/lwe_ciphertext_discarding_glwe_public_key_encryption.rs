@@ -0,0 +1,185 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlwePublicKey, PrototypesGlweSecretKey, PrototypesLweCiphertext,
+    PrototypesLweSecretKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlwePublicKey, SynthesizesLweCiphertext, SynthesizesPlaintext,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    GlweDimension, GlwePublicKeyZeroEncryptionCount, LweDimension, PolynomialSize,
+};
+use concrete_core::prelude::{
+    GlwePublicKeyEntity, LweCiphertextDiscardingGlwePublicKeyEncryptionEngine, LweCiphertextEntity,
+    PlaintextEntity,
+};
+
+/// A fixture for the types implementing the
+/// `LweCiphertextDiscardingGlwePublicKeyEncryptionEngine` trait.
+pub struct LweCiphertextDiscardingGlwePublicKeyEncryptionFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextDiscardingGlwePublicKeyEncryptionParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, PublicKey, Plaintext, Ciphertext>
+    Fixture<Precision, (KeyDistribution,), Engine, (PublicKey, Plaintext, Ciphertext)>
+    for LweCiphertextDiscardingGlwePublicKeyEncryptionFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextDiscardingGlwePublicKeyEncryptionEngine<PublicKey, Plaintext, Ciphertext>,
+    PublicKey: GlwePublicKeyEntity,
+    Plaintext: PlaintextEntity,
+    Ciphertext: LweCiphertextEntity,
+    Maker: SynthesizesGlwePublicKey<Precision, KeyDistribution, PublicKey>
+        + SynthesizesPlaintext<Precision, Plaintext>
+        + SynthesizesLweCiphertext<Precision, KeyDistribution, Ciphertext>,
+{
+    type Parameters = LweCiphertextDiscardingGlwePublicKeyEncryptionParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, KeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGlwePublicKey<Precision, KeyDistribution>>::GlwePublicKeyProto,
+    );
+    type SamplePrototypes = (
+        Precision::Raw,
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertext<Precision, KeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (PublicKey, Plaintext, Ciphertext);
+    type PostExecutionContext = (PublicKey, Plaintext, Ciphertext);
+    type Criteria = ();
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextDiscardingGlwePublicKeyEncryptionParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(256),
+                    glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount(10),
+                    message_bits: 4,
+                },
+                LweCiphertextDiscardingGlwePublicKeyEncryptionParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(512),
+                    glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount(10),
+                    message_bits: 4,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_glwe_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let proto_public_key = maker.new_glwe_public_key(
+            &proto_glwe_secret_key,
+            parameters.noise,
+            parameters.glwe_public_key_zero_encryption_count,
+        );
+        // The output ciphertexts are decrypted with the flattened glwe secret key.
+        let proto_lwe_secret_key =
+            maker.transform_glwe_secret_key_to_lwe_secret_key(&proto_glwe_secret_key);
+        (proto_lwe_secret_key, proto_public_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let raw_plaintext = Precision::Raw::uniform_n_msb(parameters.message_bits);
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_ciphertext = maker.trivially_encrypt_zero_to_lwe_ciphertext(LweDimension(
+            parameters.glwe_dimension.0 * parameters.polynomial_size.0,
+        ));
+        (raw_plaintext, proto_plaintext, proto_ciphertext)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_public_key) = repetition_proto;
+        let (_, proto_plaintext, proto_ciphertext) = sample_proto;
+        (
+            maker.synthesize_glwe_public_key(proto_public_key),
+            maker.synthesize_plaintext(proto_plaintext),
+            maker.synthesize_lwe_ciphertext(proto_ciphertext),
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (public_key, plaintext, mut ciphertext) = context;
+        unsafe {
+            engine.discard_encrypt_lwe_ciphertext_with_glwe_public_key_unchecked(
+                &public_key,
+                &mut ciphertext,
+                &plaintext,
+                parameters.noise,
+            )
+        };
+        (public_key, plaintext, ciphertext)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (proto_lwe_secret_key, _) = repetition_proto;
+        let (raw_plaintext, ..) = sample_proto;
+        let (public_key, plaintext, ciphertext) = context;
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(ciphertext);
+        maker.destroy_glwe_public_key(public_key);
+        maker.destroy_plaintext(plaintext);
+        let proto_output_plaintext = maker
+            .decrypt_lwe_ciphertext_to_plaintext(proto_lwe_secret_key, &proto_output_ciphertext);
+        let shift = Precision::Raw::BITS - parameters.message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let expected_message = *raw_plaintext >> shift;
+        let decoded_message = maker
+            .transform_plaintext_to_raw(&proto_output_plaintext)
+            .wrapping_add(rounding)
+            >> shift;
+        (expected_message, decoded_message)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
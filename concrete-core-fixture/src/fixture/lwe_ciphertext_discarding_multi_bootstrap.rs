@@ -0,0 +1,368 @@
+use crate::fixture::lwe_ciphertext_discarding_bootstrap_1::fix_estimate_pbs_noise;
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweSecretKey, PrototypesLweBootstrapKey,
+    PrototypesLweCiphertext, PrototypesLweCiphertextVector, PrototypesLweSecretKey,
+    PrototypesPlaintext, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweCiphertext, SynthesizesLweBootstrapKey, SynthesizesLweCiphertext,
+    SynthesizesLweCiphertextVector,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_delta_std_dev;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{CastInto, Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LutCountLog, LweCiphertextCount,
+    LweDimension, PolynomialSize,
+};
+use concrete_core::prelude::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextDiscardingMultiBootstrapEngine,
+    LweCiphertextEntity, LweCiphertextVectorEntity,
+};
+
+/// A fixture for the types implementing the `LweCiphertextDiscardingMultiBootstrapEngine` trait.
+pub struct LweCiphertextDiscardingMultiBootstrapFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextDiscardingMultiBootstrapParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub poly_size: PolynomialSize,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub decomp_base_log: DecompositionBaseLog,
+    pub lut_count_log: LutCountLog,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertextVector,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (
+            BootstrapKey,
+            Accumulator,
+            InputCiphertext,
+            OutputCiphertextVector,
+        ),
+    > for LweCiphertextDiscardingMultiBootstrapFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextDiscardingMultiBootstrapEngine<
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertextVector,
+    >,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertextVector: LweCiphertextVectorEntity,
+    Accumulator: GlweCiphertextEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+    Maker: SynthesizesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            BootstrapKey,
+        > + SynthesizesGlweCiphertext<Precision, OutputKeyDistribution, Accumulator>
+        + SynthesizesLweCiphertext<Precision, InputKeyDistribution, InputCiphertext>
+        + SynthesizesLweCiphertextVector<Precision, OutputKeyDistribution, OutputCiphertextVector>,
+{
+    type Parameters = LweCiphertextDiscardingMultiBootstrapParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweCiphertext<Precision, OutputKeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, OutputKeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweBootstrapKeyProto,
+    );
+    type SamplePrototypes = (
+        usize,
+        <Maker as PrototypesLweCiphertext<Precision, InputKeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            OutputKeyDistribution,
+        >>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (
+        BootstrapKey,
+        Accumulator,
+        OutputCiphertextVector,
+        InputCiphertext,
+    );
+    type PostExecutionContext = (
+        BootstrapKey,
+        Accumulator,
+        OutputCiphertextVector,
+        InputCiphertext,
+    );
+    // The predicted variance of the outputs, and the number of message bits.
+    type Criteria = (Variance, usize);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextDiscardingMultiBootstrapParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
+                    lwe_dimension: LweDimension(630),
+                    glwe_dimension: GlweDimension(1),
+                    poly_size: PolynomialSize(1024),
+                    decomp_level_count: DecompositionLevelCount(3),
+                    decomp_base_log: DecompositionBaseLog(7),
+                    lut_count_log: LutCountLog(1),
+                    message_bits: 3,
+                },
+                LweCiphertextDiscardingMultiBootstrapParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-29.).get_variance()),
+                    lwe_dimension: LweDimension(630),
+                    glwe_dimension: GlweDimension(1),
+                    poly_size: PolynomialSize(2048),
+                    decomp_level_count: DecompositionLevelCount(3),
+                    decomp_base_log: DecompositionBaseLog(7),
+                    lut_count_log: LutCountLog(2),
+                    message_bits: 3,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        // The j-th lut maps a message m to (m + j) mod 2^message_bits. The luts are interleaved,
+        // and each message owns a box of N / 2^message_bits consecutive coefficients.
+        let lut_count = 1 << parameters.lut_count_log.0;
+        let message_count = 1 << parameters.message_bits;
+        let raw_plaintext_vector: Vec<Precision::Raw> = (0..parameters.poly_size.0)
+            .map(|i| {
+                let message = i * message_count / parameters.poly_size.0;
+                fix_encode_multi_bootstrap_message::<Precision::Raw>(
+                    fix_evaluate_multi_bootstrap_lut(
+                        message,
+                        i % lut_count,
+                        parameters.message_bits,
+                    ),
+                    parameters.message_bits,
+                )
+            })
+            .collect();
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_accumulator = maker.trivially_encrypt_plaintext_vector_to_glwe_ciphertext(
+            parameters.glwe_dimension,
+            &proto_plaintext_vector,
+        );
+        let proto_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            InputKeyDistribution,
+        >>::new_lwe_secret_key(maker, parameters.lwe_dimension);
+        let proto_glwe_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker, parameters.glwe_dimension, parameters.poly_size
+        );
+        let proto_bootstrap_key = maker.new_lwe_bootstrap_key(
+            &proto_lwe_secret_key,
+            &proto_glwe_secret_key,
+            parameters.decomp_level_count,
+            parameters.decomp_base_log,
+            parameters.noise,
+        );
+        (
+            proto_accumulator,
+            proto_lwe_secret_key,
+            proto_glwe_secret_key,
+            proto_bootstrap_key,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (_, proto_lwe_secret_key, ..) = repetition_proto;
+        // The message is encoded in the middle of its box, so that the noise does not make the
+        // blind rotation select the box of another message.
+        let message = Precision::Raw::uniform_between(0..1 << parameters.message_bits);
+        let raw_plaintext = (message << 1 | Precision::Raw::ONE)
+            << (Precision::Raw::BITS - parameters.message_bits - 2);
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_input_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_to_lwe_ciphertext(
+            maker,
+            proto_lwe_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+        );
+        let proto_output_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            OutputKeyDistribution,
+        >>::trivially_encrypt_zeros_to_lwe_ciphertext_vector(
+            maker,
+            LweDimension(parameters.glwe_dimension.0 * parameters.poly_size.0),
+            LweCiphertextCount(1 << parameters.lut_count_log.0),
+        );
+        (
+            <Precision::Raw as CastInto<f64>>::cast_into(message) as usize,
+            proto_input_ciphertext,
+            proto_output_ciphertext_vector,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_accumulator, _, _, proto_bootstrap_key) = repetition_proto;
+        let (_, proto_input_ciphertext, proto_output_ciphertext_vector) = sample_proto;
+        let synth_bootstrap_key = maker.synthesize_lwe_bootstrap_key(proto_bootstrap_key);
+        let synth_accumulator = maker.synthesize_glwe_ciphertext(proto_accumulator);
+        let synth_input_ciphertext = maker.synthesize_lwe_ciphertext(proto_input_ciphertext);
+        let synth_output_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_output_ciphertext_vector);
+        (
+            synth_bootstrap_key,
+            synth_accumulator,
+            synth_output_ciphertext_vector,
+            synth_input_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (bootstrap_key, accumulator, mut output_ciphertext_vector, input_ciphertext) = context;
+        unsafe {
+            engine.discard_multi_bootstrap_lwe_ciphertext_unchecked(
+                &mut output_ciphertext_vector,
+                &input_ciphertext,
+                &accumulator,
+                &bootstrap_key,
+                parameters.lut_count_log,
+            )
+        };
+        (
+            bootstrap_key,
+            accumulator,
+            output_ciphertext_vector,
+            input_ciphertext,
+        )
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (bootstrap_key, accumulator, output_ciphertext_vector, input_ciphertext) = context;
+        let (_, _, proto_glwe_secret_key, _) = repetition_proto;
+        let (message, ..) = sample_proto;
+        let proto_output_ciphertext_vector =
+            maker.unsynthesize_lwe_ciphertext_vector(output_ciphertext_vector);
+        let proto_output_lwe_secret_key =
+            maker.transform_glwe_secret_key_to_lwe_secret_key(proto_glwe_secret_key);
+        let proto_output_plaintext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            OutputKeyDistribution,
+        >>::decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+            maker,
+            &proto_output_lwe_secret_key,
+            &proto_output_ciphertext_vector,
+        );
+        maker.destroy_lwe_ciphertext(input_ciphertext);
+        maker.destroy_lwe_bootstrap_key(bootstrap_key);
+        maker.destroy_glwe_ciphertext(accumulator);
+        (
+            (0..1 << parameters.lut_count_log.0)
+                .map(|j| {
+                    fix_encode_multi_bootstrap_message(
+                        fix_evaluate_multi_bootstrap_lut(*message, j, parameters.message_bits),
+                        parameters.message_bits,
+                    )
+                })
+                .collect(),
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let predicted_variance: Variance =
+            fix_estimate_pbs_noise::<Precision::Raw, Variance, OutputKeyDistribution>(
+                parameters.lwe_dimension,
+                parameters.poly_size,
+                parameters.glwe_dimension,
+                parameters.decomp_base_log,
+                parameters.decomp_level_count,
+                parameters.noise,
+            );
+        (predicted_variance, parameters.message_bits)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (predicted_variance, message_bits) = criteria;
+        let shift = Precision::Raw::BITS - message_bits - 1;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let decoded_correctly = outputs.iter().all(|(expected, actual)| {
+            expected
+                .iter()
+                .zip(actual.iter())
+                .all(|(e, a)| *e >> shift == a.wrapping_add(rounding) >> shift)
+        });
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        decoded_correctly && assert_delta_std_dev(&actual, means.as_slice(), *predicted_variance)
+    }
+}
+
+// The value of the `lut_index`-th lookup table of the fixture for `message`.
+fn fix_evaluate_multi_bootstrap_lut(
+    message: usize,
+    lut_index: usize,
+    message_bits: usize,
+) -> usize {
+    (message + lut_index) % (1 << message_bits)
+}
+
+// Encodes a message with one bit of padding.
+fn fix_encode_multi_bootstrap_message<T: UnsignedInteger>(
+    message: usize,
+    message_bits: usize,
+) -> T {
+    T::cast_from(message as f64) << (T::BITS - message_bits - 1)
+}
@@ -0,0 +1,214 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertext, PrototypesLweCompressedCiphertextVector, PrototypesLweSecretKey,
+    PrototypesPlaintext, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweCiphertext, SynthesizesLweCompressedCiphertextVector,
+};
+use crate::generation::{BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{CiphertextModulusLog, LweDimension};
+use concrete_core::prelude::{
+    LweCiphertextEntity, LweCiphertextModulusSwitchingEngine, LweCompressedCiphertextVectorEntity,
+};
+use std::any::TypeId;
+
+/// A fixture for the types implementing the `LweCiphertextModulusSwitchingEngine` trait.
+pub struct LweCiphertextModulusSwitchingFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextModulusSwitchingParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub ciphertext_modulus_log: CiphertextModulusLog,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, Ciphertext, CompressedCiphertextVector>
+    Fixture<Precision, (KeyDistribution,), Engine, (Ciphertext, CompressedCiphertextVector)>
+    for LweCiphertextModulusSwitchingFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextModulusSwitchingEngine<Ciphertext, CompressedCiphertextVector>,
+    Ciphertext: LweCiphertextEntity,
+    CompressedCiphertextVector: LweCompressedCiphertextVectorEntity,
+    Maker: SynthesizesLweCiphertext<Precision, KeyDistribution, Ciphertext>
+        + SynthesizesLweCompressedCiphertextVector<
+            Precision,
+            KeyDistribution,
+            CompressedCiphertextVector,
+        >,
+{
+    type Parameters = LweCiphertextModulusSwitchingParameters;
+    type RepetitionPrototypes =
+        (<Maker as PrototypesLweSecretKey<Precision, KeyDistribution>>::LweSecretKeyProto,);
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertext<Precision, KeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (Ciphertext,);
+    type PostExecutionContext = (Ciphertext, CompressedCiphertextVector);
+    type Criteria = (Variance,);
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![11, 16, 23, Precision::Raw::BITS]
+                .into_iter()
+                .map(
+                    |ciphertext_modulus_log| LweCiphertextModulusSwitchingParameters {
+                        noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                        lwe_dimension: LweDimension(600),
+                        ciphertext_modulus_log: CiphertextModulusLog(ciphertext_modulus_log),
+                    },
+                ),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext = Precision::Raw::uniform();
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let proto_ciphertext = maker.encrypt_plaintext_to_lwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext,
+            parameters.noise,
+        );
+        (proto_plaintext, proto_ciphertext)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_ciphertext) = sample_proto;
+        (maker.synthesize_lwe_ciphertext(proto_ciphertext),)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (ciphertext,) = context;
+        let compressed_ciphertext_vector = unsafe {
+            engine.switch_modulus_lwe_ciphertext_unchecked(
+                &ciphertext,
+                parameters.ciphertext_modulus_log,
+            )
+        };
+        (ciphertext, compressed_ciphertext_vector)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (ciphertext, compressed_ciphertext_vector) = context;
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext, _) = sample_proto;
+        let proto_compressed_ciphertext_vector =
+            maker.unsynthesize_lwe_compressed_ciphertext_vector(compressed_ciphertext_vector);
+        let proto_output_plaintext_vector = maker
+            .decrypt_lwe_compressed_ciphertext_vector_to_plaintext_vector(
+                proto_secret_key,
+                &proto_compressed_ciphertext_vector,
+            );
+        maker.destroy_lwe_ciphertext(ciphertext);
+        let raw_plaintext = maker.transform_plaintext_to_raw(proto_plaintext);
+        (
+            fix_round_to_ciphertext_modulus(raw_plaintext, parameters.ciphertext_modulus_log),
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector)[0],
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let predicted_variance =
+            fix_estimate_modulus_switching_noise::<Precision::Raw, _, KeyDistribution>(
+                parameters.lwe_dimension,
+                parameters.noise,
+                parameters.ciphertext_modulus_log,
+            );
+        (predicted_variance,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
+
+/// Rounds a raw plaintext to the closest multiple of $2^{w - q}$, where $w$ is the bit width of the
+/// raw type and $q$ is `ciphertext_modulus_log`. This is the value around which a ciphertext
+/// switched to the modulus $2^q$ decrypts.
+pub(crate) fn fix_round_to_ciphertext_modulus<T>(
+    value: T,
+    ciphertext_modulus_log: CiphertextModulusLog,
+) -> T
+where
+    T: UnsignedInteger,
+{
+    if ciphertext_modulus_log.0 == T::BITS {
+        return value;
+    }
+    let shift = T::BITS - ciphertext_modulus_log.0;
+    (value.wrapping_add(T::ONE << (shift - 1)) >> shift) << shift
+}
+
+// FIXME:
+// The current NPE does not use the key distribution markers of concrete-core. This function makes
+// the mapping. This function should be removed as soon as the npe uses the types of concrete-core.
+//
+// On top of the estimate of the npe, the variance of the rounding of a uniform plaintext to the
+// closest multiple of $2^{w - q}$ is added, since the outputs are compared against the rounded
+// plaintexts.
+pub(crate) fn fix_estimate_modulus_switching_noise<T, D, K>(
+    lwe_mask_size: LweDimension,
+    dispersion: D,
+    ciphertext_modulus_log: CiphertextModulusLog,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D: DispersionParameter,
+    K: KeyDistributionMarker,
+{
+    let k_type_id = TypeId::of::<K>();
+    if k_type_id == TypeId::of::<BinaryKeyDistribution>() {
+        let switching_variance = concrete_npe::estimate_modulus_switching_noise_with_binary_key(
+            lwe_mask_size,
+            ciphertext_modulus_log.0,
+            dispersion,
+            T::BITS as u32,
+        );
+        let rounding_variance = 2_f64.powi(-2 * ciphertext_modulus_log.0 as i32) / 12.;
+        Variance(switching_variance.get_variance() + rounding_variance)
+    } else {
+        panic!("Unknown key distribution encountered.")
+    }
+}
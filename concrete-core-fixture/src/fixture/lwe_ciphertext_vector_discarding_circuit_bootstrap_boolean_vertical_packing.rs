@@ -0,0 +1,399 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweSecretKey, PrototypesLweBootstrapKey, PrototypesLweCiphertextVector,
+    PrototypesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys, PrototypesLweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweBootstrapKey, SynthesizesLweCiphertextVector,
+    SynthesizesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    SynthesizesPlaintextVector,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount, LweDimension,
+    PolynomialCount, PolynomialSize,
+};
+use concrete_core::prelude::{
+    LweBootstrapKeyEntity,
+    LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingEngine,
+    LweCiphertextVectorEntity, LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    PlaintextVectorEntity,
+};
+
+/// A fixture for the types implementing the
+/// `LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingEngine` trait.
+pub struct LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub poly_size: PolynomialSize,
+    pub bsk_decomp_level_count: DecompositionLevelCount,
+    pub bsk_decomp_base_log: DecompositionBaseLog,
+    pub pfpksk_decomp_level_count: DecompositionLevelCount,
+    pub pfpksk_decomp_base_log: DecompositionBaseLog,
+    pub cbs_decomp_level_count: DecompositionLevelCount,
+    pub cbs_decomp_base_log: DecompositionBaseLog,
+    pub input_count: LweCiphertextCount,
+    pub output_count: LweCiphertextCount,
+    pub lut_polynomial_count: PolynomialCount,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+        BootstrapKey,
+        LookupTables,
+        CircuitBootstrapFunctionalPackingKeyswitchKeys,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (
+            InputCiphertextVector,
+            OutputCiphertextVector,
+            BootstrapKey,
+            LookupTables,
+            CircuitBootstrapFunctionalPackingKeyswitchKeys,
+        ),
+    > for LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingEngine<
+        InputCiphertextVector,
+        OutputCiphertextVector,
+        BootstrapKey,
+        LookupTables,
+        CircuitBootstrapFunctionalPackingKeyswitchKeys,
+    >,
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    OutputCiphertextVector: LweCiphertextVectorEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+    LookupTables: PlaintextVectorEntity,
+    CircuitBootstrapFunctionalPackingKeyswitchKeys:
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    Maker: SynthesizesLweCiphertextVector<Precision, InputKeyDistribution, InputCiphertextVector>
+        + SynthesizesLweCiphertextVector<Precision, OutputKeyDistribution, OutputCiphertextVector>
+        + SynthesizesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            BootstrapKey,
+        > + SynthesizesPlaintextVector<Precision, LookupTables>
+        + SynthesizesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+            Precision,
+            OutputKeyDistribution,
+            OutputKeyDistribution,
+            CircuitBootstrapFunctionalPackingKeyswitchKeys,
+        >,
+{
+    type Parameters = LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesLweSecretKey<Precision, OutputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesLweBootstrapKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweBootstrapKeyProto,
+        <Maker as PrototypesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+            Precision,
+            OutputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto,
+    );
+    type SamplePrototypes = (
+        Vec<Precision::Raw>,
+        Vec<Precision::Raw>,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::LweCiphertextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            OutputKeyDistribution,
+        >>::LweCiphertextVectorProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+    );
+    type PreExecutionContext = (
+        InputCiphertextVector,
+        OutputCiphertextVector,
+        BootstrapKey,
+        LookupTables,
+        CircuitBootstrapFunctionalPackingKeyswitchKeys,
+    );
+    type PostExecutionContext = (
+        InputCiphertextVector,
+        OutputCiphertextVector,
+        BootstrapKey,
+        LookupTables,
+        CircuitBootstrapFunctionalPackingKeyswitchKeys,
+    );
+    type Criteria = ();
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                // The lookup tables fit in a single polynomial, the value only drives the final
+                // blind rotation.
+                LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-40.).get_variance()),
+                    lwe_dimension: LweDimension(10),
+                    glwe_dimension: GlweDimension(1),
+                    poly_size: PolynomialSize(512),
+                    bsk_decomp_level_count: DecompositionLevelCount(3),
+                    bsk_decomp_base_log: DecompositionBaseLog(10),
+                    pfpksk_decomp_level_count: DecompositionLevelCount(3),
+                    pfpksk_decomp_base_log: DecompositionBaseLog(10),
+                    cbs_decomp_level_count: DecompositionLevelCount(3),
+                    cbs_decomp_base_log: DecompositionBaseLog(5),
+                    input_count: LweCiphertextCount(3),
+                    output_count: LweCiphertextCount(1),
+                    lut_polynomial_count: PolynomialCount(1),
+                    message_bits: 3,
+                },
+                // The msb of the value select one of the polynomials of each lookup table with
+                // the cmux tree.
+                LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-40.).get_variance()),
+                    lwe_dimension: LweDimension(10),
+                    glwe_dimension: GlweDimension(1),
+                    poly_size: PolynomialSize(512),
+                    bsk_decomp_level_count: DecompositionLevelCount(3),
+                    bsk_decomp_base_log: DecompositionBaseLog(10),
+                    pfpksk_decomp_level_count: DecompositionLevelCount(3),
+                    pfpksk_decomp_base_log: DecompositionBaseLog(10),
+                    cbs_decomp_level_count: DecompositionLevelCount(3),
+                    cbs_decomp_base_log: DecompositionBaseLog(5),
+                    input_count: LweCiphertextCount(4),
+                    output_count: LweCiphertextCount(2),
+                    lut_polynomial_count: PolynomialCount(4),
+                    message_bits: 3,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_lwe_secret_key = <Maker as PrototypesLweSecretKey<
+            Precision,
+            InputKeyDistribution,
+        >>::new_lwe_secret_key(maker, parameters.lwe_dimension);
+        let proto_glwe_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker, parameters.glwe_dimension, parameters.poly_size
+        );
+        let proto_bootstrap_key = maker.new_lwe_bootstrap_key(
+            &proto_lwe_secret_key,
+            &proto_glwe_secret_key,
+            parameters.bsk_decomp_level_count,
+            parameters.bsk_decomp_base_log,
+            parameters.noise,
+        );
+        // The output ciphertexts, as well as the ciphertexts taken by the keyswitch keys, are
+        // encrypted under the GLWE key seen as an LWE key.
+        let proto_bootstrap_output_secret_key =
+            maker.transform_glwe_secret_key_to_lwe_secret_key(&proto_glwe_secret_key);
+        let proto_cbs_pfpksk = maker.new_lwe_private_functional_packing_keyswitch_key(
+            &proto_bootstrap_output_secret_key,
+            &proto_glwe_secret_key,
+            parameters.pfpksk_decomp_base_log,
+            parameters.pfpksk_decomp_level_count,
+            parameters.noise,
+        );
+        (
+            proto_lwe_secret_key,
+            proto_bootstrap_output_secret_key,
+            proto_bootstrap_key,
+            proto_cbs_pfpksk,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_lwe_secret_key, ..) = repetition_proto;
+        // The bits are stored in the most significant bit, with no bit of padding.
+        let raw_bits = Precision::Raw::uniform_n_msb_vec(1, parameters.input_count.0);
+        let proto_bits = maker.transform_raw_vec_to_plaintext_vector(&raw_bits);
+        let proto_input_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+            maker,
+            proto_lwe_secret_key,
+            &proto_bits,
+            parameters.noise,
+        );
+        let proto_output_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            OutputKeyDistribution,
+        >>::trivially_encrypt_zeros_to_lwe_ciphertext_vector(
+            maker,
+            LweDimension(parameters.glwe_dimension.0 * parameters.poly_size.0),
+            parameters.output_count,
+        );
+        let raw_luts = Precision::Raw::uniform_n_msb_vec(
+            parameters.message_bits,
+            parameters.output_count.0 * parameters.lut_polynomial_count.0 * parameters.poly_size.0,
+        );
+        let proto_luts = maker.transform_raw_vec_to_plaintext_vector(&raw_luts);
+        (
+            raw_bits,
+            raw_luts,
+            proto_input_ciphertext_vector,
+            proto_output_ciphertext_vector,
+            proto_luts,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, _, proto_bootstrap_key, proto_cbs_pfpksk) = repetition_proto;
+        let (_, _, proto_input_ciphertext_vector, proto_output_ciphertext_vector, proto_luts) =
+            sample_proto;
+        let synth_input_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_input_ciphertext_vector);
+        let synth_output_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_output_ciphertext_vector);
+        let synth_bootstrap_key = maker.synthesize_lwe_bootstrap_key(proto_bootstrap_key);
+        let synth_luts = maker.synthesize_plaintext_vector(proto_luts);
+        let synth_cbs_pfpksk = maker
+            .synthesize_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+                proto_cbs_pfpksk,
+            );
+        (
+            synth_input_ciphertext_vector,
+            synth_output_ciphertext_vector,
+            synth_bootstrap_key,
+            synth_luts,
+            synth_cbs_pfpksk,
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (
+            input_ciphertext_vector,
+            mut output_ciphertext_vector,
+            bootstrap_key,
+            luts,
+            cbs_pfpksk,
+        ) = context;
+        unsafe {
+            engine
+                .discard_circuit_bootstrap_boolean_vertical_packing_lwe_ciphertext_vector_unchecked(
+                    &mut output_ciphertext_vector,
+                    &input_ciphertext_vector,
+                    &bootstrap_key,
+                    &luts,
+                    parameters.cbs_decomp_level_count,
+                    parameters.cbs_decomp_base_log,
+                    &cbs_pfpksk,
+                )
+        };
+        (
+            input_ciphertext_vector,
+            output_ciphertext_vector,
+            bootstrap_key,
+            luts,
+            cbs_pfpksk,
+        )
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (input_ciphertext_vector, output_ciphertext_vector, bootstrap_key, luts, cbs_pfpksk) =
+            context;
+        let (_, proto_bootstrap_output_secret_key, ..) = repetition_proto;
+        let (raw_bits, raw_luts, ..) = sample_proto;
+        let proto_output_ciphertext_vector =
+            maker.unsynthesize_lwe_ciphertext_vector(output_ciphertext_vector);
+        maker.destroy_lwe_ciphertext_vector(input_ciphertext_vector);
+        maker.destroy_lwe_bootstrap_key(bootstrap_key);
+        maker.destroy_plaintext_vector(luts);
+        maker.destroy_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(cbs_pfpksk);
+        let proto_output_plaintext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            OutputKeyDistribution,
+        >>::decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+            maker,
+            proto_bootstrap_output_secret_key,
+            &proto_output_ciphertext_vector,
+        );
+
+        // The input bits, from the msb to the lsb, form the value on which the lookup tables are
+        // evaluated. Its msb select a polynomial of the lookup table, and its lsb a coefficient
+        // of this polynomial.
+        let value = raw_bits.iter().fold(0_usize, |value, raw_bit| {
+            (value << 1) | (*raw_bit != Precision::Raw::ZERO) as usize
+        });
+        let log_lut_polynomial_count = parameters.lut_polynomial_count.0.trailing_zeros() as usize;
+        let rotation_bits = parameters.input_count.0 - log_lut_polynomial_count;
+        let lut_index = (value >> rotation_bits) * parameters.poly_size.0
+            + (value & ((1 << rotation_bits) - 1));
+        let lut_size = parameters.lut_polynomial_count.0 * parameters.poly_size.0;
+
+        let shift = Precision::Raw::BITS - parameters.message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let expected_messages = raw_luts
+            .chunks_exact(lut_size)
+            .map(|lut| lut[lut_index] >> shift)
+            .collect();
+        let decoded_messages = maker
+            .transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector)
+            .into_iter()
+            .map(|raw| raw.wrapping_add(rounding) >> shift)
+            .collect();
+        (expected_messages, decoded_messages)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
@@ -0,0 +1,324 @@
+use crate::fixture::{fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms, Fixture};
+use crate::generation::prototyping::{
+    PrototypesGlweAutomorphismKey, PrototypesGlweCiphertext, PrototypesGlweSecretKey,
+    PrototypesLweCiphertextVector, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweAutomorphismKey, SynthesizesGlweCiphertext, SynthesizesLweCiphertextVector,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    LweCiphertextCount, LweDimension, MonomialIndex, PolynomialSize,
+};
+use concrete_core::prelude::{
+    GlweAutomorphismKeyEntity, GlweCiphertextEntity, LweCiphertextVectorEntity,
+    LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingEngine,
+};
+
+/// A fixture for the types implementing the
+/// `LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingEngine` trait.
+pub struct LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub lwe_ciphertext_count: LweCiphertextCount,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, AutomorphismKey, CiphertextVector, OutputCiphertext>
+    Fixture<
+        Precision,
+        (KeyDistribution,),
+        Engine,
+        (AutomorphismKey, CiphertextVector, OutputCiphertext),
+    > for LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingEngine<
+        AutomorphismKey,
+        CiphertextVector,
+        OutputCiphertext,
+    >,
+    AutomorphismKey: GlweAutomorphismKeyEntity,
+    CiphertextVector: LweCiphertextVectorEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+    Maker: SynthesizesGlweAutomorphismKey<Precision, KeyDistribution, AutomorphismKey>
+        + SynthesizesLweCiphertextVector<Precision, KeyDistribution, CiphertextVector>
+        + SynthesizesGlweCiphertext<Precision, KeyDistribution, OutputCiphertext>,
+{
+    type Parameters = LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesGlweAutomorphismKey<Precision, KeyDistribution>>::GlweAutomorphismKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<Precision, KeyDistribution>>::LweCiphertextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+    );
+    type PreExecutionContext = (AutomorphismKey, CiphertextVector, OutputCiphertext);
+    type PostExecutionContext = (AutomorphismKey, CiphertextVector, OutputCiphertext);
+    // The predicted variance of the packed coefficients, and the number of message bits.
+    type Criteria = (Variance, usize);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(256),
+                    decomposition_level_count: DecompositionLevelCount(5),
+                    decomposition_base_log: DecompositionBaseLog(4),
+                    lwe_ciphertext_count: LweCiphertextCount(256),
+                    message_bits: 2,
+                },
+                LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(256),
+                    decomposition_level_count: DecompositionLevelCount(5),
+                    decomposition_base_log: DecompositionBaseLog(4),
+                    lwe_ciphertext_count: LweCiphertextCount(10),
+                    message_bits: 2,
+                },
+                LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(512),
+                    decomposition_level_count: DecompositionLevelCount(5),
+                    decomposition_base_log: DecompositionBaseLog(4),
+                    lwe_ciphertext_count: LweCiphertextCount(3),
+                    message_bits: 2,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        // The packing uses the automorphisms of exponents 2^i + 1, for 1 <= i <= log2(N).
+        let exponents = (1..=parameters.polynomial_size.log2().0)
+            .map(|i| AutomorphismExponent((1 << i) + 1))
+            .collect::<Vec<_>>();
+        let proto_automorphism_key = maker.new_glwe_automorphism_key(
+            &proto_secret_key,
+            &exponents,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+            parameters.noise,
+        );
+        (proto_secret_key, proto_automorphism_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key, _) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_n_msb_vec(
+            parameters.message_bits,
+            parameters.polynomial_size.0,
+        );
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        let proto_glwe_ciphertext = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        // The input ciphertexts are encrypted under the LWE secret key obtained by flattening the
+        // GLWE secret key of the automorphism key.
+        let indices = (0..parameters.lwe_ciphertext_count.0)
+            .map(MonomialIndex)
+            .collect::<Vec<_>>();
+        let proto_input_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            KeyDistribution,
+        >>::extract_lwe_ciphertext_vector_from_glwe_ciphertext(
+            maker,
+            &proto_glwe_ciphertext,
+            &indices,
+        );
+        let proto_output_ciphertext = maker.trivially_encrypt_zeros_to_glwe_ciphertext(
+            parameters.glwe_dimension,
+            parameters.polynomial_size,
+        );
+        (
+            proto_plaintext_vector,
+            proto_input_ciphertext_vector,
+            proto_output_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_automorphism_key) = repetition_proto;
+        let (_, proto_input_ciphertext_vector, proto_output_ciphertext) = sample_proto;
+        let synth_automorphism_key = maker.synthesize_glwe_automorphism_key(proto_automorphism_key);
+        let synth_input_ciphertext_vector =
+            maker.synthesize_lwe_ciphertext_vector(proto_input_ciphertext_vector);
+        let synth_output_ciphertext = maker.synthesize_glwe_ciphertext(proto_output_ciphertext);
+        (
+            synth_automorphism_key,
+            synth_input_ciphertext_vector,
+            synth_output_ciphertext,
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (automorphism_key, input_ciphertext_vector, mut output_ciphertext) = context;
+        unsafe {
+            engine.discard_automorphism_pack_lwe_ciphertext_vector_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext_vector,
+                &automorphism_key,
+            )
+        };
+        (automorphism_key, input_ciphertext_vector, output_ciphertext)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (automorphism_key, input_ciphertext_vector, output_ciphertext) = context;
+        let (proto_secret_key, _) = repetition_proto;
+        let (proto_plaintext_vector, ..) = sample_proto;
+        let proto_output_ciphertext = maker.unsynthesize_glwe_ciphertext(output_ciphertext);
+        maker.destroy_glwe_automorphism_key(automorphism_key);
+        maker.destroy_lwe_ciphertext_vector(input_ciphertext_vector);
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_secret_key,
+            &proto_output_ciphertext,
+        );
+
+        // The j-th input message lands in the coefficient of degree j * N / 2^l, where 2^l is the
+        // smallest power of two greater or equal to the number of inputs. The other coefficients
+        // encrypt zero.
+        let raw_plaintext_vector =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector);
+        let stride =
+            parameters.polynomial_size.0 / parameters.lwe_ciphertext_count.0.next_power_of_two();
+        let mut expected_plaintexts = vec![Precision::Raw::ZERO; parameters.polynomial_size.0];
+        for (j, raw_plaintext) in raw_plaintext_vector
+            .iter()
+            .take(parameters.lwe_ciphertext_count.0)
+            .enumerate()
+        {
+            expected_plaintexts[j * stride] = *raw_plaintext;
+        }
+        (
+            expected_plaintexts,
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let predicted_variance =
+            fix_estimate_automorphism_packing_noise::<Precision::Raw, _, KeyDistribution>(
+                parameters.glwe_dimension,
+                parameters.polynomial_size,
+                parameters.lwe_ciphertext_count,
+                parameters.noise,
+                parameters.decomposition_base_log,
+                parameters.decomposition_level_count,
+            );
+        (predicted_variance, parameters.message_bits)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (predicted_variance, message_bits) = criteria;
+        let shift = Precision::Raw::BITS - message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let decoded_correctly = outputs.iter().all(|(expected, actual)| {
+            expected
+                .iter()
+                .zip(actual.iter())
+                .all(|(e, a)| *e >> shift == a.wrapping_add(rounding) >> shift)
+        });
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        decoded_correctly
+            && assert_noise_distribution(&actual, means.as_slice(), *predicted_variance)
+    }
+}
+
+// An estimate of the variance of the coefficients of a GLWE ciphertext obtained by packing LWE
+// ciphertexts encrypted with the same noise as the automorphism key. It sums:
+// + the input noise, which is divided by N before the packing and multiplied back by N by it;
+// + the rounding of the input coefficients to the closest multiple of N, amplified by N;
+// + the keyswitch noise of the automorphisms. Each of the l merge levels multiplies the variance
+// of its inputs by 4, and each of the log2(N) - l trace steps multiplies it by 2, before adding
+// the noise of one keyswitch.
+fn fix_estimate_automorphism_packing_noise<T, D, K>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    lwe_ciphertext_count: LweCiphertextCount,
+    dispersion: D,
+    base_log: DecompositionBaseLog,
+    level: DecompositionLevelCount,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D: DispersionParameter,
+    K: KeyDistributionMarker,
+{
+    let lwe_mask_size = glwe_dimension.0 * polynomial_size.0;
+    let n = polynomial_size.0 as f64;
+    let rounding_variance =
+        n.powi(2) * (1. + lwe_mask_size as f64 / 2.) / 12. / 2_f64.powi(2 * T::BITS as i32);
+    let keyswitch_variance =
+        fix_estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<T, _, _, K>(
+            LweDimension(lwe_mask_size),
+            Variance(0.),
+            dispersion,
+            base_log,
+            level,
+        );
+    let merge_levels = lwe_ciphertext_count.0.next_power_of_two().trailing_zeros() as i32;
+    let trace_steps = polynomial_size.log2().0 as i32 - merge_levels;
+    let merged_keyswitch_count = (4_f64.powi(merge_levels) - 1.) / 3.;
+    let keyswitch_count =
+        merged_keyswitch_count * 2_f64.powi(trace_steps) + 2_f64.powi(trace_steps) - 1.;
+    Variance(
+        dispersion.get_variance()
+            + rounding_variance
+            + keyswitch_variance.get_variance() * keyswitch_count,
+    )
+}
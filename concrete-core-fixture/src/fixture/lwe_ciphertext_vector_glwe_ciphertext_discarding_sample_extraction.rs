@@ -0,0 +1,200 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweSecretKey, PrototypesLweCiphertextVector,
+    PrototypesLweSecretKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{SynthesizesGlweCiphertext, SynthesizesLweCiphertextVector};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    GlweDimension, LweCiphertextCount, LweDimension, MonomialIndex, PolynomialSize,
+};
+use concrete_core::prelude::{
+    GlweCiphertextEntity, LweCiphertextVectorEntity,
+    LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionEngine,
+};
+
+/// A fixture for the types implementing the
+/// `LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionEngine` trait.
+pub struct LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionParameters {
+    pub noise: Variance,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub indices: Vec<MonomialIndex>,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, GlweCiphertext, LweCiphertextVector>
+    Fixture<Precision, (KeyDistribution,), Engine, (GlweCiphertext, LweCiphertextVector)>
+    for LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionEngine<
+        GlweCiphertext,
+        LweCiphertextVector,
+    >,
+    GlweCiphertext: GlweCiphertextEntity,
+    LweCiphertextVector: LweCiphertextVectorEntity,
+    Maker: SynthesizesGlweCiphertext<Precision, KeyDistribution, GlweCiphertext>
+        + SynthesizesLweCiphertextVector<Precision, KeyDistribution, LweCiphertextVector>,
+{
+    type Parameters = LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesGlweSecretKey<Precision, KeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesLweSecretKey<Precision, KeyDistribution>>::LweSecretKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+        <Maker as PrototypesLweCiphertextVector<Precision, KeyDistribution>>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (GlweCiphertext, LweCiphertextVector);
+    type PostExecutionContext = (GlweCiphertext, LweCiphertextVector);
+    type Criteria = ();
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(2),
+                    polynomial_size: PolynomialSize(256),
+                    indices: (0..256).map(MonomialIndex).collect(),
+                    message_bits: 4,
+                },
+                LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    glwe_dimension: GlweDimension(1),
+                    polynomial_size: PolynomialSize(512),
+                    indices: (0..512).step_by(3).map(MonomialIndex).collect(),
+                    message_bits: 4,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_glwe_secret_key =
+            maker.new_glwe_secret_key(parameters.glwe_dimension, parameters.polynomial_size);
+        let proto_lwe_secret_key =
+            maker.transform_glwe_secret_key_to_lwe_secret_key(&proto_glwe_secret_key);
+        (proto_glwe_secret_key, proto_lwe_secret_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_glwe_secret_key, _) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_n_msb_vec(
+            parameters.message_bits,
+            parameters.polynomial_size.0,
+        );
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        let proto_glwe_ciphertext = maker.encrypt_plaintext_vector_to_glwe_ciphertext(
+            proto_glwe_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        let proto_lwe_ciphertext_vector = maker.trivially_encrypt_zeros_to_lwe_ciphertext_vector(
+            LweDimension(parameters.glwe_dimension.0 * parameters.polynomial_size.0),
+            LweCiphertextCount(parameters.indices.len()),
+        );
+        (
+            proto_plaintext_vector,
+            proto_glwe_ciphertext,
+            proto_lwe_ciphertext_vector,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_glwe_ciphertext, proto_lwe_ciphertext_vector) = sample_proto;
+        (
+            maker.synthesize_glwe_ciphertext(proto_glwe_ciphertext),
+            maker.synthesize_lwe_ciphertext_vector(proto_lwe_ciphertext_vector),
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (glwe_ciphertext, mut lwe_ciphertext_vector) = context;
+        unsafe {
+            engine.discard_sample_extract_lwe_ciphertext_vector_unchecked(
+                &mut lwe_ciphertext_vector,
+                &glwe_ciphertext,
+                &parameters.indices,
+            )
+        };
+        (glwe_ciphertext, lwe_ciphertext_vector)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (_, proto_lwe_secret_key) = repetition_proto;
+        let (proto_plaintext_vector, ..) = sample_proto;
+        let (glwe_ciphertext, lwe_ciphertext_vector) = context;
+        let proto_output_ciphertext_vector =
+            maker.unsynthesize_lwe_ciphertext_vector(lwe_ciphertext_vector);
+        maker.destroy_glwe_ciphertext(glwe_ciphertext);
+        let proto_output_plaintext_vector = maker
+            .decrypt_lwe_ciphertext_vector_to_plaintext_vector(
+                proto_lwe_secret_key,
+                &proto_output_ciphertext_vector,
+            );
+        let shift = Precision::Raw::BITS - parameters.message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let raw_plaintext_vector =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector);
+        let expected_messages = parameters
+            .indices
+            .iter()
+            .map(|nth| raw_plaintext_vector[nth.0] >> shift)
+            .collect();
+        let decoded_messages = maker
+            .transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector)
+            .into_iter()
+            .map(|raw| raw.wrapping_add(rounding) >> shift)
+            .collect();
+        (expected_messages, decoded_messages)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
@@ -0,0 +1,176 @@
+use crate::fixture::{
+    fix_estimate_modulus_switching_noise, fix_round_to_ciphertext_modulus, Fixture,
+};
+use crate::generation::prototyping::{
+    PrototypesLweCiphertextVector, PrototypesLweCompressedCiphertextVector, PrototypesLweSecretKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweCiphertextVector, SynthesizesLweCompressedCiphertextVector,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::Numeric;
+use concrete_commons::parameters::{CiphertextModulusLog, LweCiphertextCount, LweDimension};
+use concrete_core::prelude::{
+    LweCiphertextVectorEntity, LweCiphertextVectorModulusSwitchingEngine,
+    LweCompressedCiphertextVectorEntity,
+};
+
+/// A fixture for the types implementing the `LweCiphertextVectorModulusSwitchingEngine` trait.
+pub struct LweCiphertextVectorModulusSwitchingFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextVectorModulusSwitchingParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub lwe_ciphertext_count: LweCiphertextCount,
+    pub ciphertext_modulus_log: CiphertextModulusLog,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, CiphertextVector, CompressedCiphertextVector>
+    Fixture<Precision, (KeyDistribution,), Engine, (CiphertextVector, CompressedCiphertextVector)>
+    for LweCiphertextVectorModulusSwitchingFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextVectorModulusSwitchingEngine<CiphertextVector, CompressedCiphertextVector>,
+    CiphertextVector: LweCiphertextVectorEntity,
+    CompressedCiphertextVector: LweCompressedCiphertextVectorEntity,
+    Maker: SynthesizesLweCiphertextVector<Precision, KeyDistribution, CiphertextVector>
+        + SynthesizesLweCompressedCiphertextVector<
+            Precision,
+            KeyDistribution,
+            CompressedCiphertextVector,
+        >,
+{
+    type Parameters = LweCiphertextVectorModulusSwitchingParameters;
+    type RepetitionPrototypes =
+        (<Maker as PrototypesLweSecretKey<Precision, KeyDistribution>>::LweSecretKeyProto,);
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<Precision, KeyDistribution>>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (CiphertextVector,);
+    type PostExecutionContext = (CiphertextVector, CompressedCiphertextVector);
+    type Criteria = (Variance,);
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![11, 16, 23, Precision::Raw::BITS]
+                .into_iter()
+                .map(
+                    |ciphertext_modulus_log| LweCiphertextVectorModulusSwitchingParameters {
+                        noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                        lwe_dimension: LweDimension(600),
+                        lwe_ciphertext_count: LweCiphertextCount(10),
+                        ciphertext_modulus_log: CiphertextModulusLog(ciphertext_modulus_log),
+                    },
+                ),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(parameters.lwe_ciphertext_count.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        let proto_ciphertext_vector = maker.encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+            proto_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        (proto_plaintext_vector, proto_ciphertext_vector)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_ciphertext_vector) = sample_proto;
+        (maker.synthesize_lwe_ciphertext_vector(proto_ciphertext_vector),)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (ciphertext_vector,) = context;
+        let compressed_ciphertext_vector = unsafe {
+            engine.switch_modulus_lwe_ciphertext_vector_unchecked(
+                &ciphertext_vector,
+                parameters.ciphertext_modulus_log,
+            )
+        };
+        (ciphertext_vector, compressed_ciphertext_vector)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (ciphertext_vector, compressed_ciphertext_vector) = context;
+        let (proto_secret_key,) = repetition_proto;
+        let (proto_plaintext_vector, _) = sample_proto;
+        let proto_compressed_ciphertext_vector =
+            maker.unsynthesize_lwe_compressed_ciphertext_vector(compressed_ciphertext_vector);
+        let proto_output_plaintext_vector = maker
+            .decrypt_lwe_compressed_ciphertext_vector_to_plaintext_vector(
+                proto_secret_key,
+                &proto_compressed_ciphertext_vector,
+            );
+        maker.destroy_lwe_ciphertext_vector(ciphertext_vector);
+        let raw_plaintext_vector =
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector);
+        (
+            raw_plaintext_vector
+                .into_iter()
+                .map(|raw| fix_round_to_ciphertext_modulus(raw, parameters.ciphertext_modulus_log))
+                .collect(),
+            maker.transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        let predicted_variance =
+            fix_estimate_modulus_switching_noise::<Precision::Raw, _, KeyDistribution>(
+                parameters.lwe_dimension,
+                parameters.noise,
+                parameters.ciphertext_modulus_log,
+            );
+        (predicted_variance,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        let means: Vec<Precision::Raw> = means.into_iter().flatten().collect();
+        let actual: Vec<Precision::Raw> = actual.into_iter().flatten().collect();
+        assert_noise_distribution(&actual, means.as_slice(), criteria.0)
+    }
+}
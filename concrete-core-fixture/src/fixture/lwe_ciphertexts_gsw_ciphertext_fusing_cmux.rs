@@ -0,0 +1,213 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGswCiphertext, PrototypesLweCiphertext, PrototypesLweSecretKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{SynthesizesGswCiphertext, SynthesizesLweCiphertext};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+use concrete_core::prelude::{
+    GswCiphertextEntity, LweCiphertextEntity, LweCiphertextsGswCiphertextFusingCmuxEngine,
+};
+
+/// A fixture for the types implementing the `LweCiphertextsGswCiphertextFusingCmuxEngine` trait.
+pub struct LweCiphertextsGswCiphertextFusingCmuxFixture;
+
+#[derive(Debug)]
+pub struct LweCiphertextsGswCiphertextFusingCmuxParameters {
+    pub gsw_noise: Variance,
+    pub lwe_noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, InputCiphertext, OutputCiphertext, GswCiphertext>
+    Fixture<
+        Precision,
+        (KeyDistribution,),
+        Engine,
+        (InputCiphertext, OutputCiphertext, GswCiphertext),
+    > for LweCiphertextsGswCiphertextFusingCmuxFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: LweCiphertextsGswCiphertextFusingCmuxEngine<
+        InputCiphertext,
+        OutputCiphertext,
+        GswCiphertext,
+    >,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertext: LweCiphertextEntity,
+    GswCiphertext: GswCiphertextEntity,
+    Maker: SynthesizesLweCiphertext<Precision, KeyDistribution, InputCiphertext>
+        + SynthesizesLweCiphertext<Precision, KeyDistribution, OutputCiphertext>
+        + SynthesizesGswCiphertext<Precision, KeyDistribution, GswCiphertext>,
+{
+    type Parameters = LweCiphertextsGswCiphertextFusingCmuxParameters;
+    type RepetitionPrototypes =
+        (<Maker as PrototypesLweSecretKey<Precision, KeyDistribution>>::LweSecretKeyProto,);
+    type SamplePrototypes = (
+        Precision::Raw,
+        Precision::Raw,
+        Precision::Raw,
+        <Maker as PrototypesLweCiphertext<Precision, KeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesLweCiphertext<Precision, KeyDistribution>>::LweCiphertextProto,
+        <Maker as PrototypesGswCiphertext<Precision, KeyDistribution>>::GswCiphertextProto,
+    );
+    type PreExecutionContext = (OutputCiphertext, InputCiphertext, GswCiphertext);
+    type PostExecutionContext = (OutputCiphertext, InputCiphertext, GswCiphertext);
+    type Criteria = ();
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweCiphertextsGswCiphertextFusingCmuxParameters {
+                    gsw_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    lwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    lwe_dimension: LweDimension(256),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    message_bits: 4,
+                },
+                LweCiphertextsGswCiphertextFusingCmuxParameters {
+                    gsw_noise: Variance(LogStandardDev(-25.).get_variance()),
+                    lwe_noise: Variance(LogStandardDev(-20.).get_variance()),
+                    lwe_dimension: LweDimension(100),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    message_bits: 4,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_output_message = Precision::Raw::uniform_n_msb(parameters.message_bits);
+        let proto_output_message = maker.transform_raw_to_plaintext(&raw_output_message);
+        let proto_output_ciphertext = maker.encrypt_plaintext_to_lwe_ciphertext(
+            proto_secret_key,
+            &proto_output_message,
+            parameters.lwe_noise,
+        );
+        let raw_input_message = Precision::Raw::uniform_n_msb(parameters.message_bits);
+        let proto_input_message = maker.transform_raw_to_plaintext(&raw_input_message);
+        let proto_input_ciphertext = maker.encrypt_plaintext_to_lwe_ciphertext(
+            proto_secret_key,
+            &proto_input_message,
+            parameters.lwe_noise,
+        );
+        let raw_bit = Precision::Raw::uniform_between(0..2);
+        let proto_bit = maker.transform_raw_to_plaintext(&raw_bit);
+        let proto_gsw_ciphertext = maker.encrypt_plaintext_to_gsw_ciphertext(
+            proto_secret_key,
+            &proto_bit,
+            parameters.gsw_noise,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+        );
+        (
+            raw_output_message,
+            raw_input_message,
+            raw_bit,
+            proto_output_ciphertext,
+            proto_input_ciphertext,
+            proto_gsw_ciphertext,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, _, _, proto_output_ciphertext, proto_input_ciphertext, proto_gsw_ciphertext) =
+            sample_proto;
+        (
+            maker.synthesize_lwe_ciphertext(proto_output_ciphertext),
+            maker.synthesize_lwe_ciphertext(proto_input_ciphertext),
+            maker.synthesize_gsw_ciphertext(proto_gsw_ciphertext),
+        )
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (mut output_ciphertext, input_ciphertext, gsw_ciphertext) = context;
+        unsafe {
+            engine.fuse_cmux_lwe_ciphertexts_gsw_ciphertext_unchecked(
+                &mut output_ciphertext,
+                &input_ciphertext,
+                &gsw_ciphertext,
+            )
+        };
+        (output_ciphertext, input_ciphertext, gsw_ciphertext)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (proto_secret_key,) = repetition_proto;
+        let (raw_output_message, raw_input_message, raw_bit, ..) = sample_proto;
+        let (output_ciphertext, input_ciphertext, gsw_ciphertext) = context;
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(output_ciphertext);
+        maker.destroy_lwe_ciphertext(input_ciphertext);
+        maker.destroy_gsw_ciphertext(gsw_ciphertext);
+        let proto_output_plaintext =
+            maker.decrypt_lwe_ciphertext_to_plaintext(proto_secret_key, &proto_output_ciphertext);
+        // The output ciphertext is replaced by the input ciphertext when the bit is set, and is
+        // left untouched otherwise.
+        let selected_message = if *raw_bit == Precision::Raw::ONE {
+            *raw_input_message
+        } else {
+            *raw_output_message
+        };
+        let shift = Precision::Raw::BITS - parameters.message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let expected_message = selected_message >> shift;
+        let decoded_message = maker
+            .transform_plaintext_to_raw(&proto_output_plaintext)
+            .wrapping_add(rounding)
+            >> shift;
+        (expected_message, decoded_message)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
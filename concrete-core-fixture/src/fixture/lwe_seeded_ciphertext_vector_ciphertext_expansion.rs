@@ -0,0 +1,192 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertext, PrototypesLweSecretKey, PrototypesLweSeededCiphertextVector,
+    PrototypesPlaintext, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweCiphertext, SynthesizesLweSecretKey, SynthesizesLweSeededCiphertextVector,
+    SynthesizesPlaintextVector,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use crate::raw::statistical_test::assert_noise_distribution;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{LweCiphertextCount, LweCiphertextIndex, LweDimension};
+use concrete_core::prelude::{
+    LweCiphertextEntity, LweSecretKeyEntity, LweSeededCiphertextVectorCiphertextExpansionEngine,
+    LweSeededCiphertextVectorEntity, PlaintextVectorEntity,
+};
+
+/// A fixture for the types implementing the `LweSeededCiphertextVectorCiphertextExpansionEngine`
+/// trait.
+pub struct LweSeededCiphertextVectorCiphertextExpansionFixture;
+
+#[derive(Debug)]
+pub struct LweSeededCiphertextVectorCiphertextExpansionParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub lwe_ciphertext_count: LweCiphertextCount,
+    pub index: LweCiphertextIndex,
+}
+
+impl<
+        Precision,
+        Engine,
+        KeyDistribution,
+        PlaintextVector,
+        SecretKey,
+        CiphertextVector,
+        Ciphertext,
+    >
+    Fixture<
+        Precision,
+        (KeyDistribution,),
+        Engine,
+        (PlaintextVector, SecretKey, CiphertextVector, Ciphertext),
+    > for LweSeededCiphertextVectorCiphertextExpansionFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: LweSeededCiphertextVectorCiphertextExpansionEngine<CiphertextVector, Ciphertext>,
+    PlaintextVector: PlaintextVectorEntity,
+    SecretKey: LweSecretKeyEntity,
+    CiphertextVector: LweSeededCiphertextVectorEntity,
+    Ciphertext: LweCiphertextEntity,
+    Maker: SynthesizesPlaintextVector<Precision, PlaintextVector>
+        + SynthesizesLweSecretKey<Precision, KeyDistribution, SecretKey>
+        + SynthesizesLweSeededCiphertextVector<Precision, KeyDistribution, CiphertextVector>
+        + SynthesizesLweCiphertext<Precision, KeyDistribution, Ciphertext>,
+{
+    type Parameters = LweSeededCiphertextVectorCiphertextExpansionParameters;
+    type RepetitionPrototypes =
+        (<Maker as PrototypesLweSecretKey<Precision, KeyDistribution>>::LweSecretKeyProto,);
+    type SamplePrototypes = (
+        <Maker as PrototypesLweSeededCiphertextVector<
+            Precision,
+            KeyDistribution,
+        >>::LweSeededCiphertextVectorProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+    );
+    type PreExecutionContext = (CiphertextVector,);
+    type PostExecutionContext = (CiphertextVector, Ciphertext);
+    type Criteria = (Variance,);
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSeededCiphertextVectorCiphertextExpansionParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(100),
+                    lwe_ciphertext_count: LweCiphertextCount(1),
+                    index: LweCiphertextIndex(0),
+                },
+                LweSeededCiphertextVectorCiphertextExpansionParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(100),
+                    lwe_ciphertext_count: LweCiphertextCount(100),
+                    index: LweCiphertextIndex(0),
+                },
+                LweSeededCiphertextVectorCiphertextExpansionParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(600),
+                    lwe_ciphertext_count: LweCiphertextCount(100),
+                    index: LweCiphertextIndex(37),
+                },
+                LweSeededCiphertextVectorCiphertextExpansionParameters {
+                    noise: Variance(0.00000001),
+                    lwe_dimension: LweDimension(1000),
+                    lwe_ciphertext_count: LweCiphertextCount(100),
+                    index: LweCiphertextIndex(99),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        (proto_secret_key,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key,) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_vec(parameters.lwe_ciphertext_count.0);
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(raw_plaintext_vector.as_slice());
+        let proto_seeded_ciphertext_vector = maker
+            .encrypt_plaintext_vector_to_lwe_seeded_ciphertext_vector(
+                proto_secret_key,
+                &proto_plaintext_vector,
+                parameters.noise,
+            );
+        (proto_seeded_ciphertext_vector, proto_plaintext_vector)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_seeded_ciphertext_vector, _) = sample_proto;
+        let synth_seeded_ciphertext_vector =
+            maker.synthesize_lwe_seeded_ciphertext_vector(proto_seeded_ciphertext_vector);
+        (synth_seeded_ciphertext_vector,)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (seeded_ciphertext_vector,) = context;
+        let ciphertext = unsafe {
+            engine.expand_lwe_seeded_ciphertext_vector_ciphertext_unchecked(
+                &seeded_ciphertext_vector,
+                parameters.index,
+            )
+        };
+        (seeded_ciphertext_vector, ciphertext)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (seeded_ciphertext_vector, ciphertext) = context;
+        let (proto_secret_key,) = repetition_proto;
+        let (_, proto_plaintext_vector) = sample_proto;
+        maker.destroy_lwe_seeded_ciphertext_vector(seeded_ciphertext_vector);
+        let proto_output_ciphertext = maker.unsynthesize_lwe_ciphertext(ciphertext);
+        let proto_output_plaintext =
+            maker.decrypt_lwe_ciphertext_to_plaintext(proto_secret_key, &proto_output_ciphertext);
+        (
+            maker.transform_plaintext_vector_to_raw_vec(proto_plaintext_vector)[parameters.index.0],
+            maker.transform_plaintext_to_raw(&proto_output_plaintext),
+        )
+    }
+
+    fn compute_criteria(
+        parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+        (parameters.noise,)
+    }
+
+    fn verify(criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        let (means, actual): (Vec<_>, Vec<_>) = outputs.iter().cloned().unzip();
+        assert_noise_distribution(actual.as_slice(), means.as_slice(), criteria.0)
+    }
+}
@@ -0,0 +1,205 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{PrototypesGlweSecretKey, PrototypesLweSecretKey};
+use crate::generation::synthesizing::{
+    SynthesizesGlweSecretKey, SynthesizesLweSecretKey,
+    SynthesizesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use concrete_core::prelude::{
+    GlweSecretKeyEntity, LweSecretKeyEntity,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationEngine,
+};
+
+/// A fixture for the types implementing the
+/// `LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationEngine` trait.
+pub struct LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationFixture;
+
+#[derive(Debug)]
+pub struct LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationParameters {
+    pub noise: Variance,
+    pub lwe_dimension_in: LweDimension,
+    pub glwe_dimension_out: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub level: DecompositionLevelCount,
+    pub base_log: DecompositionBaseLog,
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        InputSecretKey,
+        OutputSecretKey,
+        SeededKeys,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (InputSecretKey, OutputSecretKey, SeededKeys),
+    > for LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationEngine<
+        InputSecretKey,
+        OutputSecretKey,
+        SeededKeys,
+    >,
+    InputSecretKey: LweSecretKeyEntity,
+    OutputSecretKey: GlweSecretKeyEntity,
+    SeededKeys: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    Maker: SynthesizesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            SeededKeys,
+        > + SynthesizesLweSecretKey<Precision, InputKeyDistribution, InputSecretKey>
+        + SynthesizesGlweSecretKey<Precision, OutputKeyDistribution, OutputSecretKey>,
+{
+    type Parameters =
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationParameters;
+    type RepetitionPrototypes = ();
+    type SamplePrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, OutputKeyDistribution>>::GlweSecretKeyProto,
+    );
+    type PreExecutionContext = (InputSecretKey, OutputSecretKey);
+    type PostExecutionContext = (SeededKeys,);
+    type Criteria = ();
+    type Outcome = (Vec<usize>, Vec<usize>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    lwe_dimension_in: LweDimension(50),
+                    glwe_dimension_out: GlweDimension(1),
+                    polynomial_size: PolynomialSize(128),
+                    level: DecompositionLevelCount(3),
+                    base_log: DecompositionBaseLog(7),
+                },
+                LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    lwe_dimension_in: LweDimension(50),
+                    glwe_dimension_out: GlweDimension(2),
+                    polynomial_size: PolynomialSize(64),
+                    level: DecompositionLevelCount(3),
+                    base_log: DecompositionBaseLog(7),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let proto_lwe_secret_key_in =
+            <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::new_lwe_secret_key(
+                maker,
+                parameters.lwe_dimension_in,
+            );
+        let proto_glwe_secret_key_out = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker,
+            parameters.glwe_dimension_out,
+            parameters.polynomial_size,
+        );
+        (proto_lwe_secret_key_in, proto_glwe_secret_key_out)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_lwe_secret_key_in, proto_glwe_secret_key_out) = sample_proto;
+        let synth_lwe_secret_key_in = maker.synthesize_lwe_secret_key(proto_lwe_secret_key_in);
+        let synth_glwe_secret_key_out = maker.synthesize_glwe_secret_key(proto_glwe_secret_key_out);
+        (synth_lwe_secret_key_in, synth_glwe_secret_key_out)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (lwe_sk_in, glwe_sk_out) = context;
+        let seeded_cbs_fpksk = unsafe {
+            engine
+                .generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+                    &lwe_sk_in,
+                    &glwe_sk_out,
+                    parameters.base_log,
+                    parameters.level,
+                    parameters.noise,
+                )
+        };
+        (seeded_cbs_fpksk,)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (seeded_cbs_fpksk,) = context;
+        // The key material can only be checked through a full circuit bootstrap, so the generated
+        // keys are checked against the requested shape: one key per GLWE secret key polynomial,
+        // plus one for the constant term.
+        let expected = vec![
+            parameters.lwe_dimension_in.0,
+            parameters.glwe_dimension_out.0,
+            parameters.polynomial_size.0,
+            parameters.level.0,
+            parameters.base_log.0,
+            parameters.glwe_dimension_out.0 + 1,
+        ];
+        let actual = vec![
+            seeded_cbs_fpksk.input_lwe_dimension().0,
+            seeded_cbs_fpksk.output_glwe_dimension().0,
+            seeded_cbs_fpksk.output_polynomial_size().0,
+            seeded_cbs_fpksk.decomposition_level_count().0,
+            seeded_cbs_fpksk.decomposition_base_log().0,
+            seeded_cbs_fpksk.key_count().0,
+        ];
+        maker.destroy_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+            seeded_cbs_fpksk,
+        );
+        (expected, actual)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs.iter().all(|(expected, actual)| expected == actual)
+    }
+}
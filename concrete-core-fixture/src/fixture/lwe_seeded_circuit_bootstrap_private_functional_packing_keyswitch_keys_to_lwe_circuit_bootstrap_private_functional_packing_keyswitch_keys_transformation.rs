@@ -0,0 +1,209 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweSecretKey, PrototypesLweSecretKey,
+    PrototypesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    SynthesizesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use concrete_core::prelude::{
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationEngine,
+};
+
+/// A fixture for the types implementing the
+/// `LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationEngine`
+/// trait.
+pub struct LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationFixture;
+
+#[derive(Debug)]
+pub struct LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationParameters
+{
+    pub noise: Variance,
+    pub lwe_dimension_in: LweDimension,
+    pub glwe_dimension_out: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub level: DecompositionLevelCount,
+    pub base_log: DecompositionBaseLog,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, InputKeyDistribution, OutputKeyDistribution, Engine, SeededKeys, Keys>
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (SeededKeys, Keys),
+    > for LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationEngine<
+        SeededKeys,
+        Keys,
+    >,
+    SeededKeys: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    Keys: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    Maker: SynthesizesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            SeededKeys,
+        > + SynthesizesLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            Keys,
+        >,
+{
+    type Parameters = LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto,
+    );
+    type SamplePrototypes = ();
+    type PreExecutionContext = (SeededKeys,);
+    type PostExecutionContext = (Keys,);
+    type Criteria = ();
+    type Outcome = (Vec<usize>, Vec<usize>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    lwe_dimension_in: LweDimension(50),
+                    glwe_dimension_out: GlweDimension(1),
+                    polynomial_size: PolynomialSize(128),
+                    level: DecompositionLevelCount(3),
+                    base_log: DecompositionBaseLog(7),
+                },
+                LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    lwe_dimension_in: LweDimension(50),
+                    glwe_dimension_out: GlweDimension(2),
+                    polynomial_size: PolynomialSize(64),
+                    level: DecompositionLevelCount(3),
+                    base_log: DecompositionBaseLog(7),
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_lwe_secret_key_in =
+            <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::new_lwe_secret_key(
+                maker,
+                parameters.lwe_dimension_in,
+            );
+        let proto_glwe_secret_key_out = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker,
+            parameters.glwe_dimension_out,
+            parameters.polynomial_size,
+        );
+        let proto_seeded_cbs_fpksk = maker
+            .new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+                &proto_lwe_secret_key_in,
+                &proto_glwe_secret_key_out,
+                parameters.base_log,
+                parameters.level,
+                parameters.noise,
+            );
+        (proto_seeded_cbs_fpksk,)
+    }
+
+    fn generate_random_sample_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_seeded_cbs_fpksk,) = repetition_proto;
+        let synth_seeded_cbs_fpksk = maker
+            .synthesize_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+                proto_seeded_cbs_fpksk,
+            );
+        (synth_seeded_cbs_fpksk,)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (seeded_cbs_fpksk,) = context;
+        let cbs_fpksk = unsafe {
+            engine
+                .transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+                    seeded_cbs_fpksk,
+                )
+        };
+        (cbs_fpksk,)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (cbs_fpksk,) = context;
+        // The key material can only be checked through a full circuit bootstrap, so the expanded
+        // keys are checked against the shape of the seeded keys they come from.
+        let expected = vec![
+            parameters.lwe_dimension_in.0,
+            parameters.glwe_dimension_out.0,
+            parameters.polynomial_size.0,
+            parameters.level.0,
+            parameters.base_log.0,
+            parameters.glwe_dimension_out.0 + 1,
+        ];
+        let actual = vec![
+            cbs_fpksk.input_lwe_dimension().0,
+            cbs_fpksk.output_glwe_dimension().0,
+            cbs_fpksk.output_polynomial_size().0,
+            cbs_fpksk.decomposition_level_count().0,
+            cbs_fpksk.decomposition_base_log().0,
+            cbs_fpksk.key_count().0,
+        ];
+        maker.destroy_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(cbs_fpksk);
+        (expected, actual)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs.iter().all(|(expected, actual)| expected == actual)
+    }
+}
@@ -0,0 +1,255 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertext, PrototypesLweKeyswitchKey, PrototypesLweSecretKey,
+    PrototypesLweSeededKeyswitchKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweKeyswitchKey, SynthesizesLweSeededKeyswitchKey,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweKeyswitchKeyRowRange,
+};
+use concrete_core::prelude::{
+    LweKeyswitchKeyEntity, LweSeededKeyswitchKeyEntity, LweSeededKeyswitchKeyRangeExpansionEngine,
+};
+
+/// A fixture for the types implementing the `LweSeededKeyswitchKeyRangeExpansionEngine` trait.
+pub struct LweSeededKeyswitchKeyRangeExpansionFixture;
+
+#[derive(Debug)]
+pub struct LweSeededKeyswitchKeyRangeExpansionParameters {
+    pub noise: Variance,
+    pub lwe_dimension_in: LweDimension,
+    pub lwe_dimension_out: LweDimension,
+    pub level: DecompositionLevelCount,
+    pub base_log: DecompositionBaseLog,
+    pub range: LweKeyswitchKeyRowRange,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        SeededKeyswitchKey,
+        KeyswitchKey,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (SeededKeyswitchKey, KeyswitchKey),
+    > for LweSeededKeyswitchKeyRangeExpansionFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweSeededKeyswitchKeyRangeExpansionEngine<SeededKeyswitchKey, KeyswitchKey>,
+    SeededKeyswitchKey: LweSeededKeyswitchKeyEntity,
+    KeyswitchKey: LweKeyswitchKeyEntity,
+    Maker: SynthesizesLweSeededKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            SeededKeyswitchKey,
+        > + SynthesizesLweKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            KeyswitchKey,
+        >,
+{
+    type Parameters = LweSeededKeyswitchKeyRangeExpansionParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesLweSecretKey<Precision, OutputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesLweSeededKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweSeededKeyswitchKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+        <Maker as PrototypesLweCiphertext<Precision, InputKeyDistribution>>::LweCiphertextProto,
+    );
+    type PreExecutionContext = (SeededKeyswitchKey,);
+    type PostExecutionContext = (SeededKeyswitchKey, KeyswitchKey);
+    type Criteria = ();
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSeededKeyswitchKeyRangeExpansionParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    lwe_dimension_in: LweDimension(600),
+                    lwe_dimension_out: LweDimension(300),
+                    level: DecompositionLevelCount(3),
+                    base_log: DecompositionBaseLog(7),
+                    range: LweKeyswitchKeyRowRange(0, 0),
+                    message_bits: 4,
+                },
+                LweSeededKeyswitchKeyRangeExpansionParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    lwe_dimension_in: LweDimension(600),
+                    lwe_dimension_out: LweDimension(300),
+                    level: DecompositionLevelCount(3),
+                    base_log: DecompositionBaseLog(7),
+                    range: LweKeyswitchKeyRowRange(100, 399),
+                    message_bits: 4,
+                },
+                LweSeededKeyswitchKeyRangeExpansionParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    lwe_dimension_in: LweDimension(600),
+                    lwe_dimension_out: LweDimension(300),
+                    level: DecompositionLevelCount(3),
+                    base_log: DecompositionBaseLog(7),
+                    range: LweKeyswitchKeyRowRange(0, 599),
+                    message_bits: 4,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key_in = <Maker as PrototypesLweSecretKey<
+            Precision,
+            InputKeyDistribution,
+        >>::new_lwe_secret_key(maker, parameters.lwe_dimension_in);
+        let proto_secret_key_out = <Maker as PrototypesLweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_lwe_secret_key(
+            maker, parameters.lwe_dimension_out
+        );
+        let proto_seeded_ksk = maker.new_lwe_seeded_keyswitch_key(
+            &proto_secret_key_in,
+            &proto_secret_key_out,
+            parameters.level,
+            parameters.base_log,
+            parameters.noise,
+        );
+        (proto_secret_key_in, proto_secret_key_out, proto_seeded_ksk)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_secret_key_in, ..) = repetition_proto;
+        // The expanded key only switches the input key coefficients of the range. We build a
+        // noiseless encryption under those coefficients: the mask is random on the range, and the
+        // body is computed by decrypting the same mask, padded with zeros, under the full input
+        // key.
+        let LweKeyswitchKeyRowRange(start, end) = parameters.range;
+        let raw_mask = Precision::Raw::uniform_vec(end - start + 1);
+        let mut raw_padded_ciphertext =
+            vec![Precision::Raw::ZERO; parameters.lwe_dimension_in.to_lwe_size().0];
+        raw_padded_ciphertext[start..=end].copy_from_slice(raw_mask.as_slice());
+        let proto_padded_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            InputKeyDistribution,
+        >>::transform_raw_vec_to_lwe_ciphertext(
+            maker, raw_padded_ciphertext.as_slice()
+        );
+        let proto_opposite_mask_product = <Maker as PrototypesLweCiphertext<
+            Precision,
+            InputKeyDistribution,
+        >>::decrypt_lwe_ciphertext_to_plaintext(
+            maker,
+            proto_secret_key_in,
+            &proto_padded_ciphertext,
+        );
+        let raw_opposite_mask_product =
+            maker.transform_plaintext_to_raw(&proto_opposite_mask_product);
+        let raw_plaintext = Precision::Raw::uniform_n_msb(parameters.message_bits);
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        let mut raw_ciphertext = raw_mask;
+        raw_ciphertext.push(raw_plaintext.wrapping_sub(raw_opposite_mask_product));
+        let proto_ciphertext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            InputKeyDistribution,
+        >>::transform_raw_vec_to_lwe_ciphertext(
+            maker, raw_ciphertext.as_slice()
+        );
+        (proto_plaintext, proto_ciphertext)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, _, proto_seeded_ksk) = repetition_proto;
+        (maker.synthesize_lwe_seeded_keyswitch_key(proto_seeded_ksk),)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (seeded_ksk,) = context;
+        let ksk = unsafe {
+            engine.expand_lwe_seeded_keyswitch_key_range_unchecked(&seeded_ksk, parameters.range)
+        };
+        (seeded_ksk, ksk)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (seeded_ksk, ksk) = context;
+        let (_, proto_secret_key_out, _) = repetition_proto;
+        let (proto_plaintext, proto_ciphertext) = sample_proto;
+        maker.destroy_lwe_seeded_keyswitch_key(seeded_ksk);
+        let proto_ksk = maker.unsynthesize_lwe_keyswitch_key(ksk);
+        let proto_output_ciphertext = maker.keyswitch_lwe_ciphertext(proto_ciphertext, &proto_ksk);
+        let proto_output_plaintext = <Maker as PrototypesLweCiphertext<
+            Precision,
+            OutputKeyDistribution,
+        >>::decrypt_lwe_ciphertext_to_plaintext(
+            maker,
+            proto_secret_key_out,
+            &proto_output_ciphertext,
+        );
+        let shift = Precision::Raw::BITS - parameters.message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let expected_message = maker.transform_plaintext_to_raw(proto_plaintext) >> shift;
+        let decoded_message = maker
+            .transform_plaintext_to_raw(&proto_output_plaintext)
+            .wrapping_add(rounding)
+            >> shift;
+        (expected_message, decoded_message)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
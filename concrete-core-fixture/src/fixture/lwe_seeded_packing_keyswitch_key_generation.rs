@@ -0,0 +1,247 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweSecretKey, PrototypesLweCiphertextVector,
+    PrototypesLwePackingKeyswitchKey, PrototypesLweSecretKey,
+    PrototypesLweSeededPackingKeyswitchKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesGlweSecretKey, SynthesizesLweSecretKey, SynthesizesLweSeededPackingKeyswitchKey,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount, LweDimension,
+    PolynomialSize,
+};
+use concrete_core::prelude::{
+    GlweSecretKeyEntity, LweSecretKeyEntity, LweSeededPackingKeyswitchKeyEntity,
+    LweSeededPackingKeyswitchKeyGenerationEngine,
+};
+
+/// A fixture for the types implementing the `LweSeededPackingKeyswitchKeyGenerationEngine` trait.
+pub struct LweSeededPackingKeyswitchKeyGenerationFixture;
+
+#[derive(Debug)]
+pub struct LweSeededPackingKeyswitchKeyGenerationParameters {
+    pub noise: Variance,
+    pub input_lwe_dimension: LweDimension,
+    pub input_lwe_count: LweCiphertextCount,
+    pub output_glwe_dimension: GlweDimension,
+    pub output_polynomial_size: PolynomialSize,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        InputSecretKey,
+        OutputSecretKey,
+        SeededKeyswitchKey,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (InputSecretKey, OutputSecretKey, SeededKeyswitchKey),
+    > for LweSeededPackingKeyswitchKeyGenerationFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweSeededPackingKeyswitchKeyGenerationEngine<
+        InputSecretKey,
+        OutputSecretKey,
+        SeededKeyswitchKey,
+    >,
+    InputSecretKey: LweSecretKeyEntity,
+    OutputSecretKey: GlweSecretKeyEntity,
+    SeededKeyswitchKey: LweSeededPackingKeyswitchKeyEntity,
+    Maker: SynthesizesLweSeededPackingKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            SeededKeyswitchKey,
+        > + SynthesizesLweSecretKey<Precision, InputKeyDistribution, InputSecretKey>
+        + SynthesizesGlweSecretKey<Precision, OutputKeyDistribution, OutputSecretKey>,
+{
+    type Parameters = LweSeededPackingKeyswitchKeyGenerationParameters;
+    type RepetitionPrototypes = ();
+    type SamplePrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, OutputKeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (InputSecretKey, OutputSecretKey);
+    type PostExecutionContext = (SeededKeyswitchKey,);
+    type Criteria = ();
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSeededPackingKeyswitchKeyGenerationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    input_lwe_dimension: LweDimension(100),
+                    input_lwe_count: LweCiphertextCount(10),
+                    output_glwe_dimension: GlweDimension(1),
+                    output_polynomial_size: PolynomialSize(256),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    message_bits: 4,
+                },
+                LweSeededPackingKeyswitchKeyGenerationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    input_lwe_dimension: LweDimension(100),
+                    input_lwe_count: LweCiphertextCount(10),
+                    output_glwe_dimension: GlweDimension(2),
+                    output_polynomial_size: PolynomialSize(128),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    message_bits: 4,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let proto_input_secret_key =
+            <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::new_lwe_secret_key(
+                maker,
+                parameters.input_lwe_dimension,
+            );
+        let proto_output_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker,
+            parameters.output_glwe_dimension,
+            parameters.output_polynomial_size,
+        );
+        let raw_plaintext_vector = Precision::Raw::uniform_n_msb_vec(
+            parameters.message_bits,
+            parameters.input_lwe_count.0,
+        );
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        let proto_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+            maker,
+            &proto_input_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        (
+            proto_input_secret_key,
+            proto_output_secret_key,
+            proto_plaintext_vector,
+            proto_ciphertext_vector,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_input_secret_key, proto_output_secret_key, ..) = sample_proto;
+        let synth_input_secret_key = maker.synthesize_lwe_secret_key(proto_input_secret_key);
+        let synth_output_secret_key = maker.synthesize_glwe_secret_key(proto_output_secret_key);
+        (synth_input_secret_key, synth_output_secret_key)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_secret_key, output_secret_key) = context;
+        let seeded_keyswitch_key = unsafe {
+            engine.generate_new_lwe_seeded_packing_keyswitch_key_unchecked(
+                &input_secret_key,
+                &output_secret_key,
+                parameters.decomposition_level_count,
+                parameters.decomposition_base_log,
+                parameters.noise,
+            )
+        };
+        (seeded_keyswitch_key,)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (seeded_keyswitch_key,) = context;
+        let (_, proto_output_secret_key, proto_plaintext_vector, proto_ciphertext_vector) =
+            sample_proto;
+        // The generated key is checked by expanding it, packing the ciphertexts and decrypting the
+        // result: the messages must come out in the first coefficients, followed by zeros.
+        let proto_seeded_keyswitch_key =
+            maker.unsynthesize_lwe_seeded_packing_keyswitch_key(seeded_keyswitch_key);
+        let proto_keyswitch_key = maker
+            .transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key(
+                &proto_seeded_keyswitch_key,
+            );
+        let proto_output_ciphertext = maker
+            .packing_keyswitch_lwe_ciphertext_vector(proto_ciphertext_vector, &proto_keyswitch_key);
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_output_secret_key,
+            &proto_output_ciphertext,
+        );
+        let shift = Precision::Raw::BITS - parameters.message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let mut expected_messages: Vec<Precision::Raw> = maker
+            .transform_plaintext_vector_to_raw_vec(proto_plaintext_vector)
+            .into_iter()
+            .map(|raw| raw >> shift)
+            .collect();
+        expected_messages.resize(parameters.output_polynomial_size.0, Precision::Raw::ZERO);
+        let decoded_messages = maker
+            .transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector)
+            .into_iter()
+            .map(|raw| raw.wrapping_add(rounding) >> shift)
+            .collect();
+        (expected_messages, decoded_messages)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
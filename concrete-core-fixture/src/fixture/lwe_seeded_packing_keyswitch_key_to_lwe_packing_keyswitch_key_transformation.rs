@@ -0,0 +1,255 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesGlweCiphertext, PrototypesGlweSecretKey, PrototypesLweCiphertextVector,
+    PrototypesLwePackingKeyswitchKey, PrototypesLweSecretKey,
+    PrototypesLweSeededPackingKeyswitchKey, PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesLweSeededPackingKeyswitchKey, SynthesizesPackingKeyswitchKey,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount, LweDimension,
+    PolynomialSize,
+};
+use concrete_core::prelude::{
+    LwePackingKeyswitchKeyEntity, LweSeededPackingKeyswitchKeyEntity,
+    LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationEngine,
+};
+
+/// A fixture for the types implementing the
+/// `LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationEngine` trait.
+pub struct LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationFixture;
+
+#[derive(Debug)]
+pub struct LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationParameters {
+    pub noise: Variance,
+    pub input_lwe_dimension: LweDimension,
+    pub input_lwe_count: LweCiphertextCount,
+    pub output_glwe_dimension: GlweDimension,
+    pub output_polynomial_size: PolynomialSize,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        SeededKeyswitchKey,
+        KeyswitchKey,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (SeededKeyswitchKey, KeyswitchKey),
+    > for LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationEngine<
+        SeededKeyswitchKey,
+        KeyswitchKey,
+    >,
+    SeededKeyswitchKey: LweSeededPackingKeyswitchKeyEntity,
+    KeyswitchKey: LwePackingKeyswitchKeyEntity,
+    Maker: SynthesizesLweSeededPackingKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            SeededKeyswitchKey,
+        > + SynthesizesPackingKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            KeyswitchKey,
+        >,
+{
+    type Parameters = LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, OutputKeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesLweSeededPackingKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+        >>::LweSeededPackingKeyswitchKeyProto,
+    );
+    type SamplePrototypes = (
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (SeededKeyswitchKey,);
+    type PostExecutionContext = (KeyswitchKey,);
+    type Criteria = ();
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    input_lwe_dimension: LweDimension(100),
+                    input_lwe_count: LweCiphertextCount(10),
+                    output_glwe_dimension: GlweDimension(1),
+                    output_polynomial_size: PolynomialSize(256),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    message_bits: 4,
+                },
+                LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    input_lwe_dimension: LweDimension(100),
+                    input_lwe_count: LweCiphertextCount(10),
+                    output_glwe_dimension: GlweDimension(2),
+                    output_polynomial_size: PolynomialSize(128),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    message_bits: 4,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_input_secret_key =
+            <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::new_lwe_secret_key(
+                maker,
+                parameters.input_lwe_dimension,
+            );
+        let proto_output_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker,
+            parameters.output_glwe_dimension,
+            parameters.output_polynomial_size,
+        );
+        let proto_seeded_keyswitch_key = maker.new_lwe_seeded_packing_keyswitch_key(
+            &proto_input_secret_key,
+            &proto_output_secret_key,
+            parameters.decomposition_level_count,
+            parameters.decomposition_base_log,
+            parameters.noise,
+        );
+        (
+            proto_input_secret_key,
+            proto_output_secret_key,
+            proto_seeded_keyswitch_key,
+        )
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let (proto_input_secret_key, ..) = repetition_proto;
+        let raw_plaintext_vector = Precision::Raw::uniform_n_msb_vec(
+            parameters.message_bits,
+            parameters.input_lwe_count.0,
+        );
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        let proto_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+            maker,
+            proto_input_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        (proto_plaintext_vector, proto_ciphertext_vector)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, _, proto_seeded_keyswitch_key) = repetition_proto;
+        let synth_seeded_keyswitch_key =
+            maker.synthesize_lwe_seeded_packing_keyswitch_key(proto_seeded_keyswitch_key);
+        (synth_seeded_keyswitch_key,)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (seeded_keyswitch_key,) = context;
+        let keyswitch_key = unsafe {
+            engine
+                .transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_unchecked(
+                    seeded_keyswitch_key,
+                )
+        };
+        (keyswitch_key,)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (keyswitch_key,) = context;
+        let (_, proto_output_secret_key, _) = repetition_proto;
+        let (proto_plaintext_vector, proto_ciphertext_vector) = sample_proto;
+        // The expanded key is checked by packing the ciphertexts and decrypting the result: the
+        // messages must come out in the first coefficients, followed by zeros.
+        let proto_keyswitch_key = maker.unsynthesize_packing_keyswitch_key(keyswitch_key);
+        let proto_output_ciphertext = maker
+            .packing_keyswitch_lwe_ciphertext_vector(proto_ciphertext_vector, &proto_keyswitch_key);
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_output_secret_key,
+            &proto_output_ciphertext,
+        );
+        let shift = Precision::Raw::BITS - parameters.message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let mut expected_messages: Vec<Precision::Raw> = maker
+            .transform_plaintext_vector_to_raw_vec(proto_plaintext_vector)
+            .into_iter()
+            .map(|raw| raw >> shift)
+            .collect();
+        expected_messages.resize(parameters.output_polynomial_size.0, Precision::Raw::ZERO);
+        let decoded_messages = maker
+            .transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector)
+            .into_iter()
+            .map(|raw| raw.wrapping_add(rounding) >> shift)
+            .collect();
+        (expected_messages, decoded_messages)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
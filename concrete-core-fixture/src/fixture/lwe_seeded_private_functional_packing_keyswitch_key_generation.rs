@@ -0,0 +1,287 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesCleartextVector, PrototypesGlweCiphertext, PrototypesGlweSecretKey,
+    PrototypesLweCiphertextVector, PrototypesLwePrivateFunctionalPackingKeyswitchKey,
+    PrototypesLweSecretKey, PrototypesLweSeededPrivateFunctionalPackingKeyswitchKey,
+    PrototypesPlaintextVector,
+};
+use crate::generation::synthesizing::{
+    SynthesizesCleartextVector, SynthesizesGlweSecretKey, SynthesizesLweSecretKey,
+    SynthesizesLweSeededPrivateFunctionalPackingKeyswitchKey,
+};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, StandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount, LweDimension,
+    PolynomialSize,
+};
+use concrete_core::prelude::{
+    CleartextVectorEntity, GlweSecretKeyEntity, LweSecretKeyEntity,
+    LweSeededPrivateFunctionalPackingKeyswitchKeyEntity,
+    LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationEngine,
+};
+
+/// A fixture for the types implementing the `LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationEngine` trait.
+pub struct LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationFixture;
+
+#[derive(Debug)]
+pub struct LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationParameters {
+    pub noise: Variance,
+    pub keyswitch_key_noise: StandardDev,
+    pub input_lwe_dimension: LweDimension,
+    pub input_lwe_count: LweCiphertextCount,
+    pub output_glwe_dimension: GlweDimension,
+    pub output_polynomial_size: PolynomialSize,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<
+        Precision,
+        InputKeyDistribution,
+        OutputKeyDistribution,
+        Engine,
+        InputSecretKey,
+        OutputSecretKey,
+        CleartextVector,
+        SeededKeyswitchKey,
+    >
+    Fixture<
+        Precision,
+        (InputKeyDistribution, OutputKeyDistribution),
+        Engine,
+        (
+            InputSecretKey,
+            OutputSecretKey,
+            CleartextVector,
+            SeededKeyswitchKey,
+        ),
+    > for LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationFixture
+where
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    Engine: LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationEngine<
+        InputSecretKey,
+        OutputSecretKey,
+        SeededKeyswitchKey,
+        CleartextVector,
+        Precision::Raw,
+    >,
+    InputSecretKey: LweSecretKeyEntity,
+    OutputSecretKey: GlweSecretKeyEntity,
+    CleartextVector: CleartextVectorEntity,
+    SeededKeyswitchKey: LweSeededPrivateFunctionalPackingKeyswitchKeyEntity,
+    Maker: SynthesizesLweSeededPrivateFunctionalPackingKeyswitchKey<
+            Precision,
+            InputKeyDistribution,
+            OutputKeyDistribution,
+            SeededKeyswitchKey,
+        > + SynthesizesLweSecretKey<Precision, InputKeyDistribution, InputSecretKey>
+        + SynthesizesGlweSecretKey<Precision, OutputKeyDistribution, OutputSecretKey>
+        + SynthesizesCleartextVector<Precision, CleartextVector>,
+{
+    type Parameters = LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationParameters;
+    type RepetitionPrototypes =
+        (<Maker as PrototypesCleartextVector<Precision>>::CleartextVectorProto,);
+    type SamplePrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesGlweSecretKey<Precision, OutputKeyDistribution>>::GlweSecretKeyProto,
+        <Maker as PrototypesPlaintextVector<Precision>>::PlaintextVectorProto,
+        <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::LweCiphertextVectorProto,
+    );
+    type PreExecutionContext = (InputSecretKey, OutputSecretKey, CleartextVector);
+    type PostExecutionContext = (SeededKeyswitchKey,);
+    type Criteria = ();
+    type Outcome = (Vec<Precision::Raw>, Vec<Precision::Raw>);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    keyswitch_key_noise: StandardDev(
+                        LogStandardDev::from_log_standard_dev(-25.).get_standard_dev(),
+                    ),
+                    input_lwe_dimension: LweDimension(100),
+                    input_lwe_count: LweCiphertextCount(10),
+                    output_glwe_dimension: GlweDimension(1),
+                    output_polynomial_size: PolynomialSize(256),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    message_bits: 4,
+                },
+                LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    keyswitch_key_noise: StandardDev(
+                        LogStandardDev::from_log_standard_dev(-25.).get_standard_dev(),
+                    ),
+                    input_lwe_dimension: LweDimension(100),
+                    input_lwe_count: LweCiphertextCount(10),
+                    output_glwe_dimension: GlweDimension(2),
+                    output_polynomial_size: PolynomialSize(128),
+                    decomposition_level_count: DecompositionLevelCount(3),
+                    decomposition_base_log: DecompositionBaseLog(7),
+                    message_bits: 4,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        // The keys are generated for the identity function and the constant polynomial 1, so that
+        // the packed plaintexts are the input messages themselves.
+        let mut raw_cleartext_vector =
+            vec![Precision::Raw::ZERO; parameters.output_polynomial_size.0];
+        raw_cleartext_vector[0] = Precision::Raw::ONE;
+        let proto_cleartext_vector =
+            maker.transform_raw_vec_to_cleartext_vector(&raw_cleartext_vector);
+        (proto_cleartext_vector,)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let proto_input_secret_key =
+            <Maker as PrototypesLweSecretKey<Precision, InputKeyDistribution>>::new_lwe_secret_key(
+                maker,
+                parameters.input_lwe_dimension,
+            );
+        let proto_output_secret_key = <Maker as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::new_glwe_secret_key(
+            maker,
+            parameters.output_glwe_dimension,
+            parameters.output_polynomial_size,
+        );
+        let raw_plaintext_vector = Precision::Raw::uniform_n_msb_vec(
+            parameters.message_bits,
+            parameters.input_lwe_count.0,
+        );
+        let proto_plaintext_vector =
+            maker.transform_raw_vec_to_plaintext_vector(&raw_plaintext_vector);
+        let proto_ciphertext_vector = <Maker as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::encrypt_plaintext_vector_to_lwe_ciphertext_vector(
+            maker,
+            &proto_input_secret_key,
+            &proto_plaintext_vector,
+            parameters.noise,
+        );
+        (
+            proto_input_secret_key,
+            proto_output_secret_key,
+            proto_plaintext_vector,
+            proto_ciphertext_vector,
+        )
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_cleartext_vector,) = repetition_proto;
+        let (proto_input_secret_key, proto_output_secret_key, ..) = sample_proto;
+        let synth_input_secret_key = maker.synthesize_lwe_secret_key(proto_input_secret_key);
+        let synth_output_secret_key = maker.synthesize_glwe_secret_key(proto_output_secret_key);
+        let synth_cleartext_vector = maker.synthesize_cleartext_vector(proto_cleartext_vector);
+        (
+            synth_input_secret_key,
+            synth_output_secret_key,
+            synth_cleartext_vector,
+        )
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (input_secret_key, output_secret_key, cleartext_vector) = context;
+        let seeded_keyswitch_key = unsafe {
+            engine.generate_new_lwe_seeded_private_functional_packing_keyswitch_key_unchecked(
+                &input_secret_key,
+                &output_secret_key,
+                parameters.decomposition_level_count,
+                parameters.decomposition_base_log,
+                parameters.keyswitch_key_noise,
+                &|x| x,
+                &cleartext_vector,
+            )
+        };
+        (seeded_keyswitch_key,)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (seeded_keyswitch_key,) = context;
+        let (_, proto_output_secret_key, proto_plaintext_vector, proto_ciphertext_vector) =
+            sample_proto;
+        // The generated key is checked by expanding it, packing the ciphertexts and decrypting the
+        // result: the messages must come out in the first coefficients, followed by zeros.
+        let proto_seeded_keyswitch_key = maker
+            .unsynthesize_lwe_seeded_private_functional_packing_keyswitch_key(seeded_keyswitch_key);
+        let proto_keyswitch_key = maker
+            .transform_lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key(
+                &proto_seeded_keyswitch_key,
+            );
+        let proto_output_ciphertext = maker
+            .private_functional_packing_keyswitch_lwe_ciphertext_vector(
+                proto_ciphertext_vector,
+                &proto_keyswitch_key,
+            );
+        let proto_output_plaintext_vector = maker.decrypt_glwe_ciphertext_to_plaintext_vector(
+            proto_output_secret_key,
+            &proto_output_ciphertext,
+        );
+        let shift = Precision::Raw::BITS - parameters.message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let mut expected_messages: Vec<Precision::Raw> = maker
+            .transform_plaintext_vector_to_raw_vec(proto_plaintext_vector)
+            .into_iter()
+            .map(|raw| raw >> shift)
+            .collect();
+        expected_messages.resize(parameters.output_polynomial_size.0, Precision::Raw::ZERO);
+        let decoded_messages = maker
+            .transform_plaintext_vector_to_raw_vec(&proto_output_plaintext_vector)
+            .into_iter()
+            .map(|raw| raw.wrapping_add(rounding) >> shift)
+            .collect();
+        (expected_messages, decoded_messages)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
@@ -0,0 +1,157 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertext, PrototypesLwePublicKey, PrototypesLweSecretKey,
+    PrototypesLweSeededPublicKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{SynthesizesLweSecretKey, SynthesizesLweSeededPublicKey};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+use concrete_core::prelude::{
+    LweSecretKeyEntity, LweSeededPublicKeyEntity, LweSeededPublicKeyGenerationEngine,
+};
+
+/// A fixture for the types implementing the `LweSeededPublicKeyGenerationEngine` trait.
+pub struct LweSeededPublicKeyGenerationFixture;
+
+#[derive(Debug)]
+pub struct LweSeededPublicKeyGenerationParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, SecretKey, SeededPublicKey>
+    Fixture<Precision, (KeyDistribution,), Engine, (SecretKey, SeededPublicKey)>
+    for LweSeededPublicKeyGenerationFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: LweSeededPublicKeyGenerationEngine<SecretKey, SeededPublicKey>,
+    SecretKey: LweSecretKeyEntity,
+    SeededPublicKey: LweSeededPublicKeyEntity,
+    Maker: SynthesizesLweSecretKey<Precision, KeyDistribution, SecretKey>
+        + SynthesizesLweSeededPublicKey<Precision, KeyDistribution, SeededPublicKey>,
+{
+    type Parameters = LweSeededPublicKeyGenerationParameters;
+    type RepetitionPrototypes = ();
+    type SamplePrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, KeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesPlaintext<Precision>>::PlaintextProto,
+    );
+    type PreExecutionContext = (SecretKey,);
+    type PostExecutionContext = (SecretKey, SeededPublicKey);
+    type Criteria = ();
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSeededPublicKeyGenerationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    lwe_dimension: LweDimension(100),
+                    lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount(10),
+                    message_bits: 4,
+                },
+                LweSeededPublicKeyGenerationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    lwe_dimension: LweDimension(600),
+                    lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount(10),
+                    message_bits: 4,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        let raw_plaintext = Precision::Raw::uniform_n_msb(parameters.message_bits);
+        let proto_plaintext = maker.transform_raw_to_plaintext(&raw_plaintext);
+        (proto_secret_key, proto_plaintext)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (proto_secret_key, _) = sample_proto;
+        (maker.synthesize_lwe_secret_key(proto_secret_key),)
+    }
+
+    fn execute_engine(
+        parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (secret_key,) = context;
+        let seeded_public_key = unsafe {
+            engine.generate_new_lwe_seeded_public_key_unchecked(
+                &secret_key,
+                parameters.noise,
+                parameters.lwe_public_key_zero_encryption_count,
+            )
+        };
+        (secret_key, seeded_public_key)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (secret_key, seeded_public_key) = context;
+        let (proto_secret_key, proto_plaintext) = sample_proto;
+        maker.destroy_lwe_secret_key(secret_key);
+        // The generated key is checked by expanding it, encrypting a plaintext with it, and
+        // decrypting the result with the secret key.
+        let proto_seeded_public_key = maker.unsynthesize_lwe_seeded_public_key(seeded_public_key);
+        let proto_public_key =
+            maker.transform_lwe_seeded_public_key_to_lwe_public_key(&proto_seeded_public_key);
+        let proto_ciphertext = maker.encrypt_plaintext_to_lwe_ciphertext_with_public_key(
+            &proto_public_key,
+            proto_plaintext,
+        );
+        let proto_output_plaintext =
+            maker.decrypt_lwe_ciphertext_to_plaintext(proto_secret_key, &proto_ciphertext);
+        let shift = Precision::Raw::BITS - parameters.message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let expected_message = maker.transform_plaintext_to_raw(proto_plaintext) >> shift;
+        let decoded_message = maker
+            .transform_plaintext_to_raw(&proto_output_plaintext)
+            .wrapping_add(rounding)
+            >> shift;
+        (expected_message, decoded_message)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
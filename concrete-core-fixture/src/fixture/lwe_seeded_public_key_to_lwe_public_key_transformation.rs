@@ -0,0 +1,158 @@
+use crate::fixture::Fixture;
+use crate::generation::prototyping::{
+    PrototypesLweCiphertext, PrototypesLwePublicKey, PrototypesLweSecretKey,
+    PrototypesLweSeededPublicKey, PrototypesPlaintext,
+};
+use crate::generation::synthesizing::{SynthesizesLwePublicKey, SynthesizesLweSeededPublicKey};
+use crate::generation::{IntegerPrecision, KeyDistributionMarker, Maker};
+use crate::raw::generation::RawUnsignedIntegers;
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::numeric::{Numeric, UnsignedInteger};
+use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+use concrete_core::prelude::{
+    LwePublicKeyEntity, LweSeededPublicKeyEntity,
+    LweSeededPublicKeyToLwePublicKeyTransformationEngine,
+};
+
+/// A fixture for the types implementing the `LweSeededPublicKeyToLwePublicKeyTransformationEngine`
+/// trait.
+pub struct LweSeededPublicKeyToLwePublicKeyTransformationFixture;
+
+#[derive(Debug)]
+pub struct LweSeededPublicKeyToLwePublicKeyTransformationParameters {
+    pub noise: Variance,
+    pub lwe_dimension: LweDimension,
+    pub lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    pub message_bits: usize,
+}
+
+#[allow(clippy::type_complexity)]
+impl<Precision, KeyDistribution, Engine, SeededPublicKey, PublicKey>
+    Fixture<Precision, (KeyDistribution,), Engine, (SeededPublicKey, PublicKey)>
+    for LweSeededPublicKeyToLwePublicKeyTransformationFixture
+where
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    Engine: LweSeededPublicKeyToLwePublicKeyTransformationEngine<SeededPublicKey, PublicKey>,
+    SeededPublicKey: LweSeededPublicKeyEntity,
+    PublicKey: LwePublicKeyEntity,
+    Maker: SynthesizesLweSeededPublicKey<Precision, KeyDistribution, SeededPublicKey>
+        + SynthesizesLwePublicKey<Precision, KeyDistribution, PublicKey>,
+{
+    type Parameters = LweSeededPublicKeyToLwePublicKeyTransformationParameters;
+    type RepetitionPrototypes = (
+        <Maker as PrototypesLweSecretKey<Precision, KeyDistribution>>::LweSecretKeyProto,
+        <Maker as PrototypesLweSeededPublicKey<Precision, KeyDistribution>>::LweSeededPublicKeyProto,
+    );
+    type SamplePrototypes = (<Maker as PrototypesPlaintext<Precision>>::PlaintextProto,);
+    type PreExecutionContext = (SeededPublicKey,);
+    type PostExecutionContext = (PublicKey,);
+    type Criteria = ();
+    type Outcome = (Precision::Raw, Precision::Raw);
+
+    fn generate_parameters_iterator() -> Box<dyn Iterator<Item = Self::Parameters>> {
+        Box::new(
+            vec![
+                LweSeededPublicKeyToLwePublicKeyTransformationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    lwe_dimension: LweDimension(100),
+                    lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount(10),
+                    message_bits: 4,
+                },
+                LweSeededPublicKeyToLwePublicKeyTransformationParameters {
+                    noise: Variance(LogStandardDev::from_log_standard_dev(-25.).get_variance()),
+                    lwe_dimension: LweDimension(600),
+                    lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount(10),
+                    message_bits: 4,
+                },
+            ]
+            .into_iter(),
+        )
+    }
+
+    fn generate_random_repetition_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+    ) -> Self::RepetitionPrototypes {
+        let proto_secret_key = maker.new_lwe_secret_key(parameters.lwe_dimension);
+        let proto_seeded_public_key = maker.new_lwe_seeded_public_key(
+            &proto_secret_key,
+            parameters.noise,
+            parameters.lwe_public_key_zero_encryption_count,
+        );
+        (proto_secret_key, proto_seeded_public_key)
+    }
+
+    fn generate_random_sample_prototypes(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::SamplePrototypes {
+        let raw_plaintext = Precision::Raw::uniform_n_msb(parameters.message_bits);
+        (maker.transform_raw_to_plaintext(&raw_plaintext),)
+    }
+
+    fn prepare_context(
+        _parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        _sample_proto: &Self::SamplePrototypes,
+    ) -> Self::PreExecutionContext {
+        let (_, proto_seeded_public_key) = repetition_proto;
+        (maker.synthesize_lwe_seeded_public_key(proto_seeded_public_key),)
+    }
+
+    fn execute_engine(
+        _parameters: &Self::Parameters,
+        engine: &mut Engine,
+        context: Self::PreExecutionContext,
+    ) -> Self::PostExecutionContext {
+        let (seeded_public_key,) = context;
+        let public_key = unsafe {
+            engine.transform_lwe_seeded_public_key_to_lwe_public_key_unchecked(seeded_public_key)
+        };
+        (public_key,)
+    }
+
+    fn process_context(
+        parameters: &Self::Parameters,
+        maker: &mut Maker,
+        repetition_proto: &Self::RepetitionPrototypes,
+        sample_proto: &Self::SamplePrototypes,
+        context: Self::PostExecutionContext,
+    ) -> Self::Outcome {
+        let (public_key,) = context;
+        let (proto_secret_key, _) = repetition_proto;
+        let (proto_plaintext,) = sample_proto;
+        // The expanded key is checked by encrypting a plaintext with it, and decrypting the result
+        // with the secret key.
+        let proto_public_key = maker.unsynthesize_lwe_public_key(public_key);
+        let proto_ciphertext = maker.encrypt_plaintext_to_lwe_ciphertext_with_public_key(
+            &proto_public_key,
+            proto_plaintext,
+        );
+        let proto_output_plaintext =
+            maker.decrypt_lwe_ciphertext_to_plaintext(proto_secret_key, &proto_ciphertext);
+        let shift = Precision::Raw::BITS - parameters.message_bits;
+        let rounding = Precision::Raw::ONE << (shift - 1);
+        let expected_message = maker.transform_plaintext_to_raw(proto_plaintext) >> shift;
+        let decoded_message = maker
+            .transform_plaintext_to_raw(&proto_output_plaintext)
+            .wrapping_add(rounding)
+            >> shift;
+        (expected_message, decoded_message)
+    }
+
+    fn compute_criteria(
+        _parameters: &Self::Parameters,
+        _maker: &mut Maker,
+        _repetition_proto: &Self::RepetitionPrototypes,
+    ) -> Self::Criteria {
+    }
+
+    fn verify(_criteria: &Self::Criteria, outputs: &[Self::Outcome]) -> bool {
+        outputs
+            .iter()
+            .all(|(expected, decoded)| expected == decoded)
+    }
+}
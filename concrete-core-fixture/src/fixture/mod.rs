@@ -411,6 +411,12 @@ pub use lwe_ciphertext_discarding_bootstrap_1::*;
 mod lwe_ciphertext_discarding_bootstrap_2;
 pub use lwe_ciphertext_discarding_bootstrap_2::*;
 
+mod lwe_ciphertext_discarding_multi_bootstrap;
+pub use lwe_ciphertext_discarding_multi_bootstrap::*;
+
+mod lwe_ciphertext_discarding_bit_extraction;
+pub use lwe_ciphertext_discarding_bit_extraction::*;
+
 mod lwe_ciphertext_vector_discarding_bootstrap_1;
 pub use lwe_ciphertext_vector_discarding_bootstrap_1::*;
 
@@ -521,3 +527,141 @@ pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_pack
 
 mod glwe_ciphertext_conversion;
 pub use glwe_ciphertext_conversion::*;
+
+mod lwe_ciphertext_discarding_circuit_bootstrap_boolean;
+pub use lwe_ciphertext_discarding_circuit_bootstrap_boolean::*;
+
+mod glwe_ciphertext_tensor_product;
+pub use glwe_ciphertext_tensor_product::*;
+
+mod glwe_ciphertext_discarding_relinearization;
+pub use glwe_ciphertext_discarding_relinearization::*;
+
+mod glwe_ciphertext_discarding_multiplication;
+pub use glwe_ciphertext_discarding_multiplication::*;
+
+mod glwe_ciphertext_discarding_automorphism;
+pub use glwe_ciphertext_discarding_automorphism::*;
+
+mod glwe_ciphertext_discarding_trace;
+pub use glwe_ciphertext_discarding_trace::*;
+
+mod ggsw_ciphertext_seeded_scalar_encryption;
+pub use ggsw_ciphertext_seeded_scalar_encryption::*;
+
+mod ggsw_seeded_ciphertext_to_ggsw_ciphertext_transformation;
+pub use ggsw_seeded_ciphertext_to_ggsw_ciphertext_transformation::*;
+
+mod glwe_ciphertext_discarding_addition;
+pub use glwe_ciphertext_discarding_addition::*;
+
+mod glwe_ciphertext_fusing_addition;
+pub use glwe_ciphertext_fusing_addition::*;
+
+mod glwe_ciphertext_discarding_subtraction;
+pub use glwe_ciphertext_discarding_subtraction::*;
+
+mod glwe_ciphertext_fusing_subtraction;
+pub use glwe_ciphertext_fusing_subtraction::*;
+
+mod glwe_ciphertext_discarding_opposite;
+pub use glwe_ciphertext_discarding_opposite::*;
+
+mod glwe_ciphertext_fusing_opposite;
+pub use glwe_ciphertext_fusing_opposite::*;
+
+mod glwe_ciphertext_plaintext_vector_discarding_addition;
+pub use glwe_ciphertext_plaintext_vector_discarding_addition::*;
+
+mod glwe_ciphertext_plaintext_vector_fusing_addition;
+pub use glwe_ciphertext_plaintext_vector_fusing_addition::*;
+
+mod glwe_ciphertext_cleartext_vector_discarding_multiplication;
+pub use glwe_ciphertext_cleartext_vector_discarding_multiplication::*;
+
+mod glwe_ciphertext_cleartext_vector_fusing_multiplication;
+pub use glwe_ciphertext_cleartext_vector_fusing_multiplication::*;
+
+mod glwe_ciphertext_discarding_keyswitch;
+pub use glwe_ciphertext_discarding_keyswitch::*;
+
+mod glwe_keyswitch_key_generation;
+pub use glwe_keyswitch_key_generation::*;
+
+mod glwe_seeded_keyswitch_key_generation;
+pub use glwe_seeded_keyswitch_key_generation::*;
+
+mod glwe_seeded_keyswitch_key_to_glwe_keyswitch_key_transformation;
+pub use glwe_seeded_keyswitch_key_to_glwe_keyswitch_key_transformation::*;
+
+mod gsw_ciphertext_scalar_encryption;
+pub use gsw_ciphertext_scalar_encryption::*;
+
+mod lwe_ciphertext_gsw_ciphertext_discarding_external_product;
+pub use lwe_ciphertext_gsw_ciphertext_discarding_external_product::*;
+
+mod lwe_ciphertexts_gsw_ciphertext_fusing_cmux;
+pub use lwe_ciphertexts_gsw_ciphertext_fusing_cmux::*;
+
+mod glwe_public_key_generation;
+pub use glwe_public_key_generation::*;
+
+mod glwe_ciphertext_discarding_public_key_encryption;
+pub use glwe_ciphertext_discarding_public_key_encryption::*;
+
+mod lwe_ciphertext_discarding_glwe_public_key_encryption;
+pub use lwe_ciphertext_discarding_glwe_public_key_encryption::*;
+
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_sample_extraction;
+pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_sample_extraction::*;
+
+mod lwe_ciphertext_vector_glwe_ciphertext_vector_discarding_sample_extraction;
+pub use lwe_ciphertext_vector_glwe_ciphertext_vector_discarding_sample_extraction::*;
+
+mod lwe_seeded_packing_keyswitch_key_generation;
+pub use lwe_seeded_packing_keyswitch_key_generation::*;
+
+mod lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_transformation;
+pub use lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_transformation::*;
+
+mod lwe_seeded_private_functional_packing_keyswitch_key_generation;
+pub use lwe_seeded_private_functional_packing_keyswitch_key_generation::*;
+
+mod lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key_transformation;
+pub use lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key_transformation::*;
+
+mod lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
+pub use lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation::*;
+
+mod lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_transformation;
+pub use lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_transformation::*;
+
+mod lwe_seeded_public_key_generation;
+pub use lwe_seeded_public_key_generation::*;
+
+mod lwe_seeded_public_key_to_lwe_public_key_transformation;
+pub use lwe_seeded_public_key_to_lwe_public_key_transformation::*;
+
+mod lwe_seeded_ciphertext_vector_ciphertext_expansion;
+pub use lwe_seeded_ciphertext_vector_ciphertext_expansion::*;
+
+mod lwe_seeded_ciphertext_vector_range_expansion;
+pub use lwe_seeded_ciphertext_vector_range_expansion::*;
+
+mod lwe_seeded_keyswitch_key_range_expansion;
+pub use lwe_seeded_keyswitch_key_range_expansion::*;
+
+mod lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean_vertical_packing;
+pub use lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean_vertical_packing::*;
+
+mod lwe_ciphertext_modulus_switching;
+pub use lwe_ciphertext_modulus_switching::*;
+
+mod lwe_ciphertext_vector_modulus_switching;
+pub use lwe_ciphertext_vector_modulus_switching::*;
+
+mod lwe_compressed_ciphertext_vector_decryption;
+pub use lwe_compressed_ciphertext_vector_decryption::*;
+
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_automorphism_packing;
+pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_automorphism_packing::*;
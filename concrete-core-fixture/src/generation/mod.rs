@@ -25,7 +25,9 @@
 //! [`Maker`] instance and the `Synthesizes*` traits, which contains functions to destroy data
 //! (which are empty for all entities that are not actually views).
 use crate::raw::generation::RawUnsignedIntegers;
-use concrete_core::prelude::{AbstractEngine, DefaultEngine, DefaultParallelEngine};
+use concrete_core::prelude::{
+    AbstractEngine, DefaultEngine, DefaultParallelEngine, DefaultSerializationEngine,
+};
 use concrete_csprng::seeders::UnixSeeder;
 
 pub mod prototypes;
@@ -64,6 +66,12 @@ impl IntegerPrecision for Precision64 {
     type Raw = u64;
 }
 
+/// A type representing the 128 bits precision for integers.
+pub struct Precision128;
+impl IntegerPrecision for Precision128 {
+    type Raw = u128;
+}
+
 /// The central structure used to generate the pre-execution context for all the fixtures.
 ///
 /// This structure contains the necessary tools to:
@@ -75,6 +83,7 @@ impl IntegerPrecision for Precision64 {
 pub struct Maker {
     default_engine: DefaultEngine,
     default_parallel_engine: DefaultParallelEngine,
+    default_serialization_engine: DefaultSerializationEngine,
     #[cfg(feature = "backend_fftw")]
     fftw_engine: concrete_core::backends::fftw::engines::FftwEngine,
     #[cfg(feature = "backend_fft")]
@@ -89,6 +98,7 @@ impl Default for Maker {
             default_engine: DefaultEngine::new(Box::new(UnixSeeder::new(0))).unwrap(),
             default_parallel_engine: DefaultParallelEngine::new(Box::new(UnixSeeder::new(0)))
                 .unwrap(),
+            default_serialization_engine: DefaultSerializationEngine::new(()).unwrap(),
             #[cfg(feature = "backend_fftw")]
             fftw_engine: concrete_core::backends::fftw::engines::FftwEngine::new(()).unwrap(),
             #[cfg(feature = "backend_fft")]
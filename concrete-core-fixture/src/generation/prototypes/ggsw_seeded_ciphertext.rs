@@ -0,0 +1,24 @@
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+};
+use concrete_core::prelude::{GgswSeededCiphertext32, GgswSeededCiphertext64};
+
+/// A trait implemented by seeded ggsw ciphertext prototypes.
+pub trait GgswSeededCiphertextPrototype {
+    type KeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary seeded ggsw ciphertext entity.
+pub struct ProtoBinaryGgswSeededCiphertext32(pub(crate) GgswSeededCiphertext32);
+impl GgswSeededCiphertextPrototype for ProtoBinaryGgswSeededCiphertext32 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary seeded ggsw ciphertext entity.
+pub struct ProtoBinaryGgswSeededCiphertext64(pub(crate) GgswSeededCiphertext64);
+impl GgswSeededCiphertextPrototype for ProtoBinaryGgswSeededCiphertext64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
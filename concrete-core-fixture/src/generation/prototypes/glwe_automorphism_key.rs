@@ -0,0 +1,24 @@
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+};
+use concrete_core::prelude::{GlweAutomorphismKey32, GlweAutomorphismKey64};
+
+/// A trait implemented by glwe automorphism key prototypes.
+pub trait GlweAutomorphismKeyPrototype {
+    type KeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary glwe automorphism key entity.
+pub struct ProtoBinaryGlweAutomorphismKey32(pub(crate) GlweAutomorphismKey32);
+impl GlweAutomorphismKeyPrototype for ProtoBinaryGlweAutomorphismKey32 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary glwe automorphism key entity.
+pub struct ProtoBinaryGlweAutomorphismKey64(pub(crate) GlweAutomorphismKey64);
+impl GlweAutomorphismKeyPrototype for ProtoBinaryGlweAutomorphismKey64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
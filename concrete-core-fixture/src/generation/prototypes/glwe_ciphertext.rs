@@ -1,7 +1,8 @@
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision128, Precision32,
+    Precision64,
 };
-use concrete_core::prelude::{GlweCiphertext32, GlweCiphertext64};
+use concrete_core::prelude::{GlweCiphertext128, GlweCiphertext32, GlweCiphertext64};
 
 /// A trait implemented by glwe ciphertext prototypes.
 pub trait GlweCiphertextPrototype {
@@ -22,3 +23,10 @@ impl GlweCiphertextPrototype for ProtoBinaryGlweCiphertext64 {
     type KeyDistribution = BinaryKeyDistribution;
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 128 bit binary glwe ciphertext entity.
+pub struct ProtoBinaryGlweCiphertext128(pub(crate) GlweCiphertext128);
+impl GlweCiphertextPrototype for ProtoBinaryGlweCiphertext128 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision128;
+}
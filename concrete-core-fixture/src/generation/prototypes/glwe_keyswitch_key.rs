@@ -0,0 +1,24 @@
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+};
+use concrete_core::prelude::{GlweKeyswitchKey32, GlweKeyswitchKey64};
+
+/// A trait implemented by glwe keyswitch key prototypes.
+pub trait GlweKeyswitchKeyPrototype {
+    type KeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary glwe keyswitch key entity.
+pub struct ProtoBinaryGlweKeyswitchKey32(pub(crate) GlweKeyswitchKey32);
+impl GlweKeyswitchKeyPrototype for ProtoBinaryGlweKeyswitchKey32 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary glwe keyswitch key entity.
+pub struct ProtoBinaryGlweKeyswitchKey64(pub(crate) GlweKeyswitchKey64);
+impl GlweKeyswitchKeyPrototype for ProtoBinaryGlweKeyswitchKey64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
@@ -0,0 +1,24 @@
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+};
+use concrete_core::prelude::{GlwePublicKey32, GlwePublicKey64};
+
+/// A trait implemented by glwe public key prototypes.
+pub trait GlwePublicKeyPrototype {
+    type KeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary glwe public key entity.
+pub struct ProtoBinaryGlwePublicKey32(pub(crate) GlwePublicKey32);
+impl GlwePublicKeyPrototype for ProtoBinaryGlwePublicKey32 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary glwe public key entity.
+pub struct ProtoBinaryGlwePublicKey64(pub(crate) GlwePublicKey64);
+impl GlwePublicKeyPrototype for ProtoBinaryGlwePublicKey64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
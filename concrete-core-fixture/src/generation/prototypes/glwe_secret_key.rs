@@ -1,7 +1,8 @@
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision128, Precision32,
+    Precision64,
 };
-use concrete_core::prelude::{GlweSecretKey32, GlweSecretKey64};
+use concrete_core::prelude::{GlweSecretKey128, GlweSecretKey32, GlweSecretKey64};
 
 /// A trait implemented by glwe secret key prototypes.
 pub trait GlweSecretKeyPrototype: PartialEq {
@@ -24,3 +25,11 @@ impl GlweSecretKeyPrototype for ProtoBinaryGlweSecretKey64 {
     type KeyDistribution = BinaryKeyDistribution;
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 128 bit binary glwe secret key entity.
+#[derive(PartialEq, Eq)]
+pub struct ProtoBinaryGlweSecretKey128(pub(crate) GlweSecretKey128);
+impl GlweSecretKeyPrototype for ProtoBinaryGlweSecretKey128 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision128;
+}
@@ -0,0 +1,24 @@
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+};
+use concrete_core::prelude::{GlweSeededKeyswitchKey32, GlweSeededKeyswitchKey64};
+
+/// A trait implemented by seeded glwe keyswitch key prototypes.
+pub trait GlweSeededKeyswitchKeyPrototype {
+    type KeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary seeded glwe keyswitch key entity.
+pub struct ProtoBinaryGlweSeededKeyswitchKey32(pub(crate) GlweSeededKeyswitchKey32);
+impl GlweSeededKeyswitchKeyPrototype for ProtoBinaryGlweSeededKeyswitchKey32 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary seeded glwe keyswitch key entity.
+pub struct ProtoBinaryGlweSeededKeyswitchKey64(pub(crate) GlweSeededKeyswitchKey64);
+impl GlweSeededKeyswitchKeyPrototype for ProtoBinaryGlweSeededKeyswitchKey64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
@@ -1,7 +1,11 @@
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision128, Precision32,
+    Precision64, TernaryKeyDistribution,
+};
+use concrete_core::prelude::{
+    LweBootstrapKey128, LweBootstrapKey32, LweBootstrapKey64, LweTernaryBootstrapKey32,
+    LweTernaryBootstrapKey64,
 };
-use concrete_core::prelude::{LweBootstrapKey32, LweBootstrapKey64};
 
 /// A trait implemented by lwe bootstrap key prototypes.
 pub trait LweBootstrapKeyPrototype {
@@ -25,3 +29,27 @@ impl LweBootstrapKeyPrototype for ProtoBinaryBinaryLweBootstrapKey64 {
     type OutputKeyDistribution = BinaryKeyDistribution;
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 128 bit binary to binary lwe bootstrap key entity.
+pub struct ProtoBinaryBinaryLweBootstrapKey128(pub(crate) LweBootstrapKey128);
+impl LweBootstrapKeyPrototype for ProtoBinaryBinaryLweBootstrapKey128 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision128;
+}
+
+/// A type representing the prototype of a 32 bit ternary to binary lwe bootstrap key entity.
+pub struct ProtoTernaryBinaryLweBootstrapKey32(pub(crate) LweTernaryBootstrapKey32);
+impl LweBootstrapKeyPrototype for ProtoTernaryBinaryLweBootstrapKey32 {
+    type InputKeyDistribution = TernaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit ternary to binary lwe bootstrap key entity.
+pub struct ProtoTernaryBinaryLweBootstrapKey64(pub(crate) LweTernaryBootstrapKey64);
+impl LweBootstrapKeyPrototype for ProtoTernaryBinaryLweBootstrapKey64 {
+    type InputKeyDistribution = TernaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
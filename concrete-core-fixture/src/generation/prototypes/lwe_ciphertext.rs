@@ -1,7 +1,8 @@
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision128, Precision32,
+    Precision64, TernaryKeyDistribution,
 };
-use concrete_core::prelude::{LweCiphertext32, LweCiphertext64};
+use concrete_core::prelude::{LweCiphertext128, LweCiphertext32, LweCiphertext64};
 
 /// A trait implemented by lwe ciphertext prototypes.
 pub trait LweCiphertextPrototype {
@@ -22,3 +23,24 @@ impl LweCiphertextPrototype for ProtoBinaryLweCiphertext64 {
     type KeyDistribution = BinaryKeyDistribution;
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 128 bit binary lwe ciphertext entity.
+pub struct ProtoBinaryLweCiphertext128(pub(crate) LweCiphertext128);
+impl LweCiphertextPrototype for ProtoBinaryLweCiphertext128 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision128;
+}
+
+/// A type representing the prototype of a 32 bit ternary lwe ciphertext entity.
+pub struct ProtoTernaryLweCiphertext32(pub(crate) LweCiphertext32);
+impl LweCiphertextPrototype for ProtoTernaryLweCiphertext32 {
+    type KeyDistribution = TernaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit ternary lwe ciphertext entity.
+pub struct ProtoTernaryLweCiphertext64(pub(crate) LweCiphertext64);
+impl LweCiphertextPrototype for ProtoTernaryLweCiphertext64 {
+    type KeyDistribution = TernaryKeyDistribution;
+    type Precision = Precision64;
+}
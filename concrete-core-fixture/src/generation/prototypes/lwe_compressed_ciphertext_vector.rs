@@ -0,0 +1,24 @@
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+};
+use concrete_core::prelude::{LweCompressedCiphertextVector32, LweCompressedCiphertextVector64};
+
+/// A trait implemented by LWE compressed ciphertext vector prototypes.
+pub trait LweCompressedCiphertextVectorPrototype {
+    type KeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary LWE compressed ciphertext vector entity.
+pub struct ProtoBinaryLweCompressedCiphertextVector32(pub(crate) LweCompressedCiphertextVector32);
+impl LweCompressedCiphertextVectorPrototype for ProtoBinaryLweCompressedCiphertextVector32 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary LWE compressed ciphertext vector entity.
+pub struct ProtoBinaryLweCompressedCiphertextVector64(pub(crate) LweCompressedCiphertextVector64);
+impl LweCompressedCiphertextVectorPrototype for ProtoBinaryLweCompressedCiphertextVector64 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
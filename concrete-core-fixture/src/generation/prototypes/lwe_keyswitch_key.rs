@@ -1,5 +1,6 @@
 use crate::generation::{
     BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+    TernaryKeyDistribution,
 };
 use concrete_core::prelude::{LweKeyswitchKey32, LweKeyswitchKey64};
 
@@ -25,3 +26,19 @@ impl LweKeyswitchKeyPrototype for ProtoBinaryBinaryLweKeyswitchKey64 {
     type OutputKeyDistribution = BinaryKeyDistribution;
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 32 bit binary to ternary lwe keyswitch key entity.
+pub struct ProtoBinaryTernaryLweKeyswitchKey32(pub(crate) LweKeyswitchKey32);
+impl LweKeyswitchKeyPrototype for ProtoBinaryTernaryLweKeyswitchKey32 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = TernaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary to ternary lwe keyswitch key entity.
+pub struct ProtoBinaryTernaryLweKeyswitchKey64(pub(crate) LweKeyswitchKey64);
+impl LweKeyswitchKeyPrototype for ProtoBinaryTernaryLweKeyswitchKey64 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = TernaryKeyDistribution;
+    type Precision = Precision64;
+}
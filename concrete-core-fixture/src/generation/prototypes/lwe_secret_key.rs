@@ -1,7 +1,9 @@
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision128, Precision32,
+    Precision64, TernaryKeyDistribution,
 };
-use concrete_core::prelude::{LweSecretKey32, LweSecretKey64};
+use concrete_commons::key_kinds::TernaryKeyKind;
+use concrete_core::prelude::{LweSecretKey128, LweSecretKey32, LweSecretKey64};
 
 /// A trait implemented by lwe secret key prototypes.
 pub trait LweSecretKeyPrototype: PartialEq {
@@ -24,3 +26,27 @@ impl LweSecretKeyPrototype for ProtoBinaryLweSecretKey64 {
     type KeyDistribution = BinaryKeyDistribution;
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 128 bit binary lwe secret key entity.
+#[derive(PartialEq, Eq)]
+pub struct ProtoBinaryLweSecretKey128(pub(crate) LweSecretKey128);
+impl LweSecretKeyPrototype for ProtoBinaryLweSecretKey128 {
+    type KeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision128;
+}
+
+/// A type representing the prototype of a 32 bit ternary lwe secret key entity.
+#[derive(PartialEq, Eq)]
+pub struct ProtoTernaryLweSecretKey32(pub(crate) LweSecretKey32<TernaryKeyKind>);
+impl LweSecretKeyPrototype for ProtoTernaryLweSecretKey32 {
+    type KeyDistribution = TernaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit ternary lwe secret key entity.
+#[derive(PartialEq, Eq)]
+pub struct ProtoTernaryLweSecretKey64(pub(crate) LweSecretKey64<TernaryKeyKind>);
+impl LweSecretKeyPrototype for ProtoTernaryLweSecretKey64 {
+    type KeyDistribution = TernaryKeyDistribution;
+    type Precision = Precision64;
+}
@@ -0,0 +1,33 @@
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+};
+use concrete_core::prelude::{LweSeededPackingKeyswitchKey32, LweSeededPackingKeyswitchKey64};
+
+/// A trait implemented by seeded packing keyswitch key prototypes.
+pub trait LweSeededPackingKeyswitchKeyPrototype {
+    type InputKeyDistribution: KeyDistributionMarker;
+    type OutputKeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary to binary seeded packing keyswitch key
+/// entity.
+pub struct ProtoBinaryBinaryLweSeededPackingKeyswitchKey32(
+    pub(crate) LweSeededPackingKeyswitchKey32,
+);
+impl LweSeededPackingKeyswitchKeyPrototype for ProtoBinaryBinaryLweSeededPackingKeyswitchKey32 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary to binary seeded packing keyswitch key
+/// entity.
+pub struct ProtoBinaryBinaryLweSeededPackingKeyswitchKey64(
+    pub(crate) LweSeededPackingKeyswitchKey64,
+);
+impl LweSeededPackingKeyswitchKeyPrototype for ProtoBinaryBinaryLweSeededPackingKeyswitchKey64 {
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
@@ -0,0 +1,40 @@
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Precision32, Precision64,
+};
+use concrete_core::prelude::{
+    LweSeededPrivateFunctionalPackingKeyswitchKey32,
+    LweSeededPrivateFunctionalPackingKeyswitchKey64,
+};
+
+/// A trait implemented by seeded private functional packing keyswitch key prototypes.
+pub trait LweSeededPrivateFunctionalPackingKeyswitchKeyPrototype {
+    type InputKeyDistribution: KeyDistributionMarker;
+    type OutputKeyDistribution: KeyDistributionMarker;
+    type Precision: IntegerPrecision;
+}
+
+/// A type representing the prototype of a 32 bit binary seeded private functional packing
+/// keyswitch key entity.
+pub struct ProtoBinaryBinaryLweSeededPrivateFunctionalPackingKeyswitchKey32(
+    pub(crate) LweSeededPrivateFunctionalPackingKeyswitchKey32,
+);
+impl LweSeededPrivateFunctionalPackingKeyswitchKeyPrototype
+    for ProtoBinaryBinaryLweSeededPrivateFunctionalPackingKeyswitchKey32
+{
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision32;
+}
+
+/// A type representing the prototype of a 64 bit binary seeded private functional packing
+/// keyswitch key entity.
+pub struct ProtoBinaryBinaryLweSeededPrivateFunctionalPackingKeyswitchKey64(
+    pub(crate) LweSeededPrivateFunctionalPackingKeyswitchKey64,
+);
+impl LweSeededPrivateFunctionalPackingKeyswitchKeyPrototype
+    for ProtoBinaryBinaryLweSeededPrivateFunctionalPackingKeyswitchKey64
+{
+    type InputKeyDistribution = BinaryKeyDistribution;
+    type OutputKeyDistribution = BinaryKeyDistribution;
+    type Precision = Precision64;
+}
@@ -1,5 +1,5 @@
-use crate::generation::{IntegerPrecision, Precision32, Precision64};
-use concrete_core::prelude::{Plaintext32, Plaintext64};
+use crate::generation::{IntegerPrecision, Precision128, Precision32, Precision64};
+use concrete_core::prelude::{Plaintext128, Plaintext32, Plaintext64};
 
 /// A trait implemented by plaintext prototypes.
 pub trait PlaintextPrototype {
@@ -17,3 +17,9 @@ pub struct ProtoPlaintext64(pub(crate) Plaintext64);
 impl PlaintextPrototype for ProtoPlaintext64 {
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 128 bit plaintext entity.
+pub struct ProtoPlaintext128(pub(crate) Plaintext128);
+impl PlaintextPrototype for ProtoPlaintext128 {
+    type Precision = Precision128;
+}
@@ -1,5 +1,5 @@
-use crate::generation::{IntegerPrecision, Precision32, Precision64};
-use concrete_core::prelude::{PlaintextVector32, PlaintextVector64};
+use crate::generation::{IntegerPrecision, Precision128, Precision32, Precision64};
+use concrete_core::prelude::{PlaintextVector128, PlaintextVector32, PlaintextVector64};
 
 /// A trait implemented by plaintext vector prototypes.
 pub trait PlaintextVectorPrototype {
@@ -17,3 +17,9 @@ pub struct ProtoPlaintextVector64(pub(crate) PlaintextVector64);
 impl PlaintextVectorPrototype for ProtoPlaintextVector64 {
     type Precision = Precision64;
 }
+
+/// A type representing the prototype of a 128 bit plaintext vector entity.
+pub struct ProtoPlaintextVector128(pub(crate) PlaintextVector128);
+impl PlaintextVectorPrototype for ProtoPlaintextVector128 {
+    type Precision = Precision128;
+}
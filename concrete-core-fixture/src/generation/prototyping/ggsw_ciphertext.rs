@@ -1,6 +1,7 @@
 use crate::generation::prototypes::{
     GgswCiphertextPrototype, ProtoBinaryGgswCiphertext32, ProtoBinaryGgswCiphertext64,
 };
+use crate::generation::prototyping::glwe_ciphertext::PrototypesGlweCiphertext;
 use crate::generation::prototyping::glwe_secret_key::PrototypesGlweSecretKey;
 use crate::generation::prototyping::plaintext::PrototypesPlaintext;
 use crate::generation::{
@@ -183,3 +184,93 @@ impl PrototypesGgswCiphertext<Precision64, BinaryKeyDistribution> for Maker {
         )
     }
 }
+
+/// A trait allowing to compute the external product of GLWE and GGSW ciphertext prototypes, which is
+/// used to check the messages of GGSW ciphertexts computed by the engines.
+pub trait PrototypesGlweCiphertextGgswCiphertextExternalProduct<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>:
+    PrototypesGgswCiphertext<Precision, KeyDistribution>
+    + PrototypesGlweCiphertext<Precision, KeyDistribution>
+{
+    fn compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_ciphertext: &Self::GlweCiphertextProto,
+        ggsw_ciphertext: &Self::GgswCiphertextProto,
+    ) -> Self::GlweCiphertextProto;
+}
+
+#[cfg(feature = "backend_fft")]
+mod backend_fft {
+    use super::PrototypesGlweCiphertextGgswCiphertextExternalProduct;
+    use crate::generation::prototypes::{ProtoBinaryGlweCiphertext32, ProtoBinaryGlweCiphertext64};
+    use crate::generation::prototyping::PrototypesGlweCiphertext;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        FftFourierGgswCiphertext32, FftFourierGgswCiphertext64, GgswCiphertextConversionEngine,
+        GlweCiphertextEntity, GlweCiphertextGgswCiphertextDiscardingExternalProductEngine,
+    };
+
+    impl PrototypesGlweCiphertextGgswCiphertextExternalProduct<Precision32, BinaryKeyDistribution>
+        for Maker
+    {
+        fn compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+            &mut self,
+            glwe_ciphertext: &Self::GlweCiphertextProto,
+            ggsw_ciphertext: &Self::GgswCiphertextProto,
+        ) -> Self::GlweCiphertextProto {
+            let ggsw_ciphertext: FftFourierGgswCiphertext32 = self
+                .fft_engine
+                .convert_ggsw_ciphertext(&ggsw_ciphertext.0)
+                .unwrap();
+            let ProtoBinaryGlweCiphertext32(mut output) = <Self as PrototypesGlweCiphertext<
+                Precision32,
+                BinaryKeyDistribution,
+            >>::trivially_encrypt_zeros_to_glwe_ciphertext(
+                self,
+                glwe_ciphertext.0.glwe_dimension(),
+                glwe_ciphertext.0.polynomial_size(),
+            );
+            self.fft_engine
+                .discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+                    &glwe_ciphertext.0,
+                    &ggsw_ciphertext,
+                    &mut output,
+                )
+                .unwrap();
+            ProtoBinaryGlweCiphertext32(output)
+        }
+    }
+
+    impl PrototypesGlweCiphertextGgswCiphertextExternalProduct<Precision64, BinaryKeyDistribution>
+        for Maker
+    {
+        fn compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+            &mut self,
+            glwe_ciphertext: &Self::GlweCiphertextProto,
+            ggsw_ciphertext: &Self::GgswCiphertextProto,
+        ) -> Self::GlweCiphertextProto {
+            let ggsw_ciphertext: FftFourierGgswCiphertext64 = self
+                .fft_engine
+                .convert_ggsw_ciphertext(&ggsw_ciphertext.0)
+                .unwrap();
+            let ProtoBinaryGlweCiphertext64(mut output) = <Self as PrototypesGlweCiphertext<
+                Precision64,
+                BinaryKeyDistribution,
+            >>::trivially_encrypt_zeros_to_glwe_ciphertext(
+                self,
+                glwe_ciphertext.0.glwe_dimension(),
+                glwe_ciphertext.0.polynomial_size(),
+            );
+            self.fft_engine
+                .discard_compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+                    &glwe_ciphertext.0,
+                    &ggsw_ciphertext,
+                    &mut output,
+                )
+                .unwrap();
+            ProtoBinaryGlweCiphertext64(output)
+        }
+    }
+}
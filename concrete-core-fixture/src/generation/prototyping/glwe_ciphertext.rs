@@ -1,11 +1,13 @@
 use crate::generation::prototypes::{
-    GlweCiphertextPrototype, ProtoBinaryGlweCiphertext32, ProtoBinaryGlweCiphertext64,
-    ProtoPlaintextVector32, ProtoPlaintextVector64,
+    GlweCiphertextPrototype, ProtoBinaryGlweCiphertext128, ProtoBinaryGlweCiphertext32,
+    ProtoBinaryGlweCiphertext64, ProtoPlaintextVector128, ProtoPlaintextVector32,
+    ProtoPlaintextVector64,
 };
 use crate::generation::prototyping::glwe_secret_key::PrototypesGlweSecretKey;
 use crate::generation::prototyping::plaintext_vector::PrototypesPlaintextVector;
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision128,
+    Precision32, Precision64,
 };
 use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::{GlweDimension, PolynomialSize};
@@ -256,3 +258,88 @@ impl PrototypesGlweCiphertext<Precision64, BinaryKeyDistribution> for Maker {
         )
     }
 }
+
+impl PrototypesGlweCiphertext<Precision128, BinaryKeyDistribution> for Maker {
+    type GlweCiphertextProto = ProtoBinaryGlweCiphertext128;
+
+    fn trivially_encrypt_zeros_to_glwe_ciphertext(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto {
+        let plaintext_vector = self
+            .default_engine
+            .create_plaintext_vector_from(&vec![0u128; poly_size.0])
+            .unwrap();
+        ProtoBinaryGlweCiphertext128(
+            self.default_engine
+                .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)
+                .unwrap(),
+        )
+    }
+
+    fn trivially_encrypt_plaintext_vector_to_glwe_ciphertext(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        plaintext_vector: &Self::PlaintextVectorProto,
+    ) -> Self::GlweCiphertextProto {
+        ProtoBinaryGlweCiphertext128(
+            self.default_engine
+                .trivially_encrypt_glwe_ciphertext(
+                    glwe_dimension.to_glwe_size(),
+                    &plaintext_vector.0,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn trivially_decrypt_glwe_ciphertext(
+        &mut self,
+        _ciphertext: &Self::GlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto {
+        // FIXME:
+        unimplemented!("The 128 bit engines needed by this function were not yet implemented")
+    }
+
+    fn encrypt_plaintext_vector_to_glwe_ciphertext(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+        noise: Variance,
+    ) -> Self::GlweCiphertextProto {
+        ProtoBinaryGlweCiphertext128(
+            self.default_engine
+                .encrypt_glwe_ciphertext(&secret_key.0, &plaintext_vector.0, noise)
+                .unwrap(),
+        )
+    }
+
+    fn decrypt_glwe_ciphertext_to_plaintext_vector(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        ciphertext: &Self::GlweCiphertextProto,
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector128(
+            self.default_engine
+                .decrypt_glwe_ciphertext(&secret_key.0, &ciphertext.0)
+                .unwrap(),
+        )
+    }
+
+    fn transform_raw_vec_to_glwe_ciphertext(
+        &mut self,
+        _raw: &[u128],
+        _polynomial_size: PolynomialSize,
+    ) -> Self::GlweCiphertextProto {
+        // FIXME:
+        unimplemented!("The 128 bit engines needed by this function were not yet implemented")
+    }
+
+    fn transform_glwe_ciphertext_to_raw_vec(
+        &mut self,
+        _ciphertext: &Self::GlweCiphertextProto,
+    ) -> (Vec<u128>, PolynomialSize) {
+        // FIXME:
+        unimplemented!("The 128 bit engines needed by this function were not yet implemented")
+    }
+}
@@ -0,0 +1,129 @@
+use crate::generation::prototypes::{
+    GlweKeyswitchKeyPrototype, ProtoBinaryGlweCiphertext32, ProtoBinaryGlweCiphertext64,
+    ProtoBinaryGlweKeyswitchKey32, ProtoBinaryGlweKeyswitchKey64,
+};
+use crate::generation::prototyping::{PrototypesGlweCiphertext, PrototypesGlweSecretKey};
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_core::prelude::{
+    GlweCiphertextDiscardingKeyswitchEngine, GlweCiphertextEntity, GlweKeyswitchKeyEntity,
+    GlweKeyswitchKeyGenerationEngine,
+};
+
+/// A trait allowing to manipulate glwe keyswitch key prototypes.
+pub trait PrototypesGlweKeyswitchKey<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>:
+    PrototypesGlweSecretKey<Precision, KeyDistribution>
+    + PrototypesGlweCiphertext<Precision, KeyDistribution>
+{
+    type GlweKeyswitchKeyProto: GlweKeyswitchKeyPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn new_glwe_keyswitch_key(
+        &mut self,
+        input_key: &Self::GlweSecretKeyProto,
+        output_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweKeyswitchKeyProto;
+    fn keyswitch_glwe_ciphertext(
+        &mut self,
+        input: &Self::GlweCiphertextProto,
+        keyswitch_key: &Self::GlweKeyswitchKeyProto,
+    ) -> Self::GlweCiphertextProto;
+}
+
+impl PrototypesGlweKeyswitchKey<Precision32, BinaryKeyDistribution> for Maker {
+    type GlweKeyswitchKeyProto = ProtoBinaryGlweKeyswitchKey32;
+
+    fn new_glwe_keyswitch_key(
+        &mut self,
+        input_key: &Self::GlweSecretKeyProto,
+        output_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweKeyswitchKeyProto {
+        ProtoBinaryGlweKeyswitchKey32(
+            self.default_engine
+                .generate_new_glwe_keyswitch_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn keyswitch_glwe_ciphertext(
+        &mut self,
+        input: &Self::GlweCiphertextProto,
+        keyswitch_key: &Self::GlweKeyswitchKeyProto,
+    ) -> Self::GlweCiphertextProto {
+        let ProtoBinaryGlweCiphertext32(mut output) = <Self as PrototypesGlweCiphertext<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zeros_to_glwe_ciphertext(
+            self,
+            keyswitch_key.0.output_glwe_dimension(),
+            input.0.polynomial_size(),
+        );
+        self.default_engine
+            .discard_keyswitch_glwe_ciphertext(&mut output, &input.0, &keyswitch_key.0)
+            .unwrap();
+        ProtoBinaryGlweCiphertext32(output)
+    }
+}
+
+impl PrototypesGlweKeyswitchKey<Precision64, BinaryKeyDistribution> for Maker {
+    type GlweKeyswitchKeyProto = ProtoBinaryGlweKeyswitchKey64;
+
+    fn new_glwe_keyswitch_key(
+        &mut self,
+        input_key: &Self::GlweSecretKeyProto,
+        output_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweKeyswitchKeyProto {
+        ProtoBinaryGlweKeyswitchKey64(
+            self.default_engine
+                .generate_new_glwe_keyswitch_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn keyswitch_glwe_ciphertext(
+        &mut self,
+        input: &Self::GlweCiphertextProto,
+        keyswitch_key: &Self::GlweKeyswitchKeyProto,
+    ) -> Self::GlweCiphertextProto {
+        let ProtoBinaryGlweCiphertext64(mut output) = <Self as PrototypesGlweCiphertext<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zeros_to_glwe_ciphertext(
+            self,
+            keyswitch_key.0.output_glwe_dimension(),
+            input.0.polynomial_size(),
+        );
+        self.default_engine
+            .discard_keyswitch_glwe_ciphertext(&mut output, &input.0, &keyswitch_key.0)
+            .unwrap();
+        ProtoBinaryGlweCiphertext64(output)
+    }
+}
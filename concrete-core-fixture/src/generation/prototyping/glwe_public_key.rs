@@ -0,0 +1,132 @@
+use crate::generation::prototypes::{
+    GlwePublicKeyPrototype, ProtoBinaryGlweCiphertext32, ProtoBinaryGlweCiphertext64,
+    ProtoBinaryGlwePublicKey32, ProtoBinaryGlwePublicKey64,
+};
+use crate::generation::prototyping::{PrototypesGlweCiphertext, PrototypesGlweSecretKey};
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::GlwePublicKeyZeroEncryptionCount;
+use concrete_core::prelude::{
+    GlweCiphertextDiscardingPublicKeyEncryptionEngine, GlwePublicKeyEntity,
+    GlwePublicKeyGenerationEngine,
+};
+
+/// A trait allowing to manipulate glwe public key prototypes.
+pub trait PrototypesGlwePublicKey<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>:
+    PrototypesGlweSecretKey<Precision, KeyDistribution>
+    + PrototypesGlweCiphertext<Precision, KeyDistribution>
+{
+    type GlwePublicKeyProto: GlwePublicKeyPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn new_glwe_public_key(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        noise: Variance,
+        glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount,
+    ) -> Self::GlwePublicKeyProto;
+    fn encrypt_plaintext_vector_to_glwe_ciphertext_with_public_key(
+        &mut self,
+        public_key: &Self::GlwePublicKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+        noise: Variance,
+    ) -> Self::GlweCiphertextProto;
+}
+
+impl PrototypesGlwePublicKey<Precision32, BinaryKeyDistribution> for Maker {
+    type GlwePublicKeyProto = ProtoBinaryGlwePublicKey32;
+
+    fn new_glwe_public_key(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        noise: Variance,
+        glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount,
+    ) -> Self::GlwePublicKeyProto {
+        ProtoBinaryGlwePublicKey32(
+            self.default_engine
+                .generate_new_glwe_public_key(
+                    &secret_key.0,
+                    noise,
+                    glwe_public_key_zero_encryption_count,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn encrypt_plaintext_vector_to_glwe_ciphertext_with_public_key(
+        &mut self,
+        public_key: &Self::GlwePublicKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+        noise: Variance,
+    ) -> Self::GlweCiphertextProto {
+        let ProtoBinaryGlweCiphertext32(mut output) = <Self as PrototypesGlweCiphertext<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zeros_to_glwe_ciphertext(
+            self,
+            public_key.0.glwe_dimension(),
+            public_key.0.polynomial_size(),
+        );
+        self.default_engine
+            .discard_encrypt_glwe_ciphertext_with_public_key(
+                &public_key.0,
+                &mut output,
+                &plaintext_vector.0,
+                noise,
+            )
+            .unwrap();
+        ProtoBinaryGlweCiphertext32(output)
+    }
+}
+
+impl PrototypesGlwePublicKey<Precision64, BinaryKeyDistribution> for Maker {
+    type GlwePublicKeyProto = ProtoBinaryGlwePublicKey64;
+
+    fn new_glwe_public_key(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        noise: Variance,
+        glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount,
+    ) -> Self::GlwePublicKeyProto {
+        ProtoBinaryGlwePublicKey64(
+            self.default_engine
+                .generate_new_glwe_public_key(
+                    &secret_key.0,
+                    noise,
+                    glwe_public_key_zero_encryption_count,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn encrypt_plaintext_vector_to_glwe_ciphertext_with_public_key(
+        &mut self,
+        public_key: &Self::GlwePublicKeyProto,
+        plaintext_vector: &Self::PlaintextVectorProto,
+        noise: Variance,
+    ) -> Self::GlweCiphertextProto {
+        let ProtoBinaryGlweCiphertext64(mut output) = <Self as PrototypesGlweCiphertext<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zeros_to_glwe_ciphertext(
+            self,
+            public_key.0.glwe_dimension(),
+            public_key.0.polynomial_size(),
+        );
+        self.default_engine
+            .discard_encrypt_glwe_ciphertext_with_public_key(
+                &public_key.0,
+                &mut output,
+                &plaintext_vector.0,
+                noise,
+            )
+            .unwrap();
+        ProtoBinaryGlweCiphertext64(output)
+    }
+}
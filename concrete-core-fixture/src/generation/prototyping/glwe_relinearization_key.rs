@@ -0,0 +1,159 @@
+use crate::generation::prototypes::{
+    GlweRelinearizationKeyPrototype, ProtoBinaryGlweCiphertext32, ProtoBinaryGlweCiphertext64,
+    ProtoBinaryGlweRelinearizationKey32, ProtoBinaryGlweRelinearizationKey64,
+};
+use crate::generation::prototyping::{PrototypesGlweCiphertext, PrototypesGlweSecretKey};
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, DeltaLog};
+use concrete_core::prelude::{
+    GlweCiphertextDiscardingRelinearizationEngine, GlweCiphertextEntity,
+    GlweCiphertextTensorProductEngine, GlweRelinearizationKeyEntity,
+    GlweRelinearizationKeyGenerationEngine,
+};
+
+/// A trait allowing to manipulate glwe relinearization key prototypes.
+pub trait PrototypesGlweRelinearizationKey<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>:
+    PrototypesGlweSecretKey<Precision, KeyDistribution>
+    + PrototypesGlweCiphertext<Precision, KeyDistribution>
+{
+    type GlweRelinearizationKeyProto: GlweRelinearizationKeyPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn new_glwe_relinearization_key(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweRelinearizationKeyProto;
+    // The tensor product outputs a ciphertext which can only be decrypted once relinearized, so
+    // both operations are made available here.
+    fn compute_tensor_product_glwe_ciphertext(
+        &mut self,
+        input1: &Self::GlweCiphertextProto,
+        input2: &Self::GlweCiphertextProto,
+        delta_log: DeltaLog,
+    ) -> Self::GlweCiphertextProto;
+    fn relinearize_glwe_ciphertext(
+        &mut self,
+        input: &Self::GlweCiphertextProto,
+        relinearization_key: &Self::GlweRelinearizationKeyProto,
+    ) -> Self::GlweCiphertextProto;
+}
+
+impl PrototypesGlweRelinearizationKey<Precision32, BinaryKeyDistribution> for Maker {
+    type GlweRelinearizationKeyProto = ProtoBinaryGlweRelinearizationKey32;
+
+    fn new_glwe_relinearization_key(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweRelinearizationKeyProto {
+        ProtoBinaryGlweRelinearizationKey32(
+            self.default_engine
+                .generate_new_glwe_relinearization_key(
+                    &secret_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn compute_tensor_product_glwe_ciphertext(
+        &mut self,
+        input1: &Self::GlweCiphertextProto,
+        input2: &Self::GlweCiphertextProto,
+        delta_log: DeltaLog,
+    ) -> Self::GlweCiphertextProto {
+        ProtoBinaryGlweCiphertext32(
+            self.default_engine
+                .compute_tensor_product_glwe_ciphertext(&input1.0, &input2.0, delta_log)
+                .unwrap(),
+        )
+    }
+
+    fn relinearize_glwe_ciphertext(
+        &mut self,
+        input: &Self::GlweCiphertextProto,
+        relinearization_key: &Self::GlweRelinearizationKeyProto,
+    ) -> Self::GlweCiphertextProto {
+        let ProtoBinaryGlweCiphertext32(mut output) = <Self as PrototypesGlweCiphertext<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zeros_to_glwe_ciphertext(
+            self,
+            relinearization_key.0.glwe_dimension(),
+            input.0.polynomial_size(),
+        );
+        self.default_engine
+            .discard_relinearize_glwe_ciphertext(&mut output, &input.0, &relinearization_key.0)
+            .unwrap();
+        ProtoBinaryGlweCiphertext32(output)
+    }
+}
+
+impl PrototypesGlweRelinearizationKey<Precision64, BinaryKeyDistribution> for Maker {
+    type GlweRelinearizationKeyProto = ProtoBinaryGlweRelinearizationKey64;
+
+    fn new_glwe_relinearization_key(
+        &mut self,
+        secret_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweRelinearizationKeyProto {
+        ProtoBinaryGlweRelinearizationKey64(
+            self.default_engine
+                .generate_new_glwe_relinearization_key(
+                    &secret_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn compute_tensor_product_glwe_ciphertext(
+        &mut self,
+        input1: &Self::GlweCiphertextProto,
+        input2: &Self::GlweCiphertextProto,
+        delta_log: DeltaLog,
+    ) -> Self::GlweCiphertextProto {
+        ProtoBinaryGlweCiphertext64(
+            self.default_engine
+                .compute_tensor_product_glwe_ciphertext(&input1.0, &input2.0, delta_log)
+                .unwrap(),
+        )
+    }
+
+    fn relinearize_glwe_ciphertext(
+        &mut self,
+        input: &Self::GlweCiphertextProto,
+        relinearization_key: &Self::GlweRelinearizationKeyProto,
+    ) -> Self::GlweCiphertextProto {
+        let ProtoBinaryGlweCiphertext64(mut output) = <Self as PrototypesGlweCiphertext<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zeros_to_glwe_ciphertext(
+            self,
+            relinearization_key.0.glwe_dimension(),
+            input.0.polynomial_size(),
+        );
+        self.default_engine
+            .discard_relinearize_glwe_ciphertext(&mut output, &input.0, &relinearization_key.0)
+            .unwrap();
+        ProtoBinaryGlweCiphertext64(output)
+    }
+}
@@ -1,10 +1,12 @@
 use crate::generation::prototypes::{
-    GlweSecretKeyPrototype, ProtoBinaryGlweSecretKey32, ProtoBinaryGlweSecretKey64,
-    ProtoBinaryLweSecretKey32, ProtoBinaryLweSecretKey64,
+    GlweSecretKeyPrototype, ProtoBinaryGlweSecretKey128, ProtoBinaryGlweSecretKey32,
+    ProtoBinaryGlweSecretKey64, ProtoBinaryLweSecretKey128, ProtoBinaryLweSecretKey32,
+    ProtoBinaryLweSecretKey64,
 };
 use crate::generation::prototyping::PrototypesLweSecretKey;
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision128,
+    Precision32, Precision64,
 };
 use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 use concrete_core::prelude::{
@@ -117,3 +119,39 @@ impl PrototypesGlweSecretKey<Precision64, BinaryKeyDistribution> for Maker {
         )
     }
 }
+
+impl PrototypesGlweSecretKey<Precision128, BinaryKeyDistribution> for Maker {
+    type GlweSecretKeyProto = ProtoBinaryGlweSecretKey128;
+
+    fn new_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto {
+        ProtoBinaryGlweSecretKey128(
+            self.default_engine
+                .generate_new_glwe_secret_key(glwe_dimension, polynomial_size)
+                .unwrap(),
+        )
+    }
+
+    fn transform_glwe_secret_key_to_lwe_secret_key(
+        &mut self,
+        glwe_key: &Self::GlweSecretKeyProto,
+    ) -> Self::LweSecretKeyProto {
+        ProtoBinaryLweSecretKey128(
+            self.default_engine
+                .transform_glwe_secret_key_to_lwe_secret_key(glwe_key.0.to_owned())
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_secret_key_to_glwe_secret_key(
+        &mut self,
+        _lwe_key: &Self::LweSecretKeyProto,
+        _polynomial_size: PolynomialSize,
+    ) -> Self::GlweSecretKeyProto {
+        // FIXME:
+        unimplemented!("The 128 bit engines needed by this function were not yet implemented")
+    }
+}
@@ -0,0 +1,114 @@
+use crate::generation::prototypes::{
+    GlweSeededKeyswitchKeyPrototype, ProtoBinaryGlweKeyswitchKey32, ProtoBinaryGlweKeyswitchKey64,
+    ProtoBinaryGlweSeededKeyswitchKey32, ProtoBinaryGlweSeededKeyswitchKey64,
+};
+use crate::generation::prototyping::PrototypesGlweKeyswitchKey;
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_core::prelude::{
+    GlweSeededKeyswitchKeyGenerationEngine,
+    GlweSeededKeyswitchKeyToGlweKeyswitchKeyTransformationEngine,
+};
+
+/// A trait allowing to manipulate seeded glwe keyswitch key prototypes.
+pub trait PrototypesGlweSeededKeyswitchKey<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>: PrototypesGlweKeyswitchKey<Precision, KeyDistribution>
+{
+    type GlweSeededKeyswitchKeyProto: GlweSeededKeyswitchKeyPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn new_glwe_seeded_keyswitch_key(
+        &mut self,
+        input_key: &Self::GlweSecretKeyProto,
+        output_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweSeededKeyswitchKeyProto;
+    fn transform_glwe_seeded_keyswitch_key_to_glwe_keyswitch_key(
+        &mut self,
+        seeded_keyswitch_key: &Self::GlweSeededKeyswitchKeyProto,
+    ) -> Self::GlweKeyswitchKeyProto;
+}
+
+impl PrototypesGlweSeededKeyswitchKey<Precision32, BinaryKeyDistribution> for Maker {
+    type GlweSeededKeyswitchKeyProto = ProtoBinaryGlweSeededKeyswitchKey32;
+
+    fn new_glwe_seeded_keyswitch_key(
+        &mut self,
+        input_key: &Self::GlweSecretKeyProto,
+        output_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweSeededKeyswitchKeyProto {
+        ProtoBinaryGlweSeededKeyswitchKey32(
+            self.default_engine
+                .generate_new_glwe_seeded_keyswitch_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_glwe_seeded_keyswitch_key_to_glwe_keyswitch_key(
+        &mut self,
+        seeded_keyswitch_key: &Self::GlweSeededKeyswitchKeyProto,
+    ) -> Self::GlweKeyswitchKeyProto {
+        ProtoBinaryGlweKeyswitchKey32(
+            self.default_engine
+                .transform_glwe_seeded_keyswitch_key_to_glwe_keyswitch_key(
+                    seeded_keyswitch_key.0.to_owned(),
+                )
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesGlweSeededKeyswitchKey<Precision64, BinaryKeyDistribution> for Maker {
+    type GlweSeededKeyswitchKeyProto = ProtoBinaryGlweSeededKeyswitchKey64;
+
+    fn new_glwe_seeded_keyswitch_key(
+        &mut self,
+        input_key: &Self::GlweSecretKeyProto,
+        output_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::GlweSeededKeyswitchKeyProto {
+        ProtoBinaryGlweSeededKeyswitchKey64(
+            self.default_engine
+                .generate_new_glwe_seeded_keyswitch_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_glwe_seeded_keyswitch_key_to_glwe_keyswitch_key(
+        &mut self,
+        seeded_keyswitch_key: &Self::GlweSeededKeyswitchKeyProto,
+    ) -> Self::GlweKeyswitchKeyProto {
+        ProtoBinaryGlweKeyswitchKey64(
+            self.default_engine
+                .transform_glwe_seeded_keyswitch_key_to_glwe_keyswitch_key(
+                    seeded_keyswitch_key.0.to_owned(),
+                )
+                .unwrap(),
+        )
+    }
+}
@@ -0,0 +1,131 @@
+use crate::generation::prototypes::{
+    GswCiphertextPrototype, ProtoBinaryGswCiphertext32, ProtoBinaryGswCiphertext64,
+    ProtoBinaryLweCiphertext32, ProtoBinaryLweCiphertext64,
+};
+use crate::generation::prototyping::lwe_ciphertext::PrototypesLweCiphertext;
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_core::prelude::{
+    GswCiphertextScalarEncryptionEngine, LweCiphertextEntity,
+    LweCiphertextGswCiphertextDiscardingExternalProductEngine,
+};
+
+/// A trait allowing to manipulate GSW ciphertext prototypes.
+pub trait PrototypesGswCiphertext<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>: PrototypesLweCiphertext<Precision, KeyDistribution>
+{
+    type GswCiphertextProto: GswCiphertextPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn encrypt_plaintext_to_gsw_ciphertext(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        plaintext: &Self::PlaintextProto,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Self::GswCiphertextProto;
+    fn compute_external_product_lwe_ciphertext_gsw_ciphertext(
+        &mut self,
+        lwe_input: &Self::LweCiphertextProto,
+        gsw_input: &Self::GswCiphertextProto,
+    ) -> Self::LweCiphertextProto;
+}
+
+impl PrototypesGswCiphertext<Precision32, BinaryKeyDistribution> for Maker {
+    type GswCiphertextProto = ProtoBinaryGswCiphertext32;
+
+    fn encrypt_plaintext_to_gsw_ciphertext(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        plaintext: &Self::PlaintextProto,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Self::GswCiphertextProto {
+        ProtoBinaryGswCiphertext32(
+            self.default_engine
+                .encrypt_scalar_gsw_ciphertext(
+                    &secret_key.0,
+                    &plaintext.0,
+                    noise,
+                    decomposition_level_count,
+                    decomposition_base_log,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn compute_external_product_lwe_ciphertext_gsw_ciphertext(
+        &mut self,
+        lwe_input: &Self::LweCiphertextProto,
+        gsw_input: &Self::GswCiphertextProto,
+    ) -> Self::LweCiphertextProto {
+        let ProtoBinaryLweCiphertext32(mut output) = <Self as PrototypesLweCiphertext<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            self, lwe_input.0.lwe_dimension()
+        );
+        self.default_engine
+            .discard_compute_external_product_lwe_ciphertext_gsw_ciphertext(
+                &lwe_input.0,
+                &gsw_input.0,
+                &mut output,
+            )
+            .unwrap();
+        ProtoBinaryLweCiphertext32(output)
+    }
+}
+
+impl PrototypesGswCiphertext<Precision64, BinaryKeyDistribution> for Maker {
+    type GswCiphertextProto = ProtoBinaryGswCiphertext64;
+
+    fn encrypt_plaintext_to_gsw_ciphertext(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        plaintext: &Self::PlaintextProto,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Self::GswCiphertextProto {
+        ProtoBinaryGswCiphertext64(
+            self.default_engine
+                .encrypt_scalar_gsw_ciphertext(
+                    &secret_key.0,
+                    &plaintext.0,
+                    noise,
+                    decomposition_level_count,
+                    decomposition_base_log,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn compute_external_product_lwe_ciphertext_gsw_ciphertext(
+        &mut self,
+        lwe_input: &Self::LweCiphertextProto,
+        gsw_input: &Self::GswCiphertextProto,
+    ) -> Self::LweCiphertextProto {
+        let ProtoBinaryLweCiphertext64(mut output) = <Self as PrototypesLweCiphertext<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            self, lwe_input.0.lwe_dimension()
+        );
+        self.default_engine
+            .discard_compute_external_product_lwe_ciphertext_gsw_ciphertext(
+                &lwe_input.0,
+                &gsw_input.0,
+                &mut output,
+            )
+            .unwrap();
+        ProtoBinaryLweCiphertext64(output)
+    }
+}
@@ -1,11 +1,14 @@
 use crate::generation::prototypes::{
-    LweBootstrapKeyPrototype, ProtoBinaryBinaryLweBootstrapKey32,
-    ProtoBinaryBinaryLweBootstrapKey64,
+    LweBootstrapKeyPrototype, ProtoBinaryBinaryLweBootstrapKey128,
+    ProtoBinaryBinaryLweBootstrapKey32, ProtoBinaryBinaryLweBootstrapKey64,
+    ProtoTernaryBinaryLweBootstrapKey32, ProtoTernaryBinaryLweBootstrapKey64,
+    ProtoTernaryLweSecretKey32, ProtoTernaryLweSecretKey64,
 };
 use crate::generation::prototyping::glwe_secret_key::PrototypesGlweSecretKey;
 use crate::generation::prototyping::lwe_secret_key::PrototypesLweSecretKey;
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision128,
+    Precision32, Precision64, TernaryKeyDistribution,
 };
 use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::{
@@ -167,3 +170,166 @@ impl PrototypesLweBootstrapKey<Precision64, BinaryKeyDistribution, BinaryKeyDist
             .unwrap()
     }
 }
+
+impl PrototypesLweBootstrapKey<Precision128, BinaryKeyDistribution, BinaryKeyDistribution>
+    for Maker
+{
+    type LweBootstrapKeyProto = ProtoBinaryBinaryLweBootstrapKey128;
+
+    fn new_lwe_bootstrap_key(
+        &mut self,
+        input_key: &Self::LweSecretKeyProto,
+        output_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::LweBootstrapKeyProto {
+        ProtoBinaryBinaryLweBootstrapKey128(
+            self.default_engine
+                .generate_new_lwe_bootstrap_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_base_log,
+                    decomposition_level,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_raw_vec_to_lwe_bootstrap_key(
+        &mut self,
+        _raw: &[u128],
+        _glwe_size: GlweSize,
+        _polynomial_size: PolynomialSize,
+        _decomposition_level_count: DecompositionLevelCount,
+        _decomposition_base_log: DecompositionBaseLog,
+    ) -> Self::LweBootstrapKeyProto {
+        // FIXME:
+        unimplemented!("The 128 bit engines needed by this function were not yet implemented")
+    }
+
+    fn transform_lwe_bootstrap_key_to_raw_vec(
+        &mut self,
+        _lwe_bootstrap_key: &Self::LweBootstrapKeyProto,
+    ) -> Vec<u128> {
+        // FIXME:
+        unimplemented!("The 128 bit engines needed by this function were not yet implemented")
+    }
+}
+
+impl PrototypesLweBootstrapKey<Precision32, TernaryKeyDistribution, BinaryKeyDistribution>
+    for Maker
+{
+    type LweBootstrapKeyProto = ProtoTernaryBinaryLweBootstrapKey32;
+
+    fn new_lwe_bootstrap_key(
+        &mut self,
+        input_key: &ProtoTernaryLweSecretKey32,
+        output_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::LweBootstrapKeyProto {
+        ProtoTernaryBinaryLweBootstrapKey32(
+            self.default_parallel_engine
+                .generate_new_lwe_bootstrap_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_base_log,
+                    decomposition_level,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_raw_vec_to_lwe_bootstrap_key(
+        &mut self,
+        raw: &[u32],
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Self::LweBootstrapKeyProto {
+        ProtoTernaryBinaryLweBootstrapKey32(
+            self.default_engine
+                .create_lwe_bootstrap_key_from(
+                    raw.to_owned(),
+                    glwe_size,
+                    polynomial_size,
+                    decomposition_base_log,
+                    decomposition_level_count,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_bootstrap_key_to_raw_vec(
+        &mut self,
+        lwe_bootstrap_key: &Self::LweBootstrapKeyProto,
+    ) -> Vec<u32> {
+        let lwe_bootstrap_key = lwe_bootstrap_key.0.to_owned();
+        self.default_engine
+            .consume_retrieve_lwe_bootstrap_key(lwe_bootstrap_key)
+            .unwrap()
+    }
+}
+
+impl PrototypesLweBootstrapKey<Precision64, TernaryKeyDistribution, BinaryKeyDistribution>
+    for Maker
+{
+    type LweBootstrapKeyProto = ProtoTernaryBinaryLweBootstrapKey64;
+
+    fn new_lwe_bootstrap_key(
+        &mut self,
+        input_key: &ProtoTernaryLweSecretKey64,
+        output_key: &Self::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::LweBootstrapKeyProto {
+        ProtoTernaryBinaryLweBootstrapKey64(
+            self.default_parallel_engine
+                .generate_new_lwe_bootstrap_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_base_log,
+                    decomposition_level,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_raw_vec_to_lwe_bootstrap_key(
+        &mut self,
+        raw: &[u64],
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Self::LweBootstrapKeyProto {
+        ProtoTernaryBinaryLweBootstrapKey64(
+            self.default_engine
+                .create_lwe_bootstrap_key_from(
+                    raw.to_owned(),
+                    glwe_size,
+                    polynomial_size,
+                    decomposition_base_log,
+                    decomposition_level_count,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_bootstrap_key_to_raw_vec(
+        &mut self,
+        lwe_bootstrap_key: &Self::LweBootstrapKeyProto,
+    ) -> Vec<u64> {
+        let lwe_bootstrap_key = lwe_bootstrap_key.0.to_owned();
+        self.default_engine
+            .consume_retrieve_lwe_bootstrap_key(lwe_bootstrap_key)
+            .unwrap()
+    }
+}
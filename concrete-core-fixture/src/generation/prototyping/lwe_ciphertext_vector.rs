@@ -1,8 +1,9 @@
 use crate::generation::{BinaryKeyDistribution, KeyDistributionMarker};
 use concrete_commons::dispersion::Variance;
-use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+use concrete_commons::parameters::{LweCiphertextCount, LweDimension, MonomialIndex};
 use concrete_core::prelude::{
-    LweCiphertextVectorDecryptionEngine, LweCiphertextVectorEncryptionEngine,
+    GlweCiphertextEntity, LweCiphertextVectorDecryptionEngine, LweCiphertextVectorEncryptionEngine,
+    LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionEngine,
     LweCiphertextVectorTrivialDecryptionEngine, LweCiphertextVectorTrivialEncryptionEngine,
     PlaintextVectorCreationEngine,
 };
@@ -11,6 +12,7 @@ use crate::generation::prototypes::{
     LweCiphertextVectorPrototype, ProtoBinaryLweCiphertextVector32,
     ProtoBinaryLweCiphertextVector64, ProtoPlaintextVector32, ProtoPlaintextVector64,
 };
+use crate::generation::prototyping::glwe_ciphertext::PrototypesGlweCiphertext;
 use crate::generation::prototyping::lwe_secret_key::PrototypesLweSecretKey;
 use crate::generation::prototyping::plaintext_vector::PrototypesPlaintextVector;
 use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
@@ -55,6 +57,14 @@ pub trait PrototypesLweCiphertextVector<
         &mut self,
         ciphertext: &Self::LweCiphertextVectorProto,
     ) -> Self::PlaintextVectorProto;
+
+    fn extract_lwe_ciphertext_vector_from_glwe_ciphertext(
+        &mut self,
+        glwe_ciphertext: &<Self as PrototypesGlweCiphertext<Precision, KeyDistribution>>::GlweCiphertextProto,
+        indices: &[MonomialIndex],
+    ) -> Self::LweCiphertextVectorProto
+    where
+        Self: PrototypesGlweCiphertext<Precision, KeyDistribution>;
 }
 
 impl PrototypesLweCiphertextVector<Precision32, BinaryKeyDistribution> for Maker {
@@ -129,6 +139,34 @@ impl PrototypesLweCiphertextVector<Precision32, BinaryKeyDistribution> for Maker
                 .unwrap(),
         )
     }
+
+    fn extract_lwe_ciphertext_vector_from_glwe_ciphertext(
+        &mut self,
+        glwe_ciphertext: &<Self as PrototypesGlweCiphertext<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::GlweCiphertextProto,
+        indices: &[MonomialIndex],
+    ) -> Self::LweCiphertextVectorProto {
+        let mut lwe_ciphertext_vector = <Self as PrototypesLweCiphertextVector<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zeros_to_lwe_ciphertext_vector(
+            self,
+            LweDimension(
+                glwe_ciphertext.0.glwe_dimension().0 * glwe_ciphertext.0.polynomial_size().0,
+            ),
+            LweCiphertextCount(indices.len()),
+        );
+        self.default_engine
+            .discard_sample_extract_lwe_ciphertext_vector(
+                &mut lwe_ciphertext_vector.0,
+                &glwe_ciphertext.0,
+                indices,
+            )
+            .unwrap();
+        lwe_ciphertext_vector
+    }
 }
 
 impl PrototypesLweCiphertextVector<Precision64, BinaryKeyDistribution> for Maker {
@@ -203,4 +241,32 @@ impl PrototypesLweCiphertextVector<Precision64, BinaryKeyDistribution> for Maker
                 .unwrap(),
         )
     }
+
+    fn extract_lwe_ciphertext_vector_from_glwe_ciphertext(
+        &mut self,
+        glwe_ciphertext: &<Self as PrototypesGlweCiphertext<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::GlweCiphertextProto,
+        indices: &[MonomialIndex],
+    ) -> Self::LweCiphertextVectorProto {
+        let mut lwe_ciphertext_vector = <Self as PrototypesLweCiphertextVector<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zeros_to_lwe_ciphertext_vector(
+            self,
+            LweDimension(
+                glwe_ciphertext.0.glwe_dimension().0 * glwe_ciphertext.0.polynomial_size().0,
+            ),
+            LweCiphertextCount(indices.len()),
+        );
+        self.default_engine
+            .discard_sample_extract_lwe_ciphertext_vector(
+                &mut lwe_ciphertext_vector.0,
+                &glwe_ciphertext.0,
+                indices,
+            )
+            .unwrap();
+        lwe_ciphertext_vector
+    }
 }
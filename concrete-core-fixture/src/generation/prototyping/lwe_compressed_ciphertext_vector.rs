@@ -0,0 +1,131 @@
+use crate::generation::prototypes::{
+    LweCompressedCiphertextVectorPrototype, ProtoBinaryLweCompressedCiphertextVector32,
+    ProtoBinaryLweCompressedCiphertextVector64, ProtoPlaintextVector32, ProtoPlaintextVector64,
+};
+use crate::generation::prototyping::lwe_ciphertext_vector::PrototypesLweCiphertextVector;
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+};
+use concrete_commons::parameters::CiphertextModulusLog;
+use concrete_core::prelude::{
+    EntityDeserializationEngine, EntitySerializationEngine,
+    LweCiphertextVectorModulusSwitchingEngine, LweCompressedCiphertextVectorDecryptionEngine,
+};
+
+/// A trait allowing to manipulate LWE compressed ciphertext vector prototypes.
+pub trait PrototypesLweCompressedCiphertextVector<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>: PrototypesLweCiphertextVector<Precision, KeyDistribution>
+{
+    type LweCompressedCiphertextVectorProto: LweCompressedCiphertextVectorPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn switch_modulus_lwe_ciphertext_vector_to_lwe_compressed_ciphertext_vector(
+        &mut self,
+        ciphertext_vector: &Self::LweCiphertextVectorProto,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Self::LweCompressedCiphertextVectorProto;
+    fn decrypt_lwe_compressed_ciphertext_vector_to_plaintext_vector(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        compressed_ciphertext_vector: &Self::LweCompressedCiphertextVectorProto,
+    ) -> Self::PlaintextVectorProto;
+    fn serialize_and_deserialize_lwe_compressed_ciphertext_vector(
+        &mut self,
+        compressed_ciphertext_vector: &Self::LweCompressedCiphertextVectorProto,
+    ) -> Self::LweCompressedCiphertextVectorProto;
+}
+
+impl PrototypesLweCompressedCiphertextVector<Precision32, BinaryKeyDistribution> for Maker {
+    type LweCompressedCiphertextVectorProto = ProtoBinaryLweCompressedCiphertextVector32;
+
+    fn switch_modulus_lwe_ciphertext_vector_to_lwe_compressed_ciphertext_vector(
+        &mut self,
+        ciphertext_vector: &Self::LweCiphertextVectorProto,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Self::LweCompressedCiphertextVectorProto {
+        ProtoBinaryLweCompressedCiphertextVector32(
+            self.default_engine
+                .switch_modulus_lwe_ciphertext_vector(&ciphertext_vector.0, ciphertext_modulus_log)
+                .unwrap(),
+        )
+    }
+
+    fn decrypt_lwe_compressed_ciphertext_vector_to_plaintext_vector(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        compressed_ciphertext_vector: &Self::LweCompressedCiphertextVectorProto,
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector32(
+            self.default_engine
+                .decrypt_lwe_compressed_ciphertext_vector(
+                    &secret_key.0,
+                    &compressed_ciphertext_vector.0,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn serialize_and_deserialize_lwe_compressed_ciphertext_vector(
+        &mut self,
+        compressed_ciphertext_vector: &Self::LweCompressedCiphertextVectorProto,
+    ) -> Self::LweCompressedCiphertextVectorProto {
+        let serialized = self
+            .default_serialization_engine
+            .serialize(&compressed_ciphertext_vector.0)
+            .unwrap();
+        ProtoBinaryLweCompressedCiphertextVector32(
+            self.default_serialization_engine
+                .deserialize(serialized.as_slice())
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesLweCompressedCiphertextVector<Precision64, BinaryKeyDistribution> for Maker {
+    type LweCompressedCiphertextVectorProto = ProtoBinaryLweCompressedCiphertextVector64;
+
+    fn switch_modulus_lwe_ciphertext_vector_to_lwe_compressed_ciphertext_vector(
+        &mut self,
+        ciphertext_vector: &Self::LweCiphertextVectorProto,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Self::LweCompressedCiphertextVectorProto {
+        ProtoBinaryLweCompressedCiphertextVector64(
+            self.default_engine
+                .switch_modulus_lwe_ciphertext_vector(&ciphertext_vector.0, ciphertext_modulus_log)
+                .unwrap(),
+        )
+    }
+
+    fn decrypt_lwe_compressed_ciphertext_vector_to_plaintext_vector(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        compressed_ciphertext_vector: &Self::LweCompressedCiphertextVectorProto,
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector64(
+            self.default_engine
+                .decrypt_lwe_compressed_ciphertext_vector(
+                    &secret_key.0,
+                    &compressed_ciphertext_vector.0,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn serialize_and_deserialize_lwe_compressed_ciphertext_vector(
+        &mut self,
+        compressed_ciphertext_vector: &Self::LweCompressedCiphertextVectorProto,
+    ) -> Self::LweCompressedCiphertextVectorProto {
+        let serialized = self
+            .default_serialization_engine
+            .serialize(&compressed_ciphertext_vector.0)
+            .unwrap();
+        ProtoBinaryLweCompressedCiphertextVector64(
+            self.default_serialization_engine
+                .deserialize(serialized.as_slice())
+                .unwrap(),
+        )
+    }
+}
@@ -1,14 +1,20 @@
 use crate::generation::prototypes::{
     LweKeyswitchKeyPrototype, ProtoBinaryBinaryLweKeyswitchKey32,
-    ProtoBinaryBinaryLweKeyswitchKey64,
+    ProtoBinaryBinaryLweKeyswitchKey64, ProtoBinaryLweCiphertext32, ProtoBinaryLweCiphertext64,
+    ProtoBinaryTernaryLweKeyswitchKey32, ProtoBinaryTernaryLweKeyswitchKey64,
+    ProtoTernaryLweCiphertext32, ProtoTernaryLweCiphertext64,
 };
+use crate::generation::prototyping::lwe_ciphertext::PrototypesLweCiphertext;
 use crate::generation::prototyping::lwe_secret_key::PrototypesLweSecretKey;
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32,
+    Precision64, TernaryKeyDistribution,
 };
 use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
-use concrete_core::prelude::LweKeyswitchKeyGenerationEngine;
+use concrete_core::prelude::{
+    LweCiphertextDiscardingKeyswitchEngine, LweKeyswitchKeyEntity, LweKeyswitchKeyGenerationEngine,
+};
 
 /// A trait allowing to manipulate lwe keyswitch key prototypes.
 pub trait PrototypesLweKeyswitchKey<
@@ -18,6 +24,8 @@ pub trait PrototypesLweKeyswitchKey<
 >:
     PrototypesLweSecretKey<Precision, InputKeyDistribution>
     + PrototypesLweSecretKey<Precision, OutputKeyDistribution>
+    + PrototypesLweCiphertext<Precision, InputKeyDistribution>
+    + PrototypesLweCiphertext<Precision, OutputKeyDistribution>
 {
     type LweKeyswitchKeyProto: LweKeyswitchKeyPrototype<
         Precision = Precision,
@@ -32,6 +40,11 @@ pub trait PrototypesLweKeyswitchKey<
         decomposition_base_log: DecompositionBaseLog,
         noise: Variance,
     ) -> Self::LweKeyswitchKeyProto;
+    fn keyswitch_lwe_ciphertext(
+        &mut self,
+        input: &<Self as PrototypesLweCiphertext<Precision, InputKeyDistribution>>::LweCiphertextProto,
+        keyswitch_key: &Self::LweKeyswitchKeyProto,
+    ) -> <Self as PrototypesLweCiphertext<Precision, OutputKeyDistribution>>::LweCiphertextProto;
 }
 
 impl PrototypesLweKeyswitchKey<Precision32, BinaryKeyDistribution, BinaryKeyDistribution>
@@ -59,6 +72,24 @@ impl PrototypesLweKeyswitchKey<Precision32, BinaryKeyDistribution, BinaryKeyDist
                 .unwrap(),
         )
     }
+
+    fn keyswitch_lwe_ciphertext(
+        &mut self,
+        input: &<Self as PrototypesLweCiphertext<Precision32, BinaryKeyDistribution>>::LweCiphertextProto,
+        keyswitch_key: &Self::LweKeyswitchKeyProto,
+    ) -> <Self as PrototypesLweCiphertext<Precision32, BinaryKeyDistribution>>::LweCiphertextProto
+    {
+        let ProtoBinaryLweCiphertext32(mut output) = <Self as PrototypesLweCiphertext<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            self, keyswitch_key.0.output_lwe_dimension()
+        );
+        self.default_engine
+            .discard_keyswitch_lwe_ciphertext(&mut output, &input.0, &keyswitch_key.0)
+            .unwrap();
+        ProtoBinaryLweCiphertext32(output)
+    }
 }
 
 impl PrototypesLweKeyswitchKey<Precision64, BinaryKeyDistribution, BinaryKeyDistribution>
@@ -86,4 +117,114 @@ impl PrototypesLweKeyswitchKey<Precision64, BinaryKeyDistribution, BinaryKeyDist
                 .unwrap(),
         )
     }
+
+    fn keyswitch_lwe_ciphertext(
+        &mut self,
+        input: &<Self as PrototypesLweCiphertext<Precision64, BinaryKeyDistribution>>::LweCiphertextProto,
+        keyswitch_key: &Self::LweKeyswitchKeyProto,
+    ) -> <Self as PrototypesLweCiphertext<Precision64, BinaryKeyDistribution>>::LweCiphertextProto
+    {
+        let ProtoBinaryLweCiphertext64(mut output) = <Self as PrototypesLweCiphertext<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            self, keyswitch_key.0.output_lwe_dimension()
+        );
+        self.default_engine
+            .discard_keyswitch_lwe_ciphertext(&mut output, &input.0, &keyswitch_key.0)
+            .unwrap();
+        ProtoBinaryLweCiphertext64(output)
+    }
+}
+
+impl PrototypesLweKeyswitchKey<Precision32, BinaryKeyDistribution, TernaryKeyDistribution>
+    for Maker
+{
+    type LweKeyswitchKeyProto = ProtoBinaryTernaryLweKeyswitchKey32;
+
+    fn new_lwe_keyswitch_key(
+        &mut self,
+        input_key: &<Self as PrototypesLweSecretKey<Precision32, BinaryKeyDistribution>>::LweSecretKeyProto,
+        output_key: &<Self as PrototypesLweSecretKey<Precision32, TernaryKeyDistribution>>::LweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::LweKeyswitchKeyProto {
+        ProtoBinaryTernaryLweKeyswitchKey32(
+            self.default_engine
+                .generate_new_lwe_keyswitch_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn keyswitch_lwe_ciphertext(
+        &mut self,
+        input: &<Self as PrototypesLweCiphertext<Precision32, BinaryKeyDistribution>>::LweCiphertextProto,
+        keyswitch_key: &Self::LweKeyswitchKeyProto,
+    ) -> <Self as PrototypesLweCiphertext<Precision32, TernaryKeyDistribution>>::LweCiphertextProto
+    {
+        let ProtoTernaryLweCiphertext32(mut output) = <Self as PrototypesLweCiphertext<
+            Precision32,
+            TernaryKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            self,
+            keyswitch_key.0.output_lwe_dimension(),
+        );
+        self.default_engine
+            .discard_keyswitch_lwe_ciphertext(&mut output, &input.0, &keyswitch_key.0)
+            .unwrap();
+        ProtoTernaryLweCiphertext32(output)
+    }
+}
+
+impl PrototypesLweKeyswitchKey<Precision64, BinaryKeyDistribution, TernaryKeyDistribution>
+    for Maker
+{
+    type LweKeyswitchKeyProto = ProtoBinaryTernaryLweKeyswitchKey64;
+
+    fn new_lwe_keyswitch_key(
+        &mut self,
+        input_key: &<Self as PrototypesLweSecretKey<Precision64, BinaryKeyDistribution>>::LweSecretKeyProto,
+        output_key: &<Self as PrototypesLweSecretKey<Precision64, TernaryKeyDistribution>>::LweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::LweKeyswitchKeyProto {
+        ProtoBinaryTernaryLweKeyswitchKey64(
+            self.default_engine
+                .generate_new_lwe_keyswitch_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn keyswitch_lwe_ciphertext(
+        &mut self,
+        input: &<Self as PrototypesLweCiphertext<Precision64, BinaryKeyDistribution>>::LweCiphertextProto,
+        keyswitch_key: &Self::LweKeyswitchKeyProto,
+    ) -> <Self as PrototypesLweCiphertext<Precision64, TernaryKeyDistribution>>::LweCiphertextProto
+    {
+        let ProtoTernaryLweCiphertext64(mut output) = <Self as PrototypesLweCiphertext<
+            Precision64,
+            TernaryKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            self,
+            keyswitch_key.0.output_lwe_dimension(),
+        );
+        self.default_engine
+            .discard_keyswitch_lwe_ciphertext(&mut output, &input.0, &keyswitch_key.0)
+            .unwrap();
+        ProtoTernaryLweCiphertext64(output)
+    }
 }
@@ -1,14 +1,19 @@
 use crate::generation::prototypes::{
     LwePackingKeyswitchKeyPrototype, ProtoBinaryBinaryLwePackingKeyswitchKey32,
-    ProtoBinaryBinaryLwePackingKeyswitchKey64,
+    ProtoBinaryBinaryLwePackingKeyswitchKey64, ProtoBinaryGlweCiphertext32,
+    ProtoBinaryGlweCiphertext64,
 };
 use crate::generation::prototyping::lwe_secret_key::PrototypesLweSecretKey;
+use crate::generation::prototyping::{PrototypesGlweCiphertext, PrototypesLweCiphertextVector};
 use crate::generation::{
     BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
 };
 use concrete_commons::dispersion::Variance;
 use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
-use concrete_core::prelude::LwePackingKeyswitchKeyGenerationEngine;
+use concrete_core::prelude::{
+    LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine,
+    LwePackingKeyswitchKeyEntity, LwePackingKeyswitchKeyGenerationEngine,
+};
 
 use super::PrototypesGlweSecretKey;
 
@@ -20,6 +25,8 @@ pub trait PrototypesLwePackingKeyswitchKey<
 >:
     PrototypesLweSecretKey<Precision, InputKeyDistribution>
     + PrototypesGlweSecretKey<Precision, OutputKeyDistribution>
+    + PrototypesLweCiphertextVector<Precision, InputKeyDistribution>
+    + PrototypesGlweCiphertext<Precision, OutputKeyDistribution>
 {
     type PackingKeyswitchKeyProto: LwePackingKeyswitchKeyPrototype<
         Precision = Precision,
@@ -40,6 +47,14 @@ pub trait PrototypesLwePackingKeyswitchKey<
         decomposition_base_log: DecompositionBaseLog,
         noise: Variance,
     ) -> Self::PackingKeyswitchKeyProto;
+    fn packing_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        input: &<Self as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::LweCiphertextVectorProto,
+        packing_keyswitch_key: &Self::PackingKeyswitchKeyProto,
+    ) -> <Self as PrototypesGlweCiphertext<Precision, OutputKeyDistribution>>::GlweCiphertextProto;
 }
 
 impl PrototypesLwePackingKeyswitchKey<Precision32, BinaryKeyDistribution, BinaryKeyDistribution>
@@ -67,6 +82,29 @@ impl PrototypesLwePackingKeyswitchKey<Precision32, BinaryKeyDistribution, Binary
                 .unwrap(),
         )
     }
+
+    fn packing_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        input: &Self::LweCiphertextVectorProto,
+        packing_keyswitch_key: &Self::PackingKeyswitchKeyProto,
+    ) -> Self::GlweCiphertextProto {
+        let ProtoBinaryGlweCiphertext32(mut output) = <Self as PrototypesGlweCiphertext<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zeros_to_glwe_ciphertext(
+            self,
+            packing_keyswitch_key.0.output_glwe_dimension(),
+            packing_keyswitch_key.0.output_polynomial_size(),
+        );
+        self.default_engine
+            .discard_packing_keyswitch_lwe_ciphertext_vector(
+                &mut output,
+                &input.0,
+                &packing_keyswitch_key.0,
+            )
+            .unwrap();
+        ProtoBinaryGlweCiphertext32(output)
+    }
 }
 
 impl PrototypesLwePackingKeyswitchKey<Precision64, BinaryKeyDistribution, BinaryKeyDistribution>
@@ -94,4 +132,27 @@ impl PrototypesLwePackingKeyswitchKey<Precision64, BinaryKeyDistribution, Binary
                 .unwrap(),
         )
     }
+
+    fn packing_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        input: &Self::LweCiphertextVectorProto,
+        packing_keyswitch_key: &Self::PackingKeyswitchKeyProto,
+    ) -> Self::GlweCiphertextProto {
+        let ProtoBinaryGlweCiphertext64(mut output) = <Self as PrototypesGlweCiphertext<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zeros_to_glwe_ciphertext(
+            self,
+            packing_keyswitch_key.0.output_glwe_dimension(),
+            packing_keyswitch_key.0.output_polynomial_size(),
+        );
+        self.default_engine
+            .discard_packing_keyswitch_lwe_ciphertext_vector(
+                &mut output,
+                &input.0,
+                &packing_keyswitch_key.0,
+            )
+            .unwrap();
+        ProtoBinaryGlweCiphertext64(output)
+    }
 }
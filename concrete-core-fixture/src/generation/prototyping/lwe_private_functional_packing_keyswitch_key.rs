@@ -3,15 +3,22 @@ pub use crate::generation::prototypes::{
     ProtoBinaryBinaryLwePrivateFunctionalPackingKeyswitchKey32,
     ProtoBinaryBinaryLwePrivateFunctionalPackingKeyswitchKey64,
 };
+use crate::generation::prototypes::{ProtoBinaryGlweCiphertext32, ProtoBinaryGlweCiphertext64};
 use crate::generation::prototyping::lwe_secret_key::PrototypesLweSecretKey;
-use crate::generation::prototyping::PrototypesCleartextVector;
+use crate::generation::prototyping::{
+    PrototypesCleartextVector, PrototypesGlweCiphertext, PrototypesLweCiphertextVector,
+};
 use crate::generation::{
     BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
 };
 use concrete_commons::dispersion::StandardDev;
 
 use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
-use concrete_core::prelude::LwePrivateFunctionalLwePackingKeyswitchKeyGenerationEngine;
+use concrete_core::prelude::{
+    LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine,
+    LwePrivateFunctionalLwePackingKeyswitchKeyGenerationEngine,
+    LwePrivateFunctionalPackingKeyswitchKeyEntity,
+};
 
 use super::PrototypesGlweSecretKey;
 
@@ -24,6 +31,8 @@ pub trait PrototypesLwePrivateFunctionalPackingKeyswitchKey<
     PrototypesLweSecretKey<Precision, InputKeyDistribution>
     + PrototypesGlweSecretKey<Precision, OutputKeyDistribution>
     + PrototypesCleartextVector<Precision>
+    + PrototypesLweCiphertextVector<Precision, InputKeyDistribution>
+    + PrototypesGlweCiphertext<Precision, OutputKeyDistribution>
 {
     type LwePrivateFunctionalPackingKeyswitchKeyProto: LwePrivateFunctionalPackingKeyswitchKeyPrototype<
         Precision = Precision,
@@ -47,6 +56,14 @@ pub trait PrototypesLwePrivateFunctionalPackingKeyswitchKey<
         f: &dyn Fn(Precision::Raw) -> Precision::Raw,
         polynomial: &<Self as PrototypesCleartextVector<Precision>>::CleartextVectorProto,
     ) -> Self::LwePrivateFunctionalPackingKeyswitchKeyProto;
+    fn private_functional_packing_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        input: &<Self as PrototypesLweCiphertextVector<
+            Precision,
+            InputKeyDistribution,
+        >>::LweCiphertextVectorProto,
+        keyswitch_key: &Self::LwePrivateFunctionalPackingKeyswitchKeyProto,
+    ) -> <Self as PrototypesGlweCiphertext<Precision, OutputKeyDistribution>>::GlweCiphertextProto;
 }
 
 impl
@@ -83,6 +100,29 @@ impl
                 .unwrap(),
         )
     }
+
+    fn private_functional_packing_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        input: &Self::LweCiphertextVectorProto,
+        keyswitch_key: &Self::LwePrivateFunctionalPackingKeyswitchKeyProto,
+    ) -> Self::GlweCiphertextProto {
+        let ProtoBinaryGlweCiphertext32(mut output) = <Self as PrototypesGlweCiphertext<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zeros_to_glwe_ciphertext(
+            self,
+            keyswitch_key.0.output_glwe_dimension(),
+            keyswitch_key.0.output_polynomial_size(),
+        );
+        self.default_engine
+            .discard_private_functional_packing_keyswitch_lwe_ciphertext_vector(
+                &mut output,
+                &input.0,
+                &keyswitch_key.0,
+            )
+            .unwrap();
+        ProtoBinaryGlweCiphertext32(output)
+    }
 }
 
 impl
@@ -119,4 +159,27 @@ impl
                 .unwrap(),
         )
     }
+
+    fn private_functional_packing_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        input: &Self::LweCiphertextVectorProto,
+        keyswitch_key: &Self::LwePrivateFunctionalPackingKeyswitchKeyProto,
+    ) -> Self::GlweCiphertextProto {
+        let ProtoBinaryGlweCiphertext64(mut output) = <Self as PrototypesGlweCiphertext<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zeros_to_glwe_ciphertext(
+            self,
+            keyswitch_key.0.output_glwe_dimension(),
+            keyswitch_key.0.output_polynomial_size(),
+        );
+        self.default_engine
+            .discard_private_functional_packing_keyswitch_lwe_ciphertext_vector(
+                &mut output,
+                &input.0,
+                &keyswitch_key.0,
+            )
+            .unwrap();
+        ProtoBinaryGlweCiphertext64(output)
+    }
 }
@@ -0,0 +1,123 @@
+use crate::generation::prototypes::{
+    LwePublicKeyPrototype, ProtoBinaryLweCiphertext32, ProtoBinaryLweCiphertext64,
+    ProtoBinaryLwePublicKey32, ProtoBinaryLwePublicKey64,
+};
+use crate::generation::prototyping::{PrototypesLweCiphertext, PrototypesLweSecretKey};
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LwePublicKeyZeroEncryptionCount;
+use concrete_core::prelude::{
+    LweCiphertextDiscardingPublicKeyEncryptionEngine, LwePublicKeyEntity,
+    LwePublicKeyGenerationEngine,
+};
+
+/// A trait allowing to manipulate lwe public key prototypes.
+pub trait PrototypesLwePublicKey<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+>:
+    PrototypesLweSecretKey<Precision, KeyDistribution>
+    + PrototypesLweCiphertext<Precision, KeyDistribution>
+{
+    type LwePublicKeyProto: LwePublicKeyPrototype<
+        Precision = Precision,
+        KeyDistribution = KeyDistribution,
+    >;
+    fn new_lwe_public_key(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Self::LwePublicKeyProto;
+    fn encrypt_plaintext_to_lwe_ciphertext_with_public_key(
+        &mut self,
+        public_key: &Self::LwePublicKeyProto,
+        plaintext: &Self::PlaintextProto,
+    ) -> Self::LweCiphertextProto;
+}
+
+impl PrototypesLwePublicKey<Precision32, BinaryKeyDistribution> for Maker {
+    type LwePublicKeyProto = ProtoBinaryLwePublicKey32;
+
+    fn new_lwe_public_key(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Self::LwePublicKeyProto {
+        ProtoBinaryLwePublicKey32(
+            self.default_engine
+                .generate_new_lwe_public_key(
+                    &secret_key.0,
+                    noise,
+                    lwe_public_key_zero_encryption_count,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn encrypt_plaintext_to_lwe_ciphertext_with_public_key(
+        &mut self,
+        public_key: &Self::LwePublicKeyProto,
+        plaintext: &Self::PlaintextProto,
+    ) -> Self::LweCiphertextProto {
+        let ProtoBinaryLweCiphertext32(mut output) = <Self as PrototypesLweCiphertext<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            self, public_key.0.lwe_dimension()
+        );
+        self.default_engine
+            .discard_encrypt_lwe_ciphertext_with_public_key(
+                &public_key.0,
+                &mut output,
+                &plaintext.0,
+            )
+            .unwrap();
+        ProtoBinaryLweCiphertext32(output)
+    }
+}
+
+impl PrototypesLwePublicKey<Precision64, BinaryKeyDistribution> for Maker {
+    type LwePublicKeyProto = ProtoBinaryLwePublicKey64;
+
+    fn new_lwe_public_key(
+        &mut self,
+        secret_key: &Self::LweSecretKeyProto,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Self::LwePublicKeyProto {
+        ProtoBinaryLwePublicKey64(
+            self.default_engine
+                .generate_new_lwe_public_key(
+                    &secret_key.0,
+                    noise,
+                    lwe_public_key_zero_encryption_count,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn encrypt_plaintext_to_lwe_ciphertext_with_public_key(
+        &mut self,
+        public_key: &Self::LwePublicKeyProto,
+        plaintext: &Self::PlaintextProto,
+    ) -> Self::LweCiphertextProto {
+        let ProtoBinaryLweCiphertext64(mut output) = <Self as PrototypesLweCiphertext<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::trivially_encrypt_zero_to_lwe_ciphertext(
+            self, public_key.0.lwe_dimension()
+        );
+        self.default_engine
+            .discard_encrypt_lwe_ciphertext_with_public_key(
+                &public_key.0,
+                &mut output,
+                &plaintext.0,
+            )
+            .unwrap();
+        ProtoBinaryLweCiphertext64(output)
+    }
+}
@@ -1,11 +1,14 @@
 use crate::generation::prototypes::{
-    LweSecretKeyPrototype, ProtoBinaryLweSecretKey32, ProtoBinaryLweSecretKey64,
+    LweSecretKeyPrototype, ProtoBinaryLweSecretKey128, ProtoBinaryLweSecretKey32,
+    ProtoBinaryLweSecretKey64, ProtoTernaryLweSecretKey32, ProtoTernaryLweSecretKey64,
 };
 use crate::generation::{
-    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision128,
+    Precision32, Precision64, TernaryKeyDistribution,
 };
+use concrete_commons::key_kinds::TernaryKeyKind;
 use concrete_commons::parameters::LweDimension;
-use concrete_core::prelude::LweSecretKeyGenerationEngine;
+use concrete_core::prelude::{LweSecretKeyGenerationEngine, LweSecretKeyWithKindGenerationEngine};
 
 /// A trait allowing to manipulate lwe secret key prototypes.
 pub trait PrototypesLweSecretKey<
@@ -43,3 +46,39 @@ impl PrototypesLweSecretKey<Precision64, BinaryKeyDistribution> for Maker {
         )
     }
 }
+
+impl PrototypesLweSecretKey<Precision128, BinaryKeyDistribution> for Maker {
+    type LweSecretKeyProto = ProtoBinaryLweSecretKey128;
+
+    fn new_lwe_secret_key(&mut self, lwe_dimension: LweDimension) -> Self::LweSecretKeyProto {
+        ProtoBinaryLweSecretKey128(
+            self.default_engine
+                .generate_new_lwe_secret_key(lwe_dimension)
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesLweSecretKey<Precision32, TernaryKeyDistribution> for Maker {
+    type LweSecretKeyProto = ProtoTernaryLweSecretKey32;
+
+    fn new_lwe_secret_key(&mut self, lwe_dimension: LweDimension) -> Self::LweSecretKeyProto {
+        ProtoTernaryLweSecretKey32(
+            self.default_engine
+                .generate_new_lwe_secret_key_with_kind(lwe_dimension, TernaryKeyKind)
+                .unwrap(),
+        )
+    }
+}
+
+impl PrototypesLweSecretKey<Precision64, TernaryKeyDistribution> for Maker {
+    type LweSecretKeyProto = ProtoTernaryLweSecretKey64;
+
+    fn new_lwe_secret_key(&mut self, lwe_dimension: LweDimension) -> Self::LweSecretKeyProto {
+        ProtoTernaryLweSecretKey64(
+            self.default_engine
+                .generate_new_lwe_secret_key_with_kind(lwe_dimension, TernaryKeyKind)
+                .unwrap(),
+        )
+    }
+}
@@ -0,0 +1,150 @@
+use crate::generation::prototypes::{
+    LweSeededPackingKeyswitchKeyPrototype, ProtoBinaryBinaryLwePackingKeyswitchKey32,
+    ProtoBinaryBinaryLwePackingKeyswitchKey64, ProtoBinaryBinaryLweSeededPackingKeyswitchKey32,
+    ProtoBinaryBinaryLweSeededPackingKeyswitchKey64,
+};
+use crate::generation::prototyping::{
+    PrototypesGlweSecretKey, PrototypesLwePackingKeyswitchKey, PrototypesLweSecretKey,
+};
+use crate::generation::{
+    BinaryKeyDistribution, IntegerPrecision, KeyDistributionMarker, Maker, Precision32, Precision64,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_core::prelude::{
+    LweSeededPackingKeyswitchKeyGenerationEngine,
+    LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationEngine,
+};
+
+/// A trait allowing to manipulate seeded packing keyswitch key prototypes.
+pub trait PrototypesLweSeededPackingKeyswitchKey<
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+>:
+    PrototypesLwePackingKeyswitchKey<Precision, InputKeyDistribution, OutputKeyDistribution>
+{
+    type LweSeededPackingKeyswitchKeyProto: LweSeededPackingKeyswitchKeyPrototype<
+        Precision = Precision,
+        InputKeyDistribution = InputKeyDistribution,
+        OutputKeyDistribution = OutputKeyDistribution,
+    >;
+    fn new_lwe_seeded_packing_keyswitch_key(
+        &mut self,
+        input_key: &<Self as PrototypesLweSecretKey<
+            Precision,
+            InputKeyDistribution,
+        >>::LweSecretKeyProto,
+        output_key: &<Self as PrototypesGlweSecretKey<
+            Precision,
+            OutputKeyDistribution,
+        >>::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::LweSeededPackingKeyswitchKeyProto;
+    fn transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key(
+        &mut self,
+        seeded_key: &Self::LweSeededPackingKeyswitchKeyProto,
+    ) -> Self::PackingKeyswitchKeyProto;
+}
+
+impl
+    PrototypesLweSeededPackingKeyswitchKey<
+        Precision32,
+        BinaryKeyDistribution,
+        BinaryKeyDistribution,
+    > for Maker
+{
+    type LweSeededPackingKeyswitchKeyProto = ProtoBinaryBinaryLweSeededPackingKeyswitchKey32;
+
+    fn new_lwe_seeded_packing_keyswitch_key(
+        &mut self,
+        input_key: &<Self as PrototypesLweSecretKey<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::LweSecretKeyProto,
+        output_key: &<Self as PrototypesGlweSecretKey<
+            Precision32,
+            BinaryKeyDistribution,
+        >>::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::LweSeededPackingKeyswitchKeyProto {
+        ProtoBinaryBinaryLweSeededPackingKeyswitchKey32(
+            self.default_engine
+                .generate_new_lwe_seeded_packing_keyswitch_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key(
+        &mut self,
+        seeded_key: &Self::LweSeededPackingKeyswitchKeyProto,
+    ) -> Self::PackingKeyswitchKeyProto {
+        ProtoBinaryBinaryLwePackingKeyswitchKey32(
+            self.default_engine
+                .transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key(
+                    seeded_key.0.to_owned(),
+                )
+                .unwrap(),
+        )
+    }
+}
+
+impl
+    PrototypesLweSeededPackingKeyswitchKey<
+        Precision64,
+        BinaryKeyDistribution,
+        BinaryKeyDistribution,
+    > for Maker
+{
+    type LweSeededPackingKeyswitchKeyProto = ProtoBinaryBinaryLweSeededPackingKeyswitchKey64;
+
+    fn new_lwe_seeded_packing_keyswitch_key(
+        &mut self,
+        input_key: &<Self as PrototypesLweSecretKey<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::LweSecretKeyProto,
+        output_key: &<Self as PrototypesGlweSecretKey<
+            Precision64,
+            BinaryKeyDistribution,
+        >>::GlweSecretKeyProto,
+        decomposition_level: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Self::LweSeededPackingKeyswitchKeyProto {
+        ProtoBinaryBinaryLweSeededPackingKeyswitchKey64(
+            self.default_engine
+                .generate_new_lwe_seeded_packing_keyswitch_key(
+                    &input_key.0,
+                    &output_key.0,
+                    decomposition_level,
+                    decomposition_base_log,
+                    noise,
+                )
+                .unwrap(),
+        )
+    }
+
+    fn transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key(
+        &mut self,
+        seeded_key: &Self::LweSeededPackingKeyswitchKeyProto,
+    ) -> Self::PackingKeyswitchKeyProto {
+        ProtoBinaryBinaryLwePackingKeyswitchKey64(
+            self.default_engine
+                .transform_lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key(
+                    seeded_key.0.to_owned(),
+                )
+                .unwrap(),
+        )
+    }
+}
@@ -1,5 +1,7 @@
-use crate::generation::prototypes::{PlaintextPrototype, ProtoPlaintext32, ProtoPlaintext64};
-use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use crate::generation::prototypes::{
+    PlaintextPrototype, ProtoPlaintext128, ProtoPlaintext32, ProtoPlaintext64,
+};
+use crate::generation::{IntegerPrecision, Maker, Precision128, Precision32, Precision64};
 use concrete_core::prelude::{PlaintextCreationEngine, PlaintextRetrievalEngine};
 
 /// A trait allowing to manipulate plaintext prototypes.
@@ -36,3 +38,17 @@ impl PrototypesPlaintext<Precision64> for Maker {
             .unwrap()
     }
 }
+
+impl PrototypesPlaintext<Precision128> for Maker {
+    type PlaintextProto = ProtoPlaintext128;
+
+    fn transform_raw_to_plaintext(&mut self, raw: &u128) -> Self::PlaintextProto {
+        ProtoPlaintext128(self.default_engine.create_plaintext_from(raw).unwrap())
+    }
+
+    fn transform_plaintext_to_raw(&mut self, plaintext: &Self::PlaintextProto) -> u128 {
+        self.default_engine
+            .retrieve_plaintext(&plaintext.0)
+            .unwrap()
+    }
+}
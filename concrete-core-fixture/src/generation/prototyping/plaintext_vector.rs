@@ -1,7 +1,8 @@
 use crate::generation::prototypes::{
-    PlaintextVectorPrototype, ProtoPlaintextVector32, ProtoPlaintextVector64,
+    PlaintextVectorPrototype, ProtoPlaintextVector128, ProtoPlaintextVector32,
+    ProtoPlaintextVector64,
 };
-use crate::generation::{IntegerPrecision, Maker, Precision32, Precision64};
+use crate::generation::{IntegerPrecision, Maker, Precision128, Precision32, Precision64};
 use concrete_core::prelude::{PlaintextVectorCreationEngine, PlaintextVectorRetrievalEngine};
 
 /// A trait allowing to manipulate plaintext vector prototypes.
@@ -58,3 +59,27 @@ impl PrototypesPlaintextVector<Precision64> for Maker {
             .unwrap()
     }
 }
+
+impl PrototypesPlaintextVector<Precision128> for Maker {
+    type PlaintextVectorProto = ProtoPlaintextVector128;
+
+    fn transform_raw_vec_to_plaintext_vector(
+        &mut self,
+        raw: &[u128],
+    ) -> Self::PlaintextVectorProto {
+        ProtoPlaintextVector128(
+            self.default_engine
+                .create_plaintext_vector_from(raw)
+                .unwrap(),
+        )
+    }
+
+    fn transform_plaintext_vector_to_raw_vec(
+        &mut self,
+        plaintext: &Self::PlaintextVectorProto,
+    ) -> Vec<u128> {
+        self.default_engine
+            .retrieve_plaintext_vector(&plaintext.0)
+            .unwrap()
+    }
+}
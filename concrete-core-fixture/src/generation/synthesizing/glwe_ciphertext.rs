@@ -20,10 +20,12 @@ pub trait SynthesizesGlweCiphertext<
 }
 
 mod backend_default {
-    use crate::generation::prototypes::{ProtoBinaryGlweCiphertext32, ProtoBinaryGlweCiphertext64};
+    use crate::generation::prototypes::{
+        ProtoBinaryGlweCiphertext128, ProtoBinaryGlweCiphertext32, ProtoBinaryGlweCiphertext64,
+    };
     use crate::generation::synthesizing::SynthesizesGlweCiphertext;
-    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
-    use concrete_core::prelude::{GlweCiphertext32, GlweCiphertext64};
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision128, Precision32, Precision64};
+    use concrete_core::prelude::{GlweCiphertext128, GlweCiphertext32, GlweCiphertext64};
 
     impl SynthesizesGlweCiphertext<Precision32, BinaryKeyDistribution, GlweCiphertext32> for Maker {
         fn synthesize_glwe_ciphertext(
@@ -61,6 +63,24 @@ mod backend_default {
         fn destroy_glwe_ciphertext(&mut self, _entity: GlweCiphertext64) {}
     }
 
+    impl SynthesizesGlweCiphertext<Precision128, BinaryKeyDistribution, GlweCiphertext128> for Maker {
+        fn synthesize_glwe_ciphertext(
+            &mut self,
+            prototype: &Self::GlweCiphertextProto,
+        ) -> GlweCiphertext128 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_ciphertext(
+            &mut self,
+            entity: GlweCiphertext128,
+        ) -> Self::GlweCiphertextProto {
+            ProtoBinaryGlweCiphertext128(entity)
+        }
+
+        fn destroy_glwe_ciphertext(&mut self, _entity: GlweCiphertext128) {}
+    }
+
     use concrete_core::prelude::{
         GlweCiphertextConsumingRetrievalEngine, GlweCiphertextCreationEngine, GlweCiphertextEntity,
         GlweCiphertextView32, GlweCiphertextView64,
@@ -0,0 +1,60 @@
+use crate::generation::prototyping::PrototypesGlwePublicKey;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker};
+use concrete_core::prelude::GlwePublicKeyEntity;
+
+/// A trait allowing to synthesize an actual glwe public key entity from a prototype.
+pub trait SynthesizesGlwePublicKey<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    GlwePublicKey,
+>: PrototypesGlwePublicKey<Precision, KeyDistribution> where
+    GlwePublicKey: GlwePublicKeyEntity,
+{
+    fn synthesize_glwe_public_key(&mut self, prototype: &Self::GlwePublicKeyProto)
+        -> GlwePublicKey;
+    fn unsynthesize_glwe_public_key(&mut self, entity: GlwePublicKey) -> Self::GlwePublicKeyProto;
+    fn destroy_glwe_public_key(&mut self, entity: GlwePublicKey);
+}
+
+mod backend_default {
+    use crate::generation::prototypes::{ProtoBinaryGlwePublicKey32, ProtoBinaryGlwePublicKey64};
+    use crate::generation::synthesizing::SynthesizesGlwePublicKey;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{GlwePublicKey32, GlwePublicKey64};
+
+    impl SynthesizesGlwePublicKey<Precision32, BinaryKeyDistribution, GlwePublicKey32> for Maker {
+        fn synthesize_glwe_public_key(
+            &mut self,
+            prototype: &Self::GlwePublicKeyProto,
+        ) -> GlwePublicKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_public_key(
+            &mut self,
+            entity: GlwePublicKey32,
+        ) -> Self::GlwePublicKeyProto {
+            ProtoBinaryGlwePublicKey32(entity)
+        }
+
+        fn destroy_glwe_public_key(&mut self, _entity: GlwePublicKey32) {}
+    }
+
+    impl SynthesizesGlwePublicKey<Precision64, BinaryKeyDistribution, GlwePublicKey64> for Maker {
+        fn synthesize_glwe_public_key(
+            &mut self,
+            prototype: &Self::GlwePublicKeyProto,
+        ) -> GlwePublicKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_public_key(
+            &mut self,
+            entity: GlwePublicKey64,
+        ) -> Self::GlwePublicKeyProto {
+            ProtoBinaryGlwePublicKey64(entity)
+        }
+
+        fn destroy_glwe_public_key(&mut self, _entity: GlwePublicKey64) {}
+    }
+}
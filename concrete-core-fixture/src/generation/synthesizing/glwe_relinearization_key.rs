@@ -0,0 +1,149 @@
+use crate::generation::prototyping::PrototypesGlweRelinearizationKey;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker};
+use concrete_core::prelude::GlweRelinearizationKeyEntity;
+
+/// A trait allowing to synthesize an actual glwe relinearization key entity from a prototype.
+pub trait SynthesizesGlweRelinearizationKey<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    GlweRelinearizationKey,
+>: PrototypesGlweRelinearizationKey<Precision, KeyDistribution> where
+    GlweRelinearizationKey: GlweRelinearizationKeyEntity,
+{
+    fn synthesize_glwe_relinearization_key(
+        &mut self,
+        prototype: &Self::GlweRelinearizationKeyProto,
+    ) -> GlweRelinearizationKey;
+    fn unsynthesize_glwe_relinearization_key(
+        &mut self,
+        entity: GlweRelinearizationKey,
+    ) -> Self::GlweRelinearizationKeyProto;
+    fn destroy_glwe_relinearization_key(&mut self, entity: GlweRelinearizationKey);
+}
+
+mod backend_default {
+    use crate::generation::prototypes::{
+        ProtoBinaryGlweRelinearizationKey32, ProtoBinaryGlweRelinearizationKey64,
+    };
+    use crate::generation::synthesizing::SynthesizesGlweRelinearizationKey;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{GlweRelinearizationKey32, GlweRelinearizationKey64};
+
+    impl
+        SynthesizesGlweRelinearizationKey<
+            Precision32,
+            BinaryKeyDistribution,
+            GlweRelinearizationKey32,
+        > for Maker
+    {
+        fn synthesize_glwe_relinearization_key(
+            &mut self,
+            prototype: &Self::GlweRelinearizationKeyProto,
+        ) -> GlweRelinearizationKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_relinearization_key(
+            &mut self,
+            entity: GlweRelinearizationKey32,
+        ) -> Self::GlweRelinearizationKeyProto {
+            ProtoBinaryGlweRelinearizationKey32(entity)
+        }
+
+        fn destroy_glwe_relinearization_key(&mut self, _entity: GlweRelinearizationKey32) {}
+    }
+
+    impl
+        SynthesizesGlweRelinearizationKey<
+            Precision64,
+            BinaryKeyDistribution,
+            GlweRelinearizationKey64,
+        > for Maker
+    {
+        fn synthesize_glwe_relinearization_key(
+            &mut self,
+            prototype: &Self::GlweRelinearizationKeyProto,
+        ) -> GlweRelinearizationKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_relinearization_key(
+            &mut self,
+            entity: GlweRelinearizationKey64,
+        ) -> Self::GlweRelinearizationKeyProto {
+            ProtoBinaryGlweRelinearizationKey64(entity)
+        }
+
+        fn destroy_glwe_relinearization_key(&mut self, _entity: GlweRelinearizationKey64) {}
+    }
+}
+
+#[cfg(feature = "backend_fft")]
+mod backend_fft {
+    use crate::generation::synthesizing::SynthesizesGlweRelinearizationKey;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        FftFourierGlweRelinearizationKey32, FftFourierGlweRelinearizationKey64,
+        GlweRelinearizationKeyConversionEngine,
+    };
+
+    impl
+        SynthesizesGlweRelinearizationKey<
+            Precision32,
+            BinaryKeyDistribution,
+            FftFourierGlweRelinearizationKey32,
+        > for Maker
+    {
+        fn synthesize_glwe_relinearization_key(
+            &mut self,
+            prototype: &Self::GlweRelinearizationKeyProto,
+        ) -> FftFourierGlweRelinearizationKey32 {
+            self.fft_engine
+                .convert_glwe_relinearization_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_glwe_relinearization_key(
+            &mut self,
+            _entity: FftFourierGlweRelinearizationKey32,
+        ) -> Self::GlweRelinearizationKeyProto {
+            todo!()
+        }
+
+        fn destroy_glwe_relinearization_key(
+            &mut self,
+            _entity: FftFourierGlweRelinearizationKey32,
+        ) {
+        }
+    }
+
+    impl
+        SynthesizesGlweRelinearizationKey<
+            Precision64,
+            BinaryKeyDistribution,
+            FftFourierGlweRelinearizationKey64,
+        > for Maker
+    {
+        fn synthesize_glwe_relinearization_key(
+            &mut self,
+            prototype: &Self::GlweRelinearizationKeyProto,
+        ) -> FftFourierGlweRelinearizationKey64 {
+            self.fft_engine
+                .convert_glwe_relinearization_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_glwe_relinearization_key(
+            &mut self,
+            _entity: FftFourierGlweRelinearizationKey64,
+        ) -> Self::GlweRelinearizationKeyProto {
+            todo!()
+        }
+
+        fn destroy_glwe_relinearization_key(
+            &mut self,
+            _entity: FftFourierGlweRelinearizationKey64,
+        ) {
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::generation::prototyping::PrototypesGlweSeededKeyswitchKey;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker};
+use concrete_core::prelude::GlweSeededKeyswitchKeyEntity;
+
+/// A trait allowing to synthesize an actual seeded glwe keyswitch key entity from a prototype.
+pub trait SynthesizesGlweSeededKeyswitchKey<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    GlweSeededKeyswitchKey,
+>: PrototypesGlweSeededKeyswitchKey<Precision, KeyDistribution> where
+    GlweSeededKeyswitchKey: GlweSeededKeyswitchKeyEntity,
+{
+    fn synthesize_glwe_seeded_keyswitch_key(
+        &mut self,
+        prototype: &Self::GlweSeededKeyswitchKeyProto,
+    ) -> GlweSeededKeyswitchKey;
+    fn unsynthesize_glwe_seeded_keyswitch_key(
+        &mut self,
+        entity: GlweSeededKeyswitchKey,
+    ) -> Self::GlweSeededKeyswitchKeyProto;
+    fn destroy_glwe_seeded_keyswitch_key(&mut self, entity: GlweSeededKeyswitchKey);
+}
+
+mod backend_default {
+    use crate::generation::prototypes::{
+        ProtoBinaryGlweSeededKeyswitchKey32, ProtoBinaryGlweSeededKeyswitchKey64,
+    };
+    use crate::generation::synthesizing::SynthesizesGlweSeededKeyswitchKey;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{GlweSeededKeyswitchKey32, GlweSeededKeyswitchKey64};
+
+    impl
+        SynthesizesGlweSeededKeyswitchKey<
+            Precision32,
+            BinaryKeyDistribution,
+            GlweSeededKeyswitchKey32,
+        > for Maker
+    {
+        fn synthesize_glwe_seeded_keyswitch_key(
+            &mut self,
+            prototype: &Self::GlweSeededKeyswitchKeyProto,
+        ) -> GlweSeededKeyswitchKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_seeded_keyswitch_key(
+            &mut self,
+            entity: GlweSeededKeyswitchKey32,
+        ) -> Self::GlweSeededKeyswitchKeyProto {
+            ProtoBinaryGlweSeededKeyswitchKey32(entity)
+        }
+
+        fn destroy_glwe_seeded_keyswitch_key(&mut self, _entity: GlweSeededKeyswitchKey32) {}
+    }
+
+    impl
+        SynthesizesGlweSeededKeyswitchKey<
+            Precision64,
+            BinaryKeyDistribution,
+            GlweSeededKeyswitchKey64,
+        > for Maker
+    {
+        fn synthesize_glwe_seeded_keyswitch_key(
+            &mut self,
+            prototype: &Self::GlweSeededKeyswitchKeyProto,
+        ) -> GlweSeededKeyswitchKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_glwe_seeded_keyswitch_key(
+            &mut self,
+            entity: GlweSeededKeyswitchKey64,
+        ) -> Self::GlweSeededKeyswitchKeyProto {
+            ProtoBinaryGlweSeededKeyswitchKey64(entity)
+        }
+
+        fn destroy_glwe_seeded_keyswitch_key(&mut self, _entity: GlweSeededKeyswitchKey64) {}
+    }
+}
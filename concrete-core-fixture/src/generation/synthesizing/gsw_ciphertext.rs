@@ -0,0 +1,59 @@
+use crate::generation::prototyping::PrototypesGswCiphertext;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker};
+use concrete_core::prelude::GswCiphertextEntity;
+
+/// A trait allowing to synthesize an actual gsw ciphertext entity from a prototype.
+pub trait SynthesizesGswCiphertext<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    GswCiphertext,
+>: PrototypesGswCiphertext<Precision, KeyDistribution> where
+    GswCiphertext: GswCiphertextEntity,
+{
+    fn synthesize_gsw_ciphertext(&mut self, prototype: &Self::GswCiphertextProto) -> GswCiphertext;
+    fn unsynthesize_gsw_ciphertext(&mut self, entity: GswCiphertext) -> Self::GswCiphertextProto;
+    fn destroy_gsw_ciphertext(&mut self, entity: GswCiphertext);
+}
+
+mod backend_default {
+    use crate::generation::prototypes::{ProtoBinaryGswCiphertext32, ProtoBinaryGswCiphertext64};
+    use crate::generation::synthesizing::SynthesizesGswCiphertext;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{GswCiphertext32, GswCiphertext64};
+
+    impl SynthesizesGswCiphertext<Precision32, BinaryKeyDistribution, GswCiphertext32> for Maker {
+        fn synthesize_gsw_ciphertext(
+            &mut self,
+            prototype: &Self::GswCiphertextProto,
+        ) -> GswCiphertext32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_gsw_ciphertext(
+            &mut self,
+            entity: GswCiphertext32,
+        ) -> Self::GswCiphertextProto {
+            ProtoBinaryGswCiphertext32(entity)
+        }
+
+        fn destroy_gsw_ciphertext(&mut self, _entity: GswCiphertext32) {}
+    }
+
+    impl SynthesizesGswCiphertext<Precision64, BinaryKeyDistribution, GswCiphertext64> for Maker {
+        fn synthesize_gsw_ciphertext(
+            &mut self,
+            prototype: &Self::GswCiphertextProto,
+        ) -> GswCiphertext64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_gsw_ciphertext(
+            &mut self,
+            entity: GswCiphertext64,
+        ) -> Self::GswCiphertextProto {
+            ProtoBinaryGswCiphertext64(entity)
+        }
+
+        fn destroy_gsw_ciphertext(&mut self, _entity: GswCiphertext64) {}
+    }
+}
@@ -25,10 +25,15 @@ pub trait SynthesizesLweBootstrapKey<
 mod backend_default {
     use crate::generation::prototypes::{
         ProtoBinaryBinaryLweBootstrapKey32, ProtoBinaryBinaryLweBootstrapKey64,
+        ProtoTernaryBinaryLweBootstrapKey32, ProtoTernaryBinaryLweBootstrapKey64,
     };
     use crate::generation::synthesizing::SynthesizesLweBootstrapKey;
-    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
-    use concrete_core::prelude::{LweBootstrapKey32, LweBootstrapKey64};
+    use crate::generation::{
+        BinaryKeyDistribution, Maker, Precision32, Precision64, TernaryKeyDistribution,
+    };
+    use concrete_core::prelude::{
+        LweBootstrapKey32, LweBootstrapKey64, LweTernaryBootstrapKey32, LweTernaryBootstrapKey64,
+    };
 
     impl
         SynthesizesLweBootstrapKey<
@@ -80,6 +85,56 @@ mod backend_default {
         fn destroy_lwe_bootstrap_key(&mut self, _entity: LweBootstrapKey64) {}
     }
 
+    impl
+        SynthesizesLweBootstrapKey<
+            Precision32,
+            TernaryKeyDistribution,
+            BinaryKeyDistribution,
+            LweTernaryBootstrapKey32,
+        > for Maker
+    {
+        fn synthesize_lwe_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweBootstrapKeyProto,
+        ) -> LweTernaryBootstrapKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_bootstrap_key(
+            &mut self,
+            entity: LweTernaryBootstrapKey32,
+        ) -> Self::LweBootstrapKeyProto {
+            ProtoTernaryBinaryLweBootstrapKey32(entity)
+        }
+
+        fn destroy_lwe_bootstrap_key(&mut self, _entity: LweTernaryBootstrapKey32) {}
+    }
+
+    impl
+        SynthesizesLweBootstrapKey<
+            Precision64,
+            TernaryKeyDistribution,
+            BinaryKeyDistribution,
+            LweTernaryBootstrapKey64,
+        > for Maker
+    {
+        fn synthesize_lwe_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweBootstrapKeyProto,
+        ) -> LweTernaryBootstrapKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_bootstrap_key(
+            &mut self,
+            entity: LweTernaryBootstrapKey64,
+        ) -> Self::LweBootstrapKeyProto {
+            ProtoTernaryBinaryLweBootstrapKey64(entity)
+        }
+
+        fn destroy_lwe_bootstrap_key(&mut self, _entity: LweTernaryBootstrapKey64) {}
+    }
+
     use concrete_core::prelude::{
         LweBootstrapKeyConsumingRetrievalEngine, LweBootstrapKeyCreationEngine,
         LweBootstrapKeyEntity, LweBootstrapKeyMutView32, LweBootstrapKeyMutView64,
@@ -451,9 +506,14 @@ mod backend_fftw {
 #[cfg(feature = "backend_fft")]
 mod backend_fft {
     use crate::generation::synthesizing::SynthesizesLweBootstrapKey;
-    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use crate::generation::{
+        BinaryKeyDistribution, Maker, Precision128, Precision32, Precision64,
+        TernaryKeyDistribution,
+    };
     use concrete_core::prelude::{
-        FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, LweBootstrapKeyConversionEngine,
+        FftFourierLweBootstrapKey128, FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64,
+        FftFourierLweTernaryBootstrapKey32, FftFourierLweTernaryBootstrapKey64,
+        LweBootstrapKeyConversionEngine,
     };
 
     impl
@@ -509,6 +569,87 @@ mod backend_fft {
 
         fn destroy_lwe_bootstrap_key(&mut self, _entity: FftFourierLweBootstrapKey64) {}
     }
+
+    impl
+        SynthesizesLweBootstrapKey<
+            Precision128,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            FftFourierLweBootstrapKey128,
+        > for Maker
+    {
+        fn synthesize_lwe_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweBootstrapKeyProto,
+        ) -> FftFourierLweBootstrapKey128 {
+            self.fft_engine
+                .convert_lwe_bootstrap_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_bootstrap_key(
+            &mut self,
+            _entity: FftFourierLweBootstrapKey128,
+        ) -> Self::LweBootstrapKeyProto {
+            todo!()
+        }
+
+        fn destroy_lwe_bootstrap_key(&mut self, _entity: FftFourierLweBootstrapKey128) {}
+    }
+
+    impl
+        SynthesizesLweBootstrapKey<
+            Precision32,
+            TernaryKeyDistribution,
+            BinaryKeyDistribution,
+            FftFourierLweTernaryBootstrapKey32,
+        > for Maker
+    {
+        fn synthesize_lwe_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweBootstrapKeyProto,
+        ) -> FftFourierLweTernaryBootstrapKey32 {
+            self.fft_engine
+                .convert_lwe_bootstrap_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_bootstrap_key(
+            &mut self,
+            _entity: FftFourierLweTernaryBootstrapKey32,
+        ) -> Self::LweBootstrapKeyProto {
+            todo!()
+        }
+
+        fn destroy_lwe_bootstrap_key(&mut self, _entity: FftFourierLweTernaryBootstrapKey32) {}
+    }
+
+    impl
+        SynthesizesLweBootstrapKey<
+            Precision64,
+            TernaryKeyDistribution,
+            BinaryKeyDistribution,
+            FftFourierLweTernaryBootstrapKey64,
+        > for Maker
+    {
+        fn synthesize_lwe_bootstrap_key(
+            &mut self,
+            prototype: &Self::LweBootstrapKeyProto,
+        ) -> FftFourierLweTernaryBootstrapKey64 {
+            self.fft_engine
+                .convert_lwe_bootstrap_key(&prototype.0)
+                .unwrap()
+        }
+
+        fn unsynthesize_lwe_bootstrap_key(
+            &mut self,
+            _entity: FftFourierLweTernaryBootstrapKey64,
+        ) -> Self::LweBootstrapKeyProto {
+            todo!()
+        }
+
+        fn destroy_lwe_bootstrap_key(&mut self, _entity: FftFourierLweTernaryBootstrapKey64) {}
+    }
 }
 
 #[cfg(all(feature = "backend_cuda", not(feature = "_ci_do_not_compile")))]
@@ -16,10 +16,16 @@ pub trait SynthesizesLweCiphertext<
 }
 
 mod backend_default {
-    use crate::generation::prototypes::{ProtoBinaryLweCiphertext32, ProtoBinaryLweCiphertext64};
+    use crate::generation::prototypes::{
+        ProtoBinaryLweCiphertext128, ProtoBinaryLweCiphertext32, ProtoBinaryLweCiphertext64,
+        ProtoTernaryLweCiphertext32, ProtoTernaryLweCiphertext64,
+    };
     use crate::generation::synthesizing::SynthesizesLweCiphertext;
-    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
-    use concrete_core::prelude::{LweCiphertext32, LweCiphertext64};
+    use crate::generation::{
+        BinaryKeyDistribution, Maker, Precision128, Precision32, Precision64,
+        TernaryKeyDistribution,
+    };
+    use concrete_core::prelude::{LweCiphertext128, LweCiphertext32, LweCiphertext64};
 
     impl SynthesizesLweCiphertext<Precision32, BinaryKeyDistribution, LweCiphertext32> for Maker {
         fn synthesize_lwe_ciphertext(
@@ -57,6 +63,60 @@ mod backend_default {
         fn destroy_lwe_ciphertext(&mut self, _entity: LweCiphertext64) {}
     }
 
+    impl SynthesizesLweCiphertext<Precision128, BinaryKeyDistribution, LweCiphertext128> for Maker {
+        fn synthesize_lwe_ciphertext(
+            &mut self,
+            prototype: &Self::LweCiphertextProto,
+        ) -> LweCiphertext128 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_ciphertext(
+            &mut self,
+            entity: LweCiphertext128,
+        ) -> Self::LweCiphertextProto {
+            ProtoBinaryLweCiphertext128(entity)
+        }
+
+        fn destroy_lwe_ciphertext(&mut self, _entity: LweCiphertext128) {}
+    }
+
+    impl SynthesizesLweCiphertext<Precision32, TernaryKeyDistribution, LweCiphertext32> for Maker {
+        fn synthesize_lwe_ciphertext(
+            &mut self,
+            prototype: &Self::LweCiphertextProto,
+        ) -> LweCiphertext32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_ciphertext(
+            &mut self,
+            entity: LweCiphertext32,
+        ) -> Self::LweCiphertextProto {
+            ProtoTernaryLweCiphertext32(entity)
+        }
+
+        fn destroy_lwe_ciphertext(&mut self, _entity: LweCiphertext32) {}
+    }
+
+    impl SynthesizesLweCiphertext<Precision64, TernaryKeyDistribution, LweCiphertext64> for Maker {
+        fn synthesize_lwe_ciphertext(
+            &mut self,
+            prototype: &Self::LweCiphertextProto,
+        ) -> LweCiphertext64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_ciphertext(
+            &mut self,
+            entity: LweCiphertext64,
+        ) -> Self::LweCiphertextProto {
+            ProtoTernaryLweCiphertext64(entity)
+        }
+
+        fn destroy_lwe_ciphertext(&mut self, _entity: LweCiphertext64) {}
+    }
+
     use concrete_core::prelude::{
         LweCiphertextConsumingRetrievalEngine, LweCiphertextCreationEngine, LweCiphertextView32,
         LweCiphertextView64,
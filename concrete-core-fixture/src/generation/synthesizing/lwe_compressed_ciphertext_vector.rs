@@ -0,0 +1,89 @@
+use crate::generation::prototyping::PrototypesLweCompressedCiphertextVector;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker};
+use concrete_core::prelude::LweCompressedCiphertextVectorEntity;
+
+/// A trait allowing to synthesize an actual LweCompressedCiphertextVectorEntity from a prototype.
+pub trait SynthesizesLweCompressedCiphertextVector<
+    Precision: IntegerPrecision,
+    KeyDistribution: KeyDistributionMarker,
+    LweCompressedCiphertextVector,
+>: PrototypesLweCompressedCiphertextVector<Precision, KeyDistribution> where
+    LweCompressedCiphertextVector: LweCompressedCiphertextVectorEntity,
+{
+    fn synthesize_lwe_compressed_ciphertext_vector(
+        &mut self,
+        prototype: &Self::LweCompressedCiphertextVectorProto,
+    ) -> LweCompressedCiphertextVector;
+    fn unsynthesize_lwe_compressed_ciphertext_vector(
+        &mut self,
+        entity: LweCompressedCiphertextVector,
+    ) -> Self::LweCompressedCiphertextVectorProto;
+    fn destroy_lwe_compressed_ciphertext_vector(&mut self, entity: LweCompressedCiphertextVector);
+}
+
+mod backend_default {
+    use crate::generation::prototypes::{
+        ProtoBinaryLweCompressedCiphertextVector32, ProtoBinaryLweCompressedCiphertextVector64,
+    };
+    use crate::generation::synthesizing::SynthesizesLweCompressedCiphertextVector;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        LweCompressedCiphertextVector32, LweCompressedCiphertextVector64,
+    };
+
+    impl
+        SynthesizesLweCompressedCiphertextVector<
+            Precision32,
+            BinaryKeyDistribution,
+            LweCompressedCiphertextVector32,
+        > for Maker
+    {
+        fn synthesize_lwe_compressed_ciphertext_vector(
+            &mut self,
+            prototype: &Self::LweCompressedCiphertextVectorProto,
+        ) -> LweCompressedCiphertextVector32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_compressed_ciphertext_vector(
+            &mut self,
+            entity: LweCompressedCiphertextVector32,
+        ) -> Self::LweCompressedCiphertextVectorProto {
+            ProtoBinaryLweCompressedCiphertextVector32(entity)
+        }
+
+        fn destroy_lwe_compressed_ciphertext_vector(
+            &mut self,
+            _entity: LweCompressedCiphertextVector32,
+        ) {
+        }
+    }
+
+    impl
+        SynthesizesLweCompressedCiphertextVector<
+            Precision64,
+            BinaryKeyDistribution,
+            LweCompressedCiphertextVector64,
+        > for Maker
+    {
+        fn synthesize_lwe_compressed_ciphertext_vector(
+            &mut self,
+            prototype: &Self::LweCompressedCiphertextVectorProto,
+        ) -> LweCompressedCiphertextVector64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_compressed_ciphertext_vector(
+            &mut self,
+            entity: LweCompressedCiphertextVector64,
+        ) -> Self::LweCompressedCiphertextVectorProto {
+            ProtoBinaryLweCompressedCiphertextVector64(entity)
+        }
+
+        fn destroy_lwe_compressed_ciphertext_vector(
+            &mut self,
+            _entity: LweCompressedCiphertextVector64,
+        ) {
+        }
+    }
+}
@@ -24,9 +24,12 @@ pub trait SynthesizesLweKeyswitchKey<
 mod backend_default {
     use crate::generation::prototypes::{
         ProtoBinaryBinaryLweKeyswitchKey32, ProtoBinaryBinaryLweKeyswitchKey64,
+        ProtoBinaryTernaryLweKeyswitchKey32, ProtoBinaryTernaryLweKeyswitchKey64,
     };
     use crate::generation::synthesizing::SynthesizesLweKeyswitchKey;
-    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use crate::generation::{
+        BinaryKeyDistribution, Maker, Precision32, Precision64, TernaryKeyDistribution,
+    };
     use concrete_core::prelude::{LweKeyswitchKey32, LweKeyswitchKey64};
 
     impl
@@ -78,6 +81,56 @@ mod backend_default {
 
         fn destroy_lwe_keyswitch_key(&mut self, _entity: LweKeyswitchKey64) {}
     }
+
+    impl
+        SynthesizesLweKeyswitchKey<
+            Precision32,
+            BinaryKeyDistribution,
+            TernaryKeyDistribution,
+            LweKeyswitchKey32,
+        > for Maker
+    {
+        fn synthesize_lwe_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweKeyswitchKeyProto,
+        ) -> LweKeyswitchKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_keyswitch_key(
+            &mut self,
+            entity: LweKeyswitchKey32,
+        ) -> Self::LweKeyswitchKeyProto {
+            ProtoBinaryTernaryLweKeyswitchKey32(entity)
+        }
+
+        fn destroy_lwe_keyswitch_key(&mut self, _entity: LweKeyswitchKey32) {}
+    }
+
+    impl
+        SynthesizesLweKeyswitchKey<
+            Precision64,
+            BinaryKeyDistribution,
+            TernaryKeyDistribution,
+            LweKeyswitchKey64,
+        > for Maker
+    {
+        fn synthesize_lwe_keyswitch_key(
+            &mut self,
+            prototype: &Self::LweKeyswitchKeyProto,
+        ) -> LweKeyswitchKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_keyswitch_key(
+            &mut self,
+            entity: LweKeyswitchKey64,
+        ) -> Self::LweKeyswitchKeyProto {
+            ProtoBinaryTernaryLweKeyswitchKey64(entity)
+        }
+
+        fn destroy_lwe_keyswitch_key(&mut self, _entity: LweKeyswitchKey64) {}
+    }
 }
 
 #[cfg(all(feature = "backend_cuda", not(feature = "_ci_do_not_compile")))]
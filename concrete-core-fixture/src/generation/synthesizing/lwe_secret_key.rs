@@ -16,11 +16,20 @@ pub trait SynthesizesLweSecretKey<
 }
 
 mod backend_default {
-    use crate::generation::prototypes::{ProtoBinaryLweSecretKey32, ProtoBinaryLweSecretKey64};
+    use crate::generation::prototypes::{
+        ProtoBinaryLweSecretKey32, ProtoBinaryLweSecretKey64, ProtoTernaryLweSecretKey32,
+        ProtoTernaryLweSecretKey64,
+    };
     use crate::generation::synthesizing::SynthesizesLweSecretKey;
-    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use crate::generation::{
+        BinaryKeyDistribution, Maker, Precision32, Precision64, TernaryKeyDistribution,
+    };
+    use concrete_commons::key_kinds::TernaryKeyKind;
     use concrete_core::prelude::{LweSecretKey32, LweSecretKey64};
 
+    type TernaryLweSecretKey32 = LweSecretKey32<TernaryKeyKind>;
+    type TernaryLweSecretKey64 = LweSecretKey64<TernaryKeyKind>;
+
     impl SynthesizesLweSecretKey<Precision32, BinaryKeyDistribution, LweSecretKey32> for Maker {
         fn synthesize_lwe_secret_key(
             &mut self,
@@ -56,4 +65,40 @@ mod backend_default {
 
         fn destroy_lwe_secret_key(&mut self, _entity: LweSecretKey64) {}
     }
+
+    impl SynthesizesLweSecretKey<Precision32, TernaryKeyDistribution, TernaryLweSecretKey32> for Maker {
+        fn synthesize_lwe_secret_key(
+            &mut self,
+            prototype: &Self::LweSecretKeyProto,
+        ) -> TernaryLweSecretKey32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_secret_key(
+            &mut self,
+            entity: TernaryLweSecretKey32,
+        ) -> Self::LweSecretKeyProto {
+            ProtoTernaryLweSecretKey32(entity)
+        }
+
+        fn destroy_lwe_secret_key(&mut self, _entity: TernaryLweSecretKey32) {}
+    }
+
+    impl SynthesizesLweSecretKey<Precision64, TernaryKeyDistribution, TernaryLweSecretKey64> for Maker {
+        fn synthesize_lwe_secret_key(
+            &mut self,
+            prototype: &Self::LweSecretKeyProto,
+        ) -> TernaryLweSecretKey64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_secret_key(
+            &mut self,
+            entity: TernaryLweSecretKey64,
+        ) -> Self::LweSecretKeyProto {
+            ProtoTernaryLweSecretKey64(entity)
+        }
+
+        fn destroy_lwe_secret_key(&mut self, _entity: TernaryLweSecretKey64) {}
+    }
 }
@@ -0,0 +1,108 @@
+use crate::generation::prototyping::PrototypesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys;
+use crate::generation::{IntegerPrecision, KeyDistributionMarker};
+use concrete_core::prelude::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity;
+
+/// A trait allowing to synthesize an actual seeded private functional packing keyswitch keys
+/// vector entity from a prototype.
+pub trait SynthesizesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+    Precision: IntegerPrecision,
+    InputKeyDistribution: KeyDistributionMarker,
+    OutputKeyDistribution: KeyDistributionMarker,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+>:
+    PrototypesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+    Precision,
+    InputKeyDistribution,
+    OutputKeyDistribution,
+> where
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys:
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+{
+    fn synthesize_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        prototype: &Self::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto,
+    ) -> LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys;
+    fn unsynthesize_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        entity: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    ) -> Self::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto;
+    fn destroy_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        entity: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    );
+}
+
+mod backend_default {
+    use crate::generation::prototypes::{
+        ProtoBinaryBinaryLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        ProtoBinaryBinaryLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    };
+    use crate::generation::synthesizing::SynthesizesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys;
+    use crate::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+    use concrete_core::prelude::{
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    };
+
+    impl
+        SynthesizesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+            Precision32,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        > for Maker
+    {
+        fn synthesize_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+            &mut self,
+            prototype: &Self::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto,
+        ) -> LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+            &mut self,
+            entity: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        ) -> Self::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto {
+            ProtoBinaryBinaryLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32(
+                entity,
+            )
+        }
+
+        fn destroy_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+            &mut self,
+            _entity: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        ) {
+        }
+    }
+
+    impl
+        SynthesizesLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys<
+            Precision64,
+            BinaryKeyDistribution,
+            BinaryKeyDistribution,
+            LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+        > for Maker
+    {
+        fn synthesize_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+            &mut self,
+            prototype: &Self::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto,
+        ) -> LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+            prototype.0.to_owned()
+        }
+
+        fn unsynthesize_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+            &mut self,
+            entity: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+        ) -> Self::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysProto {
+            ProtoBinaryBinaryLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64(
+                entity,
+            )
+        }
+
+        fn destroy_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+            &mut self,
+            _entity: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+        ) {
+        }
+    }
+}
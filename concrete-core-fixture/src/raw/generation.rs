@@ -266,3 +266,115 @@ impl RawUnsignedIntegers for u64 {
         })
     }
 }
+
+impl RawUnsignedIntegers for u128 {
+    fn one() -> Self {
+        1u128
+    }
+    fn one_vec(size: usize) -> Vec<Self> {
+        vec![1u128; size]
+    }
+    fn zero() -> Self {
+        0u128
+    }
+    fn zero_vec(size: usize) -> Vec<Self> {
+        vec![0u128; size]
+    }
+
+    fn power_of_two(pow: usize) -> Self {
+        2u128.pow(pow as u32)
+    }
+
+    fn power_of_two_vec(pow: usize, size: usize) -> Vec<Self> {
+        vec![2u128.pow(pow as u32); size]
+    }
+
+    fn pick(array: &[Self]) -> Self {
+        GENERATOR.with(|g| {
+            let mut generator = g.borrow_mut();
+            let index: u16 = generator.random_uniform();
+            let index = index % array.len() as u16;
+            array[index as usize]
+        })
+    }
+
+    fn pick_vec(array: &[Self], size: usize) -> Vec<Self> {
+        GENERATOR.with(|g| {
+            let mut generator = g.borrow_mut();
+            (0..size)
+                .map(|_| {
+                    let index: u16 = generator.random_uniform();
+                    let index = index % array.len() as u16;
+                    array[index as usize]
+                })
+                .collect()
+        })
+    }
+
+    fn uniform() -> Self {
+        GENERATOR.with(|g| {
+            let mut generator = g.borrow_mut();
+            generator.random_uniform()
+        })
+    }
+    fn uniform_vec(size: usize) -> Vec<Self> {
+        GENERATOR.with(|g| {
+            let mut generator = g.borrow_mut();
+            generator.random_uniform_tensor(size).into_container()
+        })
+    }
+    fn uniform_n_msb(n: usize) -> Self {
+        GENERATOR.with(|g| {
+            let mut generator = g.borrow_mut();
+            generator.random_uniform_n_msb(n)
+        })
+    }
+    fn uniform_n_msb_vec(n: usize, size: usize) -> Vec<Self> {
+        GENERATOR.with(|g| {
+            let mut generator = g.borrow_mut();
+            generator
+                .random_uniform_n_msb_tensor(size, n)
+                .into_container()
+        })
+    }
+
+    fn uniform_between(range: Range<usize>) -> Self {
+        GENERATOR.with(|g| {
+            let mut generator = g.borrow_mut();
+            let val: u128 = generator.random_uniform();
+            val % ((range.end as u128) - (range.start as u128)) + (range.start as u128)
+        })
+    }
+
+    fn uniform_between_vec(range: Range<usize>, size: usize) -> Vec<Self> {
+        GENERATOR.with(|g| {
+            let mut generator = g.borrow_mut();
+            let mut output = generator.random_uniform_tensor(size).into_container();
+            output.iter_mut().for_each(|val| {
+                *val %= ((range.end as u128) - (range.start as u128)) + (range.start as u128)
+            });
+            output
+        })
+    }
+
+    fn uniform_zero_centered(width: usize) -> Self {
+        let val: u128 = Self::uniform();
+        let val = val % (width as u128);
+        let val: i128 = val as i128;
+        let val = val - ((width / 2) as i128);
+        val as u128
+    }
+    fn uniform_zero_centered_vec(width: usize, size: usize) -> Vec<Self> {
+        GENERATOR.with(|g| {
+            let mut generator = g.borrow_mut();
+            let mut output = generator.random_uniform_tensor(size).into_container();
+            output.iter_mut().for_each(|val| {
+                let v = *val % (width as u128);
+                let v: i128 = v as i128;
+                let v = v - ((width / 2) as i128);
+                *val = v as u128;
+            });
+            output
+        })
+    }
+}
@@ -1,7 +1,9 @@
 use crate::{REPETITIONS, SAMPLE_SIZE};
 use concrete_core::prelude::*;
 use concrete_core_fixture::fixture::*;
-use concrete_core_fixture::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+use concrete_core_fixture::generation::{
+    BinaryKeyDistribution, Maker, Precision32, Precision64, TernaryKeyDistribution,
+};
 use concrete_csprng::seeders::UnixSeeder;
 use paste::paste;
 
@@ -41,6 +43,12 @@ type Slice64 = &'static [u64];
 type MutSlice32 = &'static mut [u32];
 type MutSlice64 = &'static mut [u64];
 
+// Helper aliases for the ternary key fixtures, which also keep the test names unique
+type TernaryLweSecretKey32 = LweSecretKey32<TernaryKeyKind>;
+type TernaryLweSecretKey64 = LweSecretKey64<TernaryKeyKind>;
+type BinaryTernaryLweKeyswitchKey32 = LweKeyswitchKey32;
+type BinaryTernaryLweKeyswitchKey64 = LweKeyswitchKey64;
+
 test! {
     ((), CleartextCreationFixture, (Cleartext)),
     ((), CleartextRetrievalFixture, (Cleartext)),
@@ -72,6 +80,38 @@ test! {
     ((BinaryKeyDistribution), GlweCiphertextConsumingRetrievalFixture, (GlweCiphertext, Vec)),
     ((BinaryKeyDistribution), GlweCiphertextConsumingRetrievalFixture, (GlweCiphertextView, Slice)),
     ((BinaryKeyDistribution), GlweCiphertextConsumingRetrievalFixture, (GlweCiphertextMutView, MutSlice)),
+    ((BinaryKeyDistribution), GlweCiphertextTensorProductFixture, (GlweCiphertext, GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingRelinearizationFixture, (GlweRelinearizationKey, GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingMultiplicationFixture, (GlweRelinearizationKey, GlweCiphertext, GlweCiphertext,
+        GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingAutomorphismFixture, (GlweAutomorphismKey, GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingTraceFixture, (GlweAutomorphismKey, GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingFixture, (GlweAutomorphismKey, LweCiphertextVector,
+        GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingAdditionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextFusingAdditionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingSubtractionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextFusingSubtractionFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingOppositeFixture, (GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextFusingOppositeFixture, (GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextPlaintextVectorDiscardingAdditionFixture, (GlweCiphertext, PlaintextVector,
+        GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextPlaintextVectorFusingAdditionFixture, (GlweCiphertext, PlaintextVector)),
+    ((BinaryKeyDistribution), GlweCiphertextCleartextVectorDiscardingMultiplicationFixture, (GlweCiphertext, CleartextVector,
+        GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextCleartextVectorFusingMultiplicationFixture, (GlweCiphertext, CleartextVector)),
+    ((BinaryKeyDistribution), GlweKeyswitchKeyGenerationFixture, (GlweSecretKey, GlweSecretKey, GlweKeyswitchKey)),
+    ((BinaryKeyDistribution), GlweSeededKeyswitchKeyGenerationFixture, (GlweSecretKey, GlweSecretKey, GlweSeededKeyswitchKey)),
+    ((BinaryKeyDistribution), GlweSeededKeyswitchKeyToGlweKeyswitchKeyTransformationFixture, (GlweSeededKeyswitchKey, GlweKeyswitchKey)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingKeyswitchFixture, (GlweKeyswitchKey, GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GswCiphertextScalarEncryptionFixture, (Plaintext, LweSecretKey, GswCiphertext)),
+    ((BinaryKeyDistribution), LweCiphertextGswCiphertextDiscardingExternalProductFixture, (LweCiphertext, GswCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution), LweCiphertextsGswCiphertextFusingCmuxFixture, (LweCiphertext, LweCiphertext, GswCiphertext)),
+    ((BinaryKeyDistribution), GlwePublicKeyGenerationFixture, (GlweSecretKey, GlwePublicKey)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingPublicKeyEncryptionFixture, (GlwePublicKey, PlaintextVector, GlweCiphertext)),
+    ((BinaryKeyDistribution), LweCiphertextDiscardingGlwePublicKeyEncryptionFixture, (GlwePublicKey, Plaintext, LweCiphertext)),
+    ((BinaryKeyDistribution), LweSeededPublicKeyGenerationFixture, (LweSecretKey, LweSeededPublicKey)),
+    ((BinaryKeyDistribution), LweSeededPublicKeyToLwePublicKeyTransformationFixture, (LweSeededPublicKey, LwePublicKey)),
     ((BinaryKeyDistribution), GlweSeededCiphertextEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweSeededCiphertext)),
     ((BinaryKeyDistribution), GlweSeededCiphertextToGlweCiphertextTransformationFixture, (PlaintextVector, GlweSecretKey, GlweSeededCiphertext, GlweCiphertext)),
     ((BinaryKeyDistribution), GlweSeededCiphertextVectorEncryptionFixture, (PlaintextVector, GlweSecretKey, GlweSeededCiphertextVector)),
@@ -90,15 +130,20 @@ test! {
     ((BinaryKeyDistribution, BinaryKeyDistribution),
         LweBootstrapKeyConsumingRetrievalFixture, (LweBootstrapKeyMutView, MutSlice)),
     ((BinaryKeyDistribution), LweCiphertextEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
+    ((TernaryKeyDistribution), LweCiphertextEncryptionFixture, (Plaintext, TernaryLweSecretKey, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextZeroEncryptionFixture, (LweSecretKey, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextTrivialEncryptionFixture, (Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextTrivialDecryptionFixture, (Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextVectorZeroEncryptionFixture, (LweSecretKey, LweCiphertextVector)),
     ((BinaryKeyDistribution), LweCiphertextDecryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextDecryptionFixture, (Plaintext, LweSecretKey, LweCiphertextView)),
+    ((TernaryKeyDistribution), LweCiphertextDecryptionFixture, (Plaintext, TernaryLweSecretKey, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingEncryptionFixture, (Plaintext, LweSecretKey, LweCiphertextMutView)),
     ((BinaryKeyDistribution), LweCiphertextVectorDecryptionFixture, (PlaintextVector, LweSecretKey, LweCiphertextVector)),
+    ((BinaryKeyDistribution), LweCiphertextModulusSwitchingFixture, (LweCiphertext, LweCompressedCiphertextVector)),
+    ((BinaryKeyDistribution), LweCiphertextVectorModulusSwitchingFixture, (LweCiphertextVector, LweCompressedCiphertextVector)),
+    ((BinaryKeyDistribution), LweCompressedCiphertextVectorDecryptionFixture, (LweSecretKey, LweCompressedCiphertextVector, PlaintextVector)),
     ((BinaryKeyDistribution), LweCiphertextVectorEncryptionFixture, (PlaintextVector, LweSecretKey, LweCiphertextVector)),
     ((BinaryKeyDistribution), LweCiphertextVectorDiscardingEncryptionFixture, (PlaintextVector, LweSecretKey,
         LweCiphertextVector)),
@@ -116,6 +161,7 @@ test! {
     ((BinaryKeyDistribution), LweCiphertextVectorDiscardingAffineTransformationFixture, (LweCiphertextVector, CleartextVector, Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingKeyswitchFixture, (LweKeyswitchKey, LweCiphertext, LweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingKeyswitchFixture, (LweKeyswitchKey, LweCiphertextView, LweCiphertextMutView)),
+    ((BinaryKeyDistribution, TernaryKeyDistribution), LweCiphertextDiscardingKeyswitchFixture, (BinaryTernaryLweKeyswitchKey, LweCiphertext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingAdditionFixture, (LweCiphertext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingAdditionFixture, (LweCiphertextView, LweCiphertextMutView)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingOppositeFixture, (LweCiphertext, LweCiphertext)),
@@ -131,10 +177,22 @@ test! {
     ((BinaryKeyDistribution), LweCiphertextPlaintextDiscardingSubtractionFixture, (LweCiphertext, Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextPlaintextFusingSubtractionFixture, (Plaintext, LweCiphertext)),
     ((BinaryKeyDistribution), LweCiphertextDiscardingExtractionFixture, (GlweCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution), LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionFixture, (GlweCiphertext, LweCiphertextVector)),
+    ((BinaryKeyDistribution), LweCiphertextVectorGlweCiphertextVectorDiscardingSampleExtractionFixture, (GlweCiphertextVector, LweCiphertextVector)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchFixture, (LweCiphertextVector, LwePackingKeyswitchKey, GlweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchFixture,
         (LweCiphertextVector, LwePrivateFunctionalPackingKeyswitchKey, GlweCiphertext,
             CleartextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweSeededPackingKeyswitchKeyGenerationFixture, (LweSecretKey, GlweSecretKey, LweSeededPackingKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweSeededPackingKeyswitchKeyToLwePackingKeyswitchKeyTransformationFixture, (LweSeededPackingKeyswitchKey, LwePackingKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweSeededPrivateFunctionalPackingKeyswitchKeyGenerationFixture,
+        (LweSecretKey, GlweSecretKey, CleartextVector, LweSeededPrivateFunctionalPackingKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationFixture,
+        (LweSeededPrivateFunctionalPackingKeyswitchKey, LwePrivateFunctionalPackingKeyswitchKey)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationFixture,
+        (LweSecretKey, GlweSecretKey, LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationFixture,
+        (LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys, LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys)),
     ((BinaryKeyDistribution), LweCiphertextCreationFixture, (LweCiphertext, Vec)),
     ((BinaryKeyDistribution), LweCiphertextCreationFixture, (LweCiphertextView, Slice)),
     ((BinaryKeyDistribution), LweCiphertextCreationFixture, (LweCiphertextMutView, MutSlice)),
@@ -145,6 +203,9 @@ test! {
     ((BinaryKeyDistribution), LweSeededCiphertextToLweCiphertextTransformationFixture, (Plaintext, LweSecretKey, LweSeededCiphertext, LweCiphertext)),
     ((BinaryKeyDistribution), LweSeededCiphertextVectorEncryptionFixture, (PlaintextVector, LweSecretKey, LweSeededCiphertextVector)),
     ((BinaryKeyDistribution), LweSeededCiphertextVectorToLweCiphertextVectorTransformationFixture, (PlaintextVector, LweSecretKey, LweSeededCiphertextVector, LweCiphertextVector)),
+    ((BinaryKeyDistribution), LweSeededCiphertextVectorCiphertextExpansionFixture, (PlaintextVector, LweSecretKey, LweSeededCiphertextVector, LweCiphertext)),
+    ((BinaryKeyDistribution), LweSeededCiphertextVectorRangeExpansionFixture, (PlaintextVector, LweSecretKey, LweSeededCiphertextVector, LweCiphertextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweSeededKeyswitchKeyRangeExpansionFixture, (LweSeededKeyswitchKey, LweKeyswitchKey)),
     ((BinaryKeyDistribution), LweToGlweSecretKeyTransformationFixture, (LweSecretKey, GlweSecretKey)),
     ((), PlaintextCreationFixture, (Plaintext)),
     ((), PlaintextDiscardingRetrievalFixture, (Plaintext)),
@@ -153,3 +214,66 @@ test! {
     ((), PlaintextVectorCreationFixture, (PlaintextVector)),
     ((), PlaintextVectorRetrievalFixture, (PlaintextVector))
 }
+
+// The seeded GGSW ciphertexts are checked with an external product, which needs the fft backend.
+#[cfg(feature = "backend_fft")]
+test! {
+    ((BinaryKeyDistribution), GgswCiphertextSeededScalarEncryptionFixture, (Plaintext, GlweSecretKey, GgswSeededCiphertext)),
+    ((BinaryKeyDistribution), GgswSeededCiphertextToGgswCiphertextTransformationFixture, (Plaintext, GlweSecretKey, GgswSeededCiphertext, GgswCiphertext))
+}
+
+fn derive_keys(
+    label: &str,
+) -> (
+    LweSecretKey64,
+    GlweSecretKey64,
+    LweKeyswitchKey64,
+    LweBootstrapKey64,
+) {
+    // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    let master_seed = Seed(0x0123_4567_89ab_cdef);
+    let (decomposition_level_count, decomposition_base_log) =
+        (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    let noise = Variance(2_f64.powi(-25));
+    let mut engine = DefaultEngine::new_derived(master_seed, label);
+    let lwe_key: LweSecretKey64 = engine
+        .generate_new_lwe_secret_key(LweDimension(10))
+        .unwrap();
+    let glwe_key: GlweSecretKey64 = engine
+        .generate_new_glwe_secret_key(GlweDimension(2), PolynomialSize(256))
+        .unwrap();
+    let ksk: LweKeyswitchKey64 = engine
+        .generate_new_lwe_keyswitch_key(
+            &lwe_key,
+            &lwe_key,
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        )
+        .unwrap();
+    let bsk: LweBootstrapKey64 = engine
+        .generate_new_lwe_bootstrap_key(
+            &lwe_key,
+            &glwe_key,
+            decomposition_base_log,
+            decomposition_level_count,
+            noise,
+        )
+        .unwrap();
+    (lwe_key, glwe_key, ksk, bsk)
+}
+
+#[test]
+fn test_derived_engines_with_the_same_label_generate_the_same_keys() {
+    assert_eq!(derive_keys("keys/0"), derive_keys("keys/0"));
+}
+
+#[test]
+fn test_derived_engines_with_distinct_labels_generate_distinct_keys() {
+    let (lwe_key_0, glwe_key_0, ksk_0, bsk_0) = derive_keys("keys/0");
+    let (lwe_key_1, glwe_key_1, ksk_1, bsk_1) = derive_keys("keys/1");
+    assert_ne!(lwe_key_0, lwe_key_1);
+    assert_ne!(glwe_key_0, glwe_key_1);
+    assert_ne!(ksk_0, ksk_1);
+    assert_ne!(bsk_0, bsk_1);
+}
@@ -1,7 +1,9 @@
 use crate::{REPETITIONS, SAMPLE_SIZE};
 use concrete_core::prelude::*;
 use concrete_core_fixture::fixture::*;
-use concrete_core_fixture::generation::{BinaryKeyDistribution, Maker, Precision32, Precision64};
+use concrete_core_fixture::generation::{
+    BinaryKeyDistribution, Maker, Precision128, Precision32, Precision64, TernaryKeyDistribution,
+};
 use paste::paste;
 
 macro_rules! test {
@@ -32,9 +34,35 @@ macro_rules! test {
     };
 }
 
+macro_rules! test_128 {
+    ($((($($key_dist:ident),*), $fixture: ident, ($($types:ident),+))),+) => {
+        $(
+            paste!{
+                test!{($($key_dist),*), $fixture, Precision128, ($([< $types 128 >]),+)}
+            }
+        )+
+    };
+}
+
 test! {
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture1, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+    ((TernaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture1, (FftFourierLweTernaryBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
     ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture2, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingMultiBootstrapFixture, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBitExtractionFixture, (FftFourierLweBootstrapKey, LweKeyswitchKey, LweCiphertext, LweCiphertextVector)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingCircuitBootstrapBooleanFixture, (LweCiphertext, GgswCiphertext, FftFourierLweBootstrapKey, LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys)),
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingFixture, (LweCiphertextVector, LweCiphertextVector, FftFourierLweBootstrapKey, PlaintextVector, LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys)),
     ((BinaryKeyDistribution), GlweCiphertextGgswCiphertextDiscardingExternalProductFixture, (GlweCiphertext, FftFourierGgswCiphertext, GlweCiphertext)),
-    ((BinaryKeyDistribution), GlweCiphertextsGgswCiphertextFusingCmuxFixture, (GlweCiphertext, GlweCiphertext, FftFourierGgswCiphertext))
+    ((BinaryKeyDistribution), GlweCiphertextsGgswCiphertextFusingCmuxFixture, (GlweCiphertext, GlweCiphertext, FftFourierGgswCiphertext)),
+    ((BinaryKeyDistribution), LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionFixture, (GlweCiphertext, LweCiphertextVector)),
+    ((BinaryKeyDistribution), LweCiphertextVectorGlweCiphertextVectorDiscardingSampleExtractionFixture, (GlweCiphertextVector, LweCiphertextVector)),
+    ((BinaryKeyDistribution), GlweCiphertextTensorProductFixture, (GlweCiphertext, GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingRelinearizationFixture, (FftFourierGlweRelinearizationKey, GlweCiphertext, GlweCiphertext)),
+    ((BinaryKeyDistribution), GlweCiphertextDiscardingMultiplicationFixture, (FftFourierGlweRelinearizationKey, GlweCiphertext, GlweCiphertext,
+        GlweCiphertext))
+}
+
+// Only a few engines support 128 bit integers, so they are tested separately.
+test_128! {
+    ((BinaryKeyDistribution, BinaryKeyDistribution), LweCiphertextDiscardingBootstrapFixture1, (FftFourierLweBootstrapKey, GlweCiphertext, LweCiphertext, LweCiphertext))
 }
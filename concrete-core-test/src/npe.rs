@@ -0,0 +1,134 @@
+use concrete_core::prelude::*;
+use concrete_csprng::seeders::UnixSeeder;
+
+// The messages are encoded on the 4 most significant bits.
+const DELTA_LOG: DeltaLog = DeltaLog(60);
+
+fn new_engines() -> (DefaultEngine, NpeEngine) {
+    let default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(0))).unwrap();
+    let npe_engine = NpeEngine::new(NpeParameters {
+        seeder: Box::new(UnixSeeder::new(0)),
+        delta_log: DELTA_LOG,
+        log2_failure_probability: -40.,
+    })
+    .unwrap();
+    (default_engine, npe_engine)
+}
+
+fn decode(raw: u64) -> u64 {
+    ((raw >> (DELTA_LOG.0 - 1)).wrapping_add(1) >> 1) % (1 << (64 - DELTA_LOG.0))
+}
+
+fn keyswitch(ksk_noise: Variance) -> Result<u64, LweCiphertextDiscardingKeyswitchError<NpeError>> {
+    let (mut default_engine, mut npe_engine) = new_engines();
+    let input_key: LweSecretKey64 = default_engine
+        .generate_new_lwe_secret_key(LweDimension(630))
+        .unwrap();
+    let output_key: LweSecretKey64 = default_engine
+        .generate_new_lwe_secret_key(LweDimension(512))
+        .unwrap();
+    let ksk: NpeLweKeyswitchKey64 = npe_engine
+        .generate_new_lwe_keyswitch_key(
+            &input_key,
+            &output_key,
+            DecompositionLevelCount(5),
+            DecompositionBaseLog(4),
+            ksk_noise,
+        )
+        .unwrap();
+    let plaintext = default_engine
+        .create_plaintext_from(&(3_u64 << 60))
+        .unwrap();
+    let input: NpeLweCiphertext64 = npe_engine
+        .encrypt_lwe_ciphertext(&input_key, &plaintext, Variance(2_f64.powi(-50)))
+        .unwrap();
+    let mut output: NpeLweCiphertext64 = npe_engine
+        .zero_encrypt_lwe_ciphertext(&output_key, Variance(2_f64.powi(-50)))
+        .unwrap();
+    npe_engine.discard_keyswitch_lwe_ciphertext(&mut output, &input, &ksk)?;
+    let decrypted = npe_engine
+        .decrypt_lwe_ciphertext(&output_key, &output)
+        .unwrap();
+    Ok(decode(
+        default_engine.retrieve_plaintext(&decrypted).unwrap(),
+    ))
+}
+
+#[test]
+fn test_keyswitch_with_valid_parameters() {
+    assert_eq!(keyswitch(Variance(2_f64.powi(-70))).unwrap(), 3);
+}
+
+#[test]
+fn test_keyswitch_with_too_much_noise() {
+    assert!(matches!(
+        keyswitch(Variance(2_f64.powi(-20))),
+        Err(LweCiphertextDiscardingKeyswitchError::Engine(
+            NpeError::NoiseOverflow { .. }
+        ))
+    ));
+}
+
+#[cfg(feature = "backend_fft")]
+fn bootstrap(bsk_noise: Variance) -> Result<u64, LweCiphertextDiscardingBootstrapError<NpeError>> {
+    let (mut default_engine, mut npe_engine) = new_engines();
+    let (glwe_dimension, polynomial_size) = (GlweDimension(1), PolynomialSize(1024));
+    let lwe_key: LweSecretKey64 = default_engine
+        .generate_new_lwe_secret_key(LweDimension(630))
+        .unwrap();
+    let glwe_key: GlweSecretKey64 = default_engine
+        .generate_new_glwe_secret_key(glwe_dimension, polynomial_size)
+        .unwrap();
+    let bsk: NpeFftFourierLweBootstrapKey64 = npe_engine
+        .generate_new_lwe_bootstrap_key(
+            &lwe_key,
+            &glwe_key,
+            DecompositionBaseLog(15),
+            DecompositionLevelCount(2),
+            bsk_noise,
+        )
+        .unwrap();
+    let output_key = default_engine
+        .transform_glwe_secret_key_to_lwe_secret_key(glwe_key)
+        .unwrap();
+    // The accumulator applies a constant function.
+    let lut = default_engine
+        .create_plaintext_vector_from(&vec![5_u64 << 60; polynomial_size.0])
+        .unwrap();
+    let acc: NpeGlweCiphertext64 = npe_engine
+        .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &lut)
+        .unwrap();
+    let plaintext = default_engine
+        .create_plaintext_from(&(3_u64 << 60))
+        .unwrap();
+    let input: NpeLweCiphertext64 = npe_engine
+        .encrypt_lwe_ciphertext(&lwe_key, &plaintext, Variance(2_f64.powi(-50)))
+        .unwrap();
+    let mut output: NpeLweCiphertext64 = npe_engine
+        .zero_encrypt_lwe_ciphertext(&output_key, Variance(2_f64.powi(-50)))
+        .unwrap();
+    npe_engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    let decrypted = npe_engine
+        .decrypt_lwe_ciphertext(&output_key, &output)
+        .unwrap();
+    Ok(decode(
+        default_engine.retrieve_plaintext(&decrypted).unwrap(),
+    ))
+}
+
+#[cfg(feature = "backend_fft")]
+#[test]
+fn test_bootstrap_with_valid_parameters() {
+    assert_eq!(bootstrap(Variance(2_f64.powi(-100))).unwrap(), 5);
+}
+
+#[cfg(feature = "backend_fft")]
+#[test]
+fn test_bootstrap_with_too_much_noise() {
+    assert!(matches!(
+        bootstrap(Variance(2_f64.powi(-30))),
+        Err(LweCiphertextDiscardingBootstrapError::Engine(
+            NpeError::NoiseOverflow { .. }
+        ))
+    ));
+}
@@ -0,0 +1,224 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::key_kinds::KeyKind;
+use concrete_commons::parameters::{
+    AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount,
+};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweAutomorphismKey32, GlweAutomorphismKey64, GlweSecretKey32, GlweSecretKey64,
+};
+use crate::commons::crypto::glwe::GlweAutomorphismKey as ImplGlweAutomorphismKey;
+use crate::specification::engines::{
+    GlweAutomorphismKeyGenerationEngine, GlweAutomorphismKeyGenerationError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+use std::fmt::Debug;
+
+/// # Description:
+/// Implementation of [`GlweAutomorphismKeyGenerationEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers.
+impl<Kind> GlweAutomorphismKeyGenerationEngine<GlweSecretKey32<Kind>, GlweAutomorphismKey32>
+    for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let exponents = [AutomorphismExponent(3), AutomorphismExponent(5)];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let automorphism_key: GlweAutomorphismKey32 = engine.generate_new_glwe_automorphism_key(
+    ///     &key,
+    ///     &exponents,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(automorphism_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(automorphism_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     automorphism_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(
+    ///     automorphism_key.decomposition_base_log(),
+    ///     decomposition_base_log
+    /// );
+    /// assert_eq!(automorphism_key.automorphism_exponents(), &exponents);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_automorphism_key(
+        &mut self,
+        input_key: &GlweSecretKey32<Kind>,
+        exponents: &[AutomorphismExponent],
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<GlweAutomorphismKey32, GlweAutomorphismKeyGenerationError<Self::EngineError>> {
+        GlweAutomorphismKeyGenerationError::perform_generic_checks(
+            exponents,
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_automorphism_key_unchecked(
+                input_key,
+                exponents,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_automorphism_key_unchecked(
+        &mut self,
+        input_key: &GlweSecretKey32<Kind>,
+        exponents: &[AutomorphismExponent],
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> GlweAutomorphismKey32 {
+        let mut key = ImplGlweAutomorphismKey::allocate(
+            0u32,
+            input_key.polynomial_size(),
+            input_key.glwe_dimension(),
+            decomposition_level_count,
+            decomposition_base_log,
+            exponents,
+        );
+        key.fill_with_automorphism_key(&input_key.0, noise, &mut self.encryption_generator);
+        GlweAutomorphismKey32(key)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweAutomorphismKeyGenerationEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers.
+impl<Kind> GlweAutomorphismKeyGenerationEngine<GlweSecretKey64<Kind>, GlweAutomorphismKey64>
+    for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let exponents = [AutomorphismExponent(3), AutomorphismExponent(5)];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let automorphism_key: GlweAutomorphismKey64 = engine.generate_new_glwe_automorphism_key(
+    ///     &key,
+    ///     &exponents,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(automorphism_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(automorphism_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     automorphism_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(
+    ///     automorphism_key.decomposition_base_log(),
+    ///     decomposition_base_log
+    /// );
+    /// assert_eq!(automorphism_key.automorphism_exponents(), &exponents);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_automorphism_key(
+        &mut self,
+        input_key: &GlweSecretKey64<Kind>,
+        exponents: &[AutomorphismExponent],
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<GlweAutomorphismKey64, GlweAutomorphismKeyGenerationError<Self::EngineError>> {
+        GlweAutomorphismKeyGenerationError::perform_generic_checks(
+            exponents,
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_automorphism_key_unchecked(
+                input_key,
+                exponents,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_automorphism_key_unchecked(
+        &mut self,
+        input_key: &GlweSecretKey64<Kind>,
+        exponents: &[AutomorphismExponent],
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> GlweAutomorphismKey64 {
+        let mut key = ImplGlweAutomorphismKey::allocate(
+            0u64,
+            input_key.polynomial_size(),
+            input_key.glwe_dimension(),
+            decomposition_level_count,
+            decomposition_base_log,
+            exponents,
+        );
+        key.fill_with_automorphism_key(&input_key.0, noise, &mut self.encryption_generator);
+        GlweAutomorphismKey64(key)
+    }
+}
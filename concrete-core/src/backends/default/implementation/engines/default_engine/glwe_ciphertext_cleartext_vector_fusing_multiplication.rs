@@ -0,0 +1,150 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    CleartextVector32, CleartextVector64, GlweCiphertext32, GlweCiphertext64,
+};
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::specification::engines::{
+    GlweCiphertextCleartextVectorFusingMultiplicationEngine,
+    GlweCiphertextCleartextVectorFusingMultiplicationError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCleartextVectorFusingMultiplicationEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl GlweCiphertextCleartextVectorFusingMultiplicationEngine<GlweCiphertext32, CleartextVector32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// // The cleartext vector holds the coefficients of the polynomial to multiply with
+    /// let cleartext_input = vec![1_u32, 0, 2, 0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let cleartext_vector = engine.create_cleartext_vector_from(&cleartext_input)?;
+    /// let mut ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// engine.fuse_mul_glwe_ciphertext_cleartext_vector(&mut ciphertext, &cleartext_vector)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_glwe_ciphertext_cleartext_vector(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &CleartextVector32,
+    ) -> Result<(), GlweCiphertextCleartextVectorFusingMultiplicationError<Self::EngineError>> {
+        GlweCiphertextCleartextVectorFusingMultiplicationError::perform_generic_checks(
+            output, input,
+        )?;
+        unsafe { self.fuse_mul_glwe_ciphertext_cleartext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_glwe_ciphertext_cleartext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &CleartextVector32,
+    ) {
+        let cleartext_polynomial = Polynomial::from_container(input.0.as_tensor().as_slice());
+        let mut buffer = Polynomial::allocate(0u32, output.0.polynomial_size());
+        for mut output_polynomial in output.0.as_mut_polynomial_list().polynomial_iter_mut() {
+            buffer
+                .as_mut_tensor()
+                .fill_with_copy(output_polynomial.as_tensor());
+            output_polynomial.fill_with_wrapping_mul(&buffer, &cleartext_polynomial);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCleartextVectorFusingMultiplicationEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl GlweCiphertextCleartextVectorFusingMultiplicationEngine<GlweCiphertext64, CleartextVector64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// // The cleartext vector holds the coefficients of the polynomial to multiply with
+    /// let cleartext_input = vec![1_u64, 0, 2, 0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let cleartext_vector = engine.create_cleartext_vector_from(&cleartext_input)?;
+    /// let mut ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// engine.fuse_mul_glwe_ciphertext_cleartext_vector(&mut ciphertext, &cleartext_vector)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_glwe_ciphertext_cleartext_vector(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &CleartextVector64,
+    ) -> Result<(), GlweCiphertextCleartextVectorFusingMultiplicationError<Self::EngineError>> {
+        GlweCiphertextCleartextVectorFusingMultiplicationError::perform_generic_checks(
+            output, input,
+        )?;
+        unsafe { self.fuse_mul_glwe_ciphertext_cleartext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_glwe_ciphertext_cleartext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &CleartextVector64,
+    ) {
+        let cleartext_polynomial = Polynomial::from_container(input.0.as_tensor().as_slice());
+        let mut buffer = Polynomial::allocate(0u64, output.0.polynomial_size());
+        for mut output_polynomial in output.0.as_mut_polynomial_list().polynomial_iter_mut() {
+            buffer
+                .as_mut_tensor()
+                .fill_with_copy(output_polynomial.as_tensor());
+            output_polynomial.fill_with_wrapping_mul(&buffer, &cleartext_polynomial);
+        }
+    }
+}
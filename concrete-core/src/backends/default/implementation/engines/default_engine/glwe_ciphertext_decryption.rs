@@ -2,20 +2,25 @@ use concrete_commons::parameters::PlaintextCount;
 
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    GlweCiphertext32, GlweCiphertext64, GlweSecretKey32, GlweSecretKey64, PlaintextVector32,
-    PlaintextVector64,
+    GlweCiphertext128, GlweCiphertext32, GlweCiphertext64, GlweSecretKey128, GlweSecretKey32,
+    GlweSecretKey64, PlaintextVector128, PlaintextVector32, PlaintextVector64,
 };
 use crate::commons::crypto::encoding::PlaintextList as ImplPlaintextList;
 use crate::specification::engines::{
     GlweCiphertextDecryptionEngine, GlweCiphertextDecryptionError,
 };
 use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`GlweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on 32
 /// bits integers.
-impl GlweCiphertextDecryptionEngine<GlweSecretKey32, GlweCiphertext32, PlaintextVector32>
+impl<Kind>
+    GlweCiphertextDecryptionEngine<GlweSecretKey32<Kind>, GlweCiphertext32, PlaintextVector32>
     for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -53,7 +58,7 @@ impl GlweCiphertextDecryptionEngine<GlweSecretKey32, GlweCiphertext32, Plaintext
     /// ```
     fn decrypt_glwe_ciphertext(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         input: &GlweCiphertext32,
     ) -> Result<PlaintextVector32, GlweCiphertextDecryptionError<Self::EngineError>> {
         GlweCiphertextDecryptionError::perform_generic_checks(key, input)?;
@@ -62,7 +67,7 @@ impl GlweCiphertextDecryptionEngine<GlweSecretKey32, GlweCiphertext32, Plaintext
 
     unsafe fn decrypt_glwe_ciphertext_unchecked(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         input: &GlweCiphertext32,
     ) -> PlaintextVector32 {
         let mut plaintext =
@@ -75,8 +80,11 @@ impl GlweCiphertextDecryptionEngine<GlweSecretKey32, GlweCiphertext32, Plaintext
 /// # Description:
 /// Implementation of [`GlweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on 64
 /// bits integers.
-impl GlweCiphertextDecryptionEngine<GlweSecretKey64, GlweCiphertext64, PlaintextVector64>
+impl<Kind>
+    GlweCiphertextDecryptionEngine<GlweSecretKey64<Kind>, GlweCiphertext64, PlaintextVector64>
     for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -114,7 +122,7 @@ impl GlweCiphertextDecryptionEngine<GlweSecretKey64, GlweCiphertext64, Plaintext
     /// ```
     fn decrypt_glwe_ciphertext(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         input: &GlweCiphertext64,
     ) -> Result<PlaintextVector64, GlweCiphertextDecryptionError<Self::EngineError>> {
         GlweCiphertextDecryptionError::perform_generic_checks(key, input)?;
@@ -123,7 +131,7 @@ impl GlweCiphertextDecryptionEngine<GlweSecretKey64, GlweCiphertext64, Plaintext
 
     unsafe fn decrypt_glwe_ciphertext_unchecked(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         input: &GlweCiphertext64,
     ) -> PlaintextVector64 {
         let mut plaintext =
@@ -132,3 +140,67 @@ impl GlweCiphertextDecryptionEngine<GlweSecretKey64, GlweCiphertext64, Plaintext
         PlaintextVector64(plaintext)
     }
 }
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on 128
+/// bits integers.
+impl<Kind>
+    GlweCiphertextDecryptionEngine<GlweSecretKey128<Kind>, GlweCiphertext128, PlaintextVector128>
+    for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 110 bits)
+    /// let input = vec![3_u128 << 110; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey128 = engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector = engine.decrypt_glwe_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(
+    /// #     decrypted_plaintext_vector.plaintext_count(),
+    /// #     plaintext_vector.plaintext_count()
+    /// # );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext(
+        &mut self,
+        key: &GlweSecretKey128<Kind>,
+        input: &GlweCiphertext128,
+    ) -> Result<PlaintextVector128, GlweCiphertextDecryptionError<Self::EngineError>> {
+        GlweCiphertextDecryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.decrypt_glwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey128<Kind>,
+        input: &GlweCiphertext128,
+    ) -> PlaintextVector128 {
+        let mut plaintext =
+            ImplPlaintextList::allocate(0u128, PlaintextCount(key.polynomial_size().0));
+        key.0.decrypt_glwe(&mut plaintext, &input.0);
+        PlaintextVector128(plaintext)
+    }
+}
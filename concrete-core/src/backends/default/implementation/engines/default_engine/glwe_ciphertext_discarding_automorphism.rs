@@ -0,0 +1,189 @@
+use concrete_commons::parameters::AutomorphismExponent;
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweAutomorphismKey32, GlweAutomorphismKey64, GlweCiphertext32, GlweCiphertext64,
+};
+use crate::specification::engines::{
+    GlweCiphertextDiscardingAutomorphismEngine, GlweCiphertextDiscardingAutomorphismError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingAutomorphismEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    GlweCiphertextDiscardingAutomorphismEngine<
+        GlweAutomorphismKey32,
+        GlweCiphertext32,
+        GlweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let exponent = AutomorphismExponent(5);
+    /// // Here a hard-set encoding is applied (shift by 28 bits)
+    /// let input = vec![3_u32 << 28; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_key: GlweAutomorphismKey32 = engine.generate_new_glwe_automorphism_key(
+    ///     &key,
+    ///     &[exponent],
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let input_ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut output_ciphertext = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    /// engine.discard_automorphism_glwe_ciphertext(
+    ///     &mut output_ciphertext,
+    ///     &input_ciphertext,
+    ///     &automorphism_key,
+    ///     exponent,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output_ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_automorphism_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        key: &GlweAutomorphismKey32,
+        exponent: AutomorphismExponent,
+    ) -> Result<(), GlweCiphertextDiscardingAutomorphismError<Self::EngineError>> {
+        GlweCiphertextDiscardingAutomorphismError::perform_generic_checks(
+            output, input, key, exponent,
+        )?;
+        unsafe {
+            self.discard_automorphism_glwe_ciphertext_unchecked(output, input, key, exponent)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_automorphism_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        key: &GlweAutomorphismKey32,
+        exponent: AutomorphismExponent,
+    ) {
+        key.0
+            .automorphism_ciphertext(&mut output.0, &input.0, exponent);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingAutomorphismEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    GlweCiphertextDiscardingAutomorphismEngine<
+        GlweAutomorphismKey64,
+        GlweCiphertext64,
+        GlweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let exponent = AutomorphismExponent(5);
+    /// // Here a hard-set encoding is applied (shift by 60 bits)
+    /// let input = vec![3_u64 << 60; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_key: GlweAutomorphismKey64 = engine.generate_new_glwe_automorphism_key(
+    ///     &key,
+    ///     &[exponent],
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let input_ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut output_ciphertext = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    /// engine.discard_automorphism_glwe_ciphertext(
+    ///     &mut output_ciphertext,
+    ///     &input_ciphertext,
+    ///     &automorphism_key,
+    ///     exponent,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output_ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_automorphism_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        key: &GlweAutomorphismKey64,
+        exponent: AutomorphismExponent,
+    ) -> Result<(), GlweCiphertextDiscardingAutomorphismError<Self::EngineError>> {
+        GlweCiphertextDiscardingAutomorphismError::perform_generic_checks(
+            output, input, key, exponent,
+        )?;
+        unsafe {
+            self.discard_automorphism_glwe_ciphertext_unchecked(output, input, key, exponent)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_automorphism_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        key: &GlweAutomorphismKey64,
+        exponent: AutomorphismExponent,
+    ) {
+        key.0
+            .automorphism_ciphertext(&mut output.0, &input.0, exponent);
+    }
+}
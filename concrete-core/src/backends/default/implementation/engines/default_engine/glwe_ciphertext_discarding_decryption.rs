@@ -6,12 +6,20 @@ use crate::backends::default::implementation::entities::{
 use crate::specification::engines::{
     GlweCiphertextDiscardingDecryptionEngine, GlweCiphertextDiscardingDecryptionError,
 };
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`GlweCiphertextDiscardingDecryptionEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
-impl GlweCiphertextDiscardingDecryptionEngine<GlweSecretKey32, GlweCiphertext32, PlaintextVector32>
-    for DefaultEngine
+impl<Kind>
+    GlweCiphertextDiscardingDecryptionEngine<
+        GlweSecretKey32<Kind>,
+        GlweCiphertext32,
+        PlaintextVector32,
+    > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -47,7 +55,7 @@ impl GlweCiphertextDiscardingDecryptionEngine<GlweSecretKey32, GlweCiphertext32,
     /// ```
     fn discard_decrypt_glwe_ciphertext(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         output: &mut PlaintextVector32,
         input: &GlweCiphertext32,
     ) -> Result<(), GlweCiphertextDiscardingDecryptionError<Self::EngineError>> {
@@ -58,7 +66,7 @@ impl GlweCiphertextDiscardingDecryptionEngine<GlweSecretKey32, GlweCiphertext32,
 
     unsafe fn discard_decrypt_glwe_ciphertext_unchecked(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         output: &mut PlaintextVector32,
         input: &GlweCiphertext32,
     ) {
@@ -69,8 +77,14 @@ impl GlweCiphertextDiscardingDecryptionEngine<GlweSecretKey32, GlweCiphertext32,
 /// # Description:
 /// Implementation of [`GlweCiphertextDiscardingDecryptionEngine`] for [`DefaultEngine`] that
 /// operates on 64 bits integers.
-impl GlweCiphertextDiscardingDecryptionEngine<GlweSecretKey64, GlweCiphertext64, PlaintextVector64>
-    for DefaultEngine
+impl<Kind>
+    GlweCiphertextDiscardingDecryptionEngine<
+        GlweSecretKey64<Kind>,
+        GlweCiphertext64,
+        PlaintextVector64,
+    > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -106,7 +120,7 @@ impl GlweCiphertextDiscardingDecryptionEngine<GlweSecretKey64, GlweCiphertext64,
     /// ```
     fn discard_decrypt_glwe_ciphertext(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         output: &mut PlaintextVector64,
         input: &GlweCiphertext64,
     ) -> Result<(), GlweCiphertextDiscardingDecryptionError<Self::EngineError>> {
@@ -117,7 +131,7 @@ impl GlweCiphertextDiscardingDecryptionEngine<GlweSecretKey64, GlweCiphertext64,
 
     unsafe fn discard_decrypt_glwe_ciphertext_unchecked(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         output: &mut PlaintextVector64,
         input: &GlweCiphertext64,
     ) {
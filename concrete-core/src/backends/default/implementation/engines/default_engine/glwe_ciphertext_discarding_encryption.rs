@@ -8,12 +8,20 @@ use crate::backends::default::implementation::entities::{
 use crate::specification::engines::{
     GlweCiphertextDiscardingEncryptionEngine, GlweCiphertextDiscardingEncryptionError,
 };
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`GlweCiphertextDiscardingEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
-impl GlweCiphertextDiscardingEncryptionEngine<GlweSecretKey32, PlaintextVector32, GlweCiphertext32>
-    for DefaultEngine
+impl<Kind>
+    GlweCiphertextDiscardingEncryptionEngine<
+        GlweSecretKey32<Kind>,
+        PlaintextVector32,
+        GlweCiphertext32,
+    > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -55,7 +63,7 @@ impl GlweCiphertextDiscardingEncryptionEngine<GlweSecretKey32, PlaintextVector32
     /// ```
     fn discard_encrypt_glwe_ciphertext(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         output: &mut GlweCiphertext32,
         input: &PlaintextVector32,
         noise: Variance,
@@ -67,7 +75,7 @@ impl GlweCiphertextDiscardingEncryptionEngine<GlweSecretKey32, PlaintextVector32
 
     unsafe fn discard_encrypt_glwe_ciphertext_unchecked(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         output: &mut GlweCiphertext32,
         input: &PlaintextVector32,
         noise: Variance,
@@ -84,8 +92,14 @@ impl GlweCiphertextDiscardingEncryptionEngine<GlweSecretKey32, PlaintextVector32
 /// # Description:
 /// Implementation of [`GlweCiphertextDiscardingEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 64 bits integers.
-impl GlweCiphertextDiscardingEncryptionEngine<GlweSecretKey64, PlaintextVector64, GlweCiphertext64>
-    for DefaultEngine
+impl<Kind>
+    GlweCiphertextDiscardingEncryptionEngine<
+        GlweSecretKey64<Kind>,
+        PlaintextVector64,
+        GlweCiphertext64,
+    > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -127,7 +141,7 @@ impl GlweCiphertextDiscardingEncryptionEngine<GlweSecretKey64, PlaintextVector64
     /// ```
     fn discard_encrypt_glwe_ciphertext(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         output: &mut GlweCiphertext64,
         input: &PlaintextVector64,
         noise: Variance,
@@ -139,7 +153,7 @@ impl GlweCiphertextDiscardingEncryptionEngine<GlweSecretKey64, PlaintextVector64
 
     unsafe fn discard_encrypt_glwe_ciphertext_unchecked(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         output: &mut GlweCiphertext64,
         input: &PlaintextVector64,
         noise: Variance,
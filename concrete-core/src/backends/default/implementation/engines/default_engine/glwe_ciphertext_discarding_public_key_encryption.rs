@@ -0,0 +1,193 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlwePublicKey32, GlwePublicKey64, PlaintextVector32,
+    PlaintextVector64,
+};
+use crate::specification::engines::{
+    GlweCiphertextDiscardingPublicKeyEncryptionEngine,
+    GlweCiphertextDiscardingPublicKeyEncryptionError,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::key_kinds::BinaryKeyKind;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingPublicKeyEncryptionEngine`] for [`DefaultEngine`]
+/// that operates on 32 bits integers. The randomness polynomials are sampled with uniform binary
+/// coefficients.
+impl
+    GlweCiphertextDiscardingPublicKeyEncryptionEngine<
+        GlwePublicKey32,
+        PlaintextVector32,
+        GlweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, GlwePublicKeyZeroEncryptionCount, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let secret_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let public_key: GlwePublicKey32 = engine.generate_new_glwe_public_key(
+    ///     &secret_key,
+    ///     noise,
+    ///     GlwePublicKeyZeroEncryptionCount(1),
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let mut ciphertext = engine.zero_encrypt_glwe_ciphertext(&secret_key, noise)?;
+    ///
+    /// engine.discard_encrypt_glwe_ciphertext_with_public_key(
+    ///     &public_key,
+    ///     &mut ciphertext,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_glwe_ciphertext_with_public_key(
+        &mut self,
+        key: &GlwePublicKey32,
+        output: &mut GlweCiphertext32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<(), GlweCiphertextDiscardingPublicKeyEncryptionError<Self::EngineError>> {
+        GlweCiphertextDiscardingPublicKeyEncryptionError::perform_generic_checks(
+            key, output, input,
+        )?;
+        unsafe {
+            self.discard_encrypt_glwe_ciphertext_with_public_key_unchecked(
+                key, output, input, noise,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_glwe_ciphertext_with_public_key_unchecked(
+        &mut self,
+        key: &GlwePublicKey32,
+        output: &mut GlweCiphertext32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) {
+        key.0.encrypt_glwe::<BinaryKeyKind, _, _, _, _>(
+            &mut output.0,
+            &input.0,
+            noise,
+            &mut self.secret_generator,
+            &mut self.encryption_generator,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingPublicKeyEncryptionEngine`] for [`DefaultEngine`]
+/// that operates on 64 bits integers. The randomness polynomials are sampled with uniform binary
+/// coefficients.
+impl
+    GlweCiphertextDiscardingPublicKeyEncryptionEngine<
+        GlwePublicKey64,
+        PlaintextVector64,
+        GlweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, GlwePublicKeyZeroEncryptionCount, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let secret_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let public_key: GlwePublicKey64 = engine.generate_new_glwe_public_key(
+    ///     &secret_key,
+    ///     noise,
+    ///     GlwePublicKeyZeroEncryptionCount(1),
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let mut ciphertext = engine.zero_encrypt_glwe_ciphertext(&secret_key, noise)?;
+    ///
+    /// engine.discard_encrypt_glwe_ciphertext_with_public_key(
+    ///     &public_key,
+    ///     &mut ciphertext,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_glwe_ciphertext_with_public_key(
+        &mut self,
+        key: &GlwePublicKey64,
+        output: &mut GlweCiphertext64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<(), GlweCiphertextDiscardingPublicKeyEncryptionError<Self::EngineError>> {
+        GlweCiphertextDiscardingPublicKeyEncryptionError::perform_generic_checks(
+            key, output, input,
+        )?;
+        unsafe {
+            self.discard_encrypt_glwe_ciphertext_with_public_key_unchecked(
+                key, output, input, noise,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_glwe_ciphertext_with_public_key_unchecked(
+        &mut self,
+        key: &GlwePublicKey64,
+        output: &mut GlweCiphertext64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) {
+        key.0.encrypt_glwe::<BinaryKeyKind, _, _, _, _>(
+            &mut output.0,
+            &input.0,
+            noise,
+            &mut self.secret_generator,
+            &mut self.encryption_generator,
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweRelinearizationKey32, GlweRelinearizationKey64,
+};
+use crate::specification::engines::{
+    GlweCiphertextDiscardingRelinearizationEngine, GlweCiphertextDiscardingRelinearizationError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingRelinearizationEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    GlweCiphertextDiscardingRelinearizationEngine<
+        GlweRelinearizationKey32,
+        GlweCiphertext32,
+        GlweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_2 = vec![2_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let relinearization_key: GlweRelinearizationKey32 = engine
+    ///     .generate_new_glwe_relinearization_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let product: GlweCiphertext32 = engine.compute_tensor_product_glwe_ciphertext(
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     DeltaLog(20),
+    /// )?;
+    ///
+    /// let mut ciphertext = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    /// engine.discard_relinearize_glwe_ciphertext(
+    ///     &mut ciphertext,
+    ///     &product,
+    ///     &relinearization_key,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_relinearize_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        relinearization_key: &GlweRelinearizationKey32,
+    ) -> Result<(), GlweCiphertextDiscardingRelinearizationError<Self::EngineError>> {
+        GlweCiphertextDiscardingRelinearizationError::perform_generic_checks(
+            output,
+            input,
+            relinearization_key,
+        )?;
+        unsafe {
+            self.discard_relinearize_glwe_ciphertext_unchecked(output, input, relinearization_key)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_relinearize_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        relinearization_key: &GlweRelinearizationKey32,
+    ) {
+        relinearization_key.0.relinearize(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingRelinearizationEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    GlweCiphertextDiscardingRelinearizationEngine<
+        GlweRelinearizationKey64,
+        GlweCiphertext64,
+        GlweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = vec![3_u64 << 50; polynomial_size.0];
+    /// let input_2 = vec![2_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let relinearization_key: GlweRelinearizationKey64 = engine
+    ///     .generate_new_glwe_relinearization_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let product: GlweCiphertext64 = engine.compute_tensor_product_glwe_ciphertext(
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     DeltaLog(50),
+    /// )?;
+    ///
+    /// let mut ciphertext = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    /// engine.discard_relinearize_glwe_ciphertext(
+    ///     &mut ciphertext,
+    ///     &product,
+    ///     &relinearization_key,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_relinearize_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        relinearization_key: &GlweRelinearizationKey64,
+    ) -> Result<(), GlweCiphertextDiscardingRelinearizationError<Self::EngineError>> {
+        GlweCiphertextDiscardingRelinearizationError::perform_generic_checks(
+            output,
+            input,
+            relinearization_key,
+        )?;
+        unsafe {
+            self.discard_relinearize_glwe_ciphertext_unchecked(output, input, relinearization_key)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_relinearize_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        relinearization_key: &GlweRelinearizationKey64,
+    ) {
+        relinearization_key.0.relinearize(&mut output.0, &input.0);
+    }
+}
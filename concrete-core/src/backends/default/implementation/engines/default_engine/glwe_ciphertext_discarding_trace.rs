@@ -0,0 +1,169 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweAutomorphismKey32, GlweAutomorphismKey64, GlweCiphertext32, GlweCiphertext64,
+};
+use crate::specification::engines::{
+    GlweCiphertextDiscardingTraceEngine, GlweCiphertextDiscardingTraceError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingTraceEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers.
+impl GlweCiphertextDiscardingTraceEngine<GlweAutomorphismKey32, GlweCiphertext32, GlweCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let decomposition_base_log = DecompositionBaseLog(4);
+    /// // The exponents 2^i + 1 for 1 <= i <= log2(N)
+    /// let exponents: Vec<_> = (1..=8)
+    ///     .map(|i| AutomorphismExponent((1 << i) + 1))
+    ///     .collect();
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_key: GlweAutomorphismKey32 = engine.generate_new_glwe_automorphism_key(
+    ///     &key,
+    ///     &exponents,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// // Here a hard-set encoding is applied (shift by 28 bits)
+    /// let input = vec![3_u32 << 28; polynomial_size.0];
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let input_ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut output_ciphertext = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    /// engine.discard_trace_glwe_ciphertext(
+    ///     &mut output_ciphertext,
+    ///     &input_ciphertext,
+    ///     &automorphism_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output_ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_trace_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        key: &GlweAutomorphismKey32,
+    ) -> Result<(), GlweCiphertextDiscardingTraceError<Self::EngineError>> {
+        GlweCiphertextDiscardingTraceError::perform_generic_checks(output, input, key)?;
+        unsafe { self.discard_trace_glwe_ciphertext_unchecked(output, input, key) };
+        Ok(())
+    }
+
+    unsafe fn discard_trace_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        key: &GlweAutomorphismKey32,
+    ) {
+        key.0.trace_ciphertext(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingTraceEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers.
+impl GlweCiphertextDiscardingTraceEngine<GlweAutomorphismKey64, GlweCiphertext64, GlweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let decomposition_base_log = DecompositionBaseLog(10);
+    /// // The exponents 2^i + 1 for 1 <= i <= log2(N)
+    /// let exponents: Vec<_> = (1..=8)
+    ///     .map(|i| AutomorphismExponent((1 << i) + 1))
+    ///     .collect();
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_key: GlweAutomorphismKey64 = engine.generate_new_glwe_automorphism_key(
+    ///     &key,
+    ///     &exponents,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// // Here a hard-set encoding is applied (shift by 60 bits)
+    /// let input = vec![3_u64 << 60; polynomial_size.0];
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let input_ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut output_ciphertext = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    /// engine.discard_trace_glwe_ciphertext(
+    ///     &mut output_ciphertext,
+    ///     &input_ciphertext,
+    ///     &automorphism_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output_ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_trace_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        key: &GlweAutomorphismKey64,
+    ) -> Result<(), GlweCiphertextDiscardingTraceError<Self::EngineError>> {
+        GlweCiphertextDiscardingTraceError::perform_generic_checks(output, input, key)?;
+        unsafe { self.discard_trace_glwe_ciphertext_unchecked(output, input, key) };
+        Ok(())
+    }
+
+    unsafe fn discard_trace_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        key: &GlweAutomorphismKey64,
+    ) {
+        key.0.trace_ciphertext(&mut output.0, &input.0);
+    }
+}
@@ -2,20 +2,25 @@ use concrete_commons::dispersion::Variance;
 
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    GlweCiphertext32, GlweCiphertext64, GlweSecretKey32, GlweSecretKey64, PlaintextVector32,
-    PlaintextVector64,
+    GlweCiphertext128, GlweCiphertext32, GlweCiphertext64, GlweSecretKey128, GlweSecretKey32,
+    GlweSecretKey64, PlaintextVector128, PlaintextVector32, PlaintextVector64,
 };
 use crate::commons::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
 use crate::specification::engines::{
     GlweCiphertextEncryptionEngine, GlweCiphertextEncryptionError,
 };
 use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`GlweCiphertextEncryptionEngine`] for [`DefaultEngine`] that operates on 32
 /// bits integers.
-impl GlweCiphertextEncryptionEngine<GlweSecretKey32, PlaintextVector32, GlweCiphertext32>
+impl<Kind>
+    GlweCiphertextEncryptionEngine<GlweSecretKey32<Kind>, PlaintextVector32, GlweCiphertext32>
     for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -52,7 +57,7 @@ impl GlweCiphertextEncryptionEngine<GlweSecretKey32, PlaintextVector32, GlweCiph
     /// ```
     fn encrypt_glwe_ciphertext(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         input: &PlaintextVector32,
         noise: Variance,
     ) -> Result<GlweCiphertext32, GlweCiphertextEncryptionError<Self::EngineError>> {
@@ -62,7 +67,7 @@ impl GlweCiphertextEncryptionEngine<GlweSecretKey32, PlaintextVector32, GlweCiph
 
     unsafe fn encrypt_glwe_ciphertext_unchecked(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         input: &PlaintextVector32,
         noise: Variance,
     ) -> GlweCiphertext32 {
@@ -84,8 +89,11 @@ impl GlweCiphertextEncryptionEngine<GlweSecretKey32, PlaintextVector32, GlweCiph
 /// # Description:
 /// Implementation of [`GlweCiphertextEncryptionEngine`] for [`DefaultEngine`] that operates on 64
 /// bits integers.
-impl GlweCiphertextEncryptionEngine<GlweSecretKey64, PlaintextVector64, GlweCiphertext64>
+impl<Kind>
+    GlweCiphertextEncryptionEngine<GlweSecretKey64<Kind>, PlaintextVector64, GlweCiphertext64>
     for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -122,7 +130,7 @@ impl GlweCiphertextEncryptionEngine<GlweSecretKey64, PlaintextVector64, GlweCiph
     /// ```
     fn encrypt_glwe_ciphertext(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         input: &PlaintextVector64,
         noise: Variance,
     ) -> Result<GlweCiphertext64, GlweCiphertextEncryptionError<Self::EngineError>> {
@@ -132,7 +140,7 @@ impl GlweCiphertextEncryptionEngine<GlweSecretKey64, PlaintextVector64, GlweCiph
 
     unsafe fn encrypt_glwe_ciphertext_unchecked(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         input: &PlaintextVector64,
         noise: Variance,
     ) -> GlweCiphertext64 {
@@ -150,3 +158,76 @@ impl GlweCiphertextEncryptionEngine<GlweSecretKey64, PlaintextVector64, GlweCiph
         GlweCiphertext64(ciphertext)
     }
 }
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`DefaultEngine`] that operates on 128
+/// bits integers.
+impl<Kind>
+    GlweCiphertextEncryptionEngine<GlweSecretKey128<Kind>, PlaintextVector128, GlweCiphertext128>
+    for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 110 bits)
+    /// let input = vec![3_u128 << 110; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey128 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &GlweSecretKey128<Kind>,
+        input: &PlaintextVector128,
+        noise: Variance,
+    ) -> Result<GlweCiphertext128, GlweCiphertextEncryptionError<Self::EngineError>> {
+        GlweCiphertextEncryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.encrypt_glwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey128<Kind>,
+        input: &PlaintextVector128,
+        noise: Variance,
+    ) -> GlweCiphertext128 {
+        let mut ciphertext = ImplGlweCiphertext::allocate(
+            0u128,
+            key.polynomial_size(),
+            key.glwe_dimension().to_glwe_size(),
+        );
+        key.0.encrypt_glwe(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweCiphertext128(ciphertext)
+    }
+}
@@ -0,0 +1,159 @@
+use concrete_commons::parameters::{DeltaLog, GlweSize};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{GlweCiphertext32, GlweCiphertext64};
+use crate::commons::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::specification::engines::{
+    GlweCiphertextTensorProductEngine, GlweCiphertextTensorProductError,
+};
+use crate::specification::entities::GlweCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextTensorProductEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+impl GlweCiphertextTensorProductEngine<GlweCiphertext32, GlweCiphertext32, GlweCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DeltaLog, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_2 = vec![2_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    ///
+    /// let product: GlweCiphertext32 = engine.compute_tensor_product_glwe_ciphertext(
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     DeltaLog(20),
+    /// )?;
+    /// #
+    /// assert_eq!(product.glwe_dimension(), GlweDimension(5));
+    /// assert_eq!(product.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compute_tensor_product_glwe_ciphertext(
+        &mut self,
+        input1: &GlweCiphertext32,
+        input2: &GlweCiphertext32,
+        delta_log: DeltaLog,
+    ) -> Result<GlweCiphertext32, GlweCiphertextTensorProductError<Self::EngineError>> {
+        GlweCiphertextTensorProductError::perform_generic_checks(input1, input2, delta_log, 32)?;
+        Ok(unsafe {
+            self.compute_tensor_product_glwe_ciphertext_unchecked(input1, input2, delta_log)
+        })
+    }
+
+    unsafe fn compute_tensor_product_glwe_ciphertext_unchecked(
+        &mut self,
+        input1: &GlweCiphertext32,
+        input2: &GlweCiphertext32,
+        delta_log: DeltaLog,
+    ) -> GlweCiphertext32 {
+        let glwe_dimension = input1.glwe_dimension().0;
+        let mut output = ImplGlweCiphertext::allocate(
+            0u32,
+            input1.polynomial_size(),
+            GlweSize(glwe_dimension * (glwe_dimension + 3) / 2 + 1),
+        );
+        output.fill_with_tensor_product(&input1.0, &input2.0, delta_log);
+        GlweCiphertext32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextTensorProductEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+impl GlweCiphertextTensorProductEngine<GlweCiphertext64, GlweCiphertext64, GlweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DeltaLog, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = vec![3_u64 << 50; polynomial_size.0];
+    /// let input_2 = vec![2_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    ///
+    /// let product: GlweCiphertext64 = engine.compute_tensor_product_glwe_ciphertext(
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     DeltaLog(50),
+    /// )?;
+    /// #
+    /// assert_eq!(product.glwe_dimension(), GlweDimension(5));
+    /// assert_eq!(product.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compute_tensor_product_glwe_ciphertext(
+        &mut self,
+        input1: &GlweCiphertext64,
+        input2: &GlweCiphertext64,
+        delta_log: DeltaLog,
+    ) -> Result<GlweCiphertext64, GlweCiphertextTensorProductError<Self::EngineError>> {
+        GlweCiphertextTensorProductError::perform_generic_checks(input1, input2, delta_log, 64)?;
+        Ok(unsafe {
+            self.compute_tensor_product_glwe_ciphertext_unchecked(input1, input2, delta_log)
+        })
+    }
+
+    unsafe fn compute_tensor_product_glwe_ciphertext_unchecked(
+        &mut self,
+        input1: &GlweCiphertext64,
+        input2: &GlweCiphertext64,
+        delta_log: DeltaLog,
+    ) -> GlweCiphertext64 {
+        let glwe_dimension = input1.glwe_dimension().0;
+        let mut output = ImplGlweCiphertext::allocate(
+            0u64,
+            input1.polynomial_size(),
+            GlweSize(glwe_dimension * (glwe_dimension + 3) / 2 + 1),
+        );
+        output.fill_with_tensor_product(&input1.0, &input2.0, delta_log);
+        GlweCiphertext64(output)
+    }
+}
@@ -1,7 +1,8 @@
 use concrete_commons::parameters::GlweSize;
 
 use crate::backends::default::entities::{
-    GlweCiphertext32, GlweCiphertext64, PlaintextVector32, PlaintextVector64,
+    GlweCiphertext128, GlweCiphertext32, GlweCiphertext64, PlaintextVector128, PlaintextVector32,
+    PlaintextVector64,
 };
 use crate::commons::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
 use crate::specification::engines::{
@@ -107,3 +108,54 @@ impl GlweCiphertextTrivialEncryptionEngine<PlaintextVector64, GlweCiphertext64>
         GlweCiphertext64(ciphertext)
     }
 }
+
+impl GlweCiphertextTrivialEncryptionEngine<PlaintextVector128, GlweCiphertext128>
+    for DefaultEngine
+{
+    /// # Example:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    ///
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let input = vec![3_u128 << 20; polynomial_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVector128 = engine.create_plaintext_vector_from(&input)?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let ciphertext: GlweCiphertext128 = engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)?;
+    ///
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_glwe_ciphertext(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &PlaintextVector128,
+    ) -> Result<GlweCiphertext128, GlweCiphertextTrivialEncryptionError<Self::EngineError>> {
+        unsafe { Ok(self.trivially_encrypt_glwe_ciphertext_unchecked(glwe_size, input)) }
+    }
+
+    unsafe fn trivially_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &PlaintextVector128,
+    ) -> GlweCiphertext128 {
+        let ciphertext: ImplGlweCiphertext<Vec<u128>> =
+            ImplGlweCiphertext::new_trivial_encryption(glwe_size, &input.0);
+        GlweCiphertext128(ciphertext)
+    }
+}
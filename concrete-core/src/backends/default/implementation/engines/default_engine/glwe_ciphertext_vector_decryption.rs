@@ -10,13 +10,20 @@ use crate::specification::engines::{
     GlweCiphertextVectorDecryptionEngine, GlweCiphertextVectorDecryptionError,
 };
 use crate::specification::entities::{GlweCiphertextVectorEntity, GlweSecretKeyEntity};
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`GlweCiphertextVectorDecryptionEngine`] for [`DefaultEngine`] that operates
 /// on 32 bits integers.
-impl
-    GlweCiphertextVectorDecryptionEngine<GlweSecretKey32, GlweCiphertextVector32, PlaintextVector32>
-    for DefaultEngine
+impl<Kind>
+    GlweCiphertextVectorDecryptionEngine<
+        GlweSecretKey32<Kind>,
+        GlweCiphertextVector32,
+        PlaintextVector32,
+    > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -56,7 +63,7 @@ impl
     /// ```
     fn decrypt_glwe_ciphertext_vector(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         input: &GlweCiphertextVector32,
     ) -> Result<PlaintextVector32, GlweCiphertextVectorDecryptionError<Self::EngineError>> {
         GlweCiphertextVectorDecryptionError::perform_generic_checks(key, input)?;
@@ -65,7 +72,7 @@ impl
 
     unsafe fn decrypt_glwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         input: &GlweCiphertextVector32,
     ) -> PlaintextVector32 {
         let mut plaintext_list = ImplPlaintextList::allocate(
@@ -80,9 +87,14 @@ impl
 /// # Description:
 /// Implementation of [`GlweCiphertextVectorDecryptionEngine`] for [`DefaultEngine`] that operates
 /// on 64 bits integers.
-impl
-    GlweCiphertextVectorDecryptionEngine<GlweSecretKey64, GlweCiphertextVector64, PlaintextVector64>
-    for DefaultEngine
+impl<Kind>
+    GlweCiphertextVectorDecryptionEngine<
+        GlweSecretKey64<Kind>,
+        GlweCiphertextVector64,
+        PlaintextVector64,
+    > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -122,7 +134,7 @@ impl
     /// ```
     fn decrypt_glwe_ciphertext_vector(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         input: &GlweCiphertextVector64,
     ) -> Result<PlaintextVector64, GlweCiphertextVectorDecryptionError<Self::EngineError>> {
         GlweCiphertextVectorDecryptionError::perform_generic_checks(key, input)?;
@@ -131,7 +143,7 @@ impl
 
     unsafe fn decrypt_glwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         input: &GlweCiphertextVector64,
     ) -> PlaintextVector64 {
         let mut plaintext_list = ImplPlaintextList::allocate(
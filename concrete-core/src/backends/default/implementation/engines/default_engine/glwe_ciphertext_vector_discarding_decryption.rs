@@ -6,16 +6,20 @@ use crate::backends::default::implementation::entities::{
 use crate::specification::engines::{
     GlweCiphertextVectorDiscardingDecryptionEngine, GlweCiphertextVectorDiscardingDecryptionError,
 };
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`GlweCiphertextVectorDiscardingDecryptionEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
-impl
+impl<Kind>
     GlweCiphertextVectorDiscardingDecryptionEngine<
-        GlweSecretKey32,
+        GlweSecretKey32<Kind>,
         GlweCiphertextVector32,
         PlaintextVector32,
     > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -56,7 +60,7 @@ impl
     /// ```
     fn discard_decrypt_glwe_ciphertext_vector(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         output: &mut PlaintextVector32,
         input: &GlweCiphertextVector32,
     ) -> Result<(), GlweCiphertextVectorDiscardingDecryptionError<Self::EngineError>> {
@@ -67,7 +71,7 @@ impl
 
     unsafe fn discard_decrypt_glwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         output: &mut PlaintextVector32,
         input: &GlweCiphertextVector32,
     ) {
@@ -78,12 +82,14 @@ impl
 /// # Description:
 /// Implementation of [`GlweCiphertextVectorDiscardingDecryptionEngine`] for [`DefaultEngine`] that
 /// operates on 64 bits integers.
-impl
+impl<Kind>
     GlweCiphertextVectorDiscardingDecryptionEngine<
-        GlweSecretKey64,
+        GlweSecretKey64<Kind>,
         GlweCiphertextVector64,
         PlaintextVector64,
     > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -124,7 +130,7 @@ impl
     /// ```
     fn discard_decrypt_glwe_ciphertext_vector(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         output: &mut PlaintextVector64,
         input: &GlweCiphertextVector64,
     ) -> Result<(), GlweCiphertextVectorDiscardingDecryptionError<Self::EngineError>> {
@@ -135,7 +141,7 @@ impl
 
     unsafe fn discard_decrypt_glwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         output: &mut PlaintextVector64,
         input: &GlweCiphertextVector64,
     ) {
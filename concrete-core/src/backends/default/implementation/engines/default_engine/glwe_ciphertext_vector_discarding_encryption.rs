@@ -8,16 +8,20 @@ use crate::backends::default::implementation::entities::{
 use crate::specification::engines::{
     GlweCiphertextVectorDiscardingEncryptionEngine, GlweCiphertextVectorDiscardingEncryptionError,
 };
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`GlweCiphertextVectorDiscardingEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
-impl
+impl<Kind>
     GlweCiphertextVectorDiscardingEncryptionEngine<
-        GlweSecretKey32,
+        GlweSecretKey32<Kind>,
         PlaintextVector32,
         GlweCiphertextVector32,
     > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -66,7 +70,7 @@ impl
     /// ```
     fn discard_encrypt_glwe_ciphertext_vector(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         output: &mut GlweCiphertextVector32,
         input: &PlaintextVector32,
         noise: Variance,
@@ -78,7 +82,7 @@ impl
 
     unsafe fn discard_encrypt_glwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         output: &mut GlweCiphertextVector32,
         input: &PlaintextVector32,
         noise: Variance,
@@ -95,12 +99,14 @@ impl
 /// # Description:
 /// Implementation of [`GlweCiphertextVectorDiscardingEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 64 bits integers.
-impl
+impl<Kind>
     GlweCiphertextVectorDiscardingEncryptionEngine<
-        GlweSecretKey64,
+        GlweSecretKey64<Kind>,
         PlaintextVector64,
         GlweCiphertextVector64,
     > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -149,7 +155,7 @@ impl
     /// ```
     fn discard_encrypt_glwe_ciphertext_vector(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         output: &mut GlweCiphertextVector64,
         input: &PlaintextVector64,
         noise: Variance,
@@ -161,7 +167,7 @@ impl
 
     unsafe fn discard_encrypt_glwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         output: &mut GlweCiphertextVector64,
         input: &PlaintextVector64,
         noise: Variance,
@@ -11,13 +11,20 @@ use crate::specification::engines::{
     GlweCiphertextVectorEncryptionEngine, GlweCiphertextVectorEncryptionError,
 };
 use crate::specification::entities::{GlweSecretKeyEntity, PlaintextVectorEntity};
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`GlweCiphertextVectorEncryptionEngine`] for [`DefaultEngine`] that operates
 /// on 32 bits integers.
-impl
-    GlweCiphertextVectorEncryptionEngine<GlweSecretKey32, PlaintextVector32, GlweCiphertextVector32>
-    for DefaultEngine
+impl<Kind>
+    GlweCiphertextVectorEncryptionEngine<
+        GlweSecretKey32<Kind>,
+        PlaintextVector32,
+        GlweCiphertextVector32,
+    > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -57,7 +64,7 @@ impl
     /// ```
     fn encrypt_glwe_ciphertext_vector(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         input: &PlaintextVector32,
         noise: Variance,
     ) -> Result<GlweCiphertextVector32, GlweCiphertextVectorEncryptionError<Self::EngineError>>
@@ -68,7 +75,7 @@ impl
 
     unsafe fn encrypt_glwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         input: &PlaintextVector32,
         noise: Variance,
     ) -> GlweCiphertextVector32 {
@@ -91,9 +98,14 @@ impl
 /// # Description:
 /// Implementation of [`GlweCiphertextVectorEncryptionEngine`] for [`DefaultEngine`] that operates
 /// on 64 bits integers.
-impl
-    GlweCiphertextVectorEncryptionEngine<GlweSecretKey64, PlaintextVector64, GlweCiphertextVector64>
-    for DefaultEngine
+impl<Kind>
+    GlweCiphertextVectorEncryptionEngine<
+        GlweSecretKey64<Kind>,
+        PlaintextVector64,
+        GlweCiphertextVector64,
+    > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -133,7 +145,7 @@ impl
     /// ```
     fn encrypt_glwe_ciphertext_vector(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         input: &PlaintextVector64,
         noise: Variance,
     ) -> Result<GlweCiphertextVector64, GlweCiphertextVectorEncryptionError<Self::EngineError>>
@@ -144,7 +156,7 @@ impl
 
     unsafe fn encrypt_glwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         input: &PlaintextVector64,
         noise: Variance,
     ) -> GlweCiphertextVector64 {
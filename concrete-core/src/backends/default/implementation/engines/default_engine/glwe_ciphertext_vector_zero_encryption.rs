@@ -10,12 +10,16 @@ use crate::specification::engines::{
     GlweCiphertextVectorZeroEncryptionEngine, GlweCiphertextVectorZeroEncryptionError,
 };
 use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`GlweCiphertextVectorZeroEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
-impl GlweCiphertextVectorZeroEncryptionEngine<GlweSecretKey32, GlweCiphertextVector32>
+impl<Kind> GlweCiphertextVectorZeroEncryptionEngine<GlweSecretKey32<Kind>, GlweCiphertextVector32>
     for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -51,7 +55,7 @@ impl GlweCiphertextVectorZeroEncryptionEngine<GlweSecretKey32, GlweCiphertextVec
     /// ```
     fn zero_encrypt_glwe_ciphertext_vector(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         noise: Variance,
         count: GlweCiphertextCount,
     ) -> Result<GlweCiphertextVector32, GlweCiphertextVectorZeroEncryptionError<Self::EngineError>>
@@ -62,7 +66,7 @@ impl GlweCiphertextVectorZeroEncryptionEngine<GlweSecretKey32, GlweCiphertextVec
 
     unsafe fn zero_encrypt_glwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         noise: Variance,
         count: GlweCiphertextCount,
     ) -> GlweCiphertextVector32 {
@@ -84,8 +88,10 @@ impl GlweCiphertextVectorZeroEncryptionEngine<GlweSecretKey32, GlweCiphertextVec
 /// # Description:
 /// Implementation of [`GlweCiphertextVectorZeroEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 64 bits integers.
-impl GlweCiphertextVectorZeroEncryptionEngine<GlweSecretKey64, GlweCiphertextVector64>
+impl<Kind> GlweCiphertextVectorZeroEncryptionEngine<GlweSecretKey64<Kind>, GlweCiphertextVector64>
     for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -121,7 +127,7 @@ impl GlweCiphertextVectorZeroEncryptionEngine<GlweSecretKey64, GlweCiphertextVec
     /// ```
     fn zero_encrypt_glwe_ciphertext_vector(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         noise: Variance,
         count: GlweCiphertextCount,
     ) -> Result<GlweCiphertextVector64, GlweCiphertextVectorZeroEncryptionError<Self::EngineError>>
@@ -132,7 +138,7 @@ impl GlweCiphertextVectorZeroEncryptionEngine<GlweSecretKey64, GlweCiphertextVec
 
     unsafe fn zero_encrypt_glwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         noise: Variance,
         count: GlweCiphertextCount,
     ) -> GlweCiphertextVector64 {
@@ -9,11 +9,17 @@ use crate::specification::engines::{
     GlweCiphertextZeroEncryptionEngine, GlweCiphertextZeroEncryptionError,
 };
 use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`GlweCiphertextZeroEncryptionEngine`] for [`DefaultEngine`] that operates on
 /// 32 bits integers.
-impl GlweCiphertextZeroEncryptionEngine<GlweSecretKey32, GlweCiphertext32> for DefaultEngine {
+impl<Kind> GlweCiphertextZeroEncryptionEngine<GlweSecretKey32<Kind>, GlweCiphertext32>
+    for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -45,7 +51,7 @@ impl GlweCiphertextZeroEncryptionEngine<GlweSecretKey32, GlweCiphertext32> for D
     /// ```
     fn zero_encrypt_glwe_ciphertext(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         noise: Variance,
     ) -> Result<GlweCiphertext32, GlweCiphertextZeroEncryptionError<Self::EngineError>> {
         Ok(unsafe { self.zero_encrypt_glwe_ciphertext_unchecked(key, noise) })
@@ -53,7 +59,7 @@ impl GlweCiphertextZeroEncryptionEngine<GlweSecretKey32, GlweCiphertext32> for D
 
     unsafe fn zero_encrypt_glwe_ciphertext_unchecked(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         noise: Variance,
     ) -> GlweCiphertext32 {
         let mut ciphertext = ImplGlweCiphertext::allocate(
@@ -70,7 +76,11 @@ impl GlweCiphertextZeroEncryptionEngine<GlweSecretKey32, GlweCiphertext32> for D
 /// # Description:
 /// Implementation of [`GlweCiphertextZeroEncryptionEngine`] for [`DefaultEngine`] that operates on
 /// 64 bits integers.
-impl GlweCiphertextZeroEncryptionEngine<GlweSecretKey64, GlweCiphertext64> for DefaultEngine {
+impl<Kind> GlweCiphertextZeroEncryptionEngine<GlweSecretKey64<Kind>, GlweCiphertext64>
+    for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -102,7 +112,7 @@ impl GlweCiphertextZeroEncryptionEngine<GlweSecretKey64, GlweCiphertext64> for D
     /// ```
     fn zero_encrypt_glwe_ciphertext(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         noise: Variance,
     ) -> Result<GlweCiphertext64, GlweCiphertextZeroEncryptionError<Self::EngineError>> {
         Ok(unsafe { self.zero_encrypt_glwe_ciphertext_unchecked(key, noise) })
@@ -110,7 +120,7 @@ impl GlweCiphertextZeroEncryptionEngine<GlweSecretKey64, GlweCiphertext64> for D
 
     unsafe fn zero_encrypt_glwe_ciphertext_unchecked(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         noise: Variance,
     ) -> GlweCiphertext64 {
         let mut ciphertext = ImplGlweCiphertext::allocate(
@@ -0,0 +1,240 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::key_kinds::KeyKind;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweKeyswitchKey32, GlweKeyswitchKey64, GlweSecretKey32, GlweSecretKey64,
+};
+use crate::commons::crypto::glwe::GlweKeyswitchKey as ImplGlweKeyswitchKey;
+use crate::specification::engines::{
+    GlweKeyswitchKeyGenerationEngine, GlweKeyswitchKeyGenerationError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+use std::fmt::Debug;
+
+/// # Description:
+/// Implementation of [`GlweKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+impl<InputKind, OutputKind>
+    GlweKeyswitchKeyGenerationEngine<
+        GlweSecretKey32<InputKind>,
+        GlweSecretKey32<OutputKind>,
+        GlweKeyswitchKey32,
+    > for DefaultEngine
+where
+    InputKind: KeyKind + Debug,
+    OutputKind: KeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_glwe_dimension = GlweDimension(3);
+    /// let output_glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, polynomial_size)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let keyswitch_key: GlweKeyswitchKey32 = engine.generate_new_glwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(keyswitch_key.input_glwe_dimension(), input_glwe_dimension);
+    /// assert_eq!(keyswitch_key.output_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(keyswitch_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     keyswitch_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(keyswitch_key.decomposition_base_log(), decomposition_base_log);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_keyswitch_key(
+        &mut self,
+        input_key: &GlweSecretKey32<InputKind>,
+        output_key: &GlweSecretKey32<OutputKind>,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<GlweKeyswitchKey32, GlweKeyswitchKeyGenerationError<Self::EngineError>> {
+        GlweKeyswitchKeyGenerationError::perform_generic_checks(
+            input_key,
+            output_key,
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &GlweSecretKey32<InputKind>,
+        output_key: &GlweSecretKey32<OutputKind>,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> GlweKeyswitchKey32 {
+        let mut ksk = ImplGlweKeyswitchKey::allocate(
+            0u32,
+            input_key.polynomial_size(),
+            input_key.glwe_dimension(),
+            output_key.glwe_dimension(),
+            decomposition_level_count,
+            decomposition_base_log,
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweKeyswitchKey32(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+impl<InputKind, OutputKind>
+    GlweKeyswitchKeyGenerationEngine<
+        GlweSecretKey64<InputKind>,
+        GlweSecretKey64<OutputKind>,
+        GlweKeyswitchKey64,
+    > for DefaultEngine
+where
+    InputKind: KeyKind + Debug,
+    OutputKind: KeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_glwe_dimension = GlweDimension(3);
+    /// let output_glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, polynomial_size)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let keyswitch_key: GlweKeyswitchKey64 = engine.generate_new_glwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(keyswitch_key.input_glwe_dimension(), input_glwe_dimension);
+    /// assert_eq!(keyswitch_key.output_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(keyswitch_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     keyswitch_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(keyswitch_key.decomposition_base_log(), decomposition_base_log);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_keyswitch_key(
+        &mut self,
+        input_key: &GlweSecretKey64<InputKind>,
+        output_key: &GlweSecretKey64<OutputKind>,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<GlweKeyswitchKey64, GlweKeyswitchKeyGenerationError<Self::EngineError>> {
+        GlweKeyswitchKeyGenerationError::perform_generic_checks(
+            input_key,
+            output_key,
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &GlweSecretKey64<InputKind>,
+        output_key: &GlweSecretKey64<OutputKind>,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> GlweKeyswitchKey64 {
+        let mut ksk = ImplGlweKeyswitchKey::allocate(
+            0u64,
+            input_key.polynomial_size(),
+            input_key.glwe_dimension(),
+            output_key.glwe_dimension(),
+            decomposition_level_count,
+            decomposition_base_log,
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweKeyswitchKey64(ksk)
+    }
+}
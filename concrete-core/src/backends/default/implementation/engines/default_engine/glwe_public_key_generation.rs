@@ -0,0 +1,181 @@
+use crate::backends::default::engines::DefaultEngine;
+use crate::backends::default::entities::{
+    GlwePublicKey32, GlwePublicKey64, GlweSecretKey32, GlweSecretKey64,
+};
+use crate::commons::crypto::glwe::GlwePublicKey as ImplGlwePublicKey;
+use crate::specification::engines::{GlwePublicKeyGenerationEngine, GlwePublicKeyGenerationError};
+use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::key_kinds::KeyKind;
+use concrete_commons::parameters::GlwePublicKeyZeroEncryptionCount;
+use std::fmt::Debug;
+
+/// # Description:
+/// Implementation of [`GlwePublicKeyGenerationEngine`] for [`DefaultEngine`] that operates on
+/// 32 bits integers.
+impl<Kind> GlwePublicKeyGenerationEngine<GlweSecretKey32<Kind>, GlwePublicKey32> for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, GlwePublicKeyZeroEncryptionCount, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let glwe_public_key_zero_encryption_count = GlwePublicKeyZeroEncryptionCount(1);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let public_key: GlwePublicKey32 = engine.generate_new_glwe_public_key(
+    ///     &glwe_secret_key,
+    ///     noise,
+    ///     glwe_public_key_zero_encryption_count,
+    /// )?;
+    ///
+    /// assert_eq!(public_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(public_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     public_key.glwe_zero_encryption_count(),
+    ///     glwe_public_key_zero_encryption_count
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_public_key(
+        &mut self,
+        glwe_secret_key: &GlweSecretKey32<Kind>,
+        noise: Variance,
+        glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount,
+    ) -> Result<GlwePublicKey32, GlwePublicKeyGenerationError<Self::EngineError>> {
+        GlwePublicKeyGenerationError::perform_generic_checks(
+            glwe_public_key_zero_encryption_count,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_public_key_unchecked(
+                glwe_secret_key,
+                noise,
+                glwe_public_key_zero_encryption_count,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_public_key_unchecked(
+        &mut self,
+        glwe_secret_key: &GlweSecretKey32<Kind>,
+        noise: Variance,
+        glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount,
+    ) -> GlwePublicKey32 {
+        let mut public_key = ImplGlwePublicKey::allocate(
+            0u32,
+            glwe_secret_key.polynomial_size(),
+            glwe_secret_key.glwe_dimension(),
+            glwe_public_key_zero_encryption_count,
+        );
+        public_key.fill_with_encryptions_of_zero(
+            &glwe_secret_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlwePublicKey32(public_key)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlwePublicKeyGenerationEngine`] for [`DefaultEngine`] that operates on
+/// 64 bits integers.
+impl<Kind> GlwePublicKeyGenerationEngine<GlweSecretKey64<Kind>, GlwePublicKey64> for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, GlwePublicKeyZeroEncryptionCount, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let glwe_public_key_zero_encryption_count = GlwePublicKeyZeroEncryptionCount(1);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let public_key: GlwePublicKey64 = engine.generate_new_glwe_public_key(
+    ///     &glwe_secret_key,
+    ///     noise,
+    ///     glwe_public_key_zero_encryption_count,
+    /// )?;
+    ///
+    /// assert_eq!(public_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(public_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     public_key.glwe_zero_encryption_count(),
+    ///     glwe_public_key_zero_encryption_count
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_public_key(
+        &mut self,
+        glwe_secret_key: &GlweSecretKey64<Kind>,
+        noise: Variance,
+        glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount,
+    ) -> Result<GlwePublicKey64, GlwePublicKeyGenerationError<Self::EngineError>> {
+        GlwePublicKeyGenerationError::perform_generic_checks(
+            glwe_public_key_zero_encryption_count,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_public_key_unchecked(
+                glwe_secret_key,
+                noise,
+                glwe_public_key_zero_encryption_count,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_public_key_unchecked(
+        &mut self,
+        glwe_secret_key: &GlweSecretKey64<Kind>,
+        noise: Variance,
+        glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount,
+    ) -> GlwePublicKey64 {
+        let mut public_key = ImplGlwePublicKey::allocate(
+            0u64,
+            glwe_secret_key.polynomial_size(),
+            glwe_secret_key.glwe_dimension(),
+            glwe_public_key_zero_encryption_count,
+        );
+        public_key.fill_with_encryptions_of_zero(
+            &glwe_secret_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlwePublicKey64(public_key)
+    }
+}
@@ -0,0 +1,188 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweRelinearizationKey32, GlweRelinearizationKey64, GlweSecretKey32, GlweSecretKey64,
+};
+use crate::commons::crypto::glwe::GlweRelinearizationKey as ImplGlweRelinearizationKey;
+use crate::specification::engines::{
+    GlweRelinearizationKeyGenerationEngine, GlweRelinearizationKeyGenerationError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`GlweRelinearizationKeyGenerationEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl GlweRelinearizationKeyGenerationEngine<GlweSecretKey32, GlweRelinearizationKey32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let dec_lc = DecompositionLevelCount(3);
+    /// let dec_bl = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let rlk: GlweRelinearizationKey32 =
+    ///     engine.generate_new_glwe_relinearization_key(&key, dec_lc, dec_bl, noise)?;
+    /// #
+    /// assert_eq!(rlk.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(rlk.polynomial_size(), polynomial_size);
+    /// assert_eq!(rlk.decomposition_level_count(), dec_lc);
+    /// assert_eq!(rlk.decomposition_base_log(), dec_bl);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_relinearization_key(
+        &mut self,
+        secret_key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<GlweRelinearizationKey32, GlweRelinearizationKeyGenerationError<Self::EngineError>>
+    {
+        GlweRelinearizationKeyGenerationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_relinearization_key_unchecked(
+                secret_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_relinearization_key_unchecked(
+        &mut self,
+        secret_key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> GlweRelinearizationKey32 {
+        let mut relinearization_key = ImplGlweRelinearizationKey::allocate(
+            0u32,
+            secret_key.polynomial_size(),
+            secret_key.glwe_dimension(),
+            decomposition_level_count,
+            decomposition_base_log,
+        );
+        relinearization_key.fill_with_relinearization_key(
+            &secret_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweRelinearizationKey32(relinearization_key)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweRelinearizationKeyGenerationEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl GlweRelinearizationKeyGenerationEngine<GlweSecretKey64, GlweRelinearizationKey64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let dec_lc = DecompositionLevelCount(3);
+    /// let dec_bl = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let rlk: GlweRelinearizationKey64 =
+    ///     engine.generate_new_glwe_relinearization_key(&key, dec_lc, dec_bl, noise)?;
+    /// #
+    /// assert_eq!(rlk.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(rlk.polynomial_size(), polynomial_size);
+    /// assert_eq!(rlk.decomposition_level_count(), dec_lc);
+    /// assert_eq!(rlk.decomposition_base_log(), dec_bl);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_relinearization_key(
+        &mut self,
+        secret_key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<GlweRelinearizationKey64, GlweRelinearizationKeyGenerationError<Self::EngineError>>
+    {
+        GlweRelinearizationKeyGenerationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_relinearization_key_unchecked(
+                secret_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_relinearization_key_unchecked(
+        &mut self,
+        secret_key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> GlweRelinearizationKey64 {
+        let mut relinearization_key = ImplGlweRelinearizationKey::allocate(
+            0u64,
+            secret_key.polynomial_size(),
+            secret_key.glwe_dimension(),
+            decomposition_level_count,
+            decomposition_base_log,
+        );
+        relinearization_key.fill_with_relinearization_key(
+            &secret_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweRelinearizationKey64(relinearization_key)
+    }
+}
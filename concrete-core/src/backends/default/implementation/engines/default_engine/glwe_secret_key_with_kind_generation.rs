@@ -0,0 +1,219 @@
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweSecretKey128, GlweSecretKey32, GlweSecretKey64,
+};
+use crate::commons::crypto::secret::GlweSecretKey as ImplGlweSecretKey;
+use crate::commons::crypto::secret::RandomKeyKind;
+use crate::specification::engines::{
+    GlweSecretKeyWithKindGenerationEngine, GlweSecretKeyWithKindGenerationError,
+};
+use std::fmt::Debug;
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyWithKindGenerationEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl<Kind> GlweSecretKeyWithKindGenerationEngine<Kind, GlweSecretKey32<Kind>> for DefaultEngine
+where
+    Kind: RandomKeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKey32<GaussianKeyKind> = engine
+    ///     .generate_new_glwe_secret_key_with_kind(
+    ///         glwe_dimension,
+    ///         polynomial_size,
+    ///         GaussianKeyKind,
+    ///     )?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_secret_key_with_kind(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        kind: Kind,
+    ) -> Result<GlweSecretKey32<Kind>, GlweSecretKeyWithKindGenerationError<Self::EngineError>>
+    {
+        GlweSecretKeyWithKindGenerationError::perform_generic_checks(
+            glwe_dimension,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_secret_key_with_kind_unchecked(
+                glwe_dimension,
+                polynomial_size,
+                kind,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_secret_key_with_kind_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        _kind: Kind,
+    ) -> GlweSecretKey32<Kind> {
+        GlweSecretKey32(ImplGlweSecretKey::generate(
+            glwe_dimension,
+            polynomial_size,
+            &mut self.secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyWithKindGenerationEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl<Kind> GlweSecretKeyWithKindGenerationEngine<Kind, GlweSecretKey64<Kind>> for DefaultEngine
+where
+    Kind: RandomKeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKey64<GaussianKeyKind> = engine
+    ///     .generate_new_glwe_secret_key_with_kind(
+    ///         glwe_dimension,
+    ///         polynomial_size,
+    ///         GaussianKeyKind,
+    ///     )?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_secret_key_with_kind(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        kind: Kind,
+    ) -> Result<GlweSecretKey64<Kind>, GlweSecretKeyWithKindGenerationError<Self::EngineError>>
+    {
+        GlweSecretKeyWithKindGenerationError::perform_generic_checks(
+            glwe_dimension,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_secret_key_with_kind_unchecked(
+                glwe_dimension,
+                polynomial_size,
+                kind,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_secret_key_with_kind_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        _kind: Kind,
+    ) -> GlweSecretKey64<Kind> {
+        GlweSecretKey64(ImplGlweSecretKey::generate(
+            glwe_dimension,
+            polynomial_size,
+            &mut self.secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyWithKindGenerationEngine`] for [`DefaultEngine`] that
+/// operates on 128 bits integers.
+impl<Kind> GlweSecretKeyWithKindGenerationEngine<Kind, GlweSecretKey128<Kind>> for DefaultEngine
+where
+    Kind: RandomKeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKey128<GaussianKeyKind> = engine
+    ///     .generate_new_glwe_secret_key_with_kind(
+    ///         glwe_dimension,
+    ///         polynomial_size,
+    ///         GaussianKeyKind,
+    ///     )?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_glwe_secret_key_with_kind(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        kind: Kind,
+    ) -> Result<GlweSecretKey128<Kind>, GlweSecretKeyWithKindGenerationError<Self::EngineError>>
+    {
+        GlweSecretKeyWithKindGenerationError::perform_generic_checks(
+            glwe_dimension,
+            polynomial_size,
+        )?;
+        Ok(unsafe {
+            self.generate_new_glwe_secret_key_with_kind_unchecked(
+                glwe_dimension,
+                polynomial_size,
+                kind,
+            )
+        })
+    }
+
+    unsafe fn generate_new_glwe_secret_key_with_kind_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        _kind: Kind,
+    ) -> GlweSecretKey128<Kind> {
+        GlweSecretKey128(ImplGlweSecretKey::generate(
+            glwe_dimension,
+            polynomial_size,
+            &mut self.secret_generator,
+        ))
+    }
+}
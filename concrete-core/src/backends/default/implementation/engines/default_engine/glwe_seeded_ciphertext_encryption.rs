@@ -11,13 +11,20 @@ use crate::specification::engines::{
 };
 use crate::specification::entities::GlweSecretKeyEntity;
 use concrete_commons::dispersion::Variance;
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`GlweSeededCiphertextEncryptionEngine`] for [`DefaultEngine`] that operates
 /// on 32 bits integers.
-impl
-    GlweSeededCiphertextEncryptionEngine<GlweSecretKey32, PlaintextVector32, GlweSeededCiphertext32>
-    for DefaultEngine
+impl<Kind>
+    GlweSeededCiphertextEncryptionEngine<
+        GlweSecretKey32<Kind>,
+        PlaintextVector32,
+        GlweSeededCiphertext32,
+    > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -55,7 +62,7 @@ impl
     /// ```
     fn encrypt_glwe_seeded_ciphertext(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         input: &PlaintextVector32,
         noise: Variance,
     ) -> Result<GlweSeededCiphertext32, GlweSeededCiphertextEncryptionError<Self::EngineError>>
@@ -66,7 +73,7 @@ impl
 
     unsafe fn encrypt_glwe_seeded_ciphertext_unchecked(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         input: &PlaintextVector32,
         noise: Variance,
     ) -> GlweSeededCiphertext32 {
@@ -93,9 +100,14 @@ impl
 /// # Description:
 /// Implementation of [`GlweSeededCiphertextEncryptionEngine`] for [`DefaultEngine`] that operates
 /// on 64 bits integers.
-impl
-    GlweSeededCiphertextEncryptionEngine<GlweSecretKey64, PlaintextVector64, GlweSeededCiphertext64>
-    for DefaultEngine
+impl<Kind>
+    GlweSeededCiphertextEncryptionEngine<
+        GlweSecretKey64<Kind>,
+        PlaintextVector64,
+        GlweSeededCiphertext64,
+    > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -133,7 +145,7 @@ impl
     /// ```
     fn encrypt_glwe_seeded_ciphertext(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         input: &PlaintextVector64,
         noise: Variance,
     ) -> Result<GlweSeededCiphertext64, GlweSeededCiphertextEncryptionError<Self::EngineError>>
@@ -144,7 +156,7 @@ impl
 
     unsafe fn encrypt_glwe_seeded_ciphertext_unchecked(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         input: &PlaintextVector64,
         noise: Variance,
     ) -> GlweSeededCiphertext64 {
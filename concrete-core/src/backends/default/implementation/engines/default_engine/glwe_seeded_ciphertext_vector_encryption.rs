@@ -11,17 +11,21 @@ use crate::specification::engines::{
 };
 use crate::specification::entities::{GlweSecretKeyEntity, PlaintextVectorEntity};
 use concrete_commons::dispersion::Variance;
+use concrete_commons::key_kinds::KeyKind;
 use concrete_commons::parameters::CiphertextCount;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`GlweSeededCiphertextVectorEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
-impl
+impl<Kind>
     GlweSeededCiphertextVectorEncryptionEngine<
-        GlweSecretKey32,
+        GlweSecretKey32<Kind>,
         PlaintextVector32,
         GlweSeededCiphertextVector32,
     > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -61,7 +65,7 @@ impl
     /// ```
     fn encrypt_glwe_seeded_ciphertext_vector(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         input: &PlaintextVector32,
         noise: Variance,
     ) -> Result<
@@ -74,7 +78,7 @@ impl
 
     unsafe fn encrypt_glwe_seeded_ciphertext_vector_unchecked(
         &mut self,
-        key: &GlweSecretKey32,
+        key: &GlweSecretKey32<Kind>,
         input: &PlaintextVector32,
         noise: Variance,
     ) -> GlweSeededCiphertextVector32 {
@@ -102,12 +106,14 @@ impl
 /// # Description:
 /// Implementation of [`GlweSeededCiphertextVectorEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 64 bits integers.
-impl
+impl<Kind>
     GlweSeededCiphertextVectorEncryptionEngine<
-        GlweSecretKey64,
+        GlweSecretKey64<Kind>,
         PlaintextVector64,
         GlweSeededCiphertextVector64,
     > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -147,7 +153,7 @@ impl
     /// ```
     fn encrypt_glwe_seeded_ciphertext_vector(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         input: &PlaintextVector64,
         noise: Variance,
     ) -> Result<
@@ -160,7 +166,7 @@ impl
 
     unsafe fn encrypt_glwe_seeded_ciphertext_vector_unchecked(
         &mut self,
-        key: &GlweSecretKey64,
+        key: &GlweSecretKey64<Kind>,
         input: &PlaintextVector64,
         noise: Variance,
     ) -> GlweSeededCiphertextVector64 {
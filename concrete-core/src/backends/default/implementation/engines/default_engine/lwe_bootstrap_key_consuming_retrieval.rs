@@ -1,7 +1,8 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
     LweBootstrapKey32, LweBootstrapKey64, LweBootstrapKeyMutView32, LweBootstrapKeyMutView64,
-    LweBootstrapKeyView32, LweBootstrapKeyView64,
+    LweBootstrapKeyView32, LweBootstrapKeyView64, LweTernaryBootstrapKey32,
+    LweTernaryBootstrapKey64,
 };
 use crate::commons::math::tensor::IntoTensor;
 use crate::specification::engines::{
@@ -115,6 +116,119 @@ impl LweBootstrapKeyConsumingRetrievalEngine<LweBootstrapKey64, Vec<u64>> for De
     }
 }
 
+impl LweBootstrapKeyConsumingRetrievalEngine<LweTernaryBootstrapKey32, Vec<u32>> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let glwe_size = GlweSize(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(1);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    ///
+    /// // A ternary bootstrap key holds two GGSW ciphertexts per input key coefficient.
+    /// let owned_container = vec![
+    ///     0_u32;
+    ///     2 * lwe_dimension.0 * level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0
+    /// ];
+    /// let original_vec_ptr = owned_container.as_ptr();
+    ///
+    /// let lwe_bootstrap_key: LweTernaryBootstrapKey32 = engine.create_lwe_bootstrap_key_from(
+    ///     owned_container,
+    ///     glwe_size,
+    ///     polynomial_size,
+    ///     base_log,
+    ///     level,
+    /// )?;
+    /// let retrieved_container = engine.consume_retrieve_lwe_bootstrap_key(lwe_bootstrap_key)?;
+    /// assert_eq!(original_vec_ptr, retrieved_container.as_ptr());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_bootstrap_key(
+        &mut self,
+        bootstrap_key: LweTernaryBootstrapKey32,
+    ) -> Result<Vec<u32>, LweBootstrapKeyConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_bootstrap_key_unchecked(bootstrap_key) })
+    }
+
+    unsafe fn consume_retrieve_lwe_bootstrap_key_unchecked(
+        &mut self,
+        bootstrap_key: LweTernaryBootstrapKey32,
+    ) -> Vec<u32> {
+        bootstrap_key.0.into_tensor().into_container()
+    }
+}
+
+impl LweBootstrapKeyConsumingRetrievalEngine<LweTernaryBootstrapKey64, Vec<u64>> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let glwe_size = GlweSize(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(1);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    ///
+    /// // A ternary bootstrap key holds two GGSW ciphertexts per input key coefficient.
+    /// let owned_container = vec![
+    ///     0_u64;
+    ///     2 * lwe_dimension.0 * level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0
+    /// ];
+    /// let original_vec_ptr = owned_container.as_ptr();
+    ///
+    /// let lwe_bootstrap_key: LweTernaryBootstrapKey64 = engine.create_lwe_bootstrap_key_from(
+    ///     owned_container,
+    ///     glwe_size,
+    ///     polynomial_size,
+    ///     base_log,
+    ///     level,
+    /// )?;
+    /// let retrieved_container = engine.consume_retrieve_lwe_bootstrap_key(lwe_bootstrap_key)?;
+    /// assert_eq!(original_vec_ptr, retrieved_container.as_ptr());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_bootstrap_key(
+        &mut self,
+        bootstrap_key: LweTernaryBootstrapKey64,
+    ) -> Result<Vec<u64>, LweBootstrapKeyConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_bootstrap_key_unchecked(bootstrap_key) })
+    }
+
+    unsafe fn consume_retrieve_lwe_bootstrap_key_unchecked(
+        &mut self,
+        bootstrap_key: LweTernaryBootstrapKey64,
+    ) -> Vec<u64> {
+        bootstrap_key.0.into_tensor().into_container()
+    }
+}
+
 impl<'data>
     LweBootstrapKeyConsumingRetrievalEngine<LweBootstrapKeyMutView32<'data>, &'data mut [u32]>
     for DefaultEngine
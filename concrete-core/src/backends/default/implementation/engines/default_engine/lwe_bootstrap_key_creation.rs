@@ -1,7 +1,8 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
     LweBootstrapKey32, LweBootstrapKey64, LweBootstrapKeyMutView32, LweBootstrapKeyMutView64,
-    LweBootstrapKeyView32, LweBootstrapKeyView64,
+    LweBootstrapKeyView32, LweBootstrapKeyView64, LweTernaryBootstrapKey32,
+    LweTernaryBootstrapKey64,
 };
 use crate::commons::crypto::bootstrap::StandardBootstrapKey as ImplStandardBootstrapKey;
 use crate::specification::engines::{LweBootstrapKeyCreationEngine, LweBootstrapKeyCreationError};
@@ -185,6 +186,200 @@ impl LweBootstrapKeyCreationEngine<Vec<u64>, LweBootstrapKey64> for DefaultEngin
     }
 }
 
+impl LweBootstrapKeyCreationEngine<Vec<u32>, LweTernaryBootstrapKey32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let glwe_size = GlweSize(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(1);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    ///
+    /// // A ternary bootstrap key holds two GGSW ciphertexts per input key coefficient.
+    /// let owned_container = vec![
+    ///     0_u32;
+    ///     2 * lwe_dimension.0 * level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0
+    /// ];
+    ///
+    /// let lwe_bootstrap_key: LweTernaryBootstrapKey32 = engine.create_lwe_bootstrap_key_from(
+    ///     owned_container,
+    ///     glwe_size,
+    ///     polynomial_size,
+    ///     base_log,
+    ///     level,
+    /// )?;
+    ///
+    /// # assert_eq!(lwe_dimension, lwe_bootstrap_key.input_lwe_dimension());
+    /// # assert_eq!(glwe_size, lwe_bootstrap_key.glwe_dimension().to_glwe_size());
+    /// # assert_eq!(polynomial_size, lwe_bootstrap_key.polynomial_size());
+    /// # assert_eq!(level, lwe_bootstrap_key.decomposition_level_count());
+    /// # assert_eq!(base_log, lwe_bootstrap_key.decomposition_base_log());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key_from(
+        &mut self,
+        container: Vec<u32>,
+        glwe_size: GlweSize,
+        poly_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<LweTernaryBootstrapKey32, LweBootstrapKeyCreationError<Self::EngineError>> {
+        LweBootstrapKeyCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            glwe_size,
+            poly_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            32,
+        )?;
+        if container.len()
+            % (2 * decomposition_level_count.0 * glwe_size.0 * glwe_size.0 * poly_size.0)
+            != 0
+        {
+            return Err(LweBootstrapKeyCreationError::InvalidContainerSize);
+        }
+
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_from_unchecked(
+                container,
+                glwe_size,
+                poly_size,
+                decomposition_base_log,
+                decomposition_level_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_from_unchecked(
+        &mut self,
+        container: Vec<u32>,
+        glwe_size: GlweSize,
+        poly_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> LweTernaryBootstrapKey32 {
+        LweTernaryBootstrapKey32(ImplStandardBootstrapKey::from_container(
+            container,
+            glwe_size,
+            poly_size,
+            decomposition_level_count,
+            decomposition_base_log,
+        ))
+    }
+}
+
+impl LweBootstrapKeyCreationEngine<Vec<u64>, LweTernaryBootstrapKey64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let glwe_size = GlweSize(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(2);
+    /// let base_log = DecompositionBaseLog(1);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    ///
+    /// // A ternary bootstrap key holds two GGSW ciphertexts per input key coefficient.
+    /// let owned_container = vec![
+    ///     0_u64;
+    ///     2 * lwe_dimension.0 * level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0
+    /// ];
+    ///
+    /// let lwe_bootstrap_key: LweTernaryBootstrapKey64 = engine.create_lwe_bootstrap_key_from(
+    ///     owned_container,
+    ///     glwe_size,
+    ///     polynomial_size,
+    ///     base_log,
+    ///     level,
+    /// )?;
+    ///
+    /// # assert_eq!(lwe_dimension, lwe_bootstrap_key.input_lwe_dimension());
+    /// # assert_eq!(glwe_size, lwe_bootstrap_key.glwe_dimension().to_glwe_size());
+    /// # assert_eq!(polynomial_size, lwe_bootstrap_key.polynomial_size());
+    /// # assert_eq!(level, lwe_bootstrap_key.decomposition_level_count());
+    /// # assert_eq!(base_log, lwe_bootstrap_key.decomposition_base_log());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key_from(
+        &mut self,
+        container: Vec<u64>,
+        glwe_size: GlweSize,
+        poly_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<LweTernaryBootstrapKey64, LweBootstrapKeyCreationError<Self::EngineError>> {
+        LweBootstrapKeyCreationError::<Self::EngineError>::perform_generic_checks(
+            container.len(),
+            glwe_size,
+            poly_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            64,
+        )?;
+        if container.len()
+            % (2 * decomposition_level_count.0 * glwe_size.0 * glwe_size.0 * poly_size.0)
+            != 0
+        {
+            return Err(LweBootstrapKeyCreationError::InvalidContainerSize);
+        }
+
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_from_unchecked(
+                container,
+                glwe_size,
+                poly_size,
+                decomposition_base_log,
+                decomposition_level_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_from_unchecked(
+        &mut self,
+        container: Vec<u64>,
+        glwe_size: GlweSize,
+        poly_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> LweTernaryBootstrapKey64 {
+        LweTernaryBootstrapKey64(ImplStandardBootstrapKey::from_container(
+            container,
+            glwe_size,
+            poly_size,
+            decomposition_level_count,
+            decomposition_base_log,
+        ))
+    }
+}
+
 impl<'data> LweBootstrapKeyCreationEngine<&'data mut [u32], LweBootstrapKeyMutView32<'data>>
     for DefaultEngine
 {
@@ -1,7 +1,7 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    LweCiphertext32, LweCiphertext64, LweCiphertextMutView32, LweCiphertextMutView64,
-    LweCiphertextView32, LweCiphertextView64,
+    LweCiphertext128, LweCiphertext32, LweCiphertext64, LweCiphertextMutView32,
+    LweCiphertextMutView64, LweCiphertextView32, LweCiphertextView64,
 };
 use crate::commons::crypto::lwe::LweCiphertext as ImplLweCiphertext;
 use crate::specification::engines::{LweCiphertextCreationEngine, LweCiphertextCreationError};
@@ -88,6 +88,47 @@ impl LweCiphertextCreationEngine<Vec<u64>, LweCiphertext64> for DefaultEngine {
     }
 }
 
+/// # Description:
+/// Implementation of [`LweCiphertextCreationEngine`] for [`DefaultEngine`] which returns an
+/// [`LweCiphertext128`].
+impl LweCiphertextCreationEngine<Vec<u128>, LweCiphertext128> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// // Note that the size here is just for demonstration purposes and should not be chosen
+    /// // without proper security analysis for production
+    /// let lwe_size = LweSize(128);
+    /// let owned_container = vec![0_u128; lwe_size.0];
+    ///
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let ciphertext: LweCiphertext128 = engine.create_lwe_ciphertext_from(owned_container)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_from(
+        &mut self,
+        container: Vec<u128>,
+    ) -> Result<LweCiphertext128, LweCiphertextCreationError<Self::EngineError>> {
+        LweCiphertextCreationError::<Self::EngineError>::perform_generic_checks(container.len())?;
+        Ok(unsafe { self.create_lwe_ciphertext_from_unchecked(container) })
+    }
+
+    unsafe fn create_lwe_ciphertext_from_unchecked(
+        &mut self,
+        container: Vec<u128>,
+    ) -> LweCiphertext128 {
+        LweCiphertext128(ImplLweCiphertext::from_container(container))
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextCreationEngine`] for [`DefaultEngine`] which returns an
 /// immutable [`LweCiphertextView32`] that does not own its memory.
@@ -1,15 +1,21 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    LweCiphertext32, LweCiphertext64, LweCiphertextView32, LweCiphertextView64, LweSecretKey32,
-    LweSecretKey64, Plaintext32, Plaintext64,
+    LweCiphertext128, LweCiphertext32, LweCiphertext64, LweCiphertextView32, LweCiphertextView64,
+    LweSecretKey128, LweSecretKey32, LweSecretKey64, Plaintext128, Plaintext32, Plaintext64,
 };
 use crate::commons::crypto::encoding::Plaintext as ImplPlaintext;
 use crate::specification::engines::{LweCiphertextDecryptionEngine, LweCiphertextDecryptionError};
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`LweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on
 /// 32 bits integers.
-impl LweCiphertextDecryptionEngine<LweSecretKey32, LweCiphertext32, Plaintext32> for DefaultEngine {
+impl<Kind> LweCiphertextDecryptionEngine<LweSecretKey32<Kind>, LweCiphertext32, Plaintext32>
+    for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -40,7 +46,7 @@ impl LweCiphertextDecryptionEngine<LweSecretKey32, LweCiphertext32, Plaintext32>
     /// ```
     fn decrypt_lwe_ciphertext(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         input: &LweCiphertext32,
     ) -> Result<Plaintext32, LweCiphertextDecryptionError<Self::EngineError>> {
         Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
@@ -48,7 +54,7 @@ impl LweCiphertextDecryptionEngine<LweSecretKey32, LweCiphertext32, Plaintext32>
 
     unsafe fn decrypt_lwe_ciphertext_unchecked(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         input: &LweCiphertext32,
     ) -> Plaintext32 {
         let mut plaintext = ImplPlaintext(0u32);
@@ -60,7 +66,11 @@ impl LweCiphertextDecryptionEngine<LweSecretKey32, LweCiphertext32, Plaintext32>
 /// # Description:
 /// Implementation of [`LweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on
 /// 64 bits integers.
-impl LweCiphertextDecryptionEngine<LweSecretKey64, LweCiphertext64, Plaintext64> for DefaultEngine {
+impl<Kind> LweCiphertextDecryptionEngine<LweSecretKey64<Kind>, LweCiphertext64, Plaintext64>
+    for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -91,7 +101,7 @@ impl LweCiphertextDecryptionEngine<LweSecretKey64, LweCiphertext64, Plaintext64>
     /// ```
     fn decrypt_lwe_ciphertext(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         input: &LweCiphertext64,
     ) -> Result<Plaintext64, LweCiphertextDecryptionError<Self::EngineError>> {
         Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
@@ -99,7 +109,7 @@ impl LweCiphertextDecryptionEngine<LweSecretKey64, LweCiphertext64, Plaintext64>
 
     unsafe fn decrypt_lwe_ciphertext_unchecked(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         input: &LweCiphertext64,
     ) -> Plaintext64 {
         let mut plaintext = ImplPlaintext(0u64);
@@ -108,11 +118,68 @@ impl LweCiphertextDecryptionEngine<LweSecretKey64, LweCiphertext64, Plaintext64>
     }
 }
 
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on
+/// 128 bits integers.
+impl<Kind> LweCiphertextDecryptionEngine<LweSecretKey128<Kind>, LweCiphertext128, Plaintext128>
+    for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 110 bits)
+    /// let input = 3_u128 << 110;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey128 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKey128<Kind>,
+        input: &LweCiphertext128,
+    ) -> Result<Plaintext128, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey128<Kind>,
+        input: &LweCiphertext128,
+    ) -> Plaintext128 {
+        let mut plaintext = ImplPlaintext(0u128);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        Plaintext128(plaintext)
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on
 /// an [`LweCiphertextView32`] containing 32 bits integers.
-impl LweCiphertextDecryptionEngine<LweSecretKey32, LweCiphertextView32<'_>, Plaintext32>
+impl<Kind> LweCiphertextDecryptionEngine<LweSecretKey32<Kind>, LweCiphertextView32<'_>, Plaintext32>
     for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -153,7 +220,7 @@ impl LweCiphertextDecryptionEngine<LweSecretKey32, LweCiphertextView32<'_>, Plai
     /// ```
     fn decrypt_lwe_ciphertext(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         input: &LweCiphertextView32<'_>,
     ) -> Result<Plaintext32, LweCiphertextDecryptionError<Self::EngineError>> {
         Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
@@ -161,7 +228,7 @@ impl LweCiphertextDecryptionEngine<LweSecretKey32, LweCiphertextView32<'_>, Plai
 
     unsafe fn decrypt_lwe_ciphertext_unchecked(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         input: &LweCiphertextView32<'_>,
     ) -> Plaintext32 {
         let mut plaintext = ImplPlaintext(0u32);
@@ -173,8 +240,10 @@ impl LweCiphertextDecryptionEngine<LweSecretKey32, LweCiphertextView32<'_>, Plai
 /// # Description:
 /// Implementation of [`LweCiphertextDecryptionEngine`] for [`DefaultEngine`] that operates on
 /// an [`LweCiphertextView64`] containing 64 bits integers.
-impl LweCiphertextDecryptionEngine<LweSecretKey64, LweCiphertextView64<'_>, Plaintext64>
+impl<Kind> LweCiphertextDecryptionEngine<LweSecretKey64<Kind>, LweCiphertextView64<'_>, Plaintext64>
     for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -215,7 +284,7 @@ impl LweCiphertextDecryptionEngine<LweSecretKey64, LweCiphertextView64<'_>, Plai
     /// ```
     fn decrypt_lwe_ciphertext(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         input: &LweCiphertextView64<'_>,
     ) -> Result<Plaintext64, LweCiphertextDecryptionError<Self::EngineError>> {
         Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
@@ -223,7 +292,7 @@ impl LweCiphertextDecryptionEngine<LweSecretKey64, LweCiphertextView64<'_>, Plai
 
     unsafe fn decrypt_lwe_ciphertext_unchecked(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         input: &LweCiphertextView64<'_>,
     ) -> Plaintext64 {
         let mut plaintext = ImplPlaintext(0u64);
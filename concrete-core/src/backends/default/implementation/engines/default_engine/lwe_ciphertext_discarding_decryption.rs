@@ -5,12 +5,17 @@ use crate::backends::default::implementation::entities::{
 use crate::specification::engines::{
     LweCiphertextDiscardingDecryptionEngine, LweCiphertextDiscardingDecryptionError,
 };
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`LweCiphertextDiscardingDecryptionEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
-impl LweCiphertextDiscardingDecryptionEngine<LweSecretKey32, LweCiphertext32, Plaintext32>
+impl<Kind>
+    LweCiphertextDiscardingDecryptionEngine<LweSecretKey32<Kind>, LweCiphertext32, Plaintext32>
     for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -44,7 +49,7 @@ impl LweCiphertextDiscardingDecryptionEngine<LweSecretKey32, LweCiphertext32, Pl
     /// ```
     fn discard_decrypt_lwe_ciphertext(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         output: &mut Plaintext32,
         input: &LweCiphertext32,
     ) -> Result<(), LweCiphertextDiscardingDecryptionError<Self::EngineError>> {
@@ -55,7 +60,7 @@ impl LweCiphertextDiscardingDecryptionEngine<LweSecretKey32, LweCiphertext32, Pl
 
     unsafe fn discard_decrypt_lwe_ciphertext_unchecked(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         output: &mut Plaintext32,
         input: &LweCiphertext32,
     ) {
@@ -66,8 +71,11 @@ impl LweCiphertextDiscardingDecryptionEngine<LweSecretKey32, LweCiphertext32, Pl
 /// # Description:
 /// Implementation of [`LweCiphertextDiscardingDecryptionEngine`] for [`DefaultEngine`] that
 /// operates on 64 bits integers.
-impl LweCiphertextDiscardingDecryptionEngine<LweSecretKey64, LweCiphertext64, Plaintext64>
+impl<Kind>
+    LweCiphertextDiscardingDecryptionEngine<LweSecretKey64<Kind>, LweCiphertext64, Plaintext64>
     for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -101,7 +109,7 @@ impl LweCiphertextDiscardingDecryptionEngine<LweSecretKey64, LweCiphertext64, Pl
     /// ```
     fn discard_decrypt_lwe_ciphertext(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         output: &mut Plaintext64,
         input: &LweCiphertext64,
     ) -> Result<(), LweCiphertextDiscardingDecryptionError<Self::EngineError>> {
@@ -112,7 +120,7 @@ impl LweCiphertextDiscardingDecryptionEngine<LweSecretKey64, LweCiphertext64, Pl
 
     unsafe fn discard_decrypt_lwe_ciphertext_unchecked(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         output: &mut Plaintext64,
         input: &LweCiphertext64,
     ) {
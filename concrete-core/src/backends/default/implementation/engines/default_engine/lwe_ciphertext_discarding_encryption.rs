@@ -8,12 +8,17 @@ use crate::backends::default::implementation::entities::{
 use crate::specification::engines::{
     LweCiphertextDiscardingEncryptionEngine, LweCiphertextDiscardingEncryptionError,
 };
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`LweCiphertextDiscardingEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
-impl LweCiphertextDiscardingEncryptionEngine<LweSecretKey32, Plaintext32, LweCiphertext32>
+impl<Kind>
+    LweCiphertextDiscardingEncryptionEngine<LweSecretKey32<Kind>, Plaintext32, LweCiphertext32>
     for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -47,7 +52,7 @@ impl LweCiphertextDiscardingEncryptionEngine<LweSecretKey32, Plaintext32, LweCip
     /// ```
     fn discard_encrypt_lwe_ciphertext(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         output: &mut LweCiphertext32,
         input: &Plaintext32,
         noise: Variance,
@@ -59,7 +64,7 @@ impl LweCiphertextDiscardingEncryptionEngine<LweSecretKey32, Plaintext32, LweCip
 
     unsafe fn discard_encrypt_lwe_ciphertext_unchecked(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         output: &mut LweCiphertext32,
         input: &Plaintext32,
         noise: Variance,
@@ -76,8 +81,11 @@ impl LweCiphertextDiscardingEncryptionEngine<LweSecretKey32, Plaintext32, LweCip
 /// # Description:
 /// Implementation of [`LweCiphertextDiscardingEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 64 bits integers.
-impl LweCiphertextDiscardingEncryptionEngine<LweSecretKey64, Plaintext64, LweCiphertext64>
+impl<Kind>
+    LweCiphertextDiscardingEncryptionEngine<LweSecretKey64<Kind>, Plaintext64, LweCiphertext64>
     for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -111,7 +119,7 @@ impl LweCiphertextDiscardingEncryptionEngine<LweSecretKey64, Plaintext64, LweCip
     /// ```
     fn discard_encrypt_lwe_ciphertext(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         output: &mut LweCiphertext64,
         input: &Plaintext64,
         noise: Variance,
@@ -123,7 +131,7 @@ impl LweCiphertextDiscardingEncryptionEngine<LweSecretKey64, Plaintext64, LweCip
 
     unsafe fn discard_encrypt_lwe_ciphertext_unchecked(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         output: &mut LweCiphertext64,
         input: &Plaintext64,
         noise: Variance,
@@ -140,9 +148,14 @@ impl LweCiphertextDiscardingEncryptionEngine<LweSecretKey64, Plaintext64, LweCip
 /// # Description:
 /// Implementation of [`LweCiphertextDiscardingEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
-impl
-    LweCiphertextDiscardingEncryptionEngine<LweSecretKey32, Plaintext32, LweCiphertextMutView32<'_>>
-    for DefaultEngine
+impl<Kind>
+    LweCiphertextDiscardingEncryptionEngine<
+        LweSecretKey32<Kind>,
+        Plaintext32,
+        LweCiphertextMutView32<'_>,
+    > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -178,7 +191,7 @@ impl
     /// ```
     fn discard_encrypt_lwe_ciphertext(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         output: &mut LweCiphertextMutView32,
         input: &Plaintext32,
         noise: Variance,
@@ -190,7 +203,7 @@ impl
 
     unsafe fn discard_encrypt_lwe_ciphertext_unchecked(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         output: &mut LweCiphertextMutView32,
         input: &Plaintext32,
         noise: Variance,
@@ -207,9 +220,14 @@ impl
 /// # Description:
 /// Implementation of [`LweCiphertextDiscardingEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 64 bits integers.
-impl
-    LweCiphertextDiscardingEncryptionEngine<LweSecretKey64, Plaintext64, LweCiphertextMutView64<'_>>
-    for DefaultEngine
+impl<Kind>
+    LweCiphertextDiscardingEncryptionEngine<
+        LweSecretKey64<Kind>,
+        Plaintext64,
+        LweCiphertextMutView64<'_>,
+    > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -245,7 +263,7 @@ impl
     /// ```
     fn discard_encrypt_lwe_ciphertext(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         output: &mut LweCiphertextMutView64,
         input: &Plaintext64,
         noise: Variance,
@@ -257,7 +275,7 @@ impl
 
     unsafe fn discard_encrypt_lwe_ciphertext_unchecked(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         output: &mut LweCiphertextMutView64,
         input: &Plaintext64,
         noise: Variance,
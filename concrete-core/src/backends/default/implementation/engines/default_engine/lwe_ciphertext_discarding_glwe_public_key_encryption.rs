@@ -0,0 +1,224 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlwePublicKey32, GlwePublicKey64, LweCiphertext32, LweCiphertext64, Plaintext32, Plaintext64,
+};
+use crate::commons::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::commons::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::commons::math::tensor::AsMutTensor;
+use crate::specification::engines::{
+    LweCiphertextDiscardingGlwePublicKeyEncryptionEngine,
+    LweCiphertextDiscardingGlwePublicKeyEncryptionError,
+};
+use crate::specification::entities::GlwePublicKeyEntity;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::key_kinds::BinaryKeyKind;
+#[allow(deprecated)]
+use concrete_commons::parameters::{MonomialDegree, PlaintextCount};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingGlwePublicKeyEncryptionEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers. The randomness polynomials are sampled
+/// with uniform binary coefficients.
+impl
+    LweCiphertextDiscardingGlwePublicKeyEncryptionEngine<
+        GlwePublicKey32,
+        Plaintext32,
+        LweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, GlwePublicKeyZeroEncryptionCount, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let secret_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let public_key: GlwePublicKey32 = engine.generate_new_glwe_public_key(
+    ///     &secret_key,
+    ///     noise,
+    ///     GlwePublicKeyZeroEncryptionCount(1),
+    /// )?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let lwe_dimension = LweDimension(glwe_dimension.0 * polynomial_size.0);
+    /// let mut ciphertext: LweCiphertext32 =
+    ///     engine.trivially_encrypt_lwe_ciphertext(lwe_dimension.to_lwe_size(), &plaintext)?;
+    ///
+    /// engine.discard_encrypt_lwe_ciphertext_with_glwe_public_key(
+    ///     &public_key,
+    ///     &mut ciphertext,
+    ///     &plaintext,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext_with_glwe_public_key(
+        &mut self,
+        key: &GlwePublicKey32,
+        output: &mut LweCiphertext32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextDiscardingGlwePublicKeyEncryptionError<Self::EngineError>> {
+        LweCiphertextDiscardingGlwePublicKeyEncryptionError::perform_generic_checks(key, output)?;
+        unsafe {
+            self.discard_encrypt_lwe_ciphertext_with_glwe_public_key_unchecked(
+                key, output, input, noise,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_with_glwe_public_key_unchecked(
+        &mut self,
+        key: &GlwePublicKey32,
+        output: &mut LweCiphertext32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) {
+        // The plaintext is encrypted as the constant coefficient of a GLWE ciphertext, which is
+        // then extracted.
+        let mut plaintexts =
+            ImplPlaintextList::allocate(0u32, PlaintextCount(key.polynomial_size().0));
+        *plaintexts.as_mut_tensor().first_mut() = input.0 .0;
+        let mut glwe = ImplGlweCiphertext::allocate(
+            0u32,
+            key.polynomial_size(),
+            key.glwe_dimension().to_glwe_size(),
+        );
+        key.0.encrypt_glwe::<BinaryKeyKind, _, _, _, _>(
+            &mut glwe,
+            &plaintexts,
+            noise,
+            &mut self.secret_generator,
+            &mut self.encryption_generator,
+        );
+        #[allow(deprecated)]
+        output
+            .0
+            .fill_with_glwe_sample_extraction(&glwe, MonomialDegree(0));
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingGlwePublicKeyEncryptionEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers. The randomness polynomials are sampled
+/// with uniform binary coefficients.
+impl
+    LweCiphertextDiscardingGlwePublicKeyEncryptionEngine<
+        GlwePublicKey64,
+        Plaintext64,
+        LweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, GlwePublicKeyZeroEncryptionCount, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let secret_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let public_key: GlwePublicKey64 = engine.generate_new_glwe_public_key(
+    ///     &secret_key,
+    ///     noise,
+    ///     GlwePublicKeyZeroEncryptionCount(1),
+    /// )?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let lwe_dimension = LweDimension(glwe_dimension.0 * polynomial_size.0);
+    /// let mut ciphertext: LweCiphertext64 =
+    ///     engine.trivially_encrypt_lwe_ciphertext(lwe_dimension.to_lwe_size(), &plaintext)?;
+    ///
+    /// engine.discard_encrypt_lwe_ciphertext_with_glwe_public_key(
+    ///     &public_key,
+    ///     &mut ciphertext,
+    ///     &plaintext,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext_with_glwe_public_key(
+        &mut self,
+        key: &GlwePublicKey64,
+        output: &mut LweCiphertext64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextDiscardingGlwePublicKeyEncryptionError<Self::EngineError>> {
+        LweCiphertextDiscardingGlwePublicKeyEncryptionError::perform_generic_checks(key, output)?;
+        unsafe {
+            self.discard_encrypt_lwe_ciphertext_with_glwe_public_key_unchecked(
+                key, output, input, noise,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_with_glwe_public_key_unchecked(
+        &mut self,
+        key: &GlwePublicKey64,
+        output: &mut LweCiphertext64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) {
+        // The plaintext is encrypted as the constant coefficient of a GLWE ciphertext, which is
+        // then extracted.
+        let mut plaintexts =
+            ImplPlaintextList::allocate(0u64, PlaintextCount(key.polynomial_size().0));
+        *plaintexts.as_mut_tensor().first_mut() = input.0 .0;
+        let mut glwe = ImplGlweCiphertext::allocate(
+            0u64,
+            key.polynomial_size(),
+            key.glwe_dimension().to_glwe_size(),
+        );
+        key.0.encrypt_glwe::<BinaryKeyKind, _, _, _, _>(
+            &mut glwe,
+            &plaintexts,
+            noise,
+            &mut self.secret_generator,
+            &mut self.encryption_generator,
+        );
+        #[allow(deprecated)]
+        output
+            .0
+            .fill_with_glwe_sample_extraction(&glwe, MonomialDegree(0));
+    }
+}
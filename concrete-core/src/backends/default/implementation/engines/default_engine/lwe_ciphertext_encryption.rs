@@ -2,16 +2,23 @@ use concrete_commons::dispersion::Variance;
 
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    LweCiphertext32, LweCiphertext64, LweSecretKey32, LweSecretKey64, Plaintext32, Plaintext64,
+    LweCiphertext128, LweCiphertext32, LweCiphertext64, LweSecretKey128, LweSecretKey32,
+    LweSecretKey64, Plaintext128, Plaintext32, Plaintext64,
 };
 use crate::commons::crypto::lwe::LweCiphertext as ImplLweCiphertext;
 use crate::specification::engines::{LweCiphertextEncryptionEngine, LweCiphertextEncryptionError};
 use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`LweCiphertextEncryptionEngine`] for [`DefaultEngine`] that operates on
 /// 32 bits integers.
-impl LweCiphertextEncryptionEngine<LweSecretKey32, Plaintext32, LweCiphertext32> for DefaultEngine {
+impl<Kind> LweCiphertextEncryptionEngine<LweSecretKey32<Kind>, Plaintext32, LweCiphertext32>
+    for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -43,7 +50,7 @@ impl LweCiphertextEncryptionEngine<LweSecretKey32, Plaintext32, LweCiphertext32>
     /// ```
     fn encrypt_lwe_ciphertext(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         input: &Plaintext32,
         noise: Variance,
     ) -> Result<LweCiphertext32, LweCiphertextEncryptionError<Self::EngineError>> {
@@ -52,7 +59,7 @@ impl LweCiphertextEncryptionEngine<LweSecretKey32, Plaintext32, LweCiphertext32>
 
     unsafe fn encrypt_lwe_ciphertext_unchecked(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         input: &Plaintext32,
         noise: Variance,
     ) -> LweCiphertext32 {
@@ -70,7 +77,11 @@ impl LweCiphertextEncryptionEngine<LweSecretKey32, Plaintext32, LweCiphertext32>
 /// # Description:
 /// Implementation of [`LweCiphertextEncryptionEngine`] for [`DefaultEngine`] that operates on
 /// 64 bits integers.
-impl LweCiphertextEncryptionEngine<LweSecretKey64, Plaintext64, LweCiphertext64> for DefaultEngine {
+impl<Kind> LweCiphertextEncryptionEngine<LweSecretKey64<Kind>, Plaintext64, LweCiphertext64>
+    for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -102,7 +113,7 @@ impl LweCiphertextEncryptionEngine<LweSecretKey64, Plaintext64, LweCiphertext64>
     /// ```
     fn encrypt_lwe_ciphertext(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         input: &Plaintext64,
         noise: Variance,
     ) -> Result<LweCiphertext64, LweCiphertextEncryptionError<Self::EngineError>> {
@@ -111,7 +122,7 @@ impl LweCiphertextEncryptionEngine<LweSecretKey64, Plaintext64, LweCiphertext64>
 
     unsafe fn encrypt_lwe_ciphertext_unchecked(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         input: &Plaintext64,
         noise: Variance,
     ) -> LweCiphertext64 {
@@ -125,3 +136,66 @@ impl LweCiphertextEncryptionEngine<LweSecretKey64, Plaintext64, LweCiphertext64>
         LweCiphertext64(ciphertext)
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`DefaultEngine`] that operates on
+/// 128 bits integers.
+impl<Kind> LweCiphertextEncryptionEngine<LweSecretKey128<Kind>, Plaintext128, LweCiphertext128>
+    for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 110 bits)
+    /// let input = 3_u128 << 110;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey128 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKey128<Kind>,
+        input: &Plaintext128,
+        noise: Variance,
+    ) -> Result<LweCiphertext128, LweCiphertextEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey128<Kind>,
+        input: &Plaintext128,
+        noise: Variance,
+    ) -> LweCiphertext128 {
+        let mut ciphertext = ImplLweCiphertext::allocate(0u128, key.lwe_dimension().to_lwe_size());
+        key.0.encrypt_lwe(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweCiphertext128(ciphertext)
+    }
+}
@@ -0,0 +1,152 @@
+use concrete_commons::parameters::{CiphertextCount, CiphertextModulusLog};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweCompressedCiphertextVector32,
+    LweCompressedCiphertextVector64,
+};
+use crate::commons::crypto::lwe::{
+    LweCompressedList as ImplLweCompressedList, LweList as ImplLweList,
+};
+use crate::commons::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::specification::engines::{
+    LweCiphertextModulusSwitchingEngine, LweCiphertextModulusSwitchingError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextModulusSwitchingEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers.
+impl LweCiphertextModulusSwitchingEngine<LweCiphertext32, LweCompressedCiphertextVector32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 28 bits)
+    /// let input = 3_u32 << 28;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// // Only the 8 most significant bits of each coefficient are kept
+    /// let compressed: LweCompressedCiphertextVector32 =
+    ///     engine.switch_modulus_lwe_ciphertext(&ciphertext, CiphertextModulusLog(8))?;
+    /// #
+    /// assert_eq!(compressed.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(compressed.lwe_ciphertext_count(), LweCiphertextCount(1));
+    /// assert_eq!(compressed.ciphertext_modulus_log(), CiphertextModulusLog(8));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn switch_modulus_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext32,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Result<
+        LweCompressedCiphertextVector32,
+        LweCiphertextModulusSwitchingError<Self::EngineError>,
+    > {
+        LweCiphertextModulusSwitchingError::perform_generic_checks(ciphertext_modulus_log, 32)?;
+        Ok(unsafe { self.switch_modulus_lwe_ciphertext_unchecked(input, ciphertext_modulus_log) })
+    }
+
+    unsafe fn switch_modulus_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext32,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> LweCompressedCiphertextVector32 {
+        let input_list =
+            ImplLweList::from_container(input.0.as_tensor().as_slice(), input.0.lwe_size());
+        let mut compressed = ImplLweCompressedList::allocate(
+            input.0.lwe_size(),
+            CiphertextCount(1),
+            ciphertext_modulus_log,
+        );
+        compressed.fill_with_modulus_switch(&input_list);
+        LweCompressedCiphertextVector32(compressed)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextModulusSwitchingEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers.
+impl LweCiphertextModulusSwitchingEngine<LweCiphertext64, LweCompressedCiphertextVector64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 60 bits)
+    /// let input = 3_u64 << 60;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// // Only the 8 most significant bits of each coefficient are kept
+    /// let compressed: LweCompressedCiphertextVector64 =
+    ///     engine.switch_modulus_lwe_ciphertext(&ciphertext, CiphertextModulusLog(8))?;
+    /// #
+    /// assert_eq!(compressed.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(compressed.lwe_ciphertext_count(), LweCiphertextCount(1));
+    /// assert_eq!(compressed.ciphertext_modulus_log(), CiphertextModulusLog(8));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn switch_modulus_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext64,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Result<
+        LweCompressedCiphertextVector64,
+        LweCiphertextModulusSwitchingError<Self::EngineError>,
+    > {
+        LweCiphertextModulusSwitchingError::perform_generic_checks(ciphertext_modulus_log, 64)?;
+        Ok(unsafe { self.switch_modulus_lwe_ciphertext_unchecked(input, ciphertext_modulus_log) })
+    }
+
+    unsafe fn switch_modulus_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext64,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> LweCompressedCiphertextVector64 {
+        let input_list =
+            ImplLweList::from_container(input.0.as_tensor().as_slice(), input.0.lwe_size());
+        let mut compressed = ImplLweCompressedList::allocate(
+            input.0.lwe_size(),
+            CiphertextCount(1),
+            ciphertext_modulus_log,
+        );
+        compressed.fill_with_modulus_switch(&input_list);
+        LweCompressedCiphertextVector64(compressed)
+    }
+}
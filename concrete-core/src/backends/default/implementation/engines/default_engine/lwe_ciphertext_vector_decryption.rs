@@ -10,12 +10,20 @@ use crate::specification::engines::{
     LweCiphertextVectorDecryptionEngine, LweCiphertextVectorDecryptionError,
 };
 use crate::specification::entities::LweCiphertextVectorEntity;
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`LweCiphertextVectorDecryptionEngine`] for [`DefaultEngine`] that operates on
 /// 32 bits integers.
-impl LweCiphertextVectorDecryptionEngine<LweSecretKey32, LweCiphertextVector32, PlaintextVector32>
-    for DefaultEngine
+impl<Kind>
+    LweCiphertextVectorDecryptionEngine<
+        LweSecretKey32<Kind>,
+        LweCiphertextVector32,
+        PlaintextVector32,
+    > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -54,7 +62,7 @@ impl LweCiphertextVectorDecryptionEngine<LweSecretKey32, LweCiphertextVector32,
     /// ```
     fn decrypt_lwe_ciphertext_vector(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         input: &LweCiphertextVector32,
     ) -> Result<PlaintextVector32, LweCiphertextVectorDecryptionError<Self::EngineError>> {
         LweCiphertextVectorDecryptionError::perform_generic_checks(key, input)?;
@@ -63,7 +71,7 @@ impl LweCiphertextVectorDecryptionEngine<LweSecretKey32, LweCiphertextVector32,
 
     unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         input: &LweCiphertextVector32,
     ) -> PlaintextVector32 {
         let mut plaintext =
@@ -76,8 +84,14 @@ impl LweCiphertextVectorDecryptionEngine<LweSecretKey32, LweCiphertextVector32,
 /// # Description:
 /// Implementation of [`LweCiphertextVectorDecryptionEngine`] for [`DefaultEngine`] that operates on
 /// 64 bits integers.
-impl LweCiphertextVectorDecryptionEngine<LweSecretKey64, LweCiphertextVector64, PlaintextVector64>
-    for DefaultEngine
+impl<Kind>
+    LweCiphertextVectorDecryptionEngine<
+        LweSecretKey64<Kind>,
+        LweCiphertextVector64,
+        PlaintextVector64,
+    > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -116,7 +130,7 @@ impl LweCiphertextVectorDecryptionEngine<LweSecretKey64, LweCiphertextVector64,
     /// ```
     fn decrypt_lwe_ciphertext_vector(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         input: &LweCiphertextVector64,
     ) -> Result<PlaintextVector64, LweCiphertextVectorDecryptionError<Self::EngineError>> {
         LweCiphertextVectorDecryptionError::perform_generic_checks(key, input)?;
@@ -125,7 +139,7 @@ impl LweCiphertextVectorDecryptionEngine<LweSecretKey64, LweCiphertextVector64,
 
     unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         input: &LweCiphertextVector64,
     ) -> PlaintextVector64 {
         let mut plaintext =
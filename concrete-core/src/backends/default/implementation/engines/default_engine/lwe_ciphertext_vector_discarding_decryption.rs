@@ -6,16 +6,20 @@ use crate::backends::default::implementation::entities::{
 use crate::specification::engines::{
     LweCiphertextVectorDiscardingDecryptionEngine, LweCiphertextVectorDiscardingDecryptionError,
 };
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`LweCiphertextVectorDiscardingDecryptionEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
-impl
+impl<Kind>
     LweCiphertextVectorDiscardingDecryptionEngine<
-        LweSecretKey32,
+        LweSecretKey32<Kind>,
         LweCiphertextVector32,
         PlaintextVector32,
     > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -54,7 +58,7 @@ impl
     /// ```
     fn discard_decrypt_lwe_ciphertext_vector(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         output: &mut PlaintextVector32,
         input: &LweCiphertextVector32,
     ) -> Result<(), LweCiphertextVectorDiscardingDecryptionError<Self::EngineError>> {
@@ -65,7 +69,7 @@ impl
 
     unsafe fn discard_decrypt_lwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         output: &mut PlaintextVector32,
         input: &LweCiphertextVector32,
     ) {
@@ -76,12 +80,14 @@ impl
 /// # Description:
 /// Implementation of [`LweCiphertextVectorDiscardingDecryptionEngine`] for [`DefaultEngine`] that
 /// operates on 64 bits integers.
-impl
+impl<Kind>
     LweCiphertextVectorDiscardingDecryptionEngine<
-        LweSecretKey64,
+        LweSecretKey64<Kind>,
         LweCiphertextVector64,
         PlaintextVector64,
     > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -120,7 +126,7 @@ impl
     /// ```
     fn discard_decrypt_lwe_ciphertext_vector(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         output: &mut PlaintextVector64,
         input: &LweCiphertextVector64,
     ) -> Result<(), LweCiphertextVectorDiscardingDecryptionError<Self::EngineError>> {
@@ -131,7 +137,7 @@ impl
 
     unsafe fn discard_decrypt_lwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         output: &mut PlaintextVector64,
         input: &LweCiphertextVector64,
     ) {
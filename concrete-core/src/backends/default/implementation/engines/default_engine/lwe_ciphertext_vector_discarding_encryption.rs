@@ -8,16 +8,20 @@ use crate::backends::default::implementation::entities::{
 use crate::specification::engines::{
     LweCiphertextVectorDiscardingEncryptionEngine, LweCiphertextVectorDiscardingEncryptionError,
 };
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`LweCiphertextVectorDiscardingEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
-impl
+impl<Kind>
     LweCiphertextVectorDiscardingEncryptionEngine<
-        LweSecretKey32,
+        LweSecretKey32<Kind>,
         PlaintextVector32,
         LweCiphertextVector32,
     > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -61,7 +65,7 @@ impl
     /// ```
     fn discard_encrypt_lwe_ciphertext_vector(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         output: &mut LweCiphertextVector32,
         input: &PlaintextVector32,
         noise: Variance,
@@ -73,7 +77,7 @@ impl
 
     unsafe fn discard_encrypt_lwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         output: &mut LweCiphertextVector32,
         input: &PlaintextVector32,
         noise: Variance,
@@ -90,12 +94,14 @@ impl
 /// # Description:
 /// Implementation of [`LweCiphertextVectorDiscardingEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 64 bits integers.
-impl
+impl<Kind>
     LweCiphertextVectorDiscardingEncryptionEngine<
-        LweSecretKey64,
+        LweSecretKey64<Kind>,
         PlaintextVector64,
         LweCiphertextVector64,
     > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -139,7 +145,7 @@ impl
     /// ```
     fn discard_encrypt_lwe_ciphertext_vector(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         output: &mut LweCiphertextVector64,
         input: &PlaintextVector64,
         noise: Variance,
@@ -151,7 +157,7 @@ impl
 
     unsafe fn discard_encrypt_lwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         output: &mut LweCiphertextVector64,
         input: &PlaintextVector64,
         noise: Variance,
@@ -11,12 +11,20 @@ use crate::specification::engines::{
     LweCiphertextVectorEncryptionEngine, LweCiphertextVectorEncryptionError,
 };
 use crate::specification::entities::{LweSecretKeyEntity, PlaintextVectorEntity};
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`LweCiphertextVectorEncryptionEngine`] for [`DefaultEngine`] that operates on
 /// 32 bits integers.
-impl LweCiphertextVectorEncryptionEngine<LweSecretKey32, PlaintextVector32, LweCiphertextVector32>
-    for DefaultEngine
+impl<Kind>
+    LweCiphertextVectorEncryptionEngine<
+        LweSecretKey32<Kind>,
+        PlaintextVector32,
+        LweCiphertextVector32,
+    > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -54,7 +62,7 @@ impl LweCiphertextVectorEncryptionEngine<LweSecretKey32, PlaintextVector32, LweC
     /// ```
     fn encrypt_lwe_ciphertext_vector(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         input: &PlaintextVector32,
         noise: Variance,
     ) -> Result<LweCiphertextVector32, LweCiphertextVectorEncryptionError<Self::EngineError>> {
@@ -63,7 +71,7 @@ impl LweCiphertextVectorEncryptionEngine<LweSecretKey32, PlaintextVector32, LweC
 
     unsafe fn encrypt_lwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         input: &PlaintextVector32,
         noise: Variance,
     ) -> LweCiphertextVector32 {
@@ -81,8 +89,14 @@ impl LweCiphertextVectorEncryptionEngine<LweSecretKey32, PlaintextVector32, LweC
 /// # Description:
 /// Implementation of [`LweCiphertextVectorEncryptionEngine`] for [`DefaultEngine`] that operates on
 /// 64 bits integers.
-impl LweCiphertextVectorEncryptionEngine<LweSecretKey64, PlaintextVector64, LweCiphertextVector64>
-    for DefaultEngine
+impl<Kind>
+    LweCiphertextVectorEncryptionEngine<
+        LweSecretKey64<Kind>,
+        PlaintextVector64,
+        LweCiphertextVector64,
+    > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -120,7 +134,7 @@ impl LweCiphertextVectorEncryptionEngine<LweSecretKey64, PlaintextVector64, LweC
     /// ```
     fn encrypt_lwe_ciphertext_vector(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         input: &PlaintextVector64,
         noise: Variance,
     ) -> Result<LweCiphertextVector64, LweCiphertextVectorEncryptionError<Self::EngineError>> {
@@ -129,7 +143,7 @@ impl LweCiphertextVectorEncryptionEngine<LweSecretKey64, PlaintextVector64, LweC
 
     unsafe fn encrypt_lwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         input: &PlaintextVector64,
         noise: Variance,
     ) -> LweCiphertextVector64 {
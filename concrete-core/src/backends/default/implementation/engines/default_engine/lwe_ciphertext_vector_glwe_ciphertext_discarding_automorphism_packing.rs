@@ -0,0 +1,203 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweAutomorphismKey32, GlweAutomorphismKey64, GlweCiphertext32, GlweCiphertext64,
+    LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingEngine,
+    LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingError,
+};
+
+/// # Description:
+/// Implementation of
+/// [`LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingEngine<
+        GlweAutomorphismKey32,
+        LweCiphertextVector32,
+        GlweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let decomposition_base_log = DecompositionBaseLog(4);
+    /// // The exponents 2^i + 1 for 1 <= i <= log2(N)
+    /// let exponents: Vec<_> = (1..=8)
+    ///     .map(|i| AutomorphismExponent((1 << i) + 1))
+    ///     .collect();
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_key: GlweAutomorphismKey32 = engine.generate_new_glwe_automorphism_key(
+    ///     &key,
+    ///     &exponents,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// // The LWE ciphertexts are encrypted under the flattened GLWE secret key
+    /// let lwe_key: LweSecretKey32 =
+    ///     engine.transform_glwe_secret_key_to_lwe_secret_key(key.clone())?;
+    /// // Here a hard-set encoding is applied (shift by 28 bits)
+    /// let input = vec![1_u32 << 28, 2 << 28, 3 << 28];
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let input_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&lwe_key, &plaintext_vector, noise)?;
+    ///
+    /// let mut output_ciphertext = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    /// engine.discard_automorphism_pack_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext,
+    ///     &input_vector,
+    ///     &automorphism_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output_ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_automorphism_pack_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &LweCiphertextVector32,
+        key: &GlweAutomorphismKey32,
+    ) -> Result<
+        (),
+        LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingError<Self::EngineError>,
+    > {
+        LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingError::perform_generic_checks(
+            output, input, key,
+        )?;
+        unsafe {
+            self.discard_automorphism_pack_lwe_ciphertext_vector_unchecked(output, input, key)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_automorphism_pack_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &LweCiphertextVector32,
+        key: &GlweAutomorphismKey32,
+    ) {
+        key.0.pack_lwe_list(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of
+/// [`LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingEngine<
+        GlweAutomorphismKey64,
+        LweCiphertextVector64,
+        GlweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let decomposition_base_log = DecompositionBaseLog(10);
+    /// // The exponents 2^i + 1 for 1 <= i <= log2(N)
+    /// let exponents: Vec<_> = (1..=8)
+    ///     .map(|i| AutomorphismExponent((1 << i) + 1))
+    ///     .collect();
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let automorphism_key: GlweAutomorphismKey64 = engine.generate_new_glwe_automorphism_key(
+    ///     &key,
+    ///     &exponents,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// // The LWE ciphertexts are encrypted under the flattened GLWE secret key
+    /// let lwe_key: LweSecretKey64 =
+    ///     engine.transform_glwe_secret_key_to_lwe_secret_key(key.clone())?;
+    /// // Here a hard-set encoding is applied (shift by 60 bits)
+    /// let input = vec![1_u64 << 60, 2 << 60, 3 << 60];
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let input_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&lwe_key, &plaintext_vector, noise)?;
+    ///
+    /// let mut output_ciphertext = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    /// engine.discard_automorphism_pack_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext,
+    ///     &input_vector,
+    ///     &automorphism_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output_ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_automorphism_pack_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &LweCiphertextVector64,
+        key: &GlweAutomorphismKey64,
+    ) -> Result<
+        (),
+        LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingError<Self::EngineError>,
+    > {
+        LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingError::perform_generic_checks(
+            output, input, key,
+        )?;
+        unsafe {
+            self.discard_automorphism_pack_lwe_ciphertext_vector_unchecked(output, input, key)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_automorphism_pack_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &LweCiphertextVector64,
+        key: &GlweAutomorphismKey64,
+    ) {
+        key.0.pack_lwe_list(&mut output.0, &input.0);
+    }
+}
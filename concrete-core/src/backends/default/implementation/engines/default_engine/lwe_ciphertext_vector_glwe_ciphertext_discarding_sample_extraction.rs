@@ -0,0 +1,205 @@
+#[allow(deprecated)]
+use concrete_commons::parameters::{MonomialDegree, MonomialIndex};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionEngine,
+    LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionError,
+};
+
+/// # Description:
+/// Implementation of
+/// [`LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionEngine<
+        GlweCiphertext32,
+        LweCiphertextVector32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, LweCiphertextCount, MonomialIndex, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 28 bits)
+    /// let input = vec![3_u32 << 28; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // We extract every coefficient of the GLWE ciphertext
+    /// let indices: Vec<MonomialIndex> = (0..polynomial_size.0).map(MonomialIndex).collect();
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let glwe_ciphertext = engine.encrypt_glwe_ciphertext(&glwe_key, &plaintext_vector, noise)?;
+    /// // The extracted LWE ciphertexts are encrypted under the GLWE key seen as an LWE key
+    /// let lwe_key: LweSecretKey32 = engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key)?;
+    /// let mut lwe_ciphertext_vector = engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_key,
+    ///     noise,
+    ///     LweCiphertextCount(indices.len()),
+    /// )?;
+    ///
+    /// engine.discard_sample_extract_lwe_ciphertext_vector(
+    ///     &mut lwe_ciphertext_vector,
+    ///     &glwe_ciphertext,
+    ///     &indices,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     lwe_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(indices.len())
+    /// );
+    /// let decrypted = engine.decrypt_lwe_ciphertext_vector(&lwe_key, &lwe_ciphertext_vector)?;
+    /// let output = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// for value in output {
+    ///     assert_eq!(value.wrapping_add(1 << 27) >> 28, 3);
+    /// }
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_sample_extract_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &GlweCiphertext32,
+        indices: &[MonomialIndex],
+    ) -> Result<
+        (),
+        LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionError<Self::EngineError>,
+    > {
+        LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionError::perform_generic_checks(
+            output, input, indices,
+        )?;
+        unsafe {
+            self.discard_sample_extract_lwe_ciphertext_vector_unchecked(output, input, indices)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_sample_extract_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &GlweCiphertext32,
+        indices: &[MonomialIndex],
+    ) {
+        #[allow(deprecated)]
+        let degrees: Vec<MonomialDegree> = indices.iter().map(|i| MonomialDegree(i.0)).collect();
+        output
+            .0
+            .fill_with_glwe_sample_extractions(&input.0, &degrees);
+    }
+}
+
+/// # Description:
+/// Implementation of
+/// [`LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionEngine<
+        GlweCiphertext64,
+        LweCiphertextVector64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, LweCiphertextCount, MonomialIndex, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 60 bits)
+    /// let input = vec![3_u64 << 60; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // We extract every coefficient of the GLWE ciphertext
+    /// let indices: Vec<MonomialIndex> = (0..polynomial_size.0).map(MonomialIndex).collect();
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let glwe_ciphertext = engine.encrypt_glwe_ciphertext(&glwe_key, &plaintext_vector, noise)?;
+    /// // The extracted LWE ciphertexts are encrypted under the GLWE key seen as an LWE key
+    /// let lwe_key: LweSecretKey64 = engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key)?;
+    /// let mut lwe_ciphertext_vector = engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_key,
+    ///     noise,
+    ///     LweCiphertextCount(indices.len()),
+    /// )?;
+    ///
+    /// engine.discard_sample_extract_lwe_ciphertext_vector(
+    ///     &mut lwe_ciphertext_vector,
+    ///     &glwe_ciphertext,
+    ///     &indices,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     lwe_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(indices.len())
+    /// );
+    /// let decrypted = engine.decrypt_lwe_ciphertext_vector(&lwe_key, &lwe_ciphertext_vector)?;
+    /// let output = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// for value in output {
+    ///     assert_eq!(value.wrapping_add(1 << 59) >> 60, 3);
+    /// }
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_sample_extract_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &GlweCiphertext64,
+        indices: &[MonomialIndex],
+    ) -> Result<
+        (),
+        LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionError<Self::EngineError>,
+    > {
+        LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionError::perform_generic_checks(
+            output, input, indices,
+        )?;
+        unsafe {
+            self.discard_sample_extract_lwe_ciphertext_vector_unchecked(output, input, indices)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_sample_extract_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &GlweCiphertext64,
+        indices: &[MonomialIndex],
+    ) {
+        #[allow(deprecated)]
+        let degrees: Vec<MonomialDegree> = indices.iter().map(|i| MonomialDegree(i.0)).collect();
+        output
+            .0
+            .fill_with_glwe_sample_extractions(&input.0, &degrees);
+    }
+}
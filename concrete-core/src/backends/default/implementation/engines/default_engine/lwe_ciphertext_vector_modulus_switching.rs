@@ -0,0 +1,163 @@
+use concrete_commons::parameters::CiphertextModulusLog;
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LweCompressedCiphertextVector32,
+    LweCompressedCiphertextVector64,
+};
+use crate::commons::crypto::lwe::LweCompressedList as ImplLweCompressedList;
+use crate::specification::engines::{
+    LweCiphertextVectorModulusSwitchingEngine, LweCiphertextVectorModulusSwitchingError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorModulusSwitchingEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextVectorModulusSwitchingEngine<
+        LweCiphertextVector32,
+        LweCompressedCiphertextVector32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 28 bits)
+    /// let input = vec![3_u32 << 28; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// // Only the 8 most significant bits of each coefficient are kept
+    /// let compressed: LweCompressedCiphertextVector32 = engine
+    ///     .switch_modulus_lwe_ciphertext_vector(&ciphertext_vector, CiphertextModulusLog(8))?;
+    /// #
+    /// assert_eq!(compressed.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(compressed.lwe_ciphertext_count(), LweCiphertextCount(3));
+    /// assert_eq!(compressed.ciphertext_modulus_log(), CiphertextModulusLog(8));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn switch_modulus_lwe_ciphertext_vector(
+        &mut self,
+        input: &LweCiphertextVector32,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Result<
+        LweCompressedCiphertextVector32,
+        LweCiphertextVectorModulusSwitchingError<Self::EngineError>,
+    > {
+        LweCiphertextVectorModulusSwitchingError::perform_generic_checks(
+            ciphertext_modulus_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.switch_modulus_lwe_ciphertext_vector_unchecked(input, ciphertext_modulus_log)
+        })
+    }
+
+    unsafe fn switch_modulus_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &LweCiphertextVector32,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> LweCompressedCiphertextVector32 {
+        let mut compressed = ImplLweCompressedList::allocate(
+            input.0.lwe_size(),
+            input.0.count(),
+            ciphertext_modulus_log,
+        );
+        compressed.fill_with_modulus_switch(&input.0);
+        LweCompressedCiphertextVector32(compressed)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorModulusSwitchingEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextVectorModulusSwitchingEngine<
+        LweCiphertextVector64,
+        LweCompressedCiphertextVector64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 60 bits)
+    /// let input = vec![3_u64 << 60; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// // Only the 8 most significant bits of each coefficient are kept
+    /// let compressed: LweCompressedCiphertextVector64 = engine
+    ///     .switch_modulus_lwe_ciphertext_vector(&ciphertext_vector, CiphertextModulusLog(8))?;
+    /// #
+    /// assert_eq!(compressed.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(compressed.lwe_ciphertext_count(), LweCiphertextCount(3));
+    /// assert_eq!(compressed.ciphertext_modulus_log(), CiphertextModulusLog(8));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn switch_modulus_lwe_ciphertext_vector(
+        &mut self,
+        input: &LweCiphertextVector64,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Result<
+        LweCompressedCiphertextVector64,
+        LweCiphertextVectorModulusSwitchingError<Self::EngineError>,
+    > {
+        LweCiphertextVectorModulusSwitchingError::perform_generic_checks(
+            ciphertext_modulus_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.switch_modulus_lwe_ciphertext_vector_unchecked(input, ciphertext_modulus_log)
+        })
+    }
+
+    unsafe fn switch_modulus_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &LweCiphertextVector64,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> LweCompressedCiphertextVector64 {
+        let mut compressed = ImplLweCompressedList::allocate(
+            input.0.lwe_size(),
+            input.0.count(),
+            ciphertext_modulus_log,
+        );
+        compressed.fill_with_modulus_switch(&input.0);
+        LweCompressedCiphertextVector64(compressed)
+    }
+}
@@ -11,12 +11,16 @@ use crate::specification::engines::{
     LweCiphertextVectorZeroEncryptionEngine, LweCiphertextVectorZeroEncryptionError,
 };
 use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`LweCiphertextVectorZeroEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
-impl LweCiphertextVectorZeroEncryptionEngine<LweSecretKey32, LweCiphertextVector32>
+impl<Kind> LweCiphertextVectorZeroEncryptionEngine<LweSecretKey32<Kind>, LweCiphertextVector32>
     for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -49,7 +53,7 @@ impl LweCiphertextVectorZeroEncryptionEngine<LweSecretKey32, LweCiphertextVector
     /// ```
     fn zero_encrypt_lwe_ciphertext_vector(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         noise: Variance,
         count: LweCiphertextCount,
     ) -> Result<LweCiphertextVector32, LweCiphertextVectorZeroEncryptionError<Self::EngineError>>
@@ -60,7 +64,7 @@ impl LweCiphertextVectorZeroEncryptionEngine<LweSecretKey32, LweCiphertextVector
 
     unsafe fn zero_encrypt_lwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         noise: Variance,
         count: LweCiphertextCount,
     ) -> LweCiphertextVector32 {
@@ -83,8 +87,10 @@ impl LweCiphertextVectorZeroEncryptionEngine<LweSecretKey32, LweCiphertextVector
 /// # Description:
 /// Implementation of [`LweCiphertextVectorZeroEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 64 bits integers.
-impl LweCiphertextVectorZeroEncryptionEngine<LweSecretKey64, LweCiphertextVector64>
+impl<Kind> LweCiphertextVectorZeroEncryptionEngine<LweSecretKey64<Kind>, LweCiphertextVector64>
     for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -117,7 +123,7 @@ impl LweCiphertextVectorZeroEncryptionEngine<LweSecretKey64, LweCiphertextVector
     /// ```
     fn zero_encrypt_lwe_ciphertext_vector(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         noise: Variance,
         count: LweCiphertextCount,
     ) -> Result<LweCiphertextVector64, LweCiphertextVectorZeroEncryptionError<Self::EngineError>>
@@ -128,7 +134,7 @@ impl LweCiphertextVectorZeroEncryptionEngine<LweSecretKey64, LweCiphertextVector
 
     unsafe fn zero_encrypt_lwe_ciphertext_vector_unchecked(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         noise: Variance,
         count: LweCiphertextCount,
     ) -> LweCiphertextVector64 {
@@ -2,7 +2,8 @@ use concrete_commons::dispersion::Variance;
 
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    LweCiphertext32, LweCiphertext64, LweSecretKey32, LweSecretKey64,
+    LweCiphertext128, LweCiphertext32, LweCiphertext64, LweSecretKey128, LweSecretKey32,
+    LweSecretKey64,
 };
 use crate::commons::crypto::encoding::Plaintext as ImplPlaintext;
 use crate::commons::crypto::lwe::LweCiphertext as ImplLweCiphertext;
@@ -10,11 +11,17 @@ use crate::specification::engines::{
     LweCiphertextZeroEncryptionEngine, LweCiphertextZeroEncryptionError,
 };
 use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`LweCiphertextZeroEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
-impl LweCiphertextZeroEncryptionEngine<LweSecretKey32, LweCiphertext32> for DefaultEngine {
+impl<Kind> LweCiphertextZeroEncryptionEngine<LweSecretKey32<Kind>, LweCiphertext32>
+    for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -43,7 +50,7 @@ impl LweCiphertextZeroEncryptionEngine<LweSecretKey32, LweCiphertext32> for Defa
     /// ```
     fn zero_encrypt_lwe_ciphertext(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         noise: Variance,
     ) -> Result<LweCiphertext32, LweCiphertextZeroEncryptionError<Self::EngineError>> {
         Ok(unsafe { self.zero_encrypt_lwe_ciphertext_unchecked(key, noise) })
@@ -51,7 +58,7 @@ impl LweCiphertextZeroEncryptionEngine<LweSecretKey32, LweCiphertext32> for Defa
 
     unsafe fn zero_encrypt_lwe_ciphertext_unchecked(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         noise: Variance,
     ) -> LweCiphertext32 {
         let mut ciphertext = ImplLweCiphertext::allocate(0u32, key.lwe_dimension().to_lwe_size());
@@ -68,7 +75,11 @@ impl LweCiphertextZeroEncryptionEngine<LweSecretKey32, LweCiphertext32> for Defa
 /// # Description:
 /// Implementation of [`LweCiphertextZeroEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 64 bits integers.
-impl LweCiphertextZeroEncryptionEngine<LweSecretKey64, LweCiphertext64> for DefaultEngine {
+impl<Kind> LweCiphertextZeroEncryptionEngine<LweSecretKey64<Kind>, LweCiphertext64>
+    for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -97,7 +108,7 @@ impl LweCiphertextZeroEncryptionEngine<LweSecretKey64, LweCiphertext64> for Defa
     /// ```
     fn zero_encrypt_lwe_ciphertext(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         noise: Variance,
     ) -> Result<LweCiphertext64, LweCiphertextZeroEncryptionError<Self::EngineError>> {
         Ok(unsafe { self.zero_encrypt_lwe_ciphertext_unchecked(key, noise) })
@@ -105,7 +116,7 @@ impl LweCiphertextZeroEncryptionEngine<LweSecretKey64, LweCiphertext64> for Defa
 
     unsafe fn zero_encrypt_lwe_ciphertext_unchecked(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         noise: Variance,
     ) -> LweCiphertext64 {
         let mut ciphertext = ImplLweCiphertext::allocate(0u64, key.lwe_dimension().to_lwe_size());
@@ -118,3 +129,61 @@ impl LweCiphertextZeroEncryptionEngine<LweSecretKey64, LweCiphertext64> for Defa
         LweCiphertext64(ciphertext)
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextZeroEncryptionEngine`] for [`DefaultEngine`] that
+/// operates on 128 bits integers.
+impl<Kind> LweCiphertextZeroEncryptionEngine<LweSecretKey128<Kind>, LweCiphertext128>
+    for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey128 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let ciphertext = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn zero_encrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKey128<Kind>,
+        noise: Variance,
+    ) -> Result<LweCiphertext128, LweCiphertextZeroEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.zero_encrypt_lwe_ciphertext_unchecked(key, noise) })
+    }
+
+    unsafe fn zero_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey128<Kind>,
+        noise: Variance,
+    ) -> LweCiphertext128 {
+        let mut ciphertext = ImplLweCiphertext::allocate(0u128, key.lwe_dimension().to_lwe_size());
+        key.0.encrypt_lwe(
+            &mut ciphertext,
+            &ImplPlaintext(0u128),
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweCiphertext128(ciphertext)
+    }
+}
@@ -0,0 +1,175 @@
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GswCiphertext32, GswCiphertext64, LweCiphertext32, LweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextsGswCiphertextFusingCmuxEngine, LweCiphertextsGswCiphertextFusingCmuxError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextsGswCiphertextFusingCmuxEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl LweCiphertextsGswCiphertextFusingCmuxEngine<LweCiphertext32, LweCiphertext32, GswCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_0 = 3_u32 << 20;
+    /// let input_1 = 5_u32 << 20;
+    /// let selector = 1_u32;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_0 = engine.create_plaintext_from(&input_0)?;
+    /// let plaintext_1 = engine.create_plaintext_from(&input_1)?;
+    /// let plaintext_selector = engine.create_plaintext_from(&selector)?;
+    /// let mut ciphertext_0 = engine.encrypt_lwe_ciphertext(&key, &plaintext_0, noise)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let gsw = engine.encrypt_scalar_gsw_ciphertext(
+    ///     &key,
+    ///     &plaintext_selector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    ///
+    /// // The selector encrypts 1, so `ciphertext_0` now encrypts `input_1`.
+    /// engine.fuse_cmux_lwe_ciphertexts_gsw_ciphertext(&mut ciphertext_0, &ciphertext_1, &gsw)?;
+    /// #
+    /// assert_eq!(ciphertext_0.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_cmux_lwe_ciphertexts_gsw_ciphertext(
+        &mut self,
+        lwe_output: &mut LweCiphertext32,
+        lwe_input: &LweCiphertext32,
+        gsw_input: &GswCiphertext32,
+    ) -> Result<(), LweCiphertextsGswCiphertextFusingCmuxError<Self::EngineError>> {
+        LweCiphertextsGswCiphertextFusingCmuxError::perform_generic_checks(
+            lwe_output, lwe_input, gsw_input,
+        )?;
+        unsafe {
+            self.fuse_cmux_lwe_ciphertexts_gsw_ciphertext_unchecked(
+                lwe_output, lwe_input, gsw_input,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn fuse_cmux_lwe_ciphertexts_gsw_ciphertext_unchecked(
+        &mut self,
+        lwe_output: &mut LweCiphertext32,
+        lwe_input: &LweCiphertext32,
+        gsw_input: &GswCiphertext32,
+    ) {
+        // The external product accumulates in its output, so computing it on the difference
+        // between the two inputs directly yields ct_0 + CT * (ct_1 - ct_0).
+        let mut difference = lwe_input.0.clone();
+        difference.update_with_sub(&lwe_output.0);
+        gsw_input.0.external_product(&mut lwe_output.0, &difference);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextsGswCiphertextFusingCmuxEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl LweCiphertextsGswCiphertextFusingCmuxEngine<LweCiphertext64, LweCiphertext64, GswCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let level = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(7);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_0 = 3_u64 << 50;
+    /// let input_1 = 5_u64 << 50;
+    /// let selector = 1_u64;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_0 = engine.create_plaintext_from(&input_0)?;
+    /// let plaintext_1 = engine.create_plaintext_from(&input_1)?;
+    /// let plaintext_selector = engine.create_plaintext_from(&selector)?;
+    /// let mut ciphertext_0 = engine.encrypt_lwe_ciphertext(&key, &plaintext_0, noise)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let gsw = engine.encrypt_scalar_gsw_ciphertext(
+    ///     &key,
+    ///     &plaintext_selector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    ///
+    /// // The selector encrypts 1, so `ciphertext_0` now encrypts `input_1`.
+    /// engine.fuse_cmux_lwe_ciphertexts_gsw_ciphertext(&mut ciphertext_0, &ciphertext_1, &gsw)?;
+    /// #
+    /// assert_eq!(ciphertext_0.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_cmux_lwe_ciphertexts_gsw_ciphertext(
+        &mut self,
+        lwe_output: &mut LweCiphertext64,
+        lwe_input: &LweCiphertext64,
+        gsw_input: &GswCiphertext64,
+    ) -> Result<(), LweCiphertextsGswCiphertextFusingCmuxError<Self::EngineError>> {
+        LweCiphertextsGswCiphertextFusingCmuxError::perform_generic_checks(
+            lwe_output, lwe_input, gsw_input,
+        )?;
+        unsafe {
+            self.fuse_cmux_lwe_ciphertexts_gsw_ciphertext_unchecked(
+                lwe_output, lwe_input, gsw_input,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn fuse_cmux_lwe_ciphertexts_gsw_ciphertext_unchecked(
+        &mut self,
+        lwe_output: &mut LweCiphertext64,
+        lwe_input: &LweCiphertext64,
+        gsw_input: &GswCiphertext64,
+    ) {
+        // The external product accumulates in its output, so computing it on the difference
+        // between the two inputs directly yields ct_0 + CT * (ct_1 - ct_0).
+        let mut difference = lwe_input.0.clone();
+        difference.update_with_sub(&lwe_output.0);
+        gsw_input.0.external_product(&mut lwe_output.0, &difference);
+    }
+}
@@ -0,0 +1,164 @@
+use concrete_commons::parameters::PlaintextCount;
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweCompressedCiphertextVector32, LweCompressedCiphertextVector64, LweSecretKey32,
+    LweSecretKey64, PlaintextVector32, PlaintextVector64,
+};
+use crate::commons::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::specification::engines::{
+    LweCompressedCiphertextVectorDecryptionEngine, LweCompressedCiphertextVectorDecryptionError,
+};
+use crate::specification::entities::LweCompressedCiphertextVectorEntity;
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
+
+/// # Description:
+/// Implementation of [`LweCompressedCiphertextVectorDecryptionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl<Kind>
+    LweCompressedCiphertextVectorDecryptionEngine<
+        LweSecretKey32<Kind>,
+        LweCompressedCiphertextVector32,
+        PlaintextVector32,
+    > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweDimension, PlaintextCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 28 bits)
+    /// let input = vec![3_u32 << 28; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let compressed: LweCompressedCiphertextVector32 = engine
+    ///     .switch_modulus_lwe_ciphertext_vector(&ciphertext_vector, CiphertextModulusLog(8))?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_lwe_compressed_ciphertext_vector(&key, &compressed)?;
+    /// #
+    /// assert_eq!(
+    ///     decrypted_plaintext_vector.plaintext_count(),
+    ///     PlaintextCount(3)
+    /// );
+    /// let output = engine.retrieve_plaintext_vector(&decrypted_plaintext_vector)?;
+    /// for value in output {
+    ///     assert_eq!(value.wrapping_add(1 << 27) >> 28, 3);
+    /// }
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_compressed_ciphertext_vector(
+        &mut self,
+        key: &LweSecretKey32<Kind>,
+        input: &LweCompressedCiphertextVector32,
+    ) -> Result<PlaintextVector32, LweCompressedCiphertextVectorDecryptionError<Self::EngineError>>
+    {
+        LweCompressedCiphertextVectorDecryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.decrypt_lwe_compressed_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_compressed_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweSecretKey32<Kind>,
+        input: &LweCompressedCiphertextVector32,
+    ) -> PlaintextVector32 {
+        let mut plaintext =
+            ImplPlaintextList::allocate(0u32, PlaintextCount(input.lwe_ciphertext_count().0));
+        key.0.decrypt_compressed_lwe_list(&mut plaintext, &input.0);
+        PlaintextVector32(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCompressedCiphertextVectorDecryptionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl<Kind>
+    LweCompressedCiphertextVectorDecryptionEngine<
+        LweSecretKey64<Kind>,
+        LweCompressedCiphertextVector64,
+        PlaintextVector64,
+    > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweDimension, PlaintextCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 60 bits)
+    /// let input = vec![3_u64 << 60; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let compressed: LweCompressedCiphertextVector64 = engine
+    ///     .switch_modulus_lwe_ciphertext_vector(&ciphertext_vector, CiphertextModulusLog(8))?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_lwe_compressed_ciphertext_vector(&key, &compressed)?;
+    /// #
+    /// assert_eq!(
+    ///     decrypted_plaintext_vector.plaintext_count(),
+    ///     PlaintextCount(3)
+    /// );
+    /// let output = engine.retrieve_plaintext_vector(&decrypted_plaintext_vector)?;
+    /// for value in output {
+    ///     assert_eq!(value.wrapping_add(1 << 59) >> 60, 3);
+    /// }
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_compressed_ciphertext_vector(
+        &mut self,
+        key: &LweSecretKey64<Kind>,
+        input: &LweCompressedCiphertextVector64,
+    ) -> Result<PlaintextVector64, LweCompressedCiphertextVectorDecryptionError<Self::EngineError>>
+    {
+        LweCompressedCiphertextVectorDecryptionError::perform_generic_checks(key, input)?;
+        Ok(unsafe { self.decrypt_lwe_compressed_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_compressed_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweSecretKey64<Kind>,
+        input: &LweCompressedCiphertextVector64,
+    ) -> PlaintextVector64 {
+        let mut plaintext =
+            ImplPlaintextList::allocate(0u64, PlaintextCount(input.lwe_ciphertext_count().0));
+        key.0.decrypt_compressed_lwe_list(&mut plaintext, &input.0);
+        PlaintextVector64(plaintext)
+    }
+}
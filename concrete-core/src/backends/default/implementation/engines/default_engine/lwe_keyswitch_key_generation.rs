@@ -3,19 +3,29 @@ use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount
 
 use crate::backends::default::implementation::engines::DefaultEngine;
 use crate::backends::default::implementation::entities::{
-    LweKeyswitchKey32, LweKeyswitchKey64, LweSecretKey32, LweSecretKey64,
+    LweKeyswitchKey128, LweKeyswitchKey32, LweKeyswitchKey64, LweSecretKey128, LweSecretKey32,
+    LweSecretKey64,
 };
 use crate::commons::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
 use crate::specification::engines::{
     LweKeyswitchKeyGenerationEngine, LweKeyswitchKeyGenerationError,
 };
 use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`LweKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
-impl LweKeyswitchKeyGenerationEngine<LweSecretKey32, LweSecretKey32, LweKeyswitchKey32>
-    for DefaultEngine
+impl<InputKind, OutputKind>
+    LweKeyswitchKeyGenerationEngine<
+        LweSecretKey32<InputKind>,
+        LweSecretKey32<OutputKind>,
+        LweKeyswitchKey32,
+    > for DefaultEngine
+where
+    InputKind: KeyKind + Debug,
+    OutputKind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -66,8 +76,8 @@ impl LweKeyswitchKeyGenerationEngine<LweSecretKey32, LweSecretKey32, LweKeyswitc
     /// ```
     fn generate_new_lwe_keyswitch_key(
         &mut self,
-        input_key: &LweSecretKey32,
-        output_key: &LweSecretKey32,
+        input_key: &LweSecretKey32<InputKind>,
+        output_key: &LweSecretKey32<OutputKind>,
         decomposition_level_count: DecompositionLevelCount,
         decomposition_base_log: DecompositionBaseLog,
         noise: Variance,
@@ -90,8 +100,8 @@ impl LweKeyswitchKeyGenerationEngine<LweSecretKey32, LweSecretKey32, LweKeyswitc
 
     unsafe fn generate_new_lwe_keyswitch_key_unchecked(
         &mut self,
-        input_key: &LweSecretKey32,
-        output_key: &LweSecretKey32,
+        input_key: &LweSecretKey32<InputKind>,
+        output_key: &LweSecretKey32<OutputKind>,
         decomposition_level_count: DecompositionLevelCount,
         decomposition_base_log: DecompositionBaseLog,
         noise: Variance,
@@ -116,8 +126,15 @@ impl LweKeyswitchKeyGenerationEngine<LweSecretKey32, LweSecretKey32, LweKeyswitc
 /// # Description:
 /// Implementation of [`LweKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that
 /// operates on 64 bits integers.
-impl LweKeyswitchKeyGenerationEngine<LweSecretKey64, LweSecretKey64, LweKeyswitchKey64>
-    for DefaultEngine
+impl<InputKind, OutputKind>
+    LweKeyswitchKeyGenerationEngine<
+        LweSecretKey64<InputKind>,
+        LweSecretKey64<OutputKind>,
+        LweKeyswitchKey64,
+    > for DefaultEngine
+where
+    InputKind: KeyKind + Debug,
+    OutputKind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -168,8 +185,8 @@ impl LweKeyswitchKeyGenerationEngine<LweSecretKey64, LweSecretKey64, LweKeyswitc
     /// ```
     fn generate_new_lwe_keyswitch_key(
         &mut self,
-        input_key: &LweSecretKey64,
-        output_key: &LweSecretKey64,
+        input_key: &LweSecretKey64<InputKind>,
+        output_key: &LweSecretKey64<OutputKind>,
         decomposition_level_count: DecompositionLevelCount,
         decomposition_base_log: DecompositionBaseLog,
         noise: Variance,
@@ -192,8 +209,8 @@ impl LweKeyswitchKeyGenerationEngine<LweSecretKey64, LweSecretKey64, LweKeyswitc
 
     unsafe fn generate_new_lwe_keyswitch_key_unchecked(
         &mut self,
-        input_key: &LweSecretKey64,
-        output_key: &LweSecretKey64,
+        input_key: &LweSecretKey64<InputKind>,
+        output_key: &LweSecretKey64<OutputKind>,
         decomposition_level_count: DecompositionLevelCount,
         decomposition_base_log: DecompositionBaseLog,
         noise: Variance,
@@ -214,3 +231,112 @@ impl LweKeyswitchKeyGenerationEngine<LweSecretKey64, LweSecretKey64, LweKeyswitc
         LweKeyswitchKey64(ksk)
     }
 }
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyGenerationEngine`] for [`DefaultEngine`] that
+/// operates on 128 bits integers.
+impl<InputKind, OutputKind>
+    LweKeyswitchKeyGenerationEngine<
+        LweSecretKey128<InputKind>,
+        LweSecretKey128<OutputKind>,
+        LweKeyswitchKey128,
+    > for DefaultEngine
+where
+    InputKind: KeyKind + Debug,
+    OutputKind: KeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey128 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey128 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey128<InputKind>,
+        output_key: &LweSecretKey128<OutputKind>,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweKeyswitchKey128, LweKeyswitchKeyGenerationError<Self::EngineError>> {
+        LweKeyswitchKeyGenerationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            128,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey128<InputKind>,
+        output_key: &LweSecretKey128<OutputKind>,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweKeyswitchKey128 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweKeyswitchKey128(ksk)
+    }
+}
@@ -7,12 +7,17 @@ use crate::specification::engines::{
     LwePublicKeyGenerationError,
 };
 use concrete_commons::dispersion::Variance;
+use concrete_commons::key_kinds::KeyKind;
 use concrete_commons::parameters::{LweCiphertextCount, LwePublicKeyZeroEncryptionCount};
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`LwePublicKeyGenerationEngine`] for [`DefaultEngine`] that operates on
 /// 32 bits integers.
-impl LwePublicKeyGenerationEngine<LweSecretKey32, LwePublicKey32> for DefaultEngine {
+impl<Kind> LwePublicKeyGenerationEngine<LweSecretKey32<Kind>, LwePublicKey32> for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
     /// # Example:
     /// ```
     /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
@@ -49,7 +54,7 @@ impl LwePublicKeyGenerationEngine<LweSecretKey32, LwePublicKey32> for DefaultEng
     /// ```
     fn generate_new_lwe_public_key(
         &mut self,
-        lwe_secret_key: &LweSecretKey32,
+        lwe_secret_key: &LweSecretKey32<Kind>,
         noise: Variance,
         lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
     ) -> Result<LwePublicKey32, LwePublicKeyGenerationError<Self::EngineError>> {
@@ -65,7 +70,7 @@ impl LwePublicKeyGenerationEngine<LweSecretKey32, LwePublicKey32> for DefaultEng
 
     unsafe fn generate_new_lwe_public_key_unchecked(
         &mut self,
-        lwe_secret_key: &LweSecretKey32,
+        lwe_secret_key: &LweSecretKey32<Kind>,
         noise: Variance,
         lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
     ) -> LwePublicKey32 {
@@ -81,7 +86,10 @@ impl LwePublicKeyGenerationEngine<LweSecretKey32, LwePublicKey32> for DefaultEng
 /// # Description:
 /// Implementation of [`LwePublicKeyGenerationEngine`] for [`DefaultEngine`] that operates on
 /// 64 bits integers.
-impl LwePublicKeyGenerationEngine<LweSecretKey64, LwePublicKey64> for DefaultEngine {
+impl<Kind> LwePublicKeyGenerationEngine<LweSecretKey64<Kind>, LwePublicKey64> for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
     /// # Example:
     /// ```
     /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
@@ -118,7 +126,7 @@ impl LwePublicKeyGenerationEngine<LweSecretKey64, LwePublicKey64> for DefaultEng
     /// ```
     fn generate_new_lwe_public_key(
         &mut self,
-        lwe_secret_key: &LweSecretKey64,
+        lwe_secret_key: &LweSecretKey64<Kind>,
         noise: Variance,
         lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
     ) -> Result<LwePublicKey64, LwePublicKeyGenerationError<Self::EngineError>> {
@@ -134,7 +142,7 @@ impl LwePublicKeyGenerationEngine<LweSecretKey64, LwePublicKey64> for DefaultEng
 
     unsafe fn generate_new_lwe_public_key_unchecked(
         &mut self,
-        lwe_secret_key: &LweSecretKey64,
+        lwe_secret_key: &LweSecretKey64<Kind>,
         noise: Variance,
         lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
     ) -> LwePublicKey64 {
@@ -1,7 +1,9 @@
 use concrete_commons::parameters::LweDimension;
 
 use crate::backends::default::implementation::engines::DefaultEngine;
-use crate::backends::default::implementation::entities::{LweSecretKey32, LweSecretKey64};
+use crate::backends::default::implementation::entities::{
+    LweSecretKey128, LweSecretKey32, LweSecretKey64,
+};
 use crate::commons::crypto::secret::LweSecretKey as ImplLweSecretKey;
 use crate::specification::engines::{LweSecretKeyGenerationEngine, LweSecretKeyGenerationError};
 
@@ -55,6 +57,7 @@ impl LweSecretKeyGenerationEngine<LweSecretKey32> for DefaultEngine {
 impl LweSecretKeyGenerationEngine<LweSecretKey64> for DefaultEngine {
     /// # Example:
     /// ```
+    /// use concrete_commons::dispersion::Variance;
     /// use concrete_commons::parameters::LweDimension;
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
@@ -68,8 +71,17 @@ impl LweSecretKeyGenerationEngine<LweSecretKey64> for DefaultEngine {
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
     /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// // The generated keys are binary, so their type can be inferred from their use.
+    /// let other_lwe_secret_key = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&(3_u64 << 50))?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(
+    ///     &other_lwe_secret_key,
+    ///     &plaintext,
+    ///     Variance(2_f64.powf(-25.)),
+    /// )?;
     /// #
     /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
     /// #
     /// # Ok(())
     /// # }
@@ -92,3 +104,47 @@ impl LweSecretKeyGenerationEngine<LweSecretKey64> for DefaultEngine {
         ))
     }
 }
+
+/// # Description:
+/// Implementation of [`LweSecretKeyGenerationEngine`] for [`DefaultEngine`] that operates on
+/// 128 bits integers.
+impl LweSecretKeyGenerationEngine<LweSecretKey128> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey128 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_secret_key(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<LweSecretKey128, LweSecretKeyGenerationError<Self::EngineError>> {
+        LweSecretKeyGenerationError::perform_generic_checks(lwe_dimension)?;
+        Ok(unsafe { self.generate_new_lwe_secret_key_unchecked(lwe_dimension) })
+    }
+
+    unsafe fn generate_new_lwe_secret_key_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> LweSecretKey128 {
+        LweSecretKey128(ImplLweSecretKey::generate_binary(
+            lwe_dimension,
+            &mut self.secret_generator,
+        ))
+    }
+}
@@ -0,0 +1,169 @@
+use concrete_commons::parameters::LweDimension;
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweSecretKey128, LweSecretKey32, LweSecretKey64,
+};
+use crate::commons::crypto::secret::LweSecretKey as ImplLweSecretKey;
+use crate::commons::crypto::secret::RandomKeyKind;
+use crate::specification::engines::{
+    LweSecretKeyWithKindGenerationEngine, LweSecretKeyWithKindGenerationError,
+};
+use std::fmt::Debug;
+
+/// # Description:
+/// Implementation of [`LweSecretKeyWithKindGenerationEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers.
+impl<Kind> LweSecretKeyWithKindGenerationEngine<Kind, LweSecretKey32<Kind>> for DefaultEngine
+where
+    Kind: RandomKeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey32<TernaryKeyKind> =
+    ///     engine.generate_new_lwe_secret_key_with_kind(lwe_dimension, TernaryKeyKind)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_secret_key_with_kind(
+        &mut self,
+        lwe_dimension: LweDimension,
+        kind: Kind,
+    ) -> Result<LweSecretKey32<Kind>, LweSecretKeyWithKindGenerationError<Self::EngineError>> {
+        LweSecretKeyWithKindGenerationError::perform_generic_checks(lwe_dimension)?;
+        Ok(unsafe { self.generate_new_lwe_secret_key_with_kind_unchecked(lwe_dimension, kind) })
+    }
+
+    unsafe fn generate_new_lwe_secret_key_with_kind_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+        _kind: Kind,
+    ) -> LweSecretKey32<Kind> {
+        LweSecretKey32(ImplLweSecretKey::generate(
+            lwe_dimension,
+            &mut self.secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyWithKindGenerationEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers.
+impl<Kind> LweSecretKeyWithKindGenerationEngine<Kind, LweSecretKey64<Kind>> for DefaultEngine
+where
+    Kind: RandomKeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key =
+    ///     engine.generate_new_lwe_secret_key_with_kind(lwe_dimension, TernaryKeyKind)?;
+    /// // The precision of the key is inferred from its use.
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&lwe_secret_key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_secret_key_with_kind(
+        &mut self,
+        lwe_dimension: LweDimension,
+        kind: Kind,
+    ) -> Result<LweSecretKey64<Kind>, LweSecretKeyWithKindGenerationError<Self::EngineError>> {
+        LweSecretKeyWithKindGenerationError::perform_generic_checks(lwe_dimension)?;
+        Ok(unsafe { self.generate_new_lwe_secret_key_with_kind_unchecked(lwe_dimension, kind) })
+    }
+
+    unsafe fn generate_new_lwe_secret_key_with_kind_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+        _kind: Kind,
+    ) -> LweSecretKey64<Kind> {
+        LweSecretKey64(ImplLweSecretKey::generate(
+            lwe_dimension,
+            &mut self.secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyWithKindGenerationEngine`] for [`DefaultEngine`] that operates
+/// on 128 bits integers.
+impl<Kind> LweSecretKeyWithKindGenerationEngine<Kind, LweSecretKey128<Kind>> for DefaultEngine
+where
+    Kind: RandomKeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey128<TernaryKeyKind> =
+    ///     engine.generate_new_lwe_secret_key_with_kind(lwe_dimension, TernaryKeyKind)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_secret_key_with_kind(
+        &mut self,
+        lwe_dimension: LweDimension,
+        kind: Kind,
+    ) -> Result<LweSecretKey128<Kind>, LweSecretKeyWithKindGenerationError<Self::EngineError>> {
+        LweSecretKeyWithKindGenerationError::perform_generic_checks(lwe_dimension)?;
+        Ok(unsafe { self.generate_new_lwe_secret_key_with_kind_unchecked(lwe_dimension, kind) })
+    }
+
+    unsafe fn generate_new_lwe_secret_key_with_kind_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+        _kind: Kind,
+    ) -> LweSecretKey128<Kind> {
+        LweSecretKey128(ImplLweSecretKey::generate(
+            lwe_dimension,
+            &mut self.secret_generator,
+        ))
+    }
+}
@@ -11,12 +11,17 @@ use crate::specification::engines::{
 };
 use crate::specification::entities::LweSecretKeyEntity;
 use concrete_commons::dispersion::Variance;
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`LweSeededCiphertextEncryptionEngine`] for [`DefaultEngine`] that operates
 /// on 32 bits integers.
-impl LweSeededCiphertextEncryptionEngine<LweSecretKey32, Plaintext32, LweSeededCiphertext32>
+impl<Kind>
+    LweSeededCiphertextEncryptionEngine<LweSecretKey32<Kind>, Plaintext32, LweSeededCiphertext32>
     for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -49,7 +54,7 @@ impl LweSeededCiphertextEncryptionEngine<LweSecretKey32, Plaintext32, LweSeededC
     /// ```
     fn encrypt_lwe_seeded_ciphertext(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         input: &Plaintext32,
         noise: Variance,
     ) -> Result<LweSeededCiphertext32, LweSeededCiphertextEncryptionError<Self::EngineError>> {
@@ -58,7 +63,7 @@ impl LweSeededCiphertextEncryptionEngine<LweSecretKey32, Plaintext32, LweSeededC
 
     unsafe fn encrypt_lwe_seeded_ciphertext_unchecked(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         input: &Plaintext32,
         noise: Variance,
     ) -> LweSeededCiphertext32 {
@@ -83,8 +88,11 @@ impl LweSeededCiphertextEncryptionEngine<LweSecretKey32, Plaintext32, LweSeededC
 /// # Description:
 /// Implementation of [`LweSeededCiphertextEncryptionEngine`] for [`DefaultEngine`] that operates
 /// on 64 bits integers.
-impl LweSeededCiphertextEncryptionEngine<LweSecretKey64, Plaintext64, LweSeededCiphertext64>
+impl<Kind>
+    LweSeededCiphertextEncryptionEngine<LweSecretKey64<Kind>, Plaintext64, LweSeededCiphertext64>
     for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -117,7 +125,7 @@ impl LweSeededCiphertextEncryptionEngine<LweSecretKey64, Plaintext64, LweSeededC
     /// ```
     fn encrypt_lwe_seeded_ciphertext(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         input: &Plaintext64,
         noise: Variance,
     ) -> Result<LweSeededCiphertext64, LweSeededCiphertextEncryptionError<Self::EngineError>> {
@@ -126,7 +134,7 @@ impl LweSeededCiphertextEncryptionEngine<LweSecretKey64, Plaintext64, LweSeededC
 
     unsafe fn encrypt_lwe_seeded_ciphertext_unchecked(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         input: &Plaintext64,
         noise: Variance,
     ) -> LweSeededCiphertext64 {
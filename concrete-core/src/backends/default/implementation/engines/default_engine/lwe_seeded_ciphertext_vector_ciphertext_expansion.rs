@@ -0,0 +1,150 @@
+use super::ActivatedRandomGenerator;
+use crate::backends::default::engines::DefaultEngine;
+use crate::backends::default::entities::{
+    LweCiphertext32, LweCiphertext64, LweSeededCiphertextVector32, LweSeededCiphertextVector64,
+};
+use crate::commons::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::specification::engines::{
+    LweSeededCiphertextVectorCiphertextExpansionEngine,
+    LweSeededCiphertextVectorCiphertextExpansionError,
+};
+use crate::specification::entities::LweSeededCiphertextVectorEntity;
+use concrete_commons::parameters::LweCiphertextIndex;
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextVectorCiphertextExpansionEngine`] for [`DefaultEngine`]
+/// that operates on 32 bits integers.
+impl
+    LweSeededCiphertextVectorCiphertextExpansionEngine<LweSeededCiphertextVector32, LweCiphertext32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![1_u32 << 20, 2_u32 << 20, 3_u32 << 20];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector: LweSeededCiphertextVector32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext: LweCiphertext32 = engine.expand_lwe_seeded_ciphertext_vector_ciphertext(
+    ///     &seeded_ciphertext_vector,
+    ///     LweCiphertextIndex(2),
+    /// )?;
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// let plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    /// let decrypted = engine.retrieve_plaintext(&plaintext)?;
+    /// assert_eq!(decrypted.wrapping_add(1 << 19) >> 20, 3);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_ciphertext_vector_ciphertext(
+        &mut self,
+        vector: &LweSeededCiphertextVector32,
+        i: LweCiphertextIndex,
+    ) -> Result<LweCiphertext32, LweSeededCiphertextVectorCiphertextExpansionError<Self::EngineError>>
+    {
+        LweSeededCiphertextVectorCiphertextExpansionError::perform_generic_checks(vector, i)?;
+        Ok(unsafe { self.expand_lwe_seeded_ciphertext_vector_ciphertext_unchecked(vector, i) })
+    }
+
+    unsafe fn expand_lwe_seeded_ciphertext_vector_ciphertext_unchecked(
+        &mut self,
+        vector: &LweSeededCiphertextVector32,
+        i: LweCiphertextIndex,
+    ) -> LweCiphertext32 {
+        let mut ciphertext =
+            ImplLweCiphertext::allocate(0_u32, vector.lwe_dimension().to_lwe_size());
+        vector
+            .0
+            .expand_ciphertext_into::<_, _, ActivatedRandomGenerator>(i.0, &mut ciphertext);
+        LweCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextVectorCiphertextExpansionEngine`] for [`DefaultEngine`]
+/// that operates on 64 bits integers.
+impl
+    LweSeededCiphertextVectorCiphertextExpansionEngine<LweSeededCiphertextVector64, LweCiphertext64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![1_u64 << 50, 2_u64 << 50, 3_u64 << 50];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector: LweSeededCiphertextVector64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext: LweCiphertext64 = engine.expand_lwe_seeded_ciphertext_vector_ciphertext(
+    ///     &seeded_ciphertext_vector,
+    ///     LweCiphertextIndex(2),
+    /// )?;
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// let plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    /// let decrypted = engine.retrieve_plaintext(&plaintext)?;
+    /// assert_eq!(decrypted.wrapping_add(1 << 49) >> 50, 3);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_ciphertext_vector_ciphertext(
+        &mut self,
+        vector: &LweSeededCiphertextVector64,
+        i: LweCiphertextIndex,
+    ) -> Result<LweCiphertext64, LweSeededCiphertextVectorCiphertextExpansionError<Self::EngineError>>
+    {
+        LweSeededCiphertextVectorCiphertextExpansionError::perform_generic_checks(vector, i)?;
+        Ok(unsafe { self.expand_lwe_seeded_ciphertext_vector_ciphertext_unchecked(vector, i) })
+    }
+
+    unsafe fn expand_lwe_seeded_ciphertext_vector_ciphertext_unchecked(
+        &mut self,
+        vector: &LweSeededCiphertextVector64,
+        i: LweCiphertextIndex,
+    ) -> LweCiphertext64 {
+        let mut ciphertext =
+            ImplLweCiphertext::allocate(0_u64, vector.lwe_dimension().to_lwe_size());
+        vector
+            .0
+            .expand_ciphertext_into::<_, _, ActivatedRandomGenerator>(i.0, &mut ciphertext);
+        LweCiphertext64(ciphertext)
+    }
+}
@@ -13,16 +13,20 @@ use crate::specification::engines::{
     LweSeededCiphertextVectorEncryptionEngine, LweSeededCiphertextVectorEncryptionError,
 };
 use crate::specification::entities::{LweSecretKeyEntity, PlaintextVectorEntity};
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`LweSeededCiphertextVectorEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 32 bits integers.
-impl
+impl<Kind>
     LweSeededCiphertextVectorEncryptionEngine<
-        LweSecretKey32,
+        LweSecretKey32<Kind>,
         PlaintextVector32,
         LweSeededCiphertextVector32,
     > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -60,7 +64,7 @@ impl
     /// ```
     fn encrypt_lwe_seeded_ciphertext_vector(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         input: &PlaintextVector32,
         noise: Variance,
     ) -> Result<
@@ -72,7 +76,7 @@ impl
 
     unsafe fn encrypt_lwe_seeded_ciphertext_vector_unchecked(
         &mut self,
-        key: &LweSecretKey32,
+        key: &LweSecretKey32<Kind>,
         input: &PlaintextVector32,
         noise: Variance,
     ) -> LweSeededCiphertextVector32 {
@@ -97,12 +101,14 @@ impl
 /// # Description:
 /// Implementation of [`LweSeededCiphertextVectorEncryptionEngine`] for [`DefaultEngine`] that
 /// operates on 64 bits integers.
-impl
+impl<Kind>
     LweSeededCiphertextVectorEncryptionEngine<
-        LweSecretKey64,
+        LweSecretKey64<Kind>,
         PlaintextVector64,
         LweSeededCiphertextVector64,
     > for DefaultEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -140,7 +146,7 @@ impl
     /// ```
     fn encrypt_lwe_seeded_ciphertext_vector(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         input: &PlaintextVector64,
         noise: Variance,
     ) -> Result<
@@ -152,7 +158,7 @@ impl
 
     unsafe fn encrypt_lwe_seeded_ciphertext_vector_unchecked(
         &mut self,
-        key: &LweSecretKey64,
+        key: &LweSecretKey64<Kind>,
         input: &PlaintextVector64,
         noise: Variance,
     ) -> LweSeededCiphertextVector64 {
@@ -0,0 +1,172 @@
+use super::ActivatedRandomGenerator;
+use crate::backends::default::engines::DefaultEngine;
+use crate::backends::default::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LweSeededCiphertextVector32,
+    LweSeededCiphertextVector64,
+};
+use crate::commons::crypto::lwe::LweList as ImplLweList;
+use crate::specification::engines::{
+    LweSeededCiphertextVectorRangeExpansionEngine, LweSeededCiphertextVectorRangeExpansionError,
+};
+use crate::specification::entities::LweSeededCiphertextVectorEntity;
+use concrete_commons::parameters::{CiphertextCount, LweCiphertextRange};
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextVectorRangeExpansionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweSeededCiphertextVectorRangeExpansionEngine<
+        LweSeededCiphertextVector32,
+        LweCiphertextVector32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweCiphertextRange, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = (0..10_u32).map(|i| i << 20).collect::<Vec<_>>();
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector: LweSeededCiphertextVector32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext_vector: LweCiphertextVector32 = engine
+    ///     .expand_lwe_seeded_ciphertext_vector_range(
+    ///         &seeded_ciphertext_vector,
+    ///         LweCiphertextRange(4, 7),
+    ///     )?;
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(4));
+    ///
+    /// let plaintext_vector = engine.decrypt_lwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    /// let decrypted = engine.retrieve_plaintext_vector(&plaintext_vector)?;
+    /// for (i, decrypted) in (4..=7).zip(decrypted.iter()) {
+    ///     assert_eq!(decrypted.wrapping_add(1 << 19) >> 20, i);
+    /// }
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_ciphertext_vector_range(
+        &mut self,
+        vector: &LweSeededCiphertextVector32,
+        range: LweCiphertextRange,
+    ) -> Result<
+        LweCiphertextVector32,
+        LweSeededCiphertextVectorRangeExpansionError<Self::EngineError>,
+    > {
+        LweSeededCiphertextVectorRangeExpansionError::perform_generic_checks(vector, range)?;
+        Ok(unsafe { self.expand_lwe_seeded_ciphertext_vector_range_unchecked(vector, range) })
+    }
+
+    unsafe fn expand_lwe_seeded_ciphertext_vector_range_unchecked(
+        &mut self,
+        vector: &LweSeededCiphertextVector32,
+        range: LweCiphertextRange,
+    ) -> LweCiphertextVector32 {
+        let mut ciphertext_vector = ImplLweList::allocate(
+            0_u32,
+            vector.lwe_dimension().to_lwe_size(),
+            CiphertextCount(range.1 - range.0 + 1),
+        );
+        vector
+            .0
+            .expand_range_into::<_, _, ActivatedRandomGenerator>(range.0, &mut ciphertext_vector);
+        LweCiphertextVector32(ciphertext_vector)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextVectorRangeExpansionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweSeededCiphertextVectorRangeExpansionEngine<
+        LweSeededCiphertextVector64,
+        LweCiphertextVector64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweCiphertextRange, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = (0..10_u64).map(|i| i << 50).collect::<Vec<_>>();
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector: LweSeededCiphertextVector64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext_vector: LweCiphertextVector64 = engine
+    ///     .expand_lwe_seeded_ciphertext_vector_range(
+    ///         &seeded_ciphertext_vector,
+    ///         LweCiphertextRange(4, 7),
+    ///     )?;
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(4));
+    ///
+    /// let plaintext_vector = engine.decrypt_lwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    /// let decrypted = engine.retrieve_plaintext_vector(&plaintext_vector)?;
+    /// for (i, decrypted) in (4..=7).zip(decrypted.iter()) {
+    ///     assert_eq!(decrypted.wrapping_add(1 << 49) >> 50, i);
+    /// }
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_ciphertext_vector_range(
+        &mut self,
+        vector: &LweSeededCiphertextVector64,
+        range: LweCiphertextRange,
+    ) -> Result<
+        LweCiphertextVector64,
+        LweSeededCiphertextVectorRangeExpansionError<Self::EngineError>,
+    > {
+        LweSeededCiphertextVectorRangeExpansionError::perform_generic_checks(vector, range)?;
+        Ok(unsafe { self.expand_lwe_seeded_ciphertext_vector_range_unchecked(vector, range) })
+    }
+
+    unsafe fn expand_lwe_seeded_ciphertext_vector_range_unchecked(
+        &mut self,
+        vector: &LweSeededCiphertextVector64,
+        range: LweCiphertextRange,
+    ) -> LweCiphertextVector64 {
+        let mut ciphertext_vector = ImplLweList::allocate(
+            0_u64,
+            vector.lwe_dimension().to_lwe_size(),
+            CiphertextCount(range.1 - range.0 + 1),
+        );
+        vector
+            .0
+            .expand_range_into::<_, _, ActivatedRandomGenerator>(range.0, &mut ciphertext_vector);
+        LweCiphertextVector64(ciphertext_vector)
+    }
+}
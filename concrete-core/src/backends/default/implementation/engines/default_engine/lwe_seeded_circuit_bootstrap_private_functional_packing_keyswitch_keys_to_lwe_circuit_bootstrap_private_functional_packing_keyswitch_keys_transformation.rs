@@ -0,0 +1,208 @@
+use super::ActivatedRandomGenerator;
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+};
+use crate::commons::crypto::glwe::LwePrivateFunctionalPackingKeyswitchKeyList as ImplLwePrivateFunctionalPackingKeyswitchKeyList;
+use crate::specification::engines::{
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationEngine,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationError,
+};
+use crate::specification::entities::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity;
+
+/// # Description:
+/// Implementation of
+/// [`LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationEngine`]
+/// for [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationEngine<
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(10);
+    /// let output_glwe_dimension = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_base_log = DecompositionBaseLog(3);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_cbs_pfpksk: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 =
+    ///     engine.generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///         noise,
+    ///     )?;
+    ///
+    /// let cbs_pfpksk: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 =
+    ///     engine.transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         seeded_cbs_pfpksk,
+    ///     )?;
+    /// #
+    /// assert_eq!(cbs_pfpksk.decomposition_level_count(), decomposition_level_count);
+    /// assert_eq!(cbs_pfpksk.decomposition_base_log(), decomposition_base_log);
+    /// assert_eq!(cbs_pfpksk.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(cbs_pfpksk.output_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(cbs_pfpksk.key_count().0, output_glwe_dimension.to_glwe_size().0);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) -> Result<
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationError<Self::EngineError>,
+    > {
+        Ok(unsafe {
+            self.transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+                lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys,
+            )
+        })
+    }
+
+    unsafe fn transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+        &mut self,
+        lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) -> LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+        let seeded_keys = lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys;
+        let mut fpksk_list = ImplLwePrivateFunctionalPackingKeyswitchKeyList::allocate(
+            0u32,
+            seeded_keys.decomposition_level_count(),
+            seeded_keys.decomposition_base_log(),
+            seeded_keys.input_lwe_dimension(),
+            seeded_keys.output_glwe_dimension(),
+            seeded_keys.output_polynomial_size(),
+            seeded_keys.key_count(),
+        );
+
+        seeded_keys
+            .0
+            .expand_into::<_, _, ActivatedRandomGenerator>(&mut fpksk_list);
+
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32(fpksk_list)
+    }
+}
+
+/// # Description:
+/// Implementation of
+/// [`LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationEngine`]
+/// for [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationEngine<
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(10);
+    /// let output_glwe_dimension = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_base_log = DecompositionBaseLog(3);
+    /// let decomposition_level_count = DecompositionLevelCount(5);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_cbs_pfpksk: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 =
+    ///     engine.generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///         noise,
+    ///     )?;
+    ///
+    /// let cbs_pfpksk: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 =
+    ///     engine.transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         seeded_cbs_pfpksk,
+    ///     )?;
+    /// #
+    /// assert_eq!(cbs_pfpksk.decomposition_level_count(), decomposition_level_count);
+    /// assert_eq!(cbs_pfpksk.decomposition_base_log(), decomposition_base_log);
+    /// assert_eq!(cbs_pfpksk.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(cbs_pfpksk.output_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(cbs_pfpksk.key_count().0, output_glwe_dimension.to_glwe_size().0);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) -> Result<
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationError<Self::EngineError>,
+    > {
+        Ok(unsafe {
+            self.transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+                lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys,
+            )
+        })
+    }
+
+    unsafe fn transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+        &mut self,
+        lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) -> LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+        let seeded_keys = lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys;
+        let mut fpksk_list = ImplLwePrivateFunctionalPackingKeyswitchKeyList::allocate(
+            0u64,
+            seeded_keys.decomposition_level_count(),
+            seeded_keys.decomposition_base_log(),
+            seeded_keys.input_lwe_dimension(),
+            seeded_keys.output_glwe_dimension(),
+            seeded_keys.output_polynomial_size(),
+            seeded_keys.key_count(),
+        );
+
+        seeded_keys
+            .0
+            .expand_into::<_, _, ActivatedRandomGenerator>(&mut fpksk_list);
+
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64(fpksk_list)
+    }
+}
@@ -12,9 +12,18 @@ use crate::specification::engines::{
     LweSeededKeyswitchKeyGenerationEngine, LweSeededKeyswitchKeyGenerationError,
 };
 use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::key_kinds::KeyKind;
+use std::fmt::Debug;
 
-impl LweSeededKeyswitchKeyGenerationEngine<LweSecretKey32, LweSecretKey32, LweSeededKeyswitchKey32>
-    for DefaultEngine
+impl<InputKind, OutputKind>
+    LweSeededKeyswitchKeyGenerationEngine<
+        LweSecretKey32<InputKind>,
+        LweSecretKey32<OutputKind>,
+        LweSeededKeyswitchKey32,
+    > for DefaultEngine
+where
+    InputKind: KeyKind + Debug,
+    OutputKind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -65,8 +74,8 @@ impl LweSeededKeyswitchKeyGenerationEngine<LweSecretKey32, LweSecretKey32, LweSe
     /// ```
     fn generate_new_lwe_seeded_keyswitch_key(
         &mut self,
-        input_key: &LweSecretKey32,
-        output_key: &LweSecretKey32,
+        input_key: &LweSecretKey32<InputKind>,
+        output_key: &LweSecretKey32<OutputKind>,
         decomposition_level_count: DecompositionLevelCount,
         decomposition_base_log: DecompositionBaseLog,
         noise: Variance,
@@ -90,8 +99,8 @@ impl LweSeededKeyswitchKeyGenerationEngine<LweSecretKey32, LweSecretKey32, LweSe
 
     unsafe fn generate_new_lwe_seeded_keyswitch_key_unchecked(
         &mut self,
-        input_key: &LweSecretKey32,
-        output_key: &LweSecretKey32,
+        input_key: &LweSecretKey32<InputKind>,
+        output_key: &LweSecretKey32<OutputKind>,
         decomposition_level_count: DecompositionLevelCount,
         decomposition_base_log: DecompositionBaseLog,
         noise: Variance,
@@ -105,7 +114,7 @@ impl LweSeededKeyswitchKeyGenerationEngine<LweSecretKey32, LweSecretKey32, LweSe
                 seed: self.seeder.seed(),
             },
         );
-        ksk.fill_with_seeded_keyswitch_key::<_, _, _, _, _, ActivatedRandomGenerator>(
+        ksk.fill_with_seeded_keyswitch_key::<_, _, _, _, _, _, _, ActivatedRandomGenerator>(
             &input_key.0,
             &output_key.0,
             noise,
@@ -115,8 +124,15 @@ impl LweSeededKeyswitchKeyGenerationEngine<LweSecretKey32, LweSecretKey32, LweSe
     }
 }
 
-impl LweSeededKeyswitchKeyGenerationEngine<LweSecretKey64, LweSecretKey64, LweSeededKeyswitchKey64>
-    for DefaultEngine
+impl<InputKind, OutputKind>
+    LweSeededKeyswitchKeyGenerationEngine<
+        LweSecretKey64<InputKind>,
+        LweSecretKey64<OutputKind>,
+        LweSeededKeyswitchKey64,
+    > for DefaultEngine
+where
+    InputKind: KeyKind + Debug,
+    OutputKind: KeyKind + Debug,
 {
     /// # Example:
     /// ```
@@ -167,8 +183,8 @@ impl LweSeededKeyswitchKeyGenerationEngine<LweSecretKey64, LweSecretKey64, LweSe
     /// ```
     fn generate_new_lwe_seeded_keyswitch_key(
         &mut self,
-        input_key: &LweSecretKey64,
-        output_key: &LweSecretKey64,
+        input_key: &LweSecretKey64<InputKind>,
+        output_key: &LweSecretKey64<OutputKind>,
         decomposition_level_count: DecompositionLevelCount,
         decomposition_base_log: DecompositionBaseLog,
         noise: Variance,
@@ -192,8 +208,8 @@ impl LweSeededKeyswitchKeyGenerationEngine<LweSecretKey64, LweSecretKey64, LweSe
 
     unsafe fn generate_new_lwe_seeded_keyswitch_key_unchecked(
         &mut self,
-        input_key: &LweSecretKey64,
-        output_key: &LweSecretKey64,
+        input_key: &LweSecretKey64<InputKind>,
+        output_key: &LweSecretKey64<OutputKind>,
         decomposition_level_count: DecompositionLevelCount,
         decomposition_base_log: DecompositionBaseLog,
         noise: Variance,
@@ -207,7 +223,7 @@ impl LweSeededKeyswitchKeyGenerationEngine<LweSecretKey64, LweSecretKey64, LweSe
                 seed: self.seeder.seed(),
             },
         );
-        ksk.fill_with_seeded_keyswitch_key::<_, _, _, _, _, ActivatedRandomGenerator>(
+        ksk.fill_with_seeded_keyswitch_key::<_, _, _, _, _, _, _, ActivatedRandomGenerator>(
             &input_key.0,
             &output_key.0,
             noise,
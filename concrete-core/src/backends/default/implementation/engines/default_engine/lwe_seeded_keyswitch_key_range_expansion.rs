@@ -0,0 +1,185 @@
+use super::ActivatedRandomGenerator;
+use crate::backends::default::engines::DefaultEngine;
+use crate::backends::default::entities::{
+    LweKeyswitchKey32, LweKeyswitchKey64, LweSeededKeyswitchKey32, LweSeededKeyswitchKey64,
+};
+use crate::commons::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
+use crate::specification::engines::{
+    LweSeededKeyswitchKeyRangeExpansionEngine, LweSeededKeyswitchKeyRangeExpansionError,
+};
+use crate::specification::entities::LweSeededKeyswitchKeyEntity;
+use concrete_commons::parameters::{LweDimension, LweKeyswitchKeyRowRange};
+
+/// # Description:
+/// Implementation of [`LweSeededKeyswitchKeyRangeExpansionEngine`] for [`DefaultEngine`] that
+/// operates on 32 bits integers.
+impl LweSeededKeyswitchKeyRangeExpansionEngine<LweSeededKeyswitchKey32, LweKeyswitchKey32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweKeyswitchKeyRowRange,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let keyswitch_key: LweKeyswitchKey32 = engine.expand_lwe_seeded_keyswitch_key_range(
+    ///     &seeded_keyswitch_key,
+    ///     LweKeyswitchKeyRowRange(2, 4),
+    /// )?;
+    ///
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), LweDimension(3));
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_keyswitch_key_range(
+        &mut self,
+        keyswitch_key: &LweSeededKeyswitchKey32,
+        range: LweKeyswitchKeyRowRange,
+    ) -> Result<LweKeyswitchKey32, LweSeededKeyswitchKeyRangeExpansionError<Self::EngineError>>
+    {
+        LweSeededKeyswitchKeyRangeExpansionError::perform_generic_checks(keyswitch_key, range)?;
+        Ok(unsafe { self.expand_lwe_seeded_keyswitch_key_range_unchecked(keyswitch_key, range) })
+    }
+
+    unsafe fn expand_lwe_seeded_keyswitch_key_range_unchecked(
+        &mut self,
+        keyswitch_key: &LweSeededKeyswitchKey32,
+        range: LweKeyswitchKeyRowRange,
+    ) -> LweKeyswitchKey32 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            keyswitch_key.decomposition_level_count(),
+            keyswitch_key.decomposition_base_log(),
+            LweDimension(range.1 - range.0 + 1),
+            keyswitch_key.output_lwe_dimension(),
+        );
+        keyswitch_key
+            .0
+            .expand_rows_into::<_, _, ActivatedRandomGenerator>(range.0, &mut ksk);
+        LweKeyswitchKey32(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededKeyswitchKeyRangeExpansionEngine`] for [`DefaultEngine`] that
+/// operates on 64 bits integers.
+impl LweSeededKeyswitchKeyRangeExpansionEngine<LweSeededKeyswitchKey64, LweKeyswitchKey64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweKeyswitchKeyRowRange,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let keyswitch_key: LweKeyswitchKey64 = engine.expand_lwe_seeded_keyswitch_key_range(
+    ///     &seeded_keyswitch_key,
+    ///     LweKeyswitchKeyRowRange(2, 4),
+    /// )?;
+    ///
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), LweDimension(3));
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_keyswitch_key_range(
+        &mut self,
+        keyswitch_key: &LweSeededKeyswitchKey64,
+        range: LweKeyswitchKeyRowRange,
+    ) -> Result<LweKeyswitchKey64, LweSeededKeyswitchKeyRangeExpansionError<Self::EngineError>>
+    {
+        LweSeededKeyswitchKeyRangeExpansionError::perform_generic_checks(keyswitch_key, range)?;
+        Ok(unsafe { self.expand_lwe_seeded_keyswitch_key_range_unchecked(keyswitch_key, range) })
+    }
+
+    unsafe fn expand_lwe_seeded_keyswitch_key_range_unchecked(
+        &mut self,
+        keyswitch_key: &LweSeededKeyswitchKey64,
+        range: LweKeyswitchKeyRowRange,
+    ) -> LweKeyswitchKey64 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            keyswitch_key.decomposition_level_count(),
+            keyswitch_key.decomposition_base_log(),
+            LweDimension(range.1 - range.0 + 1),
+            keyswitch_key.output_lwe_dimension(),
+        );
+        keyswitch_key
+            .0
+            .expand_rows_into::<_, _, ActivatedRandomGenerator>(range.0, &mut ksk);
+        LweKeyswitchKey64(ksk)
+    }
+}
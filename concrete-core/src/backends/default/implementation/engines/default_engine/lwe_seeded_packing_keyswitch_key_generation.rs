@@ -0,0 +1,247 @@
+use super::ActivatedRandomGenerator;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+use crate::backends::default::implementation::engines::DefaultEngine;
+use crate::backends::default::implementation::entities::{
+    GlweSecretKey32, GlweSecretKey64, LweSecretKey32, LweSecretKey64,
+    LweSeededPackingKeyswitchKey32, LweSeededPackingKeyswitchKey64,
+};
+use crate::commons::crypto::glwe::LweSeededPackingKeyswitchKey as ImplLweSeededPackingKeyswitchKey;
+use crate::commons::math::random::{CompressionSeed, Seeder};
+use crate::specification::engines::{
+    LweSeededPackingKeyswitchKeyGenerationEngine, LweSeededPackingKeyswitchKeyGenerationError,
+};
+use crate::specification::entities::{GlweSecretKeyEntity, LweSecretKeyEntity};
+
+/// # Description:
+/// Implementation of [`LweSeededPackingKeyswitchKeyGenerationEngine`] for [`DefaultEngine`]
+/// that operates on 32 bits integers.
+impl
+    LweSeededPackingKeyswitchKeyGenerationEngine<
+        LweSecretKey32,
+        GlweSecretKey32,
+        LweSeededPackingKeyswitchKey32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_packing_keyswitch_key: LweSeededPackingKeyswitchKey32 = engine
+    ///     .generate_new_lwe_seeded_packing_keyswitch_key(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// #
+    /// assert_eq!(
+    ///     seeded_packing_keyswitch_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(
+    ///     seeded_packing_keyswitch_key.decomposition_base_log(),
+    ///     decomposition_base_log
+    /// );
+    /// assert_eq!(seeded_packing_keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(seeded_packing_keyswitch_key.output_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(seeded_packing_keyswitch_key.output_polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_seeded_packing_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<
+        LweSeededPackingKeyswitchKey32,
+        LweSeededPackingKeyswitchKeyGenerationError<Self::EngineError>,
+    > {
+        LweSeededPackingKeyswitchKeyGenerationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_seeded_packing_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_seeded_packing_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweSeededPackingKeyswitchKey32 {
+        let mut ksk = ImplLweSeededPackingKeyswitchKey::allocate(
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.glwe_dimension(),
+            output_key.polynomial_size(),
+            CompressionSeed {
+                seed: self.seeder.seed(),
+            },
+        );
+        ksk.fill_with_seeded_packing_keyswitch_key::<_, _, _, _, _, ActivatedRandomGenerator>(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.seeder,
+        );
+        LweSeededPackingKeyswitchKey32(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededPackingKeyswitchKeyGenerationEngine`] for [`DefaultEngine`]
+/// that operates on 64 bits integers.
+impl
+    LweSeededPackingKeyswitchKeyGenerationEngine<
+        LweSecretKey64,
+        GlweSecretKey64,
+        LweSeededPackingKeyswitchKey64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_packing_keyswitch_key: LweSeededPackingKeyswitchKey64 = engine
+    ///     .generate_new_lwe_seeded_packing_keyswitch_key(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// #
+    /// assert_eq!(
+    ///     seeded_packing_keyswitch_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(
+    ///     seeded_packing_keyswitch_key.decomposition_base_log(),
+    ///     decomposition_base_log
+    /// );
+    /// assert_eq!(seeded_packing_keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(seeded_packing_keyswitch_key.output_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(seeded_packing_keyswitch_key.output_polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_seeded_packing_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<
+        LweSeededPackingKeyswitchKey64,
+        LweSeededPackingKeyswitchKeyGenerationError<Self::EngineError>,
+    > {
+        LweSeededPackingKeyswitchKeyGenerationError::perform_generic_checks(
+            decomposition_level_count,
+            decomposition_base_log,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_seeded_packing_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_seeded_packing_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweSeededPackingKeyswitchKey64 {
+        let mut ksk = ImplLweSeededPackingKeyswitchKey::allocate(
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.glwe_dimension(),
+            output_key.polynomial_size(),
+            CompressionSeed {
+                seed: self.seeder.seed(),
+            },
+        );
+        ksk.fill_with_seeded_packing_keyswitch_key::<_, _, _, _, _, ActivatedRandomGenerator>(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.seeder,
+        );
+        LweSeededPackingKeyswitchKey64(ksk)
+    }
+}
@@ -0,0 +1,156 @@
+use super::ActivatedRandomGenerator;
+use crate::backends::default::engines::DefaultEngine;
+use crate::backends::default::entities::{
+    LwePublicKey32, LwePublicKey64, LweSeededPublicKey32, LweSeededPublicKey64,
+};
+use crate::commons::crypto::lwe::LweList as ImplLweList;
+use crate::specification::engines::{
+    LweSeededPublicKeyToLwePublicKeyTransformationEngine,
+    LweSeededPublicKeyToLwePublicKeyTransformationError,
+};
+use crate::specification::entities::LweSeededPublicKeyEntity;
+use concrete_commons::parameters::CiphertextCount;
+
+/// # Description:
+/// Implementation of [`LweSeededPublicKeyToLwePublicKeyTransformationEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl LweSeededPublicKeyToLwePublicKeyTransformationEngine<LweSeededPublicKey32, LwePublicKey32>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let seeded_public_key: LweSeededPublicKey32 = engine.generate_new_lwe_seeded_public_key(
+    ///     &lwe_secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    ///
+    /// let public_key: LwePublicKey32 =
+    ///     engine.transform_lwe_seeded_public_key_to_lwe_public_key(seeded_public_key)?;
+    ///
+    /// assert_eq!(public_key.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     public_key.lwe_zero_encryption_count(),
+    ///     lwe_public_key_zero_encryption_count
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transform_lwe_seeded_public_key_to_lwe_public_key(
+        &mut self,
+        lwe_seeded_public_key: LweSeededPublicKey32,
+    ) -> Result<
+        LwePublicKey32,
+        LweSeededPublicKeyToLwePublicKeyTransformationError<Self::EngineError>,
+    > {
+        Ok(unsafe {
+            self.transform_lwe_seeded_public_key_to_lwe_public_key_unchecked(lwe_seeded_public_key)
+        })
+    }
+
+    unsafe fn transform_lwe_seeded_public_key_to_lwe_public_key_unchecked(
+        &mut self,
+        lwe_seeded_public_key: LweSeededPublicKey32,
+    ) -> LwePublicKey32 {
+        let mut output_public_key = ImplLweList::allocate(
+            0_u32,
+            lwe_seeded_public_key.lwe_dimension().to_lwe_size(),
+            CiphertextCount(lwe_seeded_public_key.lwe_zero_encryption_count().0),
+        );
+        lwe_seeded_public_key
+            .0
+            .expand_into::<_, _, ActivatedRandomGenerator>(&mut output_public_key);
+
+        LwePublicKey32(output_public_key)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededPublicKeyToLwePublicKeyTransformationEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl LweSeededPublicKeyToLwePublicKeyTransformationEngine<LweSeededPublicKey64, LwePublicKey64>
+    for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let seeded_public_key: LweSeededPublicKey64 = engine.generate_new_lwe_seeded_public_key(
+    ///     &lwe_secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    ///
+    /// let public_key: LwePublicKey64 =
+    ///     engine.transform_lwe_seeded_public_key_to_lwe_public_key(seeded_public_key)?;
+    ///
+    /// assert_eq!(public_key.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     public_key.lwe_zero_encryption_count(),
+    ///     lwe_public_key_zero_encryption_count
+    /// );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transform_lwe_seeded_public_key_to_lwe_public_key(
+        &mut self,
+        lwe_seeded_public_key: LweSeededPublicKey64,
+    ) -> Result<
+        LwePublicKey64,
+        LweSeededPublicKeyToLwePublicKeyTransformationError<Self::EngineError>,
+    > {
+        Ok(unsafe {
+            self.transform_lwe_seeded_public_key_to_lwe_public_key_unchecked(lwe_seeded_public_key)
+        })
+    }
+
+    unsafe fn transform_lwe_seeded_public_key_to_lwe_public_key_unchecked(
+        &mut self,
+        lwe_seeded_public_key: LweSeededPublicKey64,
+    ) -> LwePublicKey64 {
+        let mut output_public_key = ImplLweList::allocate(
+            0_u64,
+            lwe_seeded_public_key.lwe_dimension().to_lwe_size(),
+            CiphertextCount(lwe_seeded_public_key.lwe_zero_encryption_count().0),
+        );
+        lwe_seeded_public_key
+            .0
+            .expand_into::<_, _, ActivatedRandomGenerator>(&mut output_public_key);
+
+        LwePublicKey64(output_public_key)
+    }
+}
@@ -3,13 +3,18 @@ use crate::backends::default::entities::{
     GlweSecretKey32, GlweSecretKey64, LweSecretKey32, LweSecretKey64,
 };
 use crate::commons::crypto::secret::GlweSecretKey as ImpleGlweSecretKey;
-use crate::commons::math::tensor::IntoTensor;
 use crate::specification::engines::{
     LweToGlweSecretKeyTransformationEngine, LweToGlweSecretKeyTransformationError,
 };
+use concrete_commons::key_kinds::KeyKind;
 use concrete_commons::parameters::PolynomialSize;
+use std::fmt::Debug;
 
-impl LweToGlweSecretKeyTransformationEngine<LweSecretKey32, GlweSecretKey32> for DefaultEngine {
+impl<Kind> LweToGlweSecretKeyTransformationEngine<LweSecretKey32<Kind>, GlweSecretKey32<Kind>>
+    for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
     /// # Example
     ///
     /// ```
@@ -39,9 +44,10 @@ impl LweToGlweSecretKeyTransformationEngine<LweSecretKey32, GlweSecretKey32> for
     /// ```
     fn transform_lwe_secret_key_to_glwe_secret_key(
         &mut self,
-        lwe_secret_key: LweSecretKey32,
+        lwe_secret_key: LweSecretKey32<Kind>,
         polynomial_size: PolynomialSize,
-    ) -> Result<GlweSecretKey32, LweToGlweSecretKeyTransformationError<Self::EngineError>> {
+    ) -> Result<GlweSecretKey32<Kind>, LweToGlweSecretKeyTransformationError<Self::EngineError>>
+    {
         LweToGlweSecretKeyTransformationError::perform_generic_checks(
             &lwe_secret_key,
             polynomial_size,
@@ -56,19 +62,21 @@ impl LweToGlweSecretKeyTransformationEngine<LweSecretKey32, GlweSecretKey32> for
 
     unsafe fn transform_lwe_secret_key_to_glwe_secret_key_unchecked(
         &mut self,
-        lwe_secret_key: LweSecretKey32,
+        lwe_secret_key: LweSecretKey32<Kind>,
         polynomial_size: PolynomialSize,
-    ) -> GlweSecretKey32 {
-        let LweSecretKey32(impl_lwe_key) = lwe_secret_key;
-        let lwe_key_container = impl_lwe_key.into_tensor().into_container();
-        GlweSecretKey32(ImpleGlweSecretKey::binary_from_container(
-            lwe_key_container,
+    ) -> GlweSecretKey32<Kind> {
+        GlweSecretKey32(ImpleGlweSecretKey::from_lwe_secret_key(
+            lwe_secret_key.0,
             polynomial_size,
         ))
     }
 }
 
-impl LweToGlweSecretKeyTransformationEngine<LweSecretKey64, GlweSecretKey64> for DefaultEngine {
+impl<Kind> LweToGlweSecretKeyTransformationEngine<LweSecretKey64<Kind>, GlweSecretKey64<Kind>>
+    for DefaultEngine
+where
+    Kind: KeyKind + Debug,
+{
     /// # Example
     ///
     /// ```
@@ -98,9 +106,10 @@ impl LweToGlweSecretKeyTransformationEngine<LweSecretKey64, GlweSecretKey64> for
     /// ```
     fn transform_lwe_secret_key_to_glwe_secret_key(
         &mut self,
-        lwe_secret_key: LweSecretKey64,
+        lwe_secret_key: LweSecretKey64<Kind>,
         polynomial_size: PolynomialSize,
-    ) -> Result<GlweSecretKey64, LweToGlweSecretKeyTransformationError<Self::EngineError>> {
+    ) -> Result<GlweSecretKey64<Kind>, LweToGlweSecretKeyTransformationError<Self::EngineError>>
+    {
         LweToGlweSecretKeyTransformationError::perform_generic_checks(
             &lwe_secret_key,
             polynomial_size,
@@ -115,13 +124,11 @@ impl LweToGlweSecretKeyTransformationEngine<LweSecretKey64, GlweSecretKey64> for
 
     unsafe fn transform_lwe_secret_key_to_glwe_secret_key_unchecked(
         &mut self,
-        lwe_secret_key: LweSecretKey64,
+        lwe_secret_key: LweSecretKey64<Kind>,
         polynomial_size: PolynomialSize,
-    ) -> GlweSecretKey64 {
-        let LweSecretKey64(impl_lwe_key) = lwe_secret_key;
-        let lwe_key_container = impl_lwe_key.into_tensor().into_container();
-        GlweSecretKey64(ImpleGlweSecretKey::binary_from_container(
-            lwe_key_container,
+    ) -> GlweSecretKey64<Kind> {
+        GlweSecretKey64(ImpleGlweSecretKey::from_lwe_secret_key(
+            lwe_secret_key.0,
             polynomial_size,
         ))
     }
@@ -1,30 +1,41 @@
 use crate::commons::crypto::secret::generators::{
     DeterministicSeeder as ImplDeterministicSeeder,
     EncryptionRandomGenerator as ImplEncryptionRandomGenerator,
+    EncryptionRandomGeneratorState as ImplEncryptionRandomGeneratorState,
     SecretRandomGenerator as ImplSecretRandomGenerator,
 };
+#[cfg(feature = "backend_default_serialization")]
+use crate::commons::math::random::GeneratorStateSerdeDef;
 use crate::specification::engines::sealed::AbstractEngineSeal;
 use crate::specification::engines::AbstractEngine;
 #[cfg(feature = "backend_default_generator_x86_64_aesni")]
 use concrete_csprng::generators::AesniRandomGenerator;
-#[cfg(not(feature = "backend_default_generator_x86_64_aesni"))]
+#[cfg(all(
+    feature = "backend_default_generator_chacha",
+    not(feature = "backend_default_generator_x86_64_aesni")
+))]
+use concrete_csprng::generators::ChaChaRandomGenerator;
+#[cfg(not(any(
+    feature = "backend_default_generator_x86_64_aesni",
+    feature = "backend_default_generator_chacha"
+)))]
 use concrete_csprng::generators::SoftwareRandomGenerator;
-use concrete_csprng::seeders::Seeder;
+use concrete_csprng::generators::{GeneratorState, RandomGenerator};
+use concrete_csprng::seeders::{Seed, Seeder, SeederError};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
 use std::error::Error;
-use std::fmt::{Display, Formatter};
+use std::fmt::{Debug, Display, Formatter};
 
 /// The error which can occur in the execution of FHE operations, due to the default implementation.
-///
-/// # Note:
-///
-/// There is currently no such case, as the default implementation is not expected to undergo some
-/// major issues unrelated to FHE.
 #[derive(Debug)]
 pub enum DefaultError {
     FloatEncoderMessageOutsideInterval,
     FloatEncoderNullPrecision,
     FloatEncoderMinMaxOrder,
     FloatEncoderNullRadius,
+    InvalidGeneratorState,
+    SeederFailure(SeederError),
 }
 
 impl Display for DefaultError {
@@ -45,6 +56,15 @@ impl Display for DefaultError {
             DefaultError::FloatEncoderNullRadius => {
                 write!(f, "Tried to create a float encoder with null radius.")
             }
+            DefaultError::InvalidGeneratorState => {
+                write!(
+                    f,
+                    "Tried to restore an engine from an invalid generator state."
+                )
+            }
+            DefaultError::SeederFailure(error) => {
+                write!(f, "The seeder failed to seed the engine: {}", error)
+            }
         }
     }
 }
@@ -53,7 +73,15 @@ impl Error for DefaultError {}
 
 #[cfg(feature = "backend_default_generator_x86_64_aesni")]
 type ActivatedRandomGenerator = AesniRandomGenerator;
-#[cfg(not(feature = "backend_default_generator_x86_64_aesni"))]
+#[cfg(all(
+    feature = "backend_default_generator_chacha",
+    not(feature = "backend_default_generator_x86_64_aesni")
+))]
+type ActivatedRandomGenerator = ChaChaRandomGenerator;
+#[cfg(not(any(
+    feature = "backend_default_generator_x86_64_aesni",
+    feature = "backend_default_generator_chacha"
+)))]
 type ActivatedRandomGenerator = SoftwareRandomGenerator;
 
 pub struct DefaultEngine {
@@ -78,20 +106,199 @@ impl AbstractEngine for DefaultEngine {
     type Parameters = Box<dyn Seeder>;
 
     fn new(mut parameters: Self::Parameters) -> Result<Self, Self::EngineError> {
+        let seed = parameters.try_seed().map_err(DefaultError::SeederFailure)?;
+        Ok(DefaultEngine::from_seed(seed))
+    }
+}
+
+/// The state of the random generators of a [`DefaultEngine`].
+///
+/// An engine restored from this state with [`DefaultEngine::from_state`] draws the same random
+/// values as the engine the state was taken from, which allows to checkpoint and resume a stream
+/// of key generations and encryptions.
+///
+/// # Note:
+///
+/// The state contains the seeds of the secret generators of the engine, and must be kept as secret
+/// as the keys generated by the engine. For this reason, it is not `Copy`, and its `Debug` output
+/// does not show the seeds.
+#[cfg_attr(
+    feature = "backend_default_serialization",
+    derive(Serialize, Deserialize)
+)]
+#[derive(Clone, PartialEq, Eq)]
+pub struct DefaultEngineState {
+    #[cfg_attr(
+        feature = "backend_default_serialization",
+        serde(with = "GeneratorStateSerdeDef")
+    )]
+    secret_generator: GeneratorState,
+    encryption_generator: ImplEncryptionRandomGeneratorState,
+    #[cfg_attr(
+        feature = "backend_default_serialization",
+        serde(with = "GeneratorStateSerdeDef")
+    )]
+    seeder: GeneratorState,
+}
+
+impl Debug for DefaultEngineState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DefaultEngineState").finish_non_exhaustive()
+    }
+}
+
+impl DefaultEngine {
+    fn from_seed(seed: Seed) -> DefaultEngine {
         let mut deterministic_seeder =
-            ImplDeterministicSeeder::<ActivatedRandomGenerator>::new(parameters.seed());
+            ImplDeterministicSeeder::<ActivatedRandomGenerator>::new(seed);
 
         // Note that the operands are evaluated from left to right for Rust Struct expressions
         // See: https://doc.rust-lang.org/stable/reference/expressions.html?highlight=left#evaluation-order-of-operands
-        // So parameters is moved in seeder after the calls to seed and the potential calls when it
-        // is passed as_mut in ImplEncryptionRandomGenerator::new
-        Ok(DefaultEngine {
+        // So the deterministic seeder is moved in the engine after the calls to seed and the
+        // potential calls when it is passed as_mut in ImplEncryptionRandomGenerator::new
+        DefaultEngine {
             secret_generator: ImplSecretRandomGenerator::new(deterministic_seeder.seed()),
             encryption_generator: ImplEncryptionRandomGenerator::new(
                 deterministic_seeder.seed(),
                 &mut deterministic_seeder,
             ),
             seeder: deterministic_seeder,
+        }
+    }
+
+    /// Creates an engine seeded with a seed derived from a master seed and a label.
+    ///
+    /// Engines derived from the same master seed and label generate the same keys when they
+    /// perform the same sequence of operations, and engines derived from distinct labels generate
+    /// unrelated keys. Deriving one engine per key, with a label identifying the key, allows to
+    /// regenerate every key from the master seed alone, regardless of the order in which they are
+    /// generated. Labels are free-form, and can be organized hierarchically as paths like
+    /// `"bsk/level/3"`.
+    ///
+    /// # Note:
+    ///
+    /// The master seed must be kept as secret as the keys derived from it. The derivation depends
+    /// on the random generator activated in the default backend.
+    ///
+    /// The label is given to the engine rather than to the generation operations, whose traits are
+    /// shared by every backend and do not take a label. To regenerate a key independently of the
+    /// other ones, it must be the only entity generated by its derived engine, or be generated
+    /// after the same sequence of operations.
+    ///
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The master seed must be drawn from a secure source of randomness.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_MASTER_SEED: Seed = Seed(0);
+    ///
+    /// let mut engine = DefaultEngine::new_derived(UNSAFE_MASTER_SEED, "lwe_sk");
+    /// let lwe_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(LweDimension(10))?;
+    /// let mut engine = DefaultEngine::new_derived(UNSAFE_MASTER_SEED, "glwe_sk");
+    /// let glwe_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(GlweDimension(2), PolynomialSize(256))?;
+    ///
+    /// // The keys can be regenerated in any order from the master seed.
+    /// let mut engine = DefaultEngine::new_derived(UNSAFE_MASTER_SEED, "glwe_sk");
+    /// let regenerated_glwe_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(GlweDimension(2), PolynomialSize(256))?;
+    /// assert_eq!(glwe_key, regenerated_glwe_key);
+    /// let mut engine = DefaultEngine::new_derived(UNSAFE_MASTER_SEED, "lwe_sk");
+    /// let regenerated_lwe_key: LweSecretKey64 =
+    ///     engine.generate_new_lwe_secret_key(LweDimension(10))?;
+    /// assert_eq!(lwe_key, regenerated_lwe_key);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_derived(master_seed: Seed, label: &str) -> DefaultEngine {
+        DefaultEngine::from_seed(ActivatedRandomGenerator::derive_seed(
+            master_seed,
+            label.as_bytes(),
+        ))
+    }
+
+    /// Returns the current state of the random generators of the engine.
+    ///
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let _: LweSecretKey64 = engine.generate_new_lwe_secret_key(LweDimension(10))?;
+    ///
+    /// let state = engine.get_state();
+    /// // The seeds of the state are not shown in its debug output.
+    /// assert_eq!(format!("{:?}", state), "DefaultEngineState { .. }");
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(LweDimension(10))?;
+    ///
+    /// let mut restored_engine = DefaultEngine::from_state(state)?;
+    /// let restored_key: LweSecretKey64 =
+    ///     restored_engine.generate_new_lwe_secret_key(LweDimension(10))?;
+    /// assert_eq!(key, restored_key);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_state(&self) -> DefaultEngineState {
+        DefaultEngineState {
+            secret_generator: self.secret_generator.get_state(),
+            encryption_generator: self.encryption_generator.get_state(),
+            seeder: self.seeder.get_state(),
+        }
+    }
+
+    /// Restores an engine from a state returned by [`DefaultEngine::get_state`].
+    ///
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(LweDimension(2))?;
+    /// let plaintext = engine.create_plaintext_from(&(3_u32 << 20))?;
+    ///
+    /// let state = engine.get_state();
+    /// let ciphertext: LweCiphertext32 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut restored_engine = DefaultEngine::from_state(state)?;
+    /// let restored_ciphertext: LweCiphertext32 =
+    ///     restored_engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// assert_eq!(ciphertext, restored_ciphertext);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_state(state: DefaultEngineState) -> Result<Self, DefaultError> {
+        Ok(DefaultEngine {
+            secret_generator: ImplSecretRandomGenerator::try_from_state(state.secret_generator)
+                .map_err(|_| DefaultError::InvalidGeneratorState)?,
+            encryption_generator: ImplEncryptionRandomGenerator::try_from_state(
+                state.encryption_generator,
+            )
+            .map_err(|_| DefaultError::InvalidGeneratorState)?,
+            seeder: ImplDeterministicSeeder::try_from_state(state.seeder)
+                .map_err(|_| DefaultError::InvalidGeneratorState)?,
         })
     }
 }
@@ -109,13 +316,33 @@ mod encoder_vector_creation;
 mod ggsw_ciphertext_scalar_discarding_encryption;
 mod ggsw_ciphertext_scalar_encryption;
 mod ggsw_ciphertext_scalar_trivial_encryption;
+mod ggsw_ciphertext_seeded_scalar_encryption;
+mod ggsw_seeded_ciphertext_to_ggsw_ciphertext_transformation;
+mod glwe_automorphism_key_generation;
+mod glwe_ciphertext_cleartext_vector_discarding_multiplication;
+mod glwe_ciphertext_cleartext_vector_fusing_multiplication;
 mod glwe_ciphertext_consuming_retrieval;
 mod glwe_ciphertext_creation;
 mod glwe_ciphertext_decryption;
+mod glwe_ciphertext_discarding_addition;
+mod glwe_ciphertext_discarding_automorphism;
 mod glwe_ciphertext_discarding_decryption;
 mod glwe_ciphertext_discarding_encryption;
+mod glwe_ciphertext_discarding_keyswitch;
+mod glwe_ciphertext_discarding_multiplication;
+mod glwe_ciphertext_discarding_opposite;
+mod glwe_ciphertext_discarding_public_key_encryption;
+mod glwe_ciphertext_discarding_relinearization;
+mod glwe_ciphertext_discarding_subtraction;
+mod glwe_ciphertext_discarding_trace;
 mod glwe_ciphertext_discarding_trivial_encryption;
 mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_fusing_addition;
+mod glwe_ciphertext_fusing_opposite;
+mod glwe_ciphertext_fusing_subtraction;
+mod glwe_ciphertext_plaintext_vector_discarding_addition;
+mod glwe_ciphertext_plaintext_vector_fusing_addition;
+mod glwe_ciphertext_tensor_product;
 mod glwe_ciphertext_trivial_decryption;
 mod glwe_ciphertext_trivial_encryption;
 mod glwe_ciphertext_vector_decryption;
@@ -126,12 +353,19 @@ mod glwe_ciphertext_vector_trivial_decryption;
 mod glwe_ciphertext_vector_trivial_encryption;
 mod glwe_ciphertext_vector_zero_encryption;
 mod glwe_ciphertext_zero_encryption;
+mod glwe_keyswitch_key_generation;
+mod glwe_public_key_generation;
+mod glwe_relinearization_key_generation;
 mod glwe_secret_key_generation;
+mod glwe_secret_key_with_kind_generation;
 mod glwe_seeded_ciphertext_encryption;
 mod glwe_seeded_ciphertext_to_glwe_ciphertext_transformation;
 mod glwe_seeded_ciphertext_vector_encryption;
+mod glwe_seeded_keyswitch_key_generation;
+mod glwe_seeded_keyswitch_key_to_glwe_keyswitch_key_transformation;
 mod glwe_seeded_vector_to_glwe_ciphertext_vector_transformation;
 mod glwe_to_lwe_secret_key_transformation;
+mod gsw_ciphertext_scalar_encryption;
 mod lwe_bootstrap_key_consuming_retrieval;
 mod lwe_bootstrap_key_creation;
 mod lwe_bootstrap_key_discarding_conversion;
@@ -145,6 +379,7 @@ mod lwe_ciphertext_discarding_addition;
 mod lwe_ciphertext_discarding_decryption;
 mod lwe_ciphertext_discarding_encryption;
 mod lwe_ciphertext_discarding_extraction;
+mod lwe_ciphertext_discarding_glwe_public_key_encryption;
 mod lwe_ciphertext_discarding_keyswitch;
 mod lwe_ciphertext_discarding_opposite;
 mod lwe_ciphertext_discarding_public_key_encryption;
@@ -153,6 +388,8 @@ mod lwe_ciphertext_encryption;
 mod lwe_ciphertext_fusing_addition;
 mod lwe_ciphertext_fusing_opposite;
 mod lwe_ciphertext_fusing_subtraction;
+mod lwe_ciphertext_gsw_ciphertext_discarding_external_product;
+mod lwe_ciphertext_modulus_switching;
 mod lwe_ciphertext_plaintext_discarding_addition;
 mod lwe_ciphertext_plaintext_discarding_subtraction;
 mod lwe_ciphertext_plaintext_fusing_addition;
@@ -170,25 +407,43 @@ mod lwe_ciphertext_vector_discarding_subtraction;
 mod lwe_ciphertext_vector_encryption;
 mod lwe_ciphertext_vector_fusing_addition;
 mod lwe_ciphertext_vector_fusing_subtraction;
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_automorphism_packing;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_packing_keyswitch;
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_sample_extraction;
+mod lwe_ciphertext_vector_glwe_ciphertext_vector_discarding_sample_extraction;
+mod lwe_ciphertext_vector_modulus_switching;
 mod lwe_ciphertext_vector_trivial_decryption;
 mod lwe_ciphertext_vector_trivial_encryption;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_ciphertext_zero_encryption;
+mod lwe_ciphertexts_gsw_ciphertext_fusing_cmux;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
+mod lwe_compressed_ciphertext_vector_decryption;
 mod lwe_keyswitch_key_generation;
 mod lwe_packing_keyswitch_key_generation;
 mod lwe_private_functional_packing_keyswitch_key_generation;
 mod lwe_public_key_generation;
 mod lwe_secret_key_generation;
+mod lwe_secret_key_with_kind_generation;
 mod lwe_seeded_bootstrap_key_generation;
 mod lwe_seeded_bootstrap_key_to_lwe_bootstrap_key_transformation;
 mod lwe_seeded_ciphertext_encryption;
+mod lwe_seeded_ciphertext_vector_ciphertext_expansion;
 mod lwe_seeded_ciphertext_vector_encryption;
+mod lwe_seeded_ciphertext_vector_range_expansion;
 mod lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_transformation;
+mod lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
+mod lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_transformation;
 mod lwe_seeded_keyswitch_key_generation;
+mod lwe_seeded_keyswitch_key_range_expansion;
 mod lwe_seeded_keyswitch_key_to_lwe_keyswitch_key_transformation;
+mod lwe_seeded_packing_keyswitch_key_generation;
+mod lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_transformation;
+mod lwe_seeded_private_functional_packing_keyswitch_key_generation;
+mod lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key_transformation;
+mod lwe_seeded_public_key_generation;
+mod lwe_seeded_public_key_to_lwe_public_key_transformation;
 mod lwe_seeded_to_lwe_ciphertext_transformation;
 mod lwe_to_glwe_secret_key_transformation;
 mod plaintext_creation;
@@ -1,5 +1,5 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
-use crate::backends::default::implementation::entities::{Plaintext32, Plaintext64};
+use crate::backends::default::implementation::entities::{Plaintext128, Plaintext32, Plaintext64};
 use crate::commons::crypto::encoding::Plaintext as ImplPlaintext;
 use crate::specification::engines::{PlaintextCreationEngine, PlaintextCreationError};
 
@@ -70,3 +70,37 @@ impl PlaintextCreationEngine<u64, Plaintext64> for DefaultEngine {
         Plaintext64(ImplPlaintext(*input))
     }
 }
+
+/// # Description:
+/// Implementation of [`PlaintextCreationEngine`] for [`DefaultEngine`] that operates on
+/// 128 bits integers.
+impl PlaintextCreationEngine<u128, Plaintext128> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 110 bits)
+    /// let input = 3_u128 << 110;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext: Plaintext128 = engine.create_plaintext_from(&input)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_plaintext_from(
+        &mut self,
+        input: &u128,
+    ) -> Result<Plaintext128, PlaintextCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_plaintext_from_unchecked(input) })
+    }
+
+    unsafe fn create_plaintext_from_unchecked(&mut self, input: &u128) -> Plaintext128 {
+        Plaintext128(ImplPlaintext(*input))
+    }
+}
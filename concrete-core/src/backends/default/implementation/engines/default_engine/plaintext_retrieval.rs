@@ -1,5 +1,5 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
-use crate::backends::default::implementation::entities::{Plaintext32, Plaintext64};
+use crate::backends::default::implementation::entities::{Plaintext128, Plaintext32, Plaintext64};
 use crate::specification::engines::{PlaintextRetrievalEngine, PlaintextRetrievalError};
 
 /// # Description:
@@ -75,3 +75,40 @@ impl PlaintextRetrievalEngine<Plaintext64, u64> for DefaultEngine {
         plaintext.0 .0
     }
 }
+
+/// # Description:
+/// Implementation of [`PlaintextRetrievalEngine`] for [`DefaultEngine`] that operates on 128 bits
+/// integers.
+impl PlaintextRetrievalEngine<Plaintext128, u128> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u128 << 20;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext: Plaintext128 = engine.create_plaintext_from(&input)?;
+    /// let output: u128 = engine.retrieve_plaintext(&plaintext)?;
+    ///
+    /// assert_eq!(output, 3_u128 << 20);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn retrieve_plaintext(
+        &mut self,
+        plaintext: &Plaintext128,
+    ) -> Result<u128, PlaintextRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_plaintext_unchecked(plaintext) })
+    }
+
+    unsafe fn retrieve_plaintext_unchecked(&mut self, plaintext: &Plaintext128) -> u128 {
+        plaintext.0 .0
+    }
+}
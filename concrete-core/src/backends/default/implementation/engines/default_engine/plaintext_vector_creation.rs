@@ -1,5 +1,7 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
-use crate::backends::default::implementation::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::default::implementation::entities::{
+    PlaintextVector128, PlaintextVector32, PlaintextVector64,
+};
 use crate::commons::crypto::encoding::PlaintextList as ImplPlaintextList;
 use crate::specification::engines::{PlaintextVectorCreationEngine, PlaintextVectorCreationError};
 
@@ -88,3 +90,46 @@ impl PlaintextVectorCreationEngine<u64, PlaintextVector64> for DefaultEngine {
         PlaintextVector64(ImplPlaintextList::from_container(input.to_vec()))
     }
 }
+
+/// # Description:
+/// Implementation of [`PlaintextVectorCreationEngine`] for [`DefaultEngine`] that operates on
+/// 128 bits integers.
+impl PlaintextVectorCreationEngine<u128, PlaintextVector128> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 110 bits)
+    /// let input = vec![3_u128 << 110; 3];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVector128 = engine.create_plaintext_vector_from(&input)?;
+    /// #
+    /// assert_eq!(plaintext_vector.plaintext_count(), PlaintextCount(3));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_plaintext_vector_from(
+        &mut self,
+        input: &[u128],
+    ) -> Result<PlaintextVector128, PlaintextVectorCreationError<Self::EngineError>> {
+        if input.is_empty() {
+            return Err(PlaintextVectorCreationError::EmptyInput);
+        }
+        Ok(unsafe { self.create_plaintext_vector_from_unchecked(input) })
+    }
+
+    unsafe fn create_plaintext_vector_from_unchecked(
+        &mut self,
+        input: &[u128],
+    ) -> PlaintextVector128 {
+        PlaintextVector128(ImplPlaintextList::from_container(input.to_vec()))
+    }
+}
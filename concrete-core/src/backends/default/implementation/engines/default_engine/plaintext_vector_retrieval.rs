@@ -1,5 +1,7 @@
 use crate::backends::default::implementation::engines::DefaultEngine;
-use crate::backends::default::implementation::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::default::implementation::entities::{
+    PlaintextVector128, PlaintextVector32, PlaintextVector64,
+};
 use crate::commons::math::tensor::AsRefTensor;
 use crate::specification::engines::{
     PlaintextVectorRetrievalEngine, PlaintextVectorRetrievalError,
@@ -86,3 +88,44 @@ impl PlaintextVectorRetrievalEngine<PlaintextVector64, u64> for DefaultEngine {
         plaintext.0.as_tensor().as_container().to_vec()
     }
 }
+
+/// # Description:
+/// Implementation of [`PlaintextVectorRetrievalEngine`] for [`DefaultEngine`] that operates on 128
+/// bits integers.
+impl PlaintextVectorRetrievalEngine<PlaintextVector128, u128> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 110 bits)
+    /// let input = vec![3_u128 << 110; 3];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let plaintext_vector: PlaintextVector128 = engine.create_plaintext_vector_from(&input)?;
+    /// let output: Vec<u128> = engine.retrieve_plaintext_vector(&plaintext_vector)?;
+    /// #
+    /// assert_eq!(output[0], 3_u128 << 110);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn retrieve_plaintext_vector(
+        &mut self,
+        plaintext: &PlaintextVector128,
+    ) -> Result<Vec<u128>, PlaintextVectorRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.retrieve_plaintext_vector_unchecked(plaintext) })
+    }
+
+    unsafe fn retrieve_plaintext_vector_unchecked(
+        &mut self,
+        plaintext: &PlaintextVector128,
+    ) -> Vec<u128> {
+        plaintext.0.as_tensor().as_container().to_vec()
+    }
+}
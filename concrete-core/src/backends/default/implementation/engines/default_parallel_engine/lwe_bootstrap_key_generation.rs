@@ -1,6 +1,6 @@
 use crate::backends::default::implementation::entities::{
     GlweSecretKey32, GlweSecretKey64, LweBootstrapKey32, LweBootstrapKey64, LweSecretKey32,
-    LweSecretKey64,
+    LweSecretKey64, LweTernaryBootstrapKey32, LweTernaryBootstrapKey64,
 };
 use crate::commons::crypto::bootstrap::StandardBootstrapKey as ImplStandardBootstrapKey;
 use crate::prelude::{DefaultParallelEngine, GlweSecretKeyEntity, LweSecretKeyEntity};
@@ -8,13 +8,18 @@ use crate::specification::engines::{
     LweBootstrapKeyGenerationEngine, LweBootstrapKeyGenerationError,
 };
 use concrete_commons::dispersion::Variance;
+use concrete_commons::key_kinds::{KeyKind, TernaryKeyKind};
 use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`LweBootstrapKeyGenerationEngine`] for [`DefaultParallelEngine`] that
 /// operates on 32 bits integers. It outputs a bootstrap key in the standard domain.
-impl LweBootstrapKeyGenerationEngine<LweSecretKey32, GlweSecretKey32, LweBootstrapKey32>
+/// The input LWE secret key is binary, while the output GLWE secret key can be of any kind.
+impl<Kind> LweBootstrapKeyGenerationEngine<LweSecretKey32, GlweSecretKey32<Kind>, LweBootstrapKey32>
     for DefaultParallelEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example
     /// ```
@@ -57,7 +62,7 @@ impl LweBootstrapKeyGenerationEngine<LweSecretKey32, GlweSecretKey32, LweBootstr
     fn generate_new_lwe_bootstrap_key(
         &mut self,
         input_key: &LweSecretKey32,
-        output_key: &GlweSecretKey32,
+        output_key: &GlweSecretKey32<Kind>,
         decomposition_base_log: DecompositionBaseLog,
         decomposition_level_count: DecompositionLevelCount,
         noise: Variance,
@@ -81,7 +86,7 @@ impl LweBootstrapKeyGenerationEngine<LweSecretKey32, GlweSecretKey32, LweBootstr
     unsafe fn generate_new_lwe_bootstrap_key_unchecked(
         &mut self,
         input_key: &LweSecretKey32,
-        output_key: &GlweSecretKey32,
+        output_key: &GlweSecretKey32<Kind>,
         decomposition_base_log: DecompositionBaseLog,
         decomposition_level_count: DecompositionLevelCount,
         noise: Variance,
@@ -107,8 +112,11 @@ impl LweBootstrapKeyGenerationEngine<LweSecretKey32, GlweSecretKey32, LweBootstr
 /// # Description:
 /// Implementation of [`LweBootstrapKeyGenerationEngine`] for [`DefaultParallelEngine`] that
 /// operates on 64 bits integers. It outputs a bootstrap key in the standard domain.
-impl LweBootstrapKeyGenerationEngine<LweSecretKey64, GlweSecretKey64, LweBootstrapKey64>
+/// The input LWE secret key is binary, while the output GLWE secret key can be of any kind.
+impl<Kind> LweBootstrapKeyGenerationEngine<LweSecretKey64, GlweSecretKey64<Kind>, LweBootstrapKey64>
     for DefaultParallelEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example
     /// ```
@@ -151,7 +159,7 @@ impl LweBootstrapKeyGenerationEngine<LweSecretKey64, GlweSecretKey64, LweBootstr
     fn generate_new_lwe_bootstrap_key(
         &mut self,
         input_key: &LweSecretKey64,
-        output_key: &GlweSecretKey64,
+        output_key: &GlweSecretKey64<Kind>,
         decomposition_base_log: DecompositionBaseLog,
         decomposition_level_count: DecompositionLevelCount,
         noise: Variance,
@@ -175,7 +183,7 @@ impl LweBootstrapKeyGenerationEngine<LweSecretKey64, GlweSecretKey64, LweBootstr
     unsafe fn generate_new_lwe_bootstrap_key_unchecked(
         &mut self,
         input_key: &LweSecretKey64,
-        output_key: &GlweSecretKey64,
+        output_key: &GlweSecretKey64<Kind>,
         decomposition_base_log: DecompositionBaseLog,
         decomposition_level_count: DecompositionLevelCount,
         noise: Variance,
@@ -197,3 +205,209 @@ impl LweBootstrapKeyGenerationEngine<LweSecretKey64, GlweSecretKey64, LweBootstr
         LweBootstrapKey64(key)
     }
 }
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyGenerationEngine`] for [`DefaultParallelEngine`] that operates on
+/// 32 bits integers, with a ternary input LWE secret key. It outputs a bootstrap key in the
+/// standard domain, which encrypts the binary expansion of the input key.
+impl<Kind>
+    LweBootstrapKeyGenerationEngine<
+        LweSecretKey32<TernaryKeyKind>,
+        GlweSecretKey32<Kind>,
+        LweTernaryBootstrapKey32,
+    > for DefaultParallelEngine
+where
+    Kind: KeyKind + Debug,
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut default_parallel_engine =
+    ///     DefaultParallelEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32<TernaryKeyKind> =
+    ///     default_engine.generate_new_lwe_secret_key_with_kind(lwe_dim, TernaryKeyKind)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweTernaryBootstrapKey32 = default_parallel_engine
+    ///     .generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_bootstrap_key(
+        &mut self,
+        input_key: &LweSecretKey32<TernaryKeyKind>,
+        output_key: &GlweSecretKey32<Kind>,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<LweTernaryBootstrapKey32, LweBootstrapKeyGenerationError<Self::EngineError>> {
+        LweBootstrapKeyGenerationError::perform_generic_checks(
+            decomposition_base_log,
+            decomposition_level_count,
+            32,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey32<TernaryKeyKind>,
+        output_key: &GlweSecretKey32<Kind>,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> LweTernaryBootstrapKey32 {
+        let expanded_input_key = input_key.0.binary_expansion();
+        let mut key = ImplStandardBootstrapKey::allocate(
+            0,
+            output_key.glwe_dimension().to_glwe_size(),
+            output_key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            expanded_input_key.key_size(),
+        );
+        key.par_fill_with_new_key(
+            &expanded_input_key,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweTernaryBootstrapKey32(key)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyGenerationEngine`] for [`DefaultParallelEngine`] that operates on
+/// 64 bits integers, with a ternary input LWE secret key. It outputs a bootstrap key in the
+/// standard domain, which encrypts the binary expansion of the input key.
+impl<Kind>
+    LweBootstrapKeyGenerationEngine<
+        LweSecretKey64<TernaryKeyKind>,
+        GlweSecretKey64<Kind>,
+        LweTernaryBootstrapKey64,
+    > for DefaultParallelEngine
+where
+    Kind: KeyKind + Debug,
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut default_parallel_engine =
+    ///     DefaultParallelEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64<TernaryKeyKind> =
+    ///     default_engine.generate_new_lwe_secret_key_with_kind(lwe_dim, TernaryKeyKind)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweTernaryBootstrapKey64 = default_parallel_engine
+    ///     .generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_bootstrap_key(
+        &mut self,
+        input_key: &LweSecretKey64<TernaryKeyKind>,
+        output_key: &GlweSecretKey64<Kind>,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<LweTernaryBootstrapKey64, LweBootstrapKeyGenerationError<Self::EngineError>> {
+        LweBootstrapKeyGenerationError::perform_generic_checks(
+            decomposition_base_log,
+            decomposition_level_count,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey64<TernaryKeyKind>,
+        output_key: &GlweSecretKey64<Kind>,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> LweTernaryBootstrapKey64 {
+        let expanded_input_key = input_key.0.binary_expansion();
+        let mut key = ImplStandardBootstrapKey::allocate(
+            0,
+            output_key.glwe_dimension().to_glwe_size(),
+            output_key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            expanded_input_key.key_size(),
+        );
+        key.par_fill_with_new_key(
+            &expanded_input_key,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweTernaryBootstrapKey64(key)
+    }
+}
@@ -0,0 +1,219 @@
+#[allow(deprecated)]
+use concrete_commons::parameters::{MonomialDegree, MonomialIndex};
+
+use crate::backends::default::implementation::entities::{
+    GlweCiphertextVector32, GlweCiphertextVector64, LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::prelude::DefaultParallelEngine;
+use crate::specification::engines::{
+    LweCiphertextVectorGlweCiphertextVectorDiscardingSampleExtractionEngine,
+    LweCiphertextVectorGlweCiphertextVectorDiscardingSampleExtractionError,
+};
+
+/// # Description:
+/// Implementation of
+/// [`LweCiphertextVectorGlweCiphertextVectorDiscardingSampleExtractionEngine`] for
+/// [`DefaultParallelEngine`] that operates on 32 bits integers.
+///
+/// The extractions are computed in parallel.
+impl
+    LweCiphertextVectorGlweCiphertextVectorDiscardingSampleExtractionEngine<
+        GlweCiphertextVector32,
+        LweCiphertextVector32,
+    > for DefaultParallelEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweCiphertextCount, GlweDimension, LweCiphertextCount, MonomialIndex, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let glwe_count = GlweCiphertextCount(3);
+    /// // Here a hard-set encoding is applied (shift by 28 bits)
+    /// let input = vec![3_u32 << 28; polynomial_size.0 * glwe_count.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // We extract every coefficient of every GLWE ciphertext of the vector
+    /// let indices: Vec<MonomialIndex> = (0..polynomial_size.0).map(MonomialIndex).collect();
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut default_parallel_engine =
+    ///     DefaultParallelEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let glwe_ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&glwe_key, &plaintext_vector, noise)?;
+    /// // The extracted LWE ciphertexts are encrypted under the GLWE key seen as an LWE key
+    /// let lwe_key: LweSecretKey32 = engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key)?;
+    /// let mut lwe_ciphertext_vector = engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_key,
+    ///     noise,
+    ///     LweCiphertextCount(glwe_count.0 * indices.len()),
+    /// )?;
+    ///
+    /// default_parallel_engine.discard_sample_extract_glwe_ciphertext_vector(
+    ///     &mut lwe_ciphertext_vector,
+    ///     &glwe_ciphertext_vector,
+    ///     &indices,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     lwe_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(glwe_count.0 * indices.len())
+    /// );
+    /// let decrypted = engine.decrypt_lwe_ciphertext_vector(&lwe_key, &lwe_ciphertext_vector)?;
+    /// let output = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// for value in output {
+    ///     assert_eq!(value.wrapping_add(1 << 27) >> 28, 3);
+    /// }
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_sample_extract_glwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &GlweCiphertextVector32,
+        indices: &[MonomialIndex],
+    ) -> Result<
+        (),
+        LweCiphertextVectorGlweCiphertextVectorDiscardingSampleExtractionError<Self::EngineError>,
+    > {
+        LweCiphertextVectorGlweCiphertextVectorDiscardingSampleExtractionError
+        ::perform_generic_checks(
+            output, input, indices,
+        )?;
+        unsafe {
+            self.discard_sample_extract_glwe_ciphertext_vector_unchecked(output, input, indices)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_sample_extract_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &GlweCiphertextVector32,
+        indices: &[MonomialIndex],
+    ) {
+        #[allow(deprecated)]
+        let degrees: Vec<MonomialDegree> = indices.iter().map(|i| MonomialDegree(i.0)).collect();
+        output
+            .0
+            .par_fill_with_glwe_list_sample_extractions(&input.0, &degrees);
+    }
+}
+
+/// # Description:
+/// Implementation of
+/// [`LweCiphertextVectorGlweCiphertextVectorDiscardingSampleExtractionEngine`] for
+/// [`DefaultParallelEngine`] that operates on 64 bits integers.
+///
+/// The extractions are computed in parallel.
+impl
+    LweCiphertextVectorGlweCiphertextVectorDiscardingSampleExtractionEngine<
+        GlweCiphertextVector64,
+        LweCiphertextVector64,
+    > for DefaultParallelEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweCiphertextCount, GlweDimension, LweCiphertextCount, MonomialIndex, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let glwe_count = GlweCiphertextCount(3);
+    /// // Here a hard-set encoding is applied (shift by 60 bits)
+    /// let input = vec![3_u64 << 60; polynomial_size.0 * glwe_count.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // We extract every coefficient of every GLWE ciphertext of the vector
+    /// let indices: Vec<MonomialIndex> = (0..polynomial_size.0).map(MonomialIndex).collect();
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut default_parallel_engine =
+    ///     DefaultParallelEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let glwe_ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&glwe_key, &plaintext_vector, noise)?;
+    /// // The extracted LWE ciphertexts are encrypted under the GLWE key seen as an LWE key
+    /// let lwe_key: LweSecretKey64 = engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_key)?;
+    /// let mut lwe_ciphertext_vector = engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_key,
+    ///     noise,
+    ///     LweCiphertextCount(glwe_count.0 * indices.len()),
+    /// )?;
+    ///
+    /// default_parallel_engine.discard_sample_extract_glwe_ciphertext_vector(
+    ///     &mut lwe_ciphertext_vector,
+    ///     &glwe_ciphertext_vector,
+    ///     &indices,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     lwe_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(glwe_count.0 * indices.len())
+    /// );
+    /// let decrypted = engine.decrypt_lwe_ciphertext_vector(&lwe_key, &lwe_ciphertext_vector)?;
+    /// let output = engine.retrieve_plaintext_vector(&decrypted)?;
+    /// for value in output {
+    ///     assert_eq!(value.wrapping_add(1 << 59) >> 60, 3);
+    /// }
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_sample_extract_glwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &GlweCiphertextVector64,
+        indices: &[MonomialIndex],
+    ) -> Result<
+        (),
+        LweCiphertextVectorGlweCiphertextVectorDiscardingSampleExtractionError<Self::EngineError>,
+    > {
+        LweCiphertextVectorGlweCiphertextVectorDiscardingSampleExtractionError
+        ::perform_generic_checks(
+            output, input, indices,
+        )?;
+        unsafe {
+            self.discard_sample_extract_glwe_ciphertext_vector_unchecked(output, input, indices)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_sample_extract_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &GlweCiphertextVector64,
+        indices: &[MonomialIndex],
+    ) {
+        #[allow(deprecated)]
+        let degrees: Vec<MonomialDegree> = indices.iter().map(|i| MonomialDegree(i.0)).collect();
+        output
+            .0
+            .par_fill_with_glwe_list_sample_extractions(&input.0, &degrees);
+    }
+}
@@ -11,13 +11,23 @@ use crate::specification::engines::{
     LweSeededBootstrapKeyGenerationEngine, LweSeededBootstrapKeyGenerationError,
 };
 use concrete_commons::dispersion::Variance;
+use concrete_commons::key_kinds::KeyKind;
 use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`LweSeededBootstrapKeyGenerationEngine`] for [`DefaultParallelEngine`] that
 /// operates on 32 bits integers. It outputs a seeded bootstrap key in the standard domain.
-impl LweSeededBootstrapKeyGenerationEngine<LweSecretKey32, GlweSecretKey32, LweSeededBootstrapKey32>
-    for DefaultParallelEngine
+/// The input LWE secret key must be binary, while the output GLWE secret key can be of any
+/// kind.
+impl<Kind>
+    LweSeededBootstrapKeyGenerationEngine<
+        LweSecretKey32,
+        GlweSecretKey32<Kind>,
+        LweSeededBootstrapKey32,
+    > for DefaultParallelEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example
     /// ```
@@ -60,7 +70,7 @@ impl LweSeededBootstrapKeyGenerationEngine<LweSecretKey32, GlweSecretKey32, LweS
     fn generate_new_lwe_seeded_bootstrap_key(
         &mut self,
         input_key: &LweSecretKey32,
-        output_key: &GlweSecretKey32,
+        output_key: &GlweSecretKey32<Kind>,
         decomposition_base_log: DecompositionBaseLog,
         decomposition_level_count: DecompositionLevelCount,
         noise: Variance,
@@ -85,7 +95,7 @@ impl LweSeededBootstrapKeyGenerationEngine<LweSecretKey32, GlweSecretKey32, LweS
     unsafe fn generate_new_lwe_seeded_bootstrap_key_unchecked(
         &mut self,
         input_key: &LweSecretKey32,
-        output_key: &GlweSecretKey32,
+        output_key: &GlweSecretKey32<Kind>,
         decomposition_base_log: DecompositionBaseLog,
         decomposition_level_count: DecompositionLevelCount,
         noise: Variance,
@@ -100,7 +110,7 @@ impl LweSeededBootstrapKeyGenerationEngine<LweSecretKey32, GlweSecretKey32, LweS
                 seed: self.seeder.seed(),
             },
         );
-        key.par_fill_with_new_key::<_, _, _, _, _, ActivatedRandomGenerator>(
+        key.par_fill_with_new_key::<_, _, _, _, _, _, ActivatedRandomGenerator>(
             &input_key.0,
             &output_key.0,
             noise,
@@ -113,8 +123,16 @@ impl LweSeededBootstrapKeyGenerationEngine<LweSecretKey32, GlweSecretKey32, LweS
 /// # Description:
 /// Implementation of [`LweSeededBootstrapKeyGenerationEngine`] for [`DefaultParallelEngine`] that
 /// operates on 64 bits integers. It outputs a seeded bootstrap key in the standard domain.
-impl LweSeededBootstrapKeyGenerationEngine<LweSecretKey64, GlweSecretKey64, LweSeededBootstrapKey64>
-    for DefaultParallelEngine
+/// The input LWE secret key must be binary, while the output GLWE secret key can be of any
+/// kind.
+impl<Kind>
+    LweSeededBootstrapKeyGenerationEngine<
+        LweSecretKey64,
+        GlweSecretKey64<Kind>,
+        LweSeededBootstrapKey64,
+    > for DefaultParallelEngine
+where
+    Kind: KeyKind + Debug,
 {
     /// # Example
     /// ```
@@ -157,7 +175,7 @@ impl LweSeededBootstrapKeyGenerationEngine<LweSecretKey64, GlweSecretKey64, LweS
     fn generate_new_lwe_seeded_bootstrap_key(
         &mut self,
         input_key: &LweSecretKey64,
-        output_key: &GlweSecretKey64,
+        output_key: &GlweSecretKey64<Kind>,
         decomposition_base_log: DecompositionBaseLog,
         decomposition_level_count: DecompositionLevelCount,
         noise: Variance,
@@ -182,7 +200,7 @@ impl LweSeededBootstrapKeyGenerationEngine<LweSecretKey64, GlweSecretKey64, LweS
     unsafe fn generate_new_lwe_seeded_bootstrap_key_unchecked(
         &mut self,
         input_key: &LweSecretKey64,
-        output_key: &GlweSecretKey64,
+        output_key: &GlweSecretKey64<Kind>,
         decomposition_base_log: DecompositionBaseLog,
         decomposition_level_count: DecompositionLevelCount,
         noise: Variance,
@@ -197,7 +215,7 @@ impl LweSeededBootstrapKeyGenerationEngine<LweSecretKey64, GlweSecretKey64, LweS
                 seed: self.seeder.seed(),
             },
         );
-        key.par_fill_with_new_key::<_, _, _, _, _, ActivatedRandomGenerator>(
+        key.par_fill_with_new_key::<_, _, _, _, _, _, ActivatedRandomGenerator>(
             &input_key.0,
             &output_key.0,
             noise,
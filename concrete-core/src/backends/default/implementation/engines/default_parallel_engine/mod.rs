@@ -6,25 +6,35 @@ use crate::specification::engines::sealed::AbstractEngineSeal;
 use crate::specification::engines::AbstractEngine;
 #[cfg(feature = "backend_default_generator_x86_64_aesni")]
 use concrete_csprng::generators::AesniRandomGenerator;
-#[cfg(not(feature = "backend_default_generator_x86_64_aesni"))]
+#[cfg(all(
+    feature = "backend_default_generator_chacha",
+    not(feature = "backend_default_generator_x86_64_aesni")
+))]
+use concrete_csprng::generators::ChaChaRandomGenerator;
+use concrete_csprng::generators::RandomGenerator;
+#[cfg(not(any(
+    feature = "backend_default_generator_x86_64_aesni",
+    feature = "backend_default_generator_chacha"
+)))]
 use concrete_csprng::generators::SoftwareRandomGenerator;
-use concrete_csprng::seeders::Seeder;
+use concrete_csprng::seeders::{Seed, Seeder, SeederError};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
 /// The error which can occur in the execution of FHE operations, due to the default
 /// parallel implementation.
-///
-/// # Note:
-///
-/// There is currently no such case, as the default parallel implementation is not expected to
-/// undergo major issues unrelated to FHE.
 #[derive(Debug)]
-pub enum DefaultParallelError {}
+pub enum DefaultParallelError {
+    SeederFailure(SeederError),
+}
 
 impl Display for DefaultParallelError {
-    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
-        match *self {}
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            DefaultParallelError::SeederFailure(error) => {
+                write!(f, "The seeder failed to seed the engine: {}", error)
+            }
+        }
     }
 }
 
@@ -32,7 +42,15 @@ impl Error for DefaultParallelError {}
 
 #[cfg(feature = "backend_default_generator_x86_64_aesni")]
 type ActivatedRandomGenerator = AesniRandomGenerator;
-#[cfg(not(feature = "backend_default_generator_x86_64_aesni"))]
+#[cfg(all(
+    feature = "backend_default_generator_chacha",
+    not(feature = "backend_default_generator_x86_64_aesni")
+))]
+type ActivatedRandomGenerator = ChaChaRandomGenerator;
+#[cfg(not(any(
+    feature = "backend_default_generator_x86_64_aesni",
+    feature = "backend_default_generator_chacha"
+)))]
 type ActivatedRandomGenerator = SoftwareRandomGenerator;
 
 pub struct DefaultParallelEngine {
@@ -56,18 +74,85 @@ impl AbstractEngine for DefaultParallelEngine {
     type Parameters = Box<dyn Seeder>;
 
     fn new(mut parameters: Self::Parameters) -> Result<Self, Self::EngineError> {
+        let seed = parameters
+            .try_seed()
+            .map_err(DefaultParallelError::SeederFailure)?;
+        Ok(DefaultParallelEngine::from_seed(seed))
+    }
+}
+
+impl DefaultParallelEngine {
+    fn from_seed(seed: Seed) -> DefaultParallelEngine {
         let mut deterministic_seeder =
-            ImplDeterministicSeeder::<ActivatedRandomGenerator>::new(parameters.seed());
+            ImplDeterministicSeeder::<ActivatedRandomGenerator>::new(seed);
 
-        Ok(DefaultParallelEngine {
+        DefaultParallelEngine {
             encryption_generator: ImplEncryptionRandomGenerator::new(
                 deterministic_seeder.seed(),
                 &mut deterministic_seeder,
             ),
             seeder: deterministic_seeder,
-        })
+        }
+    }
+
+    /// Creates an engine seeded with a seed derived from a master seed and a label.
+    ///
+    /// This is the parallel counterpart of
+    /// [`DefaultEngine::new_derived`](super::DefaultEngine::new_derived), and derives the same
+    /// seed from the same master seed and label.
+    ///
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim) = (LweDimension(4), GlweDimension(6));
+    /// let poly_size = PolynomialSize(256);
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // The master seed must be drawn from a secure source of randomness.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_MASTER_SEED: Seed = Seed(0);
+    ///
+    /// let mut default_engine = DefaultEngine::new_derived(UNSAFE_MASTER_SEED, "lwe_sk");
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let mut default_engine = DefaultEngine::new_derived(UNSAFE_MASTER_SEED, "glwe_sk");
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let mut engine = DefaultParallelEngine::new_derived(UNSAFE_MASTER_SEED, "bsk");
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut engine = DefaultParallelEngine::new_derived(UNSAFE_MASTER_SEED, "bsk");
+    /// let regenerated_bsk: LweBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// assert_eq!(bsk, regenerated_bsk);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_derived(master_seed: Seed, label: &str) -> DefaultParallelEngine {
+        DefaultParallelEngine::from_seed(ActivatedRandomGenerator::derive_seed(
+            master_seed,
+            label.as_bytes(),
+        ))
     }
 }
 
+mod ggsw_ciphertext_seeded_scalar_encryption;
 mod lwe_bootstrap_key_generation;
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_sample_extraction;
+mod lwe_ciphertext_vector_glwe_ciphertext_vector_discarding_sample_extraction;
 mod lwe_seeded_bootstrap_key_generation;
+mod lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
+mod lwe_seeded_packing_keyswitch_key_generation;
+mod lwe_seeded_private_functional_packing_keyswitch_key_generation;
@@ -13,17 +13,24 @@ use crate::commons::crypto::ggsw::{
     StandardGgswSeededCiphertext as ImplStandardGgswSeededCiphertext,
 };
 use crate::commons::crypto::glwe::{
-    GlweCiphertext as ImplGlweCiphertext, GlweList as ImplGlweList,
-    GlweSeededCiphertext as ImplGlweSeededCiphertext, GlweSeededList as ImplGlweSeededList,
+    GlweAutomorphismKey as ImplGlweAutomorphismKey, GlweCiphertext as ImplGlweCiphertext,
+    GlweKeyswitchKey as ImplGlweKeyswitchKey, GlweList as ImplGlweList,
+    GlwePublicKey as ImplGlwePublicKey, GlweRelinearizationKey as ImplGlweRelinearizationKey,
+    GlweSeededCiphertext as ImplGlweSeededCiphertext,
+    GlweSeededKeyswitchKey as ImplGlweSeededKeyswitchKey, GlweSeededList as ImplGlweSeededList,
     LwePackingKeyswitchKey as ImplLwePackingKeyswitchKey,
+    LweSeededPackingKeyswitchKey as ImplLweSeededPackingKeyswitchKey,
+    LweSeededPrivateFunctionalPackingKeyswitchKey as ImplLweSeededPrivateFunctionalPackingKeyswitchKey,
+    LweSeededPrivateFunctionalPackingKeyswitchKeyList as ImplLweSeededPrivateFunctionalPackingKeyswitchKeyList,
 };
 use crate::commons::crypto::lwe::{
-    LweCiphertext as ImplLweCiphertext, LweKeyswitchKey as ImplLweKeyswitchKey,
-    LweList as ImplLweList, LweSeededCiphertext as ImplLweSeededCiphertext,
+    LweCiphertext as ImplLweCiphertext, LweCompressedList as ImplLweCompressedList,
+    LweKeyswitchKey as ImplLweKeyswitchKey, LweList as ImplLweList,
+    LweSeededCiphertext as ImplLweSeededCiphertext,
     LweSeededKeyswitchKey as ImplLweSeededKeyswitchKey, LweSeededList as ImplLweSeededList,
 };
 use crate::commons::crypto::secret::{
-    GlweSecretKey as ImplGlweSecretKey, LweSecretKey as ImplLweSecretKey,
+    GlweSecretKey as ImplGlweSecretKey, KeyKindTag, LweSecretKey as ImplLweSecretKey, RandomKeyKind,
 };
 use crate::prelude::{
     Cleartext32, Cleartext32Version, Cleartext64, Cleartext64Version, CleartextF64,
@@ -33,17 +40,25 @@ use crate::prelude::{
     EntityDeserializationError, FloatEncoder, FloatEncoderVector, FloatEncoderVectorVersion,
     FloatEncoderVersion, GgswCiphertext32, GgswCiphertext32Version, GgswCiphertext64,
     GgswCiphertext64Version, GgswSeededCiphertext32, GgswSeededCiphertext32Version,
-    GgswSeededCiphertext64, GgswSeededCiphertext64Version, GlweCiphertext32,
-    GlweCiphertext32Version, GlweCiphertext64, GlweCiphertext64Version, GlweCiphertextVector32,
-    GlweCiphertextVector32Version, GlweCiphertextVector64, GlweCiphertextVector64Version,
+    GgswSeededCiphertext64, GgswSeededCiphertext64Version, GlweAutomorphismKey32,
+    GlweAutomorphismKey32Version, GlweAutomorphismKey64, GlweAutomorphismKey64Version,
+    GlweCiphertext32, GlweCiphertext32Version, GlweCiphertext64, GlweCiphertext64Version,
+    GlweCiphertextVector32, GlweCiphertextVector32Version, GlweCiphertextVector64,
+    GlweCiphertextVector64Version, GlweKeyswitchKey32, GlweKeyswitchKey32Version,
+    GlweKeyswitchKey64, GlweKeyswitchKey64Version, GlwePublicKey32, GlwePublicKey32Version,
+    GlwePublicKey64, GlwePublicKey64Version, GlweRelinearizationKey32,
+    GlweRelinearizationKey32Version, GlweRelinearizationKey64, GlweRelinearizationKey64Version,
     GlweSecretKey32, GlweSecretKey32Version, GlweSecretKey64, GlweSecretKey64Version,
     GlweSeededCiphertext32, GlweSeededCiphertext32Version, GlweSeededCiphertext64,
     GlweSeededCiphertext64Version, GlweSeededCiphertextVector32,
     GlweSeededCiphertextVector32Version, GlweSeededCiphertextVector64,
-    GlweSeededCiphertextVector64Version, LweBootstrapKey32, LweBootstrapKey32Version,
-    LweBootstrapKey64, LweBootstrapKey64Version, LweCiphertext32, LweCiphertext32Version,
-    LweCiphertext64, LweCiphertext64Version, LweCiphertextVector32, LweCiphertextVector32Version,
-    LweCiphertextVector64, LweCiphertextVector64Version, LweKeyswitchKey32,
+    GlweSeededCiphertextVector64Version, GlweSeededKeyswitchKey32, GlweSeededKeyswitchKey32Version,
+    GlweSeededKeyswitchKey64, GlweSeededKeyswitchKey64Version, LweBootstrapKey32,
+    LweBootstrapKey32Version, LweBootstrapKey64, LweBootstrapKey64Version, LweCiphertext32,
+    LweCiphertext32Version, LweCiphertext64, LweCiphertext64Version, LweCiphertextVector32,
+    LweCiphertextVector32Version, LweCiphertextVector64, LweCiphertextVector64Version,
+    LweCompressedCiphertextVector32, LweCompressedCiphertextVector32Version,
+    LweCompressedCiphertextVector64, LweCompressedCiphertextVector64Version, LweKeyswitchKey32,
     LweKeyswitchKey32Version, LweKeyswitchKey64, LweKeyswitchKey64Version,
     LwePackingKeyswitchKey32, LwePackingKeyswitchKey32Version, LwePackingKeyswitchKey64,
     LwePackingKeyswitchKey64Version, LweSecretKey32, LweSecretKey32Version, LweSecretKey64,
@@ -51,13 +66,26 @@ use crate::prelude::{
     LweSeededBootstrapKey64, LweSeededBootstrapKey64Version, LweSeededCiphertext32,
     LweSeededCiphertext32Version, LweSeededCiphertext64, LweSeededCiphertext64Version,
     LweSeededCiphertextVector32, LweSeededCiphertextVector32Version, LweSeededCiphertextVector64,
-    LweSeededCiphertextVector64Version, LweSeededKeyswitchKey32, LweSeededKeyswitchKey32Version,
-    LweSeededKeyswitchKey64, LweSeededKeyswitchKey64Version, Plaintext32, Plaintext32Version,
-    Plaintext64, Plaintext64Version, PlaintextVector32, PlaintextVector32Version,
-    PlaintextVector64, PlaintextVector64Version,
+    LweSeededCiphertextVector64Version,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version,
+    LweSeededKeyswitchKey32, LweSeededKeyswitchKey32Version, LweSeededKeyswitchKey64,
+    LweSeededKeyswitchKey64Version, LweSeededPackingKeyswitchKey32,
+    LweSeededPackingKeyswitchKey32Version, LweSeededPackingKeyswitchKey64,
+    LweSeededPackingKeyswitchKey64Version, LweSeededPrivateFunctionalPackingKeyswitchKey32,
+    LweSeededPrivateFunctionalPackingKeyswitchKey32Version,
+    LweSeededPrivateFunctionalPackingKeyswitchKey64,
+    LweSeededPrivateFunctionalPackingKeyswitchKey64Version, LweSeededPublicKey32,
+    LweSeededPublicKey32Version, LweSeededPublicKey64, LweSeededPublicKey64Version,
+    LweTernaryBootstrapKey32, LweTernaryBootstrapKey32Version, LweTernaryBootstrapKey64,
+    LweTernaryBootstrapKey64Version, Plaintext32, Plaintext32Version, Plaintext64,
+    Plaintext64Version, PlaintextVector32, PlaintextVector32Version, PlaintextVector64,
+    PlaintextVector64Version,
 };
-use concrete_commons::key_kinds::BinaryKeyKind;
 use serde::Deserialize;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
@@ -900,30 +928,46 @@ impl EntityDeserializationEngine<&[u8], GlweCiphertextVector64> for DefaultSeria
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a GLWE secret key entity.
-impl EntityDeserializationEngine<&[u8], GlweSecretKey32> for DefaultSerializationEngine {
+/// operates on 32 bits integers. It deserializes a GLWE automorphism key entity.
+impl EntityDeserializationEngine<&[u8], GlweAutomorphismKey32> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
-    /// let polynomial_size = PolynomialSize(4);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let exponents = [AutomorphismExponent(3), AutomorphismExponent(5)];
+    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let glwe_secret_key: GlweSecretKey32 =
+    /// let key: GlweSecretKey32 =
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     ///
+    /// let automorphism_key: GlweAutomorphismKey32 = engine.generate_new_glwe_automorphism_key(
+    ///     &key,
+    ///     &exponents,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
+    /// let serialized = serialization_engine.serialize(&automorphism_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(glwe_secret_key, recovered);
+    /// assert_eq!(automorphism_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -932,60 +976,76 @@ impl EntityDeserializationEngine<&[u8], GlweSecretKey32> for DefaultSerializatio
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<GlweSecretKey32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<GlweAutomorphismKey32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableGlweSecretKey32 {
-            version: GlweSecretKey32Version,
-            inner: ImplGlweSecretKey<BinaryKeyKind, Vec<u32>>,
+        struct DeserializableGlweAutomorphismKey32 {
+            version: GlweAutomorphismKey32Version,
+            inner: ImplGlweAutomorphismKey<Vec<u32>>,
         }
-        let deserialized: DeserializableGlweSecretKey32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableGlweAutomorphismKey32 = bincode::deserialize(serialized)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableGlweSecretKey32 {
-                version: GlweSecretKey32Version::Unsupported,
+            DeserializableGlweAutomorphismKey32 {
+                version: GlweAutomorphismKey32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableGlweSecretKey32 {
-                version: GlweSecretKey32Version::V0,
+            DeserializableGlweAutomorphismKey32 {
+                version: GlweAutomorphismKey32Version::V0,
                 inner,
-            } => Ok(GlweSecretKey32(inner)),
+            } => Ok(GlweAutomorphismKey32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSecretKey32 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweAutomorphismKey32 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a GLWE secret key entity.
-impl EntityDeserializationEngine<&[u8], GlweSecretKey64> for DefaultSerializationEngine {
+/// operates on 64 bits integers. It deserializes a GLWE automorphism key entity.
+impl EntityDeserializationEngine<&[u8], GlweAutomorphismKey64> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
-    /// let polynomial_size = PolynomialSize(4);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let exponents = [AutomorphismExponent(3), AutomorphismExponent(5)];
+    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let glwe_secret_key: GlweSecretKey64 =
+    /// let key: GlweSecretKey64 =
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     ///
+    /// let automorphism_key: GlweAutomorphismKey64 = engine.generate_new_glwe_automorphism_key(
+    ///     &key,
+    ///     &exponents,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
+    /// let serialized = serialization_engine.serialize(&automorphism_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(glwe_secret_key, recovered);
+    /// assert_eq!(automorphism_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -994,69 +1054,77 @@ impl EntityDeserializationEngine<&[u8], GlweSecretKey64> for DefaultSerializatio
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<GlweSecretKey64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<GlweAutomorphismKey64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableGlweSecretKey64 {
-            version: GlweSecretKey64Version,
-            inner: ImplGlweSecretKey<BinaryKeyKind, Vec<u64>>,
+        struct DeserializableGlweAutomorphismKey64 {
+            version: GlweAutomorphismKey64Version,
+            inner: ImplGlweAutomorphismKey<Vec<u64>>,
         }
-        let deserialized: DeserializableGlweSecretKey64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableGlweAutomorphismKey64 = bincode::deserialize(serialized)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableGlweSecretKey64 {
-                version: GlweSecretKey64Version::Unsupported,
+            DeserializableGlweAutomorphismKey64 {
+                version: GlweAutomorphismKey64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableGlweSecretKey64 {
-                version: GlweSecretKey64Version::V0,
+            DeserializableGlweAutomorphismKey64 {
+                version: GlweAutomorphismKey64Version::V0,
                 inner,
-            } => Ok(GlweSecretKey64(inner)),
+            } => Ok(GlweAutomorphismKey64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSecretKey64 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweAutomorphismKey64 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a GLWE seeded ciphertext entity.
-impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext32> for DefaultSerializationEngine {
+/// operates on 32 bits integers. It deserializes a GLWE keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], GlweKeyswitchKey32> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let glwe_dimension = GlweDimension(2);
-    /// let polynomial_size = PolynomialSize(4);
-    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_glwe_dimension = GlweDimension(3);
+    /// let output_glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: GlweSecretKey32 =
-    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
-    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let input_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, polynomial_size)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
     ///
-    /// let seeded_ciphertext =
-    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let keyswitch_key: GlweKeyswitchKey32 = engine.generate_new_glwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_ciphertext)?;
+    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_ciphertext, recovered);
+    /// assert_eq!(keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1065,69 +1133,77 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext32> for DefaultSeria
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<GlweSeededCiphertext32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<GlweKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableGlweSeededCiphertext32 {
-            version: GlweSeededCiphertext32Version,
-            inner: ImplGlweSeededCiphertext<Vec<u32>>,
+        struct DeserializableGlweKeyswitchKey32 {
+            version: GlweKeyswitchKey32Version,
+            inner: ImplGlweKeyswitchKey<Vec<u32>>,
         }
-        let deserialized: DeserializableGlweSeededCiphertext32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableGlweKeyswitchKey32 = bincode::deserialize(serialized)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableGlweSeededCiphertext32 {
-                version: GlweSeededCiphertext32Version::Unsupported,
+            DeserializableGlweKeyswitchKey32 {
+                version: GlweKeyswitchKey32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableGlweSeededCiphertext32 {
-                version: GlweSeededCiphertext32Version::V0,
+            DeserializableGlweKeyswitchKey32 {
+                version: GlweKeyswitchKey32Version::V0,
                 inner,
-            } => Ok(GlweSeededCiphertext32(inner)),
+            } => Ok(GlweKeyswitchKey32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertext32 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweKeyswitchKey32 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a GLWE seeded ciphertext entity.
-impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext64> for DefaultSerializationEngine {
+/// operates on 64 bits integers. It deserializes a GLWE keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], GlweKeyswitchKey64> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let glwe_dimension = GlweDimension(2);
-    /// let polynomial_size = PolynomialSize(4);
-    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let input_glwe_dimension = GlweDimension(3);
+    /// let output_glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: GlweSecretKey64 =
-    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
-    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let input_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, polynomial_size)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
     ///
-    /// let seeded_ciphertext =
-    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let keyswitch_key: GlweKeyswitchKey64 = engine.generate_new_glwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_ciphertext)?;
+    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_ciphertext, recovered);
+    /// assert_eq!(keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1136,70 +1212,70 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext64> for DefaultSeria
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<GlweSeededCiphertext64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<GlweKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableGlweSeededCiphertext64 {
-            version: GlweSeededCiphertext64Version,
-            inner: ImplGlweSeededCiphertext<Vec<u64>>,
+        struct DeserializableGlweKeyswitchKey64 {
+            version: GlweKeyswitchKey64Version,
+            inner: ImplGlweKeyswitchKey<Vec<u64>>,
         }
-        let deserialized: DeserializableGlweSeededCiphertext64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableGlweKeyswitchKey64 = bincode::deserialize(serialized)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableGlweSeededCiphertext64 {
-                version: GlweSeededCiphertext64Version::Unsupported,
+            DeserializableGlweKeyswitchKey64 {
+                version: GlweKeyswitchKey64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableGlweSeededCiphertext64 {
-                version: GlweSeededCiphertext64Version::V0,
+            DeserializableGlweKeyswitchKey64 {
+                version: GlweKeyswitchKey64Version::V0,
                 inner,
-            } => Ok(GlweSeededCiphertext64(inner)),
+            } => Ok(GlweKeyswitchKey64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertext64 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweKeyswitchKey64 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a GLWE seeded ciphertext vector entity.
-impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector32>
-    for DefaultSerializationEngine
-{
+/// operates on 32 bits integers. It deserializes a GLWE public key entity.
+impl EntityDeserializationEngine<&[u8], GlwePublicKey32> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, GlwePublicKeyZeroEncryptionCount, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
-    /// let polynomial_size = PolynomialSize(4);
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; 8];
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let polynomial_size = PolynomialSize(256);
+    /// let noise = Variance(2_f64.powf(-50.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: GlweSecretKey32 =
+    /// let secret_key: GlweSecretKey32 =
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
-    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let seeded_ciphertext_vector =
-    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let public_key: GlwePublicKey32 = engine.generate_new_glwe_public_key(
+    ///     &secret_key,
+    ///     noise,
+    ///     GlwePublicKeyZeroEncryptionCount(1),
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&public_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    /// assert_eq!(public_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1208,71 +1284,70 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector32>
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<GlweSeededCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<GlwePublicKey32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableGlweSeededCiphertextVector32 {
-            version: GlweSeededCiphertextVector32Version,
-            inner: ImplGlweSeededList<Vec<u32>>,
+        struct DeserializableGlwePublicKey32 {
+            version: GlwePublicKey32Version,
+            inner: ImplGlwePublicKey<Vec<u32>>,
         }
-        let deserialized: DeserializableGlweSeededCiphertextVector32 =
-            bincode::deserialize(serialized)
-                .map_err(DefaultSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableGlwePublicKey32 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableGlweSeededCiphertextVector32 {
-                version: GlweSeededCiphertextVector32Version::Unsupported,
+            DeserializableGlwePublicKey32 {
+                version: GlwePublicKey32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableGlweSeededCiphertextVector32 {
-                version: GlweSeededCiphertextVector32Version::V0,
+            DeserializableGlwePublicKey32 {
+                version: GlwePublicKey32Version::V0,
                 inner,
-            } => Ok(GlweSeededCiphertextVector32(inner)),
+            } => Ok(GlwePublicKey32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertextVector32 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlwePublicKey32 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a GLWE seeded ciphertext vector entity.
-impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector64>
-    for DefaultSerializationEngine
-{
+/// operates on 64 bits integers. It deserializes a GLWE public key entity.
+impl EntityDeserializationEngine<&[u8], GlwePublicKey64> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, GlwePublicKeyZeroEncryptionCount, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
-    /// let polynomial_size = PolynomialSize(4);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; 8];
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let polynomial_size = PolynomialSize(256);
+    /// let noise = Variance(2_f64.powf(-50.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: GlweSecretKey64 =
+    /// let secret_key: GlweSecretKey64 =
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
-    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let seeded_ciphertext_vector =
-    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let public_key: GlwePublicKey64 = engine.generate_new_glwe_public_key(
+    ///     &secret_key,
+    ///     noise,
+    ///     GlwePublicKeyZeroEncryptionCount(1),
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&public_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    /// assert_eq!(public_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1281,68 +1356,74 @@ impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector64>
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<GlweSeededCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<GlwePublicKey64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableGlweSeededCiphertextVector64 {
-            version: GlweSeededCiphertextVector64Version,
-            inner: ImplGlweSeededList<Vec<u64>>,
+        struct DeserializableGlwePublicKey64 {
+            version: GlwePublicKey64Version,
+            inner: ImplGlwePublicKey<Vec<u64>>,
         }
-        let deserialized: DeserializableGlweSeededCiphertextVector64 =
-            bincode::deserialize(serialized)
-                .map_err(DefaultSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableGlwePublicKey64 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableGlweSeededCiphertextVector64 {
-                version: GlweSeededCiphertextVector64Version::Unsupported,
+            DeserializableGlwePublicKey64 {
+                version: GlwePublicKey64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableGlweSeededCiphertextVector64 {
-                version: GlweSeededCiphertextVector64Version::V0,
+            DeserializableGlwePublicKey64 {
+                version: GlwePublicKey64Version::V0,
                 inner,
-            } => Ok(GlweSeededCiphertextVector64(inner)),
+            } => Ok(GlwePublicKey64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertextVector64 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlwePublicKey64 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a LWE bootstrap key entity.
-impl EntityDeserializationEngine<&[u8], LweBootstrapKey32> for DefaultSerializationEngine {
-    /// # Example
+/// operates on 32 bits integers. It deserializes a GLWE relinearization key entity.
+impl EntityDeserializationEngine<&[u8], GlweRelinearizationKey32> for DefaultSerializationEngine {
+    /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
     /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
     /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
-    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
-    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     ///
-    /// let bsk: LweBootstrapKey32 =
-    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let relinearization_key: GlweRelinearizationKey32 = engine
+    ///     .generate_new_glwe_relinearization_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let serialized = serialization_engine.serialize(&relinearization_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(bsk, recovered);
+    /// assert_eq!(relinearization_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1351,67 +1432,74 @@ impl EntityDeserializationEngine<&[u8], LweBootstrapKey32> for DefaultSerializat
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweBootstrapKey32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<GlweRelinearizationKey32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweBootstrapKey32 {
-            version: LweBootstrapKey32Version,
-            inner: ImplStandardBootstrapKey<Vec<u32>>,
+        struct DeserializableGlweRelinearizationKey32 {
+            version: GlweRelinearizationKey32Version,
+            inner: ImplGlweRelinearizationKey<Vec<u32>>,
         }
-        let deserialized: DeserializableLweBootstrapKey32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableGlweRelinearizationKey32 = bincode::deserialize(serialized)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweBootstrapKey32 {
-                version: LweBootstrapKey32Version::Unsupported,
+            DeserializableGlweRelinearizationKey32 {
+                version: GlweRelinearizationKey32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweBootstrapKey32 {
-                version: LweBootstrapKey32Version::V0,
+            DeserializableGlweRelinearizationKey32 {
+                version: GlweRelinearizationKey32Version::V0,
                 inner,
-            } => Ok(LweBootstrapKey32(inner)),
+            } => Ok(GlweRelinearizationKey32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweBootstrapKey32 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweRelinearizationKey32 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a LWE bootstrap key entity.
-impl EntityDeserializationEngine<&[u8], LweBootstrapKey64> for DefaultSerializationEngine {
-    /// # Example
+/// operates on 64 bits integers. It deserializes a GLWE relinearization key entity.
+impl EntityDeserializationEngine<&[u8], GlweRelinearizationKey64> for DefaultSerializationEngine {
+    /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
     /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
     /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
-    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
-    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     ///
-    /// let bsk: LweBootstrapKey64 =
-    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let relinearization_key: GlweRelinearizationKey64 = engine
+    ///     .generate_new_glwe_relinearization_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let serialized = serialization_engine.serialize(&relinearization_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(bsk, recovered);
+    /// assert_eq!(relinearization_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1420,65 +1508,63 @@ impl EntityDeserializationEngine<&[u8], LweBootstrapKey64> for DefaultSerializat
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweBootstrapKey64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<GlweRelinearizationKey64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweBootstrapKey64 {
-            version: LweBootstrapKey64Version,
-            inner: ImplStandardBootstrapKey<Vec<u64>>,
+        struct DeserializableGlweRelinearizationKey64 {
+            version: GlweRelinearizationKey64Version,
+            inner: ImplGlweRelinearizationKey<Vec<u64>>,
         }
-        let deserialized: DeserializableLweBootstrapKey64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableGlweRelinearizationKey64 = bincode::deserialize(serialized)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweBootstrapKey64 {
-                version: LweBootstrapKey64Version::Unsupported,
+            DeserializableGlweRelinearizationKey64 {
+                version: GlweRelinearizationKey64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweBootstrapKey64 {
-                version: LweBootstrapKey64Version::V0,
+            DeserializableGlweRelinearizationKey64 {
+                version: GlweRelinearizationKey64Version::V0,
                 inner,
-            } => Ok(LweBootstrapKey64(inner)),
+            } => Ok(GlweRelinearizationKey64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweBootstrapKey64 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweRelinearizationKey64 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a LWE ciphertext entity.
-impl EntityDeserializationEngine<&[u8], LweCiphertext32> for DefaultSerializationEngine {
+/// operates on 32 bits integers. It deserializes a GLWE secret key entity.
+impl<Kind> EntityDeserializationEngine<&[u8], GlweSecretKey32<Kind>> for DefaultSerializationEngine
+where
+    Kind: RandomKeyKind + Debug,
+{
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = 3_u32 << 20;
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
-    ///
-    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let glwe_secret_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext, recovered);
+    /// assert_eq!(glwe_secret_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1487,38 +1573,1628 @@ impl EntityDeserializationEngine<&[u8], LweCiphertext32> for DefaultSerializatio
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweCiphertext32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<GlweSecretKey32<Kind>, EntityDeserializationError<Self::EngineError>> {
+        let mut reader = serialized;
+        let version: GlweSecretKey32Version = bincode::deserialize_from(&mut reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        // Keys serialized before V1 did not store their kind, and were all binary.
+        let kind = match version {
+            GlweSecretKey32Version::Unsupported => {
+                return Err(EntityDeserializationError::Engine(
+                    DefaultSerializationError::UnsupportedVersion,
+                ))
+            }
+            GlweSecretKey32Version::V0 => KeyKindTag::Binary,
+            GlweSecretKey32Version::V1 => bincode::deserialize_from(&mut reader)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?,
+        };
+        if kind != Kind::TAG {
+            return Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::KeyKindMismatch,
+            ));
+        }
+        let inner: ImplGlweSecretKey<Kind, Vec<u32>> = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        Ok(GlweSecretKey32(inner))
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSecretKey32<Kind> {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE secret key entity.
+impl<Kind> EntityDeserializationEngine<&[u8], GlweSecretKey64<Kind>> for DefaultSerializationEngine
+where
+    Kind: RandomKeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let glwe_secret_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(glwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSecretKey64<Kind>, EntityDeserializationError<Self::EngineError>> {
+        let mut reader = serialized;
+        let version: GlweSecretKey64Version = bincode::deserialize_from(&mut reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        // Keys serialized before V1 did not store their kind, and were all binary.
+        let kind = match version {
+            GlweSecretKey64Version::Unsupported => {
+                return Err(EntityDeserializationError::Engine(
+                    DefaultSerializationError::UnsupportedVersion,
+                ))
+            }
+            GlweSecretKey64Version::V0 => KeyKindTag::Binary,
+            GlweSecretKey64Version::V1 => bincode::deserialize_from(&mut reader)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?,
+        };
+        if kind != Kind::TAG {
+            return Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::KeyKindMismatch,
+            ));
+        }
+        let inner: ImplGlweSecretKey<Kind, Vec<u64>> = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        Ok(GlweSecretKey64(inner))
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSecretKey64<Kind> {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GLWE seeded ciphertext entity.
+impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSeededCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSeededCiphertext32 {
+            version: GlweSeededCiphertext32Version,
+            inner: ImplGlweSeededCiphertext<Vec<u32>>,
+        }
+        let deserialized: DeserializableGlweSeededCiphertext32 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweSeededCiphertext32 {
+                version: GlweSeededCiphertext32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSeededCiphertext32 {
+                version: GlweSeededCiphertext32Version::V0,
+                inner,
+            } => Ok(GlweSeededCiphertext32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertext32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE seeded ciphertext entity.
+impl EntityDeserializationEngine<&[u8], GlweSeededCiphertext64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSeededCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSeededCiphertext64 {
+            version: GlweSeededCiphertext64Version,
+            inner: ImplGlweSeededCiphertext<Vec<u64>>,
+        }
+        let deserialized: DeserializableGlweSeededCiphertext64 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweSeededCiphertext64 {
+                version: GlweSeededCiphertext64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSeededCiphertext64 {
+                version: GlweSeededCiphertext64Version::V0,
+                inner,
+            } => Ok(GlweSeededCiphertext64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertext64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GLWE seeded ciphertext vector entity.
+impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSeededCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSeededCiphertextVector32 {
+            version: GlweSeededCiphertextVector32Version,
+            inner: ImplGlweSeededList<Vec<u32>>,
+        }
+        let deserialized: DeserializableGlweSeededCiphertextVector32 =
+            bincode::deserialize(serialized)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweSeededCiphertextVector32 {
+                version: GlweSeededCiphertextVector32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSeededCiphertextVector32 {
+                version: GlweSeededCiphertextVector32Version::V0,
+                inner,
+            } => Ok(GlweSeededCiphertextVector32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertextVector32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GLWE seeded ciphertext vector entity.
+impl EntityDeserializationEngine<&[u8], GlweSeededCiphertextVector64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let seeded_ciphertext_vector =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&seeded_ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSeededCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSeededCiphertextVector64 {
+            version: GlweSeededCiphertextVector64Version,
+            inner: ImplGlweSeededList<Vec<u64>>,
+        }
+        let deserialized: DeserializableGlweSeededCiphertextVector64 =
+            bincode::deserialize(serialized)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweSeededCiphertextVector64 {
+                version: GlweSeededCiphertextVector64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSeededCiphertextVector64 {
+                version: GlweSeededCiphertextVector64Version::V0,
+                inner,
+            } => Ok(GlweSeededCiphertextVector64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededCiphertextVector64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a seeded GLWE keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], GlweSeededKeyswitchKey32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_glwe_dimension = GlweDimension(3);
+    /// let output_glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, polynomial_size)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let keyswitch_key: GlweSeededKeyswitchKey32 = engine.generate_new_glwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSeededKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSeededKeyswitchKey32 {
+            version: GlweSeededKeyswitchKey32Version,
+            inner: ImplGlweSeededKeyswitchKey<Vec<u32>>,
+        }
+        let deserialized: DeserializableGlweSeededKeyswitchKey32 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweSeededKeyswitchKey32 {
+                version: GlweSeededKeyswitchKey32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSeededKeyswitchKey32 {
+                version: GlweSeededKeyswitchKey32Version::V0,
+                inner,
+            } => Ok(GlweSeededKeyswitchKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededKeyswitchKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a seeded GLWE keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], GlweSeededKeyswitchKey64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_glwe_dimension = GlweDimension(3);
+    /// let output_glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, polynomial_size)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let keyswitch_key: GlweSeededKeyswitchKey64 = engine.generate_new_glwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<GlweSeededKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableGlweSeededKeyswitchKey64 {
+            version: GlweSeededKeyswitchKey64Version,
+            inner: ImplGlweSeededKeyswitchKey<Vec<u64>>,
+        }
+        let deserialized: DeserializableGlweSeededKeyswitchKey64 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableGlweSeededKeyswitchKey64 {
+                version: GlweSeededKeyswitchKey64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableGlweSeededKeyswitchKey64 {
+                version: GlweSeededKeyswitchKey64Version::V0,
+                inner,
+            } => Ok(GlweSeededKeyswitchKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> GlweSeededKeyswitchKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE bootstrap key entity.
+impl EntityDeserializationEngine<&[u8], LweBootstrapKey32> for DefaultSerializationEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweBootstrapKey32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweBootstrapKey32 {
+            version: LweBootstrapKey32Version,
+            inner: ImplStandardBootstrapKey<Vec<u32>>,
+        }
+        let deserialized: DeserializableLweBootstrapKey32 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweBootstrapKey32 {
+                version: LweBootstrapKey32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweBootstrapKey32 {
+                version: LweBootstrapKey32Version::V0,
+                inner,
+            } => Ok(LweBootstrapKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweBootstrapKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a LWE bootstrap key entity.
+impl EntityDeserializationEngine<&[u8], LweBootstrapKey64> for DefaultSerializationEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweBootstrapKey64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweBootstrapKey64 {
+            version: LweBootstrapKey64Version,
+            inner: ImplStandardBootstrapKey<Vec<u64>>,
+        }
+        let deserialized: DeserializableLweBootstrapKey64 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweBootstrapKey64 {
+                version: LweBootstrapKey64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweBootstrapKey64 {
+                version: LweBootstrapKey64Version::V0,
+                inner,
+            } => Ok(LweBootstrapKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweBootstrapKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE ciphertext entity.
+impl EntityDeserializationEngine<&[u8], LweCiphertext32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweCiphertext32 {
+            version: LweCiphertext32Version,
+            inner: ImplLweCiphertext<Vec<u32>>,
+        }
+        let deserialized: DeserializableLweCiphertext32 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweCiphertext32 {
+                version: LweCiphertext32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweCiphertext32 {
+                version: LweCiphertext32Version::V0,
+                inner,
+            } => Ok(LweCiphertext32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertext32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a LWE ciphertext entity.
+impl EntityDeserializationEngine<&[u8], LweCiphertext64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCiphertext64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweCiphertext64 {
+            version: LweCiphertext64Version,
+            inner: ImplLweCiphertext<Vec<u64>>,
+        }
+        let deserialized: DeserializableLweCiphertext64 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweCiphertext64 {
+                version: LweCiphertext64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweCiphertext64 {
+                version: LweCiphertext64Version::V0,
+                inner,
+            } => Ok(LweCiphertext64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertext64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE ciphertext vector entity.
+impl EntityDeserializationEngine<&[u8], LweCiphertextVector32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweCiphertextVector32 {
+            version: LweCiphertextVector32Version,
+            inner: ImplLweList<Vec<u32>>,
+        }
+        let deserialized: DeserializableLweCiphertextVector32 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweCiphertextVector32 {
+                version: LweCiphertextVector32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweCiphertextVector32 {
+                version: LweCiphertextVector32Version::V0,
+                inner,
+            } => Ok(LweCiphertextVector32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertextVector32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a LWE ciphertext vector entity.
+impl EntityDeserializationEngine<&[u8], LweCiphertextVector64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweCiphertextVector64 {
+            version: LweCiphertextVector64Version,
+            inner: ImplLweList<Vec<u64>>,
+        }
+        let deserialized: DeserializableLweCiphertextVector64 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweCiphertextVector64 {
+                version: LweCiphertextVector64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweCiphertextVector64 {
+                version: LweCiphertextVector64Version::V0,
+                inner,
+            } => Ok(LweCiphertextVector64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertextVector64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a compressed LWE ciphertext vector entity.
+impl EntityDeserializationEngine<&[u8], LweCompressedCiphertextVector32>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let compressed_vector: LweCompressedCiphertextVector32 = engine
+    ///     .switch_modulus_lwe_ciphertext_vector(&ciphertext_vector, CiphertextModulusLog(8))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&compressed_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(compressed_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCompressedCiphertextVector32, EntityDeserializationError<Self::EngineError>>
+    {
+        #[derive(Deserialize)]
+        struct DeserializableLweCompressedCiphertextVector32 {
+            version: LweCompressedCiphertextVector32Version,
+            inner: ImplLweCompressedList<Vec<u32>>,
+        }
+        let deserialized: DeserializableLweCompressedCiphertextVector32 =
+            bincode::deserialize(serialized)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweCompressedCiphertextVector32 {
+                version: LweCompressedCiphertextVector32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweCompressedCiphertextVector32 {
+                version: LweCompressedCiphertextVector32Version::V0,
+                inner,
+            } => Ok(LweCompressedCiphertextVector32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweCompressedCiphertextVector32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a compressed LWE ciphertext vector entity.
+impl EntityDeserializationEngine<&[u8], LweCompressedCiphertextVector64>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let compressed_vector: LweCompressedCiphertextVector64 = engine
+    ///     .switch_modulus_lwe_ciphertext_vector(&ciphertext_vector, CiphertextModulusLog(8))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&compressed_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(compressed_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCompressedCiphertextVector64, EntityDeserializationError<Self::EngineError>>
+    {
+        #[derive(Deserialize)]
+        struct DeserializableLweCompressedCiphertextVector64 {
+            version: LweCompressedCiphertextVector64Version,
+            inner: ImplLweCompressedList<Vec<u64>>,
+        }
+        let deserialized: DeserializableLweCompressedCiphertextVector64 =
+            bincode::deserialize(serialized)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweCompressedCiphertextVector64 {
+                version: LweCompressedCiphertextVector64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweCompressedCiphertextVector64 {
+                version: LweCompressedCiphertextVector64Version::V0,
+                inner,
+            } => Ok(LweCompressedCiphertextVector64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweCompressedCiphertextVector64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LweKeyswitchKey32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweKeyswitchKey32 {
+            version: LweKeyswitchKey32Version,
+            inner: ImplLweKeyswitchKey<Vec<u32>>,
+        }
+        let deserialized: DeserializableLweKeyswitchKey32 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweKeyswitchKey32 {
+                version: LweKeyswitchKey32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweKeyswitchKey32 {
+                version: LweKeyswitchKey32Version::V0,
+                inner,
+            } => Ok(LweKeyswitchKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweKeyswitchKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a LWE keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LweKeyswitchKey64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweKeyswitchKey64 {
+            version: LweKeyswitchKey64Version,
+            inner: ImplLweKeyswitchKey<Vec<u64>>,
+        }
+        let deserialized: DeserializableLweKeyswitchKey64 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweKeyswitchKey64 {
+                version: LweKeyswitchKey64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweKeyswitchKey64 {
+                version: LweKeyswitchKey64Version::V0,
+                inner,
+            } => Ok(LweKeyswitchKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweKeyswitchKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE secret key entity.
+impl<Kind> EntityDeserializationEngine<&[u8], LweSecretKey32<Kind>> for DefaultSerializationEngine
+where
+    Kind: RandomKeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSecretKey32<Kind>, EntityDeserializationError<Self::EngineError>> {
+        let mut reader = serialized;
+        let version: LweSecretKey32Version = bincode::deserialize_from(&mut reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        // Keys serialized before V1 did not store their kind, and were all binary.
+        let kind = match version {
+            LweSecretKey32Version::Unsupported => {
+                return Err(EntityDeserializationError::Engine(
+                    DefaultSerializationError::UnsupportedVersion,
+                ))
+            }
+            LweSecretKey32Version::V0 => KeyKindTag::Binary,
+            LweSecretKey32Version::V1 => bincode::deserialize_from(&mut reader)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?,
+        };
+        if kind != Kind::TAG {
+            return Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::KeyKindMismatch,
+            ));
+        }
+        let inner: ImplLweSecretKey<Kind, Vec<u32>> = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        Ok(LweSecretKey32(inner))
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSecretKey32<Kind> {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a LWE secret key entity.
+impl<Kind> EntityDeserializationEngine<&[u8], LweSecretKey64<Kind>> for DefaultSerializationEngine
+where
+    Kind: RandomKeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::key_kinds::TernaryKeyKind;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// // The kind of the key is checked when deserializing it.
+    /// let ternary_key: LweSecretKey64<TernaryKeyKind> =
+    ///     engine.generate_new_lwe_secret_key_with_kind(lwe_dimension, TernaryKeyKind)?;
+    /// let serialized = serialization_engine.serialize(&ternary_key)?;
+    /// let recovered: LweSecretKey64<TernaryKeyKind> =
+    ///     serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ternary_key, recovered);
+    /// let result: Result<LweSecretKey64, _> =
+    ///     serialization_engine.deserialize(serialized.as_slice());
+    /// assert!(result.is_err());
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSecretKey64<Kind>, EntityDeserializationError<Self::EngineError>> {
+        let mut reader = serialized;
+        let version: LweSecretKey64Version = bincode::deserialize_from(&mut reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        // Keys serialized before V1 did not store their kind, and were all binary.
+        let kind = match version {
+            LweSecretKey64Version::Unsupported => {
+                return Err(EntityDeserializationError::Engine(
+                    DefaultSerializationError::UnsupportedVersion,
+                ))
+            }
+            LweSecretKey64Version::V0 => KeyKindTag::Binary,
+            LweSecretKey64Version::V1 => bincode::deserialize_from(&mut reader)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?,
+        };
+        if kind != Kind::TAG {
+            return Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::KeyKindMismatch,
+            ));
+        }
+        let inner: ImplLweSecretKey<Kind, Vec<u64>> = bincode::deserialize_from(reader)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        Ok(LweSecretKey64(inner))
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSecretKey64<Kind> {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a seeded LWE bootstrap key entity.
+impl EntityDeserializationEngine<&[u8], LweSeededBootstrapKey32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweSeededBootstrapKey32 =
+    ///     engine.generate_new_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    ///
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSeededBootstrapKey32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededBootstrapKey32 {
+            version: LweSeededBootstrapKey32Version,
+            inner: ImplStandardSeededBootstrapKey<Vec<u32>>,
+        }
+        let deserialized: DeserializableLweSeededBootstrapKey32 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweSeededBootstrapKey32 {
+                version: LweSeededBootstrapKey32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSeededBootstrapKey32 {
+                version: LweSeededBootstrapKey32Version::V0,
+                inner,
+            } => Ok(LweSeededBootstrapKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededBootstrapKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a seeded LWE bootstrap key entity.
+impl EntityDeserializationEngine<&[u8], LweSeededBootstrapKey64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweSeededBootstrapKey64 =
+    ///     engine.generate_new_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    ///
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSeededBootstrapKey64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweCiphertext32 {
-            version: LweCiphertext32Version,
-            inner: ImplLweCiphertext<Vec<u32>>,
+        struct DeserializableLweSeededBootstrapKey64 {
+            version: LweSeededBootstrapKey64Version,
+            inner: ImplStandardSeededBootstrapKey<Vec<u64>>,
         }
-        let deserialized: DeserializableLweCiphertext32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSeededBootstrapKey64 = bincode::deserialize(serialized)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweCiphertext32 {
-                version: LweCiphertext32Version::Unsupported,
+            DeserializableLweSeededBootstrapKey64 {
+                version: LweSeededBootstrapKey64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweCiphertext32 {
-                version: LweCiphertext32Version::V0,
+            DeserializableLweSeededBootstrapKey64 {
+                version: LweSeededBootstrapKey64Version::V0,
                 inner,
-            } => Ok(LweCiphertext32(inner)),
+            } => Ok(LweSeededBootstrapKey64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertext32 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededBootstrapKey64 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a LWE ciphertext entity.
-impl EntityDeserializationEngine<&[u8], LweCiphertext64> for DefaultSerializationEngine {
+/// operates on 32 bits integers. It deserializes a seeded LWE ciphertext entity.
+impl EntityDeserializationEngine<&[u8], LweSeededCiphertext32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext: LweSeededCiphertext32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweSeededCiphertext32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededCiphertext32 {
+            version: LweSeededCiphertext32Version,
+            inner: ImplLweSeededCiphertext<u32>,
+        }
+        let deserialized: DeserializableLweSeededCiphertext32 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweSeededCiphertext32 {
+                version: LweSeededCiphertext32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweSeededCiphertext32 {
+                version: LweSeededCiphertext32Version::V0,
+                inner,
+            } => Ok(LweSeededCiphertext32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertext32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a seeded LWE ciphertext entity.
+impl EntityDeserializationEngine<&[u8], LweSeededCiphertext64> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -1540,7 +3216,8 @@ impl EntityDeserializationEngine<&[u8], LweCiphertext64> for DefaultSerializatio
     /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
     /// let plaintext = engine.create_plaintext_from(&input)?;
     ///
-    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let ciphertext: LweSeededCiphertext64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
     /// let serialized = serialization_engine.serialize(&ciphertext)?;
@@ -1550,41 +3227,44 @@ impl EntityDeserializationEngine<&[u8], LweCiphertext64> for DefaultSerializatio
     /// #
     /// # Ok(())
     /// # }
+    /// ```
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweCiphertext64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LweSeededCiphertext64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweCiphertext64 {
-            version: LweCiphertext64Version,
-            inner: ImplLweCiphertext<Vec<u64>>,
+        struct DeserializableLweSeededCiphertext64 {
+            version: LweSeededCiphertext64Version,
+            inner: ImplLweSeededCiphertext<u64>,
         }
-        let deserialized: DeserializableLweCiphertext64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSeededCiphertext64 = bincode::deserialize(serialized)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweCiphertext64 {
-                version: LweCiphertext64Version::Unsupported,
+            DeserializableLweSeededCiphertext64 {
+                version: LweSeededCiphertext64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweCiphertext64 {
-                version: LweCiphertext64Version::V0,
+            DeserializableLweSeededCiphertext64 {
+                version: LweSeededCiphertext64Version::V0,
                 inner,
-            } => Ok(LweCiphertext64(inner)),
+            } => Ok(LweSeededCiphertext64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertext64 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertext64 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a LWE ciphertext vector entity.
-impl EntityDeserializationEngine<&[u8], LweCiphertextVector32> for DefaultSerializationEngine {
+/// operates on 32 bits integers. It deserializes a seeded LWE ciphertext vector entity.
+impl EntityDeserializationEngine<&[u8], LweSeededCiphertextVector32>
+    for DefaultSerializationEngine
+{
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -1606,8 +3286,8 @@ impl EntityDeserializationEngine<&[u8], LweCiphertextVector32> for DefaultSerial
     /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
     /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let mut ciphertext_vector: LweCiphertextVector32 =
-    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut ciphertext_vector: LweSeededCiphertextVector32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
     /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
@@ -1621,38 +3301,41 @@ impl EntityDeserializationEngine<&[u8], LweCiphertextVector32> for DefaultSerial
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LweSeededCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweCiphertextVector32 {
-            version: LweCiphertextVector32Version,
-            inner: ImplLweList<Vec<u32>>,
+        struct DeserializableLweSeededCiphertextVector32 {
+            version: LweSeededCiphertextVector32Version,
+            inner: ImplLweSeededList<Vec<u32>>,
         }
-        let deserialized: DeserializableLweCiphertextVector32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLweSeededCiphertextVector32 =
+            bincode::deserialize(serialized)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweCiphertextVector32 {
-                version: LweCiphertextVector32Version::Unsupported,
+            DeserializableLweSeededCiphertextVector32 {
+                version: LweSeededCiphertextVector32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweCiphertextVector32 {
-                version: LweCiphertextVector32Version::V0,
+            DeserializableLweSeededCiphertextVector32 {
+                version: LweSeededCiphertextVector32Version::V0,
                 inner,
-            } => Ok(LweCiphertextVector32(inner)),
+            } => Ok(LweSeededCiphertextVector32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertextVector32 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertextVector32 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a LWE ciphertext vector entity.
-impl EntityDeserializationEngine<&[u8], LweCiphertextVector64> for DefaultSerializationEngine {
+/// operates on 64 bits integers. It deserializes a seeded LWE ciphertext vector entity.
+impl EntityDeserializationEngine<&[u8], LweSeededCiphertextVector64>
+    for DefaultSerializationEngine
+{
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -1674,8 +3357,8 @@ impl EntityDeserializationEngine<&[u8], LweCiphertextVector64> for DefaultSerial
     /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
     /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let mut ciphertext_vector: LweCiphertextVector64 =
-    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut ciphertext_vector: LweSeededCiphertextVector64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
     /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
@@ -1689,38 +3372,39 @@ impl EntityDeserializationEngine<&[u8], LweCiphertextVector64> for DefaultSerial
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LweSeededCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweCiphertextVector64 {
-            version: LweCiphertextVector64Version,
-            inner: ImplLweList<Vec<u64>>,
+        struct DeserializableLweSeededCiphertextVector64 {
+            version: LweSeededCiphertextVector64Version,
+            inner: ImplLweSeededList<Vec<u64>>,
         }
-        let deserialized: DeserializableLweCiphertextVector64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLweSeededCiphertextVector64 =
+            bincode::deserialize(serialized)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweCiphertextVector64 {
-                version: LweCiphertextVector64Version::Unsupported,
+            DeserializableLweSeededCiphertextVector64 {
+                version: LweSeededCiphertextVector64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweCiphertextVector64 {
-                version: LweCiphertextVector64Version::V0,
+            DeserializableLweSeededCiphertextVector64 {
+                version: LweSeededCiphertextVector64Version::V0,
                 inner,
-            } => Ok(LweCiphertextVector64(inner)),
+            } => Ok(LweSeededCiphertextVector64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCiphertextVector64 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertextVector64 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a LWE keyswitch key entity.
-impl EntityDeserializationEngine<&[u8], LweKeyswitchKey32> for DefaultSerializationEngine {
+/// operates on 32 bits integers. It deserializes a seeded LWE ciphertext keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LweSeededKeyswitchKey32> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -1745,7 +3429,7 @@ impl EntityDeserializationEngine<&[u8], LweKeyswitchKey32> for DefaultSerializat
     /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
     /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
     ///
-    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
     ///     &input_key,
     ///     &output_key,
     ///     decomposition_level_count,
@@ -1754,9 +3438,9 @@ impl EntityDeserializationEngine<&[u8], LweKeyswitchKey32> for DefaultSerializat
     /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
+    /// let serialized = serialization_engine.serialize(&seeded_keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(keyswitch_key, recovered);
+    /// assert_eq!(seeded_keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1765,38 +3449,38 @@ impl EntityDeserializationEngine<&[u8], LweKeyswitchKey32> for DefaultSerializat
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
-        #[derive(Deserialize)]
-        struct DeserializableLweKeyswitchKey32 {
-            version: LweKeyswitchKey32Version,
-            inner: ImplLweKeyswitchKey<Vec<u32>>,
+    ) -> Result<LweSeededKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweSeededKeyswitchKey32 {
+            version: LweSeededKeyswitchKey32Version,
+            inner: ImplLweSeededKeyswitchKey<Vec<u32>>,
         }
-        let deserialized: DeserializableLweKeyswitchKey32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSeededKeyswitchKey32 = bincode::deserialize(serialized)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweKeyswitchKey32 {
-                version: LweKeyswitchKey32Version::Unsupported,
+            DeserializableLweSeededKeyswitchKey32 {
+                version: LweSeededKeyswitchKey32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweKeyswitchKey32 {
-                version: LweKeyswitchKey32Version::V0,
+            DeserializableLweSeededKeyswitchKey32 {
+                version: LweSeededKeyswitchKey32Version::V0,
                 inner,
-            } => Ok(LweKeyswitchKey32(inner)),
+            } => Ok(LweSeededKeyswitchKey32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweKeyswitchKey32 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededKeyswitchKey32 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a LWE keyswitch key entity.
-impl EntityDeserializationEngine<&[u8], LweKeyswitchKey64> for DefaultSerializationEngine {
+/// operates on 64 bits integers. It deserializes a seeded LWE ciphertext keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LweSeededKeyswitchKey64> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -1821,7 +3505,7 @@ impl EntityDeserializationEngine<&[u8], LweKeyswitchKey64> for DefaultSerializat
     /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
     /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
     ///
-    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
     ///     &input_key,
     ///     &output_key,
     ///     decomposition_level_count,
@@ -1830,9 +3514,9 @@ impl EntityDeserializationEngine<&[u8], LweKeyswitchKey64> for DefaultSerializat
     /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
+    /// let serialized = serialization_engine.serialize(&seeded_keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(keyswitch_key, recovered);
+    /// assert_eq!(seeded_keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1841,58 +3525,74 @@ impl EntityDeserializationEngine<&[u8], LweKeyswitchKey64> for DefaultSerializat
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LweSeededKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweKeyswitchKey64 {
-            version: LweKeyswitchKey64Version,
-            inner: ImplLweKeyswitchKey<Vec<u64>>,
+        struct DeserializableLweSeededKeyswitchKey64 {
+            version: LweSeededKeyswitchKey64Version,
+            inner: ImplLweSeededKeyswitchKey<Vec<u64>>,
         }
-        let deserialized: DeserializableLweKeyswitchKey64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSeededKeyswitchKey64 = bincode::deserialize(serialized)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweKeyswitchKey64 {
-                version: LweKeyswitchKey64Version::Unsupported,
+            DeserializableLweSeededKeyswitchKey64 {
+                version: LweSeededKeyswitchKey64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweKeyswitchKey64 {
-                version: LweKeyswitchKey64Version::V0,
+            DeserializableLweSeededKeyswitchKey64 {
+                version: LweSeededKeyswitchKey64Version::V0,
                 inner,
-            } => Ok(LweKeyswitchKey64(inner)),
+            } => Ok(LweSeededKeyswitchKey64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweKeyswitchKey64 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededKeyswitchKey64 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a LWE secret key entity.
-impl EntityDeserializationEngine<&[u8], LweSecretKey32> for DefaultSerializationEngine {
+/// operates on 32 bits integers. It serializes a packing keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LwePackingKeyswitchKey32> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(6);
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let packing_keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
+    /// let serialized = serialization_engine.serialize(&packing_keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(lwe_secret_key, recovered);
+    /// assert_eq!(packing_keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1901,58 +3601,74 @@ impl EntityDeserializationEngine<&[u8], LweSecretKey32> for DefaultSerialization
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweSecretKey32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LwePackingKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweSecretKey32 {
-            version: LweSecretKey32Version,
-            inner: ImplLweSecretKey<BinaryKeyKind, Vec<u32>>,
+        struct DeserializablePackingKeyswitchKey32 {
+            version: LwePackingKeyswitchKey32Version,
+            inner: ImplLwePackingKeyswitchKey<Vec<u32>>,
         }
-        let deserialized: DeserializableLweSecretKey32 = bincode::deserialize(serialized)
+        let deserialized: DeserializablePackingKeyswitchKey32 = bincode::deserialize(serialized)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweSecretKey32 {
-                version: LweSecretKey32Version::Unsupported,
+            DeserializablePackingKeyswitchKey32 {
+                version: LwePackingKeyswitchKey32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweSecretKey32 {
-                version: LweSecretKey32Version::V0,
+            DeserializablePackingKeyswitchKey32 {
+                version: LwePackingKeyswitchKey32Version::V0,
                 inner,
-            } => Ok(LweSecretKey32(inner)),
+            } => Ok(LwePackingKeyswitchKey32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSecretKey32 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LwePackingKeyswitchKey32 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a LWE secret key entity.
-impl EntityDeserializationEngine<&[u8], LweSecretKey64> for DefaultSerializationEngine {
+/// operates on 64 bits integers. It deserializes a packing keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LwePackingKeyswitchKey64> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(6);
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let packing_keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
+    /// let serialized = serialization_engine.serialize(&packing_keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(lwe_secret_key, recovered);
+    /// assert_eq!(packing_keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1961,38 +3677,40 @@ impl EntityDeserializationEngine<&[u8], LweSecretKey64> for DefaultSerialization
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweSecretKey64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LwePackingKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweSecretKey64 {
-            version: LweSecretKey64Version,
-            inner: ImplLweSecretKey<BinaryKeyKind, Vec<u64>>,
+        struct DeserializablePackingKeyswitchKey64 {
+            version: LwePackingKeyswitchKey64Version,
+            inner: ImplLwePackingKeyswitchKey<Vec<u64>>,
         }
-        let deserialized: DeserializableLweSecretKey64 = bincode::deserialize(serialized)
+        let deserialized: DeserializablePackingKeyswitchKey64 = bincode::deserialize(serialized)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweSecretKey64 {
-                version: LweSecretKey64Version::Unsupported,
+            DeserializablePackingKeyswitchKey64 {
+                version: LwePackingKeyswitchKey64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweSecretKey64 {
-                version: LweSecretKey64Version::V0,
+            DeserializablePackingKeyswitchKey64 {
+                version: LwePackingKeyswitchKey64Version::V0,
                 inner,
-            } => Ok(LweSecretKey64(inner)),
+            } => Ok(LwePackingKeyswitchKey64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSecretKey64 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LwePackingKeyswitchKey64 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a seeded LWE bootstrap key entity.
-impl EntityDeserializationEngine<&[u8], LweSeededBootstrapKey32> for DefaultSerializationEngine {
+/// operates on 32 bits integers. It deserializes a seeded LWE ciphertext keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LweSeededPackingKeyswitchKey32>
+    for DefaultSerializationEngine
+{
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -2004,25 +3722,34 @@ impl EntityDeserializationEngine<&[u8], LweSeededBootstrapKey32> for DefaultSeri
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
-    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
-    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
-    ///
-    /// let bsk: LweSeededBootstrapKey32 =
-    ///     engine.generate_new_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_packing_keyswitch_key: LweSeededPackingKeyswitchKey32 = engine
+    ///     .generate_new_lwe_seeded_packing_keyswitch_key(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let serialized = serialization_engine.serialize(&seeded_packing_keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    ///
-    /// assert_eq!(bsk, recovered);
+    /// assert_eq!(seeded_packing_keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2031,38 +3758,44 @@ impl EntityDeserializationEngine<&[u8], LweSeededBootstrapKey32> for DefaultSeri
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweSeededBootstrapKey32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LweSeededPackingKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweSeededBootstrapKey32 {
-            version: LweSeededBootstrapKey32Version,
-            inner: ImplStandardSeededBootstrapKey<Vec<u32>>,
+        struct DeserializableLweSeededPackingKeyswitchKey32 {
+            version: LweSeededPackingKeyswitchKey32Version,
+            inner: ImplLweSeededPackingKeyswitchKey<Vec<u32>>,
         }
-        let deserialized: DeserializableLweSeededBootstrapKey32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLweSeededPackingKeyswitchKey32 =
+            bincode::deserialize(serialized)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweSeededBootstrapKey32 {
-                version: LweSeededBootstrapKey32Version::Unsupported,
+            DeserializableLweSeededPackingKeyswitchKey32 {
+                version: LweSeededPackingKeyswitchKey32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweSeededBootstrapKey32 {
-                version: LweSeededBootstrapKey32Version::V0,
+            DeserializableLweSeededPackingKeyswitchKey32 {
+                version: LweSeededPackingKeyswitchKey32Version::V0,
                 inner,
-            } => Ok(LweSeededBootstrapKey32(inner)),
+            } => Ok(LweSeededPackingKeyswitchKey32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededBootstrapKey32 {
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededPackingKeyswitchKey32 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a seeded LWE bootstrap key entity.
-impl EntityDeserializationEngine<&[u8], LweSeededBootstrapKey64> for DefaultSerializationEngine {
+/// operates on 64 bits integers. It deserializes a seeded LWE ciphertext keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LweSeededPackingKeyswitchKey64>
+    for DefaultSerializationEngine
+{
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -2074,25 +3807,34 @@ impl EntityDeserializationEngine<&[u8], LweSeededBootstrapKey64> for DefaultSeri
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
-    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
-    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
-    ///
-    /// let bsk: LweSeededBootstrapKey64 =
-    ///     engine.generate_new_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_packing_keyswitch_key: LweSeededPackingKeyswitchKey64 = engine
+    ///     .generate_new_lwe_seeded_packing_keyswitch_key(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let serialized = serialization_engine.serialize(&seeded_packing_keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    ///
-    /// assert_eq!(bsk, recovered);
+    /// assert_eq!(seeded_packing_keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2101,66 +3843,87 @@ impl EntityDeserializationEngine<&[u8], LweSeededBootstrapKey64> for DefaultSeri
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweSeededBootstrapKey64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LweSeededPackingKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweSeededBootstrapKey64 {
-            version: LweSeededBootstrapKey64Version,
-            inner: ImplStandardSeededBootstrapKey<Vec<u64>>,
+        struct DeserializableLweSeededPackingKeyswitchKey64 {
+            version: LweSeededPackingKeyswitchKey64Version,
+            inner: ImplLweSeededPackingKeyswitchKey<Vec<u64>>,
         }
-        let deserialized: DeserializableLweSeededBootstrapKey64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLweSeededPackingKeyswitchKey64 =
+            bincode::deserialize(serialized)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweSeededBootstrapKey64 {
-                version: LweSeededBootstrapKey64Version::Unsupported,
+            DeserializableLweSeededPackingKeyswitchKey64 {
+                version: LweSeededPackingKeyswitchKey64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweSeededBootstrapKey64 {
-                version: LweSeededBootstrapKey64Version::V0,
+            DeserializableLweSeededPackingKeyswitchKey64 {
+                version: LweSeededPackingKeyswitchKey64Version::V0,
                 inner,
-            } => Ok(LweSeededBootstrapKey64(inner)),
+            } => Ok(LweSeededPackingKeyswitchKey64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededBootstrapKey64 {
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededPackingKeyswitchKey64 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a seeded LWE ciphertext entity.
-impl EntityDeserializationEngine<&[u8], LweSeededCiphertext32> for DefaultSerializationEngine {
+/// operates on 32 bits integers. It deserializes a seeded LWE ciphertext keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LweSeededPrivateFunctionalPackingKeyswitchKey32>
+    for DefaultSerializationEngine
+{
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::dispersion::{StandardDev, Variance};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = 3_u32 << 20;
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
-    ///
-    /// let ciphertext: LweSeededCiphertext32 =
-    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let val = vec![1_u32; output_key.polynomial_size().0];
+    /// let polynomial: CleartextVector32 = engine.create_cleartext_vector_from(&val)?;
+    /// let seeded_pfpksk: LweSeededPrivateFunctionalPackingKeyswitchKey32 = engine
+    ///     .generate_new_lwe_seeded_private_functional_packing_keyswitch_key(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         StandardDev(noise.get_standard_dev()),
+    ///         &|x| x,
+    ///         &polynomial,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let serialized = serialization_engine.serialize(&seeded_pfpksk)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext, recovered);
+    /// assert_eq!(seeded_pfpksk, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2169,66 +3932,90 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertext32> for DefaultSerial
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweSeededCiphertext32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<
+        LweSeededPrivateFunctionalPackingKeyswitchKey32,
+        EntityDeserializationError<Self::EngineError>,
+    > {
         #[derive(Deserialize)]
-        struct DeserializableLweSeededCiphertext32 {
-            version: LweSeededCiphertext32Version,
-            inner: ImplLweSeededCiphertext<u32>,
+        struct DeserializableLweSeededPrivateFunctionalPackingKeyswitchKey32 {
+            version: LweSeededPrivateFunctionalPackingKeyswitchKey32Version,
+            inner: ImplLweSeededPrivateFunctionalPackingKeyswitchKey<Vec<u32>>,
         }
-        let deserialized: DeserializableLweSeededCiphertext32 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLweSeededPrivateFunctionalPackingKeyswitchKey32 =
+            bincode::deserialize(serialized)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweSeededCiphertext32 {
-                version: LweSeededCiphertext32Version::Unsupported,
+            DeserializableLweSeededPrivateFunctionalPackingKeyswitchKey32 {
+                version: LweSeededPrivateFunctionalPackingKeyswitchKey32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweSeededCiphertext32 {
-                version: LweSeededCiphertext32Version::V0,
+            DeserializableLweSeededPrivateFunctionalPackingKeyswitchKey32 {
+                version: LweSeededPrivateFunctionalPackingKeyswitchKey32Version::V0,
                 inner,
-            } => Ok(LweSeededCiphertext32(inner)),
+            } => Ok(LweSeededPrivateFunctionalPackingKeyswitchKey32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertext32 {
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededPrivateFunctionalPackingKeyswitchKey32 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a seeded LWE ciphertext entity.
-impl EntityDeserializationEngine<&[u8], LweSeededCiphertext64> for DefaultSerializationEngine {
+/// operates on 64 bits integers. It deserializes a seeded LWE ciphertext keyswitch key entity.
+impl EntityDeserializationEngine<&[u8], LweSeededPrivateFunctionalPackingKeyswitchKey64>
+    for DefaultSerializationEngine
+{
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::dispersion::{StandardDev, Variance};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = 3_u64 << 50;
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
-    ///
-    /// let ciphertext: LweSeededCiphertext64 =
-    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let val = vec![1_u64; output_key.polynomial_size().0];
+    /// let polynomial: CleartextVector64 = engine.create_cleartext_vector_from(&val)?;
+    /// let seeded_pfpksk: LweSeededPrivateFunctionalPackingKeyswitchKey64 = engine
+    ///     .generate_new_lwe_seeded_private_functional_packing_keyswitch_key(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         StandardDev(noise.get_standard_dev()),
+    ///         &|x| x,
+    ///         &polynomial,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let serialized = serialization_engine.serialize(&seeded_pfpksk)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext, recovered);
+    /// assert_eq!(seeded_pfpksk, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2237,68 +4024,89 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertext64> for DefaultSerial
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweSeededCiphertext64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<
+        LweSeededPrivateFunctionalPackingKeyswitchKey64,
+        EntityDeserializationError<Self::EngineError>,
+    > {
         #[derive(Deserialize)]
-        struct DeserializableLweSeededCiphertext64 {
-            version: LweSeededCiphertext64Version,
-            inner: ImplLweSeededCiphertext<u64>,
+        struct DeserializableLweSeededPrivateFunctionalPackingKeyswitchKey64 {
+            version: LweSeededPrivateFunctionalPackingKeyswitchKey64Version,
+            inner: ImplLweSeededPrivateFunctionalPackingKeyswitchKey<Vec<u64>>,
         }
-        let deserialized: DeserializableLweSeededCiphertext64 = bincode::deserialize(serialized)
-            .map_err(DefaultSerializationError::Deserialization)
-            .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLweSeededPrivateFunctionalPackingKeyswitchKey64 =
+            bincode::deserialize(serialized)
+                .map_err(DefaultSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweSeededCiphertext64 {
-                version: LweSeededCiphertext64Version::Unsupported,
+            DeserializableLweSeededPrivateFunctionalPackingKeyswitchKey64 {
+                version: LweSeededPrivateFunctionalPackingKeyswitchKey64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweSeededCiphertext64 {
-                version: LweSeededCiphertext64Version::V0,
+            DeserializableLweSeededPrivateFunctionalPackingKeyswitchKey64 {
+                version: LweSeededPrivateFunctionalPackingKeyswitchKey64Version::V0,
                 inner,
-            } => Ok(LweSeededCiphertext64(inner)),
+            } => Ok(LweSeededPrivateFunctionalPackingKeyswitchKey64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertext64 {
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededPrivateFunctionalPackingKeyswitchKey64 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a seeded LWE ciphertext vector entity.
-impl EntityDeserializationEngine<&[u8], LweSeededCiphertextVector32>
-    for DefaultSerializationEngine
+/// operates on 32 bits integers. It deserializes a seeded LWE ciphertext keyswitch key entity.
+impl
+    EntityDeserializationEngine<
+        &[u8],
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    > for DefaultSerializationEngine
 {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(6);
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; 3];
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
-    ///
-    /// let mut ciphertext_vector: LweSeededCiphertextVector32 =
-    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_cbs_pfpksk: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 =
+    ///     engine.generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///         noise,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&seeded_cbs_pfpksk)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext_vector, recovered);
+    /// assert_eq!(seeded_cbs_pfpksk, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2307,69 +4115,89 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertextVector32>
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweSeededCiphertextVector32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        EntityDeserializationError<Self::EngineError>,
+    > {
         #[derive(Deserialize)]
-        struct DeserializableLweSeededCiphertextVector32 {
-            version: LweSeededCiphertextVector32Version,
-            inner: ImplLweSeededList<Vec<u32>>,
+        struct DeserializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+            version: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version,
+            inner: ImplLweSeededPrivateFunctionalPackingKeyswitchKeyList<Vec<u32>>,
         }
-        let deserialized: DeserializableLweSeededCiphertextVector32 =
-            bincode::deserialize(serialized)
-                .map_err(DefaultSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweSeededCiphertextVector32 {
-                version: LweSeededCiphertextVector32Version::Unsupported,
+            DeserializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+                version:
+                    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweSeededCiphertextVector32 {
-                version: LweSeededCiphertextVector32Version::V0,
+            DeserializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+                version: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version::V0,
                 inner,
-            } => Ok(LweSeededCiphertextVector32(inner)),
+            } => Ok(LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertextVector32 {
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a seeded LWE ciphertext vector entity.
-impl EntityDeserializationEngine<&[u8], LweSeededCiphertextVector64>
-    for DefaultSerializationEngine
+/// operates on 64 bits integers. It deserializes a seeded LWE ciphertext keyswitch key entity.
+impl
+    EntityDeserializationEngine<
+        &[u8],
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    > for DefaultSerializationEngine
 {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(6);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; 3];
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
-    ///
-    /// let mut ciphertext_vector: LweSeededCiphertextVector64 =
-    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_cbs_pfpksk: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 =
+    ///     engine.generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///         noise,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&seeded_cbs_pfpksk)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext_vector, recovered);
+    /// assert_eq!(seeded_cbs_pfpksk, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2378,75 +4206,74 @@ impl EntityDeserializationEngine<&[u8], LweSeededCiphertextVector64>
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweSeededCiphertextVector64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+        EntityDeserializationError<Self::EngineError>,
+    > {
         #[derive(Deserialize)]
-        struct DeserializableLweSeededCiphertextVector64 {
-            version: LweSeededCiphertextVector64Version,
-            inner: ImplLweSeededList<Vec<u64>>,
+        struct DeserializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+            version: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version,
+            inner: ImplLweSeededPrivateFunctionalPackingKeyswitchKeyList<Vec<u64>>,
         }
-        let deserialized: DeserializableLweSeededCiphertextVector64 =
-            bincode::deserialize(serialized)
-                .map_err(DefaultSerializationError::Deserialization)
-                .map_err(EntityDeserializationError::Engine)?;
+        let deserialized: DeserializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweSeededCiphertextVector64 {
-                version: LweSeededCiphertextVector64Version::Unsupported,
+            DeserializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+                version:
+                    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweSeededCiphertextVector64 {
-                version: LweSeededCiphertextVector64Version::V0,
+            DeserializableLweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+                version: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version::V0,
                 inner,
-            } => Ok(LweSeededCiphertextVector64(inner)),
+            } => Ok(LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededCiphertextVector64 {
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It deserializes a seeded LWE ciphertext keyswitch key entity.
-impl EntityDeserializationEngine<&[u8], LweSeededKeyswitchKey32> for DefaultSerializationEngine {
+/// operates on 32 bits integers. It deserializes a seeded LWE public key entity.
+impl EntityDeserializationEngine<&[u8], LweSeededPublicKey32> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
-    /// };
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let input_lwe_dimension = LweDimension(6);
-    /// let output_lwe_dimension = LweDimension(3);
-    /// let decomposition_level_count = DecompositionLevelCount(2);
-    /// let decomposition_base_log = DecompositionBaseLog(8);
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
-    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
     ///
-    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
-    ///     &input_key,
-    ///     &output_key,
-    ///     decomposition_level_count,
-    ///     decomposition_base_log,
+    /// let seeded_public_key: LweSeededPublicKey32 = engine.generate_new_lwe_seeded_public_key(
+    ///     &lwe_secret_key,
     ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
     /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_keyswitch_key)?;
+    /// let serialized = serialization_engine.serialize(&seeded_public_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_keyswitch_key, recovered);
+    /// assert_eq!(seeded_public_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2455,74 +4282,67 @@ impl EntityDeserializationEngine<&[u8], LweSeededKeyswitchKey32> for DefaultSeri
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweSeededKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LweSeededPublicKey32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweSeededKeyswitchKey32 {
-            version: LweSeededKeyswitchKey32Version,
-            inner: ImplLweSeededKeyswitchKey<Vec<u32>>,
+        struct DeserializableLweSeededPublicKey32 {
+            version: LweSeededPublicKey32Version,
+            inner: ImplLweSeededList<Vec<u32>>,
         }
-        let deserialized: DeserializableLweSeededKeyswitchKey32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSeededPublicKey32 = bincode::deserialize(serialized)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweSeededKeyswitchKey32 {
-                version: LweSeededKeyswitchKey32Version::Unsupported,
+            DeserializableLweSeededPublicKey32 {
+                version: LweSeededPublicKey32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweSeededKeyswitchKey32 {
-                version: LweSeededKeyswitchKey32Version::V0,
+            DeserializableLweSeededPublicKey32 {
+                version: LweSeededPublicKey32Version::V0,
                 inner,
-            } => Ok(LweSeededKeyswitchKey32(inner)),
+            } => Ok(LweSeededPublicKey32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededKeyswitchKey32 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededPublicKey32 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a seeded LWE ciphertext keyswitch key entity.
-impl EntityDeserializationEngine<&[u8], LweSeededKeyswitchKey64> for DefaultSerializationEngine {
+/// operates on 64 bits integers. It deserializes a seeded LWE public key entity.
+impl EntityDeserializationEngine<&[u8], LweSeededPublicKey64> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
-    /// };
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let input_lwe_dimension = LweDimension(6);
-    /// let output_lwe_dimension = LweDimension(3);
-    /// let decomposition_level_count = DecompositionLevelCount(2);
-    /// let decomposition_base_log = DecompositionBaseLog(8);
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
-    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
     ///
-    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
-    ///     &input_key,
-    ///     &output_key,
-    ///     decomposition_level_count,
-    ///     decomposition_base_log,
+    /// let seeded_public_key: LweSeededPublicKey64 = engine.generate_new_lwe_seeded_public_key(
+    ///     &lwe_secret_key,
     ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
     /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_keyswitch_key)?;
+    /// let serialized = serialization_engine.serialize(&seeded_public_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_keyswitch_key, recovered);
+    /// assert_eq!(seeded_public_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2531,74 +4351,69 @@ impl EntityDeserializationEngine<&[u8], LweSeededKeyswitchKey64> for DefaultSeri
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LweSeededKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LweSeededPublicKey64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializableLweSeededKeyswitchKey64 {
-            version: LweSeededKeyswitchKey64Version,
-            inner: ImplLweSeededKeyswitchKey<Vec<u64>>,
+        struct DeserializableLweSeededPublicKey64 {
+            version: LweSeededPublicKey64Version,
+            inner: ImplLweSeededList<Vec<u64>>,
         }
-        let deserialized: DeserializableLweSeededKeyswitchKey64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweSeededPublicKey64 = bincode::deserialize(serialized)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializableLweSeededKeyswitchKey64 {
-                version: LweSeededKeyswitchKey64Version::Unsupported,
+            DeserializableLweSeededPublicKey64 {
+                version: LweSeededPublicKey64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializableLweSeededKeyswitchKey64 {
-                version: LweSeededKeyswitchKey64Version::V0,
+            DeserializableLweSeededPublicKey64 {
+                version: LweSeededPublicKey64Version::V0,
                 inner,
-            } => Ok(LweSeededKeyswitchKey64(inner)),
+            } => Ok(LweSeededPublicKey64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededKeyswitchKey64 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweSeededPublicKey64 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 32 bits integers. It serializes a packing keyswitch key entity.
-impl EntityDeserializationEngine<&[u8], LwePackingKeyswitchKey32> for DefaultSerializationEngine {
-    /// # Example:
+/// operates on 32 bits integers. It deserializes a LWE ternary bootstrap key entity.
+impl EntityDeserializationEngine<&[u8], LweTernaryBootstrapKey32> for DefaultSerializationEngine {
+    /// # Example
     /// ```
     /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::key_kinds::TernaryKeyKind;
     /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
     /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let input_lwe_dimension = LweDimension(6);
-    /// let output_lwe_dimension = LweDimension(3);
-    /// let decomposition_level_count = DecompositionLevelCount(2);
-    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
-    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let lwe_sk: LweSecretKey32<TernaryKeyKind> =
+    ///     engine.generate_new_lwe_secret_key_with_kind(lwe_dim, TernaryKeyKind)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
     ///
-    /// let packing_keyswitch_key = engine.generate_new_lwe_keyswitch_key(
-    ///     &input_key,
-    ///     &output_key,
-    ///     decomposition_level_count,
-    ///     decomposition_base_log,
-    ///     noise,
-    /// )?;
+    /// let bsk: LweTernaryBootstrapKey32 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&packing_keyswitch_key)?;
+    /// let serialized = serialization_engine.serialize(&bsk)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(packing_keyswitch_key, recovered);
+    /// assert_eq!(bsk, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2607,74 +4422,69 @@ impl EntityDeserializationEngine<&[u8], LwePackingKeyswitchKey32> for DefaultSer
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LwePackingKeyswitchKey32, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LweTernaryBootstrapKey32, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializablePackingKeyswitchKey32 {
-            version: LwePackingKeyswitchKey32Version,
-            inner: ImplLwePackingKeyswitchKey<Vec<u32>>,
+        struct DeserializableLweTernaryBootstrapKey32 {
+            version: LweTernaryBootstrapKey32Version,
+            inner: ImplStandardBootstrapKey<Vec<u32>>,
         }
-        let deserialized: DeserializablePackingKeyswitchKey32 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweTernaryBootstrapKey32 = bincode::deserialize(serialized)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializablePackingKeyswitchKey32 {
-                version: LwePackingKeyswitchKey32Version::Unsupported,
+            DeserializableLweTernaryBootstrapKey32 {
+                version: LweTernaryBootstrapKey32Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializablePackingKeyswitchKey32 {
-                version: LwePackingKeyswitchKey32Version::V0,
+            DeserializableLweTernaryBootstrapKey32 {
+                version: LweTernaryBootstrapKey32Version::V0,
                 inner,
-            } => Ok(LwePackingKeyswitchKey32(inner)),
+            } => Ok(LweTernaryBootstrapKey32(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LwePackingKeyswitchKey32 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweTernaryBootstrapKey32 {
         self.deserialize(serialized).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
-/// operates on 64 bits integers. It deserializes a packing keyswitch key entity.
-impl EntityDeserializationEngine<&[u8], LwePackingKeyswitchKey64> for DefaultSerializationEngine {
-    /// # Example:
+/// operates on 64 bits integers. It deserializes a LWE ternary bootstrap key entity.
+impl EntityDeserializationEngine<&[u8], LweTernaryBootstrapKey64> for DefaultSerializationEngine {
+    /// # Example
     /// ```
     /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::key_kinds::TernaryKeyKind;
     /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
     /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let input_lwe_dimension = LweDimension(6);
-    /// let output_lwe_dimension = LweDimension(3);
-    /// let decomposition_level_count = DecompositionLevelCount(2);
-    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
-    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let lwe_sk: LweSecretKey64<TernaryKeyKind> =
+    ///     engine.generate_new_lwe_secret_key_with_kind(lwe_dim, TernaryKeyKind)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
     ///
-    /// let packing_keyswitch_key = engine.generate_new_lwe_keyswitch_key(
-    ///     &input_key,
-    ///     &output_key,
-    ///     decomposition_level_count,
-    ///     decomposition_base_log,
-    ///     noise,
-    /// )?;
+    /// let bsk: LweTernaryBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&packing_keyswitch_key)?;
+    /// let serialized = serialization_engine.serialize(&bsk)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(packing_keyswitch_key, recovered);
+    /// assert_eq!(bsk, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2683,30 +4493,30 @@ impl EntityDeserializationEngine<&[u8], LwePackingKeyswitchKey64> for DefaultSer
     fn deserialize(
         &mut self,
         serialized: &[u8],
-    ) -> Result<LwePackingKeyswitchKey64, EntityDeserializationError<Self::EngineError>> {
+    ) -> Result<LweTernaryBootstrapKey64, EntityDeserializationError<Self::EngineError>> {
         #[derive(Deserialize)]
-        struct DeserializablePackingKeyswitchKey64 {
-            version: LwePackingKeyswitchKey64Version,
-            inner: ImplLwePackingKeyswitchKey<Vec<u64>>,
+        struct DeserializableLweTernaryBootstrapKey64 {
+            version: LweTernaryBootstrapKey64Version,
+            inner: ImplStandardBootstrapKey<Vec<u64>>,
         }
-        let deserialized: DeserializablePackingKeyswitchKey64 = bincode::deserialize(serialized)
+        let deserialized: DeserializableLweTernaryBootstrapKey64 = bincode::deserialize(serialized)
             .map_err(DefaultSerializationError::Deserialization)
             .map_err(EntityDeserializationError::Engine)?;
         match deserialized {
-            DeserializablePackingKeyswitchKey64 {
-                version: LwePackingKeyswitchKey64Version::Unsupported,
+            DeserializableLweTernaryBootstrapKey64 {
+                version: LweTernaryBootstrapKey64Version::Unsupported,
                 ..
             } => Err(EntityDeserializationError::Engine(
                 DefaultSerializationError::UnsupportedVersion,
             )),
-            DeserializablePackingKeyswitchKey64 {
-                version: LwePackingKeyswitchKey64Version::V0,
+            DeserializableLweTernaryBootstrapKey64 {
+                version: LweTernaryBootstrapKey64Version::V0,
                 inner,
-            } => Ok(LwePackingKeyswitchKey64(inner)),
+            } => Ok(LweTernaryBootstrapKey64(inner)),
         }
     }
 
-    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LwePackingKeyswitchKey64 {
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweTernaryBootstrapKey64 {
         self.deserialize(serialized).unwrap()
     }
 }
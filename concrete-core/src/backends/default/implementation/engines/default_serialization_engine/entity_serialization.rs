@@ -14,18 +14,23 @@ use crate::commons::crypto::ggsw::{
     StandardGgswSeededCiphertext as ImplStandardGgswSeededCiphertext,
 };
 use crate::commons::crypto::glwe::{
-    GlweCiphertext as ImplGlweCiphertext, GlweList as ImplGlweList,
-    GlweSeededCiphertext as ImplGlweSeededCiphertext, GlweSeededList as ImplGlweSeededList,
+    GlweAutomorphismKey as ImplGlweAutomorphismKey, GlweCiphertext as ImplGlweCiphertext,
+    GlweKeyswitchKey as ImplGlweKeyswitchKey, GlweList as ImplGlweList,
+    GlwePublicKey as ImplGlwePublicKey, GlweRelinearizationKey as ImplGlweRelinearizationKey,
+    GlweSeededCiphertext as ImplGlweSeededCiphertext,
+    GlweSeededKeyswitchKey as ImplGlweSeededKeyswitchKey, GlweSeededList as ImplGlweSeededList,
     LwePackingKeyswitchKey as ImplLwePackingKeyswitchKey,
+    LweSeededPackingKeyswitchKey as ImplLweSeededPackingKeyswitchKey,
+    LweSeededPrivateFunctionalPackingKeyswitchKey as ImplLweSeededPrivateFunctionalPackingKeyswitchKey,
+    LweSeededPrivateFunctionalPackingKeyswitchKeyList as ImplLweSeededPrivateFunctionalPackingKeyswitchKeyList,
 };
 use crate::commons::crypto::lwe::{
-    LweCiphertext as ImplLweCiphertext, LweKeyswitchKey as ImplLweKeyswitchKey,
-    LweList as ImplLweList, LweSeededCiphertext as ImplLweSeededCiphertext,
+    LweCiphertext as ImplLweCiphertext, LweCompressedList as ImplLweCompressedList,
+    LweKeyswitchKey as ImplLweKeyswitchKey, LweList as ImplLweList,
+    LweSeededCiphertext as ImplLweSeededCiphertext,
     LweSeededKeyswitchKey as ImplLweSeededKeyswitchKey, LweSeededList as ImplLweSeededList,
 };
-use crate::commons::crypto::secret::{
-    GlweSecretKey as ImplGlweSecretKey, LweSecretKey as ImplLweSecretKey,
-};
+use crate::commons::crypto::secret::RandomKeyKind;
 use crate::prelude::{
     Cleartext32, Cleartext32Version, Cleartext64, Cleartext64Version, CleartextF64,
     CleartextF64Version, CleartextVector32, CleartextVector32Version, CleartextVector64,
@@ -34,34 +39,56 @@ use crate::prelude::{
     EntitySerializationError, FloatEncoder, FloatEncoderVector, FloatEncoderVectorVersion,
     FloatEncoderVersion, GgswCiphertext32, GgswCiphertext32Version, GgswCiphertext64,
     GgswCiphertext64Version, GgswSeededCiphertext32, GgswSeededCiphertext32Version,
-    GgswSeededCiphertext64, GgswSeededCiphertext64Version, GlweCiphertext32,
-    GlweCiphertext32Version, GlweCiphertext64, GlweCiphertext64Version, GlweCiphertextMutView32,
-    GlweCiphertextMutView64, GlweCiphertextVector32, GlweCiphertextVector32Version,
-    GlweCiphertextVector64, GlweCiphertextVector64Version, GlweCiphertextView32,
-    GlweCiphertextView64, GlweSecretKey32, GlweSecretKey32Version, GlweSecretKey64,
-    GlweSecretKey64Version, GlweSeededCiphertext32, GlweSeededCiphertext32Version,
-    GlweSeededCiphertext64, GlweSeededCiphertext64Version, GlweSeededCiphertextVector32,
+    GgswSeededCiphertext64, GgswSeededCiphertext64Version, GlweAutomorphismKey32,
+    GlweAutomorphismKey32Version, GlweAutomorphismKey64, GlweAutomorphismKey64Version,
+    GlweCiphertext32, GlweCiphertext32Version, GlweCiphertext64, GlweCiphertext64Version,
+    GlweCiphertextMutView32, GlweCiphertextMutView64, GlweCiphertextVector32,
+    GlweCiphertextVector32Version, GlweCiphertextVector64, GlweCiphertextVector64Version,
+    GlweCiphertextView32, GlweCiphertextView64, GlweKeyswitchKey32, GlweKeyswitchKey32Version,
+    GlweKeyswitchKey64, GlweKeyswitchKey64Version, GlwePublicKey32, GlwePublicKey32Version,
+    GlwePublicKey64, GlwePublicKey64Version, GlweRelinearizationKey32,
+    GlweRelinearizationKey32Version, GlweRelinearizationKey64, GlweRelinearizationKey64Version,
+    GlweSecretKey32, GlweSecretKey32Version, GlweSecretKey64, GlweSecretKey64Version,
+    GlweSeededCiphertext32, GlweSeededCiphertext32Version, GlweSeededCiphertext64,
+    GlweSeededCiphertext64Version, GlweSeededCiphertextVector32,
     GlweSeededCiphertextVector32Version, GlweSeededCiphertextVector64,
-    GlweSeededCiphertextVector64Version, LweBootstrapKey32, LweBootstrapKey32Version,
-    LweBootstrapKey64, LweBootstrapKey64Version, LweCiphertext32, LweCiphertext32Version,
-    LweCiphertext64, LweCiphertext64Version, LweCiphertextMutView32, LweCiphertextMutView64,
-    LweCiphertextVector32, LweCiphertextVector32Version, LweCiphertextVector64,
-    LweCiphertextVector64Version, LweCiphertextVectorMutView32, LweCiphertextVectorMutView64,
-    LweCiphertextVectorView32, LweCiphertextVectorView64, LweCiphertextView32, LweCiphertextView64,
-    LweKeyswitchKey32, LweKeyswitchKey32Version, LweKeyswitchKey64, LweKeyswitchKey64Version,
-    LwePackingKeyswitchKey32, LwePackingKeyswitchKey32Version, LwePackingKeyswitchKey64,
-    LwePackingKeyswitchKey64Version, LweSecretKey32, LweSecretKey32Version, LweSecretKey64,
-    LweSecretKey64Version, LweSeededBootstrapKey32, LweSeededBootstrapKey32Version,
-    LweSeededBootstrapKey64, LweSeededBootstrapKey64Version, LweSeededCiphertext32,
-    LweSeededCiphertext32Version, LweSeededCiphertext64, LweSeededCiphertext64Version,
-    LweSeededCiphertextVector32, LweSeededCiphertextVector32Version, LweSeededCiphertextVector64,
-    LweSeededCiphertextVector64Version, LweSeededKeyswitchKey32, LweSeededKeyswitchKey32Version,
-    LweSeededKeyswitchKey64, LweSeededKeyswitchKey64Version, Plaintext32, Plaintext32Version,
-    Plaintext64, Plaintext64Version, PlaintextVector32, PlaintextVector32Version,
-    PlaintextVector64, PlaintextVector64Version,
+    GlweSeededCiphertextVector64Version, GlweSeededKeyswitchKey32, GlweSeededKeyswitchKey32Version,
+    GlweSeededKeyswitchKey64, GlweSeededKeyswitchKey64Version, LweBootstrapKey32,
+    LweBootstrapKey32Version, LweBootstrapKey64, LweBootstrapKey64Version, LweCiphertext32,
+    LweCiphertext32Version, LweCiphertext64, LweCiphertext64Version, LweCiphertextMutView32,
+    LweCiphertextMutView64, LweCiphertextVector32, LweCiphertextVector32Version,
+    LweCiphertextVector64, LweCiphertextVector64Version, LweCiphertextVectorMutView32,
+    LweCiphertextVectorMutView64, LweCiphertextVectorView32, LweCiphertextVectorView64,
+    LweCiphertextView32, LweCiphertextView64, LweCompressedCiphertextVector32,
+    LweCompressedCiphertextVector32Version, LweCompressedCiphertextVector64,
+    LweCompressedCiphertextVector64Version, LweKeyswitchKey32, LweKeyswitchKey32Version,
+    LweKeyswitchKey64, LweKeyswitchKey64Version, LwePackingKeyswitchKey32,
+    LwePackingKeyswitchKey32Version, LwePackingKeyswitchKey64, LwePackingKeyswitchKey64Version,
+    LweSecretKey32, LweSecretKey32Version, LweSecretKey64, LweSecretKey64Version,
+    LweSeededBootstrapKey32, LweSeededBootstrapKey32Version, LweSeededBootstrapKey64,
+    LweSeededBootstrapKey64Version, LweSeededCiphertext32, LweSeededCiphertext32Version,
+    LweSeededCiphertext64, LweSeededCiphertext64Version, LweSeededCiphertextVector32,
+    LweSeededCiphertextVector32Version, LweSeededCiphertextVector64,
+    LweSeededCiphertextVector64Version,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version,
+    LweSeededKeyswitchKey32, LweSeededKeyswitchKey32Version, LweSeededKeyswitchKey64,
+    LweSeededKeyswitchKey64Version, LweSeededPackingKeyswitchKey32,
+    LweSeededPackingKeyswitchKey32Version, LweSeededPackingKeyswitchKey64,
+    LweSeededPackingKeyswitchKey64Version, LweSeededPrivateFunctionalPackingKeyswitchKey32,
+    LweSeededPrivateFunctionalPackingKeyswitchKey32Version,
+    LweSeededPrivateFunctionalPackingKeyswitchKey64,
+    LweSeededPrivateFunctionalPackingKeyswitchKey64Version, LweSeededPublicKey32,
+    LweSeededPublicKey32Version, LweSeededPublicKey64, LweSeededPublicKey64Version,
+    LweTernaryBootstrapKey32, LweTernaryBootstrapKey32Version, LweTernaryBootstrapKey64,
+    LweTernaryBootstrapKey64Version, Plaintext32, Plaintext32Version, Plaintext64,
+    Plaintext64Version, PlaintextVector32, PlaintextVector32Version, PlaintextVector64,
+    PlaintextVector64Version,
 };
-use concrete_commons::key_kinds::BinaryKeyKind;
 use serde::Serialize;
+use std::fmt::Debug;
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
@@ -1064,30 +1091,46 @@ impl EntitySerializationEngine<GlweCiphertextVector64, Vec<u8>> for DefaultSeria
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a GLWE secret key entity.
-impl EntitySerializationEngine<GlweSecretKey32, Vec<u8>> for DefaultSerializationEngine {
+/// on 32 bits integers. It serializes a GLWE automorphism key entity.
+impl EntitySerializationEngine<GlweAutomorphismKey32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
-    /// let polynomial_size = PolynomialSize(4);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let exponents = [AutomorphismExponent(3), AutomorphismExponent(5)];
+    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let glwe_secret_key: GlweSecretKey32 =
+    /// let key: GlweSecretKey32 =
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     ///
+    /// let automorphism_key: GlweAutomorphismKey32 = engine.generate_new_glwe_automorphism_key(
+    ///     &key,
+    ///     &exponents,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
+    /// let serialized = serialization_engine.serialize(&automorphism_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(glwe_secret_key, recovered);
+    /// assert_eq!(automorphism_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1095,15 +1138,15 @@ impl EntitySerializationEngine<GlweSecretKey32, Vec<u8>> for DefaultSerializatio
     /// ```
     fn serialize(
         &mut self,
-        entity: &GlweSecretKey32,
+        entity: &GlweAutomorphismKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweSecretKey32<'a> {
-            version: GlweSecretKey32Version,
-            inner: &'a ImplGlweSecretKey<BinaryKeyKind, Vec<u32>>,
+        struct SerializableGlweAutomorphismKey32<'a> {
+            version: GlweAutomorphismKey32Version,
+            inner: &'a ImplGlweAutomorphismKey<Vec<u32>>,
         }
-        let serializable = SerializableGlweSecretKey32 {
-            version: GlweSecretKey32Version::V0,
+        let serializable = SerializableGlweAutomorphismKey32 {
+            version: GlweAutomorphismKey32Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -1111,37 +1154,53 @@ impl EntitySerializationEngine<GlweSecretKey32, Vec<u8>> for DefaultSerializatio
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweSecretKey32) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweAutomorphismKey32) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a GLWE secret key entity.
-impl EntitySerializationEngine<GlweSecretKey64, Vec<u8>> for DefaultSerializationEngine {
+/// on 64 bits integers. It serializes a GLWE automorphism key entity.
+impl EntitySerializationEngine<GlweAutomorphismKey64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
-    /// let polynomial_size = PolynomialSize(4);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let exponents = [AutomorphismExponent(3), AutomorphismExponent(5)];
+    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let glwe_secret_key: GlweSecretKey64 =
+    /// let key: GlweSecretKey64 =
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     ///
+    /// let automorphism_key: GlweAutomorphismKey64 = engine.generate_new_glwe_automorphism_key(
+    ///     &key,
+    ///     &exponents,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
+    /// let serialized = serialization_engine.serialize(&automorphism_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(glwe_secret_key, recovered);
+    /// assert_eq!(automorphism_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1149,15 +1208,15 @@ impl EntitySerializationEngine<GlweSecretKey64, Vec<u8>> for DefaultSerializatio
     /// ```
     fn serialize(
         &mut self,
-        entity: &GlweSecretKey64,
+        entity: &GlweAutomorphismKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweSecretKey64<'a> {
-            version: GlweSecretKey64Version,
-            inner: &'a ImplGlweSecretKey<BinaryKeyKind, Vec<u64>>,
+        struct SerializableGlweAutomorphismKey64<'a> {
+            version: GlweAutomorphismKey64Version,
+            inner: &'a ImplGlweAutomorphismKey<Vec<u64>>,
         }
-        let serializable = SerializableGlweSecretKey64 {
-            version: GlweSecretKey64Version::V0,
+        let serializable = SerializableGlweAutomorphismKey64 {
+            version: GlweAutomorphismKey64Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -1165,46 +1224,54 @@ impl EntitySerializationEngine<GlweSecretKey64, Vec<u8>> for DefaultSerializatio
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweSecretKey64) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweAutomorphismKey64) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a seeded GLWE ciphertext entity.
-impl EntitySerializationEngine<GlweSeededCiphertext32, Vec<u8>> for DefaultSerializationEngine {
+/// on 32 bits integers. It serializes a GLWE keyswitch key entity.
+impl EntitySerializationEngine<GlweKeyswitchKey32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let glwe_dimension = GlweDimension(2);
-    /// let polynomial_size = PolynomialSize(4);
-    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_glwe_dimension = GlweDimension(3);
+    /// let output_glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: GlweSecretKey32 =
-    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
-    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let input_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, polynomial_size)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
     ///
-    /// let seeded_ciphertext =
-    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let keyswitch_key: GlweKeyswitchKey32 = engine.generate_new_glwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_ciphertext)?;
+    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_ciphertext, recovered);
+    /// assert_eq!(keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1212,15 +1279,15 @@ impl EntitySerializationEngine<GlweSeededCiphertext32, Vec<u8>> for DefaultSeria
     /// ```
     fn serialize(
         &mut self,
-        entity: &GlweSeededCiphertext32,
+        entity: &GlweKeyswitchKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweSeededCiphertext32<'a> {
-            version: GlweSeededCiphertext32Version,
-            inner: &'a ImplGlweSeededCiphertext<Vec<u32>>,
+        struct SerializableGlweKeyswitchKey32<'a> {
+            version: GlweKeyswitchKey32Version,
+            inner: &'a ImplGlweKeyswitchKey<Vec<u32>>,
         }
-        let serializable = SerializableGlweSeededCiphertext32 {
-            version: GlweSeededCiphertext32Version::V0,
+        let serializable = SerializableGlweKeyswitchKey32 {
+            version: GlweKeyswitchKey32Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -1228,46 +1295,54 @@ impl EntitySerializationEngine<GlweSeededCiphertext32, Vec<u8>> for DefaultSeria
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertext32) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweKeyswitchKey32) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a seeded GLWE ciphertext entity.
-impl EntitySerializationEngine<GlweSeededCiphertext64, Vec<u8>> for DefaultSerializationEngine {
+/// on 64 bits integers. It serializes a GLWE keyswitch key entity.
+impl EntitySerializationEngine<GlweKeyswitchKey64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let glwe_dimension = GlweDimension(2);
-    /// let polynomial_size = PolynomialSize(4);
-    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let input_glwe_dimension = GlweDimension(3);
+    /// let output_glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: GlweSecretKey64 =
-    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
-    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
+    /// let input_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, polynomial_size)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
     ///
-    /// let seeded_ciphertext =
-    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let keyswitch_key: GlweKeyswitchKey64 = engine.generate_new_glwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_ciphertext)?;
+    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_ciphertext, recovered);
+    /// assert_eq!(keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1275,15 +1350,15 @@ impl EntitySerializationEngine<GlweSeededCiphertext64, Vec<u8>> for DefaultSeria
     /// ```
     fn serialize(
         &mut self,
-        entity: &GlweSeededCiphertext64,
+        entity: &GlweKeyswitchKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweSeededCiphertext64<'a> {
-            version: GlweSeededCiphertext64Version,
-            inner: &'a ImplGlweSeededCiphertext<Vec<u64>>,
+        struct SerializableGlweKeyswitchKey64<'a> {
+            version: GlweKeyswitchKey64Version,
+            inner: &'a ImplGlweKeyswitchKey<Vec<u64>>,
         }
-        let serializable = SerializableGlweSeededCiphertext64 {
-            version: GlweSeededCiphertext64Version::V0,
+        let serializable = SerializableGlweKeyswitchKey64 {
+            version: GlweKeyswitchKey64Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -1291,47 +1366,47 @@ impl EntitySerializationEngine<GlweSeededCiphertext64, Vec<u8>> for DefaultSeria
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertext64) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweKeyswitchKey64) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a seeded GLWE ciphertext vector entity.
-impl EntitySerializationEngine<GlweSeededCiphertextVector32, Vec<u8>>
-    for DefaultSerializationEngine
-{
+/// on 32 bits integers. It serializes a GLWE public key entity.
+impl EntitySerializationEngine<GlwePublicKey32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, GlwePublicKeyZeroEncryptionCount, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
-    /// let polynomial_size = PolynomialSize(4);
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; 8];
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let polynomial_size = PolynomialSize(256);
+    /// let noise = Variance(2_f64.powf(-50.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: GlweSecretKey32 =
+    /// let secret_key: GlweSecretKey32 =
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
-    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let seeded_ciphertext_vector =
-    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let public_key: GlwePublicKey32 = engine.generate_new_glwe_public_key(
+    ///     &secret_key,
+    ///     noise,
+    ///     GlwePublicKeyZeroEncryptionCount(1),
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&public_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    /// assert_eq!(public_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1339,15 +1414,15 @@ impl EntitySerializationEngine<GlweSeededCiphertextVector32, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &GlweSeededCiphertextVector32,
+        entity: &GlwePublicKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweSeededCiphertextVector32<'a> {
-            version: GlweSeededCiphertextVector32Version,
-            inner: &'a ImplGlweSeededList<Vec<u32>>,
+        struct SerializableGlwePublicKey32<'a> {
+            version: GlwePublicKey32Version,
+            inner: &'a ImplGlwePublicKey<Vec<u32>>,
         }
-        let serializable = SerializableGlweSeededCiphertextVector32 {
-            version: GlweSeededCiphertextVector32Version::V0,
+        let serializable = SerializableGlwePublicKey32 {
+            version: GlwePublicKey32Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -1355,47 +1430,47 @@ impl EntitySerializationEngine<GlweSeededCiphertextVector32, Vec<u8>>
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertextVector32) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &GlwePublicKey32) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a seeded GLWE ciphertext vector entity.
-impl EntitySerializationEngine<GlweSeededCiphertextVector64, Vec<u8>>
-    for DefaultSerializationEngine
-{
+/// on 64 bits integers. It serializes a GLWE public key entity.
+impl EntitySerializationEngine<GlwePublicKey64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, GlwePublicKeyZeroEncryptionCount, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let glwe_dimension = GlweDimension(2);
-    /// let polynomial_size = PolynomialSize(4);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; 8];
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let polynomial_size = PolynomialSize(256);
+    /// let noise = Variance(2_f64.powf(-50.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: GlweSecretKey64 =
+    /// let secret_key: GlweSecretKey64 =
     ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
-    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let seeded_ciphertext_vector =
-    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let public_key: GlwePublicKey64 = engine.generate_new_glwe_public_key(
+    ///     &secret_key,
+    ///     noise,
+    ///     GlwePublicKeyZeroEncryptionCount(1),
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&public_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_ciphertext_vector, recovered);
+    /// assert_eq!(public_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1403,15 +1478,15 @@ impl EntitySerializationEngine<GlweSeededCiphertextVector64, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &GlweSeededCiphertextVector64,
+        entity: &GlwePublicKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableGlweSeededCiphertextVector64<'a> {
-            version: GlweSeededCiphertextVector64Version,
-            inner: &'a ImplGlweSeededList<Vec<u64>>,
+        struct SerializableGlwePublicKey64<'a> {
+            version: GlwePublicKey64Version,
+            inner: &'a ImplGlwePublicKey<Vec<u64>>,
         }
-        let serializable = SerializableGlweSeededCiphertextVector64 {
-            version: GlweSeededCiphertextVector64Version::V0,
+        let serializable = SerializableGlwePublicKey64 {
+            version: GlwePublicKey64Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -1419,44 +1494,51 @@ impl EntitySerializationEngine<GlweSeededCiphertextVector64, Vec<u8>>
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertextVector64) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &GlwePublicKey64) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a LWE bootstrap key entity.
-impl EntitySerializationEngine<LweBootstrapKey32, Vec<u8>> for DefaultSerializationEngine {
-    /// # Example
+/// on 32 bits integers. It serializes a GLWE relinearization key entity.
+impl EntitySerializationEngine<GlweRelinearizationKey32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
     /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
     /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
-    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
-    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     ///
-    /// let bsk: LweBootstrapKey32 =
-    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let relinearization_key: GlweRelinearizationKey32 = engine
+    ///     .generate_new_glwe_relinearization_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let serialized = serialization_engine.serialize(&relinearization_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(bsk, recovered);
+    /// assert_eq!(relinearization_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1464,15 +1546,15 @@ impl EntitySerializationEngine<LweBootstrapKey32, Vec<u8>> for DefaultSerializat
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweBootstrapKey32,
+        entity: &GlweRelinearizationKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweBootstrapKey32<'a> {
-            version: LweBootstrapKey32Version,
-            inner: &'a ImplStandardBootstrapKey<Vec<u32>>,
+        struct SerializableGlweRelinearizationKey32<'a> {
+            version: GlweRelinearizationKey32Version,
+            inner: &'a ImplGlweRelinearizationKey<Vec<u32>>,
         }
-        let serializable = SerializableLweBootstrapKey32 {
-            version: LweBootstrapKey32Version::V0,
+        let serializable = SerializableGlweRelinearizationKey32 {
+            version: GlweRelinearizationKey32Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -1480,44 +1562,51 @@ impl EntitySerializationEngine<LweBootstrapKey32, Vec<u8>> for DefaultSerializat
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweBootstrapKey32) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweRelinearizationKey32) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a LWE bootstrap key entity.
-impl EntitySerializationEngine<LweBootstrapKey64, Vec<u8>> for DefaultSerializationEngine {
-    /// # Example
+/// on 64 bits integers. It serializes a GLWE relinearization key entity.
+impl EntitySerializationEngine<GlweRelinearizationKey64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
     /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
     /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
-    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
-    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     ///
-    /// let bsk: LweBootstrapKey64 =
-    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let relinearization_key: GlweRelinearizationKey64 = engine
+    ///     .generate_new_glwe_relinearization_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let serialized = serialization_engine.serialize(&relinearization_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(bsk, recovered);
+    /// assert_eq!(relinearization_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1525,15 +1614,15 @@ impl EntitySerializationEngine<LweBootstrapKey64, Vec<u8>> for DefaultSerializat
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweBootstrapKey64,
+        entity: &GlweRelinearizationKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweBootstrapKey64<'a> {
-            version: LweBootstrapKey64Version,
-            inner: &'a ImplStandardBootstrapKey<Vec<u64>>,
+        struct SerializableGlweRelinearizationKey64<'a> {
+            version: GlweRelinearizationKey64Version,
+            inner: &'a ImplGlweRelinearizationKey<Vec<u64>>,
         }
-        let serializable = SerializableLweBootstrapKey64 {
-            version: LweBootstrapKey64Version::V0,
+        let serializable = SerializableGlweRelinearizationKey64 {
+            version: GlweRelinearizationKey64Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -1541,42 +1630,40 @@ impl EntitySerializationEngine<LweBootstrapKey64, Vec<u8>> for DefaultSerializat
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweBootstrapKey64) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweRelinearizationKey64) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a LWE ciphertext entity.
-impl EntitySerializationEngine<LweCiphertext32, Vec<u8>> for DefaultSerializationEngine {
+/// on 32 bits integers. It serializes a GLWE secret key entity.
+impl<Kind> EntitySerializationEngine<GlweSecretKey32<Kind>, Vec<u8>> for DefaultSerializationEngine
+where
+    Kind: RandomKeyKind + Debug,
+{
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = 3_u32 << 20;
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
-    ///
-    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let glwe_secret_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext, recovered);
+    /// assert_eq!(glwe_secret_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1584,121 +1671,105 @@ impl EntitySerializationEngine<LweCiphertext32, Vec<u8>> for DefaultSerializatio
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertext32,
+        entity: &GlweSecretKey32<Kind>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweCiphertext32<'a> {
-            version: LweCiphertext32Version,
-            inner: &'a ImplLweCiphertext<Vec<u32>>,
-        }
-        let serializable = SerializableLweCiphertext32 {
-            version: LweCiphertext32Version::V0,
-            inner: &entity.0,
-        };
+        // Since V1, the kind of the key is stored between the version and the key.
+        let serializable = (GlweSecretKey32Version::V1, Kind::TAG, &entity.0);
         bincode::serialize(&serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertext32) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweSecretKey32<Kind>) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a LWE ciphertext entity.
-impl EntitySerializationEngine<LweCiphertext64, Vec<u8>> for DefaultSerializationEngine {
+/// on 64 bits integers. It serializes a GLWE secret key entity.
+impl<Kind> EntitySerializationEngine<GlweSecretKey64<Kind>, Vec<u8>> for DefaultSerializationEngine
+where
+    Kind: RandomKeyKind + Debug,
+{
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = 3_u64 << 50;
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
-    ///
-    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let glwe_secret_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let serialized = serialization_engine.serialize(&glwe_secret_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext, recovered);
+    /// assert_eq!(glwe_secret_key, recovered);
     ///
     /// #
     /// # Ok(())
     /// # }
+    /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertext64,
+        entity: &GlweSecretKey64<Kind>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        #[derive(Serialize)]
-        struct SerializableLweCiphertext64<'a> {
-            version: LweCiphertext64Version,
-            inner: &'a ImplLweCiphertext<Vec<u64>>,
-        }
-        let serializable = SerializableLweCiphertext64 {
-            version: LweCiphertext64Version::V0,
-            inner: &entity.0,
-        };
+        // Since V1, the kind of the key is stored between the version and the key.
+        let serializable = (GlweSecretKey64Version::V1, Kind::TAG, &entity.0);
         bincode::serialize(&serializable)
             .map_err(DefaultSerializationError::Serialization)
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertext64) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweSecretKey64<Kind>) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a LWE ciphertext view entity.
-impl<'b> EntitySerializationEngine<LweCiphertextView32<'b>, Vec<u8>>
-    for DefaultSerializationEngine
-{
+/// on 32 bits integers. It serializes a seeded GLWE ciphertext entity.
+impl EntitySerializationEngine<GlweSeededCiphertext32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
     /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = 3_u32 << 20;
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
-    /// let raw_buffer = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
-    /// let view: LweCiphertextView32 = engine.create_lwe_ciphertext_from(raw_buffer.as_slice())?;
+    /// let seeded_ciphertext =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: LweCiphertext32 = serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
+    /// let serialized = serialization_engine.serialize(&seeded_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1706,15 +1777,15 @@ impl<'b> EntitySerializationEngine<LweCiphertextView32<'b>, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextView32<'b>,
+        entity: &GlweSeededCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextView32<'a, 'b> {
-            version: LweCiphertext32Version,
-            inner: &'a ImplLweCiphertext<&'b [u32]>,
+        struct SerializableGlweSeededCiphertext32<'a> {
+            version: GlweSeededCiphertext32Version,
+            inner: &'a ImplGlweSeededCiphertext<Vec<u32>>,
         }
-        let serializable = SerializableLweCiphertextView32 {
-            version: LweCiphertext32Version::V0,
+        let serializable = SerializableGlweSeededCiphertext32 {
+            version: GlweSeededCiphertext32Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -1722,63 +1793,62 @@ impl<'b> EntitySerializationEngine<LweCiphertextView32<'b>, Vec<u8>>
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextView32<'b>) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertext32) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a LWE ciphertext view entity.
-impl<'b> EntitySerializationEngine<LweCiphertextView64<'b>, Vec<u8>>
-    for DefaultSerializationEngine
-{
+/// on 64 bits integers. It serializes a seeded GLWE ciphertext entity.
+impl EntitySerializationEngine<GlweSeededCiphertext64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
     /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = 3_u64 << 50;
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
-    ///
-    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let raw_buffer = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
-    /// let view: LweCiphertextView64 = engine.create_lwe_ciphertext_from(raw_buffer.as_slice())?;
+    /// let seeded_ciphertext =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: LweCiphertext64 = serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
+    /// let serialized = serialization_engine.serialize(&seeded_ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext, recovered);
     ///
     /// #
     /// # Ok(())
     /// # }
+    /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextView64<'b>,
+        entity: &GlweSeededCiphertext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextView64<'a, 'b> {
-            version: LweCiphertext64Version,
-            inner: &'a ImplLweCiphertext<&'b [u64]>,
+        struct SerializableGlweSeededCiphertext64<'a> {
+            version: GlweSeededCiphertext64Version,
+            inner: &'a ImplGlweSeededCiphertext<Vec<u64>>,
         }
-        let serializable = SerializableLweCiphertextView64 {
-            version: LweCiphertext64Version::V0,
+        let serializable = SerializableGlweSeededCiphertext64 {
+            version: GlweSeededCiphertext64Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -1786,48 +1856,47 @@ impl<'b> EntitySerializationEngine<LweCiphertextView64<'b>, Vec<u8>>
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextView64<'b>) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertext64) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a LWE ciphertext mut view entity.
-impl<'b> EntitySerializationEngine<LweCiphertextMutView32<'b>, Vec<u8>>
+/// on 32 bits integers. It serializes a seeded GLWE ciphertext vector entity.
+impl EntitySerializationEngine<GlweSeededCiphertextVector32, Vec<u8>>
     for DefaultSerializationEngine
 {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
     /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = 3_u32 << 20;
+    /// let input = vec![3_u32 << 20; 8];
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
-    /// let mut raw_buffer = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
-    /// let view: LweCiphertextMutView32 =
-    ///     engine.create_lwe_ciphertext_from(raw_buffer.as_mut_slice())?;
+    /// let seeded_ciphertext_vector =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: LweCiphertext32 = serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
+    /// let serialized = serialization_engine.serialize(&seeded_ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext_vector, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1835,15 +1904,15 @@ impl<'b> EntitySerializationEngine<LweCiphertextMutView32<'b>, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextMutView32<'b>,
+        entity: &GlweSeededCiphertextVector32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextMutView32<'a, 'b> {
-            version: LweCiphertext32Version,
-            inner: &'a ImplLweCiphertext<&'b mut [u32]>,
+        struct SerializableGlweSeededCiphertextVector32<'a> {
+            version: GlweSeededCiphertextVector32Version,
+            inner: &'a ImplGlweSeededList<Vec<u32>>,
         }
-        let serializable = SerializableLweCiphertextMutView32 {
-            version: LweCiphertext32Version::V0,
+        let serializable = SerializableGlweSeededCiphertextVector32 {
+            version: GlweSeededCiphertextVector32Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -1851,63 +1920,63 @@ impl<'b> EntitySerializationEngine<LweCiphertextMutView32<'b>, Vec<u8>>
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextMutView32<'b>) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertextVector32) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a LWE ciphertext mut view entity.
-impl<'b> EntitySerializationEngine<LweCiphertextMutView64<'b>, Vec<u8>>
+/// on 64 bits integers. It serializes a seeded GLWE ciphertext vector entity.
+impl EntitySerializationEngine<GlweSeededCiphertextVector64, Vec<u8>>
     for DefaultSerializationEngine
 {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
     /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = 3_u64 << 50;
+    /// let input = vec![3_u64 << 50; 8];
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
-    ///
-    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let mut raw_buffer = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
-    /// let view: LweCiphertextMutView64 = engine.create_lwe_ciphertext_from(raw_buffer.as_mut_slice())?;
+    /// let seeded_ciphertext_vector =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: LweCiphertext64 = serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
+    /// let serialized = serialization_engine.serialize(&seeded_ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(seeded_ciphertext_vector, recovered);
     ///
     /// #
     /// # Ok(())
     /// # }
+    /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextMutView64<'b>,
+        entity: &GlweSeededCiphertextVector64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextMutView64<'a, 'b> {
-            version: LweCiphertext64Version,
-            inner: &'a ImplLweCiphertext<&'b mut [u64]>,
+        struct SerializableGlweSeededCiphertextVector64<'a> {
+            version: GlweSeededCiphertextVector64Version,
+            inner: &'a ImplGlweSeededList<Vec<u64>>,
         }
-        let serializable = SerializableLweCiphertextMutView64 {
-            version: LweCiphertext64Version::V0,
+        let serializable = SerializableGlweSeededCiphertextVector64 {
+            version: GlweSeededCiphertextVector64Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -1915,43 +1984,54 @@ impl<'b> EntitySerializationEngine<LweCiphertextMutView64<'b>, Vec<u8>>
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextMutView64<'b>) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededCiphertextVector64) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a LWE ciphertext vector entity.
-impl EntitySerializationEngine<LweCiphertextVector32, Vec<u8>> for DefaultSerializationEngine {
+/// on 32 bits integers. It serializes a seeded GLWE keyswitch key entity.
+impl EntitySerializationEngine<GlweSeededKeyswitchKey32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(6);
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; 3];
+    /// let input_glwe_dimension = GlweDimension(3);
+    /// let output_glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    /// let input_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, polynomial_size)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
     ///
-    /// let mut ciphertext_vector: LweCiphertextVector32 =
-    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let keyswitch_key: GlweSeededKeyswitchKey32 = engine.generate_new_glwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext_vector, recovered);
+    /// assert_eq!(keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -1959,15 +2039,15 @@ impl EntitySerializationEngine<LweCiphertextVector32, Vec<u8>> for DefaultSerial
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextVector32,
+        entity: &GlweSeededKeyswitchKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextVector32<'a> {
-            version: LweCiphertextVector32Version,
-            inner: &'a ImplLweList<Vec<u32>>,
+        struct SerializableGlweSeededKeyswitchKey32<'a> {
+            version: GlweSeededKeyswitchKey32Version,
+            inner: &'a ImplGlweSeededKeyswitchKey<Vec<u32>>,
         }
-        let serializable = SerializableLweCiphertextVector32 {
-            version: LweCiphertextVector32Version::V0,
+        let serializable = SerializableGlweSeededKeyswitchKey32 {
+            version: GlweSeededKeyswitchKey32Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -1975,43 +2055,54 @@ impl EntitySerializationEngine<LweCiphertextVector32, Vec<u8>> for DefaultSerial
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVector32) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededKeyswitchKey32) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a LWE ciphertext vector entity.
-impl EntitySerializationEngine<LweCiphertextVector64, Vec<u8>> for DefaultSerializationEngine {
+/// on 64 bits integers. It serializes a seeded GLWE keyswitch key entity.
+impl EntitySerializationEngine<GlweSeededKeyswitchKey64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(6);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; 3];
+    /// let input_glwe_dimension = GlweDimension(3);
+    /// let output_glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    /// let input_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(input_glwe_dimension, polynomial_size)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
     ///
-    /// let mut ciphertext_vector: LweCiphertextVector64 =
-    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let keyswitch_key: GlweSeededKeyswitchKey64 = engine.generate_new_glwe_seeded_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext_vector, recovered);
+    /// assert_eq!(keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2019,15 +2110,15 @@ impl EntitySerializationEngine<LweCiphertextVector64, Vec<u8>> for DefaultSerial
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextVector64,
+        entity: &GlweSeededKeyswitchKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextVector64<'a> {
-            version: LweCiphertextVector64Version,
-            inner: &'a ImplLweList<Vec<u64>>,
+        struct SerializableGlweSeededKeyswitchKey64<'a> {
+            version: GlweSeededKeyswitchKey64Version,
+            inner: &'a ImplGlweSeededKeyswitchKey<Vec<u64>>,
         }
-        let serializable = SerializableLweCiphertextVector64 {
-            version: LweCiphertextVector64Version::V0,
+        let serializable = SerializableGlweSeededKeyswitchKey64 {
+            version: GlweSeededKeyswitchKey64Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -2035,51 +2126,164 @@ impl EntitySerializationEngine<LweCiphertextVector64, Vec<u8>> for DefaultSerial
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVector64) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &GlweSeededKeyswitchKey64) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a LWE ciphertext vector view entity. Immutable variant.
-impl<'b> EntitySerializationEngine<LweCiphertextVectorView32<'b>, Vec<u8>>
-    for DefaultSerializationEngine
-{
+/// on 32 bits integers. It serializes a LWE bootstrap key entity.
+impl EntitySerializationEngine<LweBootstrapKey32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweBootstrapKey32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweBootstrapKey32<'a> {
+            version: LweBootstrapKey32Version,
+            inner: &'a ImplStandardBootstrapKey<Vec<u32>>,
+        }
+        let serializable = SerializableLweBootstrapKey32 {
+            version: LweBootstrapKey32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweBootstrapKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE bootstrap key entity.
+impl EntitySerializationEngine<LweBootstrapKey64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweBootstrapKey64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweBootstrapKey64<'a> {
+            version: LweBootstrapKey64Version,
+            inner: &'a ImplStandardBootstrapKey<Vec<u64>>,
+        }
+        let serializable = SerializableLweBootstrapKey64 {
+            version: LweBootstrapKey64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweBootstrapKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a LWE ciphertext entity.
+impl EntitySerializationEngine<LweCiphertext32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_commons::parameters::LweDimension;
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(6);
-    /// let lwe_count = LweCiphertextCount(3);
+    /// let lwe_dimension = LweDimension(2);
     /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; lwe_count.0];
-    /// let noise = Variance(2_f64.powf(-50.));
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
     /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
     ///
-    /// let mut ciphertext_vector: LweCiphertextVector32 =
-    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
-    /// let raw_buffer = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
-    /// let view: LweCiphertextVectorView32 = engine
-    ///     .create_lwe_ciphertext_vector_from(raw_buffer.as_slice(), lwe_dimension.to_lwe_size())?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: LweCiphertextVector32 =
-    ///     serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext_vector(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2087,16 +2291,73 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorView32<'b>, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextVectorView32<'b>,
+        entity: &LweCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextVectorView32<'a, 'b> {
-            version: LweCiphertextVector32Version,
-            inner: &'a ImplLweList<&'b [u32]>,
+        struct SerializableLweCiphertext32<'a> {
+            version: LweCiphertext32Version,
+            inner: &'a ImplLweCiphertext<Vec<u32>>,
         }
+        let serializable = SerializableLweCiphertext32 {
+            version: LweCiphertext32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
 
-        let serializable = SerializableLweCiphertextVectorView32 {
-            version: LweCiphertextVector32Version::V0,
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertext32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE ciphertext entity.
+impl EntitySerializationEngine<LweCiphertext64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertext64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertext64<'a> {
+            version: LweCiphertext64Version,
+            inner: &'a ImplLweCiphertext<Vec<u64>>,
+        }
+        let serializable = SerializableLweCiphertext64 {
+            version: LweCiphertext64Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -2104,50 +2365,46 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorView32<'b>, Vec<u8>>
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorView32<'b>) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertext64) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a LWE ciphertext vector view entity. Immutable variant.
-impl<'b> EntitySerializationEngine<LweCiphertextVectorView64<'b>, Vec<u8>>
+/// on 32 bits integers. It serializes a LWE ciphertext view entity.
+impl<'b> EntitySerializationEngine<LweCiphertextView32<'b>, Vec<u8>>
     for DefaultSerializationEngine
 {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_commons::parameters::LweDimension;
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(6);
-    /// let lwe_count = LweCiphertextCount(3);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; lwe_count.0];
-    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
     ///
-    /// let mut ciphertext_vector: LweCiphertextVector64 =
-    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
-    /// let raw_buffer = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
-    /// let view: LweCiphertextVectorView64 = engine
-    ///     .create_lwe_ciphertext_vector_from(raw_buffer.as_slice(), lwe_dimension.to_lwe_size())?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let raw_buffer = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
+    /// let view: LweCiphertextView32 = engine.create_lwe_ciphertext_from(raw_buffer.as_slice())?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
     /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: LweCiphertextVector64 =
-    ///     serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext_vector(recovered)?;
+    /// let recovered: LweCiphertext32 = serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext(recovered)?;
     /// assert_eq!(raw_buffer, recovered_buffer);
     ///
     /// #
@@ -2156,16 +2413,1213 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorView64<'b>, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextVectorView64<'b>,
+        entity: &LweCiphertextView32<'b>,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextVectorView64<'a, 'b> {
+        struct SerializableLweCiphertextView32<'a, 'b> {
+            version: LweCiphertext32Version,
+            inner: &'a ImplLweCiphertext<&'b [u32]>,
+        }
+        let serializable = SerializableLweCiphertextView32 {
+            version: LweCiphertext32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextView32<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE ciphertext view entity.
+impl<'b> EntitySerializationEngine<LweCiphertextView64<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let raw_buffer = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
+    /// let view: LweCiphertextView64 = engine.create_lwe_ciphertext_from(raw_buffer.as_slice())?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: LweCiphertext64 = serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextView64<'b>,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextView64<'a, 'b> {
+            version: LweCiphertext64Version,
+            inner: &'a ImplLweCiphertext<&'b [u64]>,
+        }
+        let serializable = SerializableLweCiphertextView64 {
+            version: LweCiphertext64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextView64<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a LWE ciphertext mut view entity.
+impl<'b> EntitySerializationEngine<LweCiphertextMutView32<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let mut raw_buffer = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
+    /// let view: LweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext_from(raw_buffer.as_mut_slice())?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: LweCiphertext32 = serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextMutView32<'b>,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextMutView32<'a, 'b> {
+            version: LweCiphertext32Version,
+            inner: &'a ImplLweCiphertext<&'b mut [u32]>,
+        }
+        let serializable = SerializableLweCiphertextMutView32 {
+            version: LweCiphertext32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextMutView32<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE ciphertext mut view entity.
+impl<'b> EntitySerializationEngine<LweCiphertextMutView64<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut raw_buffer = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
+    /// let view: LweCiphertextMutView64 = engine.create_lwe_ciphertext_from(raw_buffer.as_mut_slice())?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: LweCiphertext64 = serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextMutView64<'b>,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextMutView64<'a, 'b> {
+            version: LweCiphertext64Version,
+            inner: &'a ImplLweCiphertext<&'b mut [u64]>,
+        }
+        let serializable = SerializableLweCiphertextMutView64 {
+            version: LweCiphertext64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextMutView64<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a LWE ciphertext vector entity.
+impl EntitySerializationEngine<LweCiphertextVector32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextVector32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextVector32<'a> {
+            version: LweCiphertextVector32Version,
+            inner: &'a ImplLweList<Vec<u32>>,
+        }
+        let serializable = SerializableLweCiphertextVector32 {
+            version: LweCiphertextVector32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVector32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE ciphertext vector entity.
+impl EntitySerializationEngine<LweCiphertextVector64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextVector64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextVector64<'a> {
+            version: LweCiphertextVector64Version,
+            inner: &'a ImplLweList<Vec<u64>>,
+        }
+        let serializable = SerializableLweCiphertextVector64 {
+            version: LweCiphertextVector64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVector64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a compressed LWE ciphertext vector entity.
+impl EntitySerializationEngine<LweCompressedCiphertextVector32, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let compressed_vector: LweCompressedCiphertextVector32 = engine
+    ///     .switch_modulus_lwe_ciphertext_vector(&ciphertext_vector, CiphertextModulusLog(8))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&compressed_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(compressed_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCompressedCiphertextVector32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCompressedCiphertextVector32<'a> {
+            version: LweCompressedCiphertextVector32Version,
+            inner: &'a ImplLweCompressedList<Vec<u32>>,
+        }
+        let serializable = SerializableLweCompressedCiphertextVector32 {
+            version: LweCompressedCiphertextVector32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCompressedCiphertextVector32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a compressed LWE ciphertext vector entity.
+impl EntitySerializationEngine<LweCompressedCiphertextVector64, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let compressed_vector: LweCompressedCiphertextVector64 = engine
+    ///     .switch_modulus_lwe_ciphertext_vector(&ciphertext_vector, CiphertextModulusLog(8))?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&compressed_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(compressed_vector, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCompressedCiphertextVector64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCompressedCiphertextVector64<'a> {
+            version: LweCompressedCiphertextVector64Version,
+            inner: &'a ImplLweCompressedList<Vec<u64>>,
+        }
+        let serializable = SerializableLweCompressedCiphertextVector64 {
+            version: LweCompressedCiphertextVector64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCompressedCiphertextVector64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a LWE ciphertext vector view entity. Immutable variant.
+impl<'b> EntitySerializationEngine<LweCiphertextVectorView32<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; lwe_count.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let raw_buffer = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+    /// let view: LweCiphertextVectorView32 = engine
+    ///     .create_lwe_ciphertext_vector_from(raw_buffer.as_slice(), lwe_dimension.to_lwe_size())?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: LweCiphertextVector32 =
+    ///     serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext_vector(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextVectorView32<'b>,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextVectorView32<'a, 'b> {
+            version: LweCiphertextVector32Version,
+            inner: &'a ImplLweList<&'b [u32]>,
+        }
+
+        let serializable = SerializableLweCiphertextVectorView32 {
+            version: LweCiphertextVector32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorView32<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE ciphertext vector view entity. Immutable variant.
+impl<'b> EntitySerializationEngine<LweCiphertextVectorView64<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; lwe_count.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let raw_buffer = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+    /// let view: LweCiphertextVectorView64 = engine
+    ///     .create_lwe_ciphertext_vector_from(raw_buffer.as_slice(), lwe_dimension.to_lwe_size())?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: LweCiphertextVector64 =
+    ///     serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext_vector(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextVectorView64<'b>,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextVectorView64<'a, 'b> {
+            version: LweCiphertextVector64Version,
+            inner: &'a ImplLweList<&'b [u64]>,
+        }
+
+        let serializable = SerializableLweCiphertextVectorView64 {
+            version: LweCiphertextVector64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorView64<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a LWE ciphertext vector view entity. Mutable variant.
+impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView32<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; lwe_count.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut raw_buffer = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+    /// let view: LweCiphertextVectorMutView32 = engine.create_lwe_ciphertext_vector_from(
+    ///     raw_buffer.as_mut_slice(),
+    ///     lwe_dimension.to_lwe_size(),
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: LweCiphertextVector32 =
+    ///     serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext_vector(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextVectorMutView32<'b>,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextVectorMutView32<'a, 'b> {
+            version: LweCiphertextVector32Version,
+            inner: &'a ImplLweList<&'b mut [u32]>,
+        }
+
+        let serializable = SerializableLweCiphertextVectorMutView32 {
+            version: LweCiphertextVector32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorMutView32<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE ciphertext vector view entity. Mutable variant.
+impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView64<'b>, Vec<u8>>
+    for DefaultSerializationEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; lwe_count.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut raw_buffer = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+    /// let view: LweCiphertextVectorMutView64 = engine.create_lwe_ciphertext_vector_from(
+    ///     raw_buffer.as_mut_slice(),
+    ///     lwe_dimension.to_lwe_size(),
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&view)?;
+    /// let recovered: LweCiphertextVector64 =
+    ///     serialization_engine.deserialize(serialized.as_slice())?;
+    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext_vector(recovered)?;
+    /// assert_eq!(raw_buffer, recovered_buffer);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCiphertextVectorMutView64<'b>,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCiphertextVectorMutView64<'a, 'b> {
             version: LweCiphertextVector64Version,
-            inner: &'a ImplLweList<&'b [u64]>,
+            inner: &'a ImplLweList<&'b mut [u64]>,
+        }
+
+        let serializable = SerializableLweCiphertextVectorMutView64 {
+            version: LweCiphertextVector64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorMutView64<'b>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a LWE keyswitch key entity.
+impl EntitySerializationEngine<LweKeyswitchKey32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweKeyswitchKey32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweKeyswitchKey32<'a> {
+            version: LweKeyswitchKey32Version,
+            inner: &'a ImplLweKeyswitchKey<Vec<u32>>,
+        }
+        let serializable = SerializableLweKeyswitchKey32 {
+            version: LweKeyswitchKey32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweKeyswitchKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE keyswitch key entity.
+impl EntitySerializationEngine<LweKeyswitchKey64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(keyswitch_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweKeyswitchKey64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweKeyswitchKey64<'a> {
+            version: LweKeyswitchKey64Version,
+            inner: &'a ImplLweKeyswitchKey<Vec<u64>>,
+        }
+        let serializable = SerializableLweKeyswitchKey64 {
+            version: LweKeyswitchKey64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweKeyswitchKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a LWE secret key entity.
+impl<Kind> EntitySerializationEngine<LweSecretKey32<Kind>, Vec<u8>> for DefaultSerializationEngine
+where
+    Kind: RandomKeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSecretKey32<Kind>,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        // Since V1, the kind of the key is stored between the version and the key.
+        let serializable = (LweSecretKey32Version::V1, Kind::TAG, &entity.0);
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSecretKey32<Kind>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a LWE secret key entity.
+impl<Kind> EntitySerializationEngine<LweSecretKey64<Kind>, Vec<u8>> for DefaultSerializationEngine
+where
+    Kind: RandomKeyKind + Debug,
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(lwe_secret_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSecretKey64<Kind>,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        // Since V1, the kind of the key is stored between the version and the key.
+        let serializable = (LweSecretKey64Version::V1, Kind::TAG, &entity.0);
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSecretKey64<Kind>) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a seeded LWE bootstrap key entity.
+impl EntitySerializationEngine<LweSeededBootstrapKey32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweSeededBootstrapKey32 =
+    ///     engine.generate_new_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    ///
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSeededBootstrapKey32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweSeededBootstrapKey32<'a> {
+            version: LweSeededBootstrapKey32Version,
+            inner: &'a ImplStandardSeededBootstrapKey<Vec<u32>>,
+        }
+        let serializable = SerializableLweSeededBootstrapKey32 {
+            version: LweSeededBootstrapKey32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededBootstrapKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a seeded LWE bootstrap key entity.
+impl EntitySerializationEngine<LweSeededBootstrapKey64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweSeededBootstrapKey64 =
+    ///     engine.generate_new_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    ///
+    /// assert_eq!(bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSeededBootstrapKey64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweSeededBootstrapKey64<'a> {
+            version: LweSeededBootstrapKey64Version,
+            inner: &'a ImplStandardSeededBootstrapKey<Vec<u64>>,
+        }
+        let serializable = SerializableLweSeededBootstrapKey64 {
+            version: LweSeededBootstrapKey64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededBootstrapKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a seeded LWE ciphertext entity.
+impl EntitySerializationEngine<LweSeededCiphertext32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext: LweSeededCiphertext32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSeededCiphertext32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweSeededCiphertext32<'a> {
+            version: LweSeededCiphertext32Version,
+            inner: &'a ImplLweSeededCiphertext<u32>,
+        }
+        let serializable = SerializableLweSeededCiphertext32 {
+            version: LweSeededCiphertext32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertext32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a seeded LWE ciphertext entity.
+impl EntitySerializationEngine<LweSeededCiphertext64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext: LweSeededCiphertext64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweSeededCiphertext64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweSeededCiphertext64<'a> {
+            version: LweSeededCiphertext64Version,
+            inner: &'a ImplLweSeededCiphertext<u64>,
         }
-
-        let serializable = SerializableLweCiphertextVectorView64 {
-            version: LweCiphertextVector64Version::V0,
+        let serializable = SerializableLweSeededCiphertext64 {
+            version: LweSeededCiphertext64Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -2173,15 +3627,15 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorView64<'b>, Vec<u8>>
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorView64<'b>) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertext64) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a LWE ciphertext vector view entity. Mutable variant.
-impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView32<'b>, Vec<u8>>
+/// on 32 bits integers. It serializes a seeded LWE ciphertext vector entity.
+impl EntitySerializationEngine<LweSeededCiphertextVector32, Vec<u8>>
     for DefaultSerializationEngine
 {
     /// # Example:
@@ -2194,10 +3648,9 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView32<'b>, Vec<u8>>
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let lwe_dimension = LweDimension(6);
-    /// let lwe_count = LweCiphertextCount(3);
     /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; lwe_count.0];
-    /// let noise = Variance(2_f64.powf(-50.));
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
@@ -2206,20 +3659,13 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView32<'b>, Vec<u8>>
     /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
     /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let mut ciphertext_vector: LweCiphertextVector32 =
-    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
-    /// let mut raw_buffer = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
-    /// let view: LweCiphertextVectorMutView32 = engine.create_lwe_ciphertext_vector_from(
-    ///     raw_buffer.as_mut_slice(),
-    ///     lwe_dimension.to_lwe_size(),
-    /// )?;
+    /// let mut ciphertext_vector: LweSeededCiphertextVector32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: LweCiphertextVector32 =
-    ///     serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext_vector(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2227,16 +3673,15 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView32<'b>, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextVectorMutView32<'b>,
+        entity: &LweSeededCiphertextVector32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextVectorMutView32<'a, 'b> {
-            version: LweCiphertextVector32Version,
-            inner: &'a ImplLweList<&'b mut [u32]>,
+        struct SerializableLweSeededCiphertextVector32<'a> {
+            version: LweSeededCiphertextVector32Version,
+            inner: &'a ImplLweSeededList<Vec<u32>>,
         }
-
-        let serializable = SerializableLweCiphertextVectorMutView32 {
-            version: LweCiphertextVector32Version::V0,
+        let serializable = SerializableLweSeededCiphertextVector32 {
+            version: LweSeededCiphertextVector32Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -2244,15 +3689,15 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView32<'b>, Vec<u8>>
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorMutView32<'b>) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertextVector32) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a LWE ciphertext vector view entity. Mutable variant.
-impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView64<'b>, Vec<u8>>
+/// on 64 bits integers. It serializes a seeded LWE ciphertext vector entity.
+impl EntitySerializationEngine<LweSeededCiphertextVector64, Vec<u8>>
     for DefaultSerializationEngine
 {
     /// # Example:
@@ -2265,10 +3710,9 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView64<'b>, Vec<u8>>
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
     /// let lwe_dimension = LweDimension(6);
-    /// let lwe_count = LweCiphertextCount(3);
     /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; lwe_count.0];
-    /// let noise = Variance(2_f64.powf(-50.));
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
@@ -2277,20 +3721,13 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView64<'b>, Vec<u8>>
     /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
     /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
     ///
-    /// let mut ciphertext_vector: LweCiphertextVector64 =
-    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
-    /// let mut raw_buffer = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
-    /// let view: LweCiphertextVectorMutView64 = engine.create_lwe_ciphertext_vector_from(
-    ///     raw_buffer.as_mut_slice(),
-    ///     lwe_dimension.to_lwe_size(),
-    /// )?;
+    /// let mut ciphertext_vector: LweSeededCiphertextVector64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&view)?;
-    /// let recovered: LweCiphertextVector64 =
-    ///     serialization_engine.deserialize(serialized.as_slice())?;
-    /// let recovered_buffer = engine.consume_retrieve_lwe_ciphertext_vector(recovered)?;
-    /// assert_eq!(raw_buffer, recovered_buffer);
+    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(ciphertext_vector, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2298,16 +3735,15 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView64<'b>, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweCiphertextVectorMutView64<'b>,
+        entity: &LweSeededCiphertextVector64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweCiphertextVectorMutView64<'a, 'b> {
-            version: LweCiphertextVector64Version,
-            inner: &'a ImplLweList<&'b mut [u64]>,
+        struct SerializableLweSeededCiphertextVector64<'a> {
+            version: LweSeededCiphertextVector64Version,
+            inner: &'a ImplLweSeededList<Vec<u64>>,
         }
-
-        let serializable = SerializableLweCiphertextVectorMutView64 {
-            version: LweCiphertextVector64Version::V0,
+        let serializable = SerializableLweSeededCiphertextVector64 {
+            version: LweSeededCiphertextVector64Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -2315,15 +3751,15 @@ impl<'b> EntitySerializationEngine<LweCiphertextVectorMutView64<'b>, Vec<u8>>
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweCiphertextVectorMutView64<'b>) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertextVector64) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a LWE keyswitch key entity.
-impl EntitySerializationEngine<LweKeyswitchKey32, Vec<u8>> for DefaultSerializationEngine {
+/// on 32 bits integers. It serializes a seeded LWE keyswitch key entity.
+impl EntitySerializationEngine<LweSeededKeyswitchKey32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -2348,7 +3784,7 @@ impl EntitySerializationEngine<LweKeyswitchKey32, Vec<u8>> for DefaultSerializat
     /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
     /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
     ///
-    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
     ///     &input_key,
     ///     &output_key,
     ///     decomposition_level_count,
@@ -2357,9 +3793,9 @@ impl EntitySerializationEngine<LweKeyswitchKey32, Vec<u8>> for DefaultSerializat
     /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
+    /// let serialized = serialization_engine.serialize(&seeded_keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(keyswitch_key, recovered);
+    /// assert_eq!(seeded_keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2367,15 +3803,15 @@ impl EntitySerializationEngine<LweKeyswitchKey32, Vec<u8>> for DefaultSerializat
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweKeyswitchKey32,
+        entity: &LweSeededKeyswitchKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweKeyswitchKey32<'a> {
-            version: LweKeyswitchKey32Version,
-            inner: &'a ImplLweKeyswitchKey<Vec<u32>>,
+        struct LweSeededKeyswitchKey32<'a> {
+            version: LweSeededKeyswitchKey32Version,
+            inner: &'a ImplLweSeededKeyswitchKey<Vec<u32>>,
         }
-        let serializable = SerializableLweKeyswitchKey32 {
-            version: LweKeyswitchKey32Version::V0,
+        let serializable = LweSeededKeyswitchKey32 {
+            version: LweSeededKeyswitchKey32Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -2383,15 +3819,15 @@ impl EntitySerializationEngine<LweKeyswitchKey32, Vec<u8>> for DefaultSerializat
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweKeyswitchKey32) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededKeyswitchKey32) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a LWE keyswitch key entity.
-impl EntitySerializationEngine<LweKeyswitchKey64, Vec<u8>> for DefaultSerializationEngine {
+/// on 64 bits integers. It serializes a seeded LWE keyswitch key entity.
+impl EntitySerializationEngine<LweSeededKeyswitchKey64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -2416,7 +3852,7 @@ impl EntitySerializationEngine<LweKeyswitchKey64, Vec<u8>> for DefaultSerializat
     /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
     /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
     ///
-    /// let keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
     ///     &input_key,
     ///     &output_key,
     ///     decomposition_level_count,
@@ -2425,9 +3861,9 @@ impl EntitySerializationEngine<LweKeyswitchKey64, Vec<u8>> for DefaultSerializat
     /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&keyswitch_key)?;
+    /// let serialized = serialization_engine.serialize(&seeded_keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(keyswitch_key, recovered);
+    /// assert_eq!(seeded_keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2435,15 +3871,15 @@ impl EntitySerializationEngine<LweKeyswitchKey64, Vec<u8>> for DefaultSerializat
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweKeyswitchKey64,
+        entity: &LweSeededKeyswitchKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweKeyswitchKey64<'a> {
-            version: LweKeyswitchKey64Version,
-            inner: &'a ImplLweKeyswitchKey<Vec<u64>>,
+        struct LweSeededKeyswitchKey64<'a> {
+            version: LweSeededKeyswitchKey64Version,
+            inner: &'a ImplLweSeededKeyswitchKey<Vec<u64>>,
         }
-        let serializable = SerializableLweKeyswitchKey64 {
-            version: LweKeyswitchKey64Version::V0,
+        let serializable = LweSeededKeyswitchKey64 {
+            version: LweSeededKeyswitchKey64Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -2451,35 +3887,51 @@ impl EntitySerializationEngine<LweKeyswitchKey64, Vec<u8>> for DefaultSerializat
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweKeyswitchKey64) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededKeyswitchKey64) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a LWE secret key entity.
-impl EntitySerializationEngine<LweSecretKey32, Vec<u8>> for DefaultSerializationEngine {
+/// on 32 bits integers. It serializes a packing keyswitch key entity.
+impl EntitySerializationEngine<LwePackingKeyswitchKey32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(6);
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let packing_keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
+    /// let serialized = serialization_engine.serialize(&packing_keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(lwe_secret_key, recovered);
+    /// assert_eq!(packing_keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2487,15 +3939,15 @@ impl EntitySerializationEngine<LweSecretKey32, Vec<u8>> for DefaultSerialization
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweSecretKey32,
+        entity: &LwePackingKeyswitchKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweSecretKey32<'a> {
-            version: LweSecretKey32Version,
-            inner: &'a ImplLweSecretKey<BinaryKeyKind, Vec<u32>>,
+        struct SerializablePackingKeyswitchKey32<'a> {
+            version: LwePackingKeyswitchKey32Version,
+            inner: &'a ImplLwePackingKeyswitchKey<Vec<u32>>,
         }
-        let serializable = SerializableLweSecretKey32 {
-            version: LweSecretKey32Version::V0,
+        let serializable = SerializablePackingKeyswitchKey32 {
+            version: LwePackingKeyswitchKey32Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -2503,35 +3955,51 @@ impl EntitySerializationEngine<LweSecretKey32, Vec<u8>> for DefaultSerialization
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSecretKey32) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &LwePackingKeyswitchKey32) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a LWE secret key entity.
-impl EntitySerializationEngine<LweSecretKey64, Vec<u8>> for DefaultSerializationEngine {
+/// on 64 bits integers. It serializes a packing keyswitch key entity.
+impl EntitySerializationEngine<LwePackingKeyswitchKey64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(6);
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let packing_keyswitch_key = engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&lwe_secret_key)?;
+    /// let serialized = serialization_engine.serialize(&packing_keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(lwe_secret_key, recovered);
+    /// assert_eq!(packing_keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2539,15 +4007,15 @@ impl EntitySerializationEngine<LweSecretKey64, Vec<u8>> for DefaultSerialization
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweSecretKey64,
+        entity: &LwePackingKeyswitchKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweSecretKey64<'a> {
-            version: LweSecretKey64Version,
-            inner: &'a ImplLweSecretKey<BinaryKeyKind, Vec<u64>>,
+        struct SerializablePackingKeyswitchKey64<'a> {
+            version: LwePackingKeyswitchKey64Version,
+            inner: &'a ImplLwePackingKeyswitchKey<Vec<u64>>,
         }
-        let serializable = SerializableLweSecretKey64 {
-            version: LweSecretKey64Version::V0,
+        let serializable = SerializablePackingKeyswitchKey64 {
+            version: LwePackingKeyswitchKey64Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -2555,15 +4023,17 @@ impl EntitySerializationEngine<LweSecretKey64, Vec<u8>> for DefaultSerialization
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSecretKey64) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &LwePackingKeyswitchKey64) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a seeded LWE bootstrap key entity.
-impl EntitySerializationEngine<LweSeededBootstrapKey32, Vec<u8>> for DefaultSerializationEngine {
+/// on 32 bits integers. It serializes a seeded packing keyswitch key entity.
+impl EntitySerializationEngine<LweSeededPackingKeyswitchKey32, Vec<u8>>
+    for DefaultSerializationEngine
+{
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -2575,25 +4045,34 @@ impl EntitySerializationEngine<LweSeededBootstrapKey32, Vec<u8>> for DefaultSeri
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
-    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_sk: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dim)?;
-    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
-    ///
-    /// let bsk: LweSeededBootstrapKey32 =
-    ///     engine.generate_new_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_packing_keyswitch_key: LweSeededPackingKeyswitchKey32 = engine
+    ///     .generate_new_lwe_seeded_packing_keyswitch_key(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let serialized = serialization_engine.serialize(&seeded_packing_keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    ///
-    /// assert_eq!(bsk, recovered);
+    /// assert_eq!(seeded_packing_keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2601,15 +4080,15 @@ impl EntitySerializationEngine<LweSeededBootstrapKey32, Vec<u8>> for DefaultSeri
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweSeededBootstrapKey32,
+        entity: &LweSeededPackingKeyswitchKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweSeededBootstrapKey32<'a> {
-            version: LweSeededBootstrapKey32Version,
-            inner: &'a ImplStandardSeededBootstrapKey<Vec<u32>>,
+        struct LweSeededPackingKeyswitchKey32<'a> {
+            version: LweSeededPackingKeyswitchKey32Version,
+            inner: &'a ImplLweSeededPackingKeyswitchKey<Vec<u32>>,
         }
-        let serializable = SerializableLweSeededBootstrapKey32 {
-            version: LweSeededBootstrapKey32Version::V0,
+        let serializable = LweSeededPackingKeyswitchKey32 {
+            version: LweSeededPackingKeyswitchKey32Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -2617,16 +4096,17 @@ impl EntitySerializationEngine<LweSeededBootstrapKey32, Vec<u8>> for DefaultSeri
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededBootstrapKey32) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededPackingKeyswitchKey32) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a seeded LWE bootstrap key entity.
-impl EntitySerializationEngine<LweSeededBootstrapKey64, Vec<u8>> for DefaultSerializationEngine {
-    /// # Example:
+/// on 64 bits integers. It serializes a seeded packing keyswitch key entity.
+impl EntitySerializationEngine<LweSeededPackingKeyswitchKey64, Vec<u8>>
+    for DefaultSerializationEngine
+{
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
@@ -2638,25 +4118,34 @@ impl EntitySerializationEngine<LweSeededBootstrapKey64, Vec<u8>> for DefaultSeri
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
-    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
-    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
-    ///
-    /// let bsk: LweSeededBootstrapKey64 =
-    ///     engine.generate_new_lwe_seeded_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_packing_keyswitch_key: LweSeededPackingKeyswitchKey64 = engine
+    ///     .generate_new_lwe_seeded_packing_keyswitch_key(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&bsk)?;
+    /// let serialized = serialization_engine.serialize(&seeded_packing_keyswitch_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    ///
-    /// assert_eq!(bsk, recovered);
+    /// assert_eq!(seeded_packing_keyswitch_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2664,15 +4153,15 @@ impl EntitySerializationEngine<LweSeededBootstrapKey64, Vec<u8>> for DefaultSeri
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweSeededBootstrapKey64,
+        entity: &LweSeededPackingKeyswitchKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweSeededBootstrapKey64<'a> {
-            version: LweSeededBootstrapKey64Version,
-            inner: &'a ImplStandardSeededBootstrapKey<Vec<u64>>,
+        struct LweSeededPackingKeyswitchKey64<'a> {
+            version: LweSeededPackingKeyswitchKey64Version,
+            inner: &'a ImplLweSeededPackingKeyswitchKey<Vec<u64>>,
         }
-        let serializable = SerializableLweSeededBootstrapKey64 {
-            version: LweSeededBootstrapKey64Version::V0,
+        let serializable = LweSeededPackingKeyswitchKey64 {
+            version: LweSeededPackingKeyswitchKey64Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -2680,43 +4169,60 @@ impl EntitySerializationEngine<LweSeededBootstrapKey64, Vec<u8>> for DefaultSeri
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededBootstrapKey64) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededPackingKeyswitchKey64) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a seeded LWE ciphertext entity.
-impl EntitySerializationEngine<LweSeededCiphertext32, Vec<u8>> for DefaultSerializationEngine {
+/// on 32 bits integers. It serializes a seeded private functional packing keyswitch key entity.
+impl EntitySerializationEngine<LweSeededPrivateFunctionalPackingKeyswitchKey32, Vec<u8>>
+    for DefaultSerializationEngine
+{
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::dispersion::{StandardDev, Variance};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = 3_u32 << 20;
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
-    ///
-    /// let ciphertext: LweSeededCiphertext32 =
-    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let val = vec![1_u32; output_key.polynomial_size().0];
+    /// let polynomial: CleartextVector32 = engine.create_cleartext_vector_from(&val)?;
+    /// let seeded_pfpksk: LweSeededPrivateFunctionalPackingKeyswitchKey32 = engine
+    ///     .generate_new_lwe_seeded_private_functional_packing_keyswitch_key(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         StandardDev(noise.get_standard_dev()),
+    ///         &|x| x,
+    ///         &polynomial,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let serialized = serialization_engine.serialize(&seeded_pfpksk)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext, recovered);
+    /// assert_eq!(seeded_pfpksk, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2724,15 +4230,15 @@ impl EntitySerializationEngine<LweSeededCiphertext32, Vec<u8>> for DefaultSerial
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweSeededCiphertext32,
+        entity: &LweSeededPrivateFunctionalPackingKeyswitchKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweSeededCiphertext32<'a> {
-            version: LweSeededCiphertext32Version,
-            inner: &'a ImplLweSeededCiphertext<u32>,
+        struct LweSeededPrivateFunctionalPackingKeyswitchKey32<'a> {
+            version: LweSeededPrivateFunctionalPackingKeyswitchKey32Version,
+            inner: &'a ImplLweSeededPrivateFunctionalPackingKeyswitchKey<Vec<u32>>,
         }
-        let serializable = SerializableLweSeededCiphertext32 {
-            version: LweSeededCiphertext32Version::V0,
+        let serializable = LweSeededPrivateFunctionalPackingKeyswitchKey32 {
+            version: LweSeededPrivateFunctionalPackingKeyswitchKey32Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -2740,43 +4246,63 @@ impl EntitySerializationEngine<LweSeededCiphertext32, Vec<u8>> for DefaultSerial
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertext32) -> Vec<u8> {
+    unsafe fn serialize_unchecked(
+        &mut self,
+        entity: &LweSeededPrivateFunctionalPackingKeyswitchKey32,
+    ) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a seeded LWE ciphertext entity.
-impl EntitySerializationEngine<LweSeededCiphertext64, Vec<u8>> for DefaultSerializationEngine {
+/// on 64 bits integers. It serializes a seeded private functional packing keyswitch key entity.
+impl EntitySerializationEngine<LweSeededPrivateFunctionalPackingKeyswitchKey64, Vec<u8>>
+    for DefaultSerializationEngine
+{
     /// # Example:
     /// ```
-    /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_commons::dispersion::{StandardDev, Variance};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(2);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = 3_u64 << 50;
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext = engine.create_plaintext_from(&input)?;
-    ///
-    /// let ciphertext: LweSeededCiphertext64 =
-    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let val = vec![1_u64; output_key.polynomial_size().0];
+    /// let polynomial: CleartextVector64 = engine.create_cleartext_vector_from(&val)?;
+    /// let seeded_pfpksk: LweSeededPrivateFunctionalPackingKeyswitchKey64 = engine
+    ///     .generate_new_lwe_seeded_private_functional_packing_keyswitch_key(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         StandardDev(noise.get_standard_dev()),
+    ///         &|x| x,
+    ///         &polynomial,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext)?;
+    /// let serialized = serialization_engine.serialize(&seeded_pfpksk)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext, recovered);
+    /// assert_eq!(seeded_pfpksk, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2784,15 +4310,15 @@ impl EntitySerializationEngine<LweSeededCiphertext64, Vec<u8>> for DefaultSerial
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweSeededCiphertext64,
+        entity: &LweSeededPrivateFunctionalPackingKeyswitchKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweSeededCiphertext64<'a> {
-            version: LweSeededCiphertext64Version,
-            inner: &'a ImplLweSeededCiphertext<u64>,
+        struct LweSeededPrivateFunctionalPackingKeyswitchKey64<'a> {
+            version: LweSeededPrivateFunctionalPackingKeyswitchKey64Version,
+            inner: &'a ImplLweSeededPrivateFunctionalPackingKeyswitchKey<Vec<u64>>,
         }
-        let serializable = SerializableLweSeededCiphertext64 {
-            version: LweSeededCiphertext64Version::V0,
+        let serializable = LweSeededPrivateFunctionalPackingKeyswitchKey64 {
+            version: LweSeededPrivateFunctionalPackingKeyswitchKey64Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -2800,45 +4326,62 @@ impl EntitySerializationEngine<LweSeededCiphertext64, Vec<u8>> for DefaultSerial
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertext64) -> Vec<u8> {
+    unsafe fn serialize_unchecked(
+        &mut self,
+        entity: &LweSeededPrivateFunctionalPackingKeyswitchKey64,
+    ) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a seeded LWE ciphertext vector entity.
-impl EntitySerializationEngine<LweSeededCiphertextVector32, Vec<u8>>
-    for DefaultSerializationEngine
+/// on 32 bits integers. It serializes a seeded circuit bootstrap private functional packing keyswitch keys entity.
+impl
+    EntitySerializationEngine<
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        Vec<u8>,
+    > for DefaultSerializationEngine
 {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(6);
-    /// // Here a hard-set encoding is applied (shift by 20 bits)
-    /// let input = vec![3_u32 << 20; 3];
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector_from(&input)?;
-    ///
-    /// let mut ciphertext_vector: LweSeededCiphertextVector32 =
-    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_cbs_pfpksk: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 =
+    ///     engine.generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///         noise,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&seeded_cbs_pfpksk)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext_vector, recovered);
+    /// assert_eq!(seeded_cbs_pfpksk, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2846,15 +4389,15 @@ impl EntitySerializationEngine<LweSeededCiphertextVector32, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweSeededCiphertextVector32,
+        entity: &LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweSeededCiphertextVector32<'a> {
-            version: LweSeededCiphertextVector32Version,
-            inner: &'a ImplLweSeededList<Vec<u32>>,
+        struct LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32<'a> {
+            version: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version,
+            inner: &'a ImplLweSeededPrivateFunctionalPackingKeyswitchKeyList<Vec<u32>>,
         }
-        let serializable = SerializableLweSeededCiphertextVector32 {
-            version: LweSeededCiphertextVector32Version::V0,
+        let serializable = LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+            version: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -2862,45 +4405,62 @@ impl EntitySerializationEngine<LweSeededCiphertextVector32, Vec<u8>>
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertextVector32) -> Vec<u8> {
+    unsafe fn serialize_unchecked(
+        &mut self,
+        entity: &LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a seeded LWE ciphertext vector entity.
-impl EntitySerializationEngine<LweSeededCiphertextVector64, Vec<u8>>
-    for DefaultSerializationEngine
+/// on 64 bits integers. It serializes a seeded circuit bootstrap private functional packing keyswitch keys entity.
+impl
+    EntitySerializationEngine<
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+        Vec<u8>,
+    > for DefaultSerializationEngine
 {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let lwe_dimension = LweDimension(6);
-    /// // Here a hard-set encoding is applied (shift by 50 bits)
-    /// let input = vec![3_u64 << 50; 3];
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
-    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector_from(&input)?;
-    ///
-    /// let mut ciphertext_vector: LweSeededCiphertextVector64 =
-    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.generate_new_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let seeded_cbs_pfpksk: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 =
+    ///     engine.generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///         noise,
+    ///     )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&ciphertext_vector)?;
+    /// let serialized = serialization_engine.serialize(&seeded_cbs_pfpksk)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(ciphertext_vector, recovered);
+    /// assert_eq!(seeded_cbs_pfpksk, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2908,15 +4468,15 @@ impl EntitySerializationEngine<LweSeededCiphertextVector64, Vec<u8>>
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweSeededCiphertextVector64,
+        entity: &LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializableLweSeededCiphertextVector64<'a> {
-            version: LweSeededCiphertextVector64Version,
-            inner: &'a ImplLweSeededList<Vec<u64>>,
+        struct LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64<'a> {
+            version: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version,
+            inner: &'a ImplLweSeededPrivateFunctionalPackingKeyswitchKeyList<Vec<u64>>,
         }
-        let serializable = SerializableLweSeededCiphertextVector64 {
-            version: LweSeededCiphertextVector64Version::V0,
+        let serializable = LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+            version: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -2924,51 +4484,47 @@ impl EntitySerializationEngine<LweSeededCiphertextVector64, Vec<u8>>
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededCiphertextVector64) -> Vec<u8> {
+    unsafe fn serialize_unchecked(
+        &mut self,
+        entity: &LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a seeded LWE keyswitch key entity.
-impl EntitySerializationEngine<LweSeededKeyswitchKey32, Vec<u8>> for DefaultSerializationEngine {
+/// on 32 bits integers. It serializes a seeded LWE public key entity.
+impl EntitySerializationEngine<LweSeededPublicKey32, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
-    /// };
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let input_lwe_dimension = LweDimension(6);
-    /// let output_lwe_dimension = LweDimension(3);
-    /// let decomposition_level_count = DecompositionLevelCount(2);
-    /// let decomposition_base_log = DecompositionBaseLog(8);
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
-    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
     ///
-    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
-    ///     &input_key,
-    ///     &output_key,
-    ///     decomposition_level_count,
-    ///     decomposition_base_log,
+    /// let seeded_public_key: LweSeededPublicKey32 = engine.generate_new_lwe_seeded_public_key(
+    ///     &lwe_secret_key,
     ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
     /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_keyswitch_key)?;
+    /// let serialized = serialization_engine.serialize(&seeded_public_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_keyswitch_key, recovered);
+    /// assert_eq!(seeded_public_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -2976,15 +4532,15 @@ impl EntitySerializationEngine<LweSeededKeyswitchKey32, Vec<u8>> for DefaultSeri
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweSeededKeyswitchKey32,
+        entity: &LweSeededPublicKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct LweSeededKeyswitchKey32<'a> {
-            version: LweSeededKeyswitchKey32Version,
-            inner: &'a ImplLweSeededKeyswitchKey<Vec<u32>>,
+        struct SerializableLweSeededPublicKey32<'a> {
+            version: LweSeededPublicKey32Version,
+            inner: &'a ImplLweSeededList<Vec<u32>>,
         }
-        let serializable = LweSeededKeyswitchKey32 {
-            version: LweSeededKeyswitchKey32Version::V0,
+        let serializable = SerializableLweSeededPublicKey32 {
+            version: LweSeededPublicKey32Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -2992,51 +4548,44 @@ impl EntitySerializationEngine<LweSeededKeyswitchKey32, Vec<u8>> for DefaultSeri
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededKeyswitchKey32) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededPublicKey32) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a seeded LWE keyswitch key entity.
-impl EntitySerializationEngine<LweSeededKeyswitchKey64, Vec<u8>> for DefaultSerializationEngine {
+/// on 64 bits integers. It serializes a seeded LWE public key entity.
+impl EntitySerializationEngine<LweSeededPublicKey64, Vec<u8>> for DefaultSerializationEngine {
     /// # Example:
     /// ```
     /// use concrete_commons::dispersion::Variance;
-    /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
-    /// };
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let input_lwe_dimension = LweDimension(6);
-    /// let output_lwe_dimension = LweDimension(3);
-    /// let decomposition_level_count = DecompositionLevelCount(2);
-    /// let decomposition_base_log = DecompositionBaseLog(8);
-    /// let noise = Variance(2_f64.powf(-25.));
+    /// let lwe_dimension = LweDimension(6);
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(42);
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
-    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
     ///
-    /// let seeded_keyswitch_key = engine.generate_new_lwe_seeded_keyswitch_key(
-    ///     &input_key,
-    ///     &output_key,
-    ///     decomposition_level_count,
-    ///     decomposition_base_log,
+    /// let seeded_public_key: LweSeededPublicKey64 = engine.generate_new_lwe_seeded_public_key(
+    ///     &lwe_secret_key,
     ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
     /// )?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&seeded_keyswitch_key)?;
+    /// let serialized = serialization_engine.serialize(&seeded_public_key)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(seeded_keyswitch_key, recovered);
+    /// assert_eq!(seeded_public_key, recovered);
     ///
     /// #
     /// # Ok(())
@@ -3044,15 +4593,15 @@ impl EntitySerializationEngine<LweSeededKeyswitchKey64, Vec<u8>> for DefaultSeri
     /// ```
     fn serialize(
         &mut self,
-        entity: &LweSeededKeyswitchKey64,
+        entity: &LweSeededPublicKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct LweSeededKeyswitchKey64<'a> {
-            version: LweSeededKeyswitchKey64Version,
-            inner: &'a ImplLweSeededKeyswitchKey<Vec<u64>>,
+        struct SerializableLweSeededPublicKey64<'a> {
+            version: LweSeededPublicKey64Version,
+            inner: &'a ImplLweSeededList<Vec<u64>>,
         }
-        let serializable = LweSeededKeyswitchKey64 {
-            version: LweSeededKeyswitchKey64Version::V0,
+        let serializable = SerializableLweSeededPublicKey64 {
+            version: LweSeededPublicKey64Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -3060,51 +4609,46 @@ impl EntitySerializationEngine<LweSeededKeyswitchKey64, Vec<u8>> for DefaultSeri
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededKeyswitchKey64) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &LweSeededPublicKey64) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 32 bits integers. It serializes a packing keyswitch key entity.
-impl EntitySerializationEngine<LwePackingKeyswitchKey32, Vec<u8>> for DefaultSerializationEngine {
-    /// # Example:
+/// on 32 bits integers. It serializes a LWE ternary bootstrap key entity.
+impl EntitySerializationEngine<LweTernaryBootstrapKey32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example
     /// ```
     /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::key_kinds::TernaryKeyKind;
     /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
     /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let input_lwe_dimension = LweDimension(6);
-    /// let output_lwe_dimension = LweDimension(3);
-    /// let decomposition_level_count = DecompositionLevelCount(2);
-    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let input_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
-    /// let output_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let lwe_sk: LweSecretKey32<TernaryKeyKind> =
+    ///     engine.generate_new_lwe_secret_key_with_kind(lwe_dim, TernaryKeyKind)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
     ///
-    /// let packing_keyswitch_key = engine.generate_new_lwe_keyswitch_key(
-    ///     &input_key,
-    ///     &output_key,
-    ///     decomposition_level_count,
-    ///     decomposition_base_log,
-    ///     noise,
-    /// )?;
+    /// let bsk: LweTernaryBootstrapKey32 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&packing_keyswitch_key)?;
+    /// let serialized = serialization_engine.serialize(&bsk)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(packing_keyswitch_key, recovered);
+    /// assert_eq!(bsk, recovered);
     ///
     /// #
     /// # Ok(())
@@ -3112,15 +4656,15 @@ impl EntitySerializationEngine<LwePackingKeyswitchKey32, Vec<u8>> for DefaultSer
     /// ```
     fn serialize(
         &mut self,
-        entity: &LwePackingKeyswitchKey32,
+        entity: &LweTernaryBootstrapKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializablePackingKeyswitchKey32<'a> {
-            version: LwePackingKeyswitchKey32Version,
-            inner: &'a ImplLwePackingKeyswitchKey<Vec<u32>>,
+        struct SerializableLweTernaryBootstrapKey32<'a> {
+            version: LweTernaryBootstrapKey32Version,
+            inner: &'a ImplStandardBootstrapKey<Vec<u32>>,
         }
-        let serializable = SerializablePackingKeyswitchKey32 {
-            version: LwePackingKeyswitchKey32Version::V0,
+        let serializable = SerializableLweTernaryBootstrapKey32 {
+            version: LweTernaryBootstrapKey32Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -3128,51 +4672,46 @@ impl EntitySerializationEngine<LwePackingKeyswitchKey32, Vec<u8>> for DefaultSer
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LwePackingKeyswitchKey32) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &LweTernaryBootstrapKey32) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
 
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
-/// on 64 bits integers. It serializes a packing keyswitch key entity.
-impl EntitySerializationEngine<LwePackingKeyswitchKey64, Vec<u8>> for DefaultSerializationEngine {
-    /// # Example:
+/// on 64 bits integers. It serializes a LWE ternary bootstrap key entity.
+impl EntitySerializationEngine<LweTernaryBootstrapKey64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example
     /// ```
     /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::key_kinds::TernaryKeyKind;
     /// use concrete_commons::parameters::{
-    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
     /// };
     /// use concrete_core::prelude::*;
     /// # use std::error::Error;
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let input_lwe_dimension = LweDimension(6);
-    /// let output_lwe_dimension = LweDimension(3);
-    /// let decomposition_level_count = DecompositionLevelCount(2);
-    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
     /// let noise = Variance(2_f64.powf(-25.));
     ///
     /// // Unix seeder must be given a secret input.
     /// // Here we just give it 0, which is totally unsafe.
     /// const UNSAFE_SECRET: u128 = 0;
     /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
-    /// let input_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
-    /// let output_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let lwe_sk: LweSecretKey64<TernaryKeyKind> =
+    ///     engine.generate_new_lwe_secret_key_with_kind(lwe_dim, TernaryKeyKind)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
     ///
-    /// let packing_keyswitch_key = engine.generate_new_lwe_keyswitch_key(
-    ///     &input_key,
-    ///     &output_key,
-    ///     decomposition_level_count,
-    ///     decomposition_base_log,
-    ///     noise,
-    /// )?;
+    /// let bsk: LweTernaryBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
     ///
     /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
-    /// let serialized = serialization_engine.serialize(&packing_keyswitch_key)?;
+    /// let serialized = serialization_engine.serialize(&bsk)?;
     /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
-    /// assert_eq!(packing_keyswitch_key, recovered);
+    /// assert_eq!(bsk, recovered);
     ///
     /// #
     /// # Ok(())
@@ -3180,15 +4719,15 @@ impl EntitySerializationEngine<LwePackingKeyswitchKey64, Vec<u8>> for DefaultSer
     /// ```
     fn serialize(
         &mut self,
-        entity: &LwePackingKeyswitchKey64,
+        entity: &LweTernaryBootstrapKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
         #[derive(Serialize)]
-        struct SerializablePackingKeyswitchKey64<'a> {
-            version: LwePackingKeyswitchKey64Version,
-            inner: &'a ImplLwePackingKeyswitchKey<Vec<u64>>,
+        struct SerializableLweTernaryBootstrapKey64<'a> {
+            version: LweTernaryBootstrapKey64Version,
+            inner: &'a ImplStandardBootstrapKey<Vec<u64>>,
         }
-        let serializable = SerializablePackingKeyswitchKey64 {
-            version: LwePackingKeyswitchKey64Version::V0,
+        let serializable = SerializableLweTernaryBootstrapKey64 {
+            version: LweTernaryBootstrapKey64Version::V0,
             inner: &entity.0,
         };
         bincode::serialize(&serializable)
@@ -3196,7 +4735,7 @@ impl EntitySerializationEngine<LwePackingKeyswitchKey64, Vec<u8>> for DefaultSer
             .map_err(EntitySerializationError::Engine)
     }
 
-    unsafe fn serialize_unchecked(&mut self, entity: &LwePackingKeyswitchKey64) -> Vec<u8> {
+    unsafe fn serialize_unchecked(&mut self, entity: &LweTernaryBootstrapKey64) -> Vec<u8> {
         self.serialize(entity).unwrap()
     }
 }
@@ -0,0 +1,380 @@
+#![allow(clippy::missing_safety_doc)]
+
+use crate::commons::crypto::bootstrap::{
+    StandardBootstrapKey as ImplStandardBootstrapKey,
+    StandardSeededBootstrapKey as ImplStandardSeededBootstrapKey,
+};
+use crate::commons::crypto::encoding::{
+    Cleartext as ImplCleartext, CleartextList as ImplCleartextList,
+    FloatEncoder as ImplFloatEncoder, Plaintext as ImplPlaintext,
+    PlaintextList as ImplPlaintextList,
+};
+use crate::commons::crypto::ggsw::{
+    StandardGgswCiphertext as ImplStandardGgswCiphertext,
+    StandardGgswSeededCiphertext as ImplStandardGgswSeededCiphertext,
+};
+use crate::commons::crypto::glwe::{
+    GlweAutomorphismKey as ImplGlweAutomorphismKey, GlweCiphertext as ImplGlweCiphertext,
+    GlweKeyswitchKey as ImplGlweKeyswitchKey, GlweList as ImplGlweList,
+    GlwePublicKey as ImplGlwePublicKey, GlweRelinearizationKey as ImplGlweRelinearizationKey,
+    GlweSeededCiphertext as ImplGlweSeededCiphertext,
+    GlweSeededKeyswitchKey as ImplGlweSeededKeyswitchKey, GlweSeededList as ImplGlweSeededList,
+    LwePackingKeyswitchKey as ImplLwePackingKeyswitchKey,
+    LweSeededPackingKeyswitchKey as ImplLweSeededPackingKeyswitchKey,
+    LweSeededPrivateFunctionalPackingKeyswitchKey as ImplLweSeededPrivateFunctionalPackingKeyswitchKey,
+    LweSeededPrivateFunctionalPackingKeyswitchKeyList as ImplLweSeededPrivateFunctionalPackingKeyswitchKeyList,
+};
+use crate::commons::crypto::lwe::{
+    LweCiphertext as ImplLweCiphertext, LweCompressedList as ImplLweCompressedList,
+    LweKeyswitchKey as ImplLweKeyswitchKey, LweList as ImplLweList,
+    LweSeededCiphertext as ImplLweSeededCiphertext,
+    LweSeededKeyswitchKey as ImplLweSeededKeyswitchKey, LweSeededList as ImplLweSeededList,
+};
+use crate::commons::crypto::secret::RandomKeyKind;
+use crate::prelude::{
+    Cleartext32, Cleartext32Version, Cleartext64, Cleartext64Version, CleartextF64,
+    CleartextF64Version, CleartextVector32, CleartextVector32Version, CleartextVector64,
+    CleartextVector64Version, CleartextVectorF64, CleartextVectorF64Version,
+    DefaultSerializationEngine, DefaultSerializationError, EntityStreamSerializationEngine,
+    EntityStreamSerializationError, FloatEncoder, FloatEncoderVector, FloatEncoderVectorVersion,
+    FloatEncoderVersion, GgswCiphertext32, GgswCiphertext32Version, GgswCiphertext64,
+    GgswCiphertext64Version, GgswSeededCiphertext32, GgswSeededCiphertext32Version,
+    GgswSeededCiphertext64, GgswSeededCiphertext64Version, GlweAutomorphismKey32,
+    GlweAutomorphismKey32Version, GlweAutomorphismKey64, GlweAutomorphismKey64Version,
+    GlweCiphertext32, GlweCiphertext32Version, GlweCiphertext64, GlweCiphertext64Version,
+    GlweCiphertextMutView32, GlweCiphertextMutView64, GlweCiphertextVector32,
+    GlweCiphertextVector32Version, GlweCiphertextVector64, GlweCiphertextVector64Version,
+    GlweCiphertextView32, GlweCiphertextView64, GlweKeyswitchKey32, GlweKeyswitchKey32Version,
+    GlweKeyswitchKey64, GlweKeyswitchKey64Version, GlwePublicKey32, GlwePublicKey32Version,
+    GlwePublicKey64, GlwePublicKey64Version, GlweRelinearizationKey32,
+    GlweRelinearizationKey32Version, GlweRelinearizationKey64, GlweRelinearizationKey64Version,
+    GlweSecretKey32, GlweSecretKey32Version, GlweSecretKey64, GlweSecretKey64Version,
+    GlweSeededCiphertext32, GlweSeededCiphertext32Version, GlweSeededCiphertext64,
+    GlweSeededCiphertext64Version, GlweSeededCiphertextVector32,
+    GlweSeededCiphertextVector32Version, GlweSeededCiphertextVector64,
+    GlweSeededCiphertextVector64Version, GlweSeededKeyswitchKey32, GlweSeededKeyswitchKey32Version,
+    GlweSeededKeyswitchKey64, GlweSeededKeyswitchKey64Version, LweBootstrapKey32,
+    LweBootstrapKey32Version, LweBootstrapKey64, LweBootstrapKey64Version, LweCiphertext32,
+    LweCiphertext32Version, LweCiphertext64, LweCiphertext64Version, LweCiphertextMutView32,
+    LweCiphertextMutView64, LweCiphertextVector32, LweCiphertextVector32Version,
+    LweCiphertextVector64, LweCiphertextVector64Version, LweCiphertextVectorMutView32,
+    LweCiphertextVectorMutView64, LweCiphertextVectorView32, LweCiphertextVectorView64,
+    LweCiphertextView32, LweCiphertextView64, LweCompressedCiphertextVector32,
+    LweCompressedCiphertextVector32Version, LweCompressedCiphertextVector64,
+    LweCompressedCiphertextVector64Version, LweKeyswitchKey32, LweKeyswitchKey32Version,
+    LweKeyswitchKey64, LweKeyswitchKey64Version, LwePackingKeyswitchKey32,
+    LwePackingKeyswitchKey32Version, LwePackingKeyswitchKey64, LwePackingKeyswitchKey64Version,
+    LweSecretKey32, LweSecretKey32Version, LweSecretKey64, LweSecretKey64Version,
+    LweSeededBootstrapKey32, LweSeededBootstrapKey32Version, LweSeededBootstrapKey64,
+    LweSeededBootstrapKey64Version, LweSeededCiphertext32, LweSeededCiphertext32Version,
+    LweSeededCiphertext64, LweSeededCiphertext64Version, LweSeededCiphertextVector32,
+    LweSeededCiphertextVector32Version, LweSeededCiphertextVector64,
+    LweSeededCiphertextVector64Version,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version,
+    LweSeededKeyswitchKey32, LweSeededKeyswitchKey32Version, LweSeededKeyswitchKey64,
+    LweSeededKeyswitchKey64Version, LweSeededPackingKeyswitchKey32,
+    LweSeededPackingKeyswitchKey32Version, LweSeededPackingKeyswitchKey64,
+    LweSeededPackingKeyswitchKey64Version, LweSeededPrivateFunctionalPackingKeyswitchKey32,
+    LweSeededPrivateFunctionalPackingKeyswitchKey32Version,
+    LweSeededPrivateFunctionalPackingKeyswitchKey64,
+    LweSeededPrivateFunctionalPackingKeyswitchKey64Version, LweSeededPublicKey32,
+    LweSeededPublicKey32Version, LweSeededPublicKey64, LweSeededPublicKey64Version,
+    LweTernaryBootstrapKey32, LweTernaryBootstrapKey32Version, LweTernaryBootstrapKey64,
+    LweTernaryBootstrapKey64Version, Plaintext32, Plaintext32Version, Plaintext64,
+    Plaintext64Version, PlaintextVector32, PlaintextVector32Version, PlaintextVector64,
+    PlaintextVector64Version,
+};
+use serde::Serialize;
+use std::fmt::Debug;
+use std::io::Write;
+
+/// The versioned layout shared by the serialized entities. It is serialized by bincode as the
+/// version followed by the inner value, exactly like the layouts used by the
+/// [`EntitySerializationEngine`](crate::prelude::EntitySerializationEngine) implementations.
+#[derive(Serialize)]
+struct SerializableEntity<'a, Version, Inner> {
+    version: Version,
+    inner: &'a Inner,
+}
+
+/// Implements [`EntityStreamSerializationEngine`] for [`DefaultSerializationEngine`], for each
+/// `Entity => EntityVersion, Inner;` line, where `Inner` is the type wrapped by `Entity`. The
+/// documentation attributes preceding a line are attached to the `serialize_into` method.
+macro_rules! implement_stream_serialization {
+    ($($(#[$doc:meta])* $entity:ident $(<$lifetime:lifetime>)? => $version:ident, $inner:ty;)*) => {
+        $(
+            #[doc = concat!(
+                "# Description:\n",
+                "Implementation of [`EntityStreamSerializationEngine`] for ",
+                "[`DefaultSerializationEngine`] that serializes a [`",
+                stringify!($entity),
+                "`] entity to any [`Write`] sink."
+            )]
+            impl<$($lifetime,)? W: Write> EntityStreamSerializationEngine<$entity$(<$lifetime>)?, W>
+                for DefaultSerializationEngine
+            {
+                $(#[$doc])*
+                fn serialize_into(
+                    &mut self,
+                    entity: &$entity$(<$lifetime>)?,
+                    writer: W,
+                ) -> Result<(), EntityStreamSerializationError<Self::EngineError>> {
+                    let serializable: SerializableEntity<$version, $inner> = SerializableEntity {
+                        version: $version::V0,
+                        inner: &entity.0,
+                    };
+                    bincode::serialize_into(writer, &serializable)
+                        .map_err(DefaultSerializationError::Serialization)
+                        .map_err(EntityStreamSerializationError::Engine)
+                }
+
+                unsafe fn serialize_into_unchecked(
+                    &mut self,
+                    entity: &$entity$(<$lifetime>)?,
+                    writer: W,
+                ) {
+                    self.serialize_into(entity, writer).unwrap()
+                }
+            }
+        )*
+    };
+}
+
+implement_stream_serialization! {
+    Cleartext32 => Cleartext32Version, ImplCleartext<u32>;
+    Cleartext64 => Cleartext64Version, ImplCleartext<u64>;
+    CleartextF64 => CleartextF64Version, ImplCleartext<f64>;
+    CleartextVector32 => CleartextVector32Version, ImplCleartextList<Vec<u32>>;
+    CleartextVector64 => CleartextVector64Version, ImplCleartextList<Vec<u64>>;
+    CleartextVectorF64 => CleartextVectorF64Version, ImplCleartextList<Vec<f64>>;
+    FloatEncoder => FloatEncoderVersion, ImplFloatEncoder;
+    FloatEncoderVector => FloatEncoderVectorVersion, Vec<ImplFloatEncoder>;
+    GgswCiphertext32 => GgswCiphertext32Version, ImplStandardGgswCiphertext<Vec<u32>>;
+    GgswCiphertext64 => GgswCiphertext64Version, ImplStandardGgswCiphertext<Vec<u64>>;
+    GgswSeededCiphertext32 =>
+        GgswSeededCiphertext32Version, ImplStandardGgswSeededCiphertext<Vec<u32>>;
+    GgswSeededCiphertext64 =>
+        GgswSeededCiphertext64Version, ImplStandardGgswSeededCiphertext<Vec<u64>>;
+    GlweCiphertext32 => GlweCiphertext32Version, ImplGlweCiphertext<Vec<u32>>;
+    GlweCiphertext64 => GlweCiphertext64Version, ImplGlweCiphertext<Vec<u64>>;
+    GlweCiphertextMutView32<'b> => GlweCiphertext32Version, ImplGlweCiphertext<&'b mut [u32]>;
+    GlweCiphertextMutView64<'b> => GlweCiphertext64Version, ImplGlweCiphertext<&'b mut [u64]>;
+    GlweCiphertextVector32 => GlweCiphertextVector32Version, ImplGlweList<Vec<u32>>;
+    GlweCiphertextVector64 => GlweCiphertextVector64Version, ImplGlweList<Vec<u64>>;
+    GlweCiphertextView32<'b> => GlweCiphertext32Version, ImplGlweCiphertext<&'b [u32]>;
+    GlweCiphertextView64<'b> => GlweCiphertext64Version, ImplGlweCiphertext<&'b [u64]>;
+    GlweAutomorphismKey32 => GlweAutomorphismKey32Version, ImplGlweAutomorphismKey<Vec<u32>>;
+    GlweAutomorphismKey64 => GlweAutomorphismKey64Version, ImplGlweAutomorphismKey<Vec<u64>>;
+    GlweKeyswitchKey32 => GlweKeyswitchKey32Version, ImplGlweKeyswitchKey<Vec<u32>>;
+    GlweKeyswitchKey64 => GlweKeyswitchKey64Version, ImplGlweKeyswitchKey<Vec<u64>>;
+    GlwePublicKey32 => GlwePublicKey32Version, ImplGlwePublicKey<Vec<u32>>;
+    GlwePublicKey64 => GlwePublicKey64Version, ImplGlwePublicKey<Vec<u64>>;
+    GlweRelinearizationKey32 =>
+        GlweRelinearizationKey32Version, ImplGlweRelinearizationKey<Vec<u32>>;
+    GlweRelinearizationKey64 =>
+        GlweRelinearizationKey64Version, ImplGlweRelinearizationKey<Vec<u64>>;
+    GlweSeededCiphertext32 => GlweSeededCiphertext32Version, ImplGlweSeededCiphertext<Vec<u32>>;
+    GlweSeededCiphertext64 => GlweSeededCiphertext64Version, ImplGlweSeededCiphertext<Vec<u64>>;
+    GlweSeededCiphertextVector32 =>
+        GlweSeededCiphertextVector32Version, ImplGlweSeededList<Vec<u32>>;
+    GlweSeededCiphertextVector64 =>
+        GlweSeededCiphertextVector64Version, ImplGlweSeededList<Vec<u64>>;
+    GlweSeededKeyswitchKey32 =>
+        GlweSeededKeyswitchKey32Version, ImplGlweSeededKeyswitchKey<Vec<u32>>;
+    GlweSeededKeyswitchKey64 =>
+        GlweSeededKeyswitchKey64Version, ImplGlweSeededKeyswitchKey<Vec<u64>>;
+    LweBootstrapKey32 => LweBootstrapKey32Version, ImplStandardBootstrapKey<Vec<u32>>;
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// use std::io::{BufReader, BufWriter, Write};
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_sk: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// // The key is written straight to the file, without being serialized in memory first.
+    /// let path = std::env::temp_dir().join("concrete_core_doctest_lwe_bootstrap_key_64.bin");
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut writer = BufWriter::new(std::fs::File::create(&path)?);
+    /// serialization_engine.serialize_into(&bsk, &mut writer)?;
+    /// writer.flush()?;
+    ///
+    /// let reader = BufReader::new(std::fs::File::open(&path)?);
+    /// let recovered: LweBootstrapKey64 = serialization_engine.deserialize_from(reader)?;
+    /// assert_eq!(bsk, recovered);
+    /// # std::fs::remove_file(&path)?;
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    LweBootstrapKey64 => LweBootstrapKey64Version, ImplStandardBootstrapKey<Vec<u64>>;
+    LweCiphertext32 => LweCiphertext32Version, ImplLweCiphertext<Vec<u32>>;
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// // Several entities can be written in a row to the same stream.
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let mut stream: Vec<u8> = Vec::new();
+    /// serialization_engine.serialize_into(&ciphertext_1, &mut stream)?;
+    /// serialization_engine.serialize_into(&ciphertext_2, &mut stream)?;
+    ///
+    /// // The stream serialization is compatible with the in-memory one.
+    /// let serialized = serialization_engine.serialize(&ciphertext_1)?;
+    /// assert_eq!(&stream[..serialized.len()], serialized.as_slice());
+    ///
+    /// let mut reader = stream.as_slice();
+    /// let recovered_1: LweCiphertext64 = serialization_engine.deserialize_from(&mut reader)?;
+    /// let recovered_2: LweCiphertext64 = serialization_engine.deserialize_from(&mut reader)?;
+    /// assert_eq!(ciphertext_1, recovered_1);
+    /// assert_eq!(ciphertext_2, recovered_2);
+    /// assert!(reader.is_empty());
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    LweCiphertext64 => LweCiphertext64Version, ImplLweCiphertext<Vec<u64>>;
+    LweCiphertextMutView32<'b> => LweCiphertext32Version, ImplLweCiphertext<&'b mut [u32]>;
+    LweCiphertextMutView64<'b> => LweCiphertext64Version, ImplLweCiphertext<&'b mut [u64]>;
+    LweCiphertextVector32 => LweCiphertextVector32Version, ImplLweList<Vec<u32>>;
+    LweCiphertextVector64 => LweCiphertextVector64Version, ImplLweList<Vec<u64>>;
+    LweCiphertextVectorMutView32<'b> => LweCiphertextVector32Version, ImplLweList<&'b mut [u32]>;
+    LweCiphertextVectorMutView64<'b> => LweCiphertextVector64Version, ImplLweList<&'b mut [u64]>;
+    LweCiphertextVectorView32<'b> => LweCiphertextVector32Version, ImplLweList<&'b [u32]>;
+    LweCiphertextVectorView64<'b> => LweCiphertextVector64Version, ImplLweList<&'b [u64]>;
+    LweCiphertextView32<'b> => LweCiphertext32Version, ImplLweCiphertext<&'b [u32]>;
+    LweCiphertextView64<'b> => LweCiphertext64Version, ImplLweCiphertext<&'b [u64]>;
+    LweCompressedCiphertextVector32 =>
+        LweCompressedCiphertextVector32Version, ImplLweCompressedList<Vec<u32>>;
+    LweCompressedCiphertextVector64 =>
+        LweCompressedCiphertextVector64Version, ImplLweCompressedList<Vec<u64>>;
+    LweKeyswitchKey32 => LweKeyswitchKey32Version, ImplLweKeyswitchKey<Vec<u32>>;
+    LweKeyswitchKey64 => LweKeyswitchKey64Version, ImplLweKeyswitchKey<Vec<u64>>;
+    LwePackingKeyswitchKey32 =>
+        LwePackingKeyswitchKey32Version, ImplLwePackingKeyswitchKey<Vec<u32>>;
+    LwePackingKeyswitchKey64 =>
+        LwePackingKeyswitchKey64Version, ImplLwePackingKeyswitchKey<Vec<u64>>;
+    LweSeededBootstrapKey32 =>
+        LweSeededBootstrapKey32Version, ImplStandardSeededBootstrapKey<Vec<u32>>;
+    LweSeededBootstrapKey64 =>
+        LweSeededBootstrapKey64Version, ImplStandardSeededBootstrapKey<Vec<u64>>;
+    LweSeededCiphertext32 => LweSeededCiphertext32Version, ImplLweSeededCiphertext<u32>;
+    LweSeededCiphertext64 => LweSeededCiphertext64Version, ImplLweSeededCiphertext<u64>;
+    LweSeededCiphertextVector32 => LweSeededCiphertextVector32Version, ImplLweSeededList<Vec<u32>>;
+    LweSeededCiphertextVector64 => LweSeededCiphertextVector64Version, ImplLweSeededList<Vec<u64>>;
+    LweSeededKeyswitchKey32 => LweSeededKeyswitchKey32Version, ImplLweSeededKeyswitchKey<Vec<u32>>;
+    LweSeededKeyswitchKey64 => LweSeededKeyswitchKey64Version, ImplLweSeededKeyswitchKey<Vec<u64>>;
+    LweSeededPackingKeyswitchKey32 =>
+        LweSeededPackingKeyswitchKey32Version, ImplLweSeededPackingKeyswitchKey<Vec<u32>>;
+    LweSeededPackingKeyswitchKey64 =>
+        LweSeededPackingKeyswitchKey64Version, ImplLweSeededPackingKeyswitchKey<Vec<u64>>;
+    LweSeededPrivateFunctionalPackingKeyswitchKey32 =>
+        LweSeededPrivateFunctionalPackingKeyswitchKey32Version,
+        ImplLweSeededPrivateFunctionalPackingKeyswitchKey<Vec<u32>>;
+    LweSeededPrivateFunctionalPackingKeyswitchKey64 =>
+        LweSeededPrivateFunctionalPackingKeyswitchKey64Version,
+        ImplLweSeededPrivateFunctionalPackingKeyswitchKey<Vec<u64>>;
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 =>
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version,
+        ImplLweSeededPrivateFunctionalPackingKeyswitchKeyList<Vec<u32>>;
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 =>
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version,
+        ImplLweSeededPrivateFunctionalPackingKeyswitchKeyList<Vec<u64>>;
+    LweSeededPublicKey32 => LweSeededPublicKey32Version, ImplLweSeededList<Vec<u32>>;
+    LweSeededPublicKey64 => LweSeededPublicKey64Version, ImplLweSeededList<Vec<u64>>;
+    LweTernaryBootstrapKey32 => LweTernaryBootstrapKey32Version, ImplStandardBootstrapKey<Vec<u32>>;
+    LweTernaryBootstrapKey64 => LweTernaryBootstrapKey64Version, ImplStandardBootstrapKey<Vec<u64>>;
+    Plaintext32 => Plaintext32Version, ImplPlaintext<u32>;
+    Plaintext64 => Plaintext64Version, ImplPlaintext<u64>;
+    PlaintextVector32 => PlaintextVector32Version, ImplPlaintextList<Vec<u32>>;
+    PlaintextVector64 => PlaintextVector64Version, ImplPlaintextList<Vec<u64>>;
+}
+
+/// Implements [`EntityStreamSerializationEngine`] for [`DefaultSerializationEngine`], for each
+/// `Entity => EntityVersion;` line, where `Entity` is a secret key generic over its kind. The kind
+/// of the key is written between the version and the key.
+macro_rules! implement_secret_key_stream_serialization {
+    ($($entity:ident => $version:ident;)*) => {
+        $(
+            #[doc = concat!(
+                "# Description:\n",
+                "Implementation of [`EntityStreamSerializationEngine`] for ",
+                "[`DefaultSerializationEngine`] that serializes a [`",
+                stringify!($entity),
+                "`] entity to any [`Write`] sink."
+            )]
+            impl<Kind, W> EntityStreamSerializationEngine<$entity<Kind>, W>
+                for DefaultSerializationEngine
+            where
+                Kind: RandomKeyKind + Debug,
+                W: Write,
+            {
+                fn serialize_into(
+                    &mut self,
+                    entity: &$entity<Kind>,
+                    writer: W,
+                ) -> Result<(), EntityStreamSerializationError<Self::EngineError>> {
+                    let serializable = ($version::V1, Kind::TAG, &entity.0);
+                    bincode::serialize_into(writer, &serializable)
+                        .map_err(DefaultSerializationError::Serialization)
+                        .map_err(EntityStreamSerializationError::Engine)
+                }
+
+                unsafe fn serialize_into_unchecked(&mut self, entity: &$entity<Kind>, writer: W) {
+                    self.serialize_into(entity, writer).unwrap()
+                }
+            }
+        )*
+    };
+}
+
+implement_secret_key_stream_serialization! {
+    GlweSecretKey32 => GlweSecretKey32Version;
+    GlweSecretKey64 => GlweSecretKey64Version;
+    LweSecretKey32 => LweSecretKey32Version;
+    LweSecretKey64 => LweSecretKey64Version;
+}
@@ -9,6 +9,7 @@ pub enum DefaultSerializationError {
     Serialization(bincode::Error),
     Deserialization(bincode::Error),
     UnsupportedVersion,
+    KeyKindMismatch,
 }
 
 #[allow(unused_variables)]
@@ -28,6 +29,12 @@ impl Display for DefaultSerializationError {
                     "The version used to serialize the entity is not supported."
                 )
             }
+            DefaultSerializationError::KeyKindMismatch => {
+                write!(
+                    f,
+                    "The kind of the serialized secret key does not match the requested one."
+                )
+            }
         }
     }
 }
@@ -52,3 +59,5 @@ impl AbstractEngine for DefaultSerializationEngine {
 
 mod entity_deserialization;
 mod entity_serialization;
+mod entity_stream_deserialization;
+mod entity_stream_serialization;
@@ -0,0 +1,85 @@
+use crate::commons::crypto::glwe::GlweAutomorphismKey as ImplGlweAutomorphismKey;
+use crate::specification::entities::markers::GlweAutomorphismKeyKind;
+use crate::specification::entities::{AbstractEntity, GlweAutomorphismKeyEntity};
+use concrete_commons::parameters::{
+    AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    PolynomialSize,
+};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a GLWE automorphism key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweAutomorphismKey32(pub(crate) ImplGlweAutomorphismKey<Vec<u32>>);
+
+impl AbstractEntity for GlweAutomorphismKey32 {
+    type Kind = GlweAutomorphismKeyKind;
+}
+
+impl GlweAutomorphismKeyEntity for GlweAutomorphismKey32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn automorphism_exponents(&self) -> &[AutomorphismExponent] {
+        self.0.automorphism_exponents()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweAutomorphismKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a GLWE automorphism key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweAutomorphismKey64(pub(crate) ImplGlweAutomorphismKey<Vec<u64>>);
+
+impl AbstractEntity for GlweAutomorphismKey64 {
+    type Kind = GlweAutomorphismKeyKind;
+}
+
+impl GlweAutomorphismKeyEntity for GlweAutomorphismKey64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn automorphism_exponents(&self) -> &[AutomorphismExponent] {
+        self.0.automorphism_exponents()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweAutomorphismKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
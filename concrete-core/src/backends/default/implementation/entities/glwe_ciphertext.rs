@@ -57,6 +57,24 @@ pub(crate) enum GlweCiphertext64Version {
     Unsupported,
 }
 
+/// A structure representing a GLWE ciphertext with 128 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweCiphertext128(pub(crate) ImplGlweCiphertext<Vec<u128>>);
+
+impl AbstractEntity for GlweCiphertext128 {
+    type Kind = GlweCiphertextKind;
+}
+
+impl GlweCiphertextEntity for GlweCiphertext128 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
 // GlweCiphertextViews are just GlweCiphertext entities that do not own their memory, they use a
 // slice as a container as opposed to Vec for the standard GlweCiphertext
 
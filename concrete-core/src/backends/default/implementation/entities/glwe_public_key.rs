@@ -0,0 +1,64 @@
+use crate::commons::crypto::glwe::GlwePublicKey as ImpGlwePublicKey;
+use crate::specification::entities::markers::GlwePublicKeyKind;
+use crate::specification::entities::{AbstractEntity, GlwePublicKeyEntity};
+use concrete_commons::parameters::{
+    GlweDimension, GlwePublicKeyZeroEncryptionCount, PolynomialSize,
+};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a GLWE public key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlwePublicKey32(pub(crate) ImpGlwePublicKey<Vec<u32>>);
+impl AbstractEntity for GlwePublicKey32 {
+    type Kind = GlwePublicKeyKind;
+}
+impl GlwePublicKeyEntity for GlwePublicKey32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn glwe_zero_encryption_count(&self) -> GlwePublicKeyZeroEncryptionCount {
+        self.0.zero_encryption_count()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlwePublicKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a GLWE public key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlwePublicKey64(pub(crate) ImpGlwePublicKey<Vec<u64>>);
+impl AbstractEntity for GlwePublicKey64 {
+    type Kind = GlwePublicKeyKind;
+}
+impl GlwePublicKeyEntity for GlwePublicKey64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn glwe_zero_encryption_count(&self) -> GlwePublicKeyZeroEncryptionCount {
+        self.0.zero_encryption_count()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlwePublicKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
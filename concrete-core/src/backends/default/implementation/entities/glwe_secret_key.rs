@@ -1,18 +1,24 @@
 use crate::commons::crypto::secret::GlweSecretKey as ImpGlweSecretKey;
 use crate::specification::entities::markers::GlweSecretKeyKind;
 use crate::specification::entities::{AbstractEntity, GlweSecretKeyEntity};
-use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::key_kinds::{BinaryKeyKind, KeyKind};
 use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 #[cfg(feature = "backend_default_serialization")]
 use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
 
 /// A structure representing a GLWE secret key with 32 bits of precision.
+///
+/// The `Kind` parameter gives the distribution of the key coefficients, and defaults to
+/// [`BinaryKeyKind`].
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct GlweSecretKey32(pub(crate) ImpGlweSecretKey<BinaryKeyKind, Vec<u32>>);
-impl AbstractEntity for GlweSecretKey32 {
+pub struct GlweSecretKey32<Kind: KeyKind = BinaryKeyKind>(
+    pub(crate) ImpGlweSecretKey<Kind, Vec<u32>>,
+);
+impl<Kind: KeyKind + Debug> AbstractEntity for GlweSecretKey32<Kind> {
     type Kind = GlweSecretKeyKind;
 }
-impl GlweSecretKeyEntity for GlweSecretKey32 {
+impl<Kind: KeyKind + Debug> GlweSecretKeyEntity for GlweSecretKey32<Kind> {
     fn glwe_dimension(&self) -> GlweDimension {
         self.0.key_size()
     }
@@ -26,17 +32,23 @@ impl GlweSecretKeyEntity for GlweSecretKey32 {
 #[derive(Serialize, Deserialize)]
 pub(crate) enum GlweSecretKey32Version {
     V0,
+    V1,
     #[serde(other)]
     Unsupported,
 }
 
 /// A structure representing a GLWE secret key with 64 bits of precision.
+///
+/// The `Kind` parameter gives the distribution of the key coefficients, and defaults to
+/// [`BinaryKeyKind`].
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct GlweSecretKey64(pub(crate) ImpGlweSecretKey<BinaryKeyKind, Vec<u64>>);
-impl AbstractEntity for GlweSecretKey64 {
+pub struct GlweSecretKey64<Kind: KeyKind = BinaryKeyKind>(
+    pub(crate) ImpGlweSecretKey<Kind, Vec<u64>>,
+);
+impl<Kind: KeyKind + Debug> AbstractEntity for GlweSecretKey64<Kind> {
     type Kind = GlweSecretKeyKind;
 }
-impl GlweSecretKeyEntity for GlweSecretKey64 {
+impl<Kind: KeyKind + Debug> GlweSecretKeyEntity for GlweSecretKey64<Kind> {
     fn glwe_dimension(&self) -> GlweDimension {
         self.0.key_size()
     }
@@ -50,6 +62,28 @@ impl GlweSecretKeyEntity for GlweSecretKey64 {
 #[derive(Serialize, Deserialize)]
 pub(crate) enum GlweSecretKey64Version {
     V0,
+    V1,
     #[serde(other)]
     Unsupported,
 }
+
+/// A structure representing a GLWE secret key with 128 bits of precision.
+///
+/// The `Kind` parameter gives the distribution of the key coefficients, and defaults to
+/// [`BinaryKeyKind`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweSecretKey128<Kind: KeyKind = BinaryKeyKind>(
+    pub(crate) ImpGlweSecretKey<Kind, Vec<u128>>,
+);
+impl<Kind: KeyKind + Debug> AbstractEntity for GlweSecretKey128<Kind> {
+    type Kind = GlweSecretKeyKind;
+}
+impl<Kind: KeyKind + Debug> GlweSecretKeyEntity for GlweSecretKey128<Kind> {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
@@ -0,0 +1,93 @@
+use crate::commons::crypto::glwe::GlweSeededKeyswitchKey as ImplGlweSeededKeyswitchKey;
+use crate::commons::math::random::CompressionSeed;
+use crate::specification::entities::markers::GlweSeededKeyswitchKeyKind;
+use crate::specification::entities::{AbstractEntity, GlweSeededKeyswitchKeyEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a seeded GLWE keyswitch key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweSeededKeyswitchKey32(pub(crate) ImplGlweSeededKeyswitchKey<Vec<u32>>);
+
+impl AbstractEntity for GlweSeededKeyswitchKey32 {
+    type Kind = GlweSeededKeyswitchKeyKind;
+}
+
+impl GlweSeededKeyswitchKeyEntity for GlweSeededKeyswitchKey32 {
+    fn input_glwe_dimension(&self) -> GlweDimension {
+        self.0.input_glwe_dimension()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn compression_seed(&self) -> CompressionSeed {
+        self.0.compression_seed()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweSeededKeyswitchKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a seeded GLWE keyswitch key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweSeededKeyswitchKey64(pub(crate) ImplGlweSeededKeyswitchKey<Vec<u64>>);
+
+impl AbstractEntity for GlweSeededKeyswitchKey64 {
+    type Kind = GlweSeededKeyswitchKeyKind;
+}
+
+impl GlweSeededKeyswitchKeyEntity for GlweSeededKeyswitchKey64 {
+    fn input_glwe_dimension(&self) -> GlweDimension {
+        self.0.input_glwe_dimension()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn compression_seed(&self) -> CompressionSeed {
+        self.0.compression_seed()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum GlweSeededKeyswitchKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
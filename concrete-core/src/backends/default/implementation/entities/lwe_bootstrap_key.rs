@@ -79,6 +79,34 @@ pub(crate) enum LweBootstrapKey64Version {
     Unsupported,
 }
 
+/// A structure representing an LWE bootstrap key with 128 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweBootstrapKey128(pub(crate) ImplStandardBootstrapKey<Vec<u128>>);
+impl AbstractEntity for LweBootstrapKey128 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for LweBootstrapKey128 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+
 /// A structure representing an LWE bootstrap key with 32 bits of precision.
 ///
 /// By _view_ here, we mean that the entity does not own the data, but mutably borrows it.
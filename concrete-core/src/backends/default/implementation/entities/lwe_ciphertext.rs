@@ -45,6 +45,18 @@ pub(crate) enum LweCiphertext64Version {
     Unsupported,
 }
 
+/// A structure representing an LWE ciphertext with 128 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCiphertext128(pub(crate) ImplLweCiphertext<Vec<u128>>);
+impl AbstractEntity for LweCiphertext128 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for LweCiphertext128 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+}
+
 // LweCiphertextViews are just LweCiphertext entities that do not own their memory, they use a slice
 // as a container as opposed to Vec for the standard LweCiphertext
 
@@ -0,0 +1,66 @@
+use crate::commons::crypto::lwe::LweCompressedList as ImplLweCompressedList;
+use crate::specification::entities::markers::LweCompressedCiphertextVectorKind;
+use crate::specification::entities::{AbstractEntity, LweCompressedCiphertextVectorEntity};
+use concrete_commons::parameters::{CiphertextModulusLog, LweCiphertextCount, LweDimension};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a vector of compressed LWE ciphertexts with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCompressedCiphertextVector32(pub(crate) ImplLweCompressedList<Vec<u32>>);
+
+impl AbstractEntity for LweCompressedCiphertextVector32 {
+    type Kind = LweCompressedCiphertextVectorKind;
+}
+
+impl LweCompressedCiphertextVectorEntity for LweCompressedCiphertextVector32 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.mask_size()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+
+    fn ciphertext_modulus_log(&self) -> CiphertextModulusLog {
+        self.0.ciphertext_modulus_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweCompressedCiphertextVector32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a vector of compressed LWE ciphertexts with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCompressedCiphertextVector64(pub(crate) ImplLweCompressedList<Vec<u64>>);
+
+impl AbstractEntity for LweCompressedCiphertextVector64 {
+    type Kind = LweCompressedCiphertextVectorKind;
+}
+
+impl LweCompressedCiphertextVectorEntity for LweCompressedCiphertextVector64 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.mask_size()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+
+    fn ciphertext_modulus_log(&self) -> CiphertextModulusLog {
+        self.0.ciphertext_modulus_log()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweCompressedCiphertextVector64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -68,3 +68,27 @@ pub(crate) enum LweKeyswitchKey64Version {
     #[serde(other)]
     Unsupported,
 }
+
+/// A structure representing an LWE keyswitch key with 128 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweKeyswitchKey128(pub(crate) ImplLweKeyswitchKey<Vec<u128>>);
+impl AbstractEntity for LweKeyswitchKey128 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for LweKeyswitchKey128 {
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
@@ -1,18 +1,24 @@
 use crate::commons::crypto::secret::LweSecretKey as ImpLweSecretKey;
 use crate::specification::entities::markers::LweSecretKeyKind;
 use crate::specification::entities::{AbstractEntity, LweSecretKeyEntity};
-use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::key_kinds::{BinaryKeyKind, KeyKind};
 use concrete_commons::parameters::LweDimension;
 #[cfg(feature = "backend_default_serialization")]
 use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
 
 /// A structure representing an LWE secret key with 32 bits of precision.
+///
+/// The `Kind` parameter gives the distribution of the key coefficients, and defaults to
+/// [`BinaryKeyKind`].
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct LweSecretKey32(pub(crate) ImpLweSecretKey<BinaryKeyKind, Vec<u32>>);
-impl AbstractEntity for LweSecretKey32 {
+pub struct LweSecretKey32<Kind: KeyKind = BinaryKeyKind>(
+    pub(crate) ImpLweSecretKey<Kind, Vec<u32>>,
+);
+impl<Kind: KeyKind + Debug> AbstractEntity for LweSecretKey32<Kind> {
     type Kind = LweSecretKeyKind;
 }
-impl LweSecretKeyEntity for LweSecretKey32 {
+impl<Kind: KeyKind + Debug> LweSecretKeyEntity for LweSecretKey32<Kind> {
     fn lwe_dimension(&self) -> LweDimension {
         self.0.key_size()
     }
@@ -22,17 +28,23 @@ impl LweSecretKeyEntity for LweSecretKey32 {
 #[derive(Serialize, Deserialize)]
 pub(crate) enum LweSecretKey32Version {
     V0,
+    V1,
     #[serde(other)]
     Unsupported,
 }
 
 /// A structure representing an LWE secret key with 64 bits of precision.
+///
+/// The `Kind` parameter gives the distribution of the key coefficients, and defaults to
+/// [`BinaryKeyKind`].
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct LweSecretKey64(pub(crate) ImpLweSecretKey<BinaryKeyKind, Vec<u64>>);
-impl AbstractEntity for LweSecretKey64 {
+pub struct LweSecretKey64<Kind: KeyKind = BinaryKeyKind>(
+    pub(crate) ImpLweSecretKey<Kind, Vec<u64>>,
+);
+impl<Kind: KeyKind + Debug> AbstractEntity for LweSecretKey64<Kind> {
     type Kind = LweSecretKeyKind;
 }
-impl LweSecretKeyEntity for LweSecretKey64 {
+impl<Kind: KeyKind + Debug> LweSecretKeyEntity for LweSecretKey64<Kind> {
     fn lwe_dimension(&self) -> LweDimension {
         self.0.key_size()
     }
@@ -42,6 +54,24 @@ impl LweSecretKeyEntity for LweSecretKey64 {
 #[derive(Serialize, Deserialize)]
 pub(crate) enum LweSecretKey64Version {
     V0,
+    V1,
     #[serde(other)]
     Unsupported,
 }
+
+/// A structure representing an LWE secret key with 128 bits of precision.
+///
+/// The `Kind` parameter gives the distribution of the key coefficients, and defaults to
+/// [`BinaryKeyKind`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSecretKey128<Kind: KeyKind = BinaryKeyKind>(
+    pub(crate) ImpLweSecretKey<Kind, Vec<u128>>,
+);
+impl<Kind: KeyKind + Debug> AbstractEntity for LweSecretKey128<Kind> {
+    type Kind = LweSecretKeyKind;
+}
+impl<Kind: KeyKind + Debug> LweSecretKeyEntity for LweSecretKey128<Kind> {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
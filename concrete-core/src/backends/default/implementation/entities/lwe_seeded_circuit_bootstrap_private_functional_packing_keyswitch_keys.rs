@@ -0,0 +1,110 @@
+use crate::commons::crypto::glwe::LweSeededPrivateFunctionalPackingKeyswitchKeyList as ImplLweSeededPrivateFunctionalPackingKeyswitchKeyList;
+use crate::commons::math::random::CompressionSeed;
+use crate::specification::entities::markers::LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysKind;
+use crate::specification::entities::{
+    AbstractEntity, LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    GlweDimension, LweDimension, PolynomialSize,
+};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a vector of seeded private functional packing keyswitch keys used
+/// for a circuit bootstrap with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32(
+    pub(crate) ImplLweSeededPrivateFunctionalPackingKeyswitchKeyList<Vec<u32>>,
+);
+impl AbstractEntity for LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+    type Kind = LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysKind;
+}
+impl LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity
+    for LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32
+{
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.input_lwe_key_dimension()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_key_dimension()
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.0.output_polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn key_count(&self) -> FunctionalPackingKeyswitchKeyCount {
+        self.0.fpksk_count()
+    }
+
+    fn compression_seed(&self) -> CompressionSeed {
+        self.0.compression_seed()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a vector of seeded private functional packing keyswitch keys used
+/// for a circuit bootstrap with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64(
+    pub(crate) ImplLweSeededPrivateFunctionalPackingKeyswitchKeyList<Vec<u64>>,
+);
+impl AbstractEntity for LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+    type Kind = LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysKind;
+}
+impl LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity
+    for LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64
+{
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.input_lwe_key_dimension()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_key_dimension()
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.0.output_polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn key_count(&self) -> FunctionalPackingKeyswitchKeyCount {
+        self.0.fpksk_count()
+    }
+
+    fn compression_seed(&self) -> CompressionSeed {
+        self.0.compression_seed()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -0,0 +1,67 @@
+use crate::commons::crypto::lwe::LweSeededList as ImplLweSeededList;
+use crate::commons::math::random::CompressionSeed;
+use crate::specification::entities::markers::LweSeededPublicKeyKind;
+use crate::specification::entities::{AbstractEntity, LweSeededPublicKeyEntity};
+use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a seeded LWE public key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededPublicKey32(pub(crate) ImplLweSeededList<Vec<u32>>);
+
+impl AbstractEntity for LweSeededPublicKey32 {
+    type Kind = LweSeededPublicKeyKind;
+}
+
+impl LweSeededPublicKeyEntity for LweSeededPublicKey32 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_zero_encryption_count(&self) -> LwePublicKeyZeroEncryptionCount {
+        LwePublicKeyZeroEncryptionCount(self.0.count().0)
+    }
+
+    fn compression_seed(&self) -> CompressionSeed {
+        self.0.get_compression_seed()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweSeededPublicKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a seeded LWE public key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededPublicKey64(pub(crate) ImplLweSeededList<Vec<u64>>);
+
+impl AbstractEntity for LweSeededPublicKey64 {
+    type Kind = LweSeededPublicKeyKind;
+}
+
+impl LweSeededPublicKeyEntity for LweSeededPublicKey64 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_zero_encryption_count(&self) -> LwePublicKeyZeroEncryptionCount {
+        LwePublicKeyZeroEncryptionCount(self.0.count().0)
+    }
+
+    fn compression_seed(&self) -> CompressionSeed {
+        self.0.get_compression_seed()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweSeededPublicKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
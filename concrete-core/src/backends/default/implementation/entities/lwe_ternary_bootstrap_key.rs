@@ -0,0 +1,88 @@
+use crate::commons::crypto::bootstrap::StandardBootstrapKey as ImplStandardBootstrapKey;
+use crate::specification::entities::markers::LweBootstrapKeyKind;
+use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing an LWE bootstrap key for a ternary input key, with 32 bits of
+/// precision.
+///
+/// The key contains two GGSW ciphertexts per coefficient of the ternary input key, which
+/// respectively encrypt whether this coefficient is equal to `1` and to `-1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweTernaryBootstrapKey32(pub(crate) ImplStandardBootstrapKey<Vec<u32>>);
+impl AbstractEntity for LweTernaryBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for LweTernaryBootstrapKey32 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.key_size().0 / 2)
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweTernaryBootstrapKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing an LWE bootstrap key for a ternary input key, with 64 bits of
+/// precision.
+///
+/// The key contains two GGSW ciphertexts per coefficient of the ternary input key, which
+/// respectively encrypt whether this coefficient is equal to `1` and to `-1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweTernaryBootstrapKey64(pub(crate) ImplStandardBootstrapKey<Vec<u64>>);
+impl AbstractEntity for LweTernaryBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for LweTernaryBootstrapKey64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.key_size().0 / 2)
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweTernaryBootstrapKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -35,3 +35,11 @@ pub(crate) enum Plaintext64Version {
     #[serde(other)]
     Unsupported,
 }
+
+/// A structure representing a plaintext with 128 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plaintext128(pub(crate) ImplPlaintext<u128>);
+impl AbstractEntity for Plaintext128 {
+    type Kind = PlaintextKind;
+}
+impl PlaintextEntity for Plaintext128 {}
@@ -44,3 +44,15 @@ pub(crate) enum PlaintextVector64Version {
     #[serde(other)]
     Unsupported,
 }
+
+/// A structure representing a vector of plaintexts with 128 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlaintextVector128(pub(crate) ImplPlaintextList<Vec<u128>>);
+impl AbstractEntity for PlaintextVector128 {
+    type Kind = PlaintextVectorKind;
+}
+impl PlaintextVectorEntity for PlaintextVector128 {
+    fn plaintext_count(&self) -> PlaintextCount {
+        self.0.count()
+    }
+}
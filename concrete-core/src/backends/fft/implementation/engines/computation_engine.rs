@@ -1,4 +1,6 @@
-use concrete_commons::parameters::PolynomialSize;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
 use dyn_stack::DynStack;
 
 use crate::specification::engines::sealed::AbstractEngineSeal;
@@ -10,6 +12,7 @@ use core::mem::MaybeUninit;
 #[non_exhaustive]
 pub enum FftError {
     UnsupportedPolynomialSize,
+    Unsupported128BitDecomposition,
 }
 
 impl core::fmt::Display for FftError {
@@ -19,6 +22,10 @@ impl core::fmt::Display for FftError {
                 "The Concrete-FFT backend only supports polynomials of sizes that are powers of two \
                     and greater than or equal to 32.",
             ),
+            FftError::Unsupported128BitDecomposition => f.write_str(
+                "The Concrete-FFT backend only supports 128 bits bootstrap keys whose parameters \
+                    satisfy (k + 1) * level_count * polynomial_size * 2^(base_log + 14) < 2^52.",
+            ),
         }
     }
 }
@@ -46,6 +53,24 @@ impl FftEngine {
             Err(FftError::UnsupportedPolynomialSize)
         }
     }
+
+    /// Checks that the products computed by the 128 bits external product can be recombined
+    /// exactly, that is $(k+1) \cdot \ell \cdot N \cdot 2^{B + 14} < 2^{52}$.
+    pub(crate) fn check_supported_128_bit_decomposition(
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        base_log: DecompositionBaseLog,
+        level_count: DecompositionLevelCount,
+    ) -> Result<(), FftError> {
+        let term_count = (glwe_size.0 * level_count.0 * polynomial_size.0) as u128;
+        let bound = 1_u128
+            .checked_shl((base_log.0 + 14) as u32)
+            .and_then(|term_bound| term_count.checked_mul(term_bound));
+        match bound {
+            Some(bound) if bound < 1 << 52 => Ok(()),
+            _ => Err(FftError::Unsupported128BitDecomposition),
+        }
+    }
 }
 
 impl AbstractEngineSeal for FftEngine {}
@@ -1,18 +1,16 @@
 use super::super::super::private::crypto::bootstrap::FourierLweBootstrapKey;
 use super::super::super::private::crypto::ggsw::FourierGgswCiphertext;
-use super::super::super::private::math::fft::Fft;
 use super::{FftSerializationEngine, FftSerializationError};
 use crate::prelude::{
     EntityDeserializationEngine, EntityDeserializationError, FftFourierGgswCiphertext32,
     FftFourierGgswCiphertext32Version, FftFourierGgswCiphertext64,
     FftFourierGgswCiphertext64Version, FftFourierLweBootstrapKey32,
     FftFourierLweBootstrapKey32Version, FftFourierLweBootstrapKey64,
-    FftFourierLweBootstrapKey64Version,
-};
-use aligned_vec::avec;
-use concrete_commons::parameters::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
+    FftFourierLweBootstrapKey64Version, FftFourierLweTernaryBootstrapKey32,
+    FftFourierLweTernaryBootstrapKey32Version, FftFourierLweTernaryBootstrapKey64,
+    FftFourierLweTernaryBootstrapKey64Version,
 };
+use aligned_vec::ABox;
 use concrete_fft::c64;
 use serde::Deserialize;
 
@@ -68,7 +66,7 @@ impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertext32> for FftSeria
         #[derive(Deserialize)]
         struct SerializableFftFourierGgswCiphertext32 {
             version: FftFourierGgswCiphertext32Version,
-            inner: FourierGgswCiphertext,
+            inner: FourierGgswCiphertext<ABox<[c64]>>,
         }
         let deserialized: SerializableFftFourierGgswCiphertext32 = bincode::deserialize(serialized)
             .map_err(FftSerializationError::Deserialization)
@@ -145,7 +143,7 @@ impl EntityDeserializationEngine<&[u8], FftFourierGgswCiphertext64> for FftSeria
         #[derive(Deserialize)]
         struct SerializableFftFourierGgswCiphertext64 {
             version: FftFourierGgswCiphertext64Version,
-            inner: FourierGgswCiphertext,
+            inner: FourierGgswCiphertext<ABox<[c64]>>,
         }
         let deserialized: SerializableFftFourierGgswCiphertext64 = bincode::deserialize(serialized)
             .map_err(FftSerializationError::Deserialization)
@@ -214,7 +212,7 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey32> for FftSeri
         #[derive(Deserialize)]
         struct SerializableFftFourierLweBootstrapKey32 {
             version: FftFourierLweBootstrapKey32Version,
-            inner: FourierLweBootstrapKey,
+            inner: FourierLweBootstrapKey<ABox<[c64]>>,
         }
         let deserialized: SerializableFftFourierLweBootstrapKey32 =
             bincode::deserialize(serialized)
@@ -284,7 +282,7 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey64> for FftSeri
         #[derive(Deserialize)]
         struct SerializableFftFourierLweBootstrapKey64 {
             version: FftFourierLweBootstrapKey64Version,
-            inner: FourierLweBootstrapKey,
+            inner: FourierLweBootstrapKey<ABox<[c64]>>,
         }
         let deserialized: SerializableFftFourierLweBootstrapKey64 =
             bincode::deserialize(serialized)
@@ -308,3 +306,161 @@ impl EntityDeserializationEngine<&[u8], FftFourierLweBootstrapKey64> for FftSeri
         self.deserialize(serialized).unwrap()
     }
 }
+
+impl EntityDeserializationEngine<&[u8], FftFourierLweTernaryBootstrapKey32>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::key_kinds::TernaryKeyKind;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32<TernaryKeyKind> =
+    ///     default_engine.generate_new_lwe_secret_key_with_kind(lwe_dim, TernaryKeyKind)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweTernaryBootstrapKey32 =
+    ///     fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&fourier_bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<FftFourierLweTernaryBootstrapKey32, EntityDeserializationError<Self::EngineError>>
+    {
+        #[derive(Deserialize)]
+        struct SerializableFftFourierLweTernaryBootstrapKey32 {
+            version: FftFourierLweTernaryBootstrapKey32Version,
+            inner: FourierLweBootstrapKey<ABox<[c64]>>,
+        }
+        let deserialized: SerializableFftFourierLweTernaryBootstrapKey32 =
+            bincode::deserialize(serialized)
+                .map_err(FftSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            SerializableFftFourierLweTernaryBootstrapKey32 {
+                version: FftFourierLweTernaryBootstrapKey32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            )),
+            SerializableFftFourierLweTernaryBootstrapKey32 {
+                version: FftFourierLweTernaryBootstrapKey32Version::V0,
+                inner,
+            } => Ok(FftFourierLweTernaryBootstrapKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> FftFourierLweTernaryBootstrapKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+impl EntityDeserializationEngine<&[u8], FftFourierLweTernaryBootstrapKey64>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::key_kinds::TernaryKeyKind;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64<TernaryKeyKind> =
+    ///     default_engine.generate_new_lwe_secret_key_with_kind(lwe_dim, TernaryKeyKind)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweTernaryBootstrapKey64 =
+    ///     fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&fourier_bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<FftFourierLweTernaryBootstrapKey64, EntityDeserializationError<Self::EngineError>>
+    {
+        #[derive(Deserialize)]
+        struct SerializableFftFourierLweTernaryBootstrapKey64 {
+            version: FftFourierLweTernaryBootstrapKey64Version,
+            inner: FourierLweBootstrapKey<ABox<[c64]>>,
+        }
+        let deserialized: SerializableFftFourierLweTernaryBootstrapKey64 =
+            bincode::deserialize(serialized)
+                .map_err(FftSerializationError::Deserialization)
+                .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            SerializableFftFourierLweTernaryBootstrapKey64 {
+                version: FftFourierLweTernaryBootstrapKey64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            )),
+            SerializableFftFourierLweTernaryBootstrapKey64 {
+                version: FftFourierLweTernaryBootstrapKey64Version::V0,
+                inner,
+            } => Ok(FftFourierLweTernaryBootstrapKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(
+        &mut self,
+        serialized: &[u8],
+    ) -> FftFourierLweTernaryBootstrapKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
@@ -0,0 +1,252 @@
+use super::super::super::entities::{
+    FftFourierGlweRelinearizationKey32, FftFourierGlweRelinearizationKey64,
+};
+use super::{FftEngine, FftError};
+use crate::prelude::{
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextDiscardingMultiplicationEngine,
+    GlweCiphertextDiscardingMultiplicationError, GlweCiphertextDiscardingRelinearizationEngine,
+    GlweCiphertextTensorProductEngine,
+};
+use concrete_commons::parameters::DeltaLog;
+
+impl From<FftError> for GlweCiphertextDiscardingMultiplicationError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextDiscardingMultiplicationEngine`] for [`FftEngine`] that
+/// operates on 32 bit integers.
+impl
+    GlweCiphertextDiscardingMultiplicationEngine<
+        FftFourierGlweRelinearizationKey32,
+        GlweCiphertext32,
+        GlweCiphertext32,
+        GlweCiphertext32,
+    > for FftEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_2 = vec![2_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let relinearization_key: GlweRelinearizationKey32 = default_engine
+    ///     .generate_new_glwe_relinearization_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// let fourier_relinearization_key: FftFourierGlweRelinearizationKey32 =
+    ///     fft_engine.convert_glwe_relinearization_key(&relinearization_key)?;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    ///
+    /// let mut ciphertext = default_engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    /// fft_engine.discard_mul_glwe_ciphertext(
+    ///     &mut ciphertext,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &fourier_relinearization_key,
+    ///     DeltaLog(20),
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input1: &GlweCiphertext32,
+        input2: &GlweCiphertext32,
+        relinearization_key: &FftFourierGlweRelinearizationKey32,
+        delta_log: DeltaLog,
+    ) -> Result<(), GlweCiphertextDiscardingMultiplicationError<Self::EngineError>> {
+        FftEngine::check_supported_size(output.0.polynomial_size())?;
+        GlweCiphertextDiscardingMultiplicationError::perform_generic_checks(
+            output,
+            input1,
+            input2,
+            relinearization_key,
+            delta_log,
+            32,
+        )?;
+        unsafe {
+            self.discard_mul_glwe_ciphertext_unchecked(
+                output,
+                input1,
+                input2,
+                relinearization_key,
+                delta_log,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input1: &GlweCiphertext32,
+        input2: &GlweCiphertext32,
+        relinearization_key: &FftFourierGlweRelinearizationKey32,
+        delta_log: DeltaLog,
+    ) {
+        let tensor_product: GlweCiphertext32 =
+            self.compute_tensor_product_glwe_ciphertext_unchecked(input1, input2, delta_log);
+        self.discard_relinearize_glwe_ciphertext_unchecked(
+            output,
+            &tensor_product,
+            relinearization_key,
+        );
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextDiscardingMultiplicationEngine`] for [`FftEngine`] that
+/// operates on 64 bit integers.
+impl
+    GlweCiphertextDiscardingMultiplicationEngine<
+        FftFourierGlweRelinearizationKey64,
+        GlweCiphertext64,
+        GlweCiphertext64,
+        GlweCiphertext64,
+    > for FftEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = vec![3_u64 << 50; polynomial_size.0];
+    /// let input_2 = vec![2_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let relinearization_key: GlweRelinearizationKey64 = default_engine
+    ///     .generate_new_glwe_relinearization_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// let fourier_relinearization_key: FftFourierGlweRelinearizationKey64 =
+    ///     fft_engine.convert_glwe_relinearization_key(&relinearization_key)?;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    ///
+    /// let mut ciphertext = default_engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    /// fft_engine.discard_mul_glwe_ciphertext(
+    ///     &mut ciphertext,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &fourier_relinearization_key,
+    ///     DeltaLog(50),
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input1: &GlweCiphertext64,
+        input2: &GlweCiphertext64,
+        relinearization_key: &FftFourierGlweRelinearizationKey64,
+        delta_log: DeltaLog,
+    ) -> Result<(), GlweCiphertextDiscardingMultiplicationError<Self::EngineError>> {
+        FftEngine::check_supported_size(output.0.polynomial_size())?;
+        GlweCiphertextDiscardingMultiplicationError::perform_generic_checks(
+            output,
+            input1,
+            input2,
+            relinearization_key,
+            delta_log,
+            64,
+        )?;
+        unsafe {
+            self.discard_mul_glwe_ciphertext_unchecked(
+                output,
+                input1,
+                input2,
+                relinearization_key,
+                delta_log,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input1: &GlweCiphertext64,
+        input2: &GlweCiphertext64,
+        relinearization_key: &FftFourierGlweRelinearizationKey64,
+        delta_log: DeltaLog,
+    ) {
+        let tensor_product: GlweCiphertext64 =
+            self.compute_tensor_product_glwe_ciphertext_unchecked(input1, input2, delta_log);
+        self.discard_relinearize_glwe_ciphertext_unchecked(
+            output,
+            &tensor_product,
+            relinearization_key,
+        );
+    }
+}
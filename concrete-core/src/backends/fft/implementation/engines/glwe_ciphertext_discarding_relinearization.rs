@@ -0,0 +1,254 @@
+use super::super::super::entities::{
+    FftFourierGlweRelinearizationKey32, FftFourierGlweRelinearizationKey64,
+};
+use super::super::super::private::crypto::glwe::{GlweCiphertextMutView, GlweCiphertextView};
+use super::super::super::private::crypto::relinearization::relinearize_scratch;
+use super::super::super::private::math::fft::Fft;
+use super::{FftEngine, FftError};
+use crate::commons::math::tensor::{AsMutSlice, AsRefSlice};
+use crate::prelude::{
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextDiscardingRelinearizationEngine,
+    GlweCiphertextDiscardingRelinearizationError,
+};
+
+impl From<FftError> for GlweCiphertextDiscardingRelinearizationError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextDiscardingRelinearizationEngine`] for [`FftEngine`] that
+/// operates on 32 bit integers.
+impl
+    GlweCiphertextDiscardingRelinearizationEngine<
+        FftFourierGlweRelinearizationKey32,
+        GlweCiphertext32,
+        GlweCiphertext32,
+    > for FftEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_2 = vec![2_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let relinearization_key: GlweRelinearizationKey32 = default_engine
+    ///     .generate_new_glwe_relinearization_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// let fourier_relinearization_key: FftFourierGlweRelinearizationKey32 =
+    ///     fft_engine.convert_glwe_relinearization_key(&relinearization_key)?;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let product: GlweCiphertext32 = default_engine.compute_tensor_product_glwe_ciphertext(
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     DeltaLog(20),
+    /// )?;
+    ///
+    /// let mut ciphertext = default_engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    /// fft_engine.discard_relinearize_glwe_ciphertext(
+    ///     &mut ciphertext,
+    ///     &product,
+    ///     &fourier_relinearization_key,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_relinearize_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        relinearization_key: &FftFourierGlweRelinearizationKey32,
+    ) -> Result<(), GlweCiphertextDiscardingRelinearizationError<Self::EngineError>> {
+        FftEngine::check_supported_size(output.0.polynomial_size())?;
+        GlweCiphertextDiscardingRelinearizationError::perform_generic_checks(
+            output,
+            input,
+            relinearization_key,
+        )?;
+        unsafe {
+            self.discard_relinearize_glwe_ciphertext_unchecked(output, input, relinearization_key)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_relinearize_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &GlweCiphertext32,
+        relinearization_key: &FftFourierGlweRelinearizationKey32,
+    ) {
+        let glwe_size = output.0.size();
+        let polynomial_size = output.0.polynomial_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            relinearize_scratch::<u32>(glwe_size, polynomial_size, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+        let out =
+            GlweCiphertextMutView::new(output.0.tensor.as_mut_slice(), polynomial_size, glwe_size);
+        let input =
+            GlweCiphertextView::new(input.0.tensor.as_slice(), polynomial_size, input.0.size());
+        relinearization_key
+            .0
+            .as_view()
+            .relinearize(out, input, fft, stack);
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextDiscardingRelinearizationEngine`] for [`FftEngine`] that
+/// operates on 64 bit integers.
+impl
+    GlweCiphertextDiscardingRelinearizationEngine<
+        FftFourierGlweRelinearizationKey64,
+        GlweCiphertext64,
+        GlweCiphertext64,
+    > for FftEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = vec![3_u64 << 50; polynomial_size.0];
+    /// let input_2 = vec![2_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let relinearization_key: GlweRelinearizationKey64 = default_engine
+    ///     .generate_new_glwe_relinearization_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// let fourier_relinearization_key: FftFourierGlweRelinearizationKey64 =
+    ///     fft_engine.convert_glwe_relinearization_key(&relinearization_key)?;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let product: GlweCiphertext64 = default_engine.compute_tensor_product_glwe_ciphertext(
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     DeltaLog(50),
+    /// )?;
+    ///
+    /// let mut ciphertext = default_engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    /// fft_engine.discard_relinearize_glwe_ciphertext(
+    ///     &mut ciphertext,
+    ///     &product,
+    ///     &fourier_relinearization_key,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_relinearize_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        relinearization_key: &FftFourierGlweRelinearizationKey64,
+    ) -> Result<(), GlweCiphertextDiscardingRelinearizationError<Self::EngineError>> {
+        FftEngine::check_supported_size(output.0.polynomial_size())?;
+        GlweCiphertextDiscardingRelinearizationError::perform_generic_checks(
+            output,
+            input,
+            relinearization_key,
+        )?;
+        unsafe {
+            self.discard_relinearize_glwe_ciphertext_unchecked(output, input, relinearization_key)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_relinearize_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &GlweCiphertext64,
+        relinearization_key: &FftFourierGlweRelinearizationKey64,
+    ) {
+        let glwe_size = output.0.size();
+        let polynomial_size = output.0.polynomial_size();
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        self.resize(
+            relinearize_scratch::<u64>(glwe_size, polynomial_size, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+        let out =
+            GlweCiphertextMutView::new(output.0.tensor.as_mut_slice(), polynomial_size, glwe_size);
+        let input =
+            GlweCiphertextView::new(input.0.tensor.as_slice(), polynomial_size, input.0.size());
+        relinearization_key
+            .0
+            .as_view()
+            .relinearize(out, input, fft, stack);
+    }
+}
@@ -0,0 +1,183 @@
+use super::{FftEngine, FftError};
+use crate::commons::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::prelude::{
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextEntity, GlweCiphertextTensorProductEngine,
+    GlweCiphertextTensorProductError,
+};
+use concrete_commons::parameters::{DeltaLog, GlweSize};
+
+impl From<FftError> for GlweCiphertextTensorProductError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextTensorProductEngine`] for [`FftEngine`] that operates on
+/// 32 bit integers.
+///
+/// # Note
+///
+/// The products of the input polynomials do not fit in the mantissa of a double precision float,
+/// so they are computed exactly in the standard domain rather than with the FFT.
+impl GlweCiphertextTensorProductEngine<GlweCiphertext32, GlweCiphertext32, GlweCiphertext32>
+    for FftEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DeltaLog, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_2 = vec![2_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    ///
+    /// let product: GlweCiphertext32 = fft_engine.compute_tensor_product_glwe_ciphertext(
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     DeltaLog(20),
+    /// )?;
+    /// #
+    /// assert_eq!(product.glwe_dimension(), GlweDimension(5));
+    /// assert_eq!(product.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compute_tensor_product_glwe_ciphertext(
+        &mut self,
+        input1: &GlweCiphertext32,
+        input2: &GlweCiphertext32,
+        delta_log: DeltaLog,
+    ) -> Result<GlweCiphertext32, GlweCiphertextTensorProductError<Self::EngineError>> {
+        FftEngine::check_supported_size(input1.0.polynomial_size())?;
+        GlweCiphertextTensorProductError::perform_generic_checks(input1, input2, delta_log, 32)?;
+        Ok(unsafe {
+            self.compute_tensor_product_glwe_ciphertext_unchecked(input1, input2, delta_log)
+        })
+    }
+
+    unsafe fn compute_tensor_product_glwe_ciphertext_unchecked(
+        &mut self,
+        input1: &GlweCiphertext32,
+        input2: &GlweCiphertext32,
+        delta_log: DeltaLog,
+    ) -> GlweCiphertext32 {
+        let glwe_dimension = input1.glwe_dimension().0;
+        let mut output = ImplGlweCiphertext::allocate(
+            0u32,
+            input1.polynomial_size(),
+            GlweSize(glwe_dimension * (glwe_dimension + 3) / 2 + 1),
+        );
+        output.fill_with_tensor_product(&input1.0, &input2.0, delta_log);
+        GlweCiphertext32(output)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweCiphertextTensorProductEngine`] for [`FftEngine`] that operates on
+/// 64 bit integers.
+///
+/// # Note
+///
+/// The products of the input polynomials do not fit in the mantissa of a double precision float,
+/// so they are computed exactly in the standard domain rather than with the FFT.
+impl GlweCiphertextTensorProductEngine<GlweCiphertext64, GlweCiphertext64, GlweCiphertext64>
+    for FftEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DeltaLog, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = vec![3_u64 << 50; polynomial_size.0];
+    /// let input_2 = vec![2_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector_1 = default_engine.create_plaintext_vector_from(&input_1)?;
+    /// let plaintext_vector_2 = default_engine.create_plaintext_vector_from(&input_2)?;
+    /// let ciphertext_1 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 =
+    ///     default_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    ///
+    /// let product: GlweCiphertext64 = fft_engine.compute_tensor_product_glwe_ciphertext(
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     DeltaLog(50),
+    /// )?;
+    /// #
+    /// assert_eq!(product.glwe_dimension(), GlweDimension(5));
+    /// assert_eq!(product.polynomial_size(), polynomial_size);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compute_tensor_product_glwe_ciphertext(
+        &mut self,
+        input1: &GlweCiphertext64,
+        input2: &GlweCiphertext64,
+        delta_log: DeltaLog,
+    ) -> Result<GlweCiphertext64, GlweCiphertextTensorProductError<Self::EngineError>> {
+        FftEngine::check_supported_size(input1.0.polynomial_size())?;
+        GlweCiphertextTensorProductError::perform_generic_checks(input1, input2, delta_log, 64)?;
+        Ok(unsafe {
+            self.compute_tensor_product_glwe_ciphertext_unchecked(input1, input2, delta_log)
+        })
+    }
+
+    unsafe fn compute_tensor_product_glwe_ciphertext_unchecked(
+        &mut self,
+        input1: &GlweCiphertext64,
+        input2: &GlweCiphertext64,
+        delta_log: DeltaLog,
+    ) -> GlweCiphertext64 {
+        let glwe_dimension = input1.glwe_dimension().0;
+        let mut output = ImplGlweCiphertext::allocate(
+            0u64,
+            input1.polynomial_size(),
+            GlweSize(glwe_dimension * (glwe_dimension + 3) / 2 + 1),
+        );
+        output.fill_with_tensor_product(&input1.0, &input2.0, delta_log);
+        GlweCiphertext64(output)
+    }
+}
@@ -0,0 +1,211 @@
+use super::super::super::private::crypto::relinearization::{
+    fill_with_forward_fourier_scratch, FourierGlweRelinearizationKey,
+};
+use super::super::super::private::math::fft::Fft;
+use super::{FftEngine, FftError};
+use crate::commons::math::tensor::AsRefSlice;
+use crate::prelude::{
+    FftFourierGlweRelinearizationKey32, FftFourierGlweRelinearizationKey64,
+    GlweRelinearizationKey32, GlweRelinearizationKey64, GlweRelinearizationKeyConversionEngine,
+    GlweRelinearizationKeyConversionError, GlweRelinearizationKeyEntity,
+};
+use aligned_vec::avec;
+use concrete_fft::c64;
+
+impl From<FftError> for GlweRelinearizationKeyConversionError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweRelinearizationKeyConversionEngine`] for [`FftEngine`] that operates
+/// on 32 bit integers. It converts a relinearization key from the standard to the Fourier domain.
+impl
+    GlweRelinearizationKeyConversionEngine<
+        GlweRelinearizationKey32,
+        FftFourierGlweRelinearizationKey32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (glwe_dim, poly_size) = (GlweDimension(2), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let rlk: GlweRelinearizationKey32 =
+    ///     default_engine.generate_new_glwe_relinearization_key(&glwe_sk, dec_lc, dec_bl, noise)?;
+    ///
+    /// let fourier_rlk: FftFourierGlweRelinearizationKey32 =
+    ///     fft_engine.convert_glwe_relinearization_key(&rlk)?;
+    /// #
+    /// assert_eq!(fourier_rlk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_rlk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_rlk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_rlk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_relinearization_key(
+        &mut self,
+        input: &GlweRelinearizationKey32,
+    ) -> Result<
+        FftFourierGlweRelinearizationKey32,
+        GlweRelinearizationKeyConversionError<Self::EngineError>,
+    > {
+        FftEngine::check_supported_size(input.0.polynomial_size())?;
+        Ok(unsafe { self.convert_glwe_relinearization_key_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_relinearization_key_unchecked(
+        &mut self,
+        input: &GlweRelinearizationKey32,
+    ) -> FftFourierGlweRelinearizationKey32 {
+        let glwe_dimension = input.glwe_dimension();
+        let glwe_size = glwe_dimension.to_glwe_size();
+
+        let boxed = avec![
+            c64::default();
+            input.polynomial_size().0 / 2
+                * glwe_size.0
+                * (glwe_dimension.0 * (glwe_dimension.0 + 1) / 2)
+                * input.decomposition_level_count().0
+        ]
+        .into_boxed_slice();
+        let fft = Fft::new(input.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            fill_with_forward_fourier_scratch(fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+
+        let mut output = FourierGlweRelinearizationKey::new(
+            boxed,
+            input.polynomial_size(),
+            glwe_size,
+            input.decomposition_base_log(),
+            input.decomposition_level_count(),
+        );
+        output
+            .as_mut_view()
+            .fill_with_forward_fourier(input.0.tensor.as_slice(), fft, stack);
+        FftFourierGlweRelinearizationKey32(output)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`GlweRelinearizationKeyConversionEngine`] for [`FftEngine`] that operates
+/// on 64 bit integers. It converts a relinearization key from the standard to the Fourier domain.
+impl
+    GlweRelinearizationKeyConversionEngine<
+        GlweRelinearizationKey64,
+        FftFourierGlweRelinearizationKey64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (glwe_dim, poly_size) = (GlweDimension(2), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let rlk: GlweRelinearizationKey64 =
+    ///     default_engine.generate_new_glwe_relinearization_key(&glwe_sk, dec_lc, dec_bl, noise)?;
+    ///
+    /// let fourier_rlk: FftFourierGlweRelinearizationKey64 =
+    ///     fft_engine.convert_glwe_relinearization_key(&rlk)?;
+    /// #
+    /// assert_eq!(fourier_rlk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_rlk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_rlk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_rlk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_relinearization_key(
+        &mut self,
+        input: &GlweRelinearizationKey64,
+    ) -> Result<
+        FftFourierGlweRelinearizationKey64,
+        GlweRelinearizationKeyConversionError<Self::EngineError>,
+    > {
+        FftEngine::check_supported_size(input.0.polynomial_size())?;
+        Ok(unsafe { self.convert_glwe_relinearization_key_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_relinearization_key_unchecked(
+        &mut self,
+        input: &GlweRelinearizationKey64,
+    ) -> FftFourierGlweRelinearizationKey64 {
+        let glwe_dimension = input.glwe_dimension();
+        let glwe_size = glwe_dimension.to_glwe_size();
+
+        let boxed = avec![
+            c64::default();
+            input.polynomial_size().0 / 2
+                * glwe_size.0
+                * (glwe_dimension.0 * (glwe_dimension.0 + 1) / 2)
+                * input.decomposition_level_count().0
+        ]
+        .into_boxed_slice();
+        let fft = Fft::new(input.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            fill_with_forward_fourier_scratch(fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+
+        let mut output = FourierGlweRelinearizationKey::new(
+            boxed,
+            input.polynomial_size(),
+            glwe_size,
+            input.decomposition_base_log(),
+            input.decomposition_level_count(),
+        );
+        output
+            .as_mut_view()
+            .fill_with_forward_fourier(input.0.tensor.as_slice(), fft, stack);
+        FftFourierGlweRelinearizationKey64(output)
+    }
+}
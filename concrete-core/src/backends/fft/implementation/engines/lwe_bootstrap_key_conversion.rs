@@ -1,12 +1,19 @@
 use super::super::super::private::crypto::bootstrap::{
     fill_with_forward_fourier_scratch, FourierLweBootstrapKey, StandardLweBootstrapKeyView,
 };
+use super::super::super::private::crypto::bootstrap128::{
+    fill_with_forward_fourier_scratch as fill_with_forward_fourier_scratch128,
+    FourierLweBootstrapKey128, LIMB_COUNT,
+};
 use super::super::super::private::math::fft::Fft;
 use super::{FftEngine, FftError};
 use crate::commons::math::tensor::AsRefSlice;
 use crate::prelude::{
-    FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, LweBootstrapKey32, LweBootstrapKey64,
-    LweBootstrapKeyConversionEngine, LweBootstrapKeyConversionError, LweBootstrapKeyEntity,
+    FftFourierLweBootstrapKey128, FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64,
+    FftFourierLweTernaryBootstrapKey32, FftFourierLweTernaryBootstrapKey64, LweBootstrapKey128,
+    LweBootstrapKey32, LweBootstrapKey64, LweBootstrapKeyConversionEngine,
+    LweBootstrapKeyConversionError, LweBootstrapKeyEntity, LweTernaryBootstrapKey32,
+    LweTernaryBootstrapKey64,
 };
 use aligned_vec::avec;
 use concrete_fft::c64;
@@ -218,6 +225,344 @@ impl LweBootstrapKeyConversionEngine<LweBootstrapKey64, FftFourierLweBootstrapKe
     }
 }
 
+/// # Description
+///
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`FftEngine`] that operates on
+/// 128 bit integers. It converts a bootstrap key from the standard to the Fourier domain, splitting
+/// its coefficients in limbs.
+impl LweBootstrapKeyConversionEngine<LweBootstrapKey128, FftFourierLweBootstrapKey128>
+    for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-100.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey128 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey128 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey128 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey128 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(fourier_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(fourier_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// // The products of the 128 bits external product can not be recombined exactly when
+    /// // (k + 1) * level_count * polynomial_size * 2^(base_log + 14) >= 2^52.
+    /// let (glwe_dim, poly_size) = (GlweDimension(1), PolynomialSize(2048));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(1), DecompositionBaseLog(30));
+    /// let glwe_sk: GlweSecretKey128 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey128 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let result: Result<FftFourierLweBootstrapKey128, _> = fft_engine.convert_lwe_bootstrap_key(&bsk);
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(LweBootstrapKeyConversionError::Engine(
+    ///         FftError::Unsupported128BitDecomposition
+    ///     ))
+    /// ));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweBootstrapKey128,
+    ) -> Result<FftFourierLweBootstrapKey128, LweBootstrapKeyConversionError<Self::EngineError>>
+    {
+        FftEngine::check_supported_size(input.0.polynomial_size())?;
+        FftEngine::check_supported_128_bit_decomposition(
+            input.0.glwe_size(),
+            input.0.polynomial_size(),
+            input.0.base_log(),
+            input.0.level_count(),
+        )?;
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweBootstrapKey128,
+    ) -> FftFourierLweBootstrapKey128 {
+        let glwe_size = input.0.glwe_size();
+
+        let boxed = avec![
+            c64::default();
+            LIMB_COUNT
+                * input.0.polynomial_size().0
+                * input.0.key_size().0
+                * input.0.level_count().0
+                * glwe_size.0
+                * glwe_size.0
+                / 2
+        ]
+        .into_boxed_slice();
+
+        let fft = Fft::new(input.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            fill_with_forward_fourier_scratch128(fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+
+        let mut output = FourierLweBootstrapKey128::new(
+            boxed,
+            input.0.key_size(),
+            input.0.polynomial_size(),
+            input.0.glwe_size(),
+            input.0.base_log(),
+            input.0.level_count(),
+        );
+        let input = StandardLweBootstrapKeyView::new(
+            input.0.tensor.as_slice(),
+            input.0.key_size(),
+            input.0.polynomial_size(),
+            input.0.glwe_size(),
+            input.0.base_log(),
+            input.0.level_count(),
+        );
+        output
+            .as_mut_view()
+            .fill_with_forward_fourier(input, fft, stack);
+        FftFourierLweBootstrapKey128(output)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`FftEngine`] that operates on
+/// 32 bit integers. It converts a bootstrap key for a ternary input key from the standard to the
+/// Fourier domain.
+impl LweBootstrapKeyConversionEngine<LweTernaryBootstrapKey32, FftFourierLweTernaryBootstrapKey32>
+    for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32<TernaryKeyKind> =
+    ///     default_engine.generate_new_lwe_secret_key_with_kind(lwe_dim, TernaryKeyKind)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweTernaryBootstrapKey32 =
+    ///     fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(fourier_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(fourier_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweTernaryBootstrapKey32,
+    ) -> Result<FftFourierLweTernaryBootstrapKey32, LweBootstrapKeyConversionError<Self::EngineError>>
+    {
+        FftEngine::check_supported_size(input.0.polynomial_size())?;
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweTernaryBootstrapKey32,
+    ) -> FftFourierLweTernaryBootstrapKey32 {
+        let glwe_size = input.0.glwe_size();
+
+        let boxed = avec![
+            c64::default();
+            input.0.polynomial_size().0
+                * input.0.key_size().0
+                * input.0.level_count().0
+                * glwe_size.0
+                * glwe_size.0
+                / 2
+        ]
+        .into_boxed_slice();
+
+        let fft = Fft::new(input.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            fill_with_forward_fourier_scratch(fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+
+        let mut output = FourierLweBootstrapKey::new(
+            boxed,
+            input.0.key_size(),
+            input.0.polynomial_size(),
+            input.0.glwe_size(),
+            input.0.base_log(),
+            input.0.level_count(),
+        );
+        let input = StandardLweBootstrapKeyView::new(
+            input.0.tensor.as_slice(),
+            input.0.key_size(),
+            input.0.polynomial_size(),
+            input.0.glwe_size(),
+            input.0.base_log(),
+            input.0.level_count(),
+        );
+        output
+            .as_mut_view()
+            .fill_with_forward_fourier(input, fft, stack);
+        FftFourierLweTernaryBootstrapKey32(output)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`FftEngine`] that operates on
+/// 64 bit integers. It converts a bootstrap key for a ternary input key from the standard to the
+/// Fourier domain.
+impl LweBootstrapKeyConversionEngine<LweTernaryBootstrapKey64, FftFourierLweTernaryBootstrapKey64>
+    for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64<TernaryKeyKind> =
+    ///     default_engine.generate_new_lwe_secret_key_with_kind(lwe_dim, TernaryKeyKind)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweTernaryBootstrapKey64 =
+    ///     fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(fourier_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(fourier_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweTernaryBootstrapKey64,
+    ) -> Result<FftFourierLweTernaryBootstrapKey64, LweBootstrapKeyConversionError<Self::EngineError>>
+    {
+        FftEngine::check_supported_size(input.0.polynomial_size())?;
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweTernaryBootstrapKey64,
+    ) -> FftFourierLweTernaryBootstrapKey64 {
+        let glwe_size = input.0.glwe_size();
+
+        let boxed = avec![
+            c64::default();
+            input.0.polynomial_size().0
+                * input.0.key_size().0
+                * input.0.level_count().0
+                * glwe_size.0
+                * glwe_size.0
+                / 2
+        ]
+        .into_boxed_slice();
+
+        let fft = Fft::new(input.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            fill_with_forward_fourier_scratch(fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let stack = self.stack();
+
+        let mut output = FourierLweBootstrapKey::new(
+            boxed,
+            input.0.key_size(),
+            input.0.polynomial_size(),
+            input.0.glwe_size(),
+            input.0.base_log(),
+            input.0.level_count(),
+        );
+        let input = StandardLweBootstrapKeyView::new(
+            input.0.tensor.as_slice(),
+            input.0.key_size(),
+            input.0.polynomial_size(),
+            input.0.glwe_size(),
+            input.0.base_log(),
+            input.0.level_count(),
+        );
+        output
+            .as_mut_view()
+            .fill_with_forward_fourier(input, fft, stack);
+        FftFourierLweTernaryBootstrapKey64(output)
+    }
+}
+
 impl<Key> LweBootstrapKeyConversionEngine<Key, Key> for FftEngine
 where
     Key: LweBootstrapKeyEntity + Clone,
@@ -1,13 +1,18 @@
-use super::super::super::private::crypto::bootstrap::bootstrap_scratch;
+use super::super::super::private::crypto::bootstrap::{
+    bootstrap_scratch, ternary_bootstrap_scratch,
+};
+use super::super::super::private::crypto::bootstrap128::bootstrap_scratch as bootstrap_scratch128;
 use super::super::super::private::crypto::glwe::GlweCiphertextView;
 use super::super::super::private::math::fft::Fft;
 use super::{FftEngine, FftError};
 use crate::commons::math::tensor::{AsMutSlice, AsRefSlice};
 use crate::prelude::{
-    FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, GlweCiphertext32, GlweCiphertext64,
-    GlweCiphertextView32, GlweCiphertextView64, LweCiphertext32, LweCiphertext64,
-    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
-    LweCiphertextMutView32, LweCiphertextMutView64, LweCiphertextView32, LweCiphertextView64,
+    FftFourierLweBootstrapKey128, FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64,
+    FftFourierLweTernaryBootstrapKey32, FftFourierLweTernaryBootstrapKey64, GlweCiphertext128,
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextView32, GlweCiphertextView64,
+    LweCiphertext128, LweCiphertext32, LweCiphertext64, LweCiphertextDiscardingBootstrapEngine,
+    LweCiphertextDiscardingBootstrapError, LweCiphertextMutView32, LweCiphertextMutView64,
+    LweCiphertextView32, LweCiphertextView64,
 };
 
 impl From<FftError> for LweCiphertextDiscardingBootstrapError<FftError> {
@@ -236,6 +241,127 @@ impl
     }
 }
 
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`FftEngine`] that operates
+/// on 128 bit integers.
+///
+/// # Note
+///
+/// The decomposition parameters of the bootstrap key must satisfy
+/// $(k+1) \cdot \ell \cdot N \cdot 2^{B + 14} < 2^{52}$ for the bootstrap to be exact, where
+/// $k$ is the GLWE dimension, $N$ the polynomial size, $B$ the decomposition base log and $\ell$
+/// the decomposition level count.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        FftFourierLweBootstrapKey128,
+        GlweCiphertext128,
+        LweCiphertext128,
+        LweCiphertext128,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 120 bits), which keeps the input away from
+    /// // the negacyclic boundaries of the lookup table
+    /// let input = 3_u128 << 120;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(1), PolynomialSize(1024));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u128 << 120; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-100.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey128 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey128 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey128 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey128 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// // The output of the bootstrap is encrypted under the GLWE key seen as an LWE key.
+    /// let lwe_sk_output: LweSecretKey128 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// fft_engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), LweDimension(glwe_dim.0 * poly_size.0));
+    ///
+    /// // The decrypted output is rounded to the closest multiple of 2^120, which gives the value
+    /// // of the lookup table.
+    /// let decrypted = default_engine.decrypt_lwe_ciphertext(&lwe_sk_output, &output)?;
+    /// let raw = default_engine.retrieve_plaintext(&decrypted)?;
+    /// let rounded = ((raw >> 119).wrapping_add(1) >> 1) % (1 << 8);
+    /// assert_eq!(rounded, 8);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext128,
+        input: &LweCiphertext128,
+        acc: &GlweCiphertext128,
+        bsk: &FftFourierLweBootstrapKey128,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if !(acc.0.polynomial_size().0.is_power_of_two() && acc.0.polynomial_size().0 >= 32) {
+            return Err(LweCiphertextDiscardingBootstrapError::from(
+                FftError::UnsupportedPolynomialSize,
+            ));
+        }
+        LweCiphertextDiscardingBootstrapError::perform_generic_checks(output, input, acc, bsk)?;
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext128,
+        input: &LweCiphertext128,
+        acc: &GlweCiphertext128,
+        bsk: &FftFourierLweBootstrapKey128,
+    ) {
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            bootstrap_scratch128(acc.0.size(), acc.0.polynomial_size(), fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let acc = GlweCiphertextView::new(
+            acc.0.tensor.as_slice(),
+            acc.0.polynomial_size(),
+            acc.0.size(),
+        );
+        bsk.0.as_view().bootstrap(
+            output.0.tensor.as_mut_slice(),
+            input.0.tensor.as_slice(),
+            acc,
+            fft,
+            self.stack(),
+        );
+    }
+}
+
 /// # Description
 ///
 /// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`FftEngine`] that operates
@@ -489,3 +615,233 @@ impl
         );
     }
 }
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`FftEngine`] that operates
+/// on 32 bit integers, with a bootstrap key for a ternary input key.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        FftFourierLweTernaryBootstrapKey32,
+        GlweCiphertext32,
+        LweCiphertext32,
+        LweCiphertext32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The input encodes a quarter of the torus, for the rotation of the accumulator to stay in
+    /// // the first half of the lookup table.
+    /// let input = 1_u32 << 30;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(1024));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-60.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32<TernaryKeyKind> =
+    ///     default_engine.generate_new_lwe_secret_key_with_kind(lwe_dim, TernaryKeyKind)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweTernaryBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey32 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// fft_engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// let decrypted = default_engine.decrypt_lwe_ciphertext(&lwe_sk_output, &output)?;
+    /// let decrypted: u32 = default_engine.retrieve_plaintext(&decrypted)?;
+    /// // The output encrypts the value of the lookup table, up to the noise
+    /// assert_eq!((decrypted.wrapping_add(1 << 19)) >> 20, 8);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FftFourierLweTernaryBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if !(acc.0.polynomial_size().0.is_power_of_two() && acc.0.polynomial_size().0 >= 32) {
+            return Err(LweCiphertextDiscardingBootstrapError::from(
+                FftError::UnsupportedPolynomialSize,
+            ));
+        }
+        LweCiphertextDiscardingBootstrapError::perform_generic_checks(output, input, acc, bsk)?;
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FftFourierLweTernaryBootstrapKey32,
+    ) {
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            ternary_bootstrap_scratch::<u32>(
+                input.0.lwe_size().to_lwe_dimension(),
+                acc.0.size(),
+                acc.0.polynomial_size(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        let acc = GlweCiphertextView::new(
+            acc.0.tensor.as_slice(),
+            acc.0.polynomial_size(),
+            acc.0.size(),
+        );
+        bsk.0.as_view().ternary_bootstrap(
+            output.0.tensor.as_mut_slice(),
+            input.0.tensor.as_slice(),
+            acc,
+            fft,
+            self.stack(),
+        );
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`FftEngine`] that operates
+/// on 64 bit integers, with a bootstrap key for a ternary input key.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        FftFourierLweTernaryBootstrapKey64,
+        GlweCiphertext64,
+        LweCiphertext64,
+        LweCiphertext64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The input encodes a quarter of the torus, for the rotation of the accumulator to stay in
+    /// // the first half of the lookup table.
+    /// let input = 1_u64 << 62;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(1024));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(4), DecompositionBaseLog(10));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-100.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64<TernaryKeyKind> =
+    ///     default_engine.generate_new_lwe_secret_key_with_kind(lwe_dim, TernaryKeyKind)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweTernaryBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey64 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// fft_engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// let decrypted = default_engine.decrypt_lwe_ciphertext(&lwe_sk_output, &output)?;
+    /// let decrypted: u64 = default_engine.retrieve_plaintext(&decrypted)?;
+    /// // The output encrypts the value of the lookup table, up to the noise
+    /// assert_eq!((decrypted.wrapping_add(1 << 49)) >> 50, 8);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FftFourierLweTernaryBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if !(acc.0.polynomial_size().0.is_power_of_two() && acc.0.polynomial_size().0 >= 32) {
+            return Err(LweCiphertextDiscardingBootstrapError::from(
+                FftError::UnsupportedPolynomialSize,
+            ));
+        }
+        LweCiphertextDiscardingBootstrapError::perform_generic_checks(output, input, acc, bsk)?;
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FftFourierLweTernaryBootstrapKey64,
+    ) {
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            ternary_bootstrap_scratch::<u64>(
+                input.0.lwe_size().to_lwe_dimension(),
+                acc.0.size(),
+                acc.0.polynomial_size(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        let acc = GlweCiphertextView::new(
+            acc.0.tensor.as_slice(),
+            acc.0.polynomial_size(),
+            acc.0.size(),
+        );
+        bsk.0.as_view().ternary_bootstrap(
+            output.0.tensor.as_mut_slice(),
+            input.0.tensor.as_slice(),
+            acc,
+            fft,
+            self.stack(),
+        );
+    }
+}
@@ -0,0 +1,331 @@
+use super::super::super::private::crypto::ggsw::StandardGgswCiphertext;
+use super::super::super::private::crypto::wop_pbs::{
+    circuit_bootstrap_boolean, circuit_bootstrap_boolean_scratch,
+};
+use super::super::super::private::math::fft::Fft;
+use super::{FftEngine, FftError};
+use crate::commons::math::tensor::{AsMutSlice, AsRefSlice};
+use crate::prelude::{
+    DeltaLog, FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, GgswCiphertext32,
+    GgswCiphertext64, LweBootstrapKeyEntity, LweCiphertext32, LweCiphertext64,
+    LweCiphertextDiscardingCircuitBootstrapBooleanEngine,
+    LweCiphertextDiscardingCircuitBootstrapBooleanError,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+};
+
+impl From<FftError> for LweCiphertextDiscardingCircuitBootstrapBooleanError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingCircuitBootstrapBooleanEngine`] for [`FftEngine`]
+/// that operates on 32 bit integers.
+impl
+    LweCiphertextDiscardingCircuitBootstrapBooleanEngine<
+        LweCiphertext32,
+        GgswCiphertext32,
+        FftFourierLweBootstrapKey32,
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied: the bit of message is stored in the MSB
+    /// let delta_log = DeltaLog(32 - 1);
+    /// let input = 1_u32 << delta_log.0;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(10), GlweDimension(1), PolynomialSize(512));
+    /// let (bsk_level_count, bsk_base_log) =
+    ///     (DecompositionLevelCount(2), DecompositionBaseLog(10));
+    /// let (pfpksk_level_count, pfpksk_base_log) =
+    ///     (DecompositionLevelCount(2), DecompositionBaseLog(10));
+    /// let (cbs_level_count, cbs_base_log) =
+    ///     (DecompositionLevelCount(1), DecompositionBaseLog(10));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 = default_engine.generate_new_lwe_bootstrap_key(
+    ///     &lwe_sk,
+    ///     &glwe_sk,
+    ///     bsk_base_log,
+    ///     bsk_level_count,
+    ///     noise,
+    /// )?;
+    /// let bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let bsk_output_lwe_sk: LweSecretKey32 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    /// let cbs_pfpksk: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 = default_engine
+    ///     .generate_new_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &bsk_output_lwe_sk,
+    ///         &glwe_sk,
+    ///         pfpksk_base_log,
+    ///         pfpksk_level_count,
+    ///         noise,
+    ///     )?;
+    ///
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let zero = default_engine.create_plaintext_from(&0_u32)?;
+    /// let mut output: GgswCiphertext32 = default_engine.trivially_encrypt_scalar_ggsw_ciphertext(
+    ///     poly_size,
+    ///     glwe_dim.to_glwe_size(),
+    ///     cbs_level_count,
+    ///     cbs_base_log,
+    ///     &zero,
+    /// )?;
+    ///
+    /// fft_engine.discard_circuit_bootstrap_boolean_lwe_ciphertext(
+    ///     &mut output,
+    ///     &input,
+    ///     delta_log,
+    ///     &bsk,
+    ///     &cbs_pfpksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output.polynomial_size(), poly_size);
+    /// assert_eq!(output.decomposition_level_count(), cbs_level_count);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_circuit_bootstrap_boolean_lwe_ciphertext(
+        &mut self,
+        output: &mut GgswCiphertext32,
+        input: &LweCiphertext32,
+        delta_log: DeltaLog,
+        bsk: &FftFourierLweBootstrapKey32,
+        cbs_pfpksk: &LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) -> Result<(), LweCiphertextDiscardingCircuitBootstrapBooleanError<Self::EngineError>> {
+        FftEngine::check_supported_size(bsk.polynomial_size())?;
+        LweCiphertextDiscardingCircuitBootstrapBooleanError::perform_generic_checks(
+            output, input, delta_log, bsk, cbs_pfpksk, 32,
+        )?;
+        unsafe {
+            self.discard_circuit_bootstrap_boolean_lwe_ciphertext_unchecked(
+                output, input, delta_log, bsk, cbs_pfpksk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_circuit_bootstrap_boolean_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GgswCiphertext32,
+        input: &LweCiphertext32,
+        delta_log: DeltaLog,
+        bsk: &FftFourierLweBootstrapKey32,
+        cbs_pfpksk: &LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) {
+        let fft = Fft::new(bsk.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            circuit_bootstrap_boolean_scratch::<u32>(
+                input.0.lwe_size(),
+                bsk.output_lwe_dimension().to_lwe_size(),
+                bsk.glwe_dimension().to_glwe_size(),
+                bsk.polynomial_size(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        let polynomial_size = output.0.polynomial_size();
+        let glwe_size = output.0.glwe_size();
+        let base_log = output.0.decomposition_base_log();
+        let level_count = output.0.decomposition_level_count();
+        let output = StandardGgswCiphertext::new(
+            output.0.tensor.as_mut_slice(),
+            polynomial_size,
+            glwe_size,
+            base_log,
+            level_count,
+        );
+        circuit_bootstrap_boolean(
+            bsk.0.as_view(),
+            input.0.tensor.as_slice(),
+            output,
+            delta_log,
+            &cbs_pfpksk.0,
+            fft,
+            self.stack(),
+        );
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingCircuitBootstrapBooleanEngine`] for [`FftEngine`]
+/// that operates on 64 bit integers.
+impl
+    LweCiphertextDiscardingCircuitBootstrapBooleanEngine<
+        LweCiphertext64,
+        GgswCiphertext64,
+        FftFourierLweBootstrapKey64,
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied: the bit of message is stored in the MSB
+    /// let delta_log = DeltaLog(64 - 1);
+    /// let input = 1_u64 << delta_log.0;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(10), GlweDimension(1), PolynomialSize(512));
+    /// let (bsk_level_count, bsk_base_log) =
+    ///     (DecompositionLevelCount(2), DecompositionBaseLog(10));
+    /// let (pfpksk_level_count, pfpksk_base_log) =
+    ///     (DecompositionLevelCount(2), DecompositionBaseLog(10));
+    /// let (cbs_level_count, cbs_base_log) =
+    ///     (DecompositionLevelCount(1), DecompositionBaseLog(10));
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 = default_engine.generate_new_lwe_bootstrap_key(
+    ///     &lwe_sk,
+    ///     &glwe_sk,
+    ///     bsk_base_log,
+    ///     bsk_level_count,
+    ///     noise,
+    /// )?;
+    /// let bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let bsk_output_lwe_sk: LweSecretKey64 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    /// let cbs_pfpksk: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 = default_engine
+    ///     .generate_new_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &bsk_output_lwe_sk,
+    ///         &glwe_sk,
+    ///         pfpksk_base_log,
+    ///         pfpksk_level_count,
+    ///         noise,
+    ///     )?;
+    ///
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let zero = default_engine.create_plaintext_from(&0_u64)?;
+    /// let mut output: GgswCiphertext64 = default_engine.trivially_encrypt_scalar_ggsw_ciphertext(
+    ///     poly_size,
+    ///     glwe_dim.to_glwe_size(),
+    ///     cbs_level_count,
+    ///     cbs_base_log,
+    ///     &zero,
+    /// )?;
+    ///
+    /// fft_engine.discard_circuit_bootstrap_boolean_lwe_ciphertext(
+    ///     &mut output,
+    ///     &input,
+    ///     delta_log,
+    ///     &bsk,
+    ///     &cbs_pfpksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output.polynomial_size(), poly_size);
+    /// assert_eq!(output.decomposition_level_count(), cbs_level_count);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_circuit_bootstrap_boolean_lwe_ciphertext(
+        &mut self,
+        output: &mut GgswCiphertext64,
+        input: &LweCiphertext64,
+        delta_log: DeltaLog,
+        bsk: &FftFourierLweBootstrapKey64,
+        cbs_pfpksk: &LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) -> Result<(), LweCiphertextDiscardingCircuitBootstrapBooleanError<Self::EngineError>> {
+        FftEngine::check_supported_size(bsk.polynomial_size())?;
+        LweCiphertextDiscardingCircuitBootstrapBooleanError::perform_generic_checks(
+            output, input, delta_log, bsk, cbs_pfpksk, 64,
+        )?;
+        unsafe {
+            self.discard_circuit_bootstrap_boolean_lwe_ciphertext_unchecked(
+                output, input, delta_log, bsk, cbs_pfpksk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_circuit_bootstrap_boolean_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GgswCiphertext64,
+        input: &LweCiphertext64,
+        delta_log: DeltaLog,
+        bsk: &FftFourierLweBootstrapKey64,
+        cbs_pfpksk: &LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) {
+        let fft = Fft::new(bsk.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            circuit_bootstrap_boolean_scratch::<u64>(
+                input.0.lwe_size(),
+                bsk.output_lwe_dimension().to_lwe_size(),
+                bsk.glwe_dimension().to_glwe_size(),
+                bsk.polynomial_size(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        let polynomial_size = output.0.polynomial_size();
+        let glwe_size = output.0.glwe_size();
+        let base_log = output.0.decomposition_base_log();
+        let level_count = output.0.decomposition_level_count();
+        let output = StandardGgswCiphertext::new(
+            output.0.tensor.as_mut_slice(),
+            polynomial_size,
+            glwe_size,
+            base_log,
+            level_count,
+        );
+        circuit_bootstrap_boolean(
+            bsk.0.as_view(),
+            input.0.tensor.as_slice(),
+            output,
+            delta_log,
+            &cbs_pfpksk.0,
+            fft,
+            self.stack(),
+        );
+    }
+}
@@ -0,0 +1,316 @@
+use super::super::super::private::crypto::bootstrap::bootstrap_scratch;
+use super::super::super::private::crypto::glwe::GlweCiphertextView;
+use super::super::super::private::math::fft::Fft;
+use super::{FftEngine, FftError};
+use crate::commons::math::tensor::{AsMutSlice, AsRefSlice};
+use crate::prelude::{
+    FftFourierLweBootstrapKey32, FftFourierLweBootstrapKey64, GlweCiphertext32, GlweCiphertext64,
+    LutCountLog, LweCiphertext32, LweCiphertext64, LweCiphertextDiscardingMultiBootstrapEngine,
+    LweCiphertextDiscardingMultiBootstrapError, LweCiphertextVector32, LweCiphertextVector64,
+};
+
+impl From<FftError> for LweCiphertextDiscardingMultiBootstrapError<FftError> {
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingMultiBootstrapEngine`] for [`FftEngine`] that
+/// operates on 32 bit integers.
+impl
+    LweCiphertextDiscardingMultiBootstrapEngine<
+        FftFourierLweBootstrapKey32,
+        GlweCiphertext32,
+        LweCiphertext32,
+        LweCiphertextVector32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LutCountLog,
+    ///     LweCiphertextCount, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Messages of 3 bits are encoded with one bit of padding, in the middle of their box
+    /// let message = 5_u32;
+    /// let input = (2 * message + 1) << (32 - 3 - 2);
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // Two functions are interleaved in the accumulator, the j-th one maps m to (m + j) mod 8.
+    /// // Each message owns a box of N / 8 consecutive coefficients.
+    /// let lut_count_log = LutCountLog(1);
+    /// let f = |m: usize, j: usize| (((m + j) % 8) as u32) << (32 - 3 - 1);
+    /// let lut = (0..poly_size.0)
+    ///     .map(|i| f(i * 8 / poly_size.0, i % 2))
+    ///     .collect::<Vec<_>>();
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey32 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_sk_output,
+    ///     noise,
+    ///     LweCiphertextCount(1 << lut_count_log.0),
+    /// )?;
+    ///
+    /// fft_engine.discard_multi_bootstrap_lwe_ciphertext(
+    ///     &mut output,
+    ///     &input,
+    ///     &acc,
+    ///     &bsk,
+    ///     lut_count_log,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_sk_output.lwe_dimension());
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(2));
+    ///
+    /// // The j-th output encrypts f_j(5)
+    /// let decrypted = default_engine.decrypt_lwe_ciphertext_vector(&lwe_sk_output, &output)?;
+    /// let decrypted = default_engine.retrieve_plaintext_vector(&decrypted)?;
+    /// let rounding = 1_u32 << (32 - 3 - 2);
+    /// for (j, d) in decrypted.iter().enumerate() {
+    ///     assert_eq!(
+    ///         d.wrapping_add(rounding) >> (32 - 3 - 1),
+    ///         f(message as usize, j) >> (32 - 3 - 1)
+    ///     );
+    /// }
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_multi_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FftFourierLweBootstrapKey32,
+        lut_count_log: LutCountLog,
+    ) -> Result<(), LweCiphertextDiscardingMultiBootstrapError<Self::EngineError>> {
+        FftEngine::check_supported_size(acc.0.polynomial_size())?;
+        LweCiphertextDiscardingMultiBootstrapError::perform_generic_checks(
+            output,
+            input,
+            acc,
+            bsk,
+            lut_count_log,
+        )?;
+        unsafe {
+            self.discard_multi_bootstrap_lwe_ciphertext_unchecked(
+                output,
+                input,
+                acc,
+                bsk,
+                lut_count_log,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_multi_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FftFourierLweBootstrapKey32,
+        lut_count_log: LutCountLog,
+    ) {
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            bootstrap_scratch::<u32>(acc.0.size(), acc.0.polynomial_size(), fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let acc = GlweCiphertextView::new(
+            acc.0.tensor.as_slice(),
+            acc.0.polynomial_size(),
+            acc.0.size(),
+        );
+        bsk.0.as_view().multi_bootstrap(
+            output.0.tensor.as_mut_slice(),
+            input.0.tensor.as_slice(),
+            acc,
+            lut_count_log,
+            fft,
+            self.stack(),
+        );
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextDiscardingMultiBootstrapEngine`] for [`FftEngine`] that
+/// operates on 64 bit integers.
+impl
+    LweCiphertextDiscardingMultiBootstrapEngine<
+        FftFourierLweBootstrapKey64,
+        GlweCiphertext64,
+        LweCiphertext64,
+        LweCiphertextVector64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LutCountLog,
+    ///     LweCiphertextCount, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Messages of 3 bits are encoded with one bit of padding, in the middle of their box
+    /// let message = 5_u64;
+    /// let input = (2 * message + 1) << (64 - 3 - 2);
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // Four functions are interleaved in the accumulator, the j-th one maps m to (m + j) mod 8.
+    /// // Each message owns a box of N / 8 consecutive coefficients.
+    /// let lut_count_log = LutCountLog(2);
+    /// let f = |m: usize, j: usize| (((m + j) % 8) as u64) << (64 - 3 - 1);
+    /// let lut = (0..poly_size.0)
+    ///     .map(|i| f(i * 8 / poly_size.0, i % 4))
+    ///     .collect::<Vec<_>>();
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey64 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc = default_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input = default_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_sk_output,
+    ///     noise,
+    ///     LweCiphertextCount(1 << lut_count_log.0),
+    /// )?;
+    ///
+    /// fft_engine.discard_multi_bootstrap_lwe_ciphertext(
+    ///     &mut output,
+    ///     &input,
+    ///     &acc,
+    ///     &bsk,
+    ///     lut_count_log,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_sk_output.lwe_dimension());
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(4));
+    ///
+    /// // The j-th output encrypts f_j(5)
+    /// let decrypted = default_engine.decrypt_lwe_ciphertext_vector(&lwe_sk_output, &output)?;
+    /// let decrypted = default_engine.retrieve_plaintext_vector(&decrypted)?;
+    /// let rounding = 1_u64 << (64 - 3 - 2);
+    /// for (j, d) in decrypted.iter().enumerate() {
+    ///     assert_eq!(
+    ///         d.wrapping_add(rounding) >> (64 - 3 - 1),
+    ///         f(message as usize, j) >> (64 - 3 - 1)
+    ///     );
+    /// }
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_multi_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FftFourierLweBootstrapKey64,
+        lut_count_log: LutCountLog,
+    ) -> Result<(), LweCiphertextDiscardingMultiBootstrapError<Self::EngineError>> {
+        FftEngine::check_supported_size(acc.0.polynomial_size())?;
+        LweCiphertextDiscardingMultiBootstrapError::perform_generic_checks(
+            output,
+            input,
+            acc,
+            bsk,
+            lut_count_log,
+        )?;
+        unsafe {
+            self.discard_multi_bootstrap_lwe_ciphertext_unchecked(
+                output,
+                input,
+                acc,
+                bsk,
+                lut_count_log,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_multi_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FftFourierLweBootstrapKey64,
+        lut_count_log: LutCountLog,
+    ) {
+        let fft = Fft::new(acc.0.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            bootstrap_scratch::<u64>(acc.0.size(), acc.0.polynomial_size(), fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+        let acc = GlweCiphertextView::new(
+            acc.0.tensor.as_slice(),
+            acc.0.polynomial_size(),
+            acc.0.size(),
+        );
+        bsk.0.as_view().multi_bootstrap(
+            output.0.tensor.as_mut_slice(),
+            input.0.tensor.as_slice(),
+            acc,
+            lut_count_log,
+            fft,
+            self.stack(),
+        );
+    }
+}
@@ -0,0 +1,390 @@
+use super::super::super::private::crypto::wop_pbs::{
+    circuit_bootstrap_boolean_vertical_packing, circuit_bootstrap_boolean_vertical_packing_scratch,
+};
+use super::super::super::private::math::fft::Fft;
+use super::{FftEngine, FftError};
+use crate::commons::math::tensor::{AsMutSlice, AsRefSlice};
+use crate::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, FftFourierLweBootstrapKey32,
+    FftFourierLweBootstrapKey64, LweBootstrapKeyEntity, LweCiphertextVector32,
+    LweCiphertextVector64,
+    LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingEngine,
+    LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingError,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64, PlaintextVector32,
+    PlaintextVector64, PolynomialCount,
+};
+
+impl From<FftError>
+    for LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingError<FftError>
+{
+    fn from(err: FftError) -> Self {
+        Self::Engine(err)
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingEngine`]
+/// for [`FftEngine`] that operates on 32 bit integers.
+impl
+    LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingEngine<
+        LweCiphertextVector32,
+        LweCiphertextVector32,
+        FftFourierLweBootstrapKey32,
+        PlaintextVector32,
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied: the bits of message are stored in the MSB
+    /// let input = vec![1_u32 << (32 - 1), 0, 1_u32 << (32 - 1)];
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(10), GlweDimension(1), PolynomialSize(512));
+    /// let (bsk_level_count, bsk_base_log) =
+    ///     (DecompositionLevelCount(2), DecompositionBaseLog(10));
+    /// let (pfpksk_level_count, pfpksk_base_log) =
+    ///     (DecompositionLevelCount(2), DecompositionBaseLog(10));
+    /// let (cbs_level_count, cbs_base_log) =
+    ///     (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-80.));
+    /// // A single lookup table, made of a single polynomial, mapping each value on 3 bits to its
+    /// // square
+    /// let lut = (0..poly_size.0 as u32)
+    ///     .map(|i| ((i * i) % 8) << (32 - 4))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 = default_engine.generate_new_lwe_bootstrap_key(
+    ///     &lwe_sk,
+    ///     &glwe_sk,
+    ///     bsk_base_log,
+    ///     bsk_level_count,
+    ///     noise,
+    /// )?;
+    /// let bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let bsk_output_lwe_sk: LweSecretKey32 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    /// let cbs_pfpksk: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 = default_engine
+    ///     .generate_new_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &bsk_output_lwe_sk,
+    ///         &glwe_sk,
+    ///         pfpksk_base_log,
+    ///         pfpksk_level_count,
+    ///         noise,
+    ///     )?;
+    ///
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let input =
+    ///     default_engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector, noise)?;
+    /// let luts = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &bsk_output_lwe_sk,
+    ///     noise,
+    ///     LweCiphertextCount(1),
+    /// )?;
+    ///
+    /// fft_engine.discard_circuit_bootstrap_boolean_vertical_packing_lwe_ciphertext_vector(
+    ///     &mut output,
+    ///     &input,
+    ///     &bsk,
+    ///     &luts,
+    ///     cbs_level_count,
+    ///     cbs_base_log,
+    ///     &cbs_pfpksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), bsk_output_lwe_sk.lwe_dimension());
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(1));
+    ///
+    /// // The input bits (1, 0, 1), read MSB first, select the entry at index 5 of the lookup table
+    /// let decrypted = default_engine.decrypt_lwe_ciphertext_vector(&bsk_output_lwe_sk, &output)?;
+    /// let decrypted = default_engine.retrieve_plaintext_vector(&decrypted)?;
+    /// let rounding = 1_u32 << (32 - 4 - 1);
+    /// assert_eq!(
+    ///     decrypted[0].wrapping_add(rounding) >> (32 - 4),
+    ///     lut[0b101] >> (32 - 4)
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_circuit_bootstrap_boolean_vertical_packing_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        bsk: &FftFourierLweBootstrapKey32,
+        luts: &PlaintextVector32,
+        cbs_level_count: DecompositionLevelCount,
+        cbs_base_log: DecompositionBaseLog,
+        cbs_pfpksk: &LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) -> Result<
+        (),
+        LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingError<Self::EngineError>,
+    > {
+        FftEngine::check_supported_size(bsk.polynomial_size())?;
+        LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingError::perform_generic_checks(
+            output,
+            input,
+            bsk,
+            luts,
+            cbs_level_count,
+            cbs_base_log,
+            cbs_pfpksk,
+            32,
+        )?;
+        unsafe {
+            self.discard_circuit_bootstrap_boolean_vertical_packing_lwe_ciphertext_vector_unchecked(
+                output,
+                input,
+                bsk,
+                luts,
+                cbs_level_count,
+                cbs_base_log,
+                cbs_pfpksk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_circuit_bootstrap_boolean_vertical_packing_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        bsk: &FftFourierLweBootstrapKey32,
+        luts: &PlaintextVector32,
+        cbs_level_count: DecompositionLevelCount,
+        cbs_base_log: DecompositionBaseLog,
+        cbs_pfpksk: &LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) {
+        let fft = Fft::new(bsk.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            circuit_bootstrap_boolean_vertical_packing_scratch::<u32>(
+                input.0.count(),
+                output.0.count(),
+                input.0.lwe_size(),
+                PolynomialCount(luts.0.count().0 / bsk.polynomial_size().0),
+                bsk.output_lwe_dimension().to_lwe_size(),
+                bsk.glwe_dimension().to_glwe_size(),
+                bsk.polynomial_size(),
+                cbs_level_count,
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        circuit_bootstrap_boolean_vertical_packing(
+            luts.0.tensor.as_slice(),
+            bsk.0.as_view(),
+            output.0.tensor.as_mut_slice(),
+            input.0.tensor.as_slice(),
+            &cbs_pfpksk.0,
+            cbs_level_count,
+            cbs_base_log,
+            fft,
+            self.stack(),
+        );
+    }
+}
+
+/// # Description
+///
+/// Implementation of [`LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingEngine`]
+/// for [`FftEngine`] that operates on 64 bit integers.
+impl
+    LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingEngine<
+        LweCiphertextVector64,
+        LweCiphertextVector64,
+        FftFourierLweBootstrapKey64,
+        PlaintextVector64,
+        LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    > for FftEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied: the bits of message are stored in the MSB
+    /// let input = vec![1_u64 << (64 - 1), 0, 1_u64 << (64 - 1)];
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(10), GlweDimension(1), PolynomialSize(512));
+    /// let (bsk_level_count, bsk_base_log) =
+    ///     (DecompositionLevelCount(2), DecompositionBaseLog(10));
+    /// let (pfpksk_level_count, pfpksk_base_log) =
+    ///     (DecompositionLevelCount(2), DecompositionBaseLog(10));
+    /// let (cbs_level_count, cbs_base_log) =
+    ///     (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-120.));
+    /// // A single lookup table, made of a single polynomial, mapping each value on 3 bits to its
+    /// // square
+    /// let lut = (0..poly_size.0 as u64)
+    ///     .map(|i| ((i * i) % 8) << (64 - 4))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 = default_engine.generate_new_lwe_bootstrap_key(
+    ///     &lwe_sk,
+    ///     &glwe_sk,
+    ///     bsk_base_log,
+    ///     bsk_level_count,
+    ///     noise,
+    /// )?;
+    /// let bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let bsk_output_lwe_sk: LweSecretKey64 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    /// let cbs_pfpksk: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 = default_engine
+    ///     .generate_new_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &bsk_output_lwe_sk,
+    ///         &glwe_sk,
+    ///         pfpksk_base_log,
+    ///         pfpksk_level_count,
+    ///         noise,
+    ///     )?;
+    ///
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let input =
+    ///     default_engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector, noise)?;
+    /// let luts = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let mut output = default_engine.zero_encrypt_lwe_ciphertext_vector(
+    ///     &bsk_output_lwe_sk,
+    ///     noise,
+    ///     LweCiphertextCount(1),
+    /// )?;
+    ///
+    /// fft_engine.discard_circuit_bootstrap_boolean_vertical_packing_lwe_ciphertext_vector(
+    ///     &mut output,
+    ///     &input,
+    ///     &bsk,
+    ///     &luts,
+    ///     cbs_level_count,
+    ///     cbs_base_log,
+    ///     &cbs_pfpksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), bsk_output_lwe_sk.lwe_dimension());
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(1));
+    ///
+    /// // The input bits (1, 0, 1), read MSB first, select the entry at index 5 of the lookup table
+    /// let decrypted = default_engine.decrypt_lwe_ciphertext_vector(&bsk_output_lwe_sk, &output)?;
+    /// let decrypted = default_engine.retrieve_plaintext_vector(&decrypted)?;
+    /// let rounding = 1_u64 << (64 - 4 - 1);
+    /// assert_eq!(
+    ///     decrypted[0].wrapping_add(rounding) >> (64 - 4),
+    ///     lut[0b101] >> (64 - 4)
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_circuit_bootstrap_boolean_vertical_packing_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        bsk: &FftFourierLweBootstrapKey64,
+        luts: &PlaintextVector64,
+        cbs_level_count: DecompositionLevelCount,
+        cbs_base_log: DecompositionBaseLog,
+        cbs_pfpksk: &LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) -> Result<
+        (),
+        LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingError<Self::EngineError>,
+    > {
+        FftEngine::check_supported_size(bsk.polynomial_size())?;
+        LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingError::perform_generic_checks(
+            output,
+            input,
+            bsk,
+            luts,
+            cbs_level_count,
+            cbs_base_log,
+            cbs_pfpksk,
+            64,
+        )?;
+        unsafe {
+            self.discard_circuit_bootstrap_boolean_vertical_packing_lwe_ciphertext_vector_unchecked(
+                output,
+                input,
+                bsk,
+                luts,
+                cbs_level_count,
+                cbs_base_log,
+                cbs_pfpksk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_circuit_bootstrap_boolean_vertical_packing_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        bsk: &FftFourierLweBootstrapKey64,
+        luts: &PlaintextVector64,
+        cbs_level_count: DecompositionLevelCount,
+        cbs_base_log: DecompositionBaseLog,
+        cbs_pfpksk: &LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) {
+        let fft = Fft::new(bsk.polynomial_size());
+        let fft = fft.as_view();
+        self.resize(
+            circuit_bootstrap_boolean_vertical_packing_scratch::<u64>(
+                input.0.count(),
+                output.0.count(),
+                input.0.lwe_size(),
+                PolynomialCount(luts.0.count().0 / bsk.polynomial_size().0),
+                bsk.output_lwe_dimension().to_lwe_size(),
+                bsk.glwe_dimension().to_glwe_size(),
+                bsk.polynomial_size(),
+                cbs_level_count,
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+        circuit_bootstrap_boolean_vertical_packing(
+            luts.0.tensor.as_slice(),
+            bsk.0.as_view(),
+            output.0.tensor.as_mut_slice(),
+            input.0.tensor.as_slice(),
+            &cbs_pfpksk.0,
+            cbs_level_count,
+            cbs_base_log,
+            fft,
+            self.stack(),
+        );
+    }
+}
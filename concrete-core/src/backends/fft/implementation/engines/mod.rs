@@ -5,8 +5,33 @@ mod computation_engine;
 
 pub use computation_engine::{FftEngine, FftError};
 
+#[cfg(feature = "backend_fft_serialization")]
+mod serialization_engine;
+
+#[cfg(feature = "backend_fft_serialization")]
+pub use serialization_engine::{FftSerializationEngine, FftSerializationError};
+
+#[cfg(feature = "backend_fft_serialization")]
+mod deserialization;
+#[cfg(feature = "backend_fft_serialization")]
+mod serialization;
+#[cfg(feature = "backend_fft_serialization")]
+mod stream_deserialization;
+#[cfg(feature = "backend_fft_serialization")]
+mod stream_serialization;
+
 mod ggsw_ciphertext_conversion;
+mod glwe_ciphertext_discarding_multiplication;
+mod glwe_ciphertext_discarding_relinearization;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
+mod glwe_ciphertext_tensor_product;
 mod glwe_ciphertexts_ggsw_ciphertext_fusing_cmux;
+mod glwe_relinearization_key_conversion;
 mod lwe_bootstrap_key_conversion;
+mod lwe_ciphertext_discarding_bit_extraction;
 mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_circuit_bootstrap_boolean;
+mod lwe_ciphertext_discarding_multi_bootstrap;
+mod lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean_vertical_packing;
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_sample_extraction;
+mod lwe_ciphertext_vector_glwe_ciphertext_vector_discarding_sample_extraction;
@@ -1,12 +1,14 @@
-use super::super::super::private::crypto::bootstrap::FourierLweBootstrapKeyRef;
-use super::super::super::private::crypto::ggsw::FourierGgswCiphertextRef;
+use super::super::super::private::crypto::bootstrap::FourierLweBootstrapKeyView;
+use super::super::super::private::crypto::ggsw::FourierGgswCiphertextView;
 use super::{FftSerializationEngine, FftSerializationError};
 use crate::prelude::{
     EntitySerializationEngine, EntitySerializationError, FftFourierGgswCiphertext32,
     FftFourierGgswCiphertext32Version, FftFourierGgswCiphertext64,
     FftFourierGgswCiphertext64Version, FftFourierLweBootstrapKey32,
     FftFourierLweBootstrapKey32Version, FftFourierLweBootstrapKey64,
-    FftFourierLweBootstrapKey64Version,
+    FftFourierLweBootstrapKey64Version, FftFourierLweTernaryBootstrapKey32,
+    FftFourierLweTernaryBootstrapKey32Version, FftFourierLweTernaryBootstrapKey64,
+    FftFourierLweTernaryBootstrapKey64Version,
 };
 use serde::Serialize;
 
@@ -60,11 +62,11 @@ impl EntitySerializationEngine<FftFourierGgswCiphertext32, Vec<u8>> for FftSeria
         &mut self,
         entity: &FftFourierGgswCiphertext32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        let entity = entity.0.as_ref();
+        let entity = entity.0.as_view();
         #[derive(Serialize)]
         struct SerializableFftFourierGgswCiphertext32<'a> {
             version: FftFourierGgswCiphertext32Version,
-            inner: FourierGgswCiphertextRef<'a>,
+            inner: FourierGgswCiphertextView<'a>,
         }
         let value = SerializableFftFourierGgswCiphertext32 {
             version: FftFourierGgswCiphertext32Version::V0,
@@ -130,11 +132,11 @@ impl EntitySerializationEngine<FftFourierGgswCiphertext64, Vec<u8>> for FftSeria
         &mut self,
         entity: &FftFourierGgswCiphertext64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        let entity = entity.0.as_ref();
+        let entity = entity.0.as_view();
         #[derive(Serialize)]
         struct SerializableFftFourierGgswCiphertext64<'a> {
             version: FftFourierGgswCiphertext64Version,
-            inner: FourierGgswCiphertextRef<'a>,
+            inner: FourierGgswCiphertextView<'a>,
         }
         let value = SerializableFftFourierGgswCiphertext64 {
             version: FftFourierGgswCiphertext64Version::V0,
@@ -191,11 +193,11 @@ impl EntitySerializationEngine<FftFourierLweBootstrapKey32, Vec<u8>> for FftSeri
         &mut self,
         entity: &FftFourierLweBootstrapKey32,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        let entity = entity.0.as_ref();
+        let entity = entity.0.as_view();
         #[derive(Serialize)]
         struct SerializableFftFourierLweBootstrapKey32<'a> {
             version: FftFourierLweBootstrapKey32Version,
-            inner: FourierLweBootstrapKeyRef<'a>,
+            inner: FourierLweBootstrapKeyView<'a>,
         }
         let value = SerializableFftFourierLweBootstrapKey32 {
             version: FftFourierLweBootstrapKey32Version::V0,
@@ -252,11 +254,11 @@ impl EntitySerializationEngine<FftFourierLweBootstrapKey64, Vec<u8>> for FftSeri
         &mut self,
         entity: &FftFourierLweBootstrapKey64,
     ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
-        let entity = entity.0.as_ref();
+        let entity = entity.0.as_view();
         #[derive(Serialize)]
         struct SerializableFftFourierLweBootstrapKey64<'a> {
             version: FftFourierLweBootstrapKey64Version,
-            inner: FourierLweBootstrapKeyRef<'a>,
+            inner: FourierLweBootstrapKeyView<'a>,
         }
         let value = SerializableFftFourierLweBootstrapKey64 {
             version: FftFourierLweBootstrapKey64Version::V0,
@@ -271,3 +273,141 @@ impl EntitySerializationEngine<FftFourierLweBootstrapKey64, Vec<u8>> for FftSeri
         self.serialize(entity).unwrap()
     }
 }
+
+impl EntitySerializationEngine<FftFourierLweTernaryBootstrapKey32, Vec<u8>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::key_kinds::TernaryKeyKind;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32<TernaryKeyKind> =
+    ///     default_engine.generate_new_lwe_secret_key_with_kind(lwe_dim, TernaryKeyKind)?;
+    /// let glwe_sk: GlweSecretKey32 = default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweTernaryBootstrapKey32 =
+    ///     fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&fourier_bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &FftFourierLweTernaryBootstrapKey32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let entity = entity.0.as_view();
+        #[derive(Serialize)]
+        struct SerializableFftFourierLweTernaryBootstrapKey32<'a> {
+            version: FftFourierLweTernaryBootstrapKey32Version,
+            inner: FourierLweBootstrapKeyView<'a>,
+        }
+        let value = SerializableFftFourierLweTernaryBootstrapKey32 {
+            version: FftFourierLweTernaryBootstrapKey32Version::V0,
+            inner: entity,
+        };
+        bincode::serialize(&value)
+            .map_err(FftSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(
+        &mut self,
+        entity: &FftFourierLweTernaryBootstrapKey32,
+    ) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+impl EntitySerializationEngine<FftFourierLweTernaryBootstrapKey64, Vec<u8>>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::key_kinds::TernaryKeyKind;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64<TernaryKeyKind> =
+    ///     default_engine.generate_new_lwe_secret_key_with_kind(lwe_dim, TernaryKeyKind)?;
+    /// let glwe_sk: GlweSecretKey64 = default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweTernaryBootstrapKey64 =
+    ///     fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&fourier_bsk)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &FftFourierLweTernaryBootstrapKey64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        let entity = entity.0.as_view();
+        #[derive(Serialize)]
+        struct SerializableFftFourierLweTernaryBootstrapKey64<'a> {
+            version: FftFourierLweTernaryBootstrapKey64Version,
+            inner: FourierLweBootstrapKeyView<'a>,
+        }
+        let value = SerializableFftFourierLweTernaryBootstrapKey64 {
+            version: FftFourierLweTernaryBootstrapKey64Version::V0,
+            inner: entity,
+        };
+        bincode::serialize(&value)
+            .map_err(FftSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(
+        &mut self,
+        entity: &FftFourierLweTernaryBootstrapKey64,
+    ) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
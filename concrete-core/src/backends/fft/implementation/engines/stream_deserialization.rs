@@ -0,0 +1,518 @@
+use super::super::super::private::crypto::bootstrap::FourierLweBootstrapKey;
+use super::super::super::private::crypto::ggsw::FourierGgswCiphertext;
+use super::{FftSerializationEngine, FftSerializationError};
+use crate::prelude::{
+    EntityStreamDeserializationEngine, EntityStreamDeserializationError,
+    FftFourierGgswCiphertext32, FftFourierGgswCiphertext32Version, FftFourierGgswCiphertext64,
+    FftFourierGgswCiphertext64Version, FftFourierLweBootstrapKey32,
+    FftFourierLweBootstrapKey32Version, FftFourierLweBootstrapKey64,
+    FftFourierLweBootstrapKey64Version, FftFourierLweTernaryBootstrapKey32,
+    FftFourierLweTernaryBootstrapKey32Version, FftFourierLweTernaryBootstrapKey64,
+    FftFourierLweTernaryBootstrapKey64Version,
+};
+use aligned_vec::ABox;
+use concrete_fft::c64;
+use serde::Deserialize;
+use std::io::Read;
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a GGSW ciphertext in the Fourier domain
+/// from any [`Read`] source.
+impl<R: Read> EntityStreamDeserializationEngine<R, FftFourierGgswCiphertext32>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey32 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext in the standard domain
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext: FftFourierGgswCiphertext32 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized: Vec<u8> = Vec::new();
+    /// serialization_engine.serialize_into(&fourier_ciphertext, &mut serialized)?;
+    /// let recovered: FftFourierGgswCiphertext32 =
+    ///     serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(fourier_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: R,
+    ) -> Result<FftFourierGgswCiphertext32, EntityStreamDeserializationError<Self::EngineError>>
+    {
+        #[derive(Deserialize)]
+        struct SerializableFftFourierGgswCiphertext32 {
+            version: FftFourierGgswCiphertext32Version,
+            inner: FourierGgswCiphertext<ABox<[c64]>>,
+        }
+        let deserialized: SerializableFftFourierGgswCiphertext32 =
+            bincode::deserialize_from(reader)
+                .map_err(FftSerializationError::Deserialization)
+                .map_err(EntityStreamDeserializationError::Engine)?;
+        match deserialized {
+            SerializableFftFourierGgswCiphertext32 {
+                version: FftFourierGgswCiphertext32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            )),
+            SerializableFftFourierGgswCiphertext32 {
+                version: FftFourierGgswCiphertext32Version::V0,
+                inner,
+            } => Ok(FftFourierGgswCiphertext32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: R) -> FftFourierGgswCiphertext32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a GGSW ciphertext in the Fourier domain
+/// from any [`Read`] source.
+impl<R: Read> EntityStreamDeserializationEngine<R, FftFourierGgswCiphertext64>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// // We encrypt a GGSW ciphertext in the standard domain
+    /// let ciphertext =
+    ///     default_engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// // Then we convert it to the Fourier domain.
+    /// let fourier_ciphertext: FftFourierGgswCiphertext64 =
+    ///     fft_engine.convert_ggsw_ciphertext(&ciphertext)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized: Vec<u8> = Vec::new();
+    /// serialization_engine.serialize_into(&fourier_ciphertext, &mut serialized)?;
+    /// let recovered: FftFourierGgswCiphertext64 =
+    ///     serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(fourier_ciphertext, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: R,
+    ) -> Result<FftFourierGgswCiphertext64, EntityStreamDeserializationError<Self::EngineError>>
+    {
+        #[derive(Deserialize)]
+        struct SerializableFftFourierGgswCiphertext64 {
+            version: FftFourierGgswCiphertext64Version,
+            inner: FourierGgswCiphertext<ABox<[c64]>>,
+        }
+        let deserialized: SerializableFftFourierGgswCiphertext64 =
+            bincode::deserialize_from(reader)
+                .map_err(FftSerializationError::Deserialization)
+                .map_err(EntityStreamDeserializationError::Engine)?;
+        match deserialized {
+            SerializableFftFourierGgswCiphertext64 {
+                version: FftFourierGgswCiphertext64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            )),
+            SerializableFftFourierGgswCiphertext64 {
+                version: FftFourierGgswCiphertext64Version::V0,
+                inner,
+            } => Ok(FftFourierGgswCiphertext64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: R) -> FftFourierGgswCiphertext64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE bootstrap key in the Fourier domain
+/// from any [`Read`] source.
+impl<R: Read> EntityStreamDeserializationEngine<R, FftFourierLweBootstrapKey32>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey32 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized: Vec<u8> = Vec::new();
+    /// serialization_engine.serialize_into(&fourier_bsk, &mut serialized)?;
+    /// let recovered: FftFourierLweBootstrapKey32 =
+    ///     serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: R,
+    ) -> Result<FftFourierLweBootstrapKey32, EntityStreamDeserializationError<Self::EngineError>>
+    {
+        #[derive(Deserialize)]
+        struct SerializableFftFourierLweBootstrapKey32 {
+            version: FftFourierLweBootstrapKey32Version,
+            inner: FourierLweBootstrapKey<ABox<[c64]>>,
+        }
+        let deserialized: SerializableFftFourierLweBootstrapKey32 =
+            bincode::deserialize_from(reader)
+                .map_err(FftSerializationError::Deserialization)
+                .map_err(EntityStreamDeserializationError::Engine)?;
+        match deserialized {
+            SerializableFftFourierLweBootstrapKey32 {
+                version: FftFourierLweBootstrapKey32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            )),
+            SerializableFftFourierLweBootstrapKey32 {
+                version: FftFourierLweBootstrapKey32Version::V0,
+                inner,
+            } => Ok(FftFourierLweBootstrapKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: R) -> FftFourierLweBootstrapKey32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a LWE bootstrap key in the Fourier domain
+/// from any [`Read`] source.
+impl<R: Read> EntityStreamDeserializationEngine<R, FftFourierLweBootstrapKey64>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweBootstrapKey64 = fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized: Vec<u8> = Vec::new();
+    /// serialization_engine.serialize_into(&fourier_bsk, &mut serialized)?;
+    /// let recovered: FftFourierLweBootstrapKey64 =
+    ///     serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: R,
+    ) -> Result<FftFourierLweBootstrapKey64, EntityStreamDeserializationError<Self::EngineError>>
+    {
+        #[derive(Deserialize)]
+        struct SerializableFftFourierLweBootstrapKey64 {
+            version: FftFourierLweBootstrapKey64Version,
+            inner: FourierLweBootstrapKey<ABox<[c64]>>,
+        }
+        let deserialized: SerializableFftFourierLweBootstrapKey64 =
+            bincode::deserialize_from(reader)
+                .map_err(FftSerializationError::Deserialization)
+                .map_err(EntityStreamDeserializationError::Engine)?;
+        match deserialized {
+            SerializableFftFourierLweBootstrapKey64 {
+                version: FftFourierLweBootstrapKey64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            )),
+            SerializableFftFourierLweBootstrapKey64 {
+                version: FftFourierLweBootstrapKey64Version::V0,
+                inner,
+            } => Ok(FftFourierLweBootstrapKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(&mut self, reader: R) -> FftFourierLweBootstrapKey64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a LWE bootstrap key in the Fourier domain
+/// from any [`Read`] source.
+impl<R: Read> EntityStreamDeserializationEngine<R, FftFourierLweTernaryBootstrapKey32>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::key_kinds::TernaryKeyKind;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey32<TernaryKeyKind> =
+    ///     default_engine.generate_new_lwe_secret_key_with_kind(lwe_dim, TernaryKeyKind)?;
+    /// let glwe_sk: GlweSecretKey32 = default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey32 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweTernaryBootstrapKey32 =
+    ///     fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized: Vec<u8> = Vec::new();
+    /// serialization_engine.serialize_into(&fourier_bsk, &mut serialized)?;
+    /// let recovered: FftFourierLweTernaryBootstrapKey32 =
+    ///     serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: R,
+    ) -> Result<
+        FftFourierLweTernaryBootstrapKey32,
+        EntityStreamDeserializationError<Self::EngineError>,
+    > {
+        #[derive(Deserialize)]
+        struct SerializableFftFourierLweTernaryBootstrapKey32 {
+            version: FftFourierLweTernaryBootstrapKey32Version,
+            inner: FourierLweBootstrapKey<ABox<[c64]>>,
+        }
+        let deserialized: SerializableFftFourierLweTernaryBootstrapKey32 =
+            bincode::deserialize_from(reader)
+                .map_err(FftSerializationError::Deserialization)
+                .map_err(EntityStreamDeserializationError::Engine)?;
+        match deserialized {
+            SerializableFftFourierLweTernaryBootstrapKey32 {
+                version: FftFourierLweTernaryBootstrapKey32Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            )),
+            SerializableFftFourierLweTernaryBootstrapKey32 {
+                version: FftFourierLweTernaryBootstrapKey32Version::V0,
+                inner,
+            } => Ok(FftFourierLweTernaryBootstrapKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(
+        &mut self,
+        reader: R,
+    ) -> FftFourierLweTernaryBootstrapKey32 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityStreamDeserializationEngine`] for [`FftSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a LWE bootstrap key in the Fourier domain
+/// from any [`Read`] source.
+impl<R: Read> EntityStreamDeserializationEngine<R, FftFourierLweTernaryBootstrapKey64>
+    for FftSerializationEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::key_kinds::TernaryKeyKind;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut fft_engine = FftEngine::new(())?;
+    /// let lwe_sk: LweSecretKey64<TernaryKeyKind> =
+    ///     default_engine.generate_new_lwe_secret_key_with_kind(lwe_dim, TernaryKeyKind)?;
+    /// let glwe_sk: GlweSecretKey64 = default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey64 =
+    ///     default_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FftFourierLweTernaryBootstrapKey64 =
+    ///     fft_engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let mut serialization_engine = FftSerializationEngine::new(())?;
+    /// let mut serialized: Vec<u8> = Vec::new();
+    /// serialization_engine.serialize_into(&fourier_bsk, &mut serialized)?;
+    /// let recovered: FftFourierLweTernaryBootstrapKey64 =
+    ///     serialization_engine.deserialize_from(serialized.as_slice())?;
+    /// assert_eq!(fourier_bsk, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize_from(
+        &mut self,
+        reader: R,
+    ) -> Result<
+        FftFourierLweTernaryBootstrapKey64,
+        EntityStreamDeserializationError<Self::EngineError>,
+    > {
+        #[derive(Deserialize)]
+        struct SerializableFftFourierLweTernaryBootstrapKey64 {
+            version: FftFourierLweTernaryBootstrapKey64Version,
+            inner: FourierLweBootstrapKey<ABox<[c64]>>,
+        }
+        let deserialized: SerializableFftFourierLweTernaryBootstrapKey64 =
+            bincode::deserialize_from(reader)
+                .map_err(FftSerializationError::Deserialization)
+                .map_err(EntityStreamDeserializationError::Engine)?;
+        match deserialized {
+            SerializableFftFourierLweTernaryBootstrapKey64 {
+                version: FftFourierLweTernaryBootstrapKey64Version::Unsupported,
+                ..
+            } => Err(EntityStreamDeserializationError::Engine(
+                FftSerializationError::UnsupportedVersion,
+            )),
+            SerializableFftFourierLweTernaryBootstrapKey64 {
+                version: FftFourierLweTernaryBootstrapKey64Version::V0,
+                inner,
+            } => Ok(FftFourierLweTernaryBootstrapKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_from_unchecked(
+        &mut self,
+        reader: R,
+    ) -> FftFourierLweTernaryBootstrapKey64 {
+        self.deserialize_from(reader).unwrap()
+    }
+}
@@ -1,4 +1,5 @@
 use super::super::super::private::crypto::bootstrap::FourierLweBootstrapKey;
+use super::super::super::private::crypto::bootstrap128::FourierLweBootstrapKey128;
 use crate::specification::entities::markers::LweBootstrapKeyKind;
 use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
 use aligned_vec::ABox;
@@ -14,12 +15,22 @@ pub struct FftFourierLweBootstrapKey32(pub(crate) FourierLweBootstrapKey<ABox<[c
 #[derive(Debug, Clone, PartialEq)]
 pub struct FftFourierLweBootstrapKey64(pub(crate) FourierLweBootstrapKey<ABox<[c64]>>);
 
+/// A structure representing an LWE bootstrap key with 128 bits of precision, in the Fourier domain.
+///
+/// The coefficients of the key are split in 16 bits limbs before being converted to the Fourier
+/// domain, so that the bootstrap does not lose precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierLweBootstrapKey128(pub(crate) FourierLweBootstrapKey128<ABox<[c64]>>);
+
 impl AbstractEntity for FftFourierLweBootstrapKey32 {
     type Kind = LweBootstrapKeyKind;
 }
 impl AbstractEntity for FftFourierLweBootstrapKey64 {
     type Kind = LweBootstrapKeyKind;
 }
+impl AbstractEntity for FftFourierLweBootstrapKey128 {
+    type Kind = LweBootstrapKeyKind;
+}
 
 impl LweBootstrapKeyEntity for FftFourierLweBootstrapKey32 {
     fn glwe_dimension(&self) -> concrete_commons::parameters::GlweDimension {
@@ -63,6 +74,91 @@ impl LweBootstrapKeyEntity for FftFourierLweBootstrapKey64 {
         self.0.decomposition_level_count()
     }
 }
+impl LweBootstrapKeyEntity for FftFourierLweBootstrapKey128 {
+    fn glwe_dimension(&self) -> concrete_commons::parameters::GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> concrete_commons::parameters::PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> concrete_commons::parameters::LweDimension {
+        self.0.key_size()
+    }
+
+    fn decomposition_base_log(&self) -> concrete_commons::parameters::DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> concrete_commons::parameters::DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+}
+
+/// A structure representing an LWE bootstrap key for a ternary input key, with 32 bits of
+/// precision, in the Fourier domain.
+///
+/// The key contains two GGSW ciphertexts per coefficient of the ternary input key, which
+/// respectively encrypt whether this coefficient is equal to `1` and to `-1`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierLweTernaryBootstrapKey32(pub(crate) FourierLweBootstrapKey<ABox<[c64]>>);
+impl AbstractEntity for FftFourierLweTernaryBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for FftFourierLweTernaryBootstrapKey32 {
+    fn glwe_dimension(&self) -> concrete_commons::parameters::GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> concrete_commons::parameters::PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> concrete_commons::parameters::LweDimension {
+        concrete_commons::parameters::LweDimension(self.0.key_size().0 / 2)
+    }
+
+    fn decomposition_base_log(&self) -> concrete_commons::parameters::DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> concrete_commons::parameters::DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+}
+
+/// A structure representing an LWE bootstrap key for a ternary input key, with 64 bits of
+/// precision, in the Fourier domain.
+///
+/// The key contains two GGSW ciphertexts per coefficient of the ternary input key, which
+/// respectively encrypt whether this coefficient is equal to `1` and to `-1`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftFourierLweTernaryBootstrapKey64(pub(crate) FourierLweBootstrapKey<ABox<[c64]>>);
+impl AbstractEntity for FftFourierLweTernaryBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for FftFourierLweTernaryBootstrapKey64 {
+    fn glwe_dimension(&self) -> concrete_commons::parameters::GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> concrete_commons::parameters::PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> concrete_commons::parameters::LweDimension {
+        concrete_commons::parameters::LweDimension(self.0.key_size().0 / 2)
+    }
+
+    fn decomposition_base_log(&self) -> concrete_commons::parameters::DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> concrete_commons::parameters::DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+}
 
 #[cfg(feature = "backend_fft_serialization")]
 #[derive(Serialize, Deserialize)]
@@ -78,3 +174,17 @@ pub(crate) enum FftFourierLweBootstrapKey64Version {
     #[serde(other)]
     Unsupported,
 }
+#[cfg(feature = "backend_fft_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum FftFourierLweTernaryBootstrapKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+#[cfg(feature = "backend_fft_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum FftFourierLweTernaryBootstrapKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
@@ -2,7 +2,9 @@
 //! Concrete-FFT backend.
 
 mod ggsw_ciphertext;
+mod glwe_relinearization_key;
 mod lwe_bootstrap_key;
 
 pub use ggsw_ciphertext::*;
+pub use glwe_relinearization_key::*;
 pub use lwe_bootstrap_key::*;
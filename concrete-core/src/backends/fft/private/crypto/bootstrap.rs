@@ -26,6 +26,10 @@ pub struct StandardLweBootstrapKey<C> {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "backend_fft_serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct FourierLweBootstrapKey<C> {
     data: C,
     key_size: LweDimension,
@@ -282,11 +286,24 @@ pub fn bootstrap_scratch<Scalar>(
     )
 }
 
+/// Returns the required memory for [`FourierLweBootstrapKeyView::ternary_bootstrap`].
+pub fn ternary_bootstrap_scratch<Scalar>(
+    lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    bootstrap_scratch::<Scalar>(glwe_size, polynomial_size, fft)?.try_and(
+        StackReq::try_new_aligned::<Scalar>(2 * lwe_dimension.0 + 1, CACHELINE_ALIGN)?,
+    )
+}
+
 impl<'a> FourierLweBootstrapKeyView<'a> {
     pub fn blind_rotate<Scalar: UnsignedTorus + CastInto<usize>>(
         self,
         mut lut: GlweCiphertextMutView<'_, Scalar>,
         lwe: &[Scalar],
+        lut_count_log: LutCountLog,
         fft: FftView<'_>,
         mut stack: DynStack<'_>,
     ) {
@@ -297,7 +314,7 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
             *lwe_body,
             lut_poly_size,
             ModulusSwitchOffset(0),
-            LutCountLog(0),
+            lut_count_log,
         );
         lut.as_mut_view().into_polynomials().for_each(|poly| {
             poly.update_with_wrapping_unit_monomial_div(monomial_degree);
@@ -323,7 +340,7 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
                         *lwe_mask_element,
                         lut_poly_size,
                         ModulusSwitchOffset(0),
-                        LutCountLog(0),
+                        lut_count_log,
                     ));
                 }
 
@@ -347,11 +364,84 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
             accumulator.polynomial_size(),
             accumulator.glwe_size(),
         );
-        self.blind_rotate(local_accumulator.as_mut_view(), lwe_in, fft, stack);
+        self.blind_rotate(
+            local_accumulator.as_mut_view(),
+            lwe_in,
+            LutCountLog(0),
+            fft,
+            stack,
+        );
         local_accumulator
             .as_view()
             .fill_lwe_with_sample_extraction(lwe_out, 0);
     }
+
+    /// Bootstraps a ciphertext encrypted under a ternary key, with a key encrypting the binary
+    /// expansion of this ternary key.
+    ///
+    /// The input mask `(a_0, a_1, ...)` is expanded to `(a_0, -a_0, a_1, -a_1, ...)` before the
+    /// blind rotation, so that the two GGSW ciphertexts of each ternary coefficient respectively
+    /// rotate the accumulator by `a_i` when the coefficient is `1`, and by `-a_i` when it is `-1`.
+    pub fn ternary_bootstrap<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        lwe_out: &mut [Scalar],
+        lwe_in: &[Scalar],
+        accumulator: GlweCiphertextView<'_, Scalar>,
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        let (lwe_body, lwe_mask) = lwe_in.split_last().unwrap();
+        debug_assert_eq!(2 * lwe_mask.len(), self.key_size().0);
+
+        let expanded_mask = lwe_mask
+            .iter()
+            .flat_map(|mask_element| [*mask_element, mask_element.wrapping_neg()]);
+        let (expanded_lwe_in, stack) = stack.collect_aligned(
+            CACHELINE_ALIGN,
+            expanded_mask.chain(std::iter::once(*lwe_body)),
+        );
+        self.bootstrap(lwe_out, &expanded_lwe_in, accumulator, fft, stack);
+    }
+
+    /// Evaluates `2^lut_count_log` lookup tables, interleaved in the accumulator, with a single
+    /// blind rotation.
+    ///
+    /// `lwe_out` must contain `2^lut_count_log` contiguous LWE ciphertexts, the `j`-th of which is
+    /// filled with the evaluation of the `j`-th lookup table.
+    pub fn multi_bootstrap<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        lwe_out: &mut [Scalar],
+        lwe_in: &[Scalar],
+        accumulator: GlweCiphertextView<'_, Scalar>,
+        lut_count_log: LutCountLog,
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        let lut_count = 1 << lut_count_log.0;
+        debug_assert_eq!(lwe_out.len() % lut_count, 0);
+
+        let (mut local_accumulator_data, stack) =
+            stack.collect_aligned(CACHELINE_ALIGN, accumulator.data().iter().copied());
+        let mut local_accumulator = GlweCiphertextMutView::new(
+            &mut local_accumulator_data,
+            accumulator.polynomial_size(),
+            accumulator.glwe_size(),
+        );
+        self.blind_rotate(
+            local_accumulator.as_mut_view(),
+            lwe_in,
+            lut_count_log,
+            fft,
+            stack,
+        );
+
+        let lwe_size = lwe_out.len() / lut_count;
+        for (nth, lwe_out) in lwe_out.chunks_exact_mut(lwe_size).enumerate() {
+            local_accumulator
+                .as_view()
+                .fill_lwe_with_sample_extraction(lwe_out, nth);
+        }
+    }
 }
 
 /// This function switches modulus for a single coefficient of a ciphertext,
@@ -0,0 +1,576 @@
+//! Bootstrap of ciphertexts with 128 bits of precision.
+//!
+//! The coefficients of a 128 bits bootstrap key can not be stored in a `f64` without losing most
+//! of their precision. Each coefficient is instead split into [`LIMB_COUNT`] signed limbs of
+//! [`LIMB_BITS`] bits, and the Fourier transform of every limb polynomial is stored separately.
+//!
+//! During the external product, the decomposed GLWE polynomials (which are small) are multiplied
+//! with every limb polynomial. The products are brought back to the standard domain as exact
+//! integers, and recombined with the proper shifts. This requires the coefficients of each
+//! product to stay below $2^{52}$, that is $(k+1) \cdot \ell \cdot N \cdot 2^{B + 14} < 2^{52}$,
+//! where $B$ is the decomposition base log and $\ell$ the decomposition level count.
+use super::super::math::decomposition::TensorSignedDecompositionLendingIter;
+use super::super::math::fft::FftView;
+use super::super::math::polynomial::{
+    FourierPolynomialUninitMutView, FourierPolynomialView, PolynomialUninitMutView, PolynomialView,
+};
+use super::super::{as_mut_uninit, assume_init_mut, c64, izip, Container, IntoChunks};
+use super::bootstrap::{pbs_modulus_switch, StandardLweBootstrapKeyView};
+use super::ggsw::{
+    collect_next_term, update_with_fmadd, FourierGgswLevelRowView, StandardGgswCiphertextView,
+};
+use super::glwe::{GlweCiphertextMutView, GlweCiphertextView};
+use crate::commons::math::decomposition::SignedDecomposer;
+use aligned_vec::CACHELINE_ALIGN;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LutCountLog, LweDimension,
+    ModulusSwitchOffset, PolynomialSize,
+};
+use dyn_stack::{DynStack, ReborrowMut, SizeOverflow, StackReq};
+
+/// The number of bits of a limb.
+pub const LIMB_BITS: usize = 16;
+/// The number of limbs a 128 bits coefficient is split into.
+pub const LIMB_COUNT: usize = 128 / LIMB_BITS;
+
+const LIMB_MASK: u128 = (1 << LIMB_BITS) - 1;
+// 2^(LIMB_BITS - 1) in every limb
+const LIMB_OFFSET: u128 = (u128::MAX / LIMB_MASK) << (LIMB_BITS - 1);
+
+/// An LWE bootstrap key with 128 bits of precision, in the Fourier domain.
+///
+/// Every GGSW ciphertext is stored level by level, and every row of a level matrix is made of the
+/// [`LIMB_COUNT`] rows of its limbs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FourierLweBootstrapKey128<C> {
+    data: C,
+    key_size: LweDimension,
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+}
+
+/// A GGSW ciphertext with 128 bits of precision, in the Fourier domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FourierGgswCiphertext128<C> {
+    data: C,
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+}
+
+pub type FourierLweBootstrapKey128View<'a> = FourierLweBootstrapKey128<&'a [c64]>;
+pub type FourierLweBootstrapKey128MutView<'a> = FourierLweBootstrapKey128<&'a mut [c64]>;
+pub type FourierGgswCiphertext128View<'a> = FourierGgswCiphertext128<&'a [c64]>;
+pub type FourierGgswCiphertext128MutView<'a> = FourierGgswCiphertext128<&'a mut [c64]>;
+
+impl<C> FourierLweBootstrapKey128<C> {
+    pub fn new(
+        data: C,
+        key_size: LweDimension,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self
+    where
+        C: Container,
+    {
+        assert_eq!(polynomial_size.0 % 2, 0);
+        assert_eq!(
+            data.container_len(),
+            LIMB_COUNT * key_size.0 * polynomial_size.0 / 2
+                * decomposition_level_count.0
+                * glwe_size.0
+                * glwe_size.0
+        );
+        Self {
+            data,
+            key_size,
+            polynomial_size,
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        }
+    }
+
+    /// Returns an iterator over the GGSW ciphertexts composing the key.
+    pub fn into_ggsw_iter(self) -> impl DoubleEndedIterator<Item = FourierGgswCiphertext128<C>>
+    where
+        C: IntoChunks + Container,
+    {
+        self.data.split_into(self.key_size.0).map(move |slice| {
+            FourierGgswCiphertext128::new(
+                slice,
+                self.polynomial_size,
+                self.glwe_size,
+                self.decomposition_base_log,
+                self.decomposition_level_count,
+            )
+        })
+    }
+
+    pub fn key_size(&self) -> LweDimension {
+        self.key_size
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn data(self) -> C {
+        self.data
+    }
+
+    pub fn as_view(&self) -> FourierLweBootstrapKey128View<'_>
+    where
+        C: AsRef<[c64]>,
+    {
+        FourierLweBootstrapKey128View {
+            data: self.data.as_ref(),
+            key_size: self.key_size,
+            polynomial_size: self.polynomial_size,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> FourierLweBootstrapKey128MutView<'_>
+    where
+        C: AsMut<[c64]>,
+    {
+        FourierLweBootstrapKey128MutView {
+            data: self.data.as_mut(),
+            key_size: self.key_size,
+            polynomial_size: self.polynomial_size,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+}
+
+impl<C> FourierGgswCiphertext128<C> {
+    pub fn new(
+        data: C,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self
+    where
+        C: Container,
+    {
+        assert_eq!(polynomial_size.0 % 2, 0);
+        assert_eq!(
+            data.container_len(),
+            LIMB_COUNT * polynomial_size.0 / 2
+                * glwe_size.0
+                * glwe_size.0
+                * decomposition_level_count.0
+        );
+
+        Self {
+            data,
+            polynomial_size,
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn data(self) -> C {
+        self.data
+    }
+}
+
+/// Returns the `limb`-th signed limb of `value`.
+///
+/// The limbs take their values in $[-2^{15}, 2^{15})$, and `value` is the wrapping sum of its
+/// limbs, each shifted by `limb * LIMB_BITS` bits.
+#[inline]
+fn split_limb(value: u128, limb: usize) -> u128 {
+    let digit = (value.wrapping_add(LIMB_OFFSET) >> (limb * LIMB_BITS)) & LIMB_MASK;
+    digit.wrapping_sub(LIMB_OFFSET & LIMB_MASK)
+}
+
+/// Returns the required memory for [`FourierGgswCiphertext128MutView::fill_with_forward_fourier`].
+pub fn fill_with_forward_fourier_scratch(fft: FftView<'_>) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<u128>(fft.polynomial_size().0, CACHELINE_ALIGN)?
+        .try_and(fft.forward_scratch()?)
+}
+
+impl<'a> FourierGgswCiphertext128MutView<'a> {
+    /// Fills a GGSW ciphertext with the Fourier transform of the limbs of a GGSW ciphertext in the
+    /// standard domain.
+    pub fn fill_with_forward_fourier(
+        self,
+        coef_ggsw: StandardGgswCiphertextView<u128>,
+        fft: FftView<'_>,
+        mut stack: DynStack<'_>,
+    ) {
+        debug_assert_eq!(coef_ggsw.polynomial_size(), self.polynomial_size);
+        let poly_size = self.polynomial_size.0;
+        let glwe_size = self.glwe_size.0;
+
+        for (fourier_row, coef_row) in izip!(
+            self.data
+                .into_chunks(LIMB_COUNT * glwe_size * poly_size / 2),
+            coef_ggsw.data().into_chunks(glwe_size * poly_size)
+        ) {
+            for (limb, fourier_limb_row) in fourier_row
+                .into_chunks(glwe_size * poly_size / 2)
+                .enumerate()
+            {
+                for (fourier_poly, coef_poly) in izip!(
+                    fourier_limb_row.into_chunks(poly_size / 2),
+                    coef_row.into_chunks(poly_size)
+                ) {
+                    let (limb_poly, stack) = stack.rb_mut().collect_aligned(
+                        CACHELINE_ALIGN,
+                        coef_poly.iter().map(|coef| split_limb(*coef, limb)),
+                    );
+                    // SAFETY: forward_as_integer doesn't write any uninitialized values into its
+                    // output
+                    fft.forward_as_integer(
+                        FourierPolynomialUninitMutView {
+                            data: unsafe { as_mut_uninit(fourier_poly) },
+                        },
+                        PolynomialView { data: &limb_poly },
+                        stack,
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl<'a> FourierLweBootstrapKey128MutView<'a> {
+    /// Fills a bootstrapping key with the Fourier transform of the limbs of a bootstrapping key in
+    /// the standard domain.
+    pub fn fill_with_forward_fourier(
+        mut self,
+        coef_bsk: StandardLweBootstrapKeyView<u128>,
+        fft: FftView<'_>,
+        mut stack: DynStack<'_>,
+    ) {
+        for (fourier_ggsw, standard_ggsw) in izip!(
+            self.as_mut_view().into_ggsw_iter(),
+            coef_bsk.into_ggsw_iter()
+        ) {
+            fourier_ggsw.fill_with_forward_fourier(standard_ggsw, fft, stack.rb_mut());
+        }
+    }
+}
+
+/// Returns the required memory for [`external_product`].
+pub fn external_product_scratch(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+    let standard_scratch =
+        StackReq::try_new_aligned::<u128>(glwe_size.0 * polynomial_size.0, align)?;
+    let standard_scratch_single = StackReq::try_new_aligned::<u128>(polynomial_size.0, align)?;
+    let fourier_scratch =
+        StackReq::try_new_aligned::<c64>(LIMB_COUNT * glwe_size.0 * polynomial_size.0 / 2, align)?;
+    let fourier_scratch_single = StackReq::try_new_aligned::<c64>(polynomial_size.0 / 2, align)?;
+
+    let substack3 = fft.forward_scratch()?;
+    let substack2 = substack3.try_and(fourier_scratch_single)?;
+    let substack1 = substack2.try_and(standard_scratch)?;
+    let substack0 = StackReq::try_any_of([
+        substack1.try_and(standard_scratch)?,
+        standard_scratch_single.try_and(fft.backward_scratch()?)?,
+    ])?;
+    substack0.try_and(fourier_scratch)
+}
+
+/// Performs the external product of `ggsw` and `glwe`, and adds the result to `out`.
+pub fn external_product(
+    mut out: GlweCiphertextMutView<'_, u128>,
+    ggsw: FourierGgswCiphertext128View<'_>,
+    glwe: GlweCiphertextView<'_, u128>,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) {
+    // we check that the polynomial sizes match
+    debug_assert_eq!(ggsw.polynomial_size(), glwe.polynomial_size());
+    debug_assert_eq!(ggsw.polynomial_size(), out.polynomial_size());
+    // we check that the glwe sizes match
+    debug_assert_eq!(ggsw.glwe_size(), glwe.glwe_size());
+    debug_assert_eq!(ggsw.glwe_size(), out.glwe_size());
+
+    let align = CACHELINE_ALIGN;
+    let poly_size = ggsw.polynomial_size.0;
+    let glwe_size = ggsw.glwe_size.0;
+
+    // we round the input mask and body
+    let decomposer = SignedDecomposer::<u128>::new(
+        ggsw.decomposition_base_log(),
+        ggsw.decomposition_level_count(),
+    );
+
+    // The output buffer holds one accumulator per limb.
+    let (mut output_fft_buffer, mut substack0) =
+        stack.make_aligned_uninit::<c64>(LIMB_COUNT * glwe_size * poly_size / 2, align);
+    // output_fft_buffer is initially uninitialized, considered to be implicitly zero, to avoid
+    // the cost of filling it up with zeros. `is_output_uninit` is set to `false` once
+    // it has been fully initialized for the first time.
+    let output_fft_buffer = &mut *output_fft_buffer;
+    let mut is_output_uninit = true;
+
+    {
+        // ------------------------------------------------------ EXTERNAL PRODUCT IN FOURIER DOMAIN
+        // In this section, we perform the external product of every limb in the fourier domain,
+        // and accumulate the results in the output_fft_buffer variable.
+        let (mut decomposition, mut substack1) = TensorSignedDecompositionLendingIter::new(
+            glwe.data()
+                .iter()
+                .map(|s| decomposer.closest_representable(*s)),
+            DecompositionBaseLog(decomposer.base_log),
+            DecompositionLevelCount(decomposer.level_count),
+            substack0.rb_mut(),
+        );
+
+        // We loop through the levels (we reverse to match the order of the decomposition iterator.)
+        ggsw.data
+            .split_into(ggsw.decomposition_level_count.0)
+            .rev()
+            .for_each(|ggsw_decomp_matrix| {
+                // We retrieve the decomposition of this level.
+                let (glwe_level, glwe_decomp_term, mut substack2) =
+                    collect_next_term(&mut decomposition, &mut substack1, align);
+                let glwe_decomp_term = GlweCiphertextView::new(
+                    &glwe_decomp_term,
+                    ggsw.polynomial_size,
+                    ggsw.glwe_size,
+                );
+
+                izip!(
+                    ggsw_decomp_matrix.split_into(glwe_size),
+                    glwe_decomp_term.into_polynomials()
+                )
+                .for_each(|(ggsw_row, glwe_poly)| {
+                    let (mut fourier, substack3) = substack2
+                        .rb_mut()
+                        .make_aligned_uninit::<c64>(poly_size / 2, align);
+                    // We perform the forward fft transform for the glwe polynomial
+                    let fourier = fft
+                        .forward_as_integer(
+                            FourierPolynomialUninitMutView { data: &mut fourier },
+                            glwe_poly,
+                            substack3,
+                        )
+                        .data;
+
+                    // The same polynomial multiplies the row of every limb, and the products are
+                    // accumulated separately.
+                    izip!(
+                        (&mut *output_fft_buffer).into_chunks(glwe_size * poly_size / 2),
+                        ggsw_row.split_into(LIMB_COUNT)
+                    )
+                    .for_each(|(output_limb_buffer, ggsw_limb_row)| {
+                        // SAFETY: see comment above definition of `output_fft_buffer`
+                        unsafe {
+                            update_with_fmadd(
+                                output_limb_buffer,
+                                FourierGgswLevelRowView::new(
+                                    ggsw_limb_row,
+                                    ggsw.polynomial_size,
+                                    ggsw.glwe_size,
+                                    glwe_level,
+                                ),
+                                fourier,
+                                is_output_uninit,
+                                poly_size,
+                            )
+                        };
+                    });
+
+                    // we initialized `output_fft_buffer, so we can set this to false
+                    is_output_uninit = false;
+                });
+            });
+    }
+
+    // --------------------------------------------  TRANSFORMATION OF RESULT TO STANDARD DOMAIN
+    // In this section, we bring the products of every limb back to the standard domain as
+    // integers, and add them to the output with the shift of their limb.
+    if !is_output_uninit {
+        // SAFETY: output_fft_buffer is initialized, since `is_output_uninit` is false
+        let output_fft_buffer = &*unsafe { assume_init_mut(output_fft_buffer) };
+        for (limb, output_limb_buffer) in output_fft_buffer
+            .into_chunks(glwe_size * poly_size / 2)
+            .enumerate()
+        {
+            izip!(
+                out.as_mut_view().into_polynomials(),
+                output_limb_buffer.into_chunks(poly_size / 2)
+            )
+            .for_each(|(out, fourier)| {
+                let (mut product, substack1) = substack0
+                    .rb_mut()
+                    .make_aligned_uninit::<u128>(poly_size, align);
+                let product = fft
+                    .backward_as_integer(
+                        PolynomialUninitMutView { data: &mut product },
+                        FourierPolynomialView { data: fourier },
+                        substack1,
+                    )
+                    .data;
+                izip!(out.data, &*product).for_each(|(out, product)| {
+                    *out = out.wrapping_add(*product << (limb * LIMB_BITS));
+                });
+            });
+        }
+    }
+}
+
+/// Returns the required memory for [`cmux`].
+pub fn cmux_scratch(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    external_product_scratch(glwe_size, polynomial_size, fft)
+}
+
+/// This cmux mutates both ct1 and ct0. The result is in ct0 after the method was called.
+pub fn cmux(
+    ct0: GlweCiphertextMutView<'_, u128>,
+    mut ct1: GlweCiphertextMutView<'_, u128>,
+    ggsw: FourierGgswCiphertext128View<'_>,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) {
+    izip!(ct1.as_mut_view().data(), ct0.as_view().data()).for_each(|(c1, c0)| {
+        *c1 = c1.wrapping_sub(*c0);
+    });
+    external_product(ct0, ggsw, ct1.as_view(), fft, stack);
+}
+
+/// Returns the required memory for [`FourierLweBootstrapKey128View::blind_rotate`].
+pub fn blind_rotate_scratch(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<u128>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?
+        .try_and(cmux_scratch(glwe_size, polynomial_size, fft)?)
+}
+
+/// Returns the required memory for [`FourierLweBootstrapKey128View::bootstrap`].
+pub fn bootstrap_scratch(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    blind_rotate_scratch(glwe_size, polynomial_size, fft)?.try_and(
+        StackReq::try_new_aligned::<u128>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?,
+    )
+}
+
+impl<'a> FourierLweBootstrapKey128View<'a> {
+    pub fn blind_rotate(
+        self,
+        mut lut: GlweCiphertextMutView<'_, u128>,
+        lwe: &[u128],
+        fft: FftView<'_>,
+        mut stack: DynStack<'_>,
+    ) {
+        let (lwe_body, lwe_mask) = lwe.split_last().unwrap();
+
+        let lut_poly_size = lut.polynomial_size();
+        let monomial_degree = pbs_modulus_switch(
+            *lwe_body,
+            lut_poly_size,
+            ModulusSwitchOffset(0),
+            LutCountLog(0),
+        );
+        lut.as_mut_view().into_polynomials().for_each(|poly| {
+            poly.update_with_wrapping_unit_monomial_div(monomial_degree);
+        });
+
+        // We initialize the ct_0 used for the successive cmuxes
+        let mut ct0 = lut;
+
+        for (lwe_mask_element, bootstrap_key_ggsw) in izip!(lwe_mask.iter(), self.into_ggsw_iter())
+        {
+            if *lwe_mask_element != 0 {
+                let stack = stack.rb_mut();
+                // We copy ct_0 to ct_1
+                let (mut ct1, stack) =
+                    stack.collect_aligned(CACHELINE_ALIGN, ct0.as_view().data().iter().copied());
+
+                let mut ct1 =
+                    GlweCiphertextMutView::new(&mut ct1, ct0.polynomial_size(), ct0.glwe_size());
+
+                // We rotate ct_1 by performing ct_1 <- ct_1 * X^{a_hat}
+                for poly in ct1.as_mut_view().into_polynomials() {
+                    poly.update_with_wrapping_unit_monomial_mul(pbs_modulus_switch(
+                        *lwe_mask_element,
+                        lut_poly_size,
+                        ModulusSwitchOffset(0),
+                        LutCountLog(0),
+                    ));
+                }
+
+                cmux(ct0.as_mut_view(), ct1, bootstrap_key_ggsw, fft, stack);
+            }
+        }
+    }
+
+    pub fn bootstrap(
+        self,
+        lwe_out: &mut [u128],
+        lwe_in: &[u128],
+        accumulator: GlweCiphertextView<'_, u128>,
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        let (mut local_accumulator_data, stack) =
+            stack.collect_aligned(CACHELINE_ALIGN, accumulator.data().iter().copied());
+        let mut local_accumulator = GlweCiphertextMutView::new(
+            &mut local_accumulator_data,
+            accumulator.polynomial_size(),
+            accumulator.glwe_size(),
+        );
+        self.blind_rotate(local_accumulator.as_mut_view(), lwe_in, fft, stack);
+        local_accumulator
+            .as_view()
+            .fill_lwe_with_sample_extraction(lwe_out, 0);
+    }
+}
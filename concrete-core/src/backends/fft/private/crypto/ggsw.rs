@@ -11,7 +11,7 @@ use crate::commons::math::decomposition::{DecompositionLevel, SignedDecomposer};
 use crate::commons::math::torus::UnsignedTorus;
 use aligned_vec::CACHELINE_ALIGN;
 use concrete_commons::parameters::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweSize, PolynomialSize,
 };
 use dyn_stack::{DynStack, ReborrowMut, SizeOverflow, StackReq};
 
@@ -35,6 +35,10 @@ pub struct StandardGgswCiphertext<C> {
 
 /// A GGSW ciphertext in the Fourier domain.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "backend_fft_serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct FourierGgswCiphertext<C> {
     data: C,
     polynomial_size: PolynomialSize,
@@ -43,6 +47,17 @@ pub struct FourierGgswCiphertext<C> {
     decomposition_level_count: DecompositionLevelCount,
 }
 
+/// A list of GGSW ciphertexts in the Fourier domain, stored contiguously.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FourierGgswCiphertextList<C> {
+    data: C,
+    count: GgswCiphertextCount,
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+}
+
 /// A matrix containing a single level of gadget decomposition, in the Fourier domain.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct FourierGgswLevelMatrix<C> {
@@ -66,6 +81,8 @@ pub type StandardGgswCiphertextView<'a, Scalar> = StandardGgswCiphertext<&'a [Sc
 pub type StandardGgswCiphertextMutView<'a, Scalar> = StandardGgswCiphertext<&'a mut [Scalar]>;
 pub type FourierGgswCiphertextView<'a> = FourierGgswCiphertext<&'a [c64]>;
 pub type FourierGgswCiphertextMutView<'a> = FourierGgswCiphertext<&'a mut [c64]>;
+pub type FourierGgswCiphertextListView<'a> = FourierGgswCiphertextList<&'a [c64]>;
+pub type FourierGgswCiphertextListMutView<'a> = FourierGgswCiphertextList<&'a mut [c64]>;
 pub type FourierGgswLevelMatrixView<'a> = FourierGgswLevelMatrix<&'a [c64]>;
 pub type FourierGgswLevelMatrixMutView<'a> = FourierGgswLevelMatrix<&'a mut [c64]>;
 pub type FourierGgswLevelRowView<'a> = FourierGgswLevelRow<&'a [c64]>;
@@ -216,6 +233,140 @@ impl<C> FourierGgswCiphertext<C> {
     }
 }
 
+impl<C> FourierGgswCiphertextList<C> {
+    pub fn new(
+        data: C,
+        count: GgswCiphertextCount,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self
+    where
+        C: Container,
+    {
+        assert_eq!(polynomial_size.0 % 2, 0);
+        assert_eq!(
+            data.container_len(),
+            count.0 * polynomial_size.0 / 2
+                * glwe_size.0
+                * glwe_size.0
+                * decomposition_level_count.0
+        );
+
+        Self {
+            data,
+            count,
+            polynomial_size,
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        }
+    }
+
+    pub fn count(&self) -> GgswCiphertextCount {
+        self.count
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn data(self) -> C {
+        self.data
+    }
+
+    pub fn as_view(&self) -> FourierGgswCiphertextListView<'_>
+    where
+        C: AsRef<[c64]>,
+    {
+        FourierGgswCiphertextListView {
+            data: self.data.as_ref(),
+            count: self.count,
+            polynomial_size: self.polynomial_size,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> FourierGgswCiphertextListMutView<'_>
+    where
+        C: AsMut<[c64]>,
+    {
+        FourierGgswCiphertextListMutView {
+            data: self.data.as_mut(),
+            count: self.count,
+            polynomial_size: self.polynomial_size,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+
+    /// Returns an iterator over the GGSW ciphertexts of the list.
+    pub fn into_ggsw_iter(self) -> impl DoubleEndedIterator<Item = FourierGgswCiphertext<C>>
+    where
+        C: IntoChunks + Container,
+    {
+        let ggsw_len = self.polynomial_size.0 / 2
+            * self.glwe_size.0
+            * self.glwe_size.0
+            * self.decomposition_level_count.0;
+        self.data.into_chunks(ggsw_len).map(move |slice| {
+            FourierGgswCiphertext::new(
+                slice,
+                self.polynomial_size,
+                self.glwe_size,
+                self.decomposition_base_log,
+                self.decomposition_level_count,
+            )
+        })
+    }
+}
+
+impl<'a> FourierGgswCiphertextListView<'a> {
+    /// Splits the list in two, the first list containing the first `mid` ciphertexts.
+    pub fn split_at(self, mid: usize) -> (Self, Self) {
+        debug_assert!(mid <= self.count.0);
+        let ggsw_len = self.polynomial_size.0 / 2
+            * self.glwe_size.0
+            * self.glwe_size.0
+            * self.decomposition_level_count.0;
+        let (left, right) = self.data.split_at(mid * ggsw_len);
+        (
+            Self::new(
+                left,
+                GgswCiphertextCount(mid),
+                self.polynomial_size,
+                self.glwe_size,
+                self.decomposition_base_log,
+                self.decomposition_level_count,
+            ),
+            Self::new(
+                right,
+                GgswCiphertextCount(self.count.0 - mid),
+                self.polynomial_size,
+                self.glwe_size,
+                self.decomposition_base_log,
+                self.decomposition_level_count,
+            ),
+        )
+    }
+}
+
 impl<C> FourierGgswLevelMatrix<C> {
     pub fn new(
         data: C,
@@ -510,7 +661,7 @@ pub fn external_product<Scalar: UnsignedTorus>(
     }
 }
 
-fn collect_next_term<'a, Scalar: UnsignedTorus>(
+pub(crate) fn collect_next_term<'a, Scalar: UnsignedTorus>(
     decomposition: &mut TensorSignedDecompositionLendingIter<'_, Scalar>,
     substack1: &'a mut DynStack,
     align: usize,
@@ -684,7 +835,7 @@ unsafe fn update_with_fmadd_scalar(
 /// # Safety
 ///
 ///  - if `is_output_uninit` is false, `output_fourier` must not hold any uninitialized values.
-unsafe fn update_with_fmadd(
+pub(crate) unsafe fn update_with_fmadd(
     output_fft_buffer: &mut [MaybeUninit<c64>],
     ggsw_row: FourierGgswLevelRowView,
     fourier: &[c64],
@@ -1,3 +1,6 @@
 pub mod bootstrap;
+pub mod bootstrap128;
 pub mod ggsw;
 pub mod glwe;
+pub mod relinearization;
+pub mod wop_pbs;
@@ -0,0 +1,308 @@
+use super::super::math::decomposition::TensorSignedDecompositionLendingIter;
+use super::super::math::fft::FftView;
+use super::super::math::polynomial::{
+    FourierPolynomialUninitMutView, FourierPolynomialView, PolynomialView,
+};
+use super::super::{as_mut_uninit, assume_init_mut, c64, izip, Container, IntoChunks};
+use super::ggsw::{collect_next_term, update_with_fmadd, FourierGgswLevelMatrixView};
+use super::glwe::{GlweCiphertextMutView, GlweCiphertextView};
+use crate::commons::math::decomposition::{DecompositionLevel, SignedDecomposer};
+use crate::commons::math::torus::UnsignedTorus;
+use aligned_vec::CACHELINE_ALIGN;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+use dyn_stack::{DynStack, ReborrowMut, SizeOverflow, StackReq};
+
+/// A GLWE relinearization key in the Fourier domain.
+///
+/// Each level of the key is stored as a level matrix with one row per product $S_i S_j$ of the
+/// secret key polynomials (with $i\leq j$), which allows to reuse the GGSW machinery of the
+/// external product.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FourierGlweRelinearizationKey<C> {
+    data: C,
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+}
+
+pub type FourierGlweRelinearizationKeyView<'a> = FourierGlweRelinearizationKey<&'a [c64]>;
+pub type FourierGlweRelinearizationKeyMutView<'a> = FourierGlweRelinearizationKey<&'a mut [c64]>;
+
+/// Returns the number of products $S_i S_j$ with $i\leq j$ for a given GLWE size.
+fn key_product_count(glwe_size: GlweSize) -> usize {
+    let glwe_dimension = glwe_size.0 - 1;
+    glwe_dimension * (glwe_dimension + 1) / 2
+}
+
+impl<C> FourierGlweRelinearizationKey<C> {
+    pub fn new(
+        data: C,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self
+    where
+        C: Container,
+    {
+        assert_eq!(polynomial_size.0 % 2, 0);
+        assert_eq!(
+            data.container_len(),
+            polynomial_size.0 / 2
+                * glwe_size.0
+                * key_product_count(glwe_size)
+                * decomposition_level_count.0
+        );
+
+        Self {
+            data,
+            polynomial_size,
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn data(self) -> C {
+        self.data
+    }
+
+    pub fn as_view(&self) -> FourierGlweRelinearizationKeyView<'_>
+    where
+        C: AsRef<[c64]>,
+    {
+        FourierGlweRelinearizationKeyView {
+            data: self.data.as_ref(),
+            polynomial_size: self.polynomial_size,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> FourierGlweRelinearizationKeyMutView<'_>
+    where
+        C: AsMut<[c64]>,
+    {
+        FourierGlweRelinearizationKeyMutView {
+            data: self.data.as_mut(),
+            polynomial_size: self.polynomial_size,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+}
+
+/// Returns the required memory for
+/// [`FourierGlweRelinearizationKeyMutView::fill_with_forward_fourier`].
+pub fn fill_with_forward_fourier_scratch(fft: FftView<'_>) -> Result<StackReq, SizeOverflow> {
+    fft.forward_scratch()
+}
+
+impl<'a> FourierGlweRelinearizationKeyMutView<'a> {
+    /// Fills a relinearization key with the Fourier transform of a relinearization key in the
+    /// standard domain.
+    pub fn fill_with_forward_fourier<Scalar: UnsignedTorus>(
+        self,
+        coef_key: &[Scalar],
+        fft: FftView<'_>,
+        mut stack: DynStack<'_>,
+    ) {
+        let poly_size = self.polynomial_size.0;
+        debug_assert_eq!(coef_key.len(), self.data.len() * 2);
+
+        for (fourier_poly, coef_poly) in izip!(
+            self.data.into_chunks(poly_size / 2),
+            coef_key.into_chunks(poly_size)
+        ) {
+            // SAFETY: forward_as_torus doesn't write any uninitialized values into its output
+            fft.forward_as_torus(
+                FourierPolynomialUninitMutView {
+                    data: unsafe { as_mut_uninit(fourier_poly) },
+                },
+                PolynomialView { data: coef_poly },
+                stack.rb_mut(),
+            );
+        }
+    }
+}
+
+/// Returns the required memory for [`FourierGlweRelinearizationKeyView::relinearize`].
+pub fn relinearize_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+    let standard_scratch = StackReq::try_new_aligned::<Scalar>(
+        key_product_count(glwe_size) * polynomial_size.0,
+        align,
+    )?;
+    let fourier_scratch =
+        StackReq::try_new_aligned::<c64>(glwe_size.0 * polynomial_size.0 / 2, align)?;
+    let fourier_scratch_single = StackReq::try_new_aligned::<c64>(polynomial_size.0 / 2, align)?;
+
+    let substack3 = fft.forward_scratch()?;
+    let substack2 = substack3.try_and(fourier_scratch_single)?;
+    let substack1 = substack2.try_and(standard_scratch)?;
+    let substack0 = StackReq::try_any_of([
+        substack1.try_and(standard_scratch)?,
+        fft.backward_scratch()?,
+    ])?;
+    substack0.try_and(fourier_scratch)
+}
+
+impl<'a> FourierGlweRelinearizationKeyView<'a> {
+    /// Returns an iterator over the level matrices.
+    pub fn into_levels(self) -> impl DoubleEndedIterator<Item = FourierGgswLevelMatrixView<'a>> {
+        self.data
+            .split_into(self.decomposition_level_count.0)
+            .enumerate()
+            .map(move |(i, slice)| {
+                FourierGgswLevelMatrixView::new(
+                    slice,
+                    self.polynomial_size,
+                    self.glwe_size,
+                    key_product_count(self.glwe_size),
+                    DecompositionLevel(i + 1),
+                )
+            })
+    }
+
+    /// Relinearizes the tensor product ciphertext `input`, and stores the result in `out`.
+    pub fn relinearize<Scalar: UnsignedTorus>(
+        self,
+        mut out: GlweCiphertextMutView<'_, Scalar>,
+        input: GlweCiphertextView<'_, Scalar>,
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        // we check that the polynomial sizes match
+        debug_assert_eq!(self.polynomial_size(), input.polynomial_size());
+        debug_assert_eq!(self.polynomial_size(), out.polynomial_size());
+        // we check that the glwe sizes match
+        debug_assert_eq!(self.glwe_size(), out.glwe_size());
+        debug_assert_eq!(
+            input.glwe_size().0,
+            self.glwe_size.0 + key_product_count(self.glwe_size)
+        );
+
+        let align = CACHELINE_ALIGN;
+        let poly_size = self.polynomial_size.0;
+        let linear_size = (self.glwe_size.0 - 1) * poly_size;
+        let input = input.data();
+
+        // We copy the part of the input associated with S_i, and its body.
+        {
+            let out = out.as_mut_view().data();
+            out[..linear_size].copy_from_slice(&input[..linear_size]);
+            out[linear_size..].copy_from_slice(&input[input.len() - poly_size..]);
+        }
+
+        let decomposer = SignedDecomposer::<Scalar>::new(
+            self.decomposition_base_log(),
+            self.decomposition_level_count(),
+        );
+
+        let (mut output_fft_buffer, mut substack0) =
+            stack.make_aligned_uninit::<c64>(poly_size / 2 * self.glwe_size().0, align);
+        // output_fft_buffer is initially uninitialized, considered to be implicitly zero, to avoid
+        // the cost of filling it up with zeros. `is_output_uninit` is set to `false` once
+        // it has been fully initialized for the first time.
+        let output_fft_buffer = &mut *output_fft_buffer;
+        let mut is_output_uninit = true;
+
+        {
+            // -------------------------------------------------- RELINEARIZATION IN FOURIER DOMAIN
+            // In this section, we decompose the part of the input associated with S_i * S_j, and
+            // accumulate the product of the decomposition with the key in output_fft_buffer.
+            let (mut decomposition, mut substack1) = TensorSignedDecompositionLendingIter::new(
+                input[linear_size..input.len() - poly_size]
+                    .iter()
+                    .map(|s| decomposer.closest_representable(*s)),
+                DecompositionBaseLog(decomposer.base_log),
+                DecompositionLevelCount(decomposer.level_count),
+                substack0.rb_mut(),
+            );
+
+            // We loop through the levels (we reverse to match the order of the decomposition
+            // iterator.)
+            self.into_levels().rev().for_each(|key_level_matrix| {
+                // We retrieve the decomposition of this level.
+                let (level, decomp_term, mut substack2) =
+                    collect_next_term(&mut decomposition, &mut substack1, align);
+                let decomp_term = &*decomp_term;
+                debug_assert_eq!(key_level_matrix.decomposition_level(), level);
+
+                izip!(
+                    key_level_matrix.into_rows(),
+                    decomp_term.into_chunks(poly_size)
+                )
+                .for_each(|(key_row, decomp_poly)| {
+                    let (mut fourier, substack3) = substack2
+                        .rb_mut()
+                        .make_aligned_uninit::<c64>(poly_size / 2, align);
+                    // We perform the forward fft transform for the decomposed polynomial
+                    let fourier = fft
+                        .forward_as_integer(
+                            FourierPolynomialUninitMutView { data: &mut fourier },
+                            PolynomialView { data: decomp_poly },
+                            substack3,
+                        )
+                        .data;
+
+                    // SAFETY: see comment above definition of `output_fft_buffer`
+                    unsafe {
+                        update_with_fmadd(
+                            output_fft_buffer,
+                            key_row,
+                            fourier,
+                            is_output_uninit,
+                            poly_size,
+                        )
+                    };
+
+                    // we initialized `output_fft_buffer, so we can set this to false
+                    is_output_uninit = false;
+                });
+            });
+        }
+
+        // --------------------------------------------  TRANSFORMATION OF RESULT TO STANDARD DOMAIN
+        // In this section, we bring the result from the fourier domain, back to the standard
+        // domain, and add it to the output.
+        if !is_output_uninit {
+            // SAFETY: output_fft_buffer is initialized, since `is_output_uninit` is false
+            let output_fft_buffer = &*unsafe { assume_init_mut(output_fft_buffer) };
+            izip!(
+                out.into_polynomials(),
+                output_fft_buffer
+                    .into_chunks(poly_size / 2)
+                    .map(|slice| FourierPolynomialView { data: slice }),
+            )
+            .for_each(|(out, fourier)| {
+                fft.add_backward_as_torus(out, fourier, substack0.rb_mut());
+            });
+        }
+    }
+}
@@ -0,0 +1,754 @@
+//! Primitives for the so-called Wop-PBS (Without Padding Programmable Bootstrapping)
+
+use super::super::math::fft::FftView;
+use super::super::{c64, izip};
+use super::bootstrap::{bootstrap_scratch, FourierLweBootstrapKeyView};
+use super::ggsw::{
+    cmux, cmux_scratch, fill_with_forward_fourier_scratch, FourierGgswCiphertextListMutView,
+    FourierGgswCiphertextListView, StandardGgswCiphertext, StandardGgswCiphertextMutView,
+};
+use super::glwe::GlweCiphertextMutView;
+use crate::commons::crypto::glwe::{
+    GlweCiphertext as ImplGlweCiphertext, LwePrivateFunctionalPackingKeyswitchKeyList,
+};
+use crate::commons::crypto::lwe::{LweCiphertext as ImplLweCiphertext, LweKeyswitchKey};
+use crate::commons::math::tensor::AsRefSlice;
+use crate::commons::math::torus::UnsignedTorus;
+use aligned_vec::CACHELINE_ALIGN;
+use concrete_commons::numeric::CastInto;
+use concrete_commons::parameters::{
+    CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, DeltaLog, ExtractedBitsCount,
+    GgswCiphertextCount, GlweSize, LweDimension, LweSize, PolynomialCount, PolynomialSize,
+};
+use core::iter::repeat;
+use dyn_stack::{DynStack, ReborrowMut, SizeOverflow, StackReq};
+
+/// Returns the required memory for [`extract_bits`].
+pub fn extract_bits_scratch<Scalar>(
+    lwe_dimension: LweDimension,
+    ksk_after_key_size: LweDimension,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+
+    let lwe_in_buffer = StackReq::try_new_aligned::<Scalar>(lwe_dimension.to_lwe_size().0, align)?;
+    let lwe_out_ks_buffer =
+        StackReq::try_new_aligned::<Scalar>(ksk_after_key_size.to_lwe_size().0, align)?;
+    let pbs_accumulator =
+        StackReq::try_new_aligned::<Scalar>(glwe_size.0 * polynomial_size.0, align)?;
+    let lwe_out_pbs_buffer = StackReq::try_new_aligned::<Scalar>(
+        glwe_size.to_glwe_dimension().0 * polynomial_size.0 + 1,
+        align,
+    )?;
+    let lwe_bit_left_shift_buffer = lwe_in_buffer;
+    let bootstrap_scratch = bootstrap_scratch::<Scalar>(glwe_size, polynomial_size, fft)?;
+
+    lwe_in_buffer
+        .try_and(lwe_bit_left_shift_buffer)?
+        .try_and(lwe_out_ks_buffer)?
+        .try_and(pbs_accumulator)?
+        .try_and(lwe_out_pbs_buffer)?
+        .try_and(bootstrap_scratch)
+}
+
+/// Function to extract `number_of_bits_to_extract` from an LWE ciphertext starting at the bit
+/// number `delta_log` (0-indexed) included.
+///
+/// Output bits are ordered from the MSB to the LSB. Each one of them is output in a distinct LWE
+/// ciphertext, containing the encryption of the bit scaled by q/2 (i.e., the most significant bit
+/// in the plaintext representation).
+#[allow(clippy::too_many_arguments)]
+pub fn extract_bits<Scalar: UnsignedTorus + CastInto<usize>, C: AsRefSlice<Element = Scalar>>(
+    lwe_list_out: &mut [Scalar],
+    lwe_in: &[Scalar],
+    ksk: &LweKeyswitchKey<C>,
+    fourier_bsk: FourierLweBootstrapKeyView<'_>,
+    delta_log: DeltaLog,
+    number_of_bits_to_extract: ExtractedBitsCount,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) {
+    let ciphertext_n_bits = Scalar::BITS;
+    let number_of_bits_to_extract = number_of_bits_to_extract.0;
+    let polynomial_size = fourier_bsk.polynomial_size();
+    let glwe_size = fourier_bsk.glwe_size();
+    let glwe_dimension = glwe_size.to_glwe_dimension();
+    let bsk_output_lwe_dimension = glwe_dimension.0 * polynomial_size.0;
+
+    debug_assert!(
+        ciphertext_n_bits >= number_of_bits_to_extract + delta_log.0,
+        "Tried to extract {} bits, while the maximum number of extractable bits for {} bits
+        ciphertexts and a scaling factor of 2^{} is {}",
+        number_of_bits_to_extract,
+        ciphertext_n_bits,
+        delta_log.0,
+        ciphertext_n_bits - delta_log.0,
+    );
+    debug_assert!(
+        lwe_list_out.len() == number_of_bits_to_extract * ksk.lwe_size().0,
+        "lwe_list_out needs to contain {} ciphertexts of LWE dimension {}",
+        number_of_bits_to_extract,
+        ksk.after_key_size().0,
+    );
+    debug_assert!(
+        lwe_in.len() == bsk_output_lwe_dimension + 1,
+        "lwe_in needs to have an LWE dimension of {}, got {}",
+        bsk_output_lwe_dimension,
+        lwe_in.len() - 1,
+    );
+    debug_assert!(
+        ksk.after_key_size() == fourier_bsk.key_size(),
+        "ksk needs to have an output LWE dimension of {}, got {}",
+        fourier_bsk.key_size().0,
+        ksk.after_key_size().0,
+    );
+
+    let align = CACHELINE_ALIGN;
+
+    // The copy here is needed as we subtract extracted bits as we go from the original ciphertext
+    let (mut lwe_in_buffer_data, stack) = stack.collect_aligned(align, lwe_in.iter().copied());
+    let (mut lwe_bit_left_shift_buffer_data, stack) =
+        stack.collect_aligned(align, repeat(Scalar::ZERO).take(lwe_in.len()));
+    let (mut lwe_out_ks_buffer_data, stack) =
+        stack.collect_aligned(align, repeat(Scalar::ZERO).take(ksk.lwe_size().0));
+    let (mut pbs_accumulator_data, stack) = stack.collect_aligned(
+        align,
+        repeat(Scalar::ZERO).take(glwe_size.0 * polynomial_size.0),
+    );
+    let (mut lwe_out_pbs_buffer_data, mut stack) = stack.collect_aligned(
+        align,
+        repeat(Scalar::ZERO).take(bsk_output_lwe_dimension + 1),
+    );
+
+    let lwe_in_buffer = &mut *lwe_in_buffer_data;
+    let lwe_bit_left_shift_buffer = &mut *lwe_bit_left_shift_buffer_data;
+    let lwe_out_ks_buffer = &mut *lwe_out_ks_buffer_data;
+    let pbs_accumulator = &mut *pbs_accumulator_data;
+    let lwe_out_pbs_buffer = &mut *lwe_out_pbs_buffer_data;
+
+    // We iterate on the list in reverse as we want to store the extracted MSB at index 0
+    for (bit_idx, output_ct) in lwe_list_out
+        .chunks_exact_mut(ksk.lwe_size().0)
+        .rev()
+        .enumerate()
+    {
+        // Shift on padding bit
+        let shift = ciphertext_n_bits - delta_log.0 - bit_idx - 1;
+        for (out, inp) in izip!(lwe_bit_left_shift_buffer.iter_mut(), lwe_in_buffer.iter()) {
+            *out = *inp << shift;
+        }
+
+        // Key switch to input PBS key
+        ksk.keyswitch_ciphertext(
+            &mut ImplLweCiphertext::from_container(&mut *lwe_out_ks_buffer),
+            &ImplLweCiphertext::from_container(&*lwe_bit_left_shift_buffer),
+        );
+        // Store the keyswitch output unmodified to the output list (as we need to to do other
+        // computations on the output of the keyswitch)
+        output_ct.copy_from_slice(lwe_out_ks_buffer);
+
+        // If this was the last extracted bit, break
+        // -1 because if the number_of_bits_to_extract is 1 we want to stop right away
+        if bit_idx == number_of_bits_to_extract - 1 {
+            break;
+        }
+
+        // Add q/4 to center the error while computing a negacyclic LUT
+        let out_ks_body = lwe_out_ks_buffer.last_mut().unwrap();
+        *out_ks_body = out_ks_body.wrapping_add(Scalar::ONE << (ciphertext_n_bits - 2));
+
+        // Fill lut for the current bit (equivalent to trivial encryption as mask is 0s)
+        // The LUT is filled with -alpha in each coefficient where alpha = delta*2^{bit_idx-1}
+        let alpha = Scalar::ONE << (delta_log.0 - 1 + bit_idx);
+        for body_coeff in pbs_accumulator[glwe_dimension.0 * polynomial_size.0..].iter_mut() {
+            *body_coeff = alpha.wrapping_neg();
+        }
+
+        // Applying a negacyclic LUT on a ciphertext with one bit of message in the MSB and no bit
+        // of padding
+        fourier_bsk.bootstrap(
+            lwe_out_pbs_buffer,
+            lwe_out_ks_buffer,
+            GlweCiphertextMutView::new(pbs_accumulator, polynomial_size, glwe_size).as_view(),
+            fft,
+            stack.rb_mut(),
+        );
+
+        // Add alpha where alpha = delta*2^{bit_idx-1} to end up with an encryption of 0 if the
+        // extracted bit was 0 and 1 in the other case
+        let out_pbs_body = lwe_out_pbs_buffer.last_mut().unwrap();
+        *out_pbs_body = out_pbs_body.wrapping_add(alpha);
+
+        // Remove the extracted bit from the initial LWE to get a 0 at the extracted bit location.
+        for (out, inp) in izip!(lwe_in_buffer.iter_mut(), lwe_out_pbs_buffer.iter()) {
+            *out = out.wrapping_sub(*inp);
+        }
+    }
+}
+
+/// Returns the required memory for [`homomorphic_shift_boolean`].
+pub fn homomorphic_shift_boolean_scratch<Scalar>(
+    lwe_in_size: LweSize,
+    bsk_glwe_size: GlweSize,
+    bsk_polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+    StackReq::try_new_aligned::<Scalar>(lwe_in_size.0, align)?
+        .try_and(StackReq::try_new_aligned::<Scalar>(
+            bsk_polynomial_size.0 * bsk_glwe_size.0,
+            align,
+        )?)?
+        .try_and(bootstrap_scratch::<Scalar>(
+            bsk_glwe_size,
+            bsk_polynomial_size,
+            fft,
+        )?)
+}
+
+/// Homomorphic shift for LWE without padding bit
+///
+/// Starts by shifting the message bit at bit #delta_log to the padding bit and then shifts it to
+/// the right by base_log * level.
+#[allow(clippy::too_many_arguments)]
+pub fn homomorphic_shift_boolean<Scalar: UnsignedTorus + CastInto<usize>>(
+    fourier_bsk: FourierLweBootstrapKeyView<'_>,
+    lwe_out: &mut [Scalar],
+    lwe_in: &[Scalar],
+    level_count_cbs: DecompositionLevelCount,
+    base_log_cbs: DecompositionBaseLog,
+    delta_log: DeltaLog,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) {
+    let ciphertext_n_bits = Scalar::BITS;
+    let polynomial_size = fourier_bsk.polynomial_size();
+    let glwe_size = fourier_bsk.glwe_size();
+
+    // Shift message LSB on padding bit, at this point we expect to have messages with only 1 bit
+    // of information
+    let (mut lwe_left_shift_buffer, stack) = stack.collect_aligned(
+        CACHELINE_ALIGN,
+        lwe_in
+            .iter()
+            .map(|a| *a << (ciphertext_n_bits - delta_log.0 - 1)),
+    );
+
+    // Add q/4 to center the error while computing a negacyclic LUT
+    let shift_buffer_body = lwe_left_shift_buffer.last_mut().unwrap();
+    *shift_buffer_body = shift_buffer_body.wrapping_add(Scalar::ONE << (ciphertext_n_bits - 2));
+
+    // Fill lut (equivalent to trivial encryption as mask is 0s)
+    // The LUT is filled with -alpha in each coefficient where
+    // alpha = 2^{log(q) - 1 - base_log * level}
+    let alpha = Scalar::ONE << (ciphertext_n_bits - 1 - base_log_cbs.0 * level_count_cbs.0);
+    let (mut pbs_accumulator, stack) = stack.collect_aligned(
+        CACHELINE_ALIGN,
+        repeat(Scalar::ZERO)
+            .take(polynomial_size.0 * (glwe_size.0 - 1))
+            .chain(repeat(alpha.wrapping_neg()).take(polynomial_size.0)),
+    );
+
+    // Applying a negacyclic LUT on a ciphertext with one bit of message in the MSB and no bit
+    // of padding
+    fourier_bsk.bootstrap(
+        lwe_out,
+        &lwe_left_shift_buffer,
+        GlweCiphertextMutView::new(&mut pbs_accumulator, polynomial_size, glwe_size).as_view(),
+        fft,
+        stack,
+    );
+
+    // Add alpha where alpha = 2^{log(q) - 1 - base_log * level}
+    // To end up with an encryption of 0 if the message bit was 0 and 1 in the other case
+    let out_body = lwe_out.last_mut().unwrap();
+    *out_body = out_body.wrapping_add(alpha);
+}
+
+/// Returns the required memory for [`circuit_bootstrap_boolean`].
+pub fn circuit_bootstrap_boolean_scratch<Scalar>(
+    lwe_in_size: LweSize,
+    bsk_output_lwe_size: LweSize,
+    bsk_glwe_size: GlweSize,
+    bsk_polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<Scalar>(bsk_output_lwe_size.0, CACHELINE_ALIGN)?.try_and(
+        homomorphic_shift_boolean_scratch::<Scalar>(
+            lwe_in_size,
+            bsk_glwe_size,
+            bsk_polynomial_size,
+            fft,
+        )?,
+    )
+}
+
+/// Circuit bootstrapping for boolean messages, i.e. containing only one bit of message
+///
+/// The output GGSW ciphertext `ggsw_out` decomposition base log and level count are used as the
+/// circuit_bootstrap_boolean decomposition base log and level count.
+pub fn circuit_bootstrap_boolean<Scalar, C>(
+    fourier_bsk: FourierLweBootstrapKeyView<'_>,
+    lwe_in: &[Scalar],
+    ggsw_out: StandardGgswCiphertextMutView<'_, Scalar>,
+    delta_log: DeltaLog,
+    fpksk_list: &LwePrivateFunctionalPackingKeyswitchKeyList<C>,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) where
+    Scalar: UnsignedTorus + CastInto<usize>,
+    C: AsRefSlice<Element = Scalar>,
+{
+    let level_cbs = ggsw_out.decomposition_level_count();
+    let base_log_cbs = ggsw_out.decomposition_base_log();
+
+    debug_assert!(
+        level_cbs.0 >= 1,
+        "level_cbs needs to be >= 1, got {}",
+        level_cbs.0
+    );
+    debug_assert!(
+        base_log_cbs.0 >= 1,
+        "base_log_cbs needs to be >= 1, got {}",
+        base_log_cbs.0
+    );
+
+    let bsk_glwe_dimension = fourier_bsk.glwe_size().to_glwe_dimension();
+    let bsk_polynomial_size = fourier_bsk.polynomial_size();
+
+    debug_assert!(
+        fpksk_list.input_lwe_key_dimension().0 == bsk_polynomial_size.0 * bsk_glwe_dimension.0,
+        "The fourier_bsk output LWE dimension, got {}, must be equal to the fpksk \
+        input_lwe_key_dimension, got {}",
+        bsk_polynomial_size.0 * bsk_glwe_dimension.0,
+        fpksk_list.input_lwe_key_dimension().0
+    );
+    debug_assert!(
+        ggsw_out.polynomial_size() == fpksk_list.output_polynomial_size(),
+        "The output GGSW ciphertext needs to have the same polynomial size as the fpksks, \
+        got {}, expected {}",
+        ggsw_out.polynomial_size().0,
+        fpksk_list.output_polynomial_size().0
+    );
+    debug_assert!(
+        ggsw_out.glwe_size().to_glwe_dimension() == fpksk_list.output_glwe_key_dimension(),
+        "The output GGSW ciphertext needs to have the same GLWE dimension as the fpksks, \
+        got {}, expected {}",
+        ggsw_out.glwe_size().to_glwe_dimension().0,
+        fpksk_list.output_glwe_key_dimension().0
+    );
+    debug_assert!(
+        ggsw_out.glwe_size().0 == fpksk_list.fpksk_count().0,
+        "The input vector of fpksk needs to have {} (ggsw.glwe_size) elements got {}",
+        ggsw_out.glwe_size().0,
+        fpksk_list.fpksk_count().0,
+    );
+
+    let polynomial_size = ggsw_out.polynomial_size();
+    let glwe_size = ggsw_out.glwe_size();
+
+    // Output for every bootstrapping
+    let (mut lwe_out_bs_buffer, mut stack) = stack.collect_aligned(
+        CACHELINE_ALIGN,
+        repeat(Scalar::ZERO).take(bsk_polynomial_size.0 * bsk_glwe_dimension.0 + 1),
+    );
+
+    // Output for every pfksk that that come from the output GGSW
+    for (level_idx, level_glwes) in ggsw_out
+        .data()
+        .chunks_exact_mut(glwe_size.0 * glwe_size.0 * polynomial_size.0)
+        .enumerate()
+    {
+        homomorphic_shift_boolean(
+            fourier_bsk,
+            &mut lwe_out_bs_buffer,
+            lwe_in,
+            DecompositionLevelCount(level_idx + 1),
+            base_log_cbs,
+            delta_log,
+            fft,
+            stack.rb_mut(),
+        );
+
+        for (pfpksk, glwe_out) in fpksk_list
+            .fpksk_iter()
+            .zip(level_glwes.chunks_exact_mut(glwe_size.0 * polynomial_size.0))
+        {
+            pfpksk.private_functional_keyswitch_ciphertext(
+                &mut ImplGlweCiphertext::from_container(glwe_out, polynomial_size),
+                &ImplLweCiphertext::from_container(&*lwe_out_bs_buffer),
+            );
+        }
+    }
+}
+
+/// Returns the required memory for [`cmux_tree_memory_optimized`].
+pub fn cmux_tree_memory_optimized_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    nb_layer: usize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+    let t_scratch =
+        StackReq::try_new_aligned::<Scalar>(polynomial_size.0 * glwe_size.0 * nb_layer, align)?;
+
+    t_scratch // t_0
+        .try_and(t_scratch)? // t_1
+        .try_and(StackReq::try_new_aligned::<usize>(nb_layer, align)?)? // t_fill
+        .try_and(cmux_scratch::<Scalar>(glwe_size, polynomial_size, fft)?)
+}
+
+/// Performs a tree of cmux in a way that limits the total allocated memory to avoid issues for
+/// bigger trees.
+///
+/// `lut_per_layer` contains `2^ggsw_list.count()` contiguous polynomials. The GGSW ciphertexts
+/// are stored from the msb (`ggsw_list[0]`) to the lsb (`ggsw_list[last]`).
+pub fn cmux_tree_memory_optimized<Scalar: UnsignedTorus>(
+    mut output_glwe: GlweCiphertextMutView<'_, Scalar>,
+    lut_per_layer: &[Scalar],
+    ggsw_list: FourierGgswCiphertextListView<'_>,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) {
+    let polynomial_size = output_glwe.polynomial_size();
+    let glwe_size = output_glwe.glwe_size();
+    let nb_layer = ggsw_list.count().0;
+    let glwe_len = glwe_size.0 * polynomial_size.0;
+    let body_offset = (glwe_size.0 - 1) * polynomial_size.0;
+
+    debug_assert!(lut_per_layer.len() == polynomial_size.0 << nb_layer);
+
+    if nb_layer == 0 {
+        let output_glwe = output_glwe.data();
+        output_glwe[..body_offset].fill(Scalar::ZERO);
+        output_glwe[body_offset..].copy_from_slice(lut_per_layer);
+        return;
+    }
+
+    debug_assert_eq!(ggsw_list.polynomial_size(), polynomial_size);
+    debug_assert_eq!(ggsw_list.glwe_size(), glwe_size);
+
+    // These are accumulator that will be used to propagate the result from layer to layer
+    // At index 0 you have the lut that will be loaded, and then the result for each layer gets
+    // computed at the next index, last layer result gets stored in `output_glwe`.
+    // This allow to use memory space in C * nb_layer instead of C' * 2 ^ nb_layer
+    let (mut t_0, stack) = stack.collect_aligned(
+        CACHELINE_ALIGN,
+        repeat(Scalar::ZERO).take(glwe_len * nb_layer),
+    );
+    let (mut t_1, stack) = stack.collect_aligned(
+        CACHELINE_ALIGN,
+        repeat(Scalar::ZERO).take(glwe_len * nb_layer),
+    );
+    let (mut t_fill, mut stack) =
+        stack.collect_aligned(CACHELINE_ALIGN, repeat(0_usize).take(nb_layer));
+
+    for lut_pair in lut_per_layer.chunks_exact(2 * polynomial_size.0) {
+        let (lut_2_i, lut_2_i_plus_1) = lut_pair.split_at(polynomial_size.0);
+
+        // load 2 trivial CT with LUT
+        t_0[body_offset..glwe_len].copy_from_slice(lut_2_i);
+        t_1[body_offset..glwe_len].copy_from_slice(lut_2_i_plus_1);
+
+        t_fill[0] = 2;
+        for (j, ggsw) in ggsw_list.into_ggsw_iter().rev().enumerate() {
+            if t_fill[j] != 2 {
+                break;
+            }
+
+            // The split lets us borrow t_0[j] and t_1[j] alongside the accumulators of the next
+            // layer.
+            let (t_0_head, t_0_tail) = t_0.split_at_mut((j + 1) * glwe_len);
+            let (t_1_head, t_1_tail) = t_1.split_at_mut((j + 1) * glwe_len);
+            let t_0_j = &t_0_head[j * glwe_len..];
+            let t_1_j = &mut t_1_head[j * glwe_len..];
+
+            let mut ct0 = if j != nb_layer - 1 {
+                let next = if t_fill[j + 1] == 0 {
+                    t_0_tail
+                } else {
+                    t_1_tail
+                };
+                GlweCiphertextMutView::new(&mut next[..glwe_len], polynomial_size, glwe_size)
+            } else {
+                output_glwe.as_mut_view()
+            };
+
+            // The cmux stores ggsw ? t_1[j] : t_0[j] in ct0, and uses t_1[j] as a buffer.
+            ct0.as_mut_view().data().copy_from_slice(t_0_j);
+            cmux(
+                ct0,
+                GlweCiphertextMutView::new(t_1_j, polynomial_size, glwe_size),
+                ggsw,
+                fft,
+                stack.rb_mut(),
+            );
+
+            if j != nb_layer - 1 {
+                t_fill[j + 1] += 1;
+                t_fill[j] = 0;
+            }
+        }
+    }
+}
+
+/// Returns the required memory for [`blind_rotate_assign`].
+pub fn blind_rotate_assign_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<Scalar>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?
+        .try_and(cmux_scratch::<Scalar>(glwe_size, polynomial_size, fft)?)
+}
+
+/// Rotates `lut` by the value encrypted bit by bit in `ggsw_list`, the GGSW ciphertexts being
+/// stored from the msb (`ggsw_list[0]`) to the lsb (`ggsw_list[last]`).
+pub fn blind_rotate_assign<Scalar: UnsignedTorus>(
+    mut lut: GlweCiphertextMutView<'_, Scalar>,
+    ggsw_list: FourierGgswCiphertextListView<'_>,
+    fft: FftView<'_>,
+    mut stack: DynStack<'_>,
+) {
+    let mut monomial_degree = 1;
+
+    for ggsw in ggsw_list.into_ggsw_iter().rev() {
+        let ct0 = lut.as_mut_view();
+        let (mut ct1, stack) = stack
+            .rb_mut()
+            .collect_aligned(CACHELINE_ALIGN, ct0.as_view().data().iter().copied());
+        let mut ct1 = GlweCiphertextMutView::new(&mut ct1, ct0.polynomial_size(), ct0.glwe_size());
+
+        // We rotate ct_1 by performing ct_1 <- ct_1 * X^{-monomial_degree}
+        for poly in ct1.as_mut_view().into_polynomials() {
+            poly.update_with_wrapping_unit_monomial_div(monomial_degree);
+        }
+        monomial_degree <<= 1;
+
+        cmux(ct0, ct1, ggsw, fft, stack);
+    }
+}
+
+/// Returns the base 2 logarithm (rounded down) of the number of polynomials in a lookup table,
+/// i.e. if there is one polynomial, the number will be 0.
+fn log2_polynomial_count(polynomial_count: PolynomialCount) -> usize {
+    usize::BITS as usize - 1 - polynomial_count.0.leading_zeros() as usize
+}
+
+/// Returns the required memory for [`vertical_packing`].
+pub fn vertical_packing_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    lut_polynomial_count: PolynomialCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let log_lut_number = log2_polynomial_count(lut_polynomial_count);
+
+    StackReq::try_new_aligned::<Scalar>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?.try_and(
+        StackReq::try_any_of([
+            cmux_tree_memory_optimized_scratch::<Scalar>(
+                glwe_size,
+                polynomial_size,
+                log_lut_number,
+                fft,
+            )?,
+            blind_rotate_assign_scratch::<Scalar>(glwe_size, polynomial_size, fft)?,
+        ])?,
+    )
+}
+
+/// Evaluates the lookup table `lut` on the value encrypted bit by bit in `ggsw_list`, and stores
+/// the result in `lwe_out`.
+///
+/// `lut` contains a power of two number of contiguous polynomials. The GGSW ciphertexts are
+/// stored from the msb (`ggsw_list[0]`) to the lsb (`ggsw_list[last]`).
+pub fn vertical_packing<Scalar: UnsignedTorus>(
+    lut: &[Scalar],
+    lwe_out: &mut [Scalar],
+    ggsw_list: FourierGgswCiphertextListView<'_>,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) {
+    let polynomial_size = ggsw_list.polynomial_size();
+    let glwe_size = ggsw_list.glwe_size();
+    let glwe_dimension = glwe_size.to_glwe_dimension();
+
+    debug_assert!(
+        lwe_out.len() == polynomial_size.0 * glwe_dimension.0 + 1,
+        "Output LWE ciphertext needs to have an LweDimension of {}, got {}",
+        polynomial_size.0 * glwe_dimension.0,
+        lwe_out.len() - 1,
+    );
+    debug_assert_eq!(lut.len() % polynomial_size.0, 0);
+
+    let lut_polynomial_count = PolynomialCount(lut.len() / polynomial_size.0);
+    debug_assert!(lut_polynomial_count.0.is_power_of_two());
+
+    let log_lut_number = log2_polynomial_count(lut_polynomial_count);
+    debug_assert!(
+        log_lut_number <= ggsw_list.count().0,
+        "Need at least {} GGSW ciphertexts to select a polynomial in `lut`, got {}",
+        log_lut_number,
+        ggsw_list.count().0,
+    );
+
+    // split the list of GGSW in two, the msb GGSW is for the CMux tree and the lsb GGSW is for
+    // the last blind rotation.
+    let (cmux_ggsw, br_ggsw) = ggsw_list.split_at(log_lut_number);
+
+    let (mut cmux_tree_lut_res, mut stack) = stack.collect_aligned(
+        CACHELINE_ALIGN,
+        repeat(Scalar::ZERO).take(glwe_size.0 * polynomial_size.0),
+    );
+    let mut cmux_tree_lut_res =
+        GlweCiphertextMutView::new(&mut cmux_tree_lut_res, polynomial_size, glwe_size);
+
+    cmux_tree_memory_optimized(
+        cmux_tree_lut_res.as_mut_view(),
+        lut,
+        cmux_ggsw,
+        fft,
+        stack.rb_mut(),
+    );
+    blind_rotate_assign(cmux_tree_lut_res.as_mut_view(), br_ggsw, fft, stack);
+
+    // sample extract of the RLWE of the Vertical packing
+    cmux_tree_lut_res
+        .as_view()
+        .fill_lwe_with_sample_extraction(lwe_out, 0);
+}
+
+/// Returns the required memory for [`circuit_bootstrap_boolean_vertical_packing`].
+#[allow(clippy::too_many_arguments)]
+pub fn circuit_bootstrap_boolean_vertical_packing_scratch<Scalar>(
+    lwe_list_in_count: CiphertextCount,
+    lwe_list_out_count: CiphertextCount,
+    lwe_in_size: LweSize,
+    big_lut_polynomial_count: PolynomialCount,
+    bsk_output_lwe_size: LweSize,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    level_cbs: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+    let ggsw_len = polynomial_size.0 * glwe_size.0 * glwe_size.0 * level_cbs.0;
+    // We deduce the number of luts in the big lut from the number of ciphertexts in lwe_list_out
+    let small_lut_polynomial_count =
+        PolynomialCount(big_lut_polynomial_count.0 / lwe_list_out_count.0);
+
+    StackReq::try_new_aligned::<c64>(lwe_list_in_count.0 * ggsw_len / 2, align)?
+        .try_and(StackReq::try_new_aligned::<Scalar>(ggsw_len, align)?)?
+        .try_and(StackReq::try_any_of([
+            circuit_bootstrap_boolean_scratch::<Scalar>(
+                lwe_in_size,
+                bsk_output_lwe_size,
+                glwe_size,
+                polynomial_size,
+                fft,
+            )?,
+            fill_with_forward_fourier_scratch(fft)?,
+            vertical_packing_scratch::<Scalar>(
+                glwe_size,
+                polynomial_size,
+                small_lut_polynomial_count,
+                fft,
+            )?,
+        ])?)
+}
+
+/// Perform a circuit bootstrap followed by a vertical packing on ciphertexts encrypting boolean
+/// messages.
+///
+/// The circuit bootstrapping uses the private functional packing key switch.
+///
+/// `big_lut` contains one lookup table per output ciphertext, each made of a power of two number
+/// of contiguous polynomials. The input ciphertexts are ordered from the msb (`lwe_list_in[0]`) to
+/// the lsb (`lwe_list_in[last]`) of the value on which the lookup tables are evaluated.
+///
+/// This is supposed to be used only with boolean (1 bit of message) LWE ciphertexts.
+#[allow(clippy::too_many_arguments)]
+pub fn circuit_bootstrap_boolean_vertical_packing<Scalar, C>(
+    big_lut: &[Scalar],
+    fourier_bsk: FourierLweBootstrapKeyView<'_>,
+    lwe_list_out: &mut [Scalar],
+    lwe_list_in: &[Scalar],
+    fpksk_list: &LwePrivateFunctionalPackingKeyswitchKeyList<C>,
+    level_cbs: DecompositionLevelCount,
+    base_log_cbs: DecompositionBaseLog,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) where
+    Scalar: UnsignedTorus + CastInto<usize>,
+    C: AsRefSlice<Element = Scalar>,
+{
+    let polynomial_size = fourier_bsk.polynomial_size();
+    let glwe_size = fourier_bsk.glwe_size();
+    let lwe_in_size = fourier_bsk.key_size().to_lwe_size();
+    let lwe_out_size = polynomial_size.0 * glwe_size.to_glwe_dimension().0 + 1;
+
+    debug_assert!(!lwe_list_in.is_empty(), "Got empty `lwe_list_in`");
+    debug_assert_eq!(lwe_list_in.len() % lwe_in_size.0, 0);
+    debug_assert!(
+        lwe_list_out.len() % lwe_out_size == 0,
+        "Output LWE ciphertexts need to have an LweDimension of {}",
+        lwe_out_size - 1,
+    );
+
+    let lwe_list_in_count = lwe_list_in.len() / lwe_in_size.0;
+    let ggsw_len = polynomial_size.0 * glwe_size.0 * glwe_size.0 * level_cbs.0;
+
+    let (mut ggsw_list_data, stack) = stack.collect_aligned(
+        CACHELINE_ALIGN,
+        repeat(c64::default()).take(lwe_list_in_count * ggsw_len / 2),
+    );
+    let (mut ggsw_res_data, mut stack) =
+        stack.collect_aligned(CACHELINE_ALIGN, repeat(Scalar::ZERO).take(ggsw_len));
+
+    let mut ggsw_list = FourierGgswCiphertextListMutView::new(
+        &mut ggsw_list_data,
+        GgswCiphertextCount(lwe_list_in_count),
+        polynomial_size,
+        glwe_size,
+        base_log_cbs,
+        level_cbs,
+    );
+    let mut ggsw_res = StandardGgswCiphertext::new(
+        &mut *ggsw_res_data,
+        polynomial_size,
+        glwe_size,
+        base_log_cbs,
+        level_cbs,
+    );
+
+    for (lwe_in, ggsw) in izip!(
+        lwe_list_in.chunks_exact(lwe_in_size.0),
+        ggsw_list.as_mut_view().into_ggsw_iter()
+    ) {
+        circuit_bootstrap_boolean(
+            fourier_bsk,
+            lwe_in,
+            ggsw_res.as_mut_view(),
+            DeltaLog(Scalar::BITS - 1),
+            fpksk_list,
+            fft,
+            stack.rb_mut(),
+        );
+        ggsw.fill_with_forward_fourier(ggsw_res.as_view(), fft, stack.rb_mut());
+    }
+
+    // We deduce the number of luts in the big lut from the number of ciphertexts in lwe_list_out
+    let number_of_luts = lwe_list_out.len() / lwe_out_size;
+    debug_assert_eq!(big_lut.len() % number_of_luts, 0);
+    let small_lut_size = big_lut.len() / number_of_luts;
+
+    for (lut, lwe_out) in izip!(
+        big_lut.chunks_exact(small_lut_size),
+        lwe_list_out.chunks_exact_mut(lwe_out_size)
+    ) {
+        vertical_packing(lut, lwe_out, ggsw_list.as_view(), fft, stack.rb_mut());
+    }
+}
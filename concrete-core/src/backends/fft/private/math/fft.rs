@@ -226,6 +226,29 @@ fn convert_backward_torus<Scalar: UnsignedTorus>(
     );
 }
 
+fn convert_backward_integer<Scalar: UnsignedTorus>(
+    out_re: &mut [MaybeUninit<Scalar>],
+    out_im: &mut [MaybeUninit<Scalar>],
+    inp: &[c64],
+    twisties: TwistiesView<'_>,
+) {
+    // the integers are mapped back to the torus so that `from_torus` performs the rounding and the
+    // wrapping to the modulus
+    let normalization = 2.0_f64.powi(-(Scalar::BITS as i32)) / inp.len() as f64;
+    izip!(out_re, out_im, inp, twisties.re, twisties.im).for_each(
+        |(out_re, out_im, inp, w_re, w_im)| {
+            let tmp = inp
+                * (c64 {
+                    re: *w_re,
+                    im: -*w_im,
+                } * normalization);
+
+            out_re.write(Scalar::from_torus(tmp.re));
+            out_im.write(Scalar::from_torus(tmp.im));
+        },
+    );
+}
+
 /// Performs common work for `u32` and `u64`, used by the backward torus transformation.
 ///
 /// # Safety
@@ -645,6 +668,30 @@ impl<'a> FftView<'a> {
         unsafe { self.backward_with_conv(standard, fourier, convert_backward_torus, stack) }
     }
 
+    /// Performs an inverse negacyclic real FFT of `fourier` and stores the result in `standard`,
+    /// viewed as integers.
+    ///
+    /// The coefficients are rounded to the closest integer, and wrapped around the modulus of
+    /// `Scalar`. They are only exact if their magnitude is small enough to be represented exactly
+    /// by a `f64`.
+    ///
+    /// # Note
+    ///
+    /// this function leaves all the elements of `out_re` and `out_im` in an initialized state.
+    ///
+    /// # Panics
+    ///
+    /// See [`Self::forward_as_torus`]
+    pub fn backward_as_integer<'out, Scalar: UnsignedTorus>(
+        self,
+        standard: PolynomialUninitMutView<'out, Scalar>,
+        fourier: FourierPolynomialView<'_>,
+        stack: DynStack<'_>,
+    ) -> PolynomialMutView<'out, Scalar> {
+        // SAFETY: `convert_backward_integer` initializes the output slices that are passed to it
+        unsafe { self.backward_with_conv(standard, fourier, convert_backward_integer, stack) }
+    }
+
     /// Performs an inverse negacyclic real FFT of `fourier` and adds the result to `standard`,
     /// viewed as torus elements.
     ///
@@ -161,6 +161,84 @@ fn test_product<Scalar: UnsignedTorus>() {
     }
 }
 
+fn test_integer_product<Scalar: UnsignedTorus>() {
+    let mut generator = new_random_generator();
+    for i in 1..=10 {
+        let size = 1_usize << i;
+
+        let fft = Fft::new(PolynomialSize(size));
+        let fft = fft.as_view();
+
+        let mut poly0 = Polynomial {
+            data: avec![Scalar::ZERO; size].into_boxed_slice(),
+        };
+        let mut poly1 = Polynomial {
+            data: avec![Scalar::ZERO; size].into_boxed_slice(),
+        };
+        let mut convolution_from_fft = Polynomial {
+            data: avec![Scalar::ZERO; size].into_boxed_slice(),
+        };
+        let mut fourier0 = FourierPolynomial {
+            data: avec![c64::default(); size / 2].into_boxed_slice(),
+        };
+        let mut fourier1 = FourierPolynomial {
+            data: avec![c64::default(); size / 2].into_boxed_slice(),
+        };
+
+        // small signed integers, such that the product is exactly representable
+        let half = Scalar::ONE << 15;
+        for (x, y) in izip!(&mut *poly0.data, &mut *poly1.data) {
+            let r0: Scalar = generator.random_uniform();
+            let r1: Scalar = generator.random_uniform();
+            *x = (r0 >> (Scalar::BITS - 16)).wrapping_sub(half);
+            *y = (r1 >> (Scalar::BITS - 10)).wrapping_sub(half >> 6);
+        }
+
+        let mut mem = GlobalMemBuffer::new(
+            fft.forward_scratch()
+                .unwrap()
+                .and(fft.backward_scratch().unwrap()),
+        );
+        let mut stack = DynStack::new(&mut mem);
+
+        // SAFETY: forward_as_integer doesn't write any uninitialized values into its output
+        fft.forward_as_integer(
+            unsafe { fourier0.as_mut_view().into_uninit() },
+            poly0.as_view(),
+            stack.rb_mut(),
+        );
+        fft.forward_as_integer(
+            unsafe { fourier1.as_mut_view().into_uninit() },
+            poly1.as_view(),
+            stack.rb_mut(),
+        );
+
+        for (f0, f1) in izip!(&mut *fourier0.data, &*fourier1.data) {
+            *f0 *= *f1;
+        }
+
+        // SAFETY: backward_as_integer doesn't write any uninitialized values into its output
+        fft.backward_as_integer(
+            unsafe { convolution_from_fft.as_mut_view().into_uninit() },
+            fourier0.as_view(),
+            stack.rb_mut(),
+        );
+
+        for (j, actual) in convolution_from_fft.data.iter().enumerate() {
+            let mut expected = Scalar::ZERO;
+            for k in 0..size {
+                let prod = poly0.data[k].wrapping_mul(poly1.data[(size + j - k) % size]);
+                if k <= j {
+                    expected = expected.wrapping_add(prod);
+                } else {
+                    expected = expected.wrapping_sub(prod);
+                }
+            }
+            assert_eq!(expected, *actual);
+        }
+    }
+}
+
 #[test]
 fn test_product_u32() {
     test_product::<u32>();
@@ -179,3 +257,12 @@ fn test_roundtrip_u32() {
 fn test_roundtrip_u64() {
     test_roundtrip::<u64>();
 }
+
+#[test]
+fn test_integer_product_u64() {
+    test_integer_product::<u64>();
+}
+#[test]
+fn test_integer_product_u128() {
+    test_integer_product::<u128>();
+}
@@ -9,5 +9,8 @@ pub mod fftw;
 #[cfg(feature = "backend_fft")]
 pub mod fft;
 
+#[cfg(feature = "backend_npe")]
+pub mod npe;
+
 #[cfg(all(feature = "backend_cuda", not(feature = "_ci_do_not_compile")))]
 pub mod cuda;
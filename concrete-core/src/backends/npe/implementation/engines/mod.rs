@@ -0,0 +1,4 @@
+//! A module containing the [engines](crate::specification::engines) exposed by the npe backend.
+
+mod npe_engine;
+pub use npe_engine::*;
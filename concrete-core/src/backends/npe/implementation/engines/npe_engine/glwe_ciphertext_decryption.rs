@@ -0,0 +1,75 @@
+use crate::backends::default::entities::{GlweSecretKey64, PlaintextVector64};
+use crate::backends::npe::implementation::engines::NpeEngine;
+use crate::backends::npe::implementation::entities::NpeGlweCiphertext64;
+use crate::specification::engines::{
+    GlweCiphertextDecryptionEngine, GlweCiphertextDecryptionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDecryptionEngine`] for [`NpeEngine`] that operates on 64 bits
+/// integers. An error is returned if the estimated noise of the ciphertext is too large for the
+/// decryption to output the right messages.
+impl GlweCiphertextDecryptionEngine<GlweSecretKey64, NpeGlweCiphertext64, PlaintextVector64>
+    for NpeEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DeltaLog, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut npe_engine = NpeEngine::new(NpeParameters {
+    ///     seeder: Box::new(UnixSeeder::new(UNSAFE_SECRET)),
+    ///     delta_log: DeltaLog(50),
+    ///     log2_failure_probability: -40.,
+    /// })?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    /// let ciphertext: NpeGlweCiphertext64 =
+    ///     npe_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector = npe_engine.decrypt_glwe_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(
+    /// #     decrypted_plaintext_vector.plaintext_count(),
+    /// #     plaintext_vector.plaintext_count()
+    /// # );
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &NpeGlweCiphertext64,
+    ) -> Result<PlaintextVector64, GlweCiphertextDecryptionError<Self::EngineError>> {
+        GlweCiphertextDecryptionError::perform_generic_checks(key, input)?;
+        self.check_noise(input.1)
+            .map_err(GlweCiphertextDecryptionError::Engine)?;
+        Ok(unsafe { self.decrypt_glwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &NpeGlweCiphertext64,
+    ) -> PlaintextVector64 {
+        self.default_engine
+            .decrypt_glwe_ciphertext_unchecked(key, &input.0)
+    }
+}
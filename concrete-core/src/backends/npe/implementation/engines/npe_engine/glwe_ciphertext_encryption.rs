@@ -0,0 +1,80 @@
+use crate::backends::default::entities::{GlweSecretKey64, PlaintextVector64};
+use crate::backends::npe::implementation::engines::NpeEngine;
+use crate::backends::npe::implementation::entities::NpeGlweCiphertext64;
+use crate::specification::engines::{
+    GlweCiphertextEncryptionEngine, GlweCiphertextEncryptionError,
+};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`NpeEngine`] that operates on 64 bits
+/// integers. The variance of the noise of the output ciphertext is the variance of the encryption
+/// noise.
+impl GlweCiphertextEncryptionEngine<GlweSecretKey64, PlaintextVector64, NpeGlweCiphertext64>
+    for NpeEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DeltaLog, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut npe_engine = NpeEngine::new(NpeParameters {
+    ///     seeder: Box::new(UnixSeeder::new(UNSAFE_SECRET)),
+    ///     delta_log: DeltaLog(50),
+    ///     log2_failure_probability: -40.,
+    /// })?;
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&input)?;
+    ///
+    /// let ciphertext: NpeGlweCiphertext64 =
+    ///     npe_engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.variance(), noise);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<NpeGlweCiphertext64, GlweCiphertextEncryptionError<Self::EngineError>> {
+        GlweCiphertextEncryptionError::perform_generic_checks(key, input)?;
+        self.check_noise(noise)
+            .map_err(GlweCiphertextEncryptionError::Engine)?;
+        Ok(unsafe { self.encrypt_glwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> NpeGlweCiphertext64 {
+        NpeGlweCiphertext64(
+            self.default_engine
+                .encrypt_glwe_ciphertext_unchecked(key, input, noise),
+            noise,
+        )
+    }
+}
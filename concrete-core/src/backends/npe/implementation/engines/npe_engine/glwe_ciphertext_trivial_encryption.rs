@@ -0,0 +1,73 @@
+use crate::backends::default::entities::PlaintextVector64;
+use crate::backends::npe::implementation::engines::NpeEngine;
+use crate::backends::npe::implementation::entities::NpeGlweCiphertext64;
+use crate::specification::engines::{
+    GlweCiphertextTrivialEncryptionEngine, GlweCiphertextTrivialEncryptionError,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::GlweSize;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextTrivialEncryptionEngine`] for [`NpeEngine`] that operates on
+/// 64 bits integers. The output ciphertext has no noise.
+impl GlweCiphertextTrivialEncryptionEngine<PlaintextVector64, NpeGlweCiphertext64> for NpeEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{DeltaLog, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut npe_engine = NpeEngine::new(NpeParameters {
+    ///     seeder: Box::new(UnixSeeder::new(UNSAFE_SECRET)),
+    ///     delta_log: DeltaLog(50),
+    ///     log2_failure_probability: -40.,
+    /// })?;
+    /// let plaintext_vector: PlaintextVector64 =
+    ///     default_engine.create_plaintext_vector_from(&input)?;
+    /// // DISCLAIMER: trivial encryption is NOT secure, and DOES NOT hide the message at all.
+    /// let ciphertext: NpeGlweCiphertext64 = npe_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dimension.to_glwe_size(), &plaintext_vector)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.variance().0, 0.);
+    /// // Since its mask is zero, a trivial ciphertext decrypts to its message under any key.
+    /// let key: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let decrypted = npe_engine.decrypt_glwe_ciphertext(&key, &ciphertext)?;
+    /// assert_eq!(default_engine.retrieve_plaintext_vector(&decrypted)?, input);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_glwe_ciphertext(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &PlaintextVector64,
+    ) -> Result<NpeGlweCiphertext64, GlweCiphertextTrivialEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.trivially_encrypt_glwe_ciphertext_unchecked(glwe_size, input) })
+    }
+
+    unsafe fn trivially_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        glwe_size: GlweSize,
+        input: &PlaintextVector64,
+    ) -> NpeGlweCiphertext64 {
+        NpeGlweCiphertext64(
+            self.default_engine
+                .trivially_encrypt_glwe_ciphertext_unchecked(glwe_size, input),
+            Variance(0.),
+        )
+    }
+}
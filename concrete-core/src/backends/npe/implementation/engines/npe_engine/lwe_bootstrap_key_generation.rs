@@ -0,0 +1,112 @@
+use crate::backends::default::entities::{GlweSecretKey64, LweBootstrapKey64, LweSecretKey64};
+use crate::backends::fft::engines::FftEngine;
+use crate::backends::fft::entities::FftFourierLweBootstrapKey64;
+use crate::backends::npe::implementation::engines::{NpeEngine, NpeError};
+use crate::backends::npe::implementation::entities::NpeFftFourierLweBootstrapKey64;
+use crate::specification::engines::{
+    LweBootstrapKeyConversionEngine, LweBootstrapKeyGenerationEngine,
+    LweBootstrapKeyGenerationError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyGenerationEngine`] for [`NpeEngine`] that operates on 64
+/// bits integers. The key is generated in the standard domain and converted to the Fourier domain
+/// of the fft backend. The variance of the encryption noise is stored along with the key.
+impl
+    LweBootstrapKeyGenerationEngine<LweSecretKey64, GlweSecretKey64, NpeFftFourierLweBootstrapKey64>
+    for NpeEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(1), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut npe_engine = NpeEngine::new(NpeParameters {
+    ///     seeder: Box::new(UnixSeeder::new(UNSAFE_SECRET)),
+    ///     delta_log: DeltaLog(59),
+    ///     log2_failure_probability: -40.,
+    /// })?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: NpeFftFourierLweBootstrapKey64 =
+    ///     npe_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.variance(), noise);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_bootstrap_key(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<NpeFftFourierLweBootstrapKey64, LweBootstrapKeyGenerationError<Self::EngineError>>
+    {
+        FftEngine::check_supported_size(output_key.polynomial_size())
+            .map_err(|err| LweBootstrapKeyGenerationError::Engine(NpeError::Fft(err)))?;
+        LweBootstrapKeyGenerationError::perform_generic_checks(
+            decomposition_base_log,
+            decomposition_level_count,
+            64,
+        )?;
+        Ok(unsafe {
+            self.generate_new_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn generate_new_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> NpeFftFourierLweBootstrapKey64 {
+        let key: LweBootstrapKey64 = self
+            .default_engine
+            .generate_new_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            );
+        let key: FftFourierLweBootstrapKey64 =
+            self.fft_engine.convert_lwe_bootstrap_key_unchecked(&key);
+        NpeFftFourierLweBootstrapKey64(key, noise)
+    }
+}
@@ -0,0 +1,79 @@
+use crate::backends::default::entities::Cleartext64;
+use crate::backends::npe::implementation::engines::NpeEngine;
+use crate::backends::npe::implementation::entities::NpeLweCiphertext64;
+use crate::specification::engines::{
+    LweCiphertextCleartextFusingMultiplicationEngine,
+    LweCiphertextCleartextFusingMultiplicationError,
+};
+use concrete_npe::estimate_integer_plaintext_multiplication_noise;
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextFusingMultiplicationEngine`] for [`NpeEngine`] that
+/// operates on 64 bits integers. The variance of the noise of the output ciphertext is estimated
+/// with [`estimate_integer_plaintext_multiplication_noise`].
+impl LweCiphertextCleartextFusingMultiplicationEngine<NpeLweCiphertext64, Cleartext64>
+    for NpeEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DeltaLog, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let cleartext_input = 4_u64;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut npe_engine = NpeEngine::new(NpeParameters {
+    ///     seeder: Box::new(UnixSeeder::new(UNSAFE_SECRET)),
+    ///     delta_log: DeltaLog(50),
+    ///     log2_failure_probability: -40.,
+    /// })?;
+    /// let cleartext: Cleartext64 = default_engine.create_cleartext_from(&cleartext_input)?;
+    /// let key: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let mut ciphertext: NpeLweCiphertext64 =
+    ///     npe_engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// npe_engine.fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext.variance(), Variance(2_f64.powf(-46.)));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut NpeLweCiphertext64,
+        input: &Cleartext64,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        self.check_noise(estimate_integer_plaintext_multiplication_noise(
+            output.1,
+            (input.0).0,
+        ))
+        .map_err(LweCiphertextCleartextFusingMultiplicationError::Engine)?;
+        unsafe { self.fuse_mul_lwe_ciphertext_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut NpeLweCiphertext64,
+        input: &Cleartext64,
+    ) {
+        self.default_engine
+            .fuse_mul_lwe_ciphertext_cleartext_unchecked(&mut output.0, input);
+        output.1 = estimate_integer_plaintext_multiplication_noise(output.1, (input.0).0);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::backends::default::entities::{LweSecretKey64, Plaintext64};
+use crate::backends::npe::implementation::engines::NpeEngine;
+use crate::backends::npe::implementation::entities::NpeLweCiphertext64;
+use crate::specification::engines::{LweCiphertextDecryptionEngine, LweCiphertextDecryptionError};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`NpeEngine`] that operates on 64 bits
+/// integers. An error is returned if the estimated noise of the ciphertext is too large for the
+/// decryption to output the right message.
+impl LweCiphertextDecryptionEngine<LweSecretKey64, NpeLweCiphertext64, Plaintext64> for NpeEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DeltaLog, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut npe_engine = NpeEngine::new(NpeParameters {
+    ///     seeder: Box::new(UnixSeeder::new(UNSAFE_SECRET)),
+    ///     delta_log: DeltaLog(50),
+    ///     log2_failure_probability: -40.,
+    /// })?;
+    /// let key: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let ciphertext: NpeLweCiphertext64 =
+    ///     npe_engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = npe_engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// let output: u64 = default_engine.retrieve_plaintext(&decrypted_plaintext)?;
+    /// assert_eq!((output + (1 << 49)) >> 50, 3);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &NpeLweCiphertext64,
+    ) -> Result<Plaintext64, LweCiphertextDecryptionError<Self::EngineError>> {
+        self.check_noise(input.1)
+            .map_err(LweCiphertextDecryptionError::Engine)?;
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &NpeLweCiphertext64,
+    ) -> Plaintext64 {
+        self.default_engine
+            .decrypt_lwe_ciphertext_unchecked(key, &input.0)
+    }
+}
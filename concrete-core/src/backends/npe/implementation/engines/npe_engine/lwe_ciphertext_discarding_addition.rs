@@ -0,0 +1,82 @@
+use crate::backends::npe::implementation::engines::NpeEngine;
+use crate::backends::npe::implementation::entities::NpeLweCiphertext64;
+use crate::specification::engines::{
+    LweCiphertextDiscardingAdditionEngine, LweCiphertextDiscardingAdditionError,
+};
+use concrete_npe::estimate_addition_noise;
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingAdditionEngine`] for [`NpeEngine`] that operates on
+/// 64 bits integers. The variance of the noise of the output ciphertext is estimated with
+/// [`estimate_addition_noise`].
+impl LweCiphertextDiscardingAdditionEngine<NpeLweCiphertext64, NpeLweCiphertext64> for NpeEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DeltaLog, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = 3_u64 << 50;
+    /// let input_2 = 7_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut npe_engine = NpeEngine::new(NpeParameters {
+    ///     seeder: Box::new(UnixSeeder::new(UNSAFE_SECRET)),
+    ///     delta_log: DeltaLog(50),
+    ///     log2_failure_probability: -40.,
+    /// })?;
+    /// let key: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = default_engine.create_plaintext_from(&input_1)?;
+    /// let plaintext_2 = default_engine.create_plaintext_from(&input_2)?;
+    /// let ciphertext_1: NpeLweCiphertext64 =
+    ///     npe_engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let ciphertext_2: NpeLweCiphertext64 =
+    ///     npe_engine.encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    /// let mut ciphertext_3: NpeLweCiphertext64 =
+    ///     npe_engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// npe_engine.discard_add_lwe_ciphertext(&mut ciphertext_3, &ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_3.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext_3.variance(), Variance(2_f64.powf(-49.)));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_lwe_ciphertext(
+        &mut self,
+        output: &mut NpeLweCiphertext64,
+        input_1: &NpeLweCiphertext64,
+        input_2: &NpeLweCiphertext64,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        LweCiphertextDiscardingAdditionError::perform_generic_checks(output, input_1, input_2)?;
+        self.check_noise(estimate_addition_noise(input_1.1, input_2.1, 64))
+            .map_err(LweCiphertextDiscardingAdditionError::Engine)?;
+        unsafe { self.discard_add_lwe_ciphertext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut NpeLweCiphertext64,
+        input_1: &NpeLweCiphertext64,
+        input_2: &NpeLweCiphertext64,
+    ) {
+        self.default_engine.discard_add_lwe_ciphertext_unchecked(
+            &mut output.0,
+            &input_1.0,
+            &input_2.0,
+        );
+        output.1 = estimate_addition_noise(input_1.1, input_2.1, 64);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::backends::fft::engines::FftEngine;
+use crate::backends::npe::implementation::engines::{NpeEngine, NpeError};
+use crate::backends::npe::implementation::entities::{
+    NpeFftFourierLweBootstrapKey64, NpeGlweCiphertext64, NpeLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
+};
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_npe::{
+    estimate_addition_noise, estimate_modulus_switching_noise_with_binary_key, estimate_pbs_noise,
+};
+
+/// Estimates the variance of the noise of a bootstrapped ciphertext.
+fn estimate_bootstrap_noise(
+    acc: &NpeGlweCiphertext64,
+    bsk: &NpeFftFourierLweBootstrapKey64,
+) -> Variance {
+    let pbs_noise = estimate_pbs_noise::<_, BinaryKeyKind>(
+        bsk.input_lwe_dimension(),
+        bsk.polynomial_size(),
+        bsk.glwe_dimension(),
+        bsk.decomposition_base_log(),
+        bsk.decomposition_level_count(),
+        bsk.1,
+        64,
+    );
+    estimate_addition_noise(pbs_noise, acc.1, 64)
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`NpeEngine`] that operates
+/// on 64 bits integers. The bootstrap is executed by the fft backend.
+///
+/// The noise of the input ciphertext after the modulus switching is checked against the threshold
+/// of the engine, since a wrong message would be selected in the accumulator otherwise. The
+/// variance of the noise of the output ciphertext is estimated with [`estimate_pbs_noise`], and
+/// does not depend on the noise of the input ciphertext.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        NpeFftFourierLweBootstrapKey64,
+        NpeGlweCiphertext64,
+        NpeLweCiphertext64,
+        NpeLweCiphertext64,
+    > for NpeEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 59 bits)
+    /// let input = 3_u64 << 59;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 59; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut npe_engine = NpeEngine::new(NpeParameters {
+    ///     seeder: Box::new(UnixSeeder::new(UNSAFE_SECRET)),
+    ///     delta_log: DeltaLog(59),
+    ///     log2_failure_probability: -40.,
+    /// })?;
+    /// let lwe_sk: LweSecretKey64 = default_engine.generate_new_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 =
+    ///     default_engine.generate_new_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: NpeFftFourierLweBootstrapKey64 =
+    ///     npe_engine.generate_new_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// // The output of the bootstrap is encrypted under the GLWE key seen as an LWE key.
+    /// let lwe_sk_output: LweSecretKey64 =
+    ///     default_engine.transform_glwe_secret_key_to_lwe_secret_key(glwe_sk.clone())?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let plaintext_vector = default_engine.create_plaintext_vector_from(&lut)?;
+    /// let acc: NpeGlweCiphertext64 = npe_engine
+    ///     .trivially_encrypt_glwe_ciphertext(glwe_dim.to_glwe_size(), &plaintext_vector)?;
+    /// let input: NpeLweCiphertext64 =
+    ///     npe_engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output: NpeLweCiphertext64 =
+    ///     npe_engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// npe_engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert!(output.variance().0 > 0.);
+    /// let decrypted = npe_engine.decrypt_lwe_ciphertext(&lwe_sk_output, &output)?;
+    /// let raw = default_engine.retrieve_plaintext(&decrypted)?;
+    /// // Rounds the decrypted value to the nearest multiple of 2^59, to remove the noise.
+    /// let rounded = (raw >> 58).wrapping_add(1) >> 1;
+    /// assert_eq!(rounded % 32, 8);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut NpeLweCiphertext64,
+        input: &NpeLweCiphertext64,
+        acc: &NpeGlweCiphertext64,
+        bsk: &NpeFftFourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        FftEngine::check_supported_size(acc.polynomial_size())
+            .map_err(|err| LweCiphertextDiscardingBootstrapError::Engine(NpeError::Fft(err)))?;
+        LweCiphertextDiscardingBootstrapError::perform_generic_checks(output, input, acc, bsk)?;
+        let switched_modulus_log = acc.polynomial_size().log2().0 + 1;
+        self.check_noise(estimate_modulus_switching_noise_with_binary_key(
+            input.lwe_dimension(),
+            switched_modulus_log,
+            input.1,
+            64,
+        ))
+        .map_err(LweCiphertextDiscardingBootstrapError::Engine)?;
+        self.check_noise(estimate_bootstrap_noise(acc, bsk))
+            .map_err(LweCiphertextDiscardingBootstrapError::Engine)?;
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut NpeLweCiphertext64,
+        input: &NpeLweCiphertext64,
+        acc: &NpeGlweCiphertext64,
+        bsk: &NpeFftFourierLweBootstrapKey64,
+    ) {
+        self.fft_engine.discard_bootstrap_lwe_ciphertext_unchecked(
+            &mut output.0,
+            &input.0,
+            &acc.0,
+            &bsk.0,
+        );
+        output.1 = estimate_bootstrap_noise(acc, bsk);
+    }
+}
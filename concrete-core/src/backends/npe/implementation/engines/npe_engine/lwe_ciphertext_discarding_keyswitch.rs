@@ -0,0 +1,116 @@
+use crate::backends::npe::implementation::engines::NpeEngine;
+use crate::backends::npe::implementation::entities::{NpeLweCiphertext64, NpeLweKeyswitchKey64};
+use crate::specification::engines::{
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
+};
+use crate::specification::entities::LweKeyswitchKeyEntity;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_npe::estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms;
+
+/// Estimates the variance of the noise of a keyswitched ciphertext.
+fn estimate_keyswitch_noise(input: &NpeLweCiphertext64, ksk: &NpeLweKeyswitchKey64) -> Variance {
+    estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<_, _, BinaryKeyKind>(
+        ksk.input_lwe_dimension(),
+        input.1,
+        ksk.1,
+        ksk.decomposition_base_log(),
+        ksk.decomposition_level_count(),
+        64,
+    )
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`NpeEngine`] that operates
+/// on 64 bits integers. The variance of the noise of the output ciphertext is estimated with
+/// [`estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms`].
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        NpeLweKeyswitchKey64,
+        NpeLweCiphertext64,
+        NpeLweCiphertext64,
+    > for NpeEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(10);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// let ksk_noise = Variance(2_f64.powf(-100.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut npe_engine = NpeEngine::new(NpeParameters {
+    ///     seeder: Box::new(UnixSeeder::new(UNSAFE_SECRET)),
+    ///     delta_log: DeltaLog(50),
+    ///     log2_failure_probability: -40.,
+    /// })?;
+    /// let input_key: LweSecretKey64 =
+    ///     default_engine.generate_new_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 =
+    ///     default_engine.generate_new_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key: NpeLweKeyswitchKey64 = npe_engine.generate_new_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     ksk_noise,
+    /// )?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    /// let ciphertext_1: NpeLweCiphertext64 =
+    ///     npe_engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2: NpeLweCiphertext64 =
+    ///     npe_engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// npe_engine.discard_keyswitch_lwe_ciphertext(
+    ///     &mut ciphertext_2,
+    ///     &ciphertext_1,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    /// assert!(ciphertext_2.variance().0 > ciphertext_1.variance().0);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut NpeLweCiphertext64,
+        input: &NpeLweCiphertext64,
+        ksk: &NpeLweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        LweCiphertextDiscardingKeyswitchError::perform_generic_checks(output, input, ksk)?;
+        self.check_noise(estimate_keyswitch_noise(input, ksk))
+            .map_err(LweCiphertextDiscardingKeyswitchError::Engine)?;
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut NpeLweCiphertext64,
+        input: &NpeLweCiphertext64,
+        ksk: &NpeLweKeyswitchKey64,
+    ) {
+        self.default_engine
+            .discard_keyswitch_lwe_ciphertext_unchecked(&mut output.0, &input.0, &ksk.0);
+        output.1 = estimate_keyswitch_noise(input, ksk);
+    }
+}
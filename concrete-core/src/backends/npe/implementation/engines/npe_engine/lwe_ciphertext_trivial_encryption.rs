@@ -0,0 +1,67 @@
+use crate::backends::default::entities::Plaintext64;
+use crate::backends::npe::implementation::engines::NpeEngine;
+use crate::backends::npe::implementation::entities::NpeLweCiphertext64;
+use crate::specification::engines::{
+    LweCiphertextTrivialEncryptionEngine, LweCiphertextTrivialEncryptionError,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LweSize;
+
+/// # Description:
+/// Implementation of [`LweCiphertextTrivialEncryptionEngine`] for [`NpeEngine`] that operates on
+/// 64 bits integers. The output ciphertext has no noise.
+impl LweCiphertextTrivialEncryptionEngine<Plaintext64, NpeLweCiphertext64> for NpeEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{DeltaLog, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_size = LweSize(10);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut default_engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let mut npe_engine = NpeEngine::new(NpeParameters {
+    ///     seeder: Box::new(UnixSeeder::new(UNSAFE_SECRET)),
+    ///     delta_log: DeltaLog(50),
+    ///     log2_failure_probability: -40.,
+    /// })?;
+    /// let plaintext = default_engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext: NpeLweCiphertext64 =
+    ///     npe_engine.trivially_encrypt_lwe_ciphertext(lwe_size, &plaintext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension().to_lwe_size(), lwe_size);
+    /// assert_eq!(ciphertext.variance(), Variance(0.));
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_lwe_ciphertext(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext64,
+    ) -> Result<NpeLweCiphertext64, LweCiphertextTrivialEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.trivially_encrypt_lwe_ciphertext_unchecked(lwe_size, input) })
+    }
+
+    unsafe fn trivially_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        lwe_size: LweSize,
+        input: &Plaintext64,
+    ) -> NpeLweCiphertext64 {
+        NpeLweCiphertext64(
+            self.default_engine
+                .trivially_encrypt_lwe_ciphertext_unchecked(lwe_size, input),
+            Variance(0.),
+        )
+    }
+}
@@ -0,0 +1,125 @@
+use crate::backends::default::engines::{DefaultEngine, DefaultError};
+#[cfg(feature = "backend_fft")]
+use crate::backends::fft::engines::{FftEngine, FftError};
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::DeltaLog;
+use concrete_csprng::seeders::Seeder;
+use concrete_npe::estimate_log2_noise_overflow_probability;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The error which can occur in the execution of FHE operations by the [`NpeEngine`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum NpeError {
+    /// The estimated probability that a ciphertext decrypts to a wrong message exceeds the
+    /// threshold of the engine.
+    NoiseOverflow {
+        log2_failure_probability: f64,
+        log2_threshold: f64,
+    },
+    Default(DefaultError),
+    #[cfg(feature = "backend_fft")]
+    Fft(FftError),
+}
+
+impl Display for NpeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NpeError::NoiseOverflow {
+                log2_failure_probability,
+                log2_threshold,
+            } => write!(
+                f,
+                "The estimated probability of decrypting a wrong message (2^{:.2}) exceeds the \
+                threshold of the engine (2^{:.2}).",
+                log2_failure_probability, log2_threshold
+            ),
+            NpeError::Default(error) => error.fmt(f),
+            #[cfg(feature = "backend_fft")]
+            NpeError::Fft(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for NpeError {}
+
+/// The parameters needed to create an [`NpeEngine`].
+pub struct NpeParameters {
+    /// The seeder used to create the underlying [`DefaultEngine`].
+    pub seeder: Box<dyn Seeder>,
+    /// The logarithm in base 2 of the scaling factor applied to the messages. A decryption
+    /// outputs a wrong message when the noise is larger than half of this factor.
+    pub delta_log: DeltaLog,
+    /// The logarithm in base 2 of the largest probability of decrypting a wrong message that the
+    /// engine accepts for the ciphertexts it outputs.
+    pub log2_failure_probability: f64,
+}
+
+/// The main engine exposed by the npe backend.
+///
+/// The operations are executed by an underlying [`DefaultEngine`] (and an underlying
+/// `FftEngine` for the bootstrap), while the variance of the noise of the ciphertexts is estimated
+/// with the formulas of `concrete-npe`. The secret keys are expected to be binary.
+pub struct NpeEngine {
+    default_engine: DefaultEngine,
+    #[cfg(feature = "backend_fft")]
+    fft_engine: FftEngine,
+    delta_log: DeltaLog,
+    log2_failure_probability: f64,
+}
+
+impl NpeEngine {
+    /// Checks that a ciphertext whose noise has the given variance decrypts to the right message,
+    /// with a probability allowed by the threshold of the engine.
+    pub(crate) fn check_noise(&self, variance: Variance) -> Result<(), NpeError> {
+        let half_delta = 2_f64.powi(self.delta_log.0 as i32 - 65);
+        let log2_failure_probability =
+            estimate_log2_noise_overflow_probability(variance, half_delta);
+        if log2_failure_probability > self.log2_failure_probability {
+            return Err(NpeError::NoiseOverflow {
+                log2_failure_probability,
+                log2_threshold: self.log2_failure_probability,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl AbstractEngineSeal for NpeEngine {}
+
+impl AbstractEngine for NpeEngine {
+    type EngineError = NpeError;
+
+    type Parameters = NpeParameters;
+
+    fn new(parameters: Self::Parameters) -> Result<Self, Self::EngineError> {
+        Ok(NpeEngine {
+            default_engine: DefaultEngine::new(parameters.seeder).map_err(NpeError::Default)?,
+            #[cfg(feature = "backend_fft")]
+            fft_engine: FftEngine::new(()).map_err(NpeError::Fft)?,
+            delta_log: parameters.delta_log,
+            log2_failure_probability: parameters.log2_failure_probability,
+        })
+    }
+}
+
+mod glwe_ciphertext_decryption;
+mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_trivial_encryption;
+#[cfg(feature = "backend_fft")]
+mod lwe_bootstrap_key_generation;
+mod lwe_ciphertext_cleartext_discarding_multiplication;
+mod lwe_ciphertext_cleartext_fusing_multiplication;
+mod lwe_ciphertext_decryption;
+mod lwe_ciphertext_discarding_addition;
+#[cfg(feature = "backend_fft")]
+mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_encryption;
+mod lwe_ciphertext_fusing_addition;
+mod lwe_ciphertext_trivial_encryption;
+mod lwe_ciphertext_zero_encryption;
+mod lwe_keyswitch_key_generation;
@@ -0,0 +1,34 @@
+use crate::backends::default::entities::GlweCiphertext64;
+use crate::specification::entities::markers::GlweCiphertextKind;
+use crate::specification::entities::{AbstractEntity, GlweCiphertextEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A structure representing a GLWE ciphertext with 64 bits of precision, along with the estimated
+/// variance of the noise of its coefficients.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NpeGlweCiphertext64(pub(crate) GlweCiphertext64, pub(crate) Variance);
+impl AbstractEntity for NpeGlweCiphertext64 {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for NpeGlweCiphertext64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+impl NpeGlweCiphertext64 {
+    /// Returns the underlying GLWE ciphertext.
+    pub fn ciphertext(&self) -> &GlweCiphertext64 {
+        &self.0
+    }
+
+    /// Returns the estimated variance of the noise of the coefficients of the ciphertext.
+    pub fn variance(&self) -> Variance {
+        self.1
+    }
+}
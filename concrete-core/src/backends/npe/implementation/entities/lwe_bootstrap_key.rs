@@ -0,0 +1,51 @@
+use crate::backends::fft::entities::FftFourierLweBootstrapKey64;
+use crate::specification::entities::markers::LweBootstrapKeyKind;
+use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// A structure representing an LWE bootstrap key with 64 bits of precision in the Fourier domain,
+/// along with the variance of the noise used to encrypt it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NpeFftFourierLweBootstrapKey64(
+    pub(crate) FftFourierLweBootstrapKey64,
+    pub(crate) Variance,
+);
+impl AbstractEntity for NpeFftFourierLweBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for NpeFftFourierLweBootstrapKey64 {
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.input_lwe_dimension()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+}
+
+impl NpeFftFourierLweBootstrapKey64 {
+    /// Returns the underlying LWE bootstrap key.
+    pub fn key(&self) -> &FftFourierLweBootstrapKey64 {
+        &self.0
+    }
+
+    /// Returns the variance of the noise used to encrypt the key.
+    pub fn variance(&self) -> Variance {
+        self.1
+    }
+}
@@ -0,0 +1,30 @@
+use crate::backends::default::entities::LweCiphertext64;
+use crate::specification::entities::markers::LweCiphertextKind;
+use crate::specification::entities::{AbstractEntity, LweCiphertextEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LweDimension;
+
+/// A structure representing an LWE ciphertext with 64 bits of precision, along with the estimated
+/// variance of its noise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NpeLweCiphertext64(pub(crate) LweCiphertext64, pub(crate) Variance);
+impl AbstractEntity for NpeLweCiphertext64 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for NpeLweCiphertext64 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_dimension()
+    }
+}
+
+impl NpeLweCiphertext64 {
+    /// Returns the underlying LWE ciphertext.
+    pub fn ciphertext(&self) -> &LweCiphertext64 {
+        &self.0
+    }
+
+    /// Returns the estimated variance of the noise of the ciphertext.
+    pub fn variance(&self) -> Variance {
+        self.1
+    }
+}
@@ -0,0 +1,42 @@
+use crate::backends::default::entities::LweKeyswitchKey64;
+use crate::specification::entities::markers::LweKeyswitchKeyKind;
+use crate::specification::entities::{AbstractEntity, LweKeyswitchKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+/// A structure representing an LWE keyswitch key with 64 bits of precision, along with the
+/// variance of the noise used to encrypt it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NpeLweKeyswitchKey64(pub(crate) LweKeyswitchKey64, pub(crate) Variance);
+impl AbstractEntity for NpeLweKeyswitchKey64 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for NpeLweKeyswitchKey64 {
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.input_lwe_dimension()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.output_lwe_dimension()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+impl NpeLweKeyswitchKey64 {
+    /// Returns the underlying LWE keyswitch key.
+    pub fn key(&self) -> &LweKeyswitchKey64 {
+        &self.0
+    }
+
+    /// Returns the variance of the noise used to encrypt the key.
+    pub fn variance(&self) -> Variance {
+        self.1
+    }
+}
@@ -0,0 +1,14 @@
+//! A module containing all the [entities](crate::specification::entities) exposed by the npe
+//! backend.
+
+mod glwe_ciphertext;
+#[cfg(feature = "backend_fft")]
+mod lwe_bootstrap_key;
+mod lwe_ciphertext;
+mod lwe_keyswitch_key;
+
+pub use glwe_ciphertext::*;
+#[cfg(feature = "backend_fft")]
+pub use lwe_bootstrap_key::*;
+pub use lwe_ciphertext::*;
+pub use lwe_keyswitch_key::*;
@@ -0,0 +1,10 @@
+//! A backend tracking the noise of the ciphertexts, using the `concrete-npe` library.
+//!
+//! The entities of this backend pair the ciphertexts of the default backend with the estimated
+//! variance of their noise, which the [`NpeEngine`](engines::NpeEngine) updates after every
+//! operation. The engine returns an error as soon as the estimated probability of decrypting a
+//! ciphertext to a wrong message crosses a threshold chosen at its creation.
+
+mod implementation;
+
+pub use implementation::{engines, entities};
@@ -14,7 +14,7 @@ use crate::commons::math::tensor::{
 use crate::commons::math::torus::UnsignedTorus;
 use crate::commons::utils::{zip, zip_args};
 use concrete_commons::dispersion::DispersionParameter;
-use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::key_kinds::{BinaryKeyKind, KeyKind};
 use concrete_commons::numeric::Numeric;
 use concrete_commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
@@ -159,6 +159,9 @@ impl<Cont> StandardSeededBootstrapKey<Cont> {
     /// Generate a new seeded bootstrap key from the input parameters, and fills the current
     /// container with it.
     ///
+    /// The GLWE secret key can be of any kind, but the LWE secret key must be binary, since the
+    /// blind rotation uses each of its coefficients as a CMux selector.
+    ///
     /// # Example
     ///
     /// ```
@@ -189,23 +192,32 @@ impl<Cont> StandardSeededBootstrapKey<Cont> {
     /// );
     /// let lwe_sk = LweSecretKey::generate_binary(lwe_dim, &mut secret_generator);
     /// let glwe_sk = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
-    /// bsk.fill_with_new_key::<_, _, _, _, _, SoftwareRandomGenerator>(
+    /// bsk.fill_with_new_key::<_, _, _, _, _, _, SoftwareRandomGenerator>(
     ///     &lwe_sk,
     ///     &glwe_sk,
     ///     LogStandardDev::from_log_standard_dev(-15.),
     ///     &mut seeder,
     /// );
     /// ```
-    pub fn fill_with_new_key<LweCont, GlweCont, Scalar, NoiseParameters, NoiseSeeder, Gen>(
+    pub fn fill_with_new_key<
+        LweCont,
+        GlweCont,
+        GlweKeyKind,
+        Scalar,
+        NoiseParameters,
+        NoiseSeeder,
+        Gen,
+    >(
         &mut self,
         lwe_secret_key: &LweSecretKey<BinaryKeyKind, LweCont>,
-        glwe_secret_key: &GlweSecretKey<BinaryKeyKind, GlweCont>,
+        glwe_secret_key: &GlweSecretKey<GlweKeyKind, GlweCont>,
         noise_parameters: NoiseParameters,
         seeder: &mut NoiseSeeder,
     ) where
         Self: AsMutTensor<Element = Scalar>,
         LweSecretKey<BinaryKeyKind, LweCont>: AsRefTensor<Element = Scalar>,
-        GlweSecretKey<BinaryKeyKind, GlweCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<GlweKeyKind, GlweCont>: AsRefTensor<Element = Scalar>,
+        GlweKeyKind: KeyKind,
         Scalar: UnsignedTorus,
         NoiseParameters: DispersionParameter,
         NoiseSeeder: Seeder,
@@ -279,7 +291,7 @@ impl<Cont> StandardSeededBootstrapKey<Cont> {
     /// );
     /// let lwe_sk = LweSecretKey::generate_binary(lwe_dim, &mut secret_generator);
     /// let glwe_sk = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
-    /// bsk.par_fill_with_new_key::<_, _, _, _, _, SoftwareRandomGenerator>(
+    /// bsk.par_fill_with_new_key::<_, _, _, _, _, _, SoftwareRandomGenerator>(
     ///     &lwe_sk,
     ///     &glwe_sk,
     ///     LogStandardDev::from_log_standard_dev(-15.),
@@ -287,16 +299,25 @@ impl<Cont> StandardSeededBootstrapKey<Cont> {
     /// );
     /// ```
     #[cfg(feature = "__commons_parallel")]
-    pub fn par_fill_with_new_key<LweCont, GlweCont, Scalar, NoiseParameters, NoiseSeeder, Gen>(
+    pub fn par_fill_with_new_key<
+        LweCont,
+        GlweCont,
+        GlweKeyKind,
+        Scalar,
+        NoiseParameters,
+        NoiseSeeder,
+        Gen,
+    >(
         &mut self,
         lwe_secret_key: &LweSecretKey<BinaryKeyKind, LweCont>,
-        glwe_secret_key: &GlweSecretKey<BinaryKeyKind, GlweCont>,
+        glwe_secret_key: &GlweSecretKey<GlweKeyKind, GlweCont>,
         noise_parameters: NoiseParameters,
         seeder: &mut NoiseSeeder,
     ) where
         Self: AsMutTensor<Element = Scalar>,
         LweSecretKey<BinaryKeyKind, LweCont>: AsRefTensor<Element = Scalar>,
-        GlweSecretKey<BinaryKeyKind, GlweCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<GlweKeyKind, GlweCont>: AsRefTensor<Element = Scalar>,
+        GlweKeyKind: KeyKind,
         Scalar: UnsignedTorus + Sync + Send,
         GlweCont: Sync + Send,
         Cont: Sync + Send,
@@ -684,7 +705,7 @@ impl<Cont> StandardSeededBootstrapKey<Cont> {
     /// );
     /// let lwe_sk = LweSecretKey::generate_binary(lwe_dim, &mut secret_generator);
     /// let glwe_sk = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
-    /// seeded_bsk.fill_with_new_key::<_, _, _, _, _, SoftwareRandomGenerator>(
+    /// seeded_bsk.fill_with_new_key::<_, _, _, _, _, _, SoftwareRandomGenerator>(
     ///     &lwe_sk,
     ///     &glwe_sk,
     ///     LogStandardDev::from_log_standard_dev(-15.),
@@ -12,7 +12,7 @@ use crate::commons::math::tensor::{
 use crate::commons::math::torus::UnsignedTorus;
 use crate::commons::utils::{zip, zip_args};
 use concrete_commons::dispersion::DispersionParameter;
-use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::key_kinds::{BinaryKeyKind, KeyKind};
 use concrete_commons::numeric::Numeric;
 use concrete_commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
@@ -141,6 +141,9 @@ impl<Cont> StandardBootstrapKey<Cont> {
     /// Generate a new bootstrap key from the input parameters, and fills the current container
     /// with it.
     ///
+    /// The GLWE secret key can be of any kind, but the LWE secret key must be binary, since the
+    /// blind rotation uses each of its coefficients as a CMux selector.
+    ///
     /// # Example
     ///
     /// ```
@@ -178,16 +181,17 @@ impl<Cont> StandardBootstrapKey<Cont> {
     ///     &mut encryption_generator,
     /// );
     /// ```
-    pub fn fill_with_new_key<LweCont, RlweCont, Scalar, Gen>(
+    pub fn fill_with_new_key<LweCont, RlweCont, GlweKeyKind, Scalar, Gen>(
         &mut self,
         lwe_secret_key: &LweSecretKey<BinaryKeyKind, LweCont>,
-        glwe_secret_key: &GlweSecretKey<BinaryKeyKind, RlweCont>,
+        glwe_secret_key: &GlweSecretKey<GlweKeyKind, RlweCont>,
         noise_parameters: impl DispersionParameter,
         generator: &mut EncryptionRandomGenerator<Gen>,
     ) where
         Self: AsMutTensor<Element = Scalar>,
         LweSecretKey<BinaryKeyKind, LweCont>: AsRefTensor<Element = Scalar>,
-        GlweSecretKey<BinaryKeyKind, RlweCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<GlweKeyKind, RlweCont>: AsRefTensor<Element = Scalar>,
+        GlweKeyKind: KeyKind,
         Scalar: UnsignedTorus,
         Gen: ByteRandomGenerator,
     {
@@ -267,16 +271,17 @@ impl<Cont> StandardBootstrapKey<Cont> {
     /// );
     /// ```
     #[cfg(feature = "__commons_parallel")]
-    pub fn par_fill_with_new_key<LweCont, RlweCont, Scalar, Gen>(
+    pub fn par_fill_with_new_key<LweCont, RlweCont, GlweKeyKind, Scalar, Gen>(
         &mut self,
         lwe_secret_key: &LweSecretKey<BinaryKeyKind, LweCont>,
-        glwe_secret_key: &GlweSecretKey<BinaryKeyKind, RlweCont>,
+        glwe_secret_key: &GlweSecretKey<GlweKeyKind, RlweCont>,
         noise_parameters: impl DispersionParameter + Sync + Send,
         generator: &mut EncryptionRandomGenerator<Gen>,
     ) where
         Self: AsMutTensor<Element = Scalar>,
         LweSecretKey<BinaryKeyKind, LweCont>: AsRefTensor<Element = Scalar>,
-        GlweSecretKey<BinaryKeyKind, RlweCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<GlweKeyKind, RlweCont>: AsRefTensor<Element = Scalar>,
+        GlweKeyKind: KeyKind,
         Scalar: UnsignedTorus + Sync + Send,
         RlweCont: Sync,
         Gen: ParallelByteRandomGenerator,
@@ -346,16 +351,17 @@ impl<Cont> StandardBootstrapKey<Cont> {
     ///     &mut encryption_generator,
     /// );
     /// ```
-    pub fn fill_with_new_trivial_key<LweCont, RlweCont, Scalar, Gen>(
+    pub fn fill_with_new_trivial_key<LweCont, RlweCont, GlweKeyKind, Scalar, Gen>(
         &mut self,
         lwe_secret_key: &LweSecretKey<BinaryKeyKind, LweCont>,
-        rlwe_secret_key: &GlweSecretKey<BinaryKeyKind, RlweCont>,
+        rlwe_secret_key: &GlweSecretKey<GlweKeyKind, RlweCont>,
         noise_parameters: impl DispersionParameter,
         generator: &mut EncryptionRandomGenerator<Gen>,
     ) where
         Self: AsMutTensor<Element = Scalar>,
         LweSecretKey<BinaryKeyKind, LweCont>: AsRefTensor<Element = Scalar>,
-        GlweSecretKey<BinaryKeyKind, RlweCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<GlweKeyKind, RlweCont>: AsRefTensor<Element = Scalar>,
+        GlweKeyKind: KeyKind,
         Scalar: UnsignedTorus,
         Gen: ByteRandomGenerator,
     {
@@ -57,7 +57,7 @@ fn test_bsk_seeded_gen_equivalence<T: UnsignedTorus + Send + Sync>() {
             compression_seed,
         );
 
-        seeded_bsk.fill_with_new_key::<_, _, _, _, _, SoftwareRandomGenerator>(
+        seeded_bsk.fill_with_new_key::<_, _, _, _, _, _, SoftwareRandomGenerator>(
             &lwe_sk,
             &glwe_sk,
             StandardDev::from_standard_dev(10.),
@@ -224,7 +224,7 @@ mod parallel {
                 compression_seed,
             );
 
-            par_seeded_bsk.par_fill_with_new_key::<_, _, _, _, _, SoftwareRandomGenerator>(
+            par_seeded_bsk.par_fill_with_new_key::<_, _, _, _, _, _, SoftwareRandomGenerator>(
                 &lwe_sk,
                 &glwe_sk,
                 StandardDev::from_standard_dev(10.),
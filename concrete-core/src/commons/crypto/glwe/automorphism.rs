@@ -0,0 +1,740 @@
+use super::{GlweCiphertext, GlweKeyswitchKey};
+use crate::commons::crypto::lwe::{LweCiphertext, LweList};
+use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::commons::math::tensor::{
+    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::KeyKind;
+use concrete_commons::parameters::{
+    AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize,
+    MonomialDegree, PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A GLWE automorphism key.
+///
+/// For an odd exponent $k$, the ring automorphism $\tau\_k: X \mapsto X^k$ maps a GLWE
+/// ciphertext encrypted under a GLWE secret key $\vec{S}$ to a GLWE ciphertext encrypted under
+/// $\tau\_k(\vec{S})$. An automorphism key contains, for every exponent $k$ it supports, a
+/// [`GlweKeyswitchKey`] switching from $\tau\_k(\vec{S})$ back to $\vec{S}$.
+///
+/// The keys for the exponents $2^i + 1$, for $1 \leq i \leq \log\_2(N)$, allow to evaluate the
+/// trace of a GLWE ciphertext, and to pack LWE ciphertexts into a GLWE ciphertext.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweAutomorphismKey<Cont> {
+    tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    exponents: Vec<AutomorphismExponent>,
+}
+
+tensor_traits!(GlweAutomorphismKey);
+
+impl<Scalar> GlweAutomorphismKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates an automorphism key for the given `exponents`, whose masks and bodies are all
+    /// `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate an automorphism key, but merely allocates a container
+    /// of the right size. See [`GlweAutomorphismKey::fill_with_automorphism_key`] to fill the
+    /// container with a proper automorphism key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweAutomorphismKey;
+    /// let key = GlweAutomorphismKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    ///     &[AutomorphismExponent(3), AutomorphismExponent(5)],
+    /// );
+    /// assert_eq!(key.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(key.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(key.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(key.decomposition_base_log(), DecompositionBaseLog(7));
+    /// assert_eq!(
+    ///     key.automorphism_exponents(),
+    ///     &[AutomorphismExponent(3), AutomorphismExponent(5)]
+    /// );
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        poly_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+        decomp_level_count: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        exponents: &[AutomorphismExponent],
+    ) -> Self {
+        let glwe_size = glwe_dimension.to_glwe_size();
+        GlweAutomorphismKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                exponents.len()
+                    * glwe_dimension.0
+                    * decomp_level_count.0
+                    * glwe_size.0
+                    * poly_size.0
+            ]),
+            poly_size,
+            glwe_size,
+            decomp_base_log,
+            decomp_level_count,
+            exponents: exponents.to_vec(),
+        }
+    }
+}
+
+impl<Cont> GlweAutomorphismKey<Cont> {
+    /// Creates an automorphism key from a container.
+    ///
+    /// # Notes
+    ///
+    /// This method does not create an automorphism key, but merely wraps the container in the
+    /// proper type. It assumes that either the container already contains a proper automorphism
+    /// key, or that [`GlweAutomorphismKey::fill_with_automorphism_key`] will be called right
+    /// after.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweAutomorphismKey;
+    /// let key = GlweAutomorphismKey::from_container(
+    ///     vec![0 as u32; 2 * 2 * 3 * 3 * 256],
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2).to_glwe_size(),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    ///     &[AutomorphismExponent(3), AutomorphismExponent(5)],
+    /// );
+    /// assert_eq!(key.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(key.glwe_dimension(), GlweDimension(2));
+    /// ```
+    pub fn from_container<Coef>(
+        cont: Cont,
+        poly_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomp_level_count: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        exponents: &[AutomorphismExponent],
+    ) -> Self
+    where
+        Cont: AsRefSlice<Element = Coef>,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_eq!(
+            tensor.len() =>
+            exponents.len()
+                * (glwe_size.0 - 1)
+                * decomp_level_count.0
+                * glwe_size.0
+                * poly_size.0
+        );
+        GlweAutomorphismKey {
+            tensor,
+            poly_size,
+            glwe_size,
+            decomp_base_log,
+            decomp_level_count,
+            exponents: exponents.to_vec(),
+        }
+    }
+
+    /// Returns the size of the polynomials used in the key.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the GLWE dimension of the key.
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the number of levels used to decompose the ciphertext masks.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used to decompose the ciphertext masks.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the exponents of the automorphisms supported by the key.
+    pub fn automorphism_exponents(&self) -> &[AutomorphismExponent] {
+        &self.exponents
+    }
+
+    /// Returns whether the key supports the automorphism $X \mapsto X^k$, where $k$ is the
+    /// `exponent`.
+    ///
+    /// Since $X^{2N} = 1$, exponents are compared modulo $2N$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweAutomorphismKey;
+    /// let key = GlweAutomorphismKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(16),
+    ///     GlweDimension(1),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    ///     &[AutomorphismExponent(3)],
+    /// );
+    /// assert!(key.supports_automorphism(AutomorphismExponent(3)));
+    /// assert!(key.supports_automorphism(AutomorphismExponent(35)));
+    /// assert!(!key.supports_automorphism(AutomorphismExponent(5)));
+    /// ```
+    pub fn supports_automorphism(&self, exponent: AutomorphismExponent) -> bool {
+        self.exponent_position(exponent).is_some()
+    }
+
+    fn exponent_position(&self, exponent: AutomorphismExponent) -> Option<usize> {
+        let modulus = 2 * self.poly_size.0;
+        self.exponents
+            .iter()
+            .position(|e| e.0 % modulus == exponent.0 % modulus)
+    }
+
+    fn keyswitch_key_len(&self) -> usize {
+        (self.glwe_size.0 - 1) * self.decomp_level_count.0 * self.glwe_size.0 * self.poly_size.0
+    }
+
+    /// Returns the keyswitch key associated with the automorphism of the given `exponent`.
+    fn keyswitch_key<Scalar>(&self, exponent: AutomorphismExponent) -> GlweKeyswitchKey<&[Scalar]>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+    {
+        let position = self
+            .exponent_position(exponent)
+            .expect("The automorphism key does not support the requested exponent.");
+        let len = self.keyswitch_key_len();
+        GlweKeyswitchKey::from_container(
+            &self.as_tensor().as_slice()[position * len..(position + 1) * len],
+            self.poly_size,
+            self.glwe_size,
+            self.decomp_level_count,
+            self.decomp_base_log,
+        )
+    }
+
+    /// Fills the current container with an actual automorphism key constructed from a GLWE
+    /// secret key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweAutomorphismKey;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let secret_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    ///
+    /// let mut key = GlweAutomorphismKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    ///     &[AutomorphismExponent(3), AutomorphismExponent(5)],
+    /// );
+    /// key.fill_with_automorphism_key(
+    ///     &secret_key,
+    ///     LogStandardDev::from_log_standard_dev(-15.),
+    ///     &mut encryption_generator,
+    /// );
+    /// assert!(!key.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_automorphism_key<Kind, KeyCont, Scalar, Gen>(
+        &mut self,
+        secret_key: &GlweSecretKey<Kind, KeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<Kind, KeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<Kind, Vec<Scalar>>: AsRefTensor<Element = Scalar>,
+        Kind: KeyKind,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(secret_key.key_size().0 => self.glwe_dimension().0);
+        ck_dim_eq!(secret_key.polynomial_size().0 => self.poly_size.0);
+
+        let exponents = self.exponents.clone();
+        let len = self.keyswitch_key_len();
+        let poly_size = self.poly_size;
+        let glwe_size = self.glwe_size;
+        let level_count = self.decomp_level_count;
+        let base_log = self.decomp_base_log;
+        for (exponent, mut ksk_tensor) in exponents
+            .iter()
+            .zip(self.as_mut_tensor().subtensor_iter_mut(len))
+        {
+            let image = secret_key.automorphism_image(*exponent);
+            GlweKeyswitchKey::from_container(
+                ksk_tensor.as_mut_slice(),
+                poly_size,
+                glwe_size,
+                level_count,
+                base_log,
+            )
+            .fill_with_keyswitch_key(&image, secret_key, noise_parameters, generator);
+        }
+    }
+
+    /// Applies the automorphism $X \mapsto X^k$, where $k$ is the `exponent`, to the `input`
+    /// ciphertext, and writes the result in the `output` ciphertext.
+    ///
+    /// The output ciphertext is encrypted under the same key as the input ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key does not support the automorphism.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::{GlweAutomorphismKey, GlweCiphertext};
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefTensor};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let poly_size = PolynomialSize(256);
+    /// let glwe_dim = GlweDimension(1);
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    /// let secret_key = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    ///
+    /// let mut key = GlweAutomorphismKey::allocate(
+    ///     0u64,
+    ///     poly_size,
+    ///     glwe_dim,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(10),
+    ///     &[AutomorphismExponent(3)],
+    /// );
+    /// key.fill_with_automorphism_key(&secret_key, noise, &mut encryption_generator);
+    ///
+    /// // We encrypt the polynomial X
+    /// let mut input = GlweCiphertext::allocate(0u64, poly_size, glwe_dim.to_glwe_size());
+    /// let mut messages = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+    /// *messages.as_mut_tensor().get_element_mut(1) = 5 << 50;
+    /// secret_key.encrypt_glwe(&mut input, &messages, noise, &mut encryption_generator);
+    ///
+    /// let mut output = GlweCiphertext::allocate(0u64, poly_size, glwe_dim.to_glwe_size());
+    /// key.automorphism_ciphertext(&mut output, &input, AutomorphismExponent(3));
+    ///
+    /// // The output encrypts X^3
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+    /// secret_key.decrypt_glwe(&mut decrypted, &output);
+    /// let decoded: Vec<u64> = decrypted
+    ///     .as_tensor()
+    ///     .iter()
+    ///     .map(|d| d.wrapping_add(1 << 49) >> 50)
+    ///     .collect();
+    /// assert_eq!(decoded[3], 5);
+    /// assert_eq!(decoded.iter().filter(|d| **d != 0).count(), 1);
+    /// ```
+    pub fn automorphism_ciphertext<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        input: &GlweCiphertext<InCont>,
+        exponent: AutomorphismExponent,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.poly_size.0 => input.polynomial_size().0, output.polynomial_size().0);
+        ck_dim_eq!(self.glwe_size.0 => input.size().0, output.size().0);
+
+        // We map every polynomial of the ciphertext with the automorphism, which gives a
+        // ciphertext encrypted under the image of the secret key ...
+        let mut image = GlweCiphertext::allocate(Scalar::ZERO, self.poly_size, self.glwe_size);
+        for (mut image_poly, input_poly) in image
+            .as_mut_polynomial_list()
+            .polynomial_iter_mut()
+            .zip(input.as_polynomial_list().polynomial_iter())
+        {
+            image_poly.fill_with_automorphism(&input_poly, exponent);
+        }
+
+        // ... which we keyswitch back to the original secret key.
+        self.keyswitch_key(exponent)
+            .keyswitch_ciphertext(output, &image);
+    }
+
+    /// Computes the trace of the `input` ciphertext, and writes the result in the `output`
+    /// ciphertext.
+    ///
+    /// The trace of a polynomial $M$ is $\sum\_{k} \tau\_k(M)$, where $k$ ranges over the odd
+    /// integers smaller than $2N$. It is equal to $N \cdot m\_0$, where $m\_0$ is the constant
+    /// coefficient of $M$: the output ciphertext encrypts the constant coefficient of the input
+    /// plaintext multiplied by $N$, and zero for the other coefficients.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key does not support the automorphisms of exponents $2^i + 1$, for
+    /// $1 \leq i \leq \log\_2(N)$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::{GlweAutomorphismKey, GlweCiphertext};
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefTensor};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let poly_size = PolynomialSize(256);
+    /// let glwe_dim = GlweDimension(1);
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    /// let secret_key = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    ///
+    /// let exponents: Vec<_> = (1..=8).map(|i| AutomorphismExponent((1 << i) + 1)).collect();
+    /// let mut key = GlweAutomorphismKey::allocate(
+    ///     0u64,
+    ///     poly_size,
+    ///     glwe_dim,
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(10),
+    ///     &exponents,
+    /// );
+    /// key.fill_with_automorphism_key(&secret_key, noise, &mut encryption_generator);
+    ///
+    /// let mut input = GlweCiphertext::allocate(0u64, poly_size, glwe_dim.to_glwe_size());
+    /// let mut messages = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+    /// for (i, message) in messages.as_mut_tensor().iter_mut().enumerate() {
+    ///     *message = ((i as u64) % 16) << 52;
+    /// }
+    /// *messages.as_mut_tensor().first_mut() = 3 << 52;
+    /// secret_key.encrypt_glwe(&mut input, &messages, noise, &mut encryption_generator);
+    ///
+    /// let mut output = GlweCiphertext::allocate(0u64, poly_size, glwe_dim.to_glwe_size());
+    /// key.trace_ciphertext(&mut output, &input);
+    ///
+    /// // The output encrypts 256 * 3 in its constant coefficient, and zero elsewhere
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+    /// secret_key.decrypt_glwe(&mut decrypted, &output);
+    /// let decoded: Vec<u64> = decrypted
+    ///     .as_tensor()
+    ///     .iter()
+    ///     .map(|d| d.wrapping_add(1 << 59) >> 60)
+    ///     .collect();
+    /// assert_eq!(decoded[0], 3);
+    /// assert!(decoded[1..].iter().all(|d| *d == 0));
+    /// ```
+    pub fn trace_ciphertext<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        input: &GlweCiphertext<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.poly_size.0 => input.polynomial_size().0, output.polynomial_size().0);
+        ck_dim_eq!(self.glwe_size.0 => input.size().0, output.size().0);
+
+        let mut trace = GlweCiphertext::allocate(Scalar::ZERO, self.poly_size, self.glwe_size);
+        trace.as_mut_tensor().fill_with_copy(input.as_tensor());
+        self.update_with_partial_trace(&mut trace, 0);
+        output.as_mut_tensor().fill_with_copy(trace.as_tensor());
+    }
+
+    /// Packs the ciphertexts of the `input` LWE list into the `output` GLWE ciphertext.
+    ///
+    /// The input ciphertexts must be encrypted under the LWE secret key obtained by flattening
+    /// the GLWE secret key of the automorphism key, which is the case of ciphertexts sample
+    /// extracted from GLWE ciphertexts encrypted under this key. If the list contains $n \leq N$
+    /// ciphertexts, and $2^l$ is the smallest power of two greater or equal to $n$, the message
+    /// of the $j$-th input ciphertext is encrypted in the coefficient of degree $j \cdot N / 2^l$
+    /// of the output ciphertext, and the other coefficients encrypt zero.
+    ///
+    /// The packing follows [CDKS21](https://eprint.iacr.org/2020/015): the input ciphertexts are
+    /// recursively merged with one automorphism each, and the result is cleaned with a partial
+    /// trace. Since both steps multiply the messages by $N$, the input ciphertexts are first
+    /// divided by $N$, which adds a small rounding noise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key does not support the automorphisms of exponents $2^i + 1$, for
+    /// $1 \leq i \leq \log\_2(N)$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     AutomorphismExponent, CiphertextCount, DecompositionBaseLog, DecompositionLevelCount,
+    ///     GlweDimension, LweDimension, PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::{GlweAutomorphismKey, GlweCiphertext};
+    /// use concrete_core::commons::crypto::lwe::LweList;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let poly_size = PolynomialSize(256);
+    /// let glwe_dim = GlweDimension(1);
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    /// let secret_key = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    ///
+    /// let exponents: Vec<_> = (1..=8).map(|i| AutomorphismExponent((1 << i) + 1)).collect();
+    /// let mut key = GlweAutomorphismKey::allocate(
+    ///     0u64,
+    ///     poly_size,
+    ///     glwe_dim,
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(10),
+    ///     &exponents,
+    /// );
+    /// key.fill_with_automorphism_key(&secret_key, noise, &mut encryption_generator);
+    ///
+    /// // We encrypt 3 messages under the flattened GLWE secret key
+    /// let lwe_key = secret_key.clone().into_lwe_secret_key();
+    /// let messages = PlaintextList::from_container(vec![1_u64 << 60, 2 << 60, 3 << 60]);
+    /// let mut input = LweList::allocate(
+    ///     0u64,
+    ///     LweDimension(poly_size.0).to_lwe_size(),
+    ///     CiphertextCount(3),
+    /// );
+    /// lwe_key.encrypt_lwe_list(&mut input, &messages, noise, &mut encryption_generator);
+    ///
+    /// let mut output = GlweCiphertext::allocate(0u64, poly_size, glwe_dim.to_glwe_size());
+    /// key.pack_lwe_list(&mut output, &input);
+    ///
+    /// // The messages are spread every 256 / 4 coefficients
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+    /// secret_key.decrypt_glwe(&mut decrypted, &output);
+    /// let decoded: Vec<u64> = decrypted
+    ///     .as_tensor()
+    ///     .iter()
+    ///     .map(|d| d.wrapping_add(1 << 59) >> 60)
+    ///     .collect();
+    /// assert_eq!(decoded[0], 1);
+    /// assert_eq!(decoded[64], 2);
+    /// assert_eq!(decoded[128], 3);
+    /// assert_eq!(decoded.iter().filter(|d| **d != 0).count(), 3);
+    /// ```
+    pub fn pack_lwe_list<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        input: &LweList<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweList<InCont>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.poly_size.0 => output.polynomial_size().0);
+        ck_dim_eq!(self.glwe_size.0 => output.size().0);
+        ck_dim_eq!(input.mask_size().0 => self.glwe_dimension().0 * self.poly_size.0);
+        debug_assert!(
+            input.count().0 <= self.poly_size.0,
+            "The number of packed ciphertexts must not exceed the polynomial size."
+        );
+
+        let packed_count = input.count().0.next_power_of_two();
+        let scale_log = self.poly_size.log2().0;
+        let mut ciphertexts: Vec<_> = input
+            .ciphertext_iter()
+            .map(|lwe| self.scaled_glwe_from_lwe(&lwe, scale_log))
+            .collect();
+        ciphertexts.resize(
+            packed_count,
+            GlweCiphertext::allocate(Scalar::ZERO, self.poly_size, self.glwe_size),
+        );
+
+        let mut packed = self.pack_recursively(ciphertexts);
+        self.update_with_partial_trace(&mut packed, packed_count.trailing_zeros() as usize);
+        output.as_mut_tensor().fill_with_copy(packed.as_tensor());
+    }
+
+    /// Applies the last steps of the trace to the `ciphertext`, i.e. adds to the ciphertext its
+    /// image by the automorphisms of exponents $2^i + 1$, for $i$ going from $\log\_2(N)$ down to
+    /// `stop_log + 1`.
+    fn update_with_partial_trace<Scalar>(
+        &self,
+        ciphertext: &mut GlweCiphertext<Vec<Scalar>>,
+        stop_log: usize,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let mut buffer = GlweCiphertext::allocate(Scalar::ZERO, self.poly_size, self.glwe_size);
+        for log in (stop_log + 1..=self.poly_size.log2().0).rev() {
+            self.automorphism_ciphertext(
+                &mut buffer,
+                ciphertext,
+                AutomorphismExponent((1 << log) + 1),
+            );
+            ciphertext
+                .as_mut_tensor()
+                .update_with_wrapping_add(buffer.as_tensor());
+        }
+    }
+
+    /// Merges $2^l$ GLWE ciphertexts whose messages are in the constant coefficient into one
+    /// GLWE ciphertext encrypting the $j$-th message multiplied by $2^l$ in the coefficient of
+    /// degree $j \cdot N / 2^l$.
+    fn pack_recursively<Scalar>(
+        &self,
+        mut ciphertexts: Vec<GlweCiphertext<Vec<Scalar>>>,
+    ) -> GlweCiphertext<Vec<Scalar>>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let count = ciphertexts.len();
+        if count == 1 {
+            return ciphertexts.pop().unwrap();
+        }
+
+        let mut evens = Vec::with_capacity(count / 2);
+        let mut odds = Vec::with_capacity(count / 2);
+        for (index, ciphertext) in ciphertexts.into_iter().enumerate() {
+            if index % 2 == 0 {
+                evens.push(ciphertext);
+            } else {
+                odds.push(ciphertext);
+            }
+        }
+        let mut even = self.pack_recursively(evens);
+        let mut odd = self.pack_recursively(odds);
+
+        // We compute even + X^(N / count) * odd + tau(even - X^(N / count) * odd), with tau the
+        // automorphism of exponent count + 1, which maps X^(N / count) to -X^(N / count).
+        for mut poly in odd.as_mut_polynomial_list().polynomial_iter_mut() {
+            poly.update_with_wrapping_monic_monomial_mul(MonomialDegree(self.poly_size.0 / count));
+        }
+        let mut difference = even.clone();
+        difference
+            .as_mut_tensor()
+            .update_with_wrapping_sub(odd.as_tensor());
+        even.as_mut_tensor()
+            .update_with_wrapping_add(odd.as_tensor());
+        self.automorphism_ciphertext(&mut odd, &difference, AutomorphismExponent(count + 1));
+        even.as_mut_tensor()
+            .update_with_wrapping_add(odd.as_tensor());
+        even
+    }
+
+    /// Converts an LWE ciphertext encrypted under the flattened GLWE secret key to a GLWE
+    /// ciphertext encrypting the same message in its constant coefficient, after having divided
+    /// its coefficients by $2^{\mathsf{scale\\_log}}$.
+    fn scaled_glwe_from_lwe<Scalar>(
+        &self,
+        lwe: &LweCiphertext<&[Scalar]>,
+        scale_log: usize,
+    ) -> GlweCiphertext<Vec<Scalar>>
+    where
+        Scalar: UnsignedTorus,
+    {
+        let scale = |coefficient: Scalar| {
+            if scale_log == 0 {
+                coefficient
+            } else {
+                coefficient.wrapping_add(Scalar::ONE << (scale_log - 1)) >> scale_log
+            }
+        };
+        let poly_size = self.poly_size.0;
+        let mut glwe = GlweCiphertext::allocate(Scalar::ZERO, self.poly_size, self.glwe_size);
+        let (mut body, mut mask) = glwe.get_mut_body_and_mask();
+        // The constant coefficient of A * S is a_0 * s_0 - sum_{i > 0} a_{N - i} * s_i, so we
+        // reverse and negate the LWE mask coefficients.
+        for (mut glwe_poly, lwe_poly) in mask
+            .as_mut_polynomial_list()
+            .polynomial_iter_mut()
+            .zip(lwe.get_mask().as_tensor().subtensor_iter(poly_size))
+        {
+            let glwe_coefficients = glwe_poly.as_mut_tensor().as_mut_slice();
+            let lwe_coefficients = lwe_poly.as_slice();
+            glwe_coefficients[0] = scale(lwe_coefficients[0]);
+            for i in 1..poly_size {
+                glwe_coefficients[poly_size - i] = scale(lwe_coefficients[i]).wrapping_neg();
+            }
+        }
+        *body.as_mut_tensor().first_mut() = scale(lwe.get_body().0);
+        glwe
+    }
+}
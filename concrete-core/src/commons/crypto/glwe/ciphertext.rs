@@ -3,11 +3,13 @@ use crate::commons::crypto::encoding::{Plaintext, PlaintextList};
 use crate::commons::crypto::lwe::LweCiphertext;
 use crate::commons::math::polynomial::PolynomialList;
 use crate::commons::math::tensor::{
-    tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
 };
 use crate::commons::math::torus::UnsignedTorus;
-use concrete_commons::numeric::Numeric;
-use concrete_commons::parameters::{GlweDimension, GlweSize, MonomialDegree, PolynomialSize};
+use concrete_commons::numeric::{CastFrom, CastInto, Numeric};
+use concrete_commons::parameters::{
+    DeltaLog, GlweDimension, GlweSize, MonomialDegree, PolynomialSize,
+};
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
 
@@ -507,4 +509,158 @@ impl<Cont> GlweCiphertext<Cont> {
                 },
             );
     }
+
+    /// Fills the current ciphertext with the tensor product of two GLWE ciphertexts, rescaled by
+    /// $2^{-\mathsf{delta\\_log}}$.
+    ///
+    /// The input ciphertexts must share the same GLWE dimension $k$ and polynomial size. The
+    /// output has dimension $k(k+3)/2$: its $k$ first mask polynomials are associated with the
+    /// key polynomials $S\_i$, and the following ones with the products $S\_i S\_j$ for $i\leq j$
+    /// (in lexicographic order). The coefficients are lifted to signed integers, and the
+    /// products are computed exactly before being rounded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DeltaLog, GlweDimension, GlweSize, PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsMutTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let poly_size = PolynomialSize(256);
+    /// let glwe_dim = GlweDimension(2);
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    /// let secret_key = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    ///
+    /// let mut lhs = GlweCiphertext::allocate(0u64, poly_size, glwe_dim.to_glwe_size());
+    /// let mut rhs = GlweCiphertext::allocate(0u64, poly_size, glwe_dim.to_glwe_size());
+    /// let mut messages = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+    /// *messages.as_mut_tensor().first_mut() = 2 << 50;
+    /// secret_key.encrypt_glwe(&mut lhs, &messages, noise, &mut encryption_generator);
+    /// *messages.as_mut_tensor().first_mut() = 3 << 50;
+    /// secret_key.encrypt_glwe(&mut rhs, &messages, noise, &mut encryption_generator);
+    ///
+    /// let mut product = GlweCiphertext::allocate(0u64, poly_size, GlweSize(6));
+    /// product.fill_with_tensor_product(&lhs, &rhs, DeltaLog(50));
+    /// assert_eq!(product.mask_size(), GlweDimension(5));
+    /// ```
+    pub fn fill_with_tensor_product<LhsCont, RhsCont, Scalar>(
+        &mut self,
+        lhs: &GlweCiphertext<LhsCont>,
+        rhs: &GlweCiphertext<RhsCont>,
+        delta_log: DeltaLog,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<LhsCont>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<RhsCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + CastFrom<u128>,
+        Scalar::Signed: CastInto<i128>,
+    {
+        ck_dim_eq!(self.poly_size.0 => lhs.polynomial_size().0, rhs.polynomial_size().0);
+        ck_dim_eq!(lhs.mask_size().0 => rhs.mask_size().0);
+        let glwe_dimension = lhs.mask_size().0;
+        ck_dim_eq!(self.mask_size().0 => glwe_dimension * (glwe_dimension + 3) / 2);
+        debug_assert!(Scalar::BITS + delta_log.0 <= 128);
+
+        let poly_size = self.poly_size.0;
+
+        // The products are computed modulo 2^128 on the signed representatives of the inputs,
+        // which is enough to get the exact result modulo q * 2^delta_log.
+        let lift = |polynomials: &[Scalar]| -> Vec<u128> {
+            polynomials
+                .iter()
+                .map(|coef| {
+                    let signed: i128 = coef.into_signed().cast_into();
+                    signed as u128
+                })
+                .collect()
+        };
+        let lhs_polys = lift(lhs.as_tensor().as_slice());
+        let rhs_polys = lift(rhs.as_tensor().as_slice());
+        let lhs_poly = |i: usize| &lhs_polys[i * poly_size..(i + 1) * poly_size];
+        let rhs_poly = |i: usize| &rhs_polys[i * poly_size..(i + 1) * poly_size];
+        let (lhs_body, rhs_body) = (lhs_poly(glwe_dimension), rhs_poly(glwe_dimension));
+
+        let rounding = if delta_log.0 == 0 {
+            0
+        } else {
+            1u128 << (delta_log.0 - 1)
+        };
+        let mut buffer = vec![0u128; poly_size];
+        let mut output_polys = self.as_mut_tensor().subtensor_iter_mut(poly_size);
+        let mut write_rescaled = |buffer: &mut [u128]| {
+            let mut output = output_polys.next().unwrap();
+            for (out, acc) in output.iter_mut().zip(buffer.iter_mut()) {
+                *out = Scalar::cast_from(acc.wrapping_add(rounding) >> delta_log.0);
+                *acc = 0;
+            }
+        };
+
+        // The linear terms A1_i * B2 + B1 * A2_i, associated with S_i
+        for i in 0..glwe_dimension {
+            update_with_wrapping_negacyclic_mul(&mut buffer, lhs_poly(i), rhs_body, false);
+            update_with_wrapping_negacyclic_mul(&mut buffer, lhs_body, rhs_poly(i), false);
+            write_rescaled(&mut buffer);
+        }
+
+        // The quadratic terms -(A1_i * A2_j + A1_j * A2_i), associated with S_i * S_j
+        for i in 0..glwe_dimension {
+            for j in i..glwe_dimension {
+                update_with_wrapping_negacyclic_mul(&mut buffer, lhs_poly(i), rhs_poly(j), true);
+                if i != j {
+                    update_with_wrapping_negacyclic_mul(
+                        &mut buffer,
+                        lhs_poly(j),
+                        rhs_poly(i),
+                        true,
+                    );
+                }
+                write_rescaled(&mut buffer);
+            }
+        }
+
+        // The body B1 * B2
+        update_with_wrapping_negacyclic_mul(&mut buffer, lhs_body, rhs_body, false);
+        write_rescaled(&mut buffer);
+    }
+}
+
+/// Adds (or subtracts if `negate` is set) the product of two polynomials reduced modulo
+/// $(X^N+1)$ to `output`, with wrapping arithmetic on 128 bits integers.
+fn update_with_wrapping_negacyclic_mul(
+    output: &mut [u128],
+    lhs: &[u128],
+    rhs: &[u128],
+    negate: bool,
+) {
+    let poly_size = output.len();
+    for (i, lhs_coef) in lhs.iter().enumerate() {
+        for (j, rhs_coef) in rhs.iter().enumerate() {
+            let product = lhs_coef.wrapping_mul(*rhs_coef);
+            let target = i + j;
+            // X^N = -1, so the coefficients wrapping around change sign.
+            let (index, subtract) = if target < poly_size {
+                (target, negate)
+            } else {
+                (target - poly_size, !negate)
+            };
+            output[index] = if subtract {
+                output[index].wrapping_sub(product)
+            } else {
+                output[index].wrapping_add(product)
+            };
+        }
+    }
 }
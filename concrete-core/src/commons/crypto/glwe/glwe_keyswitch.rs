@@ -0,0 +1,486 @@
+use super::GlweCiphertext;
+use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::decomposition::{
+    DecompositionLevel, DecompositionTerm, SignedDecomposer,
+};
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::commons::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::KeyKind;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A GLWE keyswitching key.
+///
+/// A GLWE keyswitching key allows to switch a GLWE ciphertext encrypted under an input key
+/// $\vec{S} = (S\_0, \cdots, S\_{k-1})$ to a GLWE ciphertext encrypting the same plaintext under
+/// an output key $\vec{S'}$ sharing the same polynomial size, but possibly having a different GLWE
+/// dimension.
+///
+/// For every input key polynomial $S\_i$ and every level $l$ of the decomposition, the key
+/// contains a GLWE encryption under $\vec{S'}$ of $S\_i \cdot \frac{q}{B^l}$.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweKeyswitchKey<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+    output_glwe_size: GlweSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+}
+
+tensor_traits!(GlweKeyswitchKey);
+
+impl<Scalar> GlweKeyswitchKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a GLWE keyswitching key whose masks and bodies are all `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a keyswitch key, but merely allocates a container of the
+    /// right size. See [`GlweKeyswitchKey::fill_with_keyswitch_key`] to fill the container with a
+    /// proper keyswitching key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweKeyswitchKey;
+    /// let ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// assert_eq!(ksk.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(ksk.input_glwe_dimension(), GlweDimension(4));
+    /// assert_eq!(ksk.output_glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(ksk.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(ksk.decomposition_base_log(), DecompositionBaseLog(7));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        poly_size: PolynomialSize,
+        input_glwe_dimension: GlweDimension,
+        output_glwe_dimension: GlweDimension,
+        decomp_level_count: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self {
+        let output_glwe_size = output_glwe_dimension.to_glwe_size();
+        GlweKeyswitchKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                input_glwe_dimension.0
+                    * decomp_level_count.0
+                    * output_glwe_size.0
+                    * poly_size.0
+            ]),
+            poly_size,
+            output_glwe_size,
+            decomp_base_log,
+            decomp_level_count,
+        }
+    }
+}
+
+impl<Cont> GlweKeyswitchKey<Cont> {
+    /// Creates a GLWE keyswitching key from a container.
+    ///
+    /// # Notes
+    ///
+    /// This method does not create a keyswitching key, but merely wraps the container in the
+    /// proper type. It assumes that either the container already contains a proper keyswitching
+    /// key, or that [`GlweKeyswitchKey::fill_with_keyswitch_key`] will be called right after.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweKeyswitchKey;
+    /// let ksk = GlweKeyswitchKey::from_container(
+    ///     vec![0 as u32; 4 * 3 * 3 * 256],
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2).to_glwe_size(),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// assert_eq!(ksk.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(ksk.input_glwe_dimension(), GlweDimension(4));
+    /// assert_eq!(ksk.output_glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(ksk.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(ksk.decomposition_base_log(), DecompositionBaseLog(7));
+    /// ```
+    pub fn from_container<Coef>(
+        cont: Cont,
+        poly_size: PolynomialSize,
+        output_glwe_size: GlweSize,
+        decomp_level_count: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self
+    where
+        Cont: AsRefSlice<Element = Coef>,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() => decomp_level_count.0, output_glwe_size.0, poly_size.0);
+        GlweKeyswitchKey {
+            tensor,
+            poly_size,
+            output_glwe_size,
+            decomp_base_log,
+            decomp_level_count,
+        }
+    }
+
+    /// Returns the size of the polynomials used in the key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweKeyswitchKey;
+    /// let ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// assert_eq!(ksk.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the GLWE dimension of the input key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweKeyswitchKey;
+    /// let ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// assert_eq!(ksk.input_glwe_dimension(), GlweDimension(4));
+    /// ```
+    pub fn input_glwe_dimension(&self) -> GlweDimension
+    where
+        Self: AsRefTensor,
+    {
+        GlweDimension(
+            self.as_tensor().len()
+                / (self.decomp_level_count.0 * self.output_glwe_size.0 * self.poly_size.0),
+        )
+    }
+
+    /// Returns the GLWE dimension of the output key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweKeyswitchKey;
+    /// let ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// assert_eq!(ksk.output_glwe_dimension(), GlweDimension(2));
+    /// ```
+    pub fn output_glwe_dimension(&self) -> GlweDimension {
+        self.output_glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the number of levels used to decompose the input ciphertext masks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweKeyswitchKey;
+    /// let ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// assert_eq!(ksk.decomposition_level_count(), DecompositionLevelCount(3));
+    /// ```
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used to decompose the input ciphertext masks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweKeyswitchKey;
+    /// let ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// assert_eq!(ksk.decomposition_base_log(), DecompositionBaseLog(7));
+    /// ```
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the slice containing the encryption of the `level`-th term of the `index`-th input
+    /// key polynomial.
+    fn ciphertext_slice<Scalar>(&self, index: usize, level: DecompositionLevel) -> &[Scalar]
+    where
+        Self: AsRefTensor<Element = Scalar>,
+    {
+        let ciphertext_size = self.output_glwe_size.0 * self.poly_size.0;
+        let offset = (index * self.decomp_level_count.0 + level.0 - 1) * ciphertext_size;
+        &self.as_tensor().as_slice()[offset..offset + ciphertext_size]
+    }
+
+    /// Fills the current container with an actual keyswitching key constructed from an input and
+    /// an output GLWE secret keys.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweKeyswitchKey;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let input_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(4),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let output_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    ///
+    /// let mut ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// ksk.fill_with_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     LogStandardDev::from_log_standard_dev(-15.),
+    ///     &mut encryption_generator,
+    /// );
+    /// assert!(!ksk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_keyswitch_key<InKind, OutKind, InKeyCont, OutKeyCont, Scalar, Gen>(
+        &mut self,
+        input_key: &GlweSecretKey<InKind, InKeyCont>,
+        output_key: &GlweSecretKey<OutKind, OutKeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<InKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<OutKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        InKind: KeyKind,
+        OutKind: KeyKind,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(input_key.key_size().0 => self.input_glwe_dimension().0);
+        ck_dim_eq!(output_key.key_size().0 => self.output_glwe_dimension().0);
+        ck_dim_eq!(self.poly_size.0 => input_key.polynomial_size().0);
+        ck_dim_eq!(self.poly_size.0 => output_key.polynomial_size().0);
+
+        let poly_size = self.poly_size;
+        let ciphertext_size = self.output_glwe_size.0 * poly_size.0;
+        let level_count = self.decomp_level_count.0;
+        let base_log = self.decomp_base_log;
+
+        let mut messages = PlaintextList::from_container(vec![Scalar::ZERO; poly_size.0]);
+        for (key_poly, mut key_block) in input_key.as_polynomial_list().polynomial_iter().zip(
+            self.as_mut_tensor()
+                .subtensor_iter_mut(level_count * ciphertext_size),
+        ) {
+            for (level, mut ciphertext) in (1..=level_count)
+                .map(DecompositionLevel)
+                .zip(key_block.subtensor_iter_mut(ciphertext_size))
+            {
+                // We encode S_i * q / B^l
+                for (message, key_coef) in messages
+                    .as_mut_tensor()
+                    .iter_mut()
+                    .zip(key_poly.coefficient_iter())
+                {
+                    *message = DecompositionTerm::new(level, base_log, *key_coef)
+                        .to_recomposition_summand();
+                }
+                output_key.encrypt_glwe(
+                    &mut GlweCiphertext::from_container(ciphertext.as_mut_slice(), poly_size),
+                    &messages,
+                    noise_parameters,
+                    generator,
+                );
+            }
+        }
+    }
+
+    /// Keyswitches a GLWE ciphertext encrypted under the input key to a GLWE ciphertext
+    /// encrypted under the output key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PlaintextCount,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::{GlweCiphertext, GlweKeyswitchKey};
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefTensor};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let poly_size = PolynomialSize(256);
+    /// let input_dim = GlweDimension(3);
+    /// let output_dim = GlweDimension(1);
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    /// let input_key = GlweSecretKey::generate_binary(input_dim, poly_size, &mut secret_generator);
+    /// let output_key =
+    ///     GlweSecretKey::generate_binary(output_dim, poly_size, &mut secret_generator);
+    ///
+    /// let mut ksk = GlweKeyswitchKey::allocate(
+    ///     0u64,
+    ///     poly_size,
+    ///     input_dim,
+    ///     output_dim,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(10),
+    /// );
+    /// ksk.fill_with_keyswitch_key(&input_key, &output_key, noise, &mut encryption_generator);
+    ///
+    /// let mut input = GlweCiphertext::allocate(0u64, poly_size, input_dim.to_glwe_size());
+    /// let mut messages = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+    /// *messages.as_mut_tensor().first_mut() = 5 << 50;
+    /// input_key.encrypt_glwe(&mut input, &messages, noise, &mut encryption_generator);
+    ///
+    /// let mut output = GlweCiphertext::allocate(0u64, poly_size, output_dim.to_glwe_size());
+    /// ksk.keyswitch_ciphertext(&mut output, &input);
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+    /// output_key.decrypt_glwe(&mut decrypted, &output);
+    /// let decoded = decrypted.as_tensor().first().wrapping_add(1 << 49) >> 50;
+    /// assert_eq!(decoded, 5);
+    /// ```
+    pub fn keyswitch_ciphertext<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        input: &GlweCiphertext<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.poly_size.0 => input.polynomial_size().0, output.polynomial_size().0);
+        ck_dim_eq!(self.output_glwe_size.0 => output.size().0);
+        ck_dim_eq!(self.input_glwe_dimension().0 => input.mask_size().0);
+
+        let poly_size = self.poly_size.0;
+
+        // We start from the trivial encryption of the input body.
+        output.as_mut_tensor().fill_with_element(Scalar::ZERO);
+        output
+            .get_mut_body()
+            .as_mut_tensor()
+            .fill_with_copy(input.get_body().as_tensor());
+
+        // We decompose the input mask, and subtract the product of the decomposition with the
+        // keyswitching key from the output.
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+        let mut decomposition = decomposer.decompose_tensor(input.get_mask().as_tensor());
+        let mut buffer = Polynomial::allocate(Scalar::ZERO, self.poly_size);
+        while let Some(term) = decomposition.next_term() {
+            for (index, decomposed_poly) in term.as_tensor().subtensor_iter(poly_size).enumerate() {
+                let decomposed_poly = Polynomial::from_container(decomposed_poly.as_slice());
+                let key_ciphertext = self.ciphertext_slice(index, term.level());
+                for (mut output_poly, key_poly) in output
+                    .as_mut_polynomial_list()
+                    .polynomial_iter_mut()
+                    .zip(key_ciphertext.chunks(poly_size))
+                {
+                    buffer.fill_with_wrapping_mul(
+                        &decomposed_poly,
+                        &Polynomial::from_container(key_poly),
+                    );
+                    output_poly.update_with_wrapping_sub(&buffer);
+                }
+            }
+        }
+    }
+}
@@ -1,17 +1,29 @@
 //! GLWE encryption scheme
 
+mod automorphism;
 mod body;
 mod ciphertext;
+mod glwe_keyswitch;
 mod keyswitch;
 mod list;
 mod mask;
+mod public_key;
+mod relinearization;
 mod seeded_ciphertext;
+mod seeded_glwe_keyswitch;
+mod seeded_keyswitch;
 mod seeded_list;
 
+pub use automorphism::*;
 pub use body::*;
 pub use ciphertext::*;
+pub use glwe_keyswitch::*;
 pub use keyswitch::*;
 pub use list::*;
 pub use mask::*;
+pub use public_key::*;
+pub use relinearization::*;
 pub use seeded_ciphertext::*;
+pub use seeded_glwe_keyswitch::*;
+pub use seeded_keyswitch::*;
 pub use seeded_list::*;
@@ -0,0 +1,348 @@
+use super::{GlweCiphertext, GlweList};
+use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::secret::generators::{
+    EncryptionRandomGenerator, SecretRandomGenerator,
+};
+use crate::commons::crypto::secret::{GlweSecretKey, RandomKeyKind};
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::commons::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::KeyKind;
+use concrete_commons::parameters::{
+    GlweDimension, GlwePublicKeyZeroEncryptionCount, GlweSize, PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A GLWE public key.
+///
+/// A GLWE public key is a list of $m$ GLWE encryptions of zero $\left(\vec{A}\_j, B\_j\right)$
+/// under a secret key $\vec{S}$. Contrary to an LWE public key, whose size grows with the LWE
+/// dimension times the number of encryptions of zero, a single GLWE encryption of zero is enough
+/// to encrypt a whole polynomial of plaintexts.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlwePublicKey<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    glwe_size: GlweSize,
+    poly_size: PolynomialSize,
+}
+
+tensor_traits!(GlwePublicKey);
+
+impl<Scalar> GlwePublicKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a GLWE public key whose masks and bodies are all `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a public key, but merely allocates a container of the
+    /// right size. See [`GlwePublicKey::fill_with_encryptions_of_zero`] to fill the container with
+    /// a proper public key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, GlwePublicKeyZeroEncryptionCount, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlwePublicKey;
+    /// let public_key = GlwePublicKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     GlwePublicKeyZeroEncryptionCount(3),
+    /// );
+    /// assert_eq!(public_key.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(public_key.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(
+    ///     public_key.zero_encryption_count(),
+    ///     GlwePublicKeyZeroEncryptionCount(3)
+    /// );
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        poly_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+        zero_encryption_count: GlwePublicKeyZeroEncryptionCount,
+    ) -> Self {
+        let glwe_size = glwe_dimension.to_glwe_size();
+        GlwePublicKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                zero_encryption_count.0 * glwe_size.0 * poly_size.0
+            ]),
+            glwe_size,
+            poly_size,
+        }
+    }
+}
+
+impl<Cont> GlwePublicKey<Cont> {
+    /// Creates a GLWE public key from a container.
+    ///
+    /// # Notes
+    ///
+    /// This method does not create a public key, but merely wraps the container in the proper
+    /// type. It assumes that either the container already contains a proper public key, or that
+    /// [`GlwePublicKey::fill_with_encryptions_of_zero`] will be called right after.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, GlwePublicKeyZeroEncryptionCount, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlwePublicKey;
+    /// let public_key = GlwePublicKey::from_container(
+    ///     vec![0 as u32; 3 * 3 * 256],
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(public_key.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(public_key.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(
+    ///     public_key.zero_encryption_count(),
+    ///     GlwePublicKeyZeroEncryptionCount(3)
+    /// );
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> Self
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        let glwe_size = glwe_dimension.to_glwe_size();
+        ck_dim_div!(tensor.len() => glwe_size.0, poly_size.0);
+        GlwePublicKey {
+            tensor,
+            glwe_size,
+            poly_size,
+        }
+    }
+
+    /// Returns the GLWE dimension of the key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, GlwePublicKeyZeroEncryptionCount, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlwePublicKey;
+    /// let public_key = GlwePublicKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     GlwePublicKeyZeroEncryptionCount(3),
+    /// );
+    /// assert_eq!(public_key.glwe_dimension(), GlweDimension(2));
+    /// ```
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials used in the key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, GlwePublicKeyZeroEncryptionCount, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlwePublicKey;
+    /// let public_key = GlwePublicKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     GlwePublicKeyZeroEncryptionCount(3),
+    /// );
+    /// assert_eq!(public_key.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the number of GLWE encryptions of zero contained in the key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, GlwePublicKeyZeroEncryptionCount, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlwePublicKey;
+    /// let public_key = GlwePublicKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     GlwePublicKeyZeroEncryptionCount(3),
+    /// );
+    /// assert_eq!(
+    ///     public_key.zero_encryption_count(),
+    ///     GlwePublicKeyZeroEncryptionCount(3)
+    /// );
+    /// ```
+    pub fn zero_encryption_count(&self) -> GlwePublicKeyZeroEncryptionCount
+    where
+        Self: AsRefTensor,
+    {
+        GlwePublicKeyZeroEncryptionCount(
+            self.as_tensor().len() / (self.glwe_size.0 * self.poly_size.0),
+        )
+    }
+
+    /// Fills the current public key with fresh GLWE encryptions of zero under the `secret_key`.
+    ///
+    /// # Example
+    ///
+    /// See [`GlwePublicKey::encrypt_glwe`] for an example.
+    pub fn fill_with_encryptions_of_zero<Kind, KeyCont, Scalar, Gen>(
+        &mut self,
+        secret_key: &GlweSecretKey<Kind, KeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Kind: KeyKind,
+        GlweSecretKey<Kind, KeyCont>: AsRefTensor<Element = Scalar>,
+        Self: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.glwe_dimension().0 => secret_key.key_size().0);
+        ck_dim_eq!(self.polynomial_size().0 => secret_key.polynomial_size().0);
+        let glwe_dimension = self.glwe_dimension();
+        let poly_size = self.poly_size;
+        let mut list = GlweList::from_container(
+            self.as_mut_tensor().as_mut_slice(),
+            glwe_dimension,
+            poly_size,
+        );
+        secret_key.encrypt_zero_glwe_list(&mut list, noise_parameters, generator);
+    }
+
+    /// Encrypts a list of plaintexts in a GLWE ciphertext, using the current public key.
+    ///
+    /// For every encryption of zero $\left(\vec{A}\_j, B\_j\right)$ of the key, a randomness
+    /// polynomial $U\_j$ is sampled with coefficients following the `RandomnessKind`
+    /// distribution (typically binary or ternary). The output is then:
+    /// $$\left(\sum\_j U\_j \cdot \vec{A}\_j + \vec{E}, \sum\_j U\_j \cdot B\_j + E' + M\right)$$
+    /// where $\vec{E}$ and $E'$ are fresh noise polynomials.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::key_kinds::BinaryKeyKind;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, GlwePublicKeyZeroEncryptionCount, PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::{GlweCiphertext, GlwePublicKey};
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let secret_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    ///
+    /// let mut public_key = GlwePublicKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     GlwePublicKeyZeroEncryptionCount(1),
+    /// );
+    /// public_key.fill_with_encryptions_of_zero(&secret_key, noise, &mut encryption_generator);
+    ///
+    /// let plaintexts = PlaintextList::from_container(vec![5_u32 << 28; 256]);
+    /// let mut ciphertext = GlweCiphertext::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2).to_glwe_size(),
+    /// );
+    /// public_key.encrypt_glwe::<BinaryKeyKind, _, _, _, _>(
+    ///     &mut ciphertext,
+    ///     &plaintexts,
+    ///     noise,
+    ///     &mut secret_generator,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0 as u32, PlaintextCount(256));
+    /// secret_key.decrypt_glwe(&mut decrypted, &ciphertext);
+    /// for (dec, plain) in decrypted.plaintext_iter().zip(plaintexts.plaintext_iter()) {
+    ///     let d0 = dec.0.wrapping_sub(plain.0);
+    ///     let d1 = plain.0.wrapping_sub(dec.0);
+    ///     let dist = std::cmp::min(d0, d1);
+    ///     assert!(dist < 1 << 24, "dist: {:?}", dist);
+    /// }
+    /// ```
+    pub fn encrypt_glwe<RandomnessKind, OutputCont, EncCont, Scalar, Gen>(
+        &self,
+        encrypted: &mut GlweCiphertext<OutputCont>,
+        encoded: &PlaintextList<EncCont>,
+        noise_parameters: impl DispersionParameter,
+        secret_generator: &mut SecretRandomGenerator<Gen>,
+        encryption_generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        RandomnessKind: RandomKeyKind,
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
+        PlaintextList<EncCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(encrypted.mask_size().0 => self.glwe_dimension().0);
+        ck_dim_eq!(encrypted.polynomial_size().0 => self.polynomial_size().0);
+        let poly_size = self.polynomial_size();
+
+        // We start from the fresh noise polynomials, and add the plaintexts to the body.
+        encryption_generator.fill_tensor_with_random_noise(encrypted, noise_parameters);
+        encrypted
+            .get_mut_body()
+            .as_mut_tensor()
+            .update_with_wrapping_add(encoded.as_tensor());
+
+        // We add the product of every encryption of zero with a fresh randomness polynomial.
+        let mut product = Polynomial::allocate(Scalar::ZERO, poly_size);
+        let list = GlweList::from_container(
+            self.as_tensor().as_slice(),
+            self.glwe_size.to_glwe_dimension(),
+            self.poly_size,
+        );
+        for zero_encryption in list.ciphertext_iter() {
+            let randomness = Polynomial::from_container(
+                RandomnessKind::random_key_tensor::<Scalar, _>(secret_generator, poly_size.0)
+                    .into_container(),
+            );
+            for (mut output_poly, zero_poly) in encrypted
+                .as_mut_polynomial_list()
+                .polynomial_iter_mut()
+                .zip(zero_encryption.as_polynomial_list().polynomial_iter())
+            {
+                product.fill_with_wrapping_mul(&zero_poly, &randomness);
+                output_poly.update_with_wrapping_add(&product);
+            }
+        }
+    }
+}
@@ -0,0 +1,409 @@
+use super::GlweCiphertext;
+use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::decomposition::{
+    DecompositionLevel, DecompositionTerm, SignedDecomposer,
+};
+use crate::commons::math::polynomial::Polynomial;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::commons::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::KeyKind;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A GLWE relinearization key.
+///
+/// A relinearization key allows to turn the tensor product of two GLWE ciphertexts encrypted
+/// under a key $\vec{S}$ (which is encrypted under the tensored key made of the $S\_i$ and of the
+/// products $S\_i S\_j$) back into a GLWE ciphertext encrypted under $\vec{S}$.
+///
+/// For every level $l$ of the decomposition and every pair $i\leq j$ (in lexicographic order),
+/// the key contains a GLWE encryption under $\vec{S}$ of $-S\_i S\_j \cdot \frac{q}{B^l}$.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweRelinearizationKey<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+}
+
+tensor_traits!(GlweRelinearizationKey);
+
+/// Returns the number of products $S\_i S\_j$ with $i\leq j$ for a given GLWE dimension.
+fn key_product_count(glwe_dimension: GlweDimension) -> usize {
+    glwe_dimension.0 * (glwe_dimension.0 + 1) / 2
+}
+
+impl<Scalar> GlweRelinearizationKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a relinearization key whose masks and bodies are all `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a relinearization key, but merely allocates a container
+    /// of the right size. See [`GlweRelinearizationKey::fill_with_relinearization_key`] to fill
+    /// the container with a proper relinearization key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRelinearizationKey;
+    /// let rlk = GlweRelinearizationKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// assert_eq!(rlk.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(rlk.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(rlk.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(rlk.decomposition_base_log(), DecompositionBaseLog(7));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        poly_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+        decomp_level_count: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self {
+        let glwe_size = glwe_dimension.to_glwe_size();
+        GlweRelinearizationKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                decomp_level_count.0
+                    * key_product_count(glwe_dimension)
+                    * glwe_size.0
+                    * poly_size.0
+            ]),
+            poly_size,
+            glwe_size,
+            decomp_base_log,
+            decomp_level_count,
+        }
+    }
+}
+
+impl<Cont> GlweRelinearizationKey<Cont> {
+    /// Creates a relinearization key from an existing container.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRelinearizationKey;
+    /// let rlk = GlweRelinearizationKey::from_container(
+    ///     vec![0 as u32; 3 * 3 * 3 * 256],
+    ///     PolynomialSize(256),
+    ///     GlweSize(3),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// assert_eq!(rlk.glwe_dimension(), GlweDimension(2));
+    /// ```
+    pub fn from_container<Coef>(
+        cont: Cont,
+        poly_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomp_level_count: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self
+    where
+        Cont: AsRefSlice<Element = Coef>,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_eq!(
+            tensor.len() =>
+            decomp_level_count.0
+                * key_product_count(glwe_size.to_glwe_dimension())
+                * glwe_size.0
+                * poly_size.0
+        );
+        GlweRelinearizationKey {
+            tensor,
+            poly_size,
+            glwe_size,
+            decomp_base_log,
+            decomp_level_count,
+        }
+    }
+
+    /// Returns the size of the polynomials used in the key.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the dimension of the GLWE secret key the key was generated with.
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the number of levels used in the decomposition.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used in the decomposition.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the GLWE dimension of the tensor products the key can relinearize.
+    pub fn tensor_product_glwe_dimension(&self) -> GlweDimension {
+        let glwe_dimension = self.glwe_dimension();
+        GlweDimension(glwe_dimension.0 + key_product_count(glwe_dimension))
+    }
+
+    /// Returns the slice containing the GLWE ciphertexts of a given decomposition level.
+    fn level_slice<Scalar>(&self, level: DecompositionLevel) -> &[Scalar]
+    where
+        Self: AsRefTensor<Element = Scalar>,
+    {
+        let level_size =
+            key_product_count(self.glwe_dimension()) * self.glwe_size.0 * self.poly_size.0;
+        ck_dim_div!(self.as_tensor().len() => level_size);
+        &self.as_tensor().as_slice()[(level.0 - 1) * level_size..level.0 * level_size]
+    }
+
+    /// Fills the current container with an actual relinearization key constructed from a GLWE
+    /// secret key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweRelinearizationKey;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    ///
+    /// let mut rlk = GlweRelinearizationKey::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// rlk.fill_with_relinearization_key(
+    ///     &key,
+    ///     LogStandardDev::from_log_standard_dev(-15.),
+    ///     &mut encryption_generator,
+    /// );
+    /// assert!(!rlk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_relinearization_key<Kind, KeyCont, Scalar, Gen>(
+        &mut self,
+        key: &GlweSecretKey<Kind, KeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<Kind, KeyCont>: AsRefTensor<Element = Scalar>,
+        Kind: KeyKind,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(key.key_size().0 => self.glwe_dimension().0);
+        ck_dim_eq!(key.polynomial_size().0 => self.poly_size.0);
+
+        let poly_size = self.poly_size;
+        let glwe_size = self.glwe_size;
+        let glwe_dimension = self.glwe_dimension().0;
+        let base_log = self.decomp_base_log;
+
+        // We compute the products S_i * S_j of the key polynomials once.
+        let key_polys = key.as_polynomial_list();
+        let mut key_products = Vec::with_capacity(key_product_count(self.glwe_dimension()));
+        for i in 0..glwe_dimension {
+            for j in i..glwe_dimension {
+                let mut product = Polynomial::allocate(Scalar::ZERO, poly_size);
+                product.fill_with_wrapping_mul(
+                    &key_polys.get_polynomial(i),
+                    &key_polys.get_polynomial(j),
+                );
+                key_products.push(product);
+            }
+        }
+
+        let mut messages = PlaintextList::from_container(vec![Scalar::ZERO; poly_size.0]);
+        let level_size = key_products.len() * glwe_size.0 * poly_size.0;
+        for (level_index, mut level_block) in self
+            .as_mut_tensor()
+            .subtensor_iter_mut(level_size)
+            .enumerate()
+        {
+            let level = DecompositionLevel(level_index + 1);
+            for (key_product, mut ciphertext) in key_products
+                .iter()
+                .zip(level_block.subtensor_iter_mut(glwe_size.0 * poly_size.0))
+            {
+                // We encode -S_i * S_j * q / B^l
+                for (message, key_coef) in messages
+                    .as_mut_tensor()
+                    .iter_mut()
+                    .zip(key_product.coefficient_iter())
+                {
+                    *message = DecompositionTerm::new(level, base_log, key_coef.wrapping_neg())
+                        .to_recomposition_summand();
+                }
+                key.encrypt_glwe(
+                    &mut GlweCiphertext::from_container(ciphertext.as_mut_slice(), poly_size),
+                    &messages,
+                    noise_parameters,
+                    generator,
+                );
+            }
+        }
+    }
+
+    /// Relinearizes the tensor product of two GLWE ciphertexts.
+    ///
+    /// The `input` ciphertext is expected to be the output of
+    /// [`GlweCiphertext::fill_with_tensor_product`] on ciphertexts encrypted under the key used
+    /// to generate the relinearization key. The `output` ciphertext is encrypted under this key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, DeltaLog, GlweDimension,
+    ///     PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::{GlweCiphertext, GlweRelinearizationKey};
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::{AsMutTensor, AsRefTensor};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let poly_size = PolynomialSize(256);
+    /// let glwe_dim = GlweDimension(1);
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    /// let key = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    ///
+    /// let mut rlk = GlweRelinearizationKey::allocate(
+    ///     0u64,
+    ///     poly_size,
+    ///     glwe_dim,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(10),
+    /// );
+    /// rlk.fill_with_relinearization_key(&key, noise, &mut encryption_generator);
+    ///
+    /// let mut lhs = GlweCiphertext::allocate(0u64, poly_size, glwe_dim.to_glwe_size());
+    /// let mut rhs = GlweCiphertext::allocate(0u64, poly_size, glwe_dim.to_glwe_size());
+    /// let mut messages = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+    /// *messages.as_mut_tensor().first_mut() = 2 << 50;
+    /// key.encrypt_glwe(&mut lhs, &messages, noise, &mut encryption_generator);
+    /// *messages.as_mut_tensor().first_mut() = 3 << 50;
+    /// key.encrypt_glwe(&mut rhs, &messages, noise, &mut encryption_generator);
+    ///
+    /// let mut product = GlweCiphertext::allocate(
+    ///     0u64,
+    ///     poly_size,
+    ///     rlk.tensor_product_glwe_dimension().to_glwe_size(),
+    /// );
+    /// product.fill_with_tensor_product(&lhs, &rhs, DeltaLog(50));
+    /// let mut output = GlweCiphertext::allocate(0u64, poly_size, glwe_dim.to_glwe_size());
+    /// rlk.relinearize(&mut output, &product);
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+    /// key.decrypt_glwe(&mut decrypted, &output);
+    /// let decoded = decrypted.as_tensor().first().wrapping_add(1 << 49) >> 50;
+    /// assert_eq!(decoded, 6);
+    /// ```
+    pub fn relinearize<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        input: &GlweCiphertext<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.poly_size.0 => input.polynomial_size().0, output.polynomial_size().0);
+        ck_dim_eq!(self.glwe_size.0 => output.size().0);
+        ck_dim_eq!(self.tensor_product_glwe_dimension().0 => input.mask_size().0);
+
+        let poly_size = self.poly_size.0;
+        let linear_size = self.glwe_dimension().0 * poly_size;
+        let input_slice = input.as_tensor().as_slice();
+
+        // We copy the part of the input associated with S_i, and its body.
+        {
+            let output_slice = output.as_mut_tensor().as_mut_slice();
+            output_slice[..linear_size].copy_from_slice(&input_slice[..linear_size]);
+            output_slice[linear_size..].copy_from_slice(input.get_body().as_tensor().as_slice());
+        }
+
+        // We decompose the part of the input associated with S_i * S_j, and add the product of
+        // the decomposition with the relinearization key to the output.
+        let quadratic_part =
+            Tensor::from_container(&input_slice[linear_size..input_slice.len() - poly_size]);
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+        let mut decomposition = decomposer.decompose_tensor(&quadratic_part);
+        let mut buffer = Polynomial::allocate(Scalar::ZERO, self.poly_size);
+        while let Some(term) = decomposition.next_term() {
+            let level_slice = self.level_slice(term.level());
+            for (decomposed_poly, key_ciphertext) in term
+                .as_tensor()
+                .subtensor_iter(poly_size)
+                .zip(level_slice.chunks(self.glwe_size.0 * poly_size))
+            {
+                let decomposed_poly = Polynomial::from_container(decomposed_poly.as_slice());
+                for (mut output_poly, key_poly) in output
+                    .as_mut_polynomial_list()
+                    .polynomial_iter_mut()
+                    .zip(key_ciphertext.chunks(poly_size))
+                {
+                    buffer.fill_with_wrapping_mul(
+                        &decomposed_poly,
+                        &Polynomial::from_container(key_poly),
+                    );
+                    output_poly.update_with_wrapping_add(&buffer);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,366 @@
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::KeyKind;
+use concrete_commons::numeric::Numeric;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+};
+
+use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::commons::crypto::secret::GlweSecretKey;
+use crate::commons::math::decomposition::{DecompositionLevel, DecompositionTerm};
+use crate::commons::math::random::{
+    ByteRandomGenerator, CompressionSeed, RandomGenerable, RandomGenerator, Seeder, Uniform,
+};
+use crate::commons::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+
+use super::{GlweCiphertext, GlweKeyswitchKey, GlweSeededCiphertext};
+
+/// A seeded GLWE keyswitching key.
+///
+/// This key only stores the bodies of the GLWE ciphertexts of a [`GlweKeyswitchKey`], the masks
+/// being regenerated from the compression seed when the key is expanded.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweSeededKeyswitchKey<Cont> {
+    tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+    output_glwe_size: GlweSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    compression_seed: CompressionSeed,
+}
+
+tensor_traits!(GlweSeededKeyswitchKey);
+
+impl<Scalar> GlweSeededKeyswitchKey<Vec<Scalar>>
+where
+    Scalar: Copy + Numeric,
+{
+    /// Allocates a seeded GLWE keyswitching key, the underlying container has a size of
+    /// `input_glwe_dimension * decomp_level_count * poly_size`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a seeded keyswitch key, but merely allocates a container
+    /// of the right size. See [`GlweSeededKeyswitchKey::fill_with_seeded_keyswitch_key`] to fill
+    /// the container with a proper seeded keyswitching key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweSeededKeyswitchKey;
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    ///
+    /// let compression_seed = CompressionSeed { seed: Seed(42) };
+    /// let ksk: GlweSeededKeyswitchKey<Vec<u64>> = GlweSeededKeyswitchKey::allocate(
+    ///     PolynomialSize(256),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    ///     compression_seed,
+    /// );
+    /// assert_eq!(ksk.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(ksk.input_glwe_dimension(), GlweDimension(4));
+    /// assert_eq!(ksk.output_glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(ksk.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(ksk.decomposition_base_log(), DecompositionBaseLog(7));
+    /// assert_eq!(ksk.compression_seed(), compression_seed);
+    /// ```
+    pub fn allocate(
+        poly_size: PolynomialSize,
+        input_glwe_dimension: GlweDimension,
+        output_glwe_dimension: GlweDimension,
+        decomp_level_count: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        compression_seed: CompressionSeed,
+    ) -> Self {
+        GlweSeededKeyswitchKey {
+            tensor: Tensor::from_container(vec![
+                Scalar::ZERO;
+                input_glwe_dimension.0
+                    * decomp_level_count.0
+                    * poly_size.0
+            ]),
+            poly_size,
+            output_glwe_size: output_glwe_dimension.to_glwe_size(),
+            decomp_base_log,
+            decomp_level_count,
+            compression_seed,
+        }
+    }
+}
+
+impl<Cont> GlweSeededKeyswitchKey<Cont> {
+    /// Creates a seeded GLWE keyswitching key from a container of bodies.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweSeededKeyswitchKey;
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    ///
+    /// let ksk = GlweSeededKeyswitchKey::from_container(
+    ///     vec![0u64; 4 * 3 * 256],
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2).to_glwe_size(),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// assert_eq!(ksk.input_glwe_dimension(), GlweDimension(4));
+    /// assert_eq!(ksk.output_glwe_dimension(), GlweDimension(2));
+    /// ```
+    pub fn from_container<Coef>(
+        cont: Cont,
+        poly_size: PolynomialSize,
+        output_glwe_size: GlweSize,
+        decomp_level_count: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        compression_seed: CompressionSeed,
+    ) -> Self
+    where
+        Cont: AsRefSlice<Element = Coef>,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() => decomp_level_count.0, poly_size.0);
+        GlweSeededKeyswitchKey {
+            tensor,
+            poly_size,
+            output_glwe_size,
+            decomp_base_log,
+            decomp_level_count,
+            compression_seed,
+        }
+    }
+
+    /// Returns the size of the polynomials used in the key.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the GLWE dimension of the input key.
+    pub fn input_glwe_dimension(&self) -> GlweDimension
+    where
+        Self: AsRefTensor,
+    {
+        GlweDimension(self.as_tensor().len() / (self.decomp_level_count.0 * self.poly_size.0))
+    }
+
+    /// Returns the GLWE dimension of the output key.
+    pub fn output_glwe_dimension(&self) -> GlweDimension {
+        self.output_glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the number of levels used to decompose the input ciphertext masks.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used to decompose the input ciphertext masks.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the compression seed used to generate the masks of the key.
+    pub fn compression_seed(&self) -> CompressionSeed {
+        self.compression_seed
+    }
+
+    /// Fills the current container with an actual seeded keyswitching key constructed from an
+    /// input and an output GLWE secret keys.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::GlweSeededKeyswitchKey;
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::UnixSeeder;
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let input_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(4),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let output_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    ///
+    /// let mut ksk: GlweSeededKeyswitchKey<Vec<u32>> = GlweSeededKeyswitchKey::allocate(
+    ///     PolynomialSize(256),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// ksk.fill_with_seeded_keyswitch_key::<_, _, _, _, _, _, _, SoftwareRandomGenerator>(
+    ///     &input_key,
+    ///     &output_key,
+    ///     LogStandardDev::from_log_standard_dev(-15.),
+    ///     &mut UnixSeeder::new(0),
+    /// );
+    /// assert!(!ksk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_seeded_keyswitch_key<
+        InKind,
+        OutKind,
+        InKeyCont,
+        OutKeyCont,
+        Scalar,
+        NoiseParameter,
+        NoiseSeeder,
+        Gen,
+    >(
+        &mut self,
+        input_key: &GlweSecretKey<InKind, InKeyCont>,
+        output_key: &GlweSecretKey<OutKind, OutKeyCont>,
+        noise_parameters: NoiseParameter,
+        noise_seeder: &mut NoiseSeeder,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<InKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<OutKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        InKind: KeyKind,
+        OutKind: KeyKind,
+        Scalar: UnsignedTorus,
+        NoiseParameter: DispersionParameter,
+        NoiseSeeder: Seeder,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(input_key.key_size().0 => self.input_glwe_dimension().0);
+        ck_dim_eq!(output_key.key_size().0 => self.output_glwe_dimension().0);
+        ck_dim_eq!(self.poly_size.0 => input_key.polynomial_size().0);
+        ck_dim_eq!(self.poly_size.0 => output_key.polynomial_size().0);
+
+        let poly_size = self.poly_size.0;
+        let level_count = self.decomp_level_count.0;
+        let base_log = self.decomp_base_log;
+        let output_glwe_dimension = self.output_glwe_dimension();
+        let compression_seed = self.compression_seed;
+
+        let mut generator =
+            EncryptionRandomGenerator::<Gen>::new(compression_seed.seed, noise_seeder);
+
+        let mut messages = PlaintextList::from_container(vec![Scalar::ZERO; poly_size]);
+        for (key_poly, mut key_block) in input_key.as_polynomial_list().polynomial_iter().zip(
+            self.as_mut_tensor()
+                .subtensor_iter_mut(level_count * poly_size),
+        ) {
+            for (level, mut body) in (1..=level_count)
+                .map(DecompositionLevel)
+                .zip(key_block.subtensor_iter_mut(poly_size))
+            {
+                // We encode S_i * q / B^l
+                for (message, key_coef) in messages
+                    .as_mut_tensor()
+                    .iter_mut()
+                    .zip(key_poly.coefficient_iter())
+                {
+                    *message = DecompositionTerm::new(level, base_log, *key_coef)
+                        .to_recomposition_summand();
+                }
+                output_key.encrypt_seeded_glwe_with_existing_generator(
+                    &mut GlweSeededCiphertext::from_container(
+                        body.as_mut_slice(),
+                        output_glwe_dimension,
+                        compression_seed,
+                    ),
+                    &messages,
+                    noise_parameters,
+                    &mut generator,
+                );
+            }
+        }
+    }
+
+    /// Expands the seeded keyswitching key into a full fledged [`GlweKeyswitchKey`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::{GlweKeyswitchKey, GlweSeededKeyswitchKey};
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    ///
+    /// let ksk: GlweSeededKeyswitchKey<Vec<u64>> = GlweSeededKeyswitchKey::allocate(
+    ///     PolynomialSize(256),
+    ///     GlweDimension(4),
+    ///     GlweDimension(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    ///
+    /// let mut output_ksk = GlweKeyswitchKey::allocate(
+    ///     0u64,
+    ///     ksk.polynomial_size(),
+    ///     ksk.input_glwe_dimension(),
+    ///     ksk.output_glwe_dimension(),
+    ///     ksk.decomposition_level_count(),
+    ///     ksk.decomposition_base_log(),
+    /// );
+    ///
+    /// ksk.expand_into::<_, _, SoftwareRandomGenerator>(&mut output_ksk);
+    /// assert_eq!(output_ksk.input_glwe_dimension(), GlweDimension(4));
+    /// ```
+    pub fn expand_into<OutCont, Scalar, Gen>(self, output: &mut GlweKeyswitchKey<OutCont>)
+    where
+        GlweKeyswitchKey<OutCont>: AsMutTensor<Element = Scalar>,
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: Copy + RandomGenerable<Uniform> + Numeric,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.poly_size.0 => output.polynomial_size().0);
+        ck_dim_eq!(self.output_glwe_size.0 => output.output_glwe_dimension().to_glwe_size().0);
+        ck_dim_eq!(self.decomp_level_count.0 => output.decomposition_level_count().0);
+        ck_dim_eq!(self.as_tensor().len() * self.output_glwe_size.0 => output.as_tensor().len());
+
+        let mut generator = RandomGenerator::<Gen>::new(self.compression_seed.seed);
+        let poly_size = self.poly_size;
+        let output_glwe_dimension = self.output_glwe_dimension();
+
+        for (mut ciphertext, body) in output
+            .as_mut_tensor()
+            .subtensor_iter_mut(self.output_glwe_size.0 * poly_size.0)
+            .zip(self.as_tensor().subtensor_iter(poly_size.0))
+        {
+            GlweSeededCiphertext::from_container(
+                body.as_slice(),
+                output_glwe_dimension,
+                self.compression_seed,
+            )
+            .expand_into_with_existing_generator::<_, _, Gen>(
+                &mut GlweCiphertext::from_container(ciphertext.as_mut_slice(), poly_size),
+                &mut generator,
+            );
+        }
+    }
+}
@@ -0,0 +1,1727 @@
+use super::{
+    GlweCiphertext, GlweSeededCiphertext, LwePackingKeyswitchKey,
+    LwePrivateFunctionalPackingKeyswitchKey, LwePrivateFunctionalPackingKeyswitchKeyList,
+};
+use crate::commons::crypto::encoding::PlaintextList;
+use crate::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::commons::math::decomposition::{DecompositionLevel, DecompositionTerm};
+use crate::commons::math::polynomial::Polynomial;
+#[cfg(feature = "__commons_parallel")]
+use crate::commons::math::random::ParallelByteRandomGenerator;
+use crate::commons::math::random::{
+    ByteRandomGenerator, CompressionSeed, RandomGenerable, RandomGenerator, Seeder, Uniform,
+};
+use crate::commons::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::commons::math::torus::UnsignedTorus;
+use crate::commons::utils::{zip, zip_args};
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::numeric::Numeric;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    GlweDimension, GlweSize, LweDimension, LweSize, MonomialDegree, PolynomialSize,
+};
+#[cfg(feature = "__commons_parallel")]
+use rayon::{iter::IndexedParallelIterator, prelude::*};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A seeded packing keyswitching key.
+///
+/// This key only stores the bodies of the GLWE ciphertexts of a [`LwePackingKeyswitchKey`], the
+/// masks being regenerated from the compression seed when the key is expanded.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededPackingKeyswitchKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    output_glwe_size: GlweSize,
+    output_polynomial_size: PolynomialSize,
+    compression_seed: CompressionSeed,
+}
+
+tensor_traits!(LweSeededPackingKeyswitchKey);
+
+impl<Scalar> LweSeededPackingKeyswitchKey<Vec<Scalar>>
+where
+    Scalar: Copy + Numeric,
+{
+    /// Allocates a seeded packing keyswitching key, the underlying container has a size of
+    /// `decomp_size * output_polynomial_size * input_dimension`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a seeded packing keyswitch key, but merely allocates a
+    /// container of the right size. See
+    /// [`LweSeededPackingKeyswitchKey::fill_with_seeded_packing_keyswitch_key`] to fill the
+    /// container with a proper seeded packing keyswitching key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::LweSeededPackingKeyswitchKey;
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// let compression_seed = CompressionSeed { seed: Seed(42) };
+    /// let pksk = LweSeededPackingKeyswitchKey::<Vec<u32>>::allocate(
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     compression_seed,
+    /// );
+    /// assert_eq!(
+    ///     pksk.decomposition_level_count(),
+    ///     DecompositionLevelCount(10)
+    /// );
+    /// assert_eq!(pksk.decomposition_base_log(), DecompositionBaseLog(16));
+    /// assert_eq!(pksk.output_glwe_key_dimension(), GlweDimension(2));
+    /// assert_eq!(pksk.output_polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(pksk.input_lwe_key_dimension(), LweDimension(10));
+    /// assert_eq!(pksk.compression_seed(), compression_seed);
+    /// ```
+    pub fn allocate(
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_dimension: LweDimension,
+        output_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+        compression_seed: CompressionSeed,
+    ) -> Self {
+        LweSeededPackingKeyswitchKey {
+            tensor: Tensor::from_container(vec![
+                Scalar::ZERO;
+                decomp_size.0
+                    * output_polynomial_size.0
+                    * input_dimension.0
+            ]),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            output_glwe_size: output_dimension.to_glwe_size(),
+            output_polynomial_size,
+            compression_seed,
+        }
+    }
+}
+
+impl<Cont> LweSeededPackingKeyswitchKey<Cont> {
+    /// Creates a seeded packing keyswitching key from a container.
+    ///
+    /// # Notes
+    ///
+    /// This method does not create a seeded packing keyswitch key, but merely wraps the container
+    /// in the proper type. It assumes that either the container already contains a proper seeded
+    /// packing keyswitching key, or that
+    /// [`LweSeededPackingKeyswitchKey::fill_with_seeded_packing_keyswitch_key`] will be called
+    /// right after.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::LweSeededPackingKeyswitchKey;
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// let input_size = LweDimension(200);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomp_level_count = DecompositionLevelCount(4);
+    ///
+    /// let pksk = LweSeededPackingKeyswitchKey::from_container(
+    ///     vec![0 as u32; input_size.0 * polynomial_size.0 * decomp_level_count.0],
+    ///     DecompositionBaseLog(7),
+    ///     decomp_level_count,
+    ///     GlweDimension(2),
+    ///     polynomial_size,
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    ///
+    /// assert_eq!(pksk.decomposition_level_count(), DecompositionLevelCount(4));
+    /// assert_eq!(pksk.decomposition_base_log(), DecompositionBaseLog(7));
+    /// assert_eq!(pksk.output_glwe_key_dimension(), GlweDimension(2));
+    /// assert_eq!(pksk.input_lwe_key_dimension(), LweDimension(200));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_size: DecompositionLevelCount,
+        output_glwe_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+        compression_seed: CompressionSeed,
+    ) -> LweSeededPackingKeyswitchKey<Cont>
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() => output_polynomial_size.0, decomp_size.0);
+        LweSeededPackingKeyswitchKey {
+            tensor,
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            output_glwe_size: output_glwe_dimension.to_glwe_size(),
+            output_polynomial_size,
+            compression_seed,
+        }
+    }
+
+    /// Returns the dimension of the output GLWE key.
+    pub fn output_glwe_key_dimension(&self) -> GlweDimension {
+        self.output_glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials composing the output GLWE ciphertexts.
+    pub fn output_polynomial_size(&self) -> PolynomialSize {
+        self.output_polynomial_size
+    }
+
+    /// Returns the dimension of the input LWE key.
+    pub fn input_lwe_key_dimension(&self) -> LweDimension
+    where
+        Self: AsRefTensor,
+    {
+        LweDimension(
+            self.as_tensor().len() / (self.output_polynomial_size.0 * self.decomp_level_count.0),
+        )
+    }
+
+    /// Returns the number of levels used for the decomposition of the input key bits.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the input key bits.
+    ///
+    /// Indeed, the basis used is always of the form $2^b$. This function returns $b$.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the seed used to generate the masks of the key.
+    pub fn compression_seed(&self) -> CompressionSeed {
+        self.compression_seed
+    }
+
+    /// Fills the current seeded packing keyswitch key container with an actual seeded packing
+    /// keyswitching key constructed from an input and an output key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::LweSeededPackingKeyswitchKey;
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::UnixSeeder;
+    ///
+    /// let input_size = LweDimension(10);
+    /// let output_size = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut seeder = UnixSeeder::new(0);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let input_key = LweSecretKey::generate_binary(input_size, &mut secret_generator);
+    /// let output_key =
+    ///     GlweSecretKey::generate_binary(output_size, polynomial_size, &mut secret_generator);
+    ///
+    /// let mut pksk = LweSeededPackingKeyswitchKey::<Vec<u32>>::allocate(
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(3),
+    ///     input_size,
+    ///     output_size,
+    ///     polynomial_size,
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// pksk.fill_with_seeded_packing_keyswitch_key::<_, _, _, _, _, SoftwareRandomGenerator>(
+    ///     &input_key,
+    ///     &output_key,
+    ///     noise,
+    ///     &mut seeder,
+    /// );
+    ///
+    /// assert!(!pksk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_seeded_packing_keyswitch_key<
+        InKeyCont,
+        OutKeyCont,
+        Scalar,
+        NoiseParameter,
+        NoiseSeeder,
+        Gen,
+    >(
+        &mut self,
+        input_lwe_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+        output_glwe_key: &GlweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: NoiseParameter,
+        noise_seeder: &mut NoiseSeeder,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        NoiseParameter: DispersionParameter,
+        NoiseSeeder: Seeder,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.input_lwe_key_dimension().0 => input_lwe_key.key_size().0);
+        ck_dim_eq!(self.output_glwe_key_dimension().0 => output_glwe_key.key_size().0);
+        ck_dim_eq!(self.output_polynomial_size.0 => output_glwe_key.polynomial_size().0);
+
+        let mut generator =
+            EncryptionRandomGenerator::<Gen>::new(self.compression_seed.seed, noise_seeder);
+        let gen_iter = generator
+            .fork_pksk_to_key_bit_decompositions::<Scalar>(
+                input_lwe_key.key_size().0,
+                self.decomp_level_count,
+                self.output_glwe_size,
+                self.output_polynomial_size,
+            )
+            .unwrap();
+
+        let polynomial = constant_polynomial(self.output_polynomial_size);
+        let decomp_base_log = self.decomp_base_log;
+        let decomp_level_count = self.decomp_level_count;
+        let compression_seed = self.compression_seed;
+        let block_size = decomp_level_count.0 * self.output_polynomial_size.0;
+        for zip_args!(input_key_bit, mut block, mut generator) in zip!(
+            input_lwe_key.as_tensor().iter(),
+            self.as_mut_tensor().subtensor_iter_mut(block_size),
+            gen_iter
+        ) {
+            encrypt_seeded_key_bit_decomposition(
+                output_glwe_key,
+                block.as_mut_slice(),
+                *input_key_bit,
+                &polynomial,
+                decomp_base_log,
+                decomp_level_count,
+                compression_seed,
+                noise_parameters,
+                &mut generator,
+            );
+        }
+    }
+
+    /// Fills the current seeded packing keyswitch key container with an actual seeded packing
+    /// keyswitching key constructed from an input and an output key, using all the available
+    /// threads.
+    ///
+    /// # Note
+    ///
+    /// This method uses _rayon_ internally, and is hidden behind the "__commons_parallel" feature
+    /// gate. The masks of the key are the same as the ones obtained with
+    /// [`LweSeededPackingKeyswitchKey::fill_with_seeded_packing_keyswitch_key`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::LweSeededPackingKeyswitchKey;
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::UnixSeeder;
+    ///
+    /// let input_size = LweDimension(10);
+    /// let output_size = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut seeder = UnixSeeder::new(0);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let input_key = LweSecretKey::generate_binary(input_size, &mut secret_generator);
+    /// let output_key =
+    ///     GlweSecretKey::generate_binary(output_size, polynomial_size, &mut secret_generator);
+    ///
+    /// let mut pksk = LweSeededPackingKeyswitchKey::<Vec<u32>>::allocate(
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(3),
+    ///     input_size,
+    ///     output_size,
+    ///     polynomial_size,
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// pksk.par_fill_with_seeded_packing_keyswitch_key::<_, _, _, _, _, SoftwareRandomGenerator>(
+    ///     &input_key,
+    ///     &output_key,
+    ///     noise,
+    ///     &mut seeder,
+    /// );
+    ///
+    /// assert!(!pksk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_fill_with_seeded_packing_keyswitch_key<
+        InKeyCont,
+        OutKeyCont,
+        Scalar,
+        NoiseParameter,
+        NoiseSeeder,
+        Gen,
+    >(
+        &mut self,
+        input_lwe_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+        output_glwe_key: &GlweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: NoiseParameter,
+        noise_seeder: &mut NoiseSeeder,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + Sync + Send,
+        InKeyCont: Sync + Send,
+        OutKeyCont: Sync + Send,
+        NoiseParameter: DispersionParameter + Sync + Send,
+        NoiseSeeder: Seeder,
+        Gen: ParallelByteRandomGenerator,
+    {
+        ck_dim_eq!(self.input_lwe_key_dimension().0 => input_lwe_key.key_size().0);
+        ck_dim_eq!(self.output_glwe_key_dimension().0 => output_glwe_key.key_size().0);
+        ck_dim_eq!(self.output_polynomial_size.0 => output_glwe_key.polynomial_size().0);
+
+        let mut generator =
+            EncryptionRandomGenerator::<Gen>::new(self.compression_seed.seed, noise_seeder);
+        let gen_iter = generator
+            .par_fork_pksk_to_key_bit_decompositions::<Scalar>(
+                input_lwe_key.key_size().0,
+                self.decomp_level_count,
+                self.output_glwe_size,
+                self.output_polynomial_size,
+            )
+            .unwrap();
+
+        let polynomial = constant_polynomial(self.output_polynomial_size);
+        let decomp_base_log = self.decomp_base_log;
+        let decomp_level_count = self.decomp_level_count;
+        let compression_seed = self.compression_seed;
+        let block_size = decomp_level_count.0 * self.output_polynomial_size.0;
+        self.as_mut_tensor()
+            .par_subtensor_iter_mut(block_size)
+            .zip(input_lwe_key.as_tensor().par_iter())
+            .zip(gen_iter)
+            .for_each(|((mut block, input_key_bit), mut generator)| {
+                encrypt_seeded_key_bit_decomposition(
+                    output_glwe_key,
+                    block.as_mut_slice(),
+                    *input_key_bit,
+                    &polynomial,
+                    decomp_base_log,
+                    decomp_level_count,
+                    compression_seed,
+                    noise_parameters,
+                    &mut generator,
+                );
+            });
+    }
+
+    /// Expands the current seeded packing keyswitch key into a packing keyswitch key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::{
+    ///     LwePackingKeyswitchKey, LweSeededPackingKeyswitchKey,
+    /// };
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::UnixSeeder;
+    ///
+    /// let input_size = LweDimension(10);
+    /// let output_size = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut seeder = UnixSeeder::new(0);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let input_key = LweSecretKey::generate_binary(input_size, &mut secret_generator);
+    /// let output_key =
+    ///     GlweSecretKey::generate_binary(output_size, polynomial_size, &mut secret_generator);
+    ///
+    /// let mut seeded_pksk = LweSeededPackingKeyswitchKey::<Vec<u32>>::allocate(
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(3),
+    ///     input_size,
+    ///     output_size,
+    ///     polynomial_size,
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// seeded_pksk.fill_with_seeded_packing_keyswitch_key::<_, _, _, _, _, SoftwareRandomGenerator>(
+    ///     &input_key,
+    ///     &output_key,
+    ///     noise,
+    ///     &mut seeder,
+    /// );
+    ///
+    /// let mut pksk = LwePackingKeyswitchKey::allocate(
+    ///     0u32,
+    ///     seeded_pksk.decomposition_level_count(),
+    ///     seeded_pksk.decomposition_base_log(),
+    ///     seeded_pksk.input_lwe_key_dimension(),
+    ///     seeded_pksk.output_glwe_key_dimension(),
+    ///     seeded_pksk.output_polynomial_size(),
+    /// );
+    /// seeded_pksk.expand_into::<_, _, SoftwareRandomGenerator>(&mut pksk);
+    ///
+    /// assert_eq!(pksk.input_lwe_key_dimension(), input_size);
+    /// assert!(!pksk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn expand_into<OutCont, Scalar, Gen>(self, output: &mut LwePackingKeyswitchKey<OutCont>)
+    where
+        LwePackingKeyswitchKey<OutCont>: AsMutTensor<Element = Scalar>,
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: Copy + RandomGenerable<Uniform> + Numeric,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.output_polynomial_size.0 => output.output_polynomial_size().0);
+        ck_dim_eq!(self.output_glwe_key_dimension().0 => output.output_glwe_key_dimension().0);
+        ck_dim_eq!(self.decomp_level_count.0 => output.decomposition_level_count().0);
+        ck_dim_eq!(self.as_tensor().len() * self.output_glwe_size.0 => output.as_tensor().len());
+
+        expand_seeded_glwe_bodies::<_, Gen>(
+            self.as_tensor().as_slice(),
+            output.as_mut_tensor().as_mut_slice(),
+            self.output_glwe_size,
+            self.output_polynomial_size,
+            self.compression_seed,
+        );
+    }
+}
+
+/// A seeded private functional packing keyswitching key.
+///
+/// This key only stores the bodies of the GLWE ciphertexts of a
+/// [`LwePrivateFunctionalPackingKeyswitchKey`], the masks being regenerated from the compression
+/// seed when the key is expanded.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededPrivateFunctionalPackingKeyswitchKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    output_glwe_size: GlweSize,
+    output_polynomial_size: PolynomialSize,
+    compression_seed: CompressionSeed,
+}
+
+tensor_traits!(LweSeededPrivateFunctionalPackingKeyswitchKey);
+
+impl<Scalar> LweSeededPrivateFunctionalPackingKeyswitchKey<Vec<Scalar>>
+where
+    Scalar: Copy + Numeric,
+{
+    /// Allocates a seeded private functional packing keyswitching key, the underlying container
+    /// has a size of `decomp_size * output_polynomial_size * (input_dimension + 1)`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a seeded private functional packing keyswitch key, but
+    /// merely allocates a container of the right size. See
+    /// [`LweSeededPrivateFunctionalPackingKeyswitchKey::fill_with_seeded_private_functional_packing_keyswitch_key`]
+    /// to fill the container with a proper key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::LweSeededPrivateFunctionalPackingKeyswitchKey;
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// let compression_seed = CompressionSeed { seed: Seed(42) };
+    /// let pfpksk = LweSeededPrivateFunctionalPackingKeyswitchKey::<Vec<u32>>::allocate(
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     compression_seed,
+    /// );
+    /// assert_eq!(
+    ///     pfpksk.decomposition_level_count(),
+    ///     DecompositionLevelCount(10)
+    /// );
+    /// assert_eq!(pfpksk.decomposition_base_log(), DecompositionBaseLog(16));
+    /// assert_eq!(pfpksk.output_glwe_key_dimension(), GlweDimension(2));
+    /// assert_eq!(pfpksk.output_polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(pfpksk.input_lwe_key_dimension(), LweDimension(10));
+    /// assert_eq!(pfpksk.compression_seed(), compression_seed);
+    /// ```
+    pub fn allocate(
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_dimension: LweDimension,
+        output_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+        compression_seed: CompressionSeed,
+    ) -> Self {
+        LweSeededPrivateFunctionalPackingKeyswitchKey {
+            tensor: Tensor::from_container(vec![
+                Scalar::ZERO;
+                decomp_size.0
+                    * output_polynomial_size.0
+                    * input_dimension.to_lwe_size().0
+            ]),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            output_glwe_size: output_dimension.to_glwe_size(),
+            output_polynomial_size,
+            compression_seed,
+        }
+    }
+}
+
+impl<Cont> LweSeededPrivateFunctionalPackingKeyswitchKey<Cont> {
+    /// Creates a seeded private functional packing keyswitching key from a container.
+    ///
+    /// # Notes
+    ///
+    /// This method does not create a seeded private functional packing keyswitch key, but merely
+    /// wraps the container in the proper type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::LweSeededPrivateFunctionalPackingKeyswitchKey;
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// let input_size = LweDimension(200);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomp_level_count = DecompositionLevelCount(4);
+    ///
+    /// let pfpksk = LweSeededPrivateFunctionalPackingKeyswitchKey::from_container(
+    ///     vec![0 as u32; input_size.to_lwe_size().0 * polynomial_size.0 * decomp_level_count.0],
+    ///     DecompositionBaseLog(7),
+    ///     decomp_level_count,
+    ///     GlweDimension(2),
+    ///     polynomial_size,
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    ///
+    /// assert_eq!(pfpksk.decomposition_level_count(), DecompositionLevelCount(4));
+    /// assert_eq!(pfpksk.decomposition_base_log(), DecompositionBaseLog(7));
+    /// assert_eq!(pfpksk.output_glwe_key_dimension(), GlweDimension(2));
+    /// assert_eq!(pfpksk.input_lwe_key_dimension(), LweDimension(200));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_size: DecompositionLevelCount,
+        output_glwe_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+        compression_seed: CompressionSeed,
+    ) -> LweSeededPrivateFunctionalPackingKeyswitchKey<Cont>
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() => output_polynomial_size.0, decomp_size.0);
+        LweSeededPrivateFunctionalPackingKeyswitchKey {
+            tensor,
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            output_glwe_size: output_glwe_dimension.to_glwe_size(),
+            output_polynomial_size,
+            compression_seed,
+        }
+    }
+
+    /// Returns the dimension of the output GLWE key.
+    pub fn output_glwe_key_dimension(&self) -> GlweDimension {
+        self.output_glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials composing the output GLWE ciphertexts.
+    pub fn output_polynomial_size(&self) -> PolynomialSize {
+        self.output_polynomial_size
+    }
+
+    /// Returns the dimension of the input LWE key.
+    pub fn input_lwe_key_dimension(&self) -> LweDimension
+    where
+        Self: AsRefTensor,
+    {
+        LweDimension(
+            self.as_tensor().len() / (self.output_polynomial_size.0 * self.decomp_level_count.0)
+                - 1,
+        )
+    }
+
+    /// Returns the number of levels used for the decomposition of the input key bits.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the input key bits.
+    ///
+    /// Indeed, the basis used is always of the form $2^b$. This function returns $b$.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the seed used to generate the masks of the key.
+    pub fn compression_seed(&self) -> CompressionSeed {
+        self.compression_seed
+    }
+
+    /// Fills the current seeded private functional packing keyswitch key container with an actual
+    /// key constructed from an input and an output key, and the function `x -> f(x) * polynomial`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::LweSeededPrivateFunctionalPackingKeyswitchKey;
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::commons::math::polynomial::Polynomial;
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::UnixSeeder;
+    ///
+    /// let input_size = LweDimension(10);
+    /// let output_size = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut seeder = UnixSeeder::new(0);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let input_key = LweSecretKey::generate_binary(input_size, &mut secret_generator);
+    /// let output_key =
+    ///     GlweSecretKey::generate_binary(output_size, polynomial_size, &mut secret_generator);
+    ///
+    /// let mut pfpksk = LweSeededPrivateFunctionalPackingKeyswitchKey::<Vec<u32>>::allocate(
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(3),
+    ///     input_size,
+    ///     output_size,
+    ///     polynomial_size,
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// pfpksk.fill_with_seeded_private_functional_packing_keyswitch_key::<
+    ///     _,
+    ///     _,
+    ///     _,
+    ///     _,
+    ///     _,
+    ///     _,
+    ///     SoftwareRandomGenerator,
+    /// >(
+    ///     &input_key,
+    ///     &output_key,
+    ///     noise,
+    ///     &mut seeder,
+    ///     &|x| x,
+    ///     &Polynomial::allocate(1 as u32, polynomial_size),
+    /// );
+    ///
+    /// assert!(!pfpksk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn fill_with_seeded_private_functional_packing_keyswitch_key<
+        InKeyCont,
+        OutKeyCont,
+        PolyCont,
+        Scalar,
+        NoiseParameter,
+        NoiseSeeder,
+        Gen,
+    >(
+        &mut self,
+        input_lwe_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+        output_glwe_key: &GlweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: NoiseParameter,
+        noise_seeder: &mut NoiseSeeder,
+        f: &dyn Fn(Scalar) -> Scalar,
+        polynomial: &Polynomial<PolyCont>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        NoiseParameter: DispersionParameter,
+        NoiseSeeder: Seeder,
+        Gen: ByteRandomGenerator,
+    {
+        let mut generator =
+            EncryptionRandomGenerator::<Gen>::new(self.compression_seed.seed, noise_seeder);
+        self.fill_with_seeded_private_functional_packing_keyswitch_key_with_existing_generator(
+            input_lwe_key,
+            output_glwe_key,
+            noise_parameters,
+            &mut generator,
+            f,
+            polynomial,
+        );
+    }
+
+    // Fills the key using a generator already seeded with the compression seed of the key.
+    fn fill_with_seeded_private_functional_packing_keyswitch_key_with_existing_generator<
+        InKeyCont,
+        OutKeyCont,
+        PolyCont,
+        Scalar,
+        NoiseParameter,
+        Gen,
+    >(
+        &mut self,
+        input_lwe_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+        output_glwe_key: &GlweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: NoiseParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+        f: &dyn Fn(Scalar) -> Scalar,
+        polynomial: &Polynomial<PolyCont>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        NoiseParameter: DispersionParameter,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.input_lwe_key_dimension().0 => input_lwe_key.key_size().0);
+        ck_dim_eq!(self.output_glwe_key_dimension().0 => output_glwe_key.key_size().0);
+        ck_dim_eq!(self.output_polynomial_size.0 => output_glwe_key.polynomial_size().0);
+        ck_dim_eq!(self.output_polynomial_size.0 => polynomial.polynomial_size().0);
+
+        let input_key_bits = pfpksk_input_key_bits(input_lwe_key, f);
+        let gen_iter = generator
+            .fork_pksk_to_key_bit_decompositions::<Scalar>(
+                input_key_bits.len(),
+                self.decomp_level_count,
+                self.output_glwe_size,
+                self.output_polynomial_size,
+            )
+            .unwrap();
+
+        let decomp_base_log = self.decomp_base_log;
+        let decomp_level_count = self.decomp_level_count;
+        let compression_seed = self.compression_seed;
+        let block_size = decomp_level_count.0 * self.output_polynomial_size.0;
+        for zip_args!(input_key_bit, mut block, mut generator) in zip!(
+            input_key_bits.iter(),
+            self.as_mut_tensor().subtensor_iter_mut(block_size),
+            gen_iter
+        ) {
+            encrypt_seeded_key_bit_decomposition(
+                output_glwe_key,
+                block.as_mut_slice(),
+                *input_key_bit,
+                polynomial,
+                decomp_base_log,
+                decomp_level_count,
+                compression_seed,
+                noise_parameters,
+                &mut generator,
+            );
+        }
+    }
+
+    /// Fills the current seeded private functional packing keyswitch key container with an actual
+    /// key constructed from an input and an output key, and the function `x -> f(x) * polynomial`,
+    /// using all the available threads.
+    ///
+    /// # Note
+    ///
+    /// This method uses _rayon_ internally, and is hidden behind the "__commons_parallel" feature
+    /// gate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::LweSeededPrivateFunctionalPackingKeyswitchKey;
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::commons::math::polynomial::Polynomial;
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::UnixSeeder;
+    ///
+    /// let input_size = LweDimension(10);
+    /// let output_size = GlweDimension(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut seeder = UnixSeeder::new(0);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let input_key = LweSecretKey::generate_binary(input_size, &mut secret_generator);
+    /// let output_key =
+    ///     GlweSecretKey::generate_binary(output_size, polynomial_size, &mut secret_generator);
+    ///
+    /// let mut pfpksk = LweSeededPrivateFunctionalPackingKeyswitchKey::<Vec<u32>>::allocate(
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(3),
+    ///     input_size,
+    ///     output_size,
+    ///     polynomial_size,
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// pfpksk.par_fill_with_seeded_private_functional_packing_keyswitch_key::<
+    ///     _,
+    ///     _,
+    ///     _,
+    ///     _,
+    ///     _,
+    ///     _,
+    ///     SoftwareRandomGenerator,
+    /// >(
+    ///     &input_key,
+    ///     &output_key,
+    ///     noise,
+    ///     &mut seeder,
+    ///     &|x| x,
+    ///     &Polynomial::allocate(1 as u32, polynomial_size),
+    /// );
+    ///
+    /// assert!(!pfpksk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    #[cfg(feature = "__commons_parallel")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn par_fill_with_seeded_private_functional_packing_keyswitch_key<
+        InKeyCont,
+        OutKeyCont,
+        PolyCont,
+        Scalar,
+        NoiseParameter,
+        NoiseSeeder,
+        Gen,
+    >(
+        &mut self,
+        input_lwe_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+        output_glwe_key: &GlweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: NoiseParameter,
+        noise_seeder: &mut NoiseSeeder,
+        f: &dyn Fn(Scalar) -> Scalar,
+        polynomial: &Polynomial<PolyCont>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + Sync + Send,
+        OutKeyCont: Sync + Send,
+        PolyCont: Sync + Send,
+        NoiseParameter: DispersionParameter + Sync + Send,
+        NoiseSeeder: Seeder,
+        Gen: ParallelByteRandomGenerator,
+    {
+        let mut generator =
+            EncryptionRandomGenerator::<Gen>::new(self.compression_seed.seed, noise_seeder);
+        self.par_fill_with_seeded_private_functional_packing_keyswitch_key_with_existing_generator(
+            input_lwe_key,
+            output_glwe_key,
+            noise_parameters,
+            &mut generator,
+            f,
+            polynomial,
+        );
+    }
+
+    // Fills the key in parallel, using a generator already seeded with the compression seed of
+    // the key.
+    #[cfg(feature = "__commons_parallel")]
+    fn par_fill_with_seeded_private_functional_packing_keyswitch_key_with_existing_generator<
+        InKeyCont,
+        OutKeyCont,
+        PolyCont,
+        Scalar,
+        NoiseParameter,
+        Gen,
+    >(
+        &mut self,
+        input_lwe_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+        output_glwe_key: &GlweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: NoiseParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+        f: &dyn Fn(Scalar) -> Scalar,
+        polynomial: &Polynomial<PolyCont>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + Sync + Send,
+        OutKeyCont: Sync + Send,
+        PolyCont: Sync + Send,
+        NoiseParameter: DispersionParameter + Sync + Send,
+        Gen: ParallelByteRandomGenerator,
+    {
+        ck_dim_eq!(self.input_lwe_key_dimension().0 => input_lwe_key.key_size().0);
+        ck_dim_eq!(self.output_glwe_key_dimension().0 => output_glwe_key.key_size().0);
+        ck_dim_eq!(self.output_polynomial_size.0 => output_glwe_key.polynomial_size().0);
+        ck_dim_eq!(self.output_polynomial_size.0 => polynomial.polynomial_size().0);
+
+        let input_key_bits = pfpksk_input_key_bits(input_lwe_key, f);
+        let gen_iter = generator
+            .par_fork_pksk_to_key_bit_decompositions::<Scalar>(
+                input_key_bits.len(),
+                self.decomp_level_count,
+                self.output_glwe_size,
+                self.output_polynomial_size,
+            )
+            .unwrap();
+
+        let decomp_base_log = self.decomp_base_log;
+        let decomp_level_count = self.decomp_level_count;
+        let compression_seed = self.compression_seed;
+        let block_size = decomp_level_count.0 * self.output_polynomial_size.0;
+        self.as_mut_tensor()
+            .par_subtensor_iter_mut(block_size)
+            .zip(input_key_bits.par_iter())
+            .zip(gen_iter)
+            .for_each(|((mut block, input_key_bit), mut generator)| {
+                encrypt_seeded_key_bit_decomposition(
+                    output_glwe_key,
+                    block.as_mut_slice(),
+                    *input_key_bit,
+                    polynomial,
+                    decomp_base_log,
+                    decomp_level_count,
+                    compression_seed,
+                    noise_parameters,
+                    &mut generator,
+                );
+            });
+    }
+
+    /// Expands the current seeded private functional packing keyswitch key into a private
+    /// functional packing keyswitch key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::{
+    ///     LwePrivateFunctionalPackingKeyswitchKey, LweSeededPrivateFunctionalPackingKeyswitchKey,
+    /// };
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    ///
+    /// let seeded_pfpksk = LweSeededPrivateFunctionalPackingKeyswitchKey::<Vec<u32>>::allocate(
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(3),
+    ///     LweDimension(10),
+    ///     GlweDimension(3),
+    ///     PolynomialSize(256),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    ///
+    /// let mut pfpksk = LwePrivateFunctionalPackingKeyswitchKey::allocate(
+    ///     0u32,
+    ///     seeded_pfpksk.decomposition_level_count(),
+    ///     seeded_pfpksk.decomposition_base_log(),
+    ///     seeded_pfpksk.input_lwe_key_dimension(),
+    ///     seeded_pfpksk.output_glwe_key_dimension(),
+    ///     seeded_pfpksk.output_polynomial_size(),
+    /// );
+    /// seeded_pfpksk.expand_into::<_, _, SoftwareRandomGenerator>(&mut pfpksk);
+    ///
+    /// assert_eq!(pfpksk.input_lwe_key_dimension(), LweDimension(10));
+    /// ```
+    pub fn expand_into<OutCont, Scalar, Gen>(
+        self,
+        output: &mut LwePrivateFunctionalPackingKeyswitchKey<OutCont>,
+    ) where
+        LwePrivateFunctionalPackingKeyswitchKey<OutCont>: AsMutTensor<Element = Scalar>,
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: Copy + RandomGenerable<Uniform> + Numeric,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.output_polynomial_size.0 => output.output_polynomial_size().0);
+        ck_dim_eq!(self.output_glwe_key_dimension().0 => output.output_glwe_key_dimension().0);
+        ck_dim_eq!(self.decomp_level_count.0 => output.decomposition_level_count().0);
+        ck_dim_eq!(self.as_tensor().len() * self.output_glwe_size.0 => output.as_tensor().len());
+
+        expand_seeded_glwe_bodies::<_, Gen>(
+            self.as_tensor().as_slice(),
+            output.as_mut_tensor().as_mut_slice(),
+            self.output_glwe_size,
+            self.output_polynomial_size,
+            self.compression_seed,
+        );
+    }
+}
+
+/// A seeded private functional packing keyswitching key list.
+///
+/// This list only stores the bodies of the GLWE ciphertexts of a
+/// [`LwePrivateFunctionalPackingKeyswitchKeyList`], the masks being regenerated from the
+/// compression seed when the list is expanded. All the keys of the list share the same
+/// compression seed.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededPrivateFunctionalPackingKeyswitchKeyList<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    input_lwe_size: LweSize,
+    output_glwe_size: GlweSize,
+    output_polynomial_size: PolynomialSize,
+    compression_seed: CompressionSeed,
+}
+
+tensor_traits!(LweSeededPrivateFunctionalPackingKeyswitchKeyList);
+
+impl<Scalar> LweSeededPrivateFunctionalPackingKeyswitchKeyList<Vec<Scalar>>
+where
+    Scalar: Copy + Numeric,
+{
+    /// Allocates storage for an owned [`LweSeededPrivateFunctionalPackingKeyswitchKeyList`].
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a seeded private functional packing keyswitch key list,
+    /// but merely allocates a container of the right size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::LweSeededPrivateFunctionalPackingKeyswitchKeyList;
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// let input_lwe_dim = LweDimension(200);
+    /// let output_glwe_dim = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomp_base_log = DecompositionBaseLog(7);
+    /// let decomp_level_count = DecompositionLevelCount(4);
+    /// let fpksk_count = FunctionalPackingKeyswitchKeyCount(3);
+    /// let compression_seed = CompressionSeed { seed: Seed(42) };
+    ///
+    /// let pfpksk_list = LweSeededPrivateFunctionalPackingKeyswitchKeyList::<Vec<u32>>::allocate(
+    ///     decomp_level_count,
+    ///     decomp_base_log,
+    ///     input_lwe_dim,
+    ///     output_glwe_dim,
+    ///     polynomial_size,
+    ///     fpksk_count,
+    ///     compression_seed,
+    /// );
+    ///
+    /// assert_eq!(pfpksk_list.decomposition_level_count(), decomp_level_count);
+    /// assert_eq!(pfpksk_list.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(pfpksk_list.output_glwe_key_dimension(), output_glwe_dim);
+    /// assert_eq!(pfpksk_list.output_polynomial_size(), polynomial_size);
+    /// assert_eq!(pfpksk_list.input_lwe_key_dimension(), input_lwe_dim);
+    /// assert_eq!(pfpksk_list.fpksk_count(), fpksk_count);
+    /// assert_eq!(pfpksk_list.compression_seed(), compression_seed);
+    /// ```
+    pub fn allocate(
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_dimension: LweDimension,
+        output_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+        fpksk_count: FunctionalPackingKeyswitchKeyCount,
+        compression_seed: CompressionSeed,
+    ) -> Self {
+        LweSeededPrivateFunctionalPackingKeyswitchKeyList {
+            tensor: Tensor::from_container(vec![
+                Scalar::ZERO;
+                decomp_size.0
+                    * output_polynomial_size.0
+                    * input_dimension.to_lwe_size().0
+                    * fpksk_count.0
+            ]),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            input_lwe_size: input_dimension.to_lwe_size(),
+            output_glwe_size: output_dimension.to_glwe_size(),
+            output_polynomial_size,
+            compression_seed,
+        }
+    }
+}
+
+impl<Cont> LweSeededPrivateFunctionalPackingKeyswitchKeyList<Cont> {
+    /// Creates a list from a container of values.
+    ///
+    /// # Notes
+    ///
+    /// This method does not create a seeded private functional packing keyswitch key list, but
+    /// merely wraps the container in the proper type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::LweSeededPrivateFunctionalPackingKeyswitchKeyList;
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// let input_lwe_dim = LweDimension(200);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomp_level_count = DecompositionLevelCount(4);
+    /// let fpksk_count = FunctionalPackingKeyswitchKeyCount(3);
+    ///
+    /// let pfpksk_list = LweSeededPrivateFunctionalPackingKeyswitchKeyList::from_container(
+    ///     vec![
+    ///         0 as u32;
+    ///         input_lwe_dim.to_lwe_size().0 * polynomial_size.0 * decomp_level_count.0 * fpksk_count.0
+    ///     ],
+    ///     DecompositionBaseLog(7),
+    ///     decomp_level_count,
+    ///     input_lwe_dim,
+    ///     GlweDimension(2),
+    ///     polynomial_size,
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    ///
+    /// assert_eq!(pfpksk_list.input_lwe_key_dimension(), input_lwe_dim);
+    /// assert_eq!(pfpksk_list.fpksk_count(), fpksk_count);
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_size: DecompositionLevelCount,
+        input_dimension: LweDimension,
+        output_glwe_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+        compression_seed: CompressionSeed,
+    ) -> LweSeededPrivateFunctionalPackingKeyswitchKeyList<Cont>
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() =>
+            output_polynomial_size.0,
+            decomp_size.0,
+            input_dimension.to_lwe_size().0);
+        LweSeededPrivateFunctionalPackingKeyswitchKeyList {
+            tensor,
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            input_lwe_size: input_dimension.to_lwe_size(),
+            output_glwe_size: output_glwe_dimension.to_glwe_size(),
+            output_polynomial_size,
+            compression_seed,
+        }
+    }
+
+    /// Returns the dimension of the output GLWE key.
+    pub fn output_glwe_key_dimension(&self) -> GlweDimension {
+        self.output_glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials composing the output GLWE ciphertexts.
+    pub fn output_polynomial_size(&self) -> PolynomialSize {
+        self.output_polynomial_size
+    }
+
+    /// Returns the dimension of the input LWE key.
+    pub fn input_lwe_key_dimension(&self) -> LweDimension {
+        self.input_lwe_size.to_lwe_dimension()
+    }
+
+    /// Returns the number of levels used for the decomposition of the input key bits.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the input key bits.
+    ///
+    /// Indeed, the basis used is always of the form $2^b$. This function returns $b$.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the seed used to generate the masks of the keys.
+    pub fn compression_seed(&self) -> CompressionSeed {
+        self.compression_seed
+    }
+
+    /// Returns the number of seeded private functional packing keyswitch keys in the list.
+    pub fn fpksk_count(&self) -> FunctionalPackingKeyswitchKeyCount
+    where
+        Self: AsRefTensor,
+    {
+        let single_ksk_size = self.single_fpksk_size();
+        ck_dim_div!(self.as_tensor().len() => single_ksk_size);
+        FunctionalPackingKeyswitchKeyCount(self.as_tensor().len() / single_ksk_size)
+    }
+
+    /// Fills the current list with the seeded private functional packing keyswitch keys needed
+    /// to perform a circuit bootstrap.
+    ///
+    /// The first keys of the list multiply the input by the opposite of each polynomial of the
+    /// output GLWE key, and the last one is the identity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::LweSeededPrivateFunctionalPackingKeyswitchKeyList;
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::UnixSeeder;
+    ///
+    /// let input_size = LweDimension(10);
+    /// let output_size = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut seeder = UnixSeeder::new(0);
+    ///
+    /// let input_key = LweSecretKey::generate_binary(input_size, &mut secret_generator);
+    /// let output_key =
+    ///     GlweSecretKey::generate_binary(output_size, polynomial_size, &mut secret_generator);
+    ///
+    /// let mut pfpksk_list = LweSeededPrivateFunctionalPackingKeyswitchKeyList::<Vec<u32>>::allocate(
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(3),
+    ///     input_size,
+    ///     output_size,
+    ///     polynomial_size,
+    ///     FunctionalPackingKeyswitchKeyCount(output_size.to_glwe_size().0),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// pfpksk_list.fill_with_seeded_fpksk_for_circuit_bootstrap::<_, _, _, _, _, SoftwareRandomGenerator>(
+    ///     &input_key,
+    ///     &output_key,
+    ///     LogStandardDev::from_log_standard_dev(-15.),
+    ///     &mut seeder,
+    /// );
+    ///
+    /// assert!(!pfpksk_list.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_seeded_fpksk_for_circuit_bootstrap<
+        InKeyCont,
+        OutKeyCont,
+        Scalar,
+        NoiseParameter,
+        NoiseSeeder,
+        Gen,
+    >(
+        &mut self,
+        input_lwe_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+        output_glwe_key: &GlweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: NoiseParameter,
+        noise_seeder: &mut NoiseSeeder,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        NoiseParameter: DispersionParameter,
+        NoiseSeeder: Seeder,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.fpksk_count().0 => output_glwe_key.key_size().to_glwe_size().0);
+
+        let mut generator =
+            EncryptionRandomGenerator::<Gen>::new(self.compression_seed.seed, noise_seeder);
+        let gen_iter = generator
+            .fork_pfpksk_list_to_pfpksk::<Scalar>(
+                self.fpksk_count(),
+                self.input_lwe_size.0,
+                self.decomp_level_count,
+                self.output_glwe_size,
+                self.output_polynomial_size,
+            )
+            .unwrap();
+
+        let polynomials = circuit_bootstrap_polynomials(output_glwe_key);
+        let functions = circuit_bootstrap_functions(output_glwe_key.key_size());
+        let single_ksk_size = self.single_fpksk_size();
+        let decomp_base_log = self.decomp_base_log;
+        let decomp_level_count = self.decomp_level_count;
+        let output_glwe_dimension = self.output_glwe_key_dimension();
+        let output_polynomial_size = self.output_polynomial_size;
+        let compression_seed = self.compression_seed;
+        for zip_args!(mut fpksk, polynomial, f, mut generator) in zip!(
+            self.as_mut_tensor().subtensor_iter_mut(single_ksk_size),
+            polynomials.iter(),
+            functions.iter(),
+            gen_iter
+        ) {
+            LweSeededPrivateFunctionalPackingKeyswitchKey::from_container(
+                fpksk.as_mut_slice(),
+                decomp_base_log,
+                decomp_level_count,
+                output_glwe_dimension,
+                output_polynomial_size,
+                compression_seed,
+            )
+            .fill_with_seeded_private_functional_packing_keyswitch_key_with_existing_generator(
+                input_lwe_key,
+                output_glwe_key,
+                noise_parameters,
+                &mut generator,
+                f,
+                polynomial,
+            );
+        }
+    }
+
+    /// Fills the current list with the seeded private functional packing keyswitch keys needed
+    /// to perform a circuit bootstrap, using all the available threads.
+    ///
+    /// # Note
+    ///
+    /// This method uses _rayon_ internally, and is hidden behind the "__commons_parallel" feature
+    /// gate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::LweSeededPrivateFunctionalPackingKeyswitchKeyList;
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::UnixSeeder;
+    ///
+    /// let input_size = LweDimension(10);
+    /// let output_size = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut seeder = UnixSeeder::new(0);
+    ///
+    /// let input_key = LweSecretKey::generate_binary(input_size, &mut secret_generator);
+    /// let output_key =
+    ///     GlweSecretKey::generate_binary(output_size, polynomial_size, &mut secret_generator);
+    ///
+    /// let mut pfpksk_list = LweSeededPrivateFunctionalPackingKeyswitchKeyList::<Vec<u32>>::allocate(
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(3),
+    ///     input_size,
+    ///     output_size,
+    ///     polynomial_size,
+    ///     FunctionalPackingKeyswitchKeyCount(output_size.to_glwe_size().0),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    /// pfpksk_list
+    ///     .par_fill_with_seeded_fpksk_for_circuit_bootstrap::<_, _, _, _, _, SoftwareRandomGenerator>(
+    ///         &input_key,
+    ///         &output_key,
+    ///         LogStandardDev::from_log_standard_dev(-15.),
+    ///         &mut seeder,
+    ///     );
+    ///
+    /// assert!(!pfpksk_list.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_fill_with_seeded_fpksk_for_circuit_bootstrap<
+        InKeyCont,
+        OutKeyCont,
+        Scalar,
+        NoiseParameter,
+        NoiseSeeder,
+        Gen,
+    >(
+        &mut self,
+        input_lwe_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+        output_glwe_key: &GlweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: NoiseParameter,
+        noise_seeder: &mut NoiseSeeder,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + Sync + Send,
+        InKeyCont: Sync + Send,
+        OutKeyCont: Sync + Send,
+        NoiseParameter: DispersionParameter + Sync + Send,
+        NoiseSeeder: Seeder,
+        Gen: ParallelByteRandomGenerator,
+    {
+        ck_dim_eq!(self.fpksk_count().0 => output_glwe_key.key_size().to_glwe_size().0);
+
+        let mut generator =
+            EncryptionRandomGenerator::<Gen>::new(self.compression_seed.seed, noise_seeder);
+        let gen_iter = generator
+            .par_fork_pfpksk_list_to_pfpksk::<Scalar>(
+                self.fpksk_count(),
+                self.input_lwe_size.0,
+                self.decomp_level_count,
+                self.output_glwe_size,
+                self.output_polynomial_size,
+            )
+            .unwrap();
+
+        let polynomials = circuit_bootstrap_polynomials(output_glwe_key);
+        let functions = circuit_bootstrap_functions(output_glwe_key.key_size());
+        let single_ksk_size = self.single_fpksk_size();
+        let decomp_base_log = self.decomp_base_log;
+        let decomp_level_count = self.decomp_level_count;
+        let output_glwe_dimension = self.output_glwe_key_dimension();
+        let output_polynomial_size = self.output_polynomial_size;
+        let compression_seed = self.compression_seed;
+        self.as_mut_tensor()
+            .par_subtensor_iter_mut(single_ksk_size)
+            .zip(polynomials.par_iter())
+            .zip(functions.par_iter())
+            .zip(gen_iter)
+            .for_each(|(((mut fpksk, polynomial), f), mut generator)| {
+                LweSeededPrivateFunctionalPackingKeyswitchKey::from_container(
+                    fpksk.as_mut_slice(),
+                    decomp_base_log,
+                    decomp_level_count,
+                    output_glwe_dimension,
+                    output_polynomial_size,
+                    compression_seed,
+                )
+                .par_fill_with_seeded_private_functional_packing_keyswitch_key_with_existing_generator(
+                    input_lwe_key,
+                    output_glwe_key,
+                    noise_parameters,
+                    &mut generator,
+                    f,
+                    polynomial,
+                );
+            });
+    }
+
+    /// Expands the current seeded list into a private functional packing keyswitch key list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::glwe::{
+    ///     LwePrivateFunctionalPackingKeyswitchKeyList,
+    ///     LweSeededPrivateFunctionalPackingKeyswitchKeyList,
+    /// };
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    ///
+    /// let seeded_list = LweSeededPrivateFunctionalPackingKeyswitchKeyList::<Vec<u32>>::allocate(
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(3),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     FunctionalPackingKeyswitchKeyCount(3),
+    ///     CompressionSeed { seed: Seed(42) },
+    /// );
+    ///
+    /// let mut list = LwePrivateFunctionalPackingKeyswitchKeyList::allocate(
+    ///     0u32,
+    ///     seeded_list.decomposition_level_count(),
+    ///     seeded_list.decomposition_base_log(),
+    ///     seeded_list.input_lwe_key_dimension(),
+    ///     seeded_list.output_glwe_key_dimension(),
+    ///     seeded_list.output_polynomial_size(),
+    ///     seeded_list.fpksk_count(),
+    /// );
+    /// seeded_list.expand_into::<_, _, SoftwareRandomGenerator>(&mut list);
+    ///
+    /// assert_eq!(list.fpksk_count(), FunctionalPackingKeyswitchKeyCount(3));
+    /// ```
+    pub fn expand_into<OutCont, Scalar, Gen>(
+        self,
+        output: &mut LwePrivateFunctionalPackingKeyswitchKeyList<OutCont>,
+    ) where
+        LwePrivateFunctionalPackingKeyswitchKeyList<OutCont>: AsMutTensor<Element = Scalar>,
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: Copy + RandomGenerable<Uniform> + Numeric,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(self.output_polynomial_size.0 => output.output_polynomial_size().0);
+        ck_dim_eq!(self.output_glwe_key_dimension().0 => output.output_glwe_key_dimension().0);
+        ck_dim_eq!(self.decomp_level_count.0 => output.decomposition_level_count().0);
+        ck_dim_eq!(self.as_tensor().len() * self.output_glwe_size.0 => output.as_tensor().len());
+
+        expand_seeded_glwe_bodies::<_, Gen>(
+            self.as_tensor().as_slice(),
+            output.as_mut_tensor().as_mut_slice(),
+            self.output_glwe_size,
+            self.output_polynomial_size,
+            self.compression_seed,
+        );
+    }
+
+    // Returns the number of bodies coefficients stored for a single key of the list.
+    fn single_fpksk_size(&self) -> usize {
+        self.output_polynomial_size.0 * self.decomp_level_count.0 * self.input_lwe_size.0
+    }
+}
+
+// Returns the polynomial `1`.
+fn constant_polynomial<Scalar: UnsignedTorus>(
+    polynomial_size: PolynomialSize,
+) -> Polynomial<Vec<Scalar>> {
+    let mut polynomial = Polynomial::allocate(Scalar::ZERO, polynomial_size);
+    *polynomial
+        .get_mut_monomial(MonomialDegree(0))
+        .get_mut_coefficient() = Scalar::ONE;
+    polynomial
+}
+
+// Returns the values encrypted by the key bit decompositions of a private functional packing
+// keyswitch key: `f(1)` times each input key bit, followed by `f(1)` times minus one, which is
+// applied to the decomposed body of the input ciphertext.
+fn pfpksk_input_key_bits<InKeyCont, Scalar>(
+    input_lwe_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+    f: &dyn Fn(Scalar) -> Scalar,
+) -> Vec<Scalar>
+where
+    LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+    Scalar: UnsignedTorus,
+{
+    let f_one = f(Scalar::ONE);
+    input_lwe_key
+        .as_tensor()
+        .iter()
+        .copied()
+        .chain(std::iter::once(Scalar::MAX))
+        .map(|bit| f_one.wrapping_mul(bit))
+        .collect()
+}
+
+// Returns the polynomials of the private functional packing keyswitch keys of a circuit
+// bootstrap: the polynomials of the output key, followed by the constant polynomial `1`.
+fn circuit_bootstrap_polynomials<OutKeyCont, Scalar>(
+    output_glwe_key: &GlweSecretKey<BinaryKeyKind, OutKeyCont>,
+) -> Vec<Polynomial<Vec<Scalar>>>
+where
+    GlweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+    Scalar: UnsignedTorus,
+{
+    output_glwe_key
+        .as_polynomial_list()
+        .polynomial_iter()
+        .map(|polynomial| Polynomial::from_container(polynomial.as_tensor().as_slice().to_vec()))
+        .chain(std::iter::once(constant_polynomial(
+            output_glwe_key.polynomial_size(),
+        )))
+        .collect()
+}
+
+// Returns the functions of the private functional packing keyswitch keys of a circuit
+// bootstrap: the opposite for the key polynomials, and the identity for the last key.
+#[allow(clippy::type_complexity)]
+fn circuit_bootstrap_functions<Scalar: UnsignedTorus>(
+    output_glwe_dimension: GlweDimension,
+) -> Vec<fn(Scalar) -> Scalar> {
+    let mut functions: Vec<fn(Scalar) -> Scalar> =
+        vec![|x: Scalar| Scalar::ZERO.wrapping_sub(x); output_glwe_dimension.0];
+    functions.push(|x| x);
+    functions
+}
+
+// Encrypts the levels of decomposition of `value` times `polynomial` in the bodies of `block`,
+// as seeded GLWE ciphertexts.
+#[allow(clippy::too_many_arguments)]
+fn encrypt_seeded_key_bit_decomposition<OutKeyCont, PolyCont, Scalar, NoiseParameter, Gen>(
+    output_glwe_key: &GlweSecretKey<BinaryKeyKind, OutKeyCont>,
+    block: &mut [Scalar],
+    value: Scalar,
+    polynomial: &Polynomial<PolyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    compression_seed: CompressionSeed,
+    noise_parameters: NoiseParameter,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    GlweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+    Polynomial<PolyCont>: AsRefTensor<Element = Scalar>,
+    Scalar: UnsignedTorus,
+    NoiseParameter: DispersionParameter,
+    Gen: ByteRandomGenerator,
+{
+    let polynomial_size = output_glwe_key.polynomial_size();
+    let output_glwe_dimension = output_glwe_key.key_size();
+    let mut messages = PlaintextList::from_container(vec![Scalar::ZERO; polynomial_size.0]);
+    for (level, body) in (1..=decomp_level_count.0)
+        .map(DecompositionLevel)
+        .zip(block.chunks_exact_mut(polynomial_size.0))
+    {
+        messages.as_mut_tensor().fill_with_element(Scalar::ZERO);
+        messages.as_mut_tensor().update_with_add_element_mul(
+            polynomial.as_tensor(),
+            DecompositionTerm::new(level, decomp_base_log, value).to_recomposition_summand(),
+        );
+        output_glwe_key.encrypt_seeded_glwe_with_existing_generator(
+            &mut GlweSeededCiphertext::from_container(
+                body,
+                output_glwe_dimension,
+                compression_seed,
+            ),
+            &messages,
+            noise_parameters,
+            generator,
+        );
+    }
+}
+
+// Expands the bodies of a seeded key into the GLWE ciphertexts of the output key.
+fn expand_seeded_glwe_bodies<Scalar, Gen>(
+    bodies: &[Scalar],
+    output: &mut [Scalar],
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    compression_seed: CompressionSeed,
+) where
+    Scalar: Copy + RandomGenerable<Uniform> + Numeric,
+    Gen: ByteRandomGenerator,
+{
+    let mut generator = RandomGenerator::<Gen>::new(compression_seed.seed);
+    for (ciphertext, body) in output
+        .chunks_exact_mut(glwe_size.0 * polynomial_size.0)
+        .zip(bodies.chunks_exact(polynomial_size.0))
+    {
+        GlweSeededCiphertext::from_container(body, glwe_size.to_glwe_dimension(), compression_seed)
+            .expand_into_with_existing_generator::<_, _, Gen>(
+                &mut GlweCiphertext::from_container(ciphertext, polynomial_size),
+                &mut generator,
+            );
+    }
+}
@@ -0,0 +1,315 @@
+#[cfg(feature = "__commons_serialization")]
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+
+use concrete_commons::numeric::UnsignedInteger;
+use concrete_commons::parameters::{CiphertextCount, CiphertextModulusLog, LweDimension, LweSize};
+
+use crate::commons::crypto::lwe::LweList;
+use crate::commons::math::tensor::{
+    tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+
+/// A list of LWE ciphertexts switched to a smaller power-of-two modulus, in a bit-packed form.
+///
+/// Every coefficient of the ciphertexts is stored on `ciphertext_modulus_log` bits. The
+/// coefficients are laid out in the same order as in an [`LweList`] (masks first, then body, for
+/// each ciphertext), starting from the least significant bits of the first element of the
+/// container. A coefficient may straddle two consecutive elements of the container.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCompressedList<Cont> {
+    tensor: Tensor<Cont>,
+    lwe_size: LweSize,
+    count: CiphertextCount,
+    ciphertext_modulus_log: CiphertextModulusLog,
+}
+
+tensor_traits!(LweCompressedList);
+
+/// Returns the number of `Scalar` elements needed to pack the coefficients of `count` LWE
+/// ciphertexts of size `lwe_size`, on `ciphertext_modulus_log` bits each, or `None` if the number
+/// of bits overflows.
+fn packed_len<Scalar: UnsignedInteger>(
+    lwe_size: LweSize,
+    count: CiphertextCount,
+    ciphertext_modulus_log: CiphertextModulusLog,
+) -> Option<usize> {
+    let bits = lwe_size
+        .0
+        .checked_mul(count.0)?
+        .checked_mul(ciphertext_modulus_log.0)?;
+    Some(bits / Scalar::BITS + (bits % Scalar::BITS != 0) as usize)
+}
+
+/// The list is deserialized through this function rather than a derived implementation, so that a
+/// container which does not match the parameters of the list is rejected with an error, instead
+/// of making the accesses to the coefficients panic later on.
+#[cfg(feature = "__commons_serialization")]
+impl<'de, Scalar> Deserialize<'de> for LweCompressedList<Vec<Scalar>>
+where
+    Scalar: UnsignedInteger + Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename = "LweCompressedList")]
+        struct UncheckedLweCompressedList<Scalar> {
+            tensor: Tensor<Vec<Scalar>>,
+            lwe_size: LweSize,
+            count: CiphertextCount,
+            ciphertext_modulus_log: CiphertextModulusLog,
+        }
+
+        let UncheckedLweCompressedList {
+            tensor,
+            lwe_size,
+            count,
+            ciphertext_modulus_log,
+        } = UncheckedLweCompressedList::<Scalar>::deserialize(deserializer)?;
+        if ciphertext_modulus_log.0 == 0 || ciphertext_modulus_log.0 > Scalar::BITS {
+            return Err(D::Error::custom(
+                "The ciphertext modulus log must be between one and the precision of the scalars.",
+            ));
+        }
+        if packed_len::<Scalar>(lwe_size, count, ciphertext_modulus_log) != Some(tensor.len()) {
+            return Err(D::Error::custom(
+                "The container length does not match the number of packed coefficients.",
+            ));
+        }
+        Ok(LweCompressedList {
+            tensor,
+            lwe_size,
+            count,
+            ciphertext_modulus_log,
+        })
+    }
+}
+
+impl<Scalar> LweCompressedList<Vec<Scalar>>
+where
+    Scalar: UnsignedInteger,
+{
+    /// Allocates a compressed list of LWE ciphertexts whose coefficients are all zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextCount, CiphertextModulusLog, LweSize};
+    /// use concrete_core::commons::crypto::lwe::LweCompressedList;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    ///
+    /// let list = LweCompressedList::<Vec<u64>>::allocate(
+    ///     LweSize(10),
+    ///     CiphertextCount(20),
+    ///     CiphertextModulusLog(16),
+    /// );
+    /// assert_eq!(list.count(), CiphertextCount(20));
+    /// assert_eq!(list.lwe_size(), LweSize(10));
+    /// assert_eq!(list.ciphertext_modulus_log(), CiphertextModulusLog(16));
+    /// // 200 coefficients of 16 bits fit in 50 u64
+    /// assert_eq!(list.as_tensor().len(), 50);
+    /// ```
+    pub fn allocate(
+        lwe_size: LweSize,
+        count: CiphertextCount,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Self {
+        let len = packed_len::<Scalar>(lwe_size, count, ciphertext_modulus_log)
+            .expect("The number of packed coefficients overflows.");
+        LweCompressedList {
+            tensor: Tensor::from_container(vec![Scalar::ZERO; len]),
+            lwe_size,
+            count,
+            ciphertext_modulus_log,
+        }
+    }
+}
+
+impl<Cont> LweCompressedList<Cont> {
+    /// Creates a compressed list from a container of packed coefficients.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextCount, CiphertextModulusLog, LweSize};
+    /// use concrete_core::commons::crypto::lwe::LweCompressedList;
+    ///
+    /// let list = LweCompressedList::from_container(
+    ///     vec![0 as u32; 7],
+    ///     LweSize(10),
+    ///     CiphertextCount(2),
+    ///     CiphertextModulusLog(11),
+    /// );
+    /// assert_eq!(list.count(), CiphertextCount(2));
+    /// ```
+    pub fn from_container<Scalar>(
+        cont: Cont,
+        lwe_size: LweSize,
+        count: CiphertextCount,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Self
+    where
+        Cont: AsRefSlice<Element = Scalar>,
+        Scalar: UnsignedInteger,
+    {
+        let tensor = Tensor::from_container(cont);
+        debug_assert!(
+            Some(tensor.len()) == packed_len::<Scalar>(lwe_size, count, ciphertext_modulus_log),
+            "The container length does not match the number of packed coefficients."
+        );
+        LweCompressedList {
+            tensor,
+            lwe_size,
+            count,
+            ciphertext_modulus_log,
+        }
+    }
+
+    /// Returns the number of ciphertexts in the list.
+    pub fn count(&self) -> CiphertextCount {
+        self.count
+    }
+
+    /// Returns the size of the ciphertexts in the list.
+    pub fn lwe_size(&self) -> LweSize {
+        self.lwe_size
+    }
+
+    /// Returns the size of the masks of the ciphertexts in the list.
+    pub fn mask_size(&self) -> LweDimension {
+        self.lwe_size.to_lwe_dimension()
+    }
+
+    /// Returns the logarithm of the modulus the ciphertexts were switched to.
+    pub fn ciphertext_modulus_log(&self) -> CiphertextModulusLog {
+        self.ciphertext_modulus_log
+    }
+
+    /// Fills the list with the modulus switch of the ciphertexts of the `input` list.
+    ///
+    /// Each coefficient `c` of the input is mapped to `round(c * 2^log_q / 2^w) mod 2^log_q`,
+    /// where `w` is the bit width of the input scalars and `log_q` the ciphertext modulus log of
+    /// the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextCount, CiphertextModulusLog, LweSize};
+    /// use concrete_core::commons::crypto::lwe::{LweCompressedList, LweList};
+    ///
+    /// let input =
+    ///     LweList::from_container(vec![0x8000_0000_u32, 0x7FFF_FFFF, 0xFFF0_0000], LweSize(3));
+    /// let mut list = LweCompressedList::<Vec<u32>>::allocate(
+    ///     LweSize(3),
+    ///     CiphertextCount(1),
+    ///     CiphertextModulusLog(4),
+    /// );
+    /// list.fill_with_modulus_switch(&input);
+    /// let switched: Vec<u32> = list.ciphertext_coefficient_iter(0).collect();
+    /// // The coefficients are returned in the input modulus, with their lower bits cleared
+    /// assert_eq!(switched, vec![0x8000_0000, 0x8000_0000, 0]);
+    /// ```
+    pub fn fill_with_modulus_switch<InputCont, Scalar>(&mut self, input: &LweList<InputCont>)
+    where
+        Self: AsMutTensor<Element = Scalar>,
+        LweList<InputCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedInteger,
+    {
+        debug_assert!(
+            input.count() == self.count && input.lwe_size() == self.lwe_size,
+            "The input list and the compressed list are not compatible."
+        );
+        let log_q = self.ciphertext_modulus_log.0;
+        self.as_mut_tensor().fill_with_element(Scalar::ZERO);
+        for (index, coefficient) in input.as_tensor().iter().enumerate() {
+            let switched = if log_q == Scalar::BITS {
+                *coefficient
+            } else {
+                let shift = Scalar::BITS - log_q;
+                coefficient.wrapping_add(Scalar::ONE << (shift - 1)) >> shift
+            };
+            self.write_coefficient(index, switched);
+        }
+    }
+
+    /// Returns an iterator over the coefficients of the `nth` ciphertext of the list, mask first
+    /// and body last.
+    ///
+    /// The coefficients are lifted back to the modulus of the scalar type: a coefficient `c`
+    /// modulo `2^log_q` is returned as `c * 2^(w - log_q)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextCount, CiphertextModulusLog, LweSize};
+    /// use concrete_core::commons::crypto::lwe::{LweCompressedList, LweList};
+    ///
+    /// let input =
+    ///     LweList::from_container(vec![1_u64 << 60, 3 << 60, 5 << 60, 7 << 60], LweSize(2));
+    /// let mut list = LweCompressedList::<Vec<u64>>::allocate(
+    ///     LweSize(2),
+    ///     CiphertextCount(2),
+    ///     CiphertextModulusLog(5),
+    /// );
+    /// list.fill_with_modulus_switch(&input);
+    /// let second: Vec<u64> = list.ciphertext_coefficient_iter(1).collect();
+    /// assert_eq!(second, vec![5 << 60, 7 << 60]);
+    /// ```
+    pub fn ciphertext_coefficient_iter<Scalar>(
+        &self,
+        nth: usize,
+    ) -> impl Iterator<Item = Scalar> + '_
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedInteger,
+    {
+        debug_assert!(nth < self.count.0, "The ciphertext index is out of bounds.");
+        let shift = Scalar::BITS - self.ciphertext_modulus_log.0;
+        let start = nth * self.lwe_size.0;
+        (start..start + self.lwe_size.0).map(move |index| {
+            let coefficient = self.read_coefficient(index);
+            if shift == 0 {
+                coefficient
+            } else {
+                coefficient << shift
+            }
+        })
+    }
+
+    fn write_coefficient<Scalar>(&mut self, index: usize, value: Scalar)
+    where
+        Self: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedInteger,
+    {
+        let log_q = self.ciphertext_modulus_log.0;
+        let bit_index = index * log_q;
+        let (element, offset) = (bit_index / Scalar::BITS, bit_index % Scalar::BITS);
+        let data = self.as_mut_tensor().as_mut_slice();
+        data[element] |= value << offset;
+        if offset + log_q > Scalar::BITS {
+            data[element + 1] |= value >> (Scalar::BITS - offset);
+        }
+    }
+
+    fn read_coefficient<Scalar>(&self, index: usize) -> Scalar
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedInteger,
+    {
+        let log_q = self.ciphertext_modulus_log.0;
+        let bit_index = index * log_q;
+        let (element, offset) = (bit_index / Scalar::BITS, bit_index % Scalar::BITS);
+        let data = self.as_tensor().as_slice();
+        let mut value = data[element] >> offset;
+        if offset + log_q > Scalar::BITS {
+            value |= data[element + 1] << (Scalar::BITS - offset);
+        }
+        if log_q == Scalar::BITS {
+            value
+        } else {
+            value & ((Scalar::ONE << log_q) - Scalar::ONE)
+        }
+    }
+}
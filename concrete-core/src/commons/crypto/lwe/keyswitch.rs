@@ -11,7 +11,7 @@ use crate::commons::math::tensor::{
 };
 use crate::commons::math::torus::UnsignedTorus;
 use concrete_commons::dispersion::DispersionParameter;
-use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::key_kinds::KeyKind;
 use concrete_commons::parameters::{
     CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
 };
@@ -329,16 +329,18 @@ impl<Cont> LweKeyswitchKey<Cont> {
     ///
     /// assert!(!ksk.as_tensor().iter().all(|a| *a == 0));
     /// ```
-    pub fn fill_with_keyswitch_key<InKeyCont, OutKeyCont, Scalar, Gen>(
+    pub fn fill_with_keyswitch_key<InKeyKind, OutKeyKind, InKeyCont, OutKeyCont, Scalar, Gen>(
         &mut self,
-        before_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
-        after_key: &LweSecretKey<BinaryKeyKind, OutKeyCont>,
+        before_key: &LweSecretKey<InKeyKind, InKeyCont>,
+        after_key: &LweSecretKey<OutKeyKind, OutKeyCont>,
         noise_parameters: impl DispersionParameter,
         generator: &mut EncryptionRandomGenerator<Gen>,
     ) where
         Self: AsMutTensor<Element = Scalar>,
-        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
-        LweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        LweSecretKey<InKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        LweSecretKey<OutKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        InKeyKind: KeyKind,
+        OutKeyKind: KeyKind,
         Scalar: UnsignedTorus,
         Gen: ByteRandomGenerator,
     {
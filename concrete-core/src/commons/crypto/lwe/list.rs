@@ -1,11 +1,16 @@
 use super::LweCiphertext;
 use crate::commons::crypto::encoding::{CleartextList, PlaintextList};
+use crate::commons::crypto::glwe::{GlweCiphertext, GlweList};
 use crate::commons::math::tensor::{
     ck_dim_div, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
 };
 use crate::commons::math::torus::UnsignedTorus;
 use crate::commons::utils::{zip, zip_args};
-use concrete_commons::parameters::{CiphertextCount, CleartextCount, LweDimension, LweSize};
+use concrete_commons::parameters::{
+    CiphertextCount, CleartextCount, LweDimension, LweSize, MonomialDegree,
+};
+#[cfg(feature = "__commons_parallel")]
+use rayon::{iter::IndexedParallelIterator, prelude::*};
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
 
@@ -255,6 +260,43 @@ impl<Cont> LweList<Cont> {
             .map(|sub| LweCiphertext::from_container(sub.into_container()))
     }
 
+    /// Returns a parallel iterator over ciphers mutably borrowed from the list.
+    ///
+    /// # Notes
+    /// This iterator is hidden behind the "__commons_parallel" feature gate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::commons::crypto::lwe::*;
+    /// use concrete_core::commons::crypto::*;
+    /// use rayon::iter::ParallelIterator;
+    /// let mut list = LweList::from_container(vec![0 as u8; 200], LweSize(10));
+    /// list.par_ciphertext_iter_mut().for_each(|mut ciphertext| {
+    ///     let body = ciphertext.get_mut_body();
+    ///     *body = LweBody(2);
+    /// });
+    /// for ciphertext in list.ciphertext_iter() {
+    ///     let body = ciphertext.get_body();
+    ///     assert_eq!(body, &LweBody(2));
+    /// }
+    /// ```
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_ciphertext_iter_mut(
+        &mut self,
+    ) -> impl IndexedParallelIterator<Item = LweCiphertext<&mut [<Self as AsMutTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+        <Self as AsMutTensor>::Element: Sync + Send,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.lwe_size.0);
+        let lwe_size = self.lwe_size.0;
+        self.as_mut_tensor()
+            .par_subtensor_iter_mut(lwe_size)
+            .map(|sub| LweCiphertext::from_container(sub.into_container()))
+    }
+
     /// Returns an iterator over sub lists borrowed from the list.
     ///
     /// # Example
@@ -440,4 +482,163 @@ impl<Cont> LweList<Cont> {
             cipher.fill_with_trivial_encryption(plaintext);
         }
     }
+
+    /// Fills each ciphertext of the list with the sample extraction of the `glwe` ciphertext at
+    /// the corresponding monomial degree of `degrees`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     CiphertextCount, GlweDimension, LweDimension, MonomialDegree, PlaintextCount,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::commons::crypto::lwe::LweList;
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::GlweSecretKey;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let poly_size = PolynomialSize(4);
+    /// let glwe_dim = GlweDimension(2);
+    /// let glwe_secret_key =
+    ///     GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    /// let plaintext_list =
+    ///     PlaintextList::from_container(vec![100000 as u32, 200000, 300000, 400000]);
+    /// let mut glwe_ct = GlweCiphertext::allocate(0u32, poly_size, glwe_dim.to_glwe_size());
+    /// glwe_secret_key.encrypt_glwe(
+    ///     &mut glwe_ct,
+    ///     &plaintext_list,
+    ///     LogStandardDev(-25.),
+    ///     &mut encryption_generator,
+    /// );
+    /// let lwe_secret_key = glwe_secret_key.into_lwe_secret_key();
+    ///
+    /// // We extract the odd coefficients only
+    /// let degrees = vec![MonomialDegree(1), MonomialDegree(3)];
+    /// let mut lwe_list = LweList::allocate(
+    ///     0u32,
+    ///     LweDimension(poly_size.0 * glwe_dim.0).to_lwe_size(),
+    ///     CiphertextCount(degrees.len()),
+    /// );
+    /// lwe_list.fill_with_glwe_sample_extractions(&glwe_ct, &degrees);
+    ///
+    /// let mut output = PlaintextList::allocate(0u32, PlaintextCount(degrees.len()));
+    /// lwe_secret_key.decrypt_lwe_list(&mut output, &lwe_list);
+    /// for (out, degree) in output.plaintext_iter().zip(degrees.iter()) {
+    ///     let plain = plaintext_list.as_tensor().get_element(degree.0);
+    ///     let d0 = out.0.wrapping_sub(*plain);
+    ///     let d1 = plain.wrapping_sub(out.0);
+    ///     assert!(std::cmp::min(d0, d1) < 400);
+    /// }
+    /// ```
+    pub fn fill_with_glwe_sample_extractions<InputCont, Scalar>(
+        &mut self,
+        glwe: &GlweCiphertext<InputCont>,
+        degrees: &[MonomialDegree],
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<InputCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        debug_assert!(
+            self.count().0 == degrees.len(),
+            "Lwe cipher list size and number of degrees are not compatible"
+        );
+        for (mut lwe, degree) in self.ciphertext_iter_mut().zip(degrees.iter()) {
+            glwe.fill_lwe_with_sample_extraction(&mut lwe, *degree);
+        }
+    }
+
+    /// Fills the list with the sample extractions of every ciphertext of the `glwe_list`, at each
+    /// monomial degree of `degrees`.
+    ///
+    /// The output ciphertexts are ordered by input GLWE ciphertext first, then by degree.
+    pub fn fill_with_glwe_list_sample_extractions<InputCont, Scalar>(
+        &mut self,
+        glwe_list: &GlweList<InputCont>,
+        degrees: &[MonomialDegree],
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweList<InputCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        debug_assert!(
+            self.count().0 == glwe_list.ciphertext_count().0 * degrees.len(),
+            "Lwe cipher list size and number of extractions are not compatible"
+        );
+        if degrees.is_empty() {
+            return;
+        }
+        for (mut lwe_list, glwe) in self
+            .sublist_iter_mut(CiphertextCount(degrees.len()))
+            .zip(glwe_list.ciphertext_iter())
+        {
+            lwe_list.fill_with_glwe_sample_extractions(&glwe, degrees);
+        }
+    }
+
+    /// Parallel version of [`LweList::fill_with_glwe_sample_extractions`].
+    ///
+    /// # Notes
+    /// This method is hidden behind the "__commons_parallel" feature gate.
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_fill_with_glwe_sample_extractions<InputCont, Scalar>(
+        &mut self,
+        glwe: &GlweCiphertext<InputCont>,
+        degrees: &[MonomialDegree],
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<InputCont>: AsRefTensor<Element = Scalar> + Sync,
+        Scalar: UnsignedTorus + Sync + Send,
+    {
+        debug_assert!(
+            self.count().0 == degrees.len(),
+            "Lwe cipher list size and number of degrees are not compatible"
+        );
+        self.par_ciphertext_iter_mut()
+            .zip(degrees.par_iter())
+            .for_each(|(mut lwe, degree)| {
+                glwe.fill_lwe_with_sample_extraction(&mut lwe, *degree);
+            });
+    }
+
+    /// Parallel version of [`LweList::fill_with_glwe_list_sample_extractions`].
+    ///
+    /// # Notes
+    /// This method is hidden behind the "__commons_parallel" feature gate.
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_fill_with_glwe_list_sample_extractions<InputCont, Scalar>(
+        &mut self,
+        glwe_list: &GlweList<InputCont>,
+        degrees: &[MonomialDegree],
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweList<InputCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + Sync + Send,
+    {
+        debug_assert!(
+            self.count().0 == glwe_list.ciphertext_count().0 * degrees.len(),
+            "Lwe cipher list size and number of extractions are not compatible"
+        );
+        if degrees.is_empty() {
+            return;
+        }
+        let glwes: Vec<_> = glwe_list.ciphertext_iter().collect();
+        self.par_ciphertext_iter_mut()
+            .enumerate()
+            .for_each(|(i, mut lwe)| {
+                glwes[i / degrees.len()]
+                    .fill_lwe_with_sample_extraction(&mut lwe, degrees[i % degrees.len()]);
+            });
+    }
 }
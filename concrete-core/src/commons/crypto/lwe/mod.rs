@@ -1,5 +1,6 @@
 //! LWE encryption scheme.
 mod ciphertext;
+mod compressed_list;
 mod keyswitch;
 mod list;
 mod seeded_ciphertext;
@@ -7,6 +8,7 @@ mod seeded_keyswitch;
 mod seeded_list;
 
 pub use ciphertext::*;
+pub use compressed_list::*;
 pub use keyswitch::*;
 pub use list::*;
 pub use seeded_ciphertext::*;
@@ -15,17 +17,21 @@ pub use seeded_list::*;
 
 #[cfg(test)]
 mod test {
-    use crate::commons::crypto::lwe::{LweKeyswitchKey, LweSeededKeyswitchKey};
+    use crate::commons::crypto::lwe::{
+        LweCompressedList, LweKeyswitchKey, LweList, LweSeededKeyswitchKey,
+    };
     use crate::commons::crypto::secret::generators::{
         DeterministicSeeder, EncryptionRandomGenerator,
     };
     use crate::commons::crypto::secret::LweSecretKey;
-    use crate::commons::math::random::CompressionSeed;
+    use crate::commons::math::random::{CompressionSeed, RandomGenerable, Uniform};
+    use crate::commons::math::tensor::{AsMutTensor, AsRefTensor};
     use crate::commons::math::torus::UnsignedTorus;
     use crate::commons::test_tools::new_secret_random_generator;
     use concrete_commons::dispersion::StandardDev;
     use concrete_commons::parameters::{
-        DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+        CiphertextCount, CiphertextModulusLog, DecompositionBaseLog, DecompositionLevelCount,
+        LweDimension, LweSize,
     };
     use concrete_csprng::generators::SoftwareRandomGenerator;
     use concrete_csprng::seeders::Seed;
@@ -76,12 +82,15 @@ mod test {
                 compression_seed,
             );
 
-            seeded_ksk.fill_with_seeded_keyswitch_key::<_, _, _, _, _, SoftwareRandomGenerator>(
-                &input_key,
-                &output_key,
-                StandardDev::from_standard_dev(10.),
-                &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(deterministic_seeder_seed),
-            );
+            seeded_ksk
+                .fill_with_seeded_keyswitch_key::<_, _, _, _, _, _, _, SoftwareRandomGenerator>(
+                    &input_key,
+                    &output_key,
+                    StandardDev::from_standard_dev(10.),
+                    &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(
+                        deterministic_seeder_seed,
+                    ),
+                );
 
             let mut expanded_ksk =
                 LweKeyswitchKey::allocate(T::ZERO, level, base_log, input_lwe_dim, output_lwe_dim);
@@ -101,4 +110,94 @@ mod test {
     fn test_ksk_seeded_gen_equivalence_u64() {
         test_ksk_seeded_gen_equivalence::<u64>()
     }
+
+    fn test_compressed_list_modulus_switch<T: UnsignedTorus + RandomGenerable<Uniform>>() {
+        for _ in 0..10 {
+            let lwe_size = LweSize(crate::commons::test_tools::random_usize_between(2..20));
+            let count = CiphertextCount(crate::commons::test_tools::random_usize_between(1..10));
+            let log_q = CiphertextModulusLog(crate::commons::test_tools::random_usize_between(
+                1..T::BITS + 1,
+            ));
+
+            let mut list = LweList::allocate(T::ZERO, lwe_size, count);
+            for coefficient in list.as_mut_tensor().iter_mut() {
+                *coefficient = crate::commons::test_tools::any_uint();
+            }
+
+            let mut compressed = LweCompressedList::allocate(lwe_size, count, log_q);
+            compressed.fill_with_modulus_switch(&list);
+
+            let shift = T::BITS - log_q.0;
+            for (i, ciphertext) in list.ciphertext_iter().enumerate() {
+                for (input, switched) in ciphertext
+                    .as_tensor()
+                    .iter()
+                    .zip(compressed.ciphertext_coefficient_iter(i))
+                {
+                    // The switched coefficient is the closest multiple of 2^shift, modulo 2^w
+                    let distance =
+                        std::cmp::min(input.wrapping_sub(switched), switched.wrapping_sub(*input));
+                    if shift == 0 {
+                        assert_eq!(distance, T::ZERO);
+                    } else {
+                        assert!(distance <= T::ONE << (shift - 1));
+                        assert_eq!(switched & ((T::ONE << shift) - T::ONE), T::ZERO);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_compressed_list_modulus_switch_u32() {
+        test_compressed_list_modulus_switch::<u32>()
+    }
+
+    #[test]
+    fn test_compressed_list_modulus_switch_u64() {
+        test_compressed_list_modulus_switch::<u64>()
+    }
+
+    #[cfg(feature = "backend_default_serialization")]
+    fn test_compressed_list_deserialization<
+        T: UnsignedTorus + serde::Serialize + serde::de::DeserializeOwned,
+    >() {
+        use crate::commons::math::tensor::Tensor;
+
+        let (lwe_size, count, log_q) = (LweSize(10), CiphertextCount(3), CiphertextModulusLog(12));
+        let list = LweCompressedList::<Vec<T>>::allocate(lwe_size, count, log_q);
+        let serialized = bincode::serialize(&list).unwrap();
+        let recovered: LweCompressedList<Vec<T>> = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(list, recovered);
+
+        // The serialized layout is the one of the fields of the list, in order.
+        let len = list.as_tensor().len();
+        for (container_len, log_q) in [
+            (len - 1, log_q),
+            (len + 1, log_q),
+            (len, CiphertextModulusLog(0)),
+            (len, CiphertextModulusLog(T::BITS + 1)),
+        ] {
+            let malformed = bincode::serialize(&(
+                Tensor::from_container(vec![T::ZERO; container_len]),
+                lwe_size,
+                count,
+                log_q,
+            ))
+            .unwrap();
+            assert!(bincode::deserialize::<LweCompressedList<Vec<T>>>(&malformed).is_err());
+        }
+    }
+
+    #[cfg(feature = "backend_default_serialization")]
+    #[test]
+    fn test_compressed_list_deserialization_u32() {
+        test_compressed_list_deserialization::<u32>()
+    }
+
+    #[cfg(feature = "backend_default_serialization")]
+    #[test]
+    fn test_compressed_list_deserialization_u64() {
+        test_compressed_list_deserialization::<u64>()
+    }
 }
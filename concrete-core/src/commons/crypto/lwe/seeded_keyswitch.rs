@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use concrete_commons::numeric::Numeric;
 
 use concrete_commons::dispersion::DispersionParameter;
-use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::key_kinds::KeyKind;
 use concrete_commons::parameters::{
     CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
 };
@@ -280,7 +280,7 @@ impl<Cont> LweSeededKeyswitchKey<Cont> {
     ///     compression_seed,
     /// );
     ///
-    /// ksk.fill_with_seeded_keyswitch_key::<_, _, _, _, _, SoftwareRandomGenerator>(
+    /// ksk.fill_with_seeded_keyswitch_key::<_, _, _, _, _, _, _, SoftwareRandomGenerator>(
     ///     &input_key,
     ///     &output_key,
     ///     noise,
@@ -290,6 +290,8 @@ impl<Cont> LweSeededKeyswitchKey<Cont> {
     /// assert!(!ksk.as_tensor().iter().all(|a| *a == 0));
     /// ```
     pub fn fill_with_seeded_keyswitch_key<
+        InKeyKind,
+        OutKeyKind,
         InKeyCont,
         OutKeyCont,
         Scalar,
@@ -298,14 +300,16 @@ impl<Cont> LweSeededKeyswitchKey<Cont> {
         Gen,
     >(
         &mut self,
-        before_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
-        after_key: &LweSecretKey<BinaryKeyKind, OutKeyCont>,
+        before_key: &LweSecretKey<InKeyKind, InKeyCont>,
+        after_key: &LweSecretKey<OutKeyKind, OutKeyCont>,
         noise_parameters: NoiseParameter,
         noise_seeder: &mut NoiseSeeder,
     ) where
         Self: AsMutTensor<Element = Scalar>,
-        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
-        LweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        LweSecretKey<InKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        LweSecretKey<OutKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        InKeyKind: KeyKind,
+        OutKeyKind: KeyKind,
         Scalar: UnsignedTorus,
         NoiseParameter: DispersionParameter,
         NoiseSeeder: Seeder,
@@ -490,6 +494,96 @@ impl<Cont> LweSeededKeyswitchKey<Cont> {
                 .expand_into_with_existing_generator::<_, _, Gen>(&mut lwe_list, &mut generator);
         }
     }
+
+    /// Expands the rows of the key starting at row `first_row` into a full fledged keyswitch key,
+    /// without generating the masks of the rows before it. The number of expanded rows is the
+    /// input dimension of the output key.
+    ///
+    /// # Example
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::commons::crypto::lwe::{LweKeyswitchKey, LweSeededKeyswitchKey};
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    ///
+    /// let levels = DecompositionLevelCount(3);
+    /// let base_log = DecompositionBaseLog(5);
+    /// let input_dimension = LweDimension(15);
+    /// let output_dimension = LweDimension(20);
+    /// let compression_seed = CompressionSeed { seed: Seed(42) };
+    ///
+    /// let ksk: LweSeededKeyswitchKey<Vec<u64>> = LweSeededKeyswitchKey::allocate(
+    ///     levels,
+    ///     base_log,
+    ///     input_dimension,
+    ///     output_dimension,
+    ///     compression_seed,
+    /// );
+    ///
+    /// let mut partial_ksk = LweKeyswitchKey::allocate(
+    ///     0,
+    ///     ksk.decomposition_level_count(),
+    ///     ksk.decomposition_base_log(),
+    ///     LweDimension(4),
+    ///     ksk.output_lwe_dimension(),
+    /// );
+    /// ksk.expand_rows_into::<_, _, SoftwareRandomGenerator>(6, &mut partial_ksk);
+    ///
+    /// let mut output_ksk = LweKeyswitchKey::allocate(
+    ///     0,
+    ///     ksk.decomposition_level_count(),
+    ///     ksk.decomposition_base_log(),
+    ///     ksk.input_lwe_dimension(),
+    ///     ksk.output_lwe_dimension(),
+    /// );
+    /// ksk.expand_into::<_, _, SoftwareRandomGenerator>(&mut output_ksk);
+    ///
+    /// let row_len = partial_ksk.as_tensor().len() / 4;
+    /// assert_eq!(
+    ///     &output_ksk.as_tensor().as_slice()[6 * row_len..10 * row_len],
+    ///     partial_ksk.as_tensor().as_slice()
+    /// );
+    /// ```
+    pub fn expand_rows_into<OutCont, Scalar, Gen>(
+        &self,
+        first_row: usize,
+        output: &mut LweKeyswitchKey<OutCont>,
+    ) where
+        LweKeyswitchKey<OutCont>: AsMutTensor<Element = Scalar>,
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: Copy + RandomGenerable<Uniform> + Numeric,
+        Gen: ByteRandomGenerator,
+    {
+        debug_assert!(
+            first_row + output.before_key_size().0 <= self.input_lwe_dimension().0,
+            "The range of rows to expand is out of the key."
+        );
+        debug_assert!(
+            output.decomposition_levels_count() == self.decomp_level_count
+                && output.after_key_size() == self.output_lwe_dimension(),
+            "The output key does not have the parameters of the input key."
+        );
+        let mut generator = RandomGenerator::<Gen>::new(self.compression_seed.seed);
+        let row_mask_size = self.decomp_level_count.0 * self.output_lwe_dimension().0;
+        generator
+            .jump_to_byte(first_row * row_mask_size * std::mem::size_of::<Scalar>())
+            .unwrap();
+
+        for (mut output_tensor, keyswitch_key_block) in output
+            .as_mut_tensor()
+            // We need enough space for decomp_level_count ciphertexts of size lwe_size
+            .subtensor_iter_mut(self.decomp_level_count.0 * self.lwe_size.0)
+            .zip(self.bit_decomp_iter().skip(first_row))
+        {
+            let mut lwe_list = LweList::from_container(output_tensor.as_mut_slice(), self.lwe_size);
+            keyswitch_key_block
+                .into_seeded_lwe_list()
+                .expand_into_with_existing_generator::<_, _, Gen>(&mut lwe_list, &mut generator);
+        }
+    }
 }
 
 /// The encryption of a single bit of the output key.
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use concrete_commons::numeric::Numeric;
 use concrete_commons::parameters::{CiphertextCount, LweDimension, LweSize};
 
-use crate::commons::crypto::lwe::LweList;
+use crate::commons::crypto::lwe::{LweCiphertext, LweList};
 use crate::commons::math::random::{
     ByteRandomGenerator, CompressionSeed, RandomGenerable, RandomGenerator, Uniform,
 };
@@ -289,4 +289,123 @@ impl<Cont> LweSeededList<Cont> {
 
         self.expand_into_with_existing_generator(output, &mut generator);
     }
+
+    /// Expands the `index`-th ciphertext of the list into a full fledged LweCiphertext, without
+    /// generating the masks of the ciphertexts before it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextCount, LweDimension, LweSize};
+    /// use concrete_core::commons::crypto::lwe::{LweCiphertext, LweList, LweSeededList};
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_core::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    ///
+    /// let compression_seed = CompressionSeed { seed: Seed(42) };
+    ///
+    /// let seeded_list =
+    ///     LweSeededList::<Vec<u8>>::allocate(LweDimension(9), CiphertextCount(20), compression_seed);
+    ///
+    /// let mut ciphertext = LweCiphertext::allocate(0u8, seeded_list.lwe_size());
+    /// seeded_list.expand_ciphertext_into::<_, _, SoftwareRandomGenerator>(7, &mut ciphertext);
+    ///
+    /// let mut list = LweList::allocate(0u8, seeded_list.lwe_size(), seeded_list.count());
+    /// seeded_list.expand_into::<_, _, SoftwareRandomGenerator>(&mut list);
+    /// assert_eq!(
+    ///     list.ciphertext_iter().nth(7).unwrap().as_tensor().as_slice(),
+    ///     ciphertext.as_tensor().as_slice()
+    /// );
+    /// ```
+    pub fn expand_ciphertext_into<OutCont, Scalar, Gen>(
+        &self,
+        index: usize,
+        output: &mut LweCiphertext<OutCont>,
+    ) where
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: RandomGenerable<Uniform> + Numeric,
+        Gen: ByteRandomGenerator,
+    {
+        debug_assert!(
+            index < self.count().0,
+            "The index of the ciphertext to expand is out of the list."
+        );
+        debug_assert!(
+            output.lwe_size() == self.lwe_size(),
+            "The output ciphertext does not have the size of the list ciphertexts."
+        );
+        let mut generator = RandomGenerator::<Gen>::new(self.compression_seed.seed);
+        generator
+            .jump_to_byte(index * self.lwe_dimension.0 * std::mem::size_of::<Scalar>())
+            .unwrap();
+
+        let body_in = self.as_tensor().get_element(index);
+        let (output_body, mut output_mask) = output.get_mut_body_and_mask();
+        generator.fill_tensor_with_random_uniform(output_mask.as_mut_tensor());
+        output_body.0 = *body_in;
+    }
+
+    /// Expands the ciphertexts of the list starting at index `first` into a full fledged LweList,
+    /// without generating the masks of the ciphertexts before it. The number of expanded
+    /// ciphertexts is the number of ciphertexts of the output list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextCount, LweDimension, LweSize};
+    /// use concrete_core::commons::crypto::lwe::{LweList, LweSeededList};
+    /// use concrete_core::commons::math::random::{CompressionSeed, Seed};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    ///
+    /// let compression_seed = CompressionSeed { seed: Seed(42) };
+    ///
+    /// let seeded_list =
+    ///     LweSeededList::<Vec<u8>>::allocate(LweDimension(9), CiphertextCount(20), compression_seed);
+    ///
+    /// let mut sub_list = LweList::allocate(0u8, seeded_list.lwe_size(), CiphertextCount(5));
+    /// seeded_list.expand_range_into::<_, _, SoftwareRandomGenerator>(12, &mut sub_list);
+    ///
+    /// let mut list = LweList::allocate(0u8, seeded_list.lwe_size(), seeded_list.count());
+    /// seeded_list.expand_into::<_, _, SoftwareRandomGenerator>(&mut list);
+    /// for (expected, expanded) in list
+    ///     .ciphertext_iter()
+    ///     .skip(12)
+    ///     .zip(sub_list.ciphertext_iter())
+    /// {
+    ///     assert_eq!(expected, expanded);
+    /// }
+    /// ```
+    pub fn expand_range_into<OutCont, Scalar, Gen>(
+        &self,
+        first: usize,
+        output: &mut LweList<OutCont>,
+    ) where
+        LweList<OutCont>: AsMutTensor<Element = Scalar>,
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: RandomGenerable<Uniform> + Numeric,
+        Gen: ByteRandomGenerator,
+    {
+        debug_assert!(
+            first + output.count().0 <= self.count().0,
+            "The range of ciphertexts to expand is out of the list."
+        );
+        debug_assert!(
+            output.lwe_size() == self.lwe_size(),
+            "The output list does not have the ciphertext size of the input list."
+        );
+        let mut generator = RandomGenerator::<Gen>::new(self.compression_seed.seed);
+        generator
+            .jump_to_byte(first * self.lwe_dimension.0 * std::mem::size_of::<Scalar>())
+            .unwrap();
+
+        for (mut lwe_out, body_in) in output
+            .ciphertext_iter_mut()
+            .zip(self.body_iter().skip(first))
+        {
+            let (output_body, mut output_mask) = lwe_out.get_mut_body_and_mask();
+            generator.fill_tensor_with_random_uniform(output_mask.as_mut_tensor());
+            output_body.0 = body_in.0;
+        }
+    }
 }
@@ -1,3 +1,5 @@
+#[cfg(feature = "__commons_serialization")]
+use crate::commons::math::random::GeneratorStateSerdeDef;
 #[cfg(feature = "__commons_parallel")]
 use crate::commons::math::random::ParallelByteRandomGenerator;
 use crate::commons::math::random::{
@@ -8,11 +10,14 @@ use crate::commons::math::tensor::AsMutTensor;
 use concrete_commons::dispersion::DispersionParameter;
 use concrete_commons::numeric::UnsignedInteger;
 use concrete_commons::parameters::{
-    DecompositionLevelCount, GlweDimension, GlweSize, LweDimension, LweSize, PolynomialSize,
+    DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount, GlweDimension, GlweSize,
+    LweDimension, LweSize, PolynomialSize,
 };
-use concrete_csprng::generators::ForkError;
+use concrete_csprng::generators::{ForkError, GeneratorState, StateError};
 #[cfg(feature = "__commons_parallel")]
 use rayon::prelude::*;
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
 
 /// A random number generator which can be used to encrypt messages.
 pub struct EncryptionRandomGenerator<G: ByteRandomGenerator> {
@@ -22,6 +27,22 @@ pub struct EncryptionRandomGenerator<G: ByteRandomGenerator> {
     noise: RandomGenerator<G>,
 }
 
+/// The state of an [`EncryptionRandomGenerator`], from which it can be restored.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncryptionRandomGeneratorState {
+    #[cfg_attr(
+        feature = "__commons_serialization",
+        serde(with = "GeneratorStateSerdeDef")
+    )]
+    mask: GeneratorState,
+    #[cfg_attr(
+        feature = "__commons_serialization",
+        serde(with = "GeneratorStateSerdeDef")
+    )]
+    noise: GeneratorState,
+}
+
 impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
     /// Creates a new encryption, optionally seeding it with the given value.
     // S is ?Sized to allow Box<dyn Seeder> to be passed.
@@ -44,6 +65,25 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
         self.mask.remaining_bytes()
     }
 
+    /// Returns the state of both the mask and the noise generators.
+    pub fn get_state(&self) -> EncryptionRandomGeneratorState {
+        EncryptionRandomGeneratorState {
+            mask: self.mask.get_state(),
+            noise: self.noise.get_state(),
+        }
+    }
+
+    /// Tries to restore a generator from a state returned by
+    /// [`EncryptionRandomGenerator::get_state`].
+    pub fn try_from_state(
+        state: EncryptionRandomGeneratorState,
+    ) -> Result<EncryptionRandomGenerator<G>, StateError> {
+        Ok(EncryptionRandomGenerator {
+            mask: RandomGenerator::try_from_state(state.mask)?,
+            noise: RandomGenerator::try_from_state(state.noise)?,
+        })
+    }
+
     // Forks the generator, when splitting a bootstrap key into ggsw ct.
     #[allow(dead_code)]
     pub(crate) fn fork_bsk_to_ggsw<T: UnsignedInteger>(
@@ -102,6 +142,36 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
         self.try_fork(lwe_size.0, mask_bytes, noise_bytes)
     }
 
+    // Forks the generator, when splitting a packing keyswitch key into key bit decompositions.
+    pub(crate) fn fork_pksk_to_key_bit_decompositions<T: UnsignedInteger>(
+        &mut self,
+        key_bit_count: usize,
+        level: DecompositionLevelCount,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+    ) -> Result<impl Iterator<Item = EncryptionRandomGenerator<G>>, ForkError> {
+        let mask_bytes =
+            mask_bytes_per_pksk_key_bit_decomposition::<T>(level, glwe_size, polynomial_size);
+        let noise_bytes = noise_bytes_per_pksk_key_bit_decomposition(level, polynomial_size);
+        self.try_fork(key_bit_count, mask_bytes, noise_bytes)
+    }
+
+    // Forks the generator, when splitting a list of private functional packing keyswitch keys.
+    pub(crate) fn fork_pfpksk_list_to_pfpksk<T: UnsignedInteger>(
+        &mut self,
+        key_count: FunctionalPackingKeyswitchKeyCount,
+        key_bit_count: usize,
+        level: DecompositionLevelCount,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+    ) -> Result<impl Iterator<Item = EncryptionRandomGenerator<G>>, ForkError> {
+        let mask_bytes = key_bit_count
+            * mask_bytes_per_pksk_key_bit_decomposition::<T>(level, glwe_size, polynomial_size);
+        let noise_bytes =
+            key_bit_count * noise_bytes_per_pksk_key_bit_decomposition(level, polynomial_size);
+        self.try_fork(key_count.0, mask_bytes, noise_bytes)
+    }
+
     // Forks both generators into an iterator
     fn try_fork(
         &mut self,
@@ -218,6 +288,38 @@ impl<G: ParallelByteRandomGenerator> EncryptionRandomGenerator<G> {
         self.par_try_fork(lwe_size.0, mask_bytes, noise_bytes)
     }
 
+    // Forks the generator into a parallel iterator, when splitting a packing keyswitch key into
+    // key bit decompositions.
+    pub(crate) fn par_fork_pksk_to_key_bit_decompositions<T: UnsignedInteger>(
+        &mut self,
+        key_bit_count: usize,
+        level: DecompositionLevelCount,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+    ) -> Result<impl IndexedParallelIterator<Item = EncryptionRandomGenerator<G>>, ForkError> {
+        let mask_bytes =
+            mask_bytes_per_pksk_key_bit_decomposition::<T>(level, glwe_size, polynomial_size);
+        let noise_bytes = noise_bytes_per_pksk_key_bit_decomposition(level, polynomial_size);
+        self.par_try_fork(key_bit_count, mask_bytes, noise_bytes)
+    }
+
+    // Forks the generator into a parallel iterator, when splitting a list of private functional
+    // packing keyswitch keys.
+    pub(crate) fn par_fork_pfpksk_list_to_pfpksk<T: UnsignedInteger>(
+        &mut self,
+        key_count: FunctionalPackingKeyswitchKeyCount,
+        key_bit_count: usize,
+        level: DecompositionLevelCount,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+    ) -> Result<impl IndexedParallelIterator<Item = EncryptionRandomGenerator<G>>, ForkError> {
+        let mask_bytes = key_bit_count
+            * mask_bytes_per_pksk_key_bit_decomposition::<T>(level, glwe_size, polynomial_size);
+        let noise_bytes =
+            key_bit_count * noise_bytes_per_pksk_key_bit_decomposition(level, polynomial_size);
+        self.par_try_fork(key_count.0, mask_bytes, noise_bytes)
+    }
+
     // Forks both generators into a parallel iterator.
     fn par_try_fork(
         &mut self,
@@ -274,6 +376,14 @@ fn mask_bytes_per_ggsw<T: UnsignedInteger>(
     level.0 * mask_bytes_per_ggsw_level::<T>(glwe_size, poly_size)
 }
 
+fn mask_bytes_per_pksk_key_bit_decomposition<T: UnsignedInteger>(
+    level: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    poly_size: PolynomialSize,
+) -> usize {
+    level.0 * mask_bytes_per_glwe::<T>(glwe_size.to_glwe_dimension(), poly_size)
+}
+
 fn noise_bytes_per_coef() -> usize {
     // We use f64 to sample the noise for every precision, and we need 4/pi inputs to generate
     // such an output (here we take 32 to keep a safety margin).
@@ -300,6 +410,13 @@ fn noise_bytes_per_gsw_level(lwe_size: LweSize) -> usize {
     lwe_size.0 * noise_bytes_per_lwe()
 }
 
+fn noise_bytes_per_pksk_key_bit_decomposition(
+    level: DecompositionLevelCount,
+    poly_size: PolynomialSize,
+) -> usize {
+    level.0 * noise_bytes_per_glwe(poly_size)
+}
+
 fn noise_bytes_per_ggsw(
     level: DecompositionLevelCount,
     glwe_size: GlweSize,
@@ -1,5 +1,5 @@
 mod encryption;
-pub use encryption::EncryptionRandomGenerator;
+pub use encryption::{EncryptionRandomGenerator, EncryptionRandomGeneratorState};
 
 mod secret;
 pub use secret::SecretRandomGenerator;
@@ -4,6 +4,7 @@ use crate::commons::math::random::{
 use crate::commons::math::tensor::Tensor;
 use crate::commons::math::torus::UnsignedTorus;
 use concrete_commons::dispersion::DispersionParameter;
+use concrete_csprng::generators::{GeneratorState, StateError};
 
 /// A random number generator which can be used to generate secret keys.
 pub struct SecretRandomGenerator<G: ByteRandomGenerator>(RandomGenerator<G>);
@@ -19,6 +20,17 @@ impl<G: ByteRandomGenerator> SecretRandomGenerator<G> {
         self.0.remaining_bytes()
     }
 
+    /// Returns the state of the generator.
+    pub fn get_state(&self) -> GeneratorState {
+        self.0.get_state()
+    }
+
+    /// Tries to restore a generator from a state returned by
+    /// [`SecretRandomGenerator::get_state`].
+    pub fn try_from_state(state: GeneratorState) -> Result<SecretRandomGenerator<G>, StateError> {
+        RandomGenerator::try_from_state(state).map(SecretRandomGenerator)
+    }
+
     // Returns a tensor with random uniform binary values.
     pub(crate) fn random_binary_tensor<Scalar>(&mut self, length: usize) -> Tensor<Vec<Scalar>>
     where
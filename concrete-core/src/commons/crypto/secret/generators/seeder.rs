@@ -1,6 +1,7 @@
 use crate::commons::math::random::{
     ByteRandomGenerator, RandomGenerable, RandomGenerator, Seed, Seeder, Uniform,
 };
+use concrete_csprng::generators::{GeneratorState, StateError};
 
 /// Seeder backed by a CSPRNG
 ///
@@ -40,6 +41,18 @@ impl<G: ByteRandomGenerator> DeterministicSeeder<G> {
             generator: RandomGenerator::new(seed),
         }
     }
+
+    /// Returns the state of the generator backing the seeder.
+    pub fn get_state(&self) -> GeneratorState {
+        self.generator.get_state()
+    }
+
+    /// Tries to restore a seeder from a state returned by [`DeterministicSeeder::get_state`].
+    pub fn try_from_state(state: GeneratorState) -> Result<Self, StateError> {
+        Ok(DeterministicSeeder {
+            generator: RandomGenerator::try_from_state(state)?,
+        })
+    }
 }
 
 impl<G: ByteRandomGenerator> Seeder for DeterministicSeeder<G> {
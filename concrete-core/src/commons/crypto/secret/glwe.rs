@@ -6,7 +6,7 @@ use crate::commons::crypto::glwe::{
 use crate::commons::crypto::secret::generators::{
     EncryptionRandomGenerator, SecretRandomGenerator,
 };
-use crate::commons::crypto::secret::LweSecretKey;
+use crate::commons::crypto::secret::{LweSecretKey, RandomKeyKind};
 use crate::commons::math::polynomial::PolynomialList;
 #[cfg(feature = "__commons_parallel")]
 use crate::commons::math::random::ParallelByteRandomGenerator;
@@ -20,7 +20,9 @@ use concrete_commons::key_kinds::{
     BinaryKeyKind, GaussianKeyKind, KeyKind, TernaryKeyKind, UniformKeyKind,
 };
 use concrete_commons::numeric::Numeric;
-use concrete_commons::parameters::{GlweDimension, PlaintextCount, PolynomialSize};
+use concrete_commons::parameters::{
+    AutomorphismExponent, GlweDimension, PlaintextCount, PolynomialSize,
+};
 #[cfg(feature = "__commons_parallel")]
 use rayon::{iter::IndexedParallelIterator, prelude::*};
 #[cfg(feature = "__commons_serialization")]
@@ -322,6 +324,43 @@ impl<Cont> GlweSecretKey<UniformKeyKind, Cont> {
     }
 }
 
+impl<Kind, Scalar> GlweSecretKey<Kind, Vec<Scalar>>
+where
+    Kind: RandomKeyKind,
+    Scalar: UnsignedTorus,
+{
+    /// Allocates a container for a new key, and fills it with random values following the
+    /// distribution of the key kind.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::key_kinds::TernaryKeyKind;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::*;
+    /// use concrete_core::commons::crypto::*;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// let mut generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key: GlweSecretKey<TernaryKeyKind, Vec<u32>> =
+    ///     GlweSecretKey::generate(GlweDimension(256), PolynomialSize(10), &mut generator);
+    /// assert_eq!(secret_key.key_size(), GlweDimension(256));
+    /// assert_eq!(secret_key.polynomial_size(), PolynomialSize(10));
+    /// ```
+    pub fn generate<Gen: ByteRandomGenerator>(
+        dimension: GlweDimension,
+        poly_size: PolynomialSize,
+        generator: &mut SecretRandomGenerator<Gen>,
+    ) -> Self {
+        GlweSecretKey {
+            tensor: Kind::random_key_tensor(generator, poly_size.0 * dimension.0),
+            poly_size,
+            kind: PhantomData,
+        }
+    }
+}
+
 impl<Kind, Scalar> GlweSecretKey<Kind, Vec<Scalar>>
 where
     Kind: KeyKind,
@@ -348,6 +387,36 @@ where
             kind: PhantomData,
         }
     }
+
+    /// Creates a GLWE secret key from an LWE secret key, by splitting its coefficients into
+    /// polynomials of the given size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let lwe_secret_key: LweSecretKey<_, Vec<u32>> =
+    ///     LweSecretKey::generate_ternary(LweDimension(20), &mut secret_generator);
+    /// let glwe_secret_key =
+    ///     GlweSecretKey::from_lwe_secret_key(lwe_secret_key, PolynomialSize(10));
+    /// assert_eq!(glwe_secret_key.key_size(), GlweDimension(2));
+    /// ```
+    pub fn from_lwe_secret_key(
+        lwe_secret_key: LweSecretKey<Kind, Vec<Scalar>>,
+        poly_size: PolynomialSize,
+    ) -> Self {
+        ck_dim_div!(lwe_secret_key.tensor.len() => poly_size.0);
+        GlweSecretKey {
+            tensor: lwe_secret_key.tensor,
+            poly_size,
+            kind: PhantomData,
+        }
+    }
 }
 
 impl<Kind, Cont> GlweSecretKey<Kind, Cont>
@@ -465,6 +534,54 @@ where
         PolynomialList::from_container(self.as_mut_tensor().as_mut_slice(), poly_size)
     }
 
+    /// Returns the image of the current key by the ring automorphism $X \mapsto X^k$, where $k$
+    /// is the (odd) `exponent`.
+    ///
+    /// A GLWE ciphertext whose polynomials were mapped by the automorphism is encrypted under
+    /// this key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{AutomorphismExponent, GlweDimension, PolynomialSize};
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::*;
+    /// use concrete_core::commons::math::tensor::AsRefTensor;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key: GlweSecretKey<_, Vec<u32>> = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(16),
+    ///     &mut secret_generator,
+    /// );
+    /// // X -> X^(2N + 1) is the identity
+    /// let image = secret_key.automorphism_image(AutomorphismExponent(33));
+    /// assert_eq!(image, secret_key);
+    /// ```
+    pub fn automorphism_image<Scalar>(
+        &self,
+        exponent: AutomorphismExponent,
+    ) -> GlweSecretKey<Kind, Vec<Scalar>>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let mut image = GlweSecretKey {
+            tensor: Tensor::from_container(vec![Scalar::ZERO; self.as_tensor().len()]),
+            poly_size: self.poly_size,
+            kind: PhantomData,
+        };
+        for (mut image_poly, key_poly) in image
+            .as_mut_polynomial_list()
+            .polynomial_iter_mut()
+            .zip(self.as_polynomial_list().polynomial_iter())
+        {
+            image_poly.fill_with_automorphism(&key_poly, exponent);
+        }
+        image
+    }
+
     fn fill_glwe_mask_and_body_for_encryption<InputCont, BodyCont, MaskCont, Scalar, Gen>(
         &self,
         mut output_body: GlweBody<BodyCont>,
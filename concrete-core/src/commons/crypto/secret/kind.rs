@@ -0,0 +1,97 @@
+use crate::commons::crypto::secret::generators::SecretRandomGenerator;
+use crate::commons::math::random::ByteRandomGenerator;
+use crate::commons::math::tensor::Tensor;
+use crate::commons::math::torus::UnsignedTorus;
+use concrete_commons::key_kinds::{
+    BinaryKeyKind, GaussianKeyKind, KeyKind, TernaryKeyKind, UniformKeyKind,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A value identifying a [`RandomKeyKind`], e.g. to check the kind of a deserialized key.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyKindTag {
+    Binary,
+    Ternary,
+    Gaussian,
+    Uniform,
+}
+
+/// A key kind whose secret keys can be sampled with a [`SecretRandomGenerator`].
+///
+/// This trait allows to write key generation code which is generic over the distribution of the
+/// key coefficients.
+pub trait RandomKeyKind: KeyKind {
+    /// The tag identifying this kind.
+    const TAG: KeyKindTag;
+
+    /// Returns a tensor of `length` key coefficients sampled from the distribution of this kind.
+    fn random_key_tensor<Scalar, Gen>(
+        generator: &mut SecretRandomGenerator<Gen>,
+        length: usize,
+    ) -> Tensor<Vec<Scalar>>
+    where
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator;
+}
+
+impl RandomKeyKind for BinaryKeyKind {
+    const TAG: KeyKindTag = KeyKindTag::Binary;
+
+    fn random_key_tensor<Scalar, Gen>(
+        generator: &mut SecretRandomGenerator<Gen>,
+        length: usize,
+    ) -> Tensor<Vec<Scalar>>
+    where
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        generator.random_binary_tensor(length)
+    }
+}
+
+impl RandomKeyKind for TernaryKeyKind {
+    const TAG: KeyKindTag = KeyKindTag::Ternary;
+
+    fn random_key_tensor<Scalar, Gen>(
+        generator: &mut SecretRandomGenerator<Gen>,
+        length: usize,
+    ) -> Tensor<Vec<Scalar>>
+    where
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        generator.random_ternary_tensor(length)
+    }
+}
+
+impl RandomKeyKind for GaussianKeyKind {
+    const TAG: KeyKindTag = KeyKindTag::Gaussian;
+
+    fn random_key_tensor<Scalar, Gen>(
+        generator: &mut SecretRandomGenerator<Gen>,
+        length: usize,
+    ) -> Tensor<Vec<Scalar>>
+    where
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        generator.random_gaussian_tensor(length)
+    }
+}
+
+impl RandomKeyKind for UniformKeyKind {
+    const TAG: KeyKindTag = KeyKindTag::Uniform;
+
+    fn random_key_tensor<Scalar, Gen>(
+        generator: &mut SecretRandomGenerator<Gen>,
+        length: usize,
+    ) -> Tensor<Vec<Scalar>>
+    where
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        generator.random_uniform_tensor(length)
+    }
+}
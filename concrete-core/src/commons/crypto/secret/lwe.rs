@@ -1,11 +1,12 @@
 use crate::commons::crypto::encoding::{Plaintext, PlaintextList};
 use crate::commons::crypto::gsw::GswCiphertext;
 use crate::commons::crypto::lwe::{
-    LweBody, LweCiphertext, LweList, LweMask, LweSeededCiphertext, LweSeededList,
+    LweBody, LweCiphertext, LweCompressedList, LweList, LweMask, LweSeededCiphertext, LweSeededList,
 };
 use crate::commons::crypto::secret::generators::{
     EncryptionRandomGenerator, SecretRandomGenerator,
 };
+use crate::commons::crypto::secret::RandomKeyKind;
 #[cfg(feature = "__commons_parallel")]
 use crate::commons::math::random::ParallelByteRandomGenerator;
 use crate::commons::math::random::{ByteRandomGenerator, Gaussian, RandomGenerable, Seeder};
@@ -221,6 +222,42 @@ impl<Cont> LweSecretKey<TernaryKeyKind, Cont> {
             kind: PhantomData,
         }
     }
+
+    /// Returns the binary expansion of a ternary secret key.
+    ///
+    /// The expansion is a binary key twice as large, whose coefficients `2i` and `2i+1` are
+    /// respectively set when the `i`-th coefficient of the ternary key is `1` and `-1`. The inner
+    /// product of the expansion with the mask `(a_0, -a_0, a_1, -a_1, ...)` is equal to the inner
+    /// product of the ternary key with the mask `(a_0, a_1, ...)`, which is what allows to
+    /// bootstrap ciphertexts encrypted under a ternary key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::commons::crypto::secret::*;
+    /// use concrete_core::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// let secret_key = LweSecretKey::ternary_from_container(vec![1_u32, 0, u32::MAX]);
+    /// let expansion = secret_key.binary_expansion();
+    /// assert_eq!(expansion.as_tensor().as_slice(), &[1, 0, 0, 0, 0, 1]);
+    /// ```
+    pub fn binary_expansion<Scalar>(&self) -> LweSecretKey<BinaryKeyKind, Vec<Scalar>>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let indicator = |is_set: bool| if is_set { Scalar::ONE } else { Scalar::ZERO };
+        let expansion = self
+            .as_tensor()
+            .iter()
+            .flat_map(|coefficient| {
+                [
+                    indicator(*coefficient == Scalar::ONE),
+                    indicator(*coefficient == Scalar::MAX),
+                ]
+            })
+            .collect();
+        LweSecretKey::binary_from_container(expansion)
+    }
 }
 
 impl<Cont> LweSecretKey<GaussianKeyKind, Cont> {
@@ -281,6 +318,40 @@ impl<Cont> LweSecretKey<UniformKeyKind, Cont> {
     }
 }
 
+impl<Kind, Scalar> LweSecretKey<Kind, Vec<Scalar>>
+where
+    Kind: RandomKeyKind,
+    Scalar: UnsignedTorus,
+{
+    /// Generates a new secret key whose coefficients follow the distribution of the key kind;
+    /// e.g. allocates a storage and samples random values for the key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::key_kinds::TernaryKeyKind;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use concrete_core::commons::crypto::secret::*;
+    /// use concrete_core::commons::crypto::*;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// let mut generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key: LweSecretKey<TernaryKeyKind, Vec<u32>> =
+    ///     LweSecretKey::generate(LweDimension(256), &mut generator);
+    /// assert_eq!(secret_key.key_size(), LweDimension(256));
+    /// ```
+    pub fn generate<Gen: ByteRandomGenerator>(
+        size: LweDimension,
+        generator: &mut SecretRandomGenerator<Gen>,
+    ) -> Self {
+        LweSecretKey {
+            tensor: Kind::random_key_tensor(generator, size.0),
+            kind: PhantomData,
+        }
+    }
+}
+
 impl<Kind, Cont> LweSecretKey<Kind, Cont>
 where
     Kind: KeyKind,
@@ -707,6 +778,88 @@ where
         }
     }
 
+    /// Decrypts a compressed list of ciphertexts.
+    ///
+    /// The ciphertexts are decrypted directly from their packed coefficients, without
+    /// decompressing the whole list first. The output plaintexts are expressed in the modulus of
+    /// the scalar type, their lower bits being lost by the modulus switch.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     CiphertextCount, CiphertextModulusLog, LweDimension, PlaintextCount,
+    /// };
+    /// use concrete_core::commons::crypto::encoding::PlaintextList;
+    /// use concrete_core::commons::crypto::lwe::{LweCompressedList, LweList};
+    /// use concrete_core::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::commons::crypto::secret::LweSecretKey;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    /// let encoded = PlaintextList::from_container(vec![1_u64 << 60, 3 << 60, 9 << 60]);
+    /// let mut list = LweList::allocate(0u64, LweDimension(256).to_lwe_size(), CiphertextCount(3));
+    /// secret_key.encrypt_lwe_list(
+    ///     &mut list,
+    ///     &encoded,
+    ///     LogStandardDev::from_log_standard_dev(-40.),
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let mut compressed = LweCompressedList::<Vec<u64>>::allocate(
+    ///     list.lwe_size(),
+    ///     list.count(),
+    ///     CiphertextModulusLog(16),
+    /// );
+    /// compressed.fill_with_modulus_switch(&list);
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(3));
+    /// secret_key.decrypt_compressed_lwe_list(&mut decrypted, &compressed);
+    /// for (dec, enc) in decrypted.plaintext_iter().zip(encoded.plaintext_iter()) {
+    ///     // We round to the 4 most significant bits
+    ///     assert_eq!(dec.0.wrapping_add(1 << 59) >> 60, enc.0 >> 60);
+    /// }
+    /// ```
+    pub fn decrypt_compressed_lwe_list<Scalar, EncodedCont, CipherCont>(
+        &self,
+        output: &mut PlaintextList<EncodedCont>,
+        cipher: &LweCompressedList<CipherCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        PlaintextList<EncodedCont>: AsMutTensor<Element = Scalar>,
+        LweCompressedList<CipherCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        debug_assert!(
+            output.count().0 == cipher.count().0,
+            "Tried to decrypt a list into one with incompatible size.Expected {} found {}",
+            output.count().0,
+            cipher.count().0
+        );
+        let dimension = cipher.mask_size().0;
+        for (index, output) in output.plaintext_iter_mut().enumerate() {
+            // The coefficients are lifted to the scalar modulus, so that the usual decryption
+            // gives the decryption modulo the smaller modulus, lifted as well.
+            let mut coefficients = cipher.ciphertext_coefficient_iter(index);
+            let multisum = coefficients
+                .by_ref()
+                .take(dimension)
+                .zip(self.as_tensor().iter())
+                .fold(Scalar::ZERO, |acc, (mask, key)| {
+                    acc.wrapping_add(mask.wrapping_mul(*key))
+                });
+            let body = coefficients.next().unwrap();
+            output.0 = body.wrapping_sub(multisum);
+        }
+    }
+
     /// This function encrypts a message as a GSW ciphertext.
     ///
     /// # Examples
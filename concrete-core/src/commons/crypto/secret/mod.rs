@@ -1,8 +1,10 @@
 //! Secret keys for the concrete schemes.
 pub use glwe::*;
+pub use kind::*;
 pub use lwe::*;
 
 pub mod generators;
 
 mod glwe;
+mod kind;
 mod lwe;
@@ -7,7 +7,7 @@ use crate::commons::math::tensor::{
 
 use super::*;
 use concrete_commons::numeric::UnsignedInteger;
-use concrete_commons::parameters::{MonomialDegree, PolynomialSize};
+use concrete_commons::parameters::{AutomorphismExponent, MonomialDegree, PolynomialSize};
 
 // stop the induction when polynomials have KARATUSBA_STOP elements
 const KARATUSBA_STOP: usize = 32;
@@ -532,13 +532,13 @@ impl<Cont> Polynomial<Cont> {
                     let update = self
                         .as_tensor()
                         .get_element(target_degree)
-                        .wrapping_add(*lhsi.get_coefficient() * *rhsi.get_coefficient());
+                        .wrapping_add(lhsi.get_coefficient().wrapping_mul(*rhsi.get_coefficient()));
                     *self.as_mut_tensor().get_element_mut(target_degree) = update;
                 } else {
                     let update = self
                         .as_tensor()
                         .get_element(target_degree % (degree + 1))
-                        .wrapping_sub(*lhsi.get_coefficient() * *rhsi.get_coefficient());
+                        .wrapping_sub(lhsi.get_coefficient().wrapping_mul(*rhsi.get_coefficient()));
                     *self
                         .as_mut_tensor()
                         .get_element_mut(target_degree % (degree + 1)) = update;
@@ -585,13 +585,13 @@ impl<Cont> Polynomial<Cont> {
                     let update = self
                         .as_tensor()
                         .get_element(target_degree)
-                        .wrapping_sub(*lhsi.get_coefficient() * *rhsi.get_coefficient());
+                        .wrapping_sub(lhsi.get_coefficient().wrapping_mul(*rhsi.get_coefficient()));
                     *self.as_mut_tensor().get_element_mut(target_degree) = update;
                 } else {
                     let update = self
                         .as_tensor()
                         .get_element(target_degree % (degree + 1))
-                        .wrapping_add(*lhsi.get_coefficient() * *rhsi.get_coefficient());
+                        .wrapping_add(lhsi.get_coefficient().wrapping_mul(*rhsi.get_coefficient()));
                     *self
                         .as_mut_tensor()
                         .as_mut_slice()
@@ -738,6 +738,52 @@ impl<Cont> Polynomial<Cont> {
             .for_each(|a| *a = a.wrapping_neg());
     }
 
+    /// Fills the current polynomial with the image of the `input` polynomial by the ring
+    /// automorphism $X \mapsto X^k$ (mod $(X^N+1)$), where $k$ is the (odd) `exponent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use concrete_commons::parameters::AutomorphismExponent;
+    /// use concrete_core::commons::math::polynomial::{MonomialDegree, Polynomial};
+    /// let input = Polynomial::from_container(vec![1u8, 2, 3, 4]);
+    /// let mut poly = Polynomial::from_container(vec![0u8; 4]);
+    /// // X -> X^3 sends X^2 to X^6 = -X^2 and X^3 to X^9 = X
+    /// poly.fill_with_automorphism(&input, AutomorphismExponent(3));
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 1);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 4);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(2)).get_coefficient(), 253);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(3)).get_coefficient(), 2);
+    /// ```
+    pub fn fill_with_automorphism<Coef, InCont>(
+        &mut self,
+        input: &Polynomial<InCont>,
+        exponent: AutomorphismExponent,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<InCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(
+            self.polynomial_size() =>
+            input.polynomial_size()
+        );
+        debug_assert!(
+            exponent.0 % 2 == 1,
+            "The automorphism exponent must be odd."
+        );
+        let poly_size = self.polynomial_size().0;
+        let output = self.as_mut_tensor().as_mut_slice();
+        for (degree, coefficient) in input.as_tensor().iter().enumerate() {
+            let image_degree = (degree * exponent.0) % (2 * poly_size);
+            if image_degree < poly_size {
+                output[image_degree] = *coefficient;
+            } else {
+                output[image_degree - poly_size] = coefficient.wrapping_neg();
+            }
+        }
+    }
+
     /// Adds multiple integer polynomials to the current one.
     ///
     /// # Examples
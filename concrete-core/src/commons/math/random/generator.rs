@@ -4,7 +4,9 @@ use crate::commons::math::random::{
 };
 use crate::commons::math::tensor::{AsMutSlice, AsMutTensor, Tensor};
 use concrete_commons::numeric::{FloatingPoint, Numeric};
-use concrete_csprng::generators::{BytesPerChild, ChildrenCount, ForkError};
+use concrete_csprng::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, SeekError, StateError,
+};
 #[cfg(feature = "__commons_parallel")]
 use rayon::prelude::*;
 use std::convert::TryInto;
@@ -18,8 +20,9 @@ pub use concrete_csprng::seeders::{Seed, Seeder};
 /// dependency to `concrete-csprng`
 #[cfg(feature = "__commons_serialization")]
 pub mod serialization_proxy {
+    pub(crate) use concrete_csprng::generators::{AesIndex, ByteIndex, GeneratorState, TableIndex};
     pub(crate) use concrete_csprng::seeders::Seed;
-    pub(crate) use serde::{Deserialize, Serialize};
+    pub(crate) use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
     // See https://serde.rs/remote-derive.html
     // Serde calls this the definition of the remote type. It is just a copy of the remote data
@@ -28,6 +31,41 @@ pub mod serialization_proxy {
     #[derive(Serialize, Deserialize)]
     #[serde(remote = "Seed")]
     pub(crate) struct SeedSerdeDef(pub u128);
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(remote = "GeneratorState")]
+    pub(crate) struct GeneratorStateSerdeDef {
+        #[serde(with = "SeedSerdeDef")]
+        pub seed: Seed,
+        #[serde(with = "table_index_serde")]
+        pub first: TableIndex,
+        #[serde(with = "table_index_serde")]
+        pub next: TableIndex,
+        #[serde(with = "table_index_serde")]
+        pub bound: TableIndex,
+    }
+
+    // The fields of a table index are not public, so it is serialized as the pair of its aes index
+    // and byte index.
+    pub(crate) mod table_index_serde {
+        use super::*;
+        use serde::de::Error;
+
+        pub(crate) fn serialize<S: Serializer>(
+            index: &TableIndex,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            (index.aes_index().0, index.byte_index().0).serialize(serializer)
+        }
+
+        pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<TableIndex, D::Error> {
+            let (aes_index, byte_index) = <(u128, usize)>::deserialize(deserializer)?;
+            TableIndex::try_new(AesIndex(aes_index), ByteIndex(byte_index))
+                .ok_or_else(|| D::Error::custom("The byte index of the table index is too large."))
+        }
+    }
 }
 
 #[cfg(feature = "__commons_serialization")]
@@ -136,6 +174,56 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
             .map(|iter| iter.map(Self))
     }
 
+    /// Moves the generator so that the next generated byte is the `byte`-th one it is allowed to
+    /// output. For a generator created with [`RandomGenerator::new`], this is the `byte`-th byte
+    /// of the stream generated from the seed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::math::random::RandomGenerator;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let bytes = (0..100).map(|_| generator.generate_next()).collect::<Vec<_>>();
+    ///
+    /// let mut jumped_generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// jumped_generator.jump_to_byte(42).unwrap();
+    /// assert_eq!(jumped_generator.generate_next(), bytes[42]);
+    /// ```
+    pub fn jump_to_byte(&mut self, byte: usize) -> Result<(), SeekError> {
+        self.0.jump_to_byte(ByteCount(byte as u128))
+    }
+
+    /// Returns the state of the generator, from which it can be restored with
+    /// [`RandomGenerator::try_from_state`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::commons::math::random::RandomGenerator;
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// generator.generate_next();
+    /// let state = generator.get_state();
+    ///
+    /// let mut restored_generator =
+    ///     RandomGenerator::<SoftwareRandomGenerator>::try_from_state(state).unwrap();
+    /// assert_eq!(restored_generator.get_state(), state);
+    /// for _ in 0..100 {
+    ///     assert_eq!(generator.generate_next(), restored_generator.generate_next());
+    /// }
+    /// ```
+    pub fn get_state(&self) -> GeneratorState {
+        self.0.get_state()
+    }
+
+    /// Tries to restore a generator from a state returned by [`RandomGenerator::get_state`].
+    pub fn try_from_state(state: GeneratorState) -> Result<RandomGenerator<G>, StateError> {
+        G::try_from_state(state).map(Self)
+    }
+
     /// Generates a random uniform unsigned integer.
     ///
     /// # Example
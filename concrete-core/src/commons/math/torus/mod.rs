@@ -100,3 +100,7 @@ impl UnsignedTorus for u32 {
 impl UnsignedTorus for u64 {
     const GAUSSIAN_KEY_LOG_STD: LogStandardDev = LogStandardDev(-62.32192809488736);
 }
+
+impl UnsignedTorus for u128 {
+    const GAUSSIAN_KEY_LOG_STD: LogStandardDev = LogStandardDev(-126.32192809488736);
+}
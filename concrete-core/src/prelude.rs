@@ -21,6 +21,12 @@ pub use super::backends::fft::engines::*;
 #[cfg(feature = "backend_fft")]
 pub use super::backends::fft::entities::*;
 
+// ------------------------------------------------------------------------------------- NPE BACKEND
+#[cfg(feature = "backend_npe")]
+pub use super::backends::npe::engines::*;
+#[cfg(feature = "backend_npe")]
+pub use super::backends::npe::entities::*;
+
 // ------------------------------------------------------------------------------------ CUDA BACKEND
 #[cfg(all(feature = "backend_cuda", not(feature = "_ci_do_not_compile")))]
 pub use super::backends::cuda::engines::*;
@@ -38,8 +44,13 @@ pub use concrete_commons::*;
 // --------------------------------------------------------------------------------- CSPRNG REEXPORT
 // Re-export the different seeders of the `concrete-csprng` crate, which are needed to construct
 // default engines.
+#[cfg(feature = "seeder_getrandom")]
+pub use concrete_csprng::seeders::GetrandomSeeder;
+pub use concrete_csprng::seeders::HealthCheckedSeeder;
 #[cfg(feature = "seeder_x86_64_rdseed")]
 pub use concrete_csprng::seeders::RdseedSeeder;
+pub use concrete_csprng::seeders::Seed;
 pub use concrete_csprng::seeders::Seeder;
+pub use concrete_csprng::seeders::SeederError;
 #[cfg(feature = "seeder_unix")]
 pub use concrete_csprng::seeders::UnixSeeder;
@@ -0,0 +1,33 @@
+use super::engine_error;
+use crate::prelude::AbstractEntity;
+use crate::specification::engines::AbstractEngine;
+
+engine_error! {
+    EntityStreamDeserializationError for EntityStreamDeserializationEngine @
+}
+
+/// A trait for engines deserializing entities from a stream.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an entity by reading its
+/// serialization from the `reader` source. Only the bytes of the serialized entity are consumed,
+/// which makes it possible to read several entities in a row from the same source.
+pub trait EntityStreamDeserializationEngine<Reader, Entity>: AbstractEngine
+where
+    Entity: AbstractEntity,
+{
+    /// Deserializes an entity from a stream.
+    fn deserialize_from(
+        &mut self,
+        reader: Reader,
+    ) -> Result<Entity, EntityStreamDeserializationError<Self::EngineError>>;
+
+    /// Unsafely deserializes an entity from a stream.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityStreamDeserializationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn deserialize_from_unchecked(&mut self, reader: Reader) -> Entity;
+}
@@ -0,0 +1,35 @@
+use super::engine_error;
+use crate::prelude::AbstractEntity;
+use crate::specification::engines::AbstractEngine;
+
+engine_error! {
+    EntityStreamSerializationError for EntityStreamSerializationEngine @
+}
+
+/// A trait for engines serializing entities to a stream.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation writes the serialization of `entity` to
+/// the `writer` sink. The bytes written are the same as the ones produced by the
+/// [`EntitySerializationEngine`](super::EntitySerializationEngine) of the same engine, without
+/// the whole serialization being materialized in memory.
+pub trait EntityStreamSerializationEngine<Entity, Writer>: AbstractEngine
+where
+    Entity: AbstractEntity,
+{
+    /// Serializes an entity to a stream.
+    fn serialize_into(
+        &mut self,
+        entity: &Entity,
+        writer: Writer,
+    ) -> Result<(), EntityStreamSerializationError<Self::EngineError>>;
+
+    /// Unsafely serializes an entity to a stream.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityStreamSerializationError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn serialize_into_unchecked(&mut self, entity: &Entity, writer: Writer);
+}
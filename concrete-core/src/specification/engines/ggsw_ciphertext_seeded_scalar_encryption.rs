@@ -0,0 +1,97 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GgswSeededCiphertextEntity, GlweSecretKeyEntity, PlaintextEntity,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+engine_error! {
+    GgswCiphertextSeededScalarEncryptionError for GgswCiphertextSeededScalarEncryptionEngine @
+    NullDecompositionBaseLog => "The ciphertext decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The ciphertext decomposition level count must be greater than \
+                                    zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext."
+}
+
+impl<EngineError: std::error::Error> GgswCiphertextSeededScalarEncryptionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        integer_precision: usize,
+    ) -> Result<(), Self> {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > integer_precision {
+            return Err(Self::DecompositionTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines encrypting seeded GGSW ciphertexts containing a single plaintext.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a seeded GGSW ciphertext containing
+/// the encryption of the `input` plaintext, under the `key` secret key.
+///
+/// # Formal Definition
+///
+/// ## Seeded GGSW Encryption
+/// ###### inputs:
+/// - $\mathsf{pt}\in\mathbb{Z}\_q$: a plaintext
+/// - $\vec{S} \in\mathcal{R}\_q^k$: a secret key
+/// - $\mathsf{seed} \in\mathcal{S}$: a public seed
+/// - $G$: a CSPRNG working with seeds from $\mathcal{S}$
+/// - $\mathcal{D\_{\sigma^2,\mu}}$: a normal distribution of variance $\sigma^2$ and a mean $\mu$
+/// - $\ell$: the number of decomposition levels
+/// - $\beta$: the decomposition base
+///
+/// ###### outputs:
+/// - $\tilde{\overline{\overline{\mathsf{CT}}}} \in \mathsf{SeededGGSW}^{k, \ell}\_{\vec{S}, G,
+///   \beta}( \mathsf{pt})$: a seeded GGSW ciphertext
+///
+/// ###### algorithm:
+/// 1. for each level $1\leq j\leq \ell$ and each row $1\leq i\leq k+1$, compute the seeded GLWE
+/// encryption of $\mathsf{pt}\cdot\frac{q}{\beta^j}$, multiplied by $-S\_i$ for $i\leq k$, under
+/// $\vec{S}$, drawing the masks from $G$ seeded with $\mathsf{seed}$
+/// 2. output $\mathsf{seed}$ along with the bodies of the $\ell(k+1)$ seeded GLWE ciphertexts
+pub trait GgswCiphertextSeededScalarEncryptionEngine<SecretKey, Plaintext, Ciphertext>:
+    AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    Plaintext: PlaintextEntity,
+    Ciphertext: GgswSeededCiphertextEntity,
+{
+    /// Encrypts a plaintext into a seeded GGSW ciphertext.
+    fn encrypt_scalar_ggsw_seeded_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<Ciphertext, GgswCiphertextSeededScalarEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts a plaintext into a seeded GGSW ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswCiphertextSeededScalarEncryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn encrypt_scalar_ggsw_seeded_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Ciphertext;
+}
@@ -0,0 +1,60 @@
+use super::engine_error;
+use crate::prelude::AbstractEngine;
+
+use crate::specification::entities::{GgswCiphertextEntity, GgswSeededCiphertextEntity};
+
+engine_error! {
+    GgswSeededCiphertextToGgswCiphertextTransformationError for GgswSeededCiphertextToGgswCiphertextTransformationEngine @
+}
+
+/// A trait for engines transforming GGSW seeded ciphertexts into GGSW ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation moves the existing GGSW seeded ciphertext into
+/// a GGSW ciphertext.
+///
+/// # Formal Definition
+///
+/// ## GGSW seeded ciphertext to GGSW ciphertext transformation
+/// ###### inputs:
+/// - $G$: a CSPRNG working with seeds from $\mathcal{S}$
+/// - $\tilde{\overline{\overline{\mathsf{CT}}}} \in \mathsf{SeededGGSW}^{k, \ell}\_{\vec{S}, G,
+///   \beta}( \mathsf{pt})$: a seeded GGSW ciphertext
+///
+/// ###### outputs:
+/// - $\overline{\overline{\mathsf{CT}}} \in \mathsf{GGSW}^{k, \ell}\_{\vec{S}, \beta}(
+///   \mathsf{pt})$: a GGSW ciphertext
+///
+/// ###### algorithm:
+/// 1. for each of the $\ell(k+1)$ GLWE ciphertexts of the GGSW ciphertext, uniformly sample each
+/// coefficient of the polynomial vector $\vec{A}\in\mathcal{R}^k\_q$ from $G$ with the seed
+/// $\mathsf{seed}\in\mathcal{S}$, in the same order as during encryption
+/// 2. output the $\ell(k+1)$ GLWE ciphertexts $\left( \vec{A} , B \right)$
+pub trait GgswSeededCiphertextToGgswCiphertextTransformationEngine<
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    InputCiphertext: GgswSeededCiphertextEntity,
+    OutputCiphertext: GgswCiphertextEntity,
+{
+    /// Does the transformation of the GGSW seeded ciphertext into a GGSW ciphertext
+    fn transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext(
+        &mut self,
+        ggsw_seeded_ciphertext: InputCiphertext,
+    ) -> Result<
+        OutputCiphertext,
+        GgswSeededCiphertextToGgswCiphertextTransformationError<Self::EngineError>,
+    >;
+
+    /// Unsafely transforms a GGSW seeded ciphertext into a GGSW ciphertext
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswSeededCiphertextToGgswCiphertextTransformationError`].
+    /// For safety concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn transform_ggsw_seeded_ciphertext_to_ggsw_ciphertext_unchecked(
+        &mut self,
+        ggsw_seeded_ciphertext: InputCiphertext,
+    ) -> OutputCiphertext;
+}
@@ -0,0 +1,86 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweAutomorphismKeyEntity, GlweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount,
+};
+
+engine_error! {
+    GlweAutomorphismKeyGenerationError for GlweAutomorphismKeyGenerationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext.",
+    EvenAutomorphismExponent => "The automorphism exponents must be odd."
+}
+
+impl<EngineError: std::error::Error> GlweAutomorphismKeyGenerationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        exponents: &[AutomorphismExponent],
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        integer_precision: usize,
+    ) -> Result<(), Self> {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+
+        if decomposition_level_count.0 * decomposition_base_log.0 > integer_precision {
+            return Err(Self::DecompositionTooLarge);
+        }
+
+        if exponents.iter().any(|exponent| exponent.0 % 2 == 0) {
+            return Err(Self::EvenAutomorphismExponent);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new GLWE automorphism keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new GLWE automorphism key
+/// allowing to apply the automorphisms $X \mapsto X^k$ to GLWE ciphertexts encrypted under the
+/// `input_key` GLWE secret key, for every $k$ in `exponents`.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::GlweAutomorphismKeyEntity`)
+pub trait GlweAutomorphismKeyGenerationEngine<SecretKey, AutomorphismKey>: AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    AutomorphismKey: GlweAutomorphismKeyEntity,
+{
+    /// Generates a new GLWE automorphism key.
+    fn generate_new_glwe_automorphism_key(
+        &mut self,
+        input_key: &SecretKey,
+        exponents: &[AutomorphismExponent],
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<AutomorphismKey, GlweAutomorphismKeyGenerationError<Self::EngineError>>;
+
+    /// Unsafely generates a new GLWE automorphism key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweAutomorphismKeyGenerationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn generate_new_glwe_automorphism_key_unchecked(
+        &mut self,
+        input_key: &SecretKey,
+        exponents: &[AutomorphismExponent],
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> AutomorphismKey;
+}
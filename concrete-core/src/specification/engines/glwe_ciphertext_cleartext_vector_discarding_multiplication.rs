@@ -0,0 +1,94 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{CleartextVectorEntity, GlweCiphertextEntity};
+
+engine_error! {
+    GlweCiphertextCleartextVectorDiscardingMultiplicationError for GlweCiphertextCleartextVectorDiscardingMultiplicationEngine @
+    GlweDimensionMismatch => "The input and output ciphertext GLWE dimensions must be the same.",
+    PolynomialSizeMismatch => "The input and output ciphertext polynomial sizes must be the \
+    same.",
+    CleartextCountMismatch => "The cleartext count of the input vector and the ciphertext \
+    polynomial size must be the same."
+}
+
+impl<EngineError: std::error::Error>
+    GlweCiphertextCleartextVectorDiscardingMultiplicationError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext, CleartextVector, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &CleartextVector,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext: GlweCiphertextEntity,
+        CleartextVector: CleartextVectorEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if output.glwe_dimension() != input_1.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input_1.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if input_2.cleartext_count().0 != input_1.polynomial_size().0 {
+            return Err(Self::CleartextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines multiplying (discarding) GLWE ciphertexts by cleartext vectors.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the multiplication of the `input_1` GLWE ciphertext with the `input_2` cleartext vector, seen
+/// as a polynomial.
+///
+/// # Formal Definition
+///
+/// ## GLWE cleartext polynomial multiplication
+/// ###### inputs:
+/// - $\mathsf{CT} = \left( \vec{A}, B\right) \in \mathsf{GLWE}\_{\vec{S}} \left( \mathsf{PT}
+///   \right) \subseteq \mathcal{R}\_q^{k+1}$: a GLWE ciphertext
+/// - $P \in \mathcal{R}\_q$: a cleartext polynomial, given as the vector of its coefficients
+///
+/// ###### outputs:
+/// - $\mathsf{CT}' = \left( \vec{A}' , B' \right) \in \mathsf{GLWE}\_{\vec{S}}( P \cdot
+///   \mathsf{PT} )\subseteq \mathcal{R}\_q^{k+1}$: a GLWE ciphertext
+///
+/// ###### algorithm:
+/// 1. Compute $\vec{A}' = P \cdot \vec{A} \in\mathcal{R}^k\_q$
+/// 2. Compute $B' = P \cdot B \in\mathcal{R}\_q$
+/// 3. Output $\left( \vec{A}' , B' \right)$
+pub trait GlweCiphertextCleartextVectorDiscardingMultiplicationEngine<
+    InputCiphertext,
+    CleartextVector,
+    OutputCiphertext,
+>: AbstractEngine where
+    InputCiphertext: GlweCiphertextEntity,
+    CleartextVector: CleartextVectorEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Multiplies a GLWE ciphertext with a cleartext vector.
+    fn discard_mul_glwe_ciphertext_cleartext_vector(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &CleartextVector,
+    ) -> Result<(), GlweCiphertextCleartextVectorDiscardingMultiplicationError<Self::EngineError>>;
+
+    /// Unsafely multiplies a GLWE ciphertext with a cleartext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextCleartextVectorDiscardingMultiplicationError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_mul_glwe_ciphertext_cleartext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &CleartextVector,
+    );
+}
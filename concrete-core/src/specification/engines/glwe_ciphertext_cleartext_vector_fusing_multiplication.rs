@@ -0,0 +1,65 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{CleartextVectorEntity, GlweCiphertextEntity};
+
+engine_error! {
+    GlweCiphertextCleartextVectorFusingMultiplicationError for GlweCiphertextCleartextVectorFusingMultiplicationEngine @
+    CleartextCountMismatch => "The cleartext count of the input vector and the ciphertext \
+    polynomial size must be the same."
+}
+
+impl<EngineError: std::error::Error>
+    GlweCiphertextCleartextVectorFusingMultiplicationError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<Ciphertext, CleartextVector>(
+        output: &Ciphertext,
+        input: &CleartextVector,
+    ) -> Result<(), Self>
+    where
+        Ciphertext: GlweCiphertextEntity,
+        CleartextVector: CleartextVectorEntity,
+    {
+        if input.cleartext_count().0 != output.polynomial_size().0 {
+            return Err(Self::CleartextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines multiplying (fusing) GLWE ciphertexts by cleartext vectors.
+///
+/// # Semantics
+///
+/// This [fusing](super#operation-semantics) operation multiplies the `output` GLWE ciphertext with
+/// the `input` cleartext vector, seen as a polynomial.
+///
+/// # Formal Definition
+///
+/// cf
+/// [`here`](`crate::specification::engines::GlweCiphertextCleartextVectorDiscardingMultiplicationEngine`)
+pub trait GlweCiphertextCleartextVectorFusingMultiplicationEngine<Ciphertext, CleartextVector>:
+    AbstractEngine
+where
+    Ciphertext: GlweCiphertextEntity,
+    CleartextVector: CleartextVectorEntity,
+{
+    /// Multiplies a GLWE ciphertext with a cleartext vector.
+    fn fuse_mul_glwe_ciphertext_cleartext_vector(
+        &mut self,
+        output: &mut Ciphertext,
+        input: &CleartextVector,
+    ) -> Result<(), GlweCiphertextCleartextVectorFusingMultiplicationError<Self::EngineError>>;
+
+    /// Unsafely multiplies a GLWE ciphertext with a cleartext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextCleartextVectorFusingMultiplicationError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn fuse_mul_glwe_ciphertext_cleartext_vector_unchecked(
+        &mut self,
+        output: &mut Ciphertext,
+        input: &CleartextVector,
+    );
+}
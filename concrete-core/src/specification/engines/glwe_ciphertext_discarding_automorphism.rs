@@ -0,0 +1,119 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweAutomorphismKeyEntity, GlweCiphertextEntity};
+use concrete_commons::parameters::AutomorphismExponent;
+
+engine_error! {
+    GlweCiphertextDiscardingAutomorphismError for GlweCiphertextDiscardingAutomorphismEngine @
+    GlweDimensionMismatch => "The input ciphertext, output ciphertext and automorphism key GLWE \
+                              dimensions must be the same.",
+    PolynomialSizeMismatch => "The input ciphertext, output ciphertext and automorphism key \
+                               polynomial sizes must be the same.",
+    UnsupportedAutomorphismExponent => "The automorphism key must support the automorphism \
+                                        exponent."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingAutomorphismError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<AutomorphismKey, InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        key: &AutomorphismKey,
+        exponent: AutomorphismExponent,
+    ) -> Result<(), Self>
+    where
+        AutomorphismKey: GlweAutomorphismKeyEntity,
+        InputCiphertext: GlweCiphertextEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if input.glwe_dimension() != key.glwe_dimension()
+            || output.glwe_dimension() != key.glwe_dimension()
+        {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if input.polynomial_size() != key.polynomial_size()
+            || output.polynomial_size() != key.polynomial_size()
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        let modulus = 2 * key.polynomial_size().0;
+        if !key
+            .automorphism_exponents()
+            .iter()
+            .any(|supported| supported.0 % modulus == exponent.0 % modulus)
+        {
+            return Err(Self::UnsupportedAutomorphismExponent);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines applying ring automorphisms (discarding) to GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the image of the `input` GLWE ciphertext by the automorphism $X \mapsto X^k$, where $k$ is the
+/// `exponent`, using the `key` GLWE automorphism key.
+///
+/// # Formal Definition
+///
+/// ## GLWE Automorphism
+///
+/// This homomorphic procedure transforms an input
+/// [`GLWE ciphertext`](`crate::specification::entities::GlweCiphertextEntity`)
+/// $\mathsf{CT} = \left( \vec{A}, B\right)$ encrypting a plaintext $\mathsf{PT}$ under a GLWE
+/// secret key $\vec{S}$ into an output GLWE ciphertext encrypting $\tau\_k(\mathsf{PT})$ under the
+/// same key, where $\tau\_k$ is the ring automorphism $X \mapsto X^k$ for an odd $k$. It requires a
+/// [`GLWE automorphism key`](`crate::specification::entities::GlweAutomorphismKeyEntity`)
+/// supporting $k$.
+///
+/// ## Algorithm
+/// ###### inputs:
+/// - $\mathsf{CT} = \left( \vec{A}, B\right)$: a GLWE ciphertext with
+///   $\vec{A}=\left(A\_0, \cdots A\_{k-1}\right)$
+/// - $\mathsf{KSK}\_{\tau\_k(\vec{S}) \rightarrow \vec{S}}$: the GLWE keyswitch key of the
+///   automorphism key associated with $k$
+///
+/// ###### outputs:
+/// - $\mathsf{CT}\_{\mathsf{out}}$: a GLWE ciphertext encrypting $\tau\_k(\mathsf{PT})$ under
+///   $\vec{S}$
+///
+/// ###### algorithm:
+/// 1. set $\mathsf{CT}' = \left( \tau\_k(A\_0), \cdots, \tau\_k(A\_{k-1}), \tau\_k(B) \right)$,
+///    which encrypts $\tau\_k(\mathsf{PT})$ under $\tau\_k(\vec{S})$
+/// 2. keyswitch $\mathsf{CT}'$ with $\mathsf{KSK}\_{\tau\_k(\vec{S}) \rightarrow \vec{S}}$ to get
+///    $\mathsf{CT}\_{\mathsf{out}}$
+/// 3. output $\mathsf{CT}\_{\mathsf{out}}$
+pub trait GlweCiphertextDiscardingAutomorphismEngine<
+    AutomorphismKey,
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    AutomorphismKey: GlweAutomorphismKeyEntity,
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Applies an automorphism to a GLWE ciphertext.
+    fn discard_automorphism_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        key: &AutomorphismKey,
+        exponent: AutomorphismExponent,
+    ) -> Result<(), GlweCiphertextDiscardingAutomorphismError<Self::EngineError>>;
+
+    /// Unsafely applies an automorphism to a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingAutomorphismError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_automorphism_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        key: &AutomorphismKey,
+        exponent: AutomorphismExponent,
+    );
+}
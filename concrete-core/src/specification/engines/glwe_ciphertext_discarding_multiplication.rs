@@ -0,0 +1,124 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, GlweRelinearizationKeyEntity};
+use concrete_commons::parameters::DeltaLog;
+
+engine_error! {
+    GlweCiphertextDiscardingMultiplicationError for GlweCiphertextDiscardingMultiplicationEngine @
+    InputGlweDimensionMismatch => "The input ciphertexts and key GLWE dimensions must be the same.",
+    OutputGlweDimensionMismatch => "The output ciphertext and key GLWE dimensions must be the same.",
+    InputPolynomialSizeMismatch => "The input ciphertexts and key polynomial sizes must be the same.",
+    OutputPolynomialSizeMismatch => "The output ciphertext and key polynomial sizes must be the \
+                                     same.",
+    DeltaLogTooLarge => "The scaling factor log must not exceed the precision of the ciphertexts."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingMultiplicationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<
+        RelinearizationKey,
+        InputCiphertext1,
+        InputCiphertext2,
+        OutputCiphertext,
+    >(
+        output: &OutputCiphertext,
+        input1: &InputCiphertext1,
+        input2: &InputCiphertext2,
+        relinearization_key: &RelinearizationKey,
+        delta_log: DeltaLog,
+        integer_precision: usize,
+    ) -> Result<(), Self>
+    where
+        RelinearizationKey: GlweRelinearizationKeyEntity,
+        InputCiphertext1: GlweCiphertextEntity,
+        InputCiphertext2: GlweCiphertextEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if input1.glwe_dimension() != relinearization_key.glwe_dimension()
+            || input2.glwe_dimension() != relinearization_key.glwe_dimension()
+        {
+            return Err(Self::InputGlweDimensionMismatch);
+        }
+        if output.glwe_dimension() != relinearization_key.glwe_dimension() {
+            return Err(Self::OutputGlweDimensionMismatch);
+        }
+        if input1.polynomial_size() != relinearization_key.polynomial_size()
+            || input2.polynomial_size() != relinearization_key.polynomial_size()
+        {
+            return Err(Self::InputPolynomialSizeMismatch);
+        }
+        if output.polynomial_size() != relinearization_key.polynomial_size() {
+            return Err(Self::OutputPolynomialSizeMismatch);
+        }
+        if delta_log.0 > integer_precision {
+            return Err(Self::DeltaLogTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines multiplying (discarding) GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the product of the `input1` and `input2` GLWE ciphertexts, rescaled by
+/// $2^{-\mathsf{delta\\_log}}$. The product is computed as a
+/// [`tensor product`](`super::GlweCiphertextTensorProductEngine`) followed by a
+/// [`relinearization`](`super::GlweCiphertextDiscardingRelinearizationEngine`) with the
+/// `relinearization_key`, so that the `output` is encrypted under the same key as the inputs.
+///
+/// # Formal Definition
+///
+/// ## GLWE Multiplication
+///
+/// ###### inputs:
+/// - $\mathsf{CT}\_1 \in \mathsf{GLWE}\_{\vec{S}}( \Delta\_1 \cdot M\_1 )$: a GLWE ciphertext
+/// - $\mathsf{CT}\_2 \in \mathsf{GLWE}\_{\vec{S}}( \Delta\_2 \cdot M\_2 )$: a GLWE ciphertext
+/// - $\mathsf{RLK}$: a relinearization key for $\vec{S}$
+/// - $\Delta = 2^{\mathsf{delta\\_log}}$: the scaling factor removed from the product
+///
+/// ###### outputs:
+/// - $\mathsf{CT}\_{\mathsf{out}} \in \mathsf{GLWE}\_{\vec{S}}( \frac{\Delta\_1 \Delta\_2}{\Delta}
+///   \cdot M\_1 M\_2 )$: a GLWE ciphertext
+///
+/// ###### algorithm:
+/// 1. Compute the tensor product $\mathsf{CT}$ of $\mathsf{CT}\_1$ and $\mathsf{CT}\_2$, rescaled
+/// by $\Delta$
+/// 2. Output the relinearization of $\mathsf{CT}$ with $\mathsf{RLK}$
+pub trait GlweCiphertextDiscardingMultiplicationEngine<
+    RelinearizationKey,
+    InputCiphertext1,
+    InputCiphertext2,
+    OutputCiphertext,
+>: AbstractEngine where
+    RelinearizationKey: GlweRelinearizationKeyEntity,
+    InputCiphertext1: GlweCiphertextEntity,
+    InputCiphertext2: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Multiplies two GLWE ciphertexts.
+    fn discard_mul_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input1: &InputCiphertext1,
+        input2: &InputCiphertext2,
+        relinearization_key: &RelinearizationKey,
+        delta_log: DeltaLog,
+    ) -> Result<(), GlweCiphertextDiscardingMultiplicationError<Self::EngineError>>;
+
+    /// Unsafely multiplies two GLWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingMultiplicationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_mul_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input1: &InputCiphertext1,
+        input2: &InputCiphertext2,
+        relinearization_key: &RelinearizationKey,
+        delta_log: DeltaLog,
+    );
+}
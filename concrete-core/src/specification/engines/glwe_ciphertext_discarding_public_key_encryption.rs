@@ -0,0 +1,84 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GlweCiphertextEntity, GlwePublicKeyEntity, PlaintextVectorEntity,
+};
+use concrete_commons::dispersion::Variance;
+
+engine_error! {
+    GlweCiphertextDiscardingPublicKeyEncryptionError for
+    GlweCiphertextDiscardingPublicKeyEncryptionEngine @
+    GlweDimensionMismatch => "The GLWE dimension of the public key and ciphertext must be the \
+                              same.",
+    PolynomialSizeMismatch => "The polynomial size of the public key and ciphertext must be the \
+                               same.",
+    PlaintextCountMismatch => "The size of the input plaintext vector and the output ciphertext \
+                               polynomial size must be the same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingPublicKeyEncryptionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<PublicKey, PlaintextVector, Ciphertext>(
+        key: &PublicKey,
+        output: &Ciphertext,
+        input: &PlaintextVector,
+    ) -> Result<(), Self>
+    where
+        PublicKey: GlwePublicKeyEntity,
+        PlaintextVector: PlaintextVectorEntity,
+        Ciphertext: GlweCiphertextEntity,
+    {
+        if key.polynomial_size() != output.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if key.glwe_dimension() != output.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if key.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(Self::PlaintextCountMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines encrypting (discarding) GLWE ciphertexts with a public key.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the encryption of the `input` plaintext vector, using the public `key`. The ciphertext can be
+/// decrypted by the secret key used to generate the public key.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::GlwePublicKeyEntity`)
+pub trait GlweCiphertextDiscardingPublicKeyEncryptionEngine<PublicKey, PlaintextVector, Ciphertext>:
+    AbstractEngine
+where
+    PublicKey: GlwePublicKeyEntity,
+    PlaintextVector: PlaintextVectorEntity,
+    Ciphertext: GlweCiphertextEntity,
+{
+    /// Encrypts a GLWE ciphertext using a public key.
+    fn discard_encrypt_glwe_ciphertext_with_public_key(
+        &mut self,
+        key: &PublicKey,
+        output: &mut Ciphertext,
+        input: &PlaintextVector,
+        noise: Variance,
+    ) -> Result<(), GlweCiphertextDiscardingPublicKeyEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts a GLWE ciphertext using a public key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingPublicKeyEncryptionError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn discard_encrypt_glwe_ciphertext_with_public_key_unchecked(
+        &mut self,
+        key: &PublicKey,
+        output: &mut Ciphertext,
+        input: &PlaintextVector,
+        noise: Variance,
+    );
+}
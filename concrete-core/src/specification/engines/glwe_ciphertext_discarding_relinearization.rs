@@ -0,0 +1,101 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, GlweRelinearizationKeyEntity};
+
+engine_error! {
+    GlweCiphertextDiscardingRelinearizationError for GlweCiphertextDiscardingRelinearizationEngine @
+    InputGlweDimensionMismatch => "The input ciphertext GLWE dimension must be equal to k(k+3)/2, \
+                                   where k is the key GLWE dimension.",
+    OutputGlweDimensionMismatch => "The output ciphertext and key GLWE dimensions must be the same.",
+    InputPolynomialSizeMismatch => "The input ciphertext and key polynomial sizes must be the same.",
+    OutputPolynomialSizeMismatch => "The output ciphertext and key polynomial sizes must be the \
+                                     same."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingRelinearizationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<RelinearizationKey, InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        relinearization_key: &RelinearizationKey,
+    ) -> Result<(), Self>
+    where
+        RelinearizationKey: GlweRelinearizationKeyEntity,
+        InputCiphertext: GlweCiphertextEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        let key_dimension = relinearization_key.glwe_dimension().0;
+        if input.glwe_dimension().0 != key_dimension * (key_dimension + 3) / 2 {
+            return Err(Self::InputGlweDimensionMismatch);
+        }
+        if output.glwe_dimension() != relinearization_key.glwe_dimension() {
+            return Err(Self::OutputGlweDimensionMismatch);
+        }
+        if input.polynomial_size() != relinearization_key.polynomial_size() {
+            return Err(Self::InputPolynomialSizeMismatch);
+        }
+        if output.polynomial_size() != relinearization_key.polynomial_size() {
+            return Err(Self::OutputPolynomialSizeMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines relinearizing (discarding) GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the relinearization of the `input` GLWE ciphertext, using the `relinearization_key`. The
+/// `input` ciphertext is expected to be the tensor product (see
+/// [`GlweCiphertextTensorProductEngine`](`super::GlweCiphertextTensorProductEngine`)) of two GLWE
+/// ciphertexts encrypted under the secret key used to generate the `relinearization_key`.
+///
+/// # Formal Definition
+///
+/// ## GLWE Relinearization
+///
+/// ###### inputs:
+/// - $\mathsf{CT} = \left( T\_0, \cdots, T\_{k-1}, R\_{0,0}, R\_{0,1}, \cdots, R\_{k-1,k-1}, B
+///   \right)$: the tensor product of two GLWE ciphertexts encrypted under $\vec{S}$
+/// - $\mathsf{RLK}$: a relinearization key made of the encryptions $\mathsf{RLK}\_{i,j,l}$ of
+///   $-S\_i S\_j \cdot \frac{q}{B^l}$ under $\vec{S}$
+///
+/// ###### outputs:
+/// - $\mathsf{CT}\_{\mathsf{out}} \in \mathsf{GLWE}\_{\vec{S}}( \mathsf{PT} )$: a GLWE ciphertext
+///
+/// ###### algorithm:
+/// 1. Set $\mathsf{CT}\_{\mathsf{out}} = \left( T\_0, \cdots, T\_{k-1}, B \right)$
+/// 2. Decompose every $R\_{i,j}$ as $\sum\_{l=1}^{\ell} R\_{i,j,l} \frac{q}{B^l}$
+/// 3. Compute $\mathsf{CT}\_{\mathsf{out}} = \mathsf{CT}\_{\mathsf{out}} + \sum\_{i\leq j}
+/// \sum\_{l=1}^{\ell} R\_{i,j,l} \cdot \mathsf{RLK}\_{i,j,l}$
+pub trait GlweCiphertextDiscardingRelinearizationEngine<
+    RelinearizationKey,
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    RelinearizationKey: GlweRelinearizationKeyEntity,
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Relinearizes a GLWE ciphertext.
+    fn discard_relinearize_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        relinearization_key: &RelinearizationKey,
+    ) -> Result<(), GlweCiphertextDiscardingRelinearizationError<Self::EngineError>>;
+
+    /// Unsafely relinearizes a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingRelinearizationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_relinearize_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        relinearization_key: &RelinearizationKey,
+    );
+}
@@ -0,0 +1,116 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweAutomorphismKeyEntity, GlweCiphertextEntity};
+
+engine_error! {
+    GlweCiphertextDiscardingTraceError for GlweCiphertextDiscardingTraceEngine @
+    GlweDimensionMismatch => "The input ciphertext, output ciphertext and automorphism key GLWE \
+                              dimensions must be the same.",
+    PolynomialSizeMismatch => "The input ciphertext, output ciphertext and automorphism key \
+                               polynomial sizes must be the same.",
+    MissingTraceAutomorphism => "The automorphism key must support the automorphisms of exponents \
+                                 2^i + 1, for 1 <= i <= log2(N)."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextDiscardingTraceError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<AutomorphismKey, InputCiphertext, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertext,
+        key: &AutomorphismKey,
+    ) -> Result<(), Self>
+    where
+        AutomorphismKey: GlweAutomorphismKeyEntity,
+        InputCiphertext: GlweCiphertextEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if input.glwe_dimension() != key.glwe_dimension()
+            || output.glwe_dimension() != key.glwe_dimension()
+        {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if input.polynomial_size() != key.polynomial_size()
+            || output.polynomial_size() != key.polynomial_size()
+        {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        let modulus = 2 * key.polynomial_size().0;
+        let supported = |exponent: usize| {
+            key.automorphism_exponents()
+                .iter()
+                .any(|supported| supported.0 % modulus == exponent % modulus)
+        };
+        if !(1..=key.polynomial_size().log2().0).all(|i| supported((1 << i) + 1)) {
+            return Err(Self::MissingTraceAutomorphism);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the trace (discarding) of GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the trace of the `input` GLWE ciphertext, using the `key` GLWE automorphism key.
+///
+/// # Formal Definition
+///
+/// ## GLWE Trace
+///
+/// The trace of a polynomial $M$ of $\mathbb{Z}\_q\[X\] / <X^N + 1>$ is the sum of its images by
+/// the $N$ ring automorphisms $\tau\_k: X \mapsto X^k$, for $k$ odd and smaller than $2N$. It is
+/// equal to $N \cdot m\_0$, where $m\_0$ is the constant coefficient of $M$.
+///
+/// This homomorphic procedure transforms an input
+/// [`GLWE ciphertext`](`crate::specification::entities::GlweCiphertextEntity`)
+/// $\mathsf{CT}$ encrypting a plaintext $\mathsf{PT}$ under a GLWE secret key $\vec{S}$ into an
+/// output GLWE ciphertext encrypting the trace of $\mathsf{PT}$ under the same key. In other
+/// words, the constant coefficient of the output plaintext is the constant coefficient of the
+/// input plaintext multiplied by $N$, and the other coefficients are zero. It requires a
+/// [`GLWE automorphism key`](`crate::specification::entities::GlweAutomorphismKeyEntity`)
+/// supporting the exponents $2^i + 1$, for $1 \leq i \leq \log\_2(N)$.
+///
+/// ## Algorithm
+/// ###### inputs:
+/// - $\mathsf{CT}$: a GLWE ciphertext
+/// - $\mathsf{AK}$: a GLWE automorphism key
+///
+/// ###### outputs:
+/// - $\mathsf{CT}\_{\mathsf{out}}$: a GLWE ciphertext encrypting the trace of $\mathsf{PT}$
+///
+/// ###### algorithm:
+/// 1. set $\mathsf{CT}\_{\mathsf{out}} = \mathsf{CT}$
+/// 2. for $i$ going from $\log\_2(N)$ down to $1$, set
+///    $\mathsf{CT}\_{\mathsf{out}} = \mathsf{CT}\_{\mathsf{out}} +
+///    \tau\_{2^i + 1}(\mathsf{CT}\_{\mathsf{out}})$, where the automorphism is applied with
+///    $\mathsf{AK}$
+/// 3. output $\mathsf{CT}\_{\mathsf{out}}$
+pub trait GlweCiphertextDiscardingTraceEngine<AutomorphismKey, InputCiphertext, OutputCiphertext>:
+    AbstractEngine
+where
+    AutomorphismKey: GlweAutomorphismKeyEntity,
+    InputCiphertext: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Computes the trace of a GLWE ciphertext.
+    fn discard_trace_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        key: &AutomorphismKey,
+    ) -> Result<(), GlweCiphertextDiscardingTraceError<Self::EngineError>>;
+
+    /// Unsafely computes the trace of a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingTraceError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn discard_trace_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        key: &AutomorphismKey,
+    );
+}
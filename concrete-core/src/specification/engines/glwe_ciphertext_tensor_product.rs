@@ -0,0 +1,100 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextEntity;
+use concrete_commons::parameters::DeltaLog;
+
+engine_error! {
+    GlweCiphertextTensorProductError for GlweCiphertextTensorProductEngine @
+    PolynomialSizeMismatch => "The polynomial size of the input ciphertexts must be the same.",
+    GlweDimensionMismatch => "The GLWE dimension of the input ciphertexts must be the same.",
+    DeltaLogTooLarge => "The scaling factor log must not exceed the precision of the ciphertexts."
+}
+
+impl<EngineError: std::error::Error> GlweCiphertextTensorProductError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputCiphertext1, InputCiphertext2>(
+        input1: &InputCiphertext1,
+        input2: &InputCiphertext2,
+        delta_log: DeltaLog,
+        integer_precision: usize,
+    ) -> Result<(), Self>
+    where
+        InputCiphertext1: GlweCiphertextEntity,
+        InputCiphertext2: GlweCiphertextEntity,
+    {
+        if input1.polynomial_size() != input2.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+        if input1.glwe_dimension() != input2.glwe_dimension() {
+            return Err(Self::GlweDimensionMismatch);
+        }
+        if delta_log.0 > integer_precision {
+            return Err(Self::DeltaLogTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the tensor product of two GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE ciphertext containing the
+/// tensor product of the `input1` and `input2` GLWE ciphertexts, rescaled by
+/// $2^{-\mathsf{delta\\_log}}$. If both inputs are encrypted under the same secret key
+/// $\vec{S}$ of dimension $k$, the output is a GLWE ciphertext of dimension $k(k+3)/2$, encrypting
+/// the product of the input plaintexts under the tensored key made of the $S\_i$ followed by the
+/// $S\_i S\_j$ for $i\leq j$.
+///
+/// # Formal Definition
+///
+/// ## GLWE Tensor Product
+///
+/// ###### inputs:
+/// - $\mathsf{CT}\_1 = \left( \vec{A}\_1 , B\_1\right) \in \mathsf{GLWE}\_{\vec{S}}( \mathsf{PT}\_1
+///   )$: a GLWE ciphertext encrypting $\Delta\_1 \cdot M\_1$
+/// - $\mathsf{CT}\_2 = \left( \vec{A}\_2 , B\_2\right) \in \mathsf{GLWE}\_{\vec{S}}( \mathsf{PT}\_2
+///   )$: a GLWE ciphertext encrypting $\Delta\_2 \cdot M\_2$
+/// - $\Delta = 2^{\mathsf{delta\\_log}}$: the scaling factor removed from the product
+///
+/// ###### outputs:
+/// - $\mathsf{CT}\_{\mathsf{out}}$: a GLWE ciphertext encrypting $\frac{\Delta\_1 \Delta\_2}{\Delta}
+///   \cdot M\_1 M\_2$ under the tensored key
+///
+/// ###### algorithm:
+/// 1. Lift the coefficients of $\mathsf{CT}\_1$ and $\mathsf{CT}\_2$ to signed integers
+/// 2. Compute $T\_i = \left\lfloor \frac{A\_{1,i} B\_2 + B\_1 A\_{2,i}}{\Delta} \right\rceil$, for
+/// $0 \leq i < k$
+/// 3. Compute $R\_{i,j} = - \left\lfloor \frac{A\_{1,i} A\_{2,j} + A\_{1,j} A\_{2,i}}{\Delta}
+/// \right\rceil$ for $0 \leq i < j < k$, and $R\_{i,i} = - \left\lfloor \frac{A\_{1,i}
+/// A\_{2,i}}{\Delta} \right\rceil$
+/// 4. Compute $B = \left\lfloor \frac{B\_1 B\_2}{\Delta} \right\rceil$
+/// 5. Output $\left( T\_0, \cdots, T\_{k-1}, R\_{0,0}, R\_{0,1}, \cdots, R\_{k-1,k-1}, B \right)$
+pub trait GlweCiphertextTensorProductEngine<InputCiphertext1, InputCiphertext2, OutputCiphertext>:
+    AbstractEngine
+where
+    InputCiphertext1: GlweCiphertextEntity,
+    InputCiphertext2: GlweCiphertextEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Computes the tensor product of two GLWE ciphertexts.
+    fn compute_tensor_product_glwe_ciphertext(
+        &mut self,
+        input1: &InputCiphertext1,
+        input2: &InputCiphertext2,
+        delta_log: DeltaLog,
+    ) -> Result<OutputCiphertext, GlweCiphertextTensorProductError<Self::EngineError>>;
+
+    /// Unsafely computes the tensor product of two GLWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextTensorProductError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn compute_tensor_product_glwe_ciphertext_unchecked(
+        &mut self,
+        input1: &InputCiphertext1,
+        input2: &InputCiphertext2,
+        delta_log: DeltaLog,
+    ) -> OutputCiphertext;
+}
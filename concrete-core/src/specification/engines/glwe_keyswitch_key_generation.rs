@@ -0,0 +1,90 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweKeyswitchKeyEntity, GlweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+engine_error! {
+    GlweKeyswitchKeyGenerationError for GlweKeyswitchKeyGenerationEngine @
+    PolynomialSizeMismatch => "The polynomial size of the input and output keys must be the same.",
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext."
+}
+
+impl<EngineError: std::error::Error> GlweKeyswitchKeyGenerationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<InputSecretKey, OutputSecretKey>(
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        integer_precision: usize,
+    ) -> Result<(), Self>
+    where
+        InputSecretKey: GlweSecretKeyEntity,
+        OutputSecretKey: GlweSecretKeyEntity,
+    {
+        if input_key.polynomial_size() != output_key.polynomial_size() {
+            return Err(Self::PolynomialSizeMismatch);
+        }
+
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+
+        if decomposition_level_count.0 * decomposition_base_log.0 > integer_precision {
+            return Err(Self::DecompositionTooLarge);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new GLWE keyswitch keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new GLWE keyswitch key
+/// allowing to switch from the `input_key` GLWE secret key to the `output_key` GLWE secret key.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::GlweKeyswitchKeyEntity`)
+pub trait GlweKeyswitchKeyGenerationEngine<InputSecretKey, OutputSecretKey, KeyswitchKey>:
+    AbstractEngine
+where
+    InputSecretKey: GlweSecretKeyEntity,
+    OutputSecretKey: GlweSecretKeyEntity,
+    KeyswitchKey: GlweKeyswitchKeyEntity,
+{
+    /// Generates a new GLWE keyswitch key.
+    fn generate_new_glwe_keyswitch_key(
+        &mut self,
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<KeyswitchKey, GlweKeyswitchKeyGenerationError<Self::EngineError>>;
+
+    /// Unsafely generates a new GLWE keyswitch key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweKeyswitchKeyGenerationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn generate_new_glwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> KeyswitchKey;
+}
@@ -0,0 +1,59 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlwePublicKeyEntity, GlweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::GlwePublicKeyZeroEncryptionCount;
+
+engine_error! {
+    GlwePublicKeyGenerationError for GlwePublicKeyGenerationEngine @
+    NullPublicKeyZeroEncryptionCount => "The number of GLWE encryptions of zero in the GLWE public \
+                                        key must be greater than zero."
+}
+
+impl<EngineError: std::error::Error> GlwePublicKeyGenerationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount,
+    ) -> Result<(), Self> {
+        if glwe_public_key_zero_encryption_count.0 == 0 {
+            return Err(Self::NullPublicKeyZeroEncryptionCount);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new GLWE public keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new GLWE public key.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::GlwePublicKeyEntity`)
+pub trait GlwePublicKeyGenerationEngine<SecretKey, PublicKey>: AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    PublicKey: GlwePublicKeyEntity,
+{
+    /// Generates a new GLWE public key.
+    fn generate_new_glwe_public_key(
+        &mut self,
+        glwe_secret_key: &SecretKey,
+        noise: Variance,
+        glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount,
+    ) -> Result<PublicKey, GlwePublicKeyGenerationError<Self::EngineError>>;
+
+    /// Unsafely generates a new GLWE public key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlwePublicKeyGenerationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn generate_new_glwe_public_key_unchecked(
+        &mut self,
+        glwe_secret_key: &SecretKey,
+        noise: Variance,
+        glwe_public_key_zero_encryption_count: GlwePublicKeyZeroEncryptionCount,
+    ) -> PublicKey;
+}
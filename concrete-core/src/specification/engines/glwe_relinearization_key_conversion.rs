@@ -0,0 +1,36 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweRelinearizationKeyEntity;
+
+engine_error! {
+    GlweRelinearizationKeyConversionError for GlweRelinearizationKeyConversionEngine @
+}
+
+/// A trait for engines converting GLWE relinearization keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE relinearization key
+/// containing the conversion of the `input` relinearization key to a type with a different
+/// representation (for instance from standard to Fourier domain).
+///
+/// # Formal Definition
+pub trait GlweRelinearizationKeyConversionEngine<InputKey, OutputKey>: AbstractEngine
+where
+    InputKey: GlweRelinearizationKeyEntity,
+    OutputKey: GlweRelinearizationKeyEntity,
+{
+    /// Converts a GLWE relinearization key.
+    fn convert_glwe_relinearization_key(
+        &mut self,
+        input: &InputKey,
+    ) -> Result<OutputKey, GlweRelinearizationKeyConversionError<Self::EngineError>>;
+
+    /// Unsafely converts a GLWE relinearization key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweRelinearizationKeyConversionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn convert_glwe_relinearization_key_unchecked(&mut self, input: &InputKey) -> OutputKey;
+}
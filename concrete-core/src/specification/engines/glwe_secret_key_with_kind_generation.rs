@@ -0,0 +1,73 @@
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::key_kinds::KeyKind;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+engine_error! {
+    GlweSecretKeyWithKindGenerationError for GlweSecretKeyWithKindGenerationEngine @
+    NullGlweDimension => "The secret key GLWE dimension must be greater than zero.",
+    NullPolynomialSize => "The secret key polynomial size must be greater than zero.",
+    SizeOnePolynomial => "The secret key polynomial size must be greater than one. Otherwise you \
+                          should prefer the LWE scheme."
+}
+
+impl<EngineError: std::error::Error> GlweSecretKeyWithKindGenerationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<(), Self> {
+        if glwe_dimension.0 == 0 {
+            return Err(Self::NullGlweDimension);
+        }
+
+        if polynomial_size.0 == 0 {
+            return Err(Self::NullPolynomialSize);
+        }
+
+        if polynomial_size.0 == 1 {
+            return Err(Self::SizeOnePolynomial);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new GLWE secret keys of a given kind.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new GLWE secret key, whose
+/// coefficients follow the distribution of the `kind` key kind (binary, ternary, gaussian or
+/// uniform). Contrarily to [`GlweSecretKeyGenerationEngine`](super::GlweSecretKeyGenerationEngine),
+/// which generates binary keys, the kind of the key is known from the arguments.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::GlweSecretKeyEntity`)
+pub trait GlweSecretKeyWithKindGenerationEngine<Kind, SecretKey>: AbstractEngine
+where
+    Kind: KeyKind,
+    SecretKey: GlweSecretKeyEntity,
+{
+    /// Generates a new GLWE secret key of the given kind.
+    fn generate_new_glwe_secret_key_with_kind(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        kind: Kind,
+    ) -> Result<SecretKey, GlweSecretKeyWithKindGenerationError<Self::EngineError>>;
+
+    /// Unsafely generates a new GLWE secret key of the given kind.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweSecretKeyWithKindGenerationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn generate_new_glwe_secret_key_with_kind_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        kind: Kind,
+    ) -> SecretKey;
+}
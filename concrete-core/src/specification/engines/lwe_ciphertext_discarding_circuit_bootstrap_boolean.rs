@@ -0,0 +1,145 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    GgswCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+};
+use concrete_commons::parameters::DeltaLog;
+
+engine_error! {
+    LweCiphertextDiscardingCircuitBootstrapBooleanError for
+    LweCiphertextDiscardingCircuitBootstrapBooleanEngine @
+    InputLweDimensionMismatch => "The input ciphertext and bootstrap key input LWE dimension must \
+                                  be the same.",
+    KeysDimensionMismatch => "The bootstrap key output LWE dimension must be the same as the \
+                              circuit bootstrap keys input LWE dimension.",
+    OutputGlweDimensionMismatch => "The output ciphertext and circuit bootstrap keys GLWE \
+                                    dimension must be the same.",
+    OutputPolynomialSizeMismatch => "The output ciphertext and circuit bootstrap keys polynomial \
+                                     sizes must be the same.",
+    MalformedCircuitBootstrapKeys => "The number of circuit bootstrap keys must be equal to the \
+                                      output GLWE dimension + 1.",
+    NullDecompositionBaseLog => "The output decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The output decomposition level count must be greater than \
+                                    zero.",
+    DecompositionTooLarge => "The output decomposition precision (base log * level count) must \
+                              be smaller than the precision of the ciphertext.",
+    DeltaLogTooLarge => "The delta log must be smaller than the precision of the ciphertext."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextDiscardingCircuitBootstrapBooleanError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<Input, Output, BootstrapKey, CBSPFPKSK>(
+        output: &Output,
+        input: &Input,
+        delta_log: DeltaLog,
+        bsk: &BootstrapKey,
+        cbs_pfpksk: &CBSPFPKSK,
+        integer_precision: usize,
+    ) -> Result<(), Self>
+    where
+        Input: LweCiphertextEntity,
+        Output: GgswCiphertextEntity,
+        BootstrapKey: LweBootstrapKeyEntity,
+        CBSPFPKSK: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if bsk.output_lwe_dimension() != cbs_pfpksk.input_lwe_dimension() {
+            return Err(Self::KeysDimensionMismatch);
+        }
+        if output.glwe_dimension() != cbs_pfpksk.output_glwe_dimension() {
+            return Err(Self::OutputGlweDimensionMismatch);
+        }
+        if output.polynomial_size() != cbs_pfpksk.output_polynomial_size() {
+            return Err(Self::OutputPolynomialSizeMismatch);
+        }
+        if cbs_pfpksk.key_count().0 != output.glwe_dimension().to_glwe_size().0 {
+            return Err(Self::MalformedCircuitBootstrapKeys);
+        }
+        if output.decomposition_base_log().0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+        if output.decomposition_level_count().0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+        if output.decomposition_base_log().0 * output.decomposition_level_count().0
+            >= integer_precision
+        {
+            return Err(Self::DecompositionTooLarge);
+        }
+        if delta_log.0 >= integer_precision {
+            return Err(Self::DeltaLogTooLarge);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines circuit bootstrapping (discarding) LWE ciphertexts encrypting boolean
+/// messages.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GGSW ciphertext with
+/// the circuit bootstrap of the `input` LWE ciphertext, using the `bsk` bootstrap key and the
+/// `cbs_pfpksk` circuit bootstrap private functional packing keyswitch keys.
+///
+/// The `input` ciphertext must encrypt a single bit of message, located at bit `delta_log`
+/// (0-indexed) of the plaintext, with no bit of padding. The decomposition parameters of the
+/// circuit bootstrap are the ones of the `output` GGSW ciphertext.
+///
+/// # Formal Definition
+///
+/// ## Circuit Bootstrapping
+///
+/// This homomorphic procedure transforms an input
+/// [`LWE ciphertext`](`crate::specification::entities::LweCiphertextEntity`) encrypting a bit
+/// $m$ under the secret key $\vec{s}\_{\mathsf{in}}$ into an output
+/// [`GGSW ciphertext`](`crate::specification::entities::GgswCiphertextEntity`) encrypting the same
+/// bit under the GLWE secret key $\vec{S}\_{\mathsf{out}}$ of the circuit bootstrap keys.
+///
+/// For each level $j$ of the output decomposition, a programmable bootstrap computes an
+/// encryption of $m \cdot q / \beta^{j}$, which is then keyswitched with each of the $k+1$ circuit
+/// bootstrap keys to fill the $k+1$ GLWE ciphertexts of the $j$-th level matrix, the $i$-th key
+/// multiplying the message by $-S\_i$ (or by $1$ for the last key).
+pub trait LweCiphertextDiscardingCircuitBootstrapBooleanEngine<
+    Input,
+    Output,
+    BootstrapKey,
+    CBSPFPKSK,
+>: AbstractEngine where
+    Input: LweCiphertextEntity,
+    Output: GgswCiphertextEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+    CBSPFPKSK: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+{
+    /// Circuit bootstrap an LWE ciphertext.
+    fn discard_circuit_bootstrap_boolean_lwe_ciphertext(
+        &mut self,
+        output: &mut Output,
+        input: &Input,
+        delta_log: DeltaLog,
+        bsk: &BootstrapKey,
+        cbs_pfpksk: &CBSPFPKSK,
+    ) -> Result<(), LweCiphertextDiscardingCircuitBootstrapBooleanError<Self::EngineError>>;
+
+    /// Unsafely circuit bootstrap an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingCircuitBootstrapBooleanError`]. For safety concerns _specific_
+    /// to an engine, refer to the implementer safety section.
+    unsafe fn discard_circuit_bootstrap_boolean_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut Output,
+        input: &Input,
+        delta_log: DeltaLog,
+        bsk: &BootstrapKey,
+        cbs_pfpksk: &CBSPFPKSK,
+    );
+}
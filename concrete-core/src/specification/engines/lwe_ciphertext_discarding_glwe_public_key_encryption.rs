@@ -0,0 +1,76 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlwePublicKeyEntity, LweCiphertextEntity, PlaintextEntity};
+use concrete_commons::dispersion::Variance;
+
+engine_error! {
+    LweCiphertextDiscardingGlwePublicKeyEncryptionError for
+    LweCiphertextDiscardingGlwePublicKeyEncryptionEngine @
+    LweDimensionMismatch => "The output LWE dimension must be the product of the public key GLWE \
+                             dimension and polynomial size."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextDiscardingGlwePublicKeyEncryptionError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<PublicKey, Ciphertext>(
+        key: &PublicKey,
+        output: &Ciphertext,
+    ) -> Result<(), Self>
+    where
+        PublicKey: GlwePublicKeyEntity,
+        Ciphertext: LweCiphertextEntity,
+    {
+        if key.glwe_dimension().0 * key.polynomial_size().0 != output.lwe_dimension().0 {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines encrypting (discarding) LWE ciphertexts with a GLWE public key.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the encryption of the `input` plaintext, using the GLWE public `key`. The ciphertext can be
+/// decrypted by the LWE secret key obtained by
+/// [`transforming`](`crate::specification::engines::GlweToLweSecretKeyTransformationEngine`) the
+/// GLWE secret key used to generate the public key.
+///
+/// # Formal Definition
+///
+/// The `input` plaintext is encrypted as the constant coefficient of a GLWE ciphertext, using the
+/// [`GLWE public key`](`crate::specification::entities::GlwePublicKeyEntity`) encryption, and the
+/// constant coefficient is then extracted as an LWE ciphertext of dimension $k\cdot N$.
+pub trait LweCiphertextDiscardingGlwePublicKeyEncryptionEngine<PublicKey, Plaintext, Ciphertext>:
+    AbstractEngine
+where
+    PublicKey: GlwePublicKeyEntity,
+    Plaintext: PlaintextEntity,
+    Ciphertext: LweCiphertextEntity,
+{
+    /// Encrypts an LWE ciphertext using a GLWE public key.
+    fn discard_encrypt_lwe_ciphertext_with_glwe_public_key(
+        &mut self,
+        key: &PublicKey,
+        output: &mut Ciphertext,
+        input: &Plaintext,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextDiscardingGlwePublicKeyEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts an LWE ciphertext using a GLWE public key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingGlwePublicKeyEncryptionError`]. For safety concerns _specific_
+    /// to an engine, refer to the implementer safety section.
+    unsafe fn discard_encrypt_lwe_ciphertext_with_glwe_public_key_unchecked(
+        &mut self,
+        key: &PublicKey,
+        output: &mut Ciphertext,
+        input: &Plaintext,
+        noise: Variance,
+    );
+}
@@ -0,0 +1,151 @@
+use super::engine_error;
+use crate::prelude::LutCountLog;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity, LweCiphertextVectorEntity,
+};
+
+engine_error! {
+    LweCiphertextDiscardingMultiBootstrapError for LweCiphertextDiscardingMultiBootstrapEngine @
+    InputLweDimensionMismatch => "The input ciphertext and key LWE dimension must be the same.",
+    OutputLweDimensionMismatch => "The output ciphertext vector dimension and key size (dimension \
+                                   * polynomial size) must be the same.",
+    AccumulatorPolynomialSizeMismatch => "The accumulator and key polynomial sizes must be the same.",
+    AccumulatorGlweDimensionMismatch => "The accumulator and key GLWE dimensions must be the same.",
+    LutCountMismatch => "The output ciphertext vector count must be equal to the number of LUTs \
+                         (2^lut_count_log).",
+    LutCountTooLarge => "The number of LUTs (2^lut_count_log) must be smaller than the polynomial \
+                         size."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextDiscardingMultiBootstrapError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertextVector,
+    >(
+        output: &OutputCiphertextVector,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+        lut_count_log: LutCountLog,
+    ) -> Result<(), Self>
+    where
+        BootstrapKey: LweBootstrapKeyEntity,
+        Accumulator: GlweCiphertextEntity,
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertextVector: LweCiphertextVectorEntity,
+    {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(Self::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(Self::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        if lut_count_log.0 >= acc.polynomial_size().log2().0 {
+            return Err(Self::LutCountTooLarge);
+        }
+        if output.lwe_ciphertext_count().0 != 1 << lut_count_log.0 {
+            return Err(Self::LutCountMismatch);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines multi-value bootstrapping (discarding) LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext
+/// vector with the evaluation of $2^{\mathsf{lut\\_count\\_log}}$ lookup tables on the `input` LWE
+/// ciphertext, using a single blind rotation of the `acc` accumulator with the `bsk` bootstrap key.
+///
+/// The accumulator must contain the $2^{\mathsf{lut\\_count\\_log}}$ lookup tables interleaved
+/// coefficient-wise: the $j$-th coefficient of each block of $2^{\mathsf{lut\\_count\\_log}}$
+/// consecutive coefficients belongs to the $j$-th lookup table. The $j$-th ciphertext of the
+/// `output` vector then encrypts the evaluation of the $j$-th lookup table.
+///
+/// # Formal Definition
+///
+/// ## Multi-Value Programmable Bootstrapping
+///
+/// This procedure is a variant of the
+/// [`programmable bootstrapping`](`super::LweCiphertextDiscardingBootstrapEngine`) in which the
+/// modulus switching rounds the input ciphertext to a multiple of $2^\kappa$ in
+/// $\mathbb{Z}\_{2N}$, where $\kappa = \mathsf{lut\\_count\\_log}$. After the blind rotation,
+/// the first $2^\kappa$ coefficients of the accumulator hold the evaluations of the
+/// $2^\kappa$ lookup tables, which are extracted independently.
+///
+/// ## Algorithm
+/// ###### inputs:
+/// - $\mathsf{ct}\_{\mathsf{in}} = \left( \vec{a}\_{\mathsf{in}} , b\_{\mathsf{in}}\right) \in
+///   \mathsf{LWE}^{n\_{\mathsf{in}}}\_{\vec{s}\_{\mathsf{in}}}( \mathsf{pt} )$: an [`LWE
+///   ciphertext`](`LweCiphertextEntity`) with $\vec{a}\_{\mathsf{in}}=\left(a\_0, \cdots
+///   a\_{n\_{\mathsf{in}}-1}\right)$
+/// - $\mathsf{BSK}\_{\vec{s}\_{\mathsf{in}}\rightarrow \vec{S}\_{\mathsf{out}}}$: a bootstrapping
+///   key
+/// - $\mathsf{LUT} \in \mathcal{R}\_q$: $2^\kappa$ LUTs interleaved in a single polynomial
+///
+/// ###### outputs:
+/// - $\left(\mathsf{ct}\_{\mathsf{out}, j}\right)\_{j < 2^\kappa}$: $2^\kappa$ [`LWE
+///   ciphertexts`](`LweCiphertextEntity`) with $\mathsf{ct}\_{\mathsf{out}, j} \in
+///   \mathsf{LWE}^{n\_{\mathsf{out}}}\_{\vec{s}\_{\mathsf{out}}} \left( \mathsf{LUT}\_j
+///   (\mathsf{pt}) \right)$
+///
+/// ###### algorithm:
+/// 1. Compute $\tilde{a}\_i \in \mathbb{Z}\_{2N\_{\mathsf{out}}} \leftarrow 2^\kappa \cdot \lfloor
+/// \frac{2 N\_{\mathsf{out}} \cdot a\_i}{2^\kappa q} \rceil$, for $i= 0, 1, \ldots,
+/// n\_{\mathsf{in}-1}$
+/// 2. Compute $\tilde{b}\_\mathsf{in} \in \mathbb{Z}\_{2N\_{\mathsf{out}}} \leftarrow 2^\kappa
+/// \cdot \lfloor \frac{2 N\_{\mathsf{out}} \cdot b\_\mathsf{in}}{2^\kappa q} \rceil$
+/// 3. Set $\mathsf{ACC} = (0, \ldots, 0, \mathsf{LUT} \cdot X^{-\tilde{b}\_\mathsf{in}})$
+/// 4. Compute $\mathsf{ACC} = \mathsf{CMux}(\overline{\overline{\mathsf{CT}\_i}}, \mathsf{ACC}
+/// \cdot X^{\tilde{a}\_i}, \mathsf{ACC})$, for $i= 0, 1, \ldots, n\_{\mathsf{in}-1}$
+/// 5. Output $\mathsf{ct}\_{\mathsf{out}, j} \leftarrow \mathsf{SampleExtract}(\mathsf{ACC}, j)$,
+/// for $j = 0, 1, \ldots, 2^\kappa - 1$
+pub trait LweCiphertextDiscardingMultiBootstrapEngine<
+    BootstrapKey,
+    Accumulator,
+    InputCiphertext,
+    OutputCiphertextVector,
+>: AbstractEngine where
+    BootstrapKey: LweBootstrapKeyEntity,
+    Accumulator: GlweCiphertextEntity,
+    InputCiphertext: LweCiphertextEntity,
+    OutputCiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Multi-value bootstrap an LWE ciphertext.
+    fn discard_multi_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+        lut_count_log: LutCountLog,
+    ) -> Result<(), LweCiphertextDiscardingMultiBootstrapError<Self::EngineError>>;
+
+    /// Unsafely multi-value bootstrap an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingMultiBootstrapError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_multi_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+        lut_count_log: LutCountLog,
+    );
+}
@@ -0,0 +1,84 @@
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GswCiphertextEntity, LweCiphertextEntity};
+
+use super::engine_error;
+
+engine_error! {
+    LweCiphertextGswCiphertextDiscardingExternalProductError for
+    LweCiphertextGswCiphertextDiscardingExternalProductEngine @
+    LweDimensionMismatch => "All the GSW and LWE ciphertexts LWE dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextGswCiphertextDiscardingExternalProductError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<LweInput, GswInput, Output>(
+        lwe_input: &LweInput,
+        gsw_input: &GswInput,
+        output: &Output,
+    ) -> Result<(), Self>
+    where
+        LweInput: LweCiphertextEntity,
+        GswInput: GswCiphertextEntity,
+        Output: LweCiphertextEntity,
+    {
+        if lwe_input.lwe_dimension() != gsw_input.lwe_dimension()
+            || lwe_input.lwe_dimension() != output.lwe_dimension()
+        {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines computing the external product between an LWE ciphertext and a GSW
+/// ciphertext.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the result of the external product between a `lwe_input` LWE ciphertext and a `gsw_input` GSW
+/// ciphertext.
+///
+/// # Formal Definition
+///
+/// The external product takes an
+/// [`LWE ciphertext`](`crate::specification::entities::LweCiphertextEntity`)
+/// $\mathsf{ct} \in \mathsf{LWE}\_{\vec{s}}( \mathsf{pt}\_0 )$ and a
+/// [`GSW ciphertext`](`crate::specification::entities::GswCiphertextEntity`)
+/// $\overline{\overline{\mathsf{CT}}} \in \mathsf{GSW}\_{\vec{s}}^{\beta, \ell}( \mathsf{pt}\_1
+/// )$, both under the same secret key, and outputs an LWE ciphertext
+/// $\mathsf{ct}\_{\mathsf{out}} \in \mathsf{LWE}\_{\vec{s}}( \mathsf{pt}\_0 \cdot \mathsf{pt}\_1 )$.
+///
+/// Each coefficient of $\mathsf{ct}$ is decomposed into $\ell$ digits in base $\beta$, and the
+/// output is the inner product between this decomposition and the rows of
+/// $\overline{\overline{\mathsf{CT}}}$.
+pub trait LweCiphertextGswCiphertextDiscardingExternalProductEngine<LweInput, GswInput, Output>:
+    AbstractEngine
+where
+    LweInput: LweCiphertextEntity,
+    GswInput: GswCiphertextEntity,
+    Output: LweCiphertextEntity,
+{
+    /// Computes the discarding external product between an LWE and a GSW ciphertext.
+    fn discard_compute_external_product_lwe_ciphertext_gsw_ciphertext(
+        &mut self,
+        lwe_input: &LweInput,
+        gsw_input: &GswInput,
+        output: &mut Output,
+    ) -> Result<(), LweCiphertextGswCiphertextDiscardingExternalProductError<Self::EngineError>>;
+
+    /// Unsafely computes the discarding external product between an LWE and a GSW ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextGswCiphertextDiscardingExternalProductError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_compute_external_product_lwe_ciphertext_gsw_ciphertext_unchecked(
+        &mut self,
+        lwe_input: &LweInput,
+        gsw_input: &GswInput,
+        output: &mut Output,
+    );
+}
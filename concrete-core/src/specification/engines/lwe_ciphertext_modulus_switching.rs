@@ -0,0 +1,73 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextEntity, LweCompressedCiphertextVectorEntity};
+use concrete_commons::parameters::CiphertextModulusLog;
+
+engine_error! {
+    LweCiphertextModulusSwitchingError for LweCiphertextModulusSwitchingEngine @
+    NullCiphertextModulusLog => "The ciphertext modulus log must be greater than zero.",
+    CiphertextModulusLogTooLarge => "The ciphertext modulus log must not exceed the precision of \
+                                     the input ciphertext."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextModulusSwitchingError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        ciphertext_modulus_log: CiphertextModulusLog,
+        integer_precision: usize,
+    ) -> Result<(), Self> {
+        if ciphertext_modulus_log.0 == 0 {
+            return Err(Self::NullCiphertextModulusLog);
+        }
+        if ciphertext_modulus_log.0 > integer_precision {
+            return Err(Self::CiphertextModulusLogTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines switching the modulus of LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a compressed LWE ciphertext vector
+/// containing a single ciphertext, which is the `input` LWE ciphertext switched to the modulus
+/// $2^{q}$, where $q$ is `ciphertext_modulus_log`.
+///
+/// # Formal Definition
+///
+/// Let $\mathsf{CT} = (\vec{a}, b) \in \mathsf{LWE}^n\_{\vec{s}}( \mathsf{pt})\subseteq
+/// \mathbb{Z}\_{2^w}^{(n+1)}$ be an LWE ciphertext with coefficients on $w$ bits. Its modulus
+/// switch to $2^q$, with $q \leq w$, is the LWE ciphertext
+/// $\mathsf{CT}' = (\vec{a}', b')\subseteq \mathbb{Z}\_{2^q}^{(n+1)}$ where every coefficient
+/// $c$ of $\mathsf{CT}$ is mapped to:
+///
+/// $$c' = \left\lfloor \frac{2^q \cdot c}{2^w} \right\rceil \bmod 2^q$$
+///
+/// The ciphertext $\mathsf{CT}'$ decrypts to the $q$ most significant bits of $\mathsf{pt}$,
+/// up to a rounding error which adds to the noise of the input ciphertext.
+pub trait LweCiphertextModulusSwitchingEngine<Ciphertext, CompressedCiphertextVector>:
+    AbstractEngine
+where
+    Ciphertext: LweCiphertextEntity,
+    CompressedCiphertextVector: LweCompressedCiphertextVectorEntity,
+{
+    /// Switches the modulus of an LWE ciphertext.
+    fn switch_modulus_lwe_ciphertext(
+        &mut self,
+        input: &Ciphertext,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Result<CompressedCiphertextVector, LweCiphertextModulusSwitchingError<Self::EngineError>>;
+
+    /// Unsafely switches the modulus of an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextModulusSwitchingError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn switch_modulus_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &Ciphertext,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> CompressedCiphertextVector;
+}
@@ -0,0 +1,183 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    LweBootstrapKeyEntity, LweCiphertextVectorEntity,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity, PlaintextVectorEntity,
+};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+engine_error! {
+    LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingError for
+    LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingEngine @
+    EmptyInput => "The input ciphertext vector must not be empty.",
+    InputLweDimensionMismatch => "The input ciphertext vector and bootstrap key input LWE \
+                                  dimension must be the same.",
+    OutputLweDimensionMismatch => "The output ciphertext vector dimension and bootstrap key output \
+                                   LWE dimension must be the same.",
+    KeysDimensionMismatch => "The bootstrap key output LWE dimension must be the same as the \
+                              circuit bootstrap keys input LWE dimension.",
+    KeysGlweDimensionMismatch => "The bootstrap key and circuit bootstrap keys GLWE dimensions \
+                                  must be the same.",
+    KeysPolynomialSizeMismatch => "The bootstrap key and circuit bootstrap keys polynomial sizes \
+                                   must be the same.",
+    MalformedCircuitBootstrapKeys => "The number of circuit bootstrap keys must be equal to the \
+                                      bootstrap key GLWE dimension + 1.",
+    NullDecompositionBaseLog => "The circuit bootstrap decomposition base log must be greater \
+                                 than zero.",
+    NullDecompositionLevelCount => "The circuit bootstrap decomposition level count must be \
+                                    greater than zero.",
+    DecompositionTooLarge => "The circuit bootstrap decomposition precision (base log * level \
+                              count) must be smaller than the precision of the ciphertext.",
+    MalformedLookUpTables => "The lookup tables must be split in as many lookup tables as output \
+                              ciphertexts, each made of a power of two number of polynomials, at \
+                              most 2^(input ciphertext count)."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingError<EngineError>
+{
+    /// Validates the inputs
+    #[allow(clippy::too_many_arguments)]
+    pub fn perform_generic_checks<Input, Output, BootstrapKey, LUTs, CBSPFPKSK>(
+        output: &Output,
+        input: &Input,
+        bsk: &BootstrapKey,
+        luts: &LUTs,
+        cbs_level_count: DecompositionLevelCount,
+        cbs_base_log: DecompositionBaseLog,
+        cbs_pfpksk: &CBSPFPKSK,
+        integer_precision: usize,
+    ) -> Result<(), Self>
+    where
+        Input: LweCiphertextVectorEntity,
+        Output: LweCiphertextVectorEntity,
+        BootstrapKey: LweBootstrapKeyEntity,
+        LUTs: PlaintextVectorEntity,
+        CBSPFPKSK: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    {
+        if input.lwe_ciphertext_count().0 == 0 {
+            return Err(Self::EmptyInput);
+        }
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(Self::OutputLweDimensionMismatch);
+        }
+        if bsk.output_lwe_dimension() != cbs_pfpksk.input_lwe_dimension() {
+            return Err(Self::KeysDimensionMismatch);
+        }
+        if bsk.glwe_dimension() != cbs_pfpksk.output_glwe_dimension() {
+            return Err(Self::KeysGlweDimensionMismatch);
+        }
+        if bsk.polynomial_size() != cbs_pfpksk.output_polynomial_size() {
+            return Err(Self::KeysPolynomialSizeMismatch);
+        }
+        if cbs_pfpksk.key_count().0 != bsk.glwe_dimension().to_glwe_size().0 {
+            return Err(Self::MalformedCircuitBootstrapKeys);
+        }
+        if cbs_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+        if cbs_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+        if cbs_base_log.0 * cbs_level_count.0 >= integer_precision {
+            return Err(Self::DecompositionTooLarge);
+        }
+
+        let lut_size = output.lwe_ciphertext_count().0 * bsk.polynomial_size().0;
+        if lut_size == 0 || luts.plaintext_count().0 % lut_size != 0 {
+            return Err(Self::MalformedLookUpTables);
+        }
+        let lut_polynomial_count = luts.plaintext_count().0 / lut_size;
+        if !lut_polynomial_count.is_power_of_two()
+            || lut_polynomial_count.trailing_zeros() as usize > input.lwe_ciphertext_count().0
+        {
+            return Err(Self::MalformedLookUpTables);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines performing a (discarding) circuit bootstrap followed by a vertical packing
+/// on LWE ciphertext vectors encrypting boolean messages.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation circuit bootstraps each ciphertext of
+/// the `input` LWE ciphertext vector using the `bsk` bootstrap key and the `cbs_pfpksk` circuit
+/// bootstrap private functional packing keyswitch keys, with the `cbs_level_count` and
+/// `cbs_base_log` decomposition parameters. The resulting GGSW ciphertexts are then used to
+/// evaluate the `luts` lookup tables with a vertical packing, and the results are stored in the
+/// `output` LWE ciphertext vector.
+///
+/// Each ciphertext of the `input` vector must encrypt a single bit of message in its most
+/// significant bit, with no bit of padding. The ciphertexts are ordered from the most significant
+/// bit (index 0) to the least significant bit of the value on which the lookup tables are
+/// evaluated.
+///
+/// The `luts` plaintext vector contains one lookup table per `output` ciphertext, stored
+/// contiguously. Each lookup table is made of $2^p$ polynomials of the bootstrap key polynomial
+/// size: the $p$ most significant bits of the input value select a polynomial of the lookup table,
+/// and the remaining bits select a coefficient of this polynomial.
+///
+/// # Formal Definition
+///
+/// ## Vertical Packing
+///
+/// The most significant GGSW ciphertexts drive a tree of CMux selecting a polynomial of the
+/// lookup table, which is then blindly rotated by the value encrypted in the remaining GGSW
+/// ciphertexts. The output LWE ciphertext is the sample extraction of the constant coefficient of
+/// the resulting GLWE ciphertext.
+pub trait LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingEngine<
+    Input,
+    Output,
+    BootstrapKey,
+    LUTs,
+    CBSPFPKSK,
+>: AbstractEngine where
+    Input: LweCiphertextVectorEntity,
+    Output: LweCiphertextVectorEntity,
+    BootstrapKey: LweBootstrapKeyEntity,
+    LUTs: PlaintextVectorEntity,
+    CBSPFPKSK: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+{
+    /// Circuit bootstrap an LWE ciphertext vector, and evaluate lookup tables with a vertical
+    /// packing.
+    #[allow(clippy::too_many_arguments)]
+    fn discard_circuit_bootstrap_boolean_vertical_packing_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut Output,
+        input: &Input,
+        bsk: &BootstrapKey,
+        luts: &LUTs,
+        cbs_level_count: DecompositionLevelCount,
+        cbs_base_log: DecompositionBaseLog,
+        cbs_pfpksk: &CBSPFPKSK,
+    ) -> Result<
+        (),
+        LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingError<Self::EngineError>,
+    >;
+
+    /// Unsafely circuit bootstrap an LWE ciphertext vector, and evaluate lookup tables with a
+    /// vertical packing.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingCircuitBootstrapBooleanVerticalPackingError`]. For safety
+    /// concerns _specific_ to an engine, refer to the implementer safety section.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn discard_circuit_bootstrap_boolean_vertical_packing_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut Output,
+        input: &Input,
+        bsk: &BootstrapKey,
+        luts: &LUTs,
+        cbs_level_count: DecompositionLevelCount,
+        cbs_base_log: DecompositionBaseLog,
+        cbs_pfpksk: &CBSPFPKSK,
+    );
+}
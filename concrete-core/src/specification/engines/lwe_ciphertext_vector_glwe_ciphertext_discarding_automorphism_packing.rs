@@ -0,0 +1,145 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GlweAutomorphismKeyEntity, GlweCiphertextEntity, LweCiphertextVectorEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingError for LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingEngine @
+    InputLweDimensionMismatch => "The input ciphertext vector LWE dimension must be equal to the \
+                                  automorphism key GLWE dimension times its polynomial size.",
+    OutputGlweDimensionMismatch => "The output ciphertext and automorphism key GLWE dimensions \
+                                    must be the same.",
+    OutputPolynomialSizeMismatch => "The output ciphertext and automorphism key polynomial sizes \
+                                     must be the same.",
+    CiphertextCountMismatch => "The input ciphertext count is bigger than the output polynomial \
+                                size.",
+    MissingTraceAutomorphism => "The automorphism key must support the automorphisms of exponents \
+                                 2^i + 1, for 1 <= i <= log2(N)."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<AutomorphismKey, InputCiphertextVector, OutputCiphertext>(
+        output: &OutputCiphertext,
+        input: &InputCiphertextVector,
+        key: &AutomorphismKey,
+    ) -> Result<(), Self>
+    where
+        AutomorphismKey: GlweAutomorphismKeyEntity,
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        OutputCiphertext: GlweCiphertextEntity,
+    {
+        if input.lwe_dimension().0 != key.glwe_dimension().0 * key.polynomial_size().0 {
+            return Err(Self::InputLweDimensionMismatch);
+        }
+
+        if output.glwe_dimension() != key.glwe_dimension() {
+            return Err(Self::OutputGlweDimensionMismatch);
+        }
+
+        if output.polynomial_size() != key.polynomial_size() {
+            return Err(Self::OutputPolynomialSizeMismatch);
+        }
+
+        if input.lwe_ciphertext_count().0 > output.polynomial_size().0 {
+            return Err(Self::CiphertextCountMismatch);
+        }
+
+        let modulus = 2 * key.polynomial_size().0;
+        let supported = |exponent: usize| {
+            key.automorphism_exponents()
+                .iter()
+                .any(|supported| supported.0 % modulus == exponent % modulus)
+        };
+        if !(1..=key.polynomial_size().log2().0).all(|i| supported((1 << i) + 1)) {
+            return Err(Self::MissingTraceAutomorphism);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines packing (discarding) LWE ciphertext vectors into a GLWE ciphertext using
+/// ring automorphisms.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext
+/// with the packing of the `input` LWE ciphertext vector, using the `key` GLWE automorphism key.
+///
+/// Contrary to the
+/// [`packing keyswitch`](`crate::specification::engines::LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine`),
+/// no keyswitch key proportional to the input LWE dimension is needed: the input ciphertexts
+/// must be encrypted under the LWE secret key obtained by flattening the GLWE secret key of the
+/// automorphism key. This is the case of LWE ciphertexts sample extracted from GLWE ciphertexts
+/// encrypted under this key.
+///
+/// # Formal Definition
+///
+/// ## Automorphism Packing
+///
+/// Let $n \leq N$ be the number of input LWE ciphertexts, and $2^l$ the smallest power of two
+/// greater or equal to $n$. This homomorphic procedure produces a
+/// [`GLWE ciphertext`](`crate::specification::entities::GlweCiphertextEntity`) whose
+/// coefficient of degree $j \cdot N / 2^l$ encrypts the plaintext of the $j$-th input
+/// [`LWE ciphertext`](`crate::specification::entities::LweCiphertextVectorEntity`), and whose
+/// other coefficients encrypt zero. It requires a
+/// [`GLWE automorphism key`](`crate::specification::entities::GlweAutomorphismKeyEntity`)
+/// supporting the exponents $2^i + 1$, for $1 \leq i \leq \log\_2(N)$.
+///
+/// ## Algorithm
+/// ###### inputs:
+/// - $(\mathsf{ct}\_0, \cdots, \mathsf{ct}\_{n-1})$: a vector of LWE ciphertexts
+/// - $\mathsf{AK}$: a GLWE automorphism key
+///
+/// ###### outputs:
+/// - $\mathsf{CT}\_{\mathsf{out}}$: a GLWE ciphertext
+///
+/// ###### algorithm:
+/// 1. divide the coefficients of every $\mathsf{ct}\_j$ by $N$, and turn it into a GLWE
+///    ciphertext $\mathsf{CT}\_j$ encrypting its plaintext in the constant coefficient
+/// 2. set $\mathsf{CT}\_j = 0$ for $n \leq j < 2^l$
+/// 3. recursively merge the ciphertexts: the merge of $2^m$ ciphertexts is
+///    $\mathsf{CT}\_{\mathsf{even}} + X^{N / 2^m} \mathsf{CT}\_{\mathsf{odd}} +
+///    \tau\_{2^m + 1}(\mathsf{CT}\_{\mathsf{even}} - X^{N / 2^m} \mathsf{CT}\_{\mathsf{odd}})$,
+///    where $\mathsf{CT}\_{\mathsf{even}}$ (resp. $\mathsf{CT}\_{\mathsf{odd}}$) is the merge of
+///    the ciphertexts of even (resp. odd) indices
+/// 4. for $i$ going from $\log\_2(N)$ down to $l + 1$, set
+///    $\mathsf{CT}\_{\mathsf{out}} = \mathsf{CT}\_{\mathsf{out}} +
+///    \tau\_{2^i + 1}(\mathsf{CT}\_{\mathsf{out}})$
+/// 5. output $\mathsf{CT}\_{\mathsf{out}}$
+pub trait LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingEngine<
+    AutomorphismKey,
+    InputCiphertextVector,
+    OutputCiphertext,
+>: AbstractEngine where
+    AutomorphismKey: GlweAutomorphismKeyEntity,
+    InputCiphertextVector: LweCiphertextVectorEntity,
+    OutputCiphertext: GlweCiphertextEntity,
+{
+    /// Packs an LWE ciphertext vector using automorphisms.
+    fn discard_automorphism_pack_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertextVector,
+        key: &AutomorphismKey,
+    ) -> Result<
+        (),
+        LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingError<Self::EngineError>,
+    >;
+
+    /// Unsafely packs an LWE ciphertext vector using automorphisms.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorGlweCiphertextDiscardingAutomorphismPackingError`]. For safety
+    /// concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_automorphism_pack_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertextVector,
+        key: &AutomorphismKey,
+    );
+}
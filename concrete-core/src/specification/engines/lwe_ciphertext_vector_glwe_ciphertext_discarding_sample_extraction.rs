@@ -0,0 +1,86 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, LweCiphertextVectorEntity};
+use concrete_commons::parameters::{LweDimension, MonomialIndex};
+
+engine_error! {
+    LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionError for LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionEngine @
+    SizeMismatch => "The sizes of the output LWE (LWE dimension) and the input GLWE (GLWE \
+                     dimension * poly size) must be compatible.",
+    CiphertextCountMismatch => "The output LWE ciphertext count and the number of monomial \
+                                indices must be the same.",
+    MonomialIndexTooLarge => "The monomial indices must be smaller than the GLWE polynomial size."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<GlweCiphertext, LweCiphertextVector>(
+        output: &LweCiphertextVector,
+        input: &GlweCiphertext,
+        indices: &[MonomialIndex],
+    ) -> Result<(), Self>
+    where
+        GlweCiphertext: GlweCiphertextEntity,
+        LweCiphertextVector: LweCiphertextVectorEntity,
+    {
+        if output.lwe_dimension()
+            != LweDimension(input.polynomial_size().0 * input.glwe_dimension().0)
+        {
+            return Err(Self::SizeMismatch);
+        }
+        if output.lwe_ciphertext_count().0 != indices.len() {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        if indices.iter().any(|nth| nth.0 >= input.polynomial_size().0) {
+            return Err(Self::MonomialIndexTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines extracting (discarding) LWE ciphertext vectors from a GLWE ciphertext.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext
+/// vector with the extractions of the coefficients of the `input` GLWE ciphertext given by
+/// `indices`. The i-th LWE ciphertext of `output` holds the extraction of the `indices[i]`
+/// coefficient.
+///
+/// # Formal definition
+///
+/// This operation applies the _sample extract_ of the literature once for every index, see
+/// [`LweCiphertextDiscardingExtractionEngine`](super::LweCiphertextDiscardingExtractionEngine).
+pub trait LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionEngine<
+    GlweCiphertext,
+    LweCiphertextVector,
+>: AbstractEngine where
+    GlweCiphertext: GlweCiphertextEntity,
+    LweCiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Extracts an LWE ciphertext vector from a GLWE ciphertext.
+    fn discard_sample_extract_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector,
+        input: &GlweCiphertext,
+        indices: &[MonomialIndex],
+    ) -> Result<
+        (),
+        LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionError<Self::EngineError>,
+    >;
+
+    /// Unsafely extracts an LWE ciphertext vector from a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorGlweCiphertextDiscardingSampleExtractionError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_sample_extract_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector,
+        input: &GlweCiphertext,
+        indices: &[MonomialIndex],
+    );
+}
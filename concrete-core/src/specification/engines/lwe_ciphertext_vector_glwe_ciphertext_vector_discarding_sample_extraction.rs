@@ -0,0 +1,89 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextVectorEntity, LweCiphertextVectorEntity};
+use concrete_commons::parameters::{LweDimension, MonomialIndex};
+
+engine_error! {
+    LweCiphertextVectorGlweCiphertextVectorDiscardingSampleExtractionError for LweCiphertextVectorGlweCiphertextVectorDiscardingSampleExtractionEngine @
+    SizeMismatch => "The sizes of the output LWE (LWE dimension) and the input GLWE (GLWE \
+                     dimension * poly size) must be compatible.",
+    CiphertextCountMismatch => "The output LWE ciphertext count must be equal to the input GLWE \
+                                ciphertext count times the number of monomial indices.",
+    MonomialIndexTooLarge => "The monomial indices must be smaller than the GLWE polynomial size."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextVectorGlweCiphertextVectorDiscardingSampleExtractionError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<GlweCiphertextVector, LweCiphertextVector>(
+        output: &LweCiphertextVector,
+        input: &GlweCiphertextVector,
+        indices: &[MonomialIndex],
+    ) -> Result<(), Self>
+    where
+        GlweCiphertextVector: GlweCiphertextVectorEntity,
+        LweCiphertextVector: LweCiphertextVectorEntity,
+    {
+        if output.lwe_dimension()
+            != LweDimension(input.polynomial_size().0 * input.glwe_dimension().0)
+        {
+            return Err(Self::SizeMismatch);
+        }
+        if output.lwe_ciphertext_count().0 != input.glwe_ciphertext_count().0 * indices.len() {
+            return Err(Self::CiphertextCountMismatch);
+        }
+        if indices.iter().any(|nth| nth.0 >= input.polynomial_size().0) {
+            return Err(Self::MonomialIndexTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines extracting (discarding) LWE ciphertext vectors from GLWE ciphertext
+/// vectors.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext
+/// vector with the extractions of the coefficients given by `indices`, for every GLWE ciphertext
+/// of the `input` vector. The output is ordered by input GLWE ciphertext first: the
+/// `j * indices.len() + i`-th LWE ciphertext of `output` holds the extraction of the `indices[i]`
+/// coefficient of the j-th input GLWE ciphertext.
+///
+/// # Formal definition
+///
+/// This operation applies the _sample extract_ of the literature once for every index and every
+/// input GLWE ciphertext, see
+/// [`LweCiphertextDiscardingExtractionEngine`](super::LweCiphertextDiscardingExtractionEngine).
+pub trait LweCiphertextVectorGlweCiphertextVectorDiscardingSampleExtractionEngine<
+    GlweCiphertextVector,
+    LweCiphertextVector,
+>: AbstractEngine where
+    GlweCiphertextVector: GlweCiphertextVectorEntity,
+    LweCiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Extracts an LWE ciphertext vector from a GLWE ciphertext vector.
+    fn discard_sample_extract_glwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector,
+        input: &GlweCiphertextVector,
+        indices: &[MonomialIndex],
+    ) -> Result<
+        (),
+        LweCiphertextVectorGlweCiphertextVectorDiscardingSampleExtractionError<Self::EngineError>,
+    >;
+
+    /// Unsafely extracts an LWE ciphertext vector from a GLWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorGlweCiphertextVectorDiscardingSampleExtractionError`]. For safety
+    /// concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_sample_extract_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector,
+        input: &GlweCiphertextVector,
+        indices: &[MonomialIndex],
+    );
+}
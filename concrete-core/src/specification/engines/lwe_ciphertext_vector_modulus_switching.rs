@@ -0,0 +1,69 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweCiphertextVectorEntity, LweCompressedCiphertextVectorEntity,
+};
+use concrete_commons::parameters::CiphertextModulusLog;
+
+engine_error! {
+    LweCiphertextVectorModulusSwitchingError for LweCiphertextVectorModulusSwitchingEngine @
+    NullCiphertextModulusLog => "The ciphertext modulus log must be greater than zero.",
+    CiphertextModulusLogTooLarge => "The ciphertext modulus log must not exceed the precision of \
+                                     the input ciphertexts."
+}
+
+impl<EngineError: std::error::Error> LweCiphertextVectorModulusSwitchingError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        ciphertext_modulus_log: CiphertextModulusLog,
+        integer_precision: usize,
+    ) -> Result<(), Self> {
+        if ciphertext_modulus_log.0 == 0 {
+            return Err(Self::NullCiphertextModulusLog);
+        }
+        if ciphertext_modulus_log.0 > integer_precision {
+            return Err(Self::CiphertextModulusLogTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines switching the modulus of LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a compressed LWE ciphertext vector
+/// containing the element-wise modulus switch of the `input` LWE ciphertext vector to the
+/// modulus $2^{q}$, where $q$ is `ciphertext_modulus_log`.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::LweCiphertextModulusSwitchingEngine`)
+pub trait LweCiphertextVectorModulusSwitchingEngine<CiphertextVector, CompressedCiphertextVector>:
+    AbstractEngine
+where
+    CiphertextVector: LweCiphertextVectorEntity,
+    CompressedCiphertextVector: LweCompressedCiphertextVectorEntity,
+{
+    /// Switches the modulus of an LWE ciphertext vector.
+    fn switch_modulus_lwe_ciphertext_vector(
+        &mut self,
+        input: &CiphertextVector,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> Result<
+        CompressedCiphertextVector,
+        LweCiphertextVectorModulusSwitchingError<Self::EngineError>,
+    >;
+
+    /// Unsafely switches the modulus of an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorModulusSwitchingError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn switch_modulus_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &CiphertextVector,
+        ciphertext_modulus_log: CiphertextModulusLog,
+    ) -> CompressedCiphertextVector;
+}
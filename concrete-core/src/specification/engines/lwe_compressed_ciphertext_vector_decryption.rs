@@ -0,0 +1,70 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweCompressedCiphertextVectorEntity, LweSecretKeyEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    LweCompressedCiphertextVectorDecryptionError for LweCompressedCiphertextVectorDecryptionEngine @
+    LweDimensionMismatch => "The input and secret key LWE dimension must be the same."
+}
+
+impl<EngineError: std::error::Error> LweCompressedCiphertextVectorDecryptionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SecretKey, CompressedCiphertextVector>(
+        key: &SecretKey,
+        input: &CompressedCiphertextVector,
+    ) -> Result<(), Self>
+    where
+        SecretKey: LweSecretKeyEntity,
+        CompressedCiphertextVector: LweCompressedCiphertextVectorEntity,
+    {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines decrypting compressed LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a plaintext vector containing
+/// the element-wise decryption of the `input` compressed LWE ciphertext vector under the `key`
+/// secret key. The ciphertexts are decrypted directly from their compressed representation. The
+/// output plaintexts are expressed in the modulus of the secret key scalars, and only their
+/// $q$ most significant bits are meaningful, where $q$ is the ciphertext modulus log of the
+/// input.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::engines::LweCiphertextDecryptionEngine`)
+pub trait LweCompressedCiphertextVectorDecryptionEngine<
+    SecretKey,
+    CompressedCiphertextVector,
+    PlaintextVector,
+>: AbstractEngine where
+    SecretKey: LweSecretKeyEntity,
+    CompressedCiphertextVector: LweCompressedCiphertextVectorEntity,
+    PlaintextVector: PlaintextVectorEntity,
+{
+    /// Decrypts a compressed LWE ciphertext vector.
+    fn decrypt_lwe_compressed_ciphertext_vector(
+        &mut self,
+        key: &SecretKey,
+        input: &CompressedCiphertextVector,
+    ) -> Result<PlaintextVector, LweCompressedCiphertextVectorDecryptionError<Self::EngineError>>;
+
+    /// Unsafely decrypts a compressed LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCompressedCiphertextVectorDecryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn decrypt_lwe_compressed_ciphertext_vector_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &CompressedCiphertextVector,
+    ) -> PlaintextVector;
+}
@@ -0,0 +1,56 @@
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::key_kinds::KeyKind;
+use concrete_commons::parameters::LweDimension;
+
+engine_error! {
+    LweSecretKeyWithKindGenerationError for LweSecretKeyWithKindGenerationEngine @
+    NullLweDimension => "The LWE dimension must be greater than zero."
+}
+
+impl<EngineError: std::error::Error> LweSecretKeyWithKindGenerationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(lwe_dimension: LweDimension) -> Result<(), Self> {
+        if lwe_dimension.0 == 0 {
+            return Err(Self::NullLweDimension);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new LWE secret keys of a given kind.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new LWE secret key, whose
+/// coefficients follow the distribution of the `kind` key kind (binary, ternary, gaussian or
+/// uniform). Contrarily to [`LweSecretKeyGenerationEngine`](super::LweSecretKeyGenerationEngine),
+/// which generates binary keys, the kind of the key is known from the arguments.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::LweSecretKeyEntity`)
+pub trait LweSecretKeyWithKindGenerationEngine<Kind, SecretKey>: AbstractEngine
+where
+    Kind: KeyKind,
+    SecretKey: LweSecretKeyEntity,
+{
+    /// Generates a new LWE secret key of the given kind.
+    fn generate_new_lwe_secret_key_with_kind(
+        &mut self,
+        lwe_dimension: LweDimension,
+        kind: Kind,
+    ) -> Result<SecretKey, LweSecretKeyWithKindGenerationError<Self::EngineError>>;
+
+    /// Unsafely generates a new LWE secret key of the given kind.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSecretKeyWithKindGenerationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn generate_new_lwe_secret_key_with_kind_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+        kind: Kind,
+    ) -> SecretKey;
+}
@@ -0,0 +1,63 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextEntity, LweSeededCiphertextVectorEntity};
+use concrete_commons::parameters::LweCiphertextIndex;
+
+engine_error! {
+    LweSeededCiphertextVectorCiphertextExpansionError for
+    LweSeededCiphertextVectorCiphertextExpansionEngine @
+    IndexTooLarge => "The index must not exceed the size of the vector."
+}
+
+impl<EngineError: std::error::Error>
+    LweSeededCiphertextVectorCiphertextExpansionError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<CiphertextVector>(
+        vector: &CiphertextVector,
+        i: LweCiphertextIndex,
+    ) -> Result<(), Self>
+    where
+        CiphertextVector: LweSeededCiphertextVectorEntity,
+    {
+        if i.0 >= vector.lwe_ciphertext_count().0 {
+            return Err(Self::IndexTooLarge);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines expanding a single LWE ciphertext out of an LWE seeded ciphertext vector.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE ciphertext containing the
+/// `i`th LWE ciphertext of the `vector` LWE seeded ciphertext vector. Only the mask of the `i`th
+/// ciphertext is generated: the masks of the ciphertexts before it are skipped.
+///
+/// # Formal Definition
+pub trait LweSeededCiphertextVectorCiphertextExpansionEngine<CiphertextVector, Ciphertext>:
+    AbstractEngine
+where
+    CiphertextVector: LweSeededCiphertextVectorEntity,
+    Ciphertext: LweCiphertextEntity,
+{
+    /// Expands an LWE ciphertext from an LWE seeded ciphertext vector.
+    fn expand_lwe_seeded_ciphertext_vector_ciphertext(
+        &mut self,
+        vector: &CiphertextVector,
+        i: LweCiphertextIndex,
+    ) -> Result<Ciphertext, LweSeededCiphertextVectorCiphertextExpansionError<Self::EngineError>>;
+
+    /// Unsafely expands an LWE ciphertext from an LWE seeded ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededCiphertextVectorCiphertextExpansionError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn expand_lwe_seeded_ciphertext_vector_ciphertext_unchecked(
+        &mut self,
+        vector: &CiphertextVector,
+        i: LweCiphertextIndex,
+    ) -> Ciphertext;
+}
@@ -0,0 +1,66 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextVectorEntity, LweSeededCiphertextVectorEntity};
+use concrete_commons::parameters::LweCiphertextRange;
+
+engine_error! {
+    LweSeededCiphertextVectorRangeExpansionError for LweSeededCiphertextVectorRangeExpansionEngine @
+    UnorderedInputRange => "The input range bounds must be ordered.",
+    OutOfVectorInputRange => "The input vector must contain the input range."
+}
+
+impl<EngineError: std::error::Error> LweSeededCiphertextVectorRangeExpansionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<CiphertextVector>(
+        vector: &CiphertextVector,
+        range: LweCiphertextRange,
+    ) -> Result<(), Self>
+    where
+        CiphertextVector: LweSeededCiphertextVectorEntity,
+    {
+        if !range.is_ordered() {
+            return Err(Self::UnorderedInputRange);
+        }
+
+        if range.1 >= vector.lwe_ciphertext_count().0 {
+            return Err(Self::OutOfVectorInputRange);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines expanding a piece of an LWE seeded ciphertext vector.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE ciphertext vector containing
+/// the ciphertexts of the `vector` LWE seeded ciphertext vector whose indices are in `range`
+/// (bounds included). Only the masks of these ciphertexts are generated: the masks of the
+/// ciphertexts before the range are skipped.
+///
+/// # Formal Definition
+pub trait LweSeededCiphertextVectorRangeExpansionEngine<SeededCiphertextVector, CiphertextVector>:
+    AbstractEngine
+where
+    SeededCiphertextVector: LweSeededCiphertextVectorEntity,
+    CiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Expands a subpart of an LWE seeded ciphertext vector.
+    fn expand_lwe_seeded_ciphertext_vector_range(
+        &mut self,
+        vector: &SeededCiphertextVector,
+        range: LweCiphertextRange,
+    ) -> Result<CiphertextVector, LweSeededCiphertextVectorRangeExpansionError<Self::EngineError>>;
+
+    /// Unsafely expands a subpart of an LWE seeded ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededCiphertextVectorRangeExpansionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn expand_lwe_seeded_ciphertext_vector_range_unchecked(
+        &mut self,
+        vector: &SeededCiphertextVector,
+        range: LweCiphertextRange,
+    ) -> CiphertextVector;
+}
@@ -0,0 +1,94 @@
+use super::engine_error;
+use crate::prelude::{
+    GlweSecretKeyEntity, LweSecretKeyEntity,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+};
+use crate::specification::engines::AbstractEngine;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+engine_error! {
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationError for
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext."
+}
+
+impl<EngineError: std::error::Error>
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks(
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        integer_precision: usize,
+    ) -> Result<(), Self> {
+        if decomposition_base_log.0 == 0 {
+            return Err(Self::NullDecompositionBaseLog);
+        }
+
+        if decomposition_level_count.0 == 0 {
+            return Err(Self::NullDecompositionLevelCount);
+        }
+
+        if decomposition_level_count.0 * decomposition_base_log.0 > integer_precision {
+            return Err(Self::DecompositionTooLarge);
+        }
+
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new seeded LWE functional packing keyswitch keys used in a
+/// circuit bootstrapping.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new set of seeded LWE private
+/// functional packing keyswitch keys required to perform a circuit bootstrapping.
+///
+/// # Formal Definition
+///
+/// All the keys of the set share the same compression seed, from which the masks of their GLWE
+/// ciphertexts can be regenerated.
+pub trait LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationEngine<
+    InputLweSecretKey,
+    OutputGlweSecretKey,
+    SeededCBSFPKSK,
+>: AbstractEngine where
+    InputLweSecretKey: LweSecretKeyEntity,
+    OutputGlweSecretKey: GlweSecretKeyEntity,
+    SeededCBSFPKSK: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+{
+    /// Generate a new seeded LWE CBSFPKSK.
+    fn generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        input_lwe_key: &InputLweSecretKey,
+        output_glwe_key: &OutputGlweSecretKey,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<
+        SeededCBSFPKSK,
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationError<
+            Self::EngineError,
+        >,
+    >;
+
+    /// Unsafely generate a new seeded LWE CBSFPKSK.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysGenerationError`]. For
+    /// safety concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn generate_new_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+        &mut self,
+        input_lwe_key: &InputLweSecretKey,
+        output_glwe_key: &OutputGlweSecretKey,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> SeededCBSFPKSK;
+}
@@ -0,0 +1,52 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+};
+
+engine_error! {
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationError for
+    LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationEngine @
+}
+
+/// A trait for engines transforming seeded LWE private functional packing keyswitch key vectors
+/// used for circuit bootstrapping into their standard counterparts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation moves the existing seeded private functional
+/// packing keyswitch keys into private functional packing keyswitch keys.
+///
+/// # Formal Definition
+///
+/// The masks of the GLWE ciphertexts making the keys are regenerated from the compression seed,
+/// and associated with the bodies stored in the seeded keys.
+pub trait LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationEngine<
+    InputKeys,
+    OutputKeys,
+>: AbstractEngine where
+    InputKeys: LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+    OutputKeys: LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+{
+    /// Does the transformation of the seeded keys into standard keys
+    fn transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys: InputKeys,
+    ) -> Result<
+        OutputKeys,
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationError<Self::EngineError>,
+    >;
+
+    /// Unsafely transforms seeded keys into standard keys
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysToLweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysTransformationError`].
+    /// For safety concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn transform_lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+        &mut self,
+        lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys: InputKeys,
+    ) -> OutputKeys;
+}
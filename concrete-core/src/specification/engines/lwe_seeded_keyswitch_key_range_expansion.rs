@@ -0,0 +1,66 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweKeyswitchKeyEntity, LweSeededKeyswitchKeyEntity};
+use concrete_commons::parameters::LweKeyswitchKeyRowRange;
+
+engine_error! {
+    LweSeededKeyswitchKeyRangeExpansionError for LweSeededKeyswitchKeyRangeExpansionEngine @
+    UnorderedInputRange => "The input range bounds must be ordered.",
+    OutOfKeyInputRange => "The input key must contain the input range."
+}
+
+impl<EngineError: std::error::Error> LweSeededKeyswitchKeyRangeExpansionError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks<SeededKeyswitchKey>(
+        keyswitch_key: &SeededKeyswitchKey,
+        range: LweKeyswitchKeyRowRange,
+    ) -> Result<(), Self>
+    where
+        SeededKeyswitchKey: LweSeededKeyswitchKeyEntity,
+    {
+        if !range.is_ordered() {
+            return Err(Self::UnorderedInputRange);
+        }
+
+        if range.1 >= keyswitch_key.input_lwe_dimension().0 {
+            return Err(Self::OutOfKeyInputRange);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines expanding a range of rows of an LWE seeded keyswitch key.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE keyswitch key containing the
+/// rows of the `keyswitch_key` LWE seeded keyswitch key whose indices are in `range` (bounds
+/// included). The input LWE dimension of the output key is the length of the range. Only the
+/// masks of these rows are generated: the masks of the rows before the range are skipped.
+///
+/// # Formal Definition
+pub trait LweSeededKeyswitchKeyRangeExpansionEngine<SeededKeyswitchKey, KeyswitchKey>:
+    AbstractEngine
+where
+    SeededKeyswitchKey: LweSeededKeyswitchKeyEntity,
+    KeyswitchKey: LweKeyswitchKeyEntity,
+{
+    /// Expands a range of rows of an LWE seeded keyswitch key.
+    fn expand_lwe_seeded_keyswitch_key_range(
+        &mut self,
+        keyswitch_key: &SeededKeyswitchKey,
+        range: LweKeyswitchKeyRowRange,
+    ) -> Result<KeyswitchKey, LweSeededKeyswitchKeyRangeExpansionError<Self::EngineError>>;
+
+    /// Unsafely expands a range of rows of an LWE seeded keyswitch key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededKeyswitchKeyRangeExpansionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn expand_lwe_seeded_keyswitch_key_range_unchecked(
+        &mut self,
+        keyswitch_key: &SeededKeyswitchKey,
+        range: LweKeyswitchKeyRowRange,
+    ) -> KeyswitchKey;
+}
@@ -0,0 +1,54 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    LwePrivateFunctionalPackingKeyswitchKeyEntity,
+    LweSeededPrivateFunctionalPackingKeyswitchKeyEntity,
+};
+
+engine_error! {
+    LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationError for
+    LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationEngine @
+}
+
+/// A trait for engines transforming seeded LWE private functional packing keyswitch keys into
+/// LWE private functional packing keyswitch keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation moves the existing seeded LWE private
+/// functional packing keyswitch key into an LWE private functional packing keyswitch key.
+///
+/// # Formal Definition
+///
+/// The masks of the GLWE ciphertexts making the key are regenerated from the compression seed,
+/// and associated with the bodies stored in the seeded key.
+pub trait LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationEngine<
+    InputKey,
+    OutputKey,
+>: AbstractEngine where
+    InputKey: LweSeededPrivateFunctionalPackingKeyswitchKeyEntity,
+    OutputKey: LwePrivateFunctionalPackingKeyswitchKeyEntity,
+{
+    /// Does the transformation of the seeded LWE private functional packing keyswitch key into an
+    /// LWE private functional packing keyswitch key
+    fn transform_lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key(
+        &mut self,
+        lwe_seeded_private_functional_packing_keyswitch_key: InputKey,
+    ) -> Result<
+        OutputKey,
+        LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationError<Self::EngineError>,
+    >;
+
+    /// Unsafely transforms a seeded LWE private functional packing keyswitch key into an LWE
+    /// private functional packing keyswitch key
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededPrivateFunctionalPackingKeyswitchKeyToLwePrivateFunctionalPackingKeyswitchKeyTransformationError`].
+    /// For safety concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn transform_lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key_unchecked(
+        &mut self,
+        lwe_seeded_private_functional_packing_keyswitch_key: InputKey,
+    ) -> OutputKey;
+}
@@ -0,0 +1,46 @@
+use super::engine_error;
+use crate::prelude::AbstractEngine;
+
+use crate::specification::entities::{LwePublicKeyEntity, LweSeededPublicKeyEntity};
+
+engine_error! {
+    LweSeededPublicKeyToLwePublicKeyTransformationError for
+    LweSeededPublicKeyToLwePublicKeyTransformationEngine @
+}
+
+/// A trait for engines transforming seeded LWE public keys into LWE public keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation moves the existing seeded LWE public key
+/// into an LWE public key.
+///
+/// # Formal Definition
+///
+/// ## Seeded LWE public key to LWE public key transformation
+///
+/// The masks of the LWE encryptions of 0 are regenerated from the compression seed of the seeded
+/// key, and are stored alongside the bodies of the seeded key.
+pub trait LweSeededPublicKeyToLwePublicKeyTransformationEngine<InputKey, OutputKey>:
+    AbstractEngine
+where
+    InputKey: LweSeededPublicKeyEntity,
+    OutputKey: LwePublicKeyEntity,
+{
+    /// Does the transformation of the seeded LWE public key into an LWE public key
+    fn transform_lwe_seeded_public_key_to_lwe_public_key(
+        &mut self,
+        lwe_seeded_public_key: InputKey,
+    ) -> Result<OutputKey, LweSeededPublicKeyToLwePublicKeyTransformationError<Self::EngineError>>;
+
+    /// Unsafely transforms a seeded LWE public key into an LWE public key
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededPublicKeyToLwePublicKeyTransformationError`].
+    /// For safety concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn transform_lwe_seeded_public_key_to_lwe_public_key_unchecked(
+        &mut self,
+        lwe_seeded_public_key: InputKey,
+    ) -> OutputKey;
+}
@@ -164,22 +164,44 @@ mod encoder_creation;
 mod encoder_vector_creation;
 mod entity_deserialization;
 mod entity_serialization;
+mod entity_stream_deserialization;
+mod entity_stream_serialization;
 mod ggsw_ciphertext_conversion;
 mod ggsw_ciphertext_discarding_conversion;
 mod ggsw_ciphertext_scalar_discarding_encryption;
 mod ggsw_ciphertext_scalar_encryption;
 mod ggsw_ciphertext_scalar_trivial_encryption;
+mod ggsw_ciphertext_seeded_scalar_encryption;
+mod ggsw_seeded_ciphertext_to_ggsw_ciphertext_transformation;
+mod glwe_automorphism_key_generation;
+mod glwe_ciphertext_cleartext_vector_discarding_multiplication;
+mod glwe_ciphertext_cleartext_vector_fusing_multiplication;
 mod glwe_ciphertext_consuming_retrieval;
 mod glwe_ciphertext_conversion;
 mod glwe_ciphertext_creation;
 mod glwe_ciphertext_decryption;
+mod glwe_ciphertext_discarding_addition;
+mod glwe_ciphertext_discarding_automorphism;
 mod glwe_ciphertext_discarding_conversion;
 mod glwe_ciphertext_discarding_decryption;
 mod glwe_ciphertext_discarding_encryption;
+mod glwe_ciphertext_discarding_keyswitch;
+mod glwe_ciphertext_discarding_multiplication;
+mod glwe_ciphertext_discarding_opposite;
+mod glwe_ciphertext_discarding_public_key_encryption;
+mod glwe_ciphertext_discarding_relinearization;
+mod glwe_ciphertext_discarding_subtraction;
+mod glwe_ciphertext_discarding_trace;
 mod glwe_ciphertext_discarding_trivial_encryption;
 mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_fusing_addition;
+mod glwe_ciphertext_fusing_opposite;
+mod glwe_ciphertext_fusing_subtraction;
 mod glwe_ciphertext_ggsw_ciphertext_discarding_external_product;
 mod glwe_ciphertext_ggsw_ciphertext_external_product;
+mod glwe_ciphertext_plaintext_vector_discarding_addition;
+mod glwe_ciphertext_plaintext_vector_fusing_addition;
+mod glwe_ciphertext_tensor_product;
 mod glwe_ciphertext_trivial_decryption;
 mod glwe_ciphertext_trivial_encryption;
 mod glwe_ciphertext_vector_conversion;
@@ -193,14 +215,22 @@ mod glwe_ciphertext_vector_trivial_encryption;
 mod glwe_ciphertext_vector_zero_encryption;
 mod glwe_ciphertext_zero_encryption;
 mod glwe_ciphertexts_ggsw_ciphertext_fusing_cmux;
+mod glwe_keyswitch_key_generation;
+mod glwe_public_key_generation;
+mod glwe_relinearization_key_conversion;
+mod glwe_relinearization_key_generation;
 mod glwe_secret_key_conversion;
 mod glwe_secret_key_discarding_conversion;
 mod glwe_secret_key_generation;
+mod glwe_secret_key_with_kind_generation;
 mod glwe_seeded_ciphertext_encryption;
 mod glwe_seeded_ciphertext_to_glwe_ciphertext_transformation;
 mod glwe_seeded_ciphertext_vector_encryption;
 mod glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector_transformation;
+mod glwe_seeded_keyswitch_key_generation;
+mod glwe_seeded_keyswitch_key_to_glwe_keyswitch_key_transformation;
 mod glwe_to_lwe_secret_key_transformation;
+mod gsw_ciphertext_scalar_encryption;
 mod lwe_bootstrap_key_consuming_retrieval;
 mod lwe_bootstrap_key_conversion;
 mod lwe_bootstrap_key_creation;
@@ -215,12 +245,15 @@ mod lwe_ciphertext_decryption;
 mod lwe_ciphertext_discarding_addition;
 mod lwe_ciphertext_discarding_bit_extraction;
 mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_circuit_bootstrap_boolean;
 mod lwe_ciphertext_discarding_conversion;
 mod lwe_ciphertext_discarding_decryption;
 mod lwe_ciphertext_discarding_encryption;
 mod lwe_ciphertext_discarding_extraction;
+mod lwe_ciphertext_discarding_glwe_public_key_encryption;
 mod lwe_ciphertext_discarding_keyswitch;
 mod lwe_ciphertext_discarding_loading;
+mod lwe_ciphertext_discarding_multi_bootstrap;
 mod lwe_ciphertext_discarding_opposite;
 mod lwe_ciphertext_discarding_public_key_encryption;
 mod lwe_ciphertext_discarding_storing;
@@ -229,7 +262,9 @@ mod lwe_ciphertext_encryption;
 mod lwe_ciphertext_fusing_addition;
 mod lwe_ciphertext_fusing_opposite;
 mod lwe_ciphertext_fusing_subtraction;
+mod lwe_ciphertext_gsw_ciphertext_discarding_external_product;
 mod lwe_ciphertext_loading;
+mod lwe_ciphertext_modulus_switching;
 mod lwe_ciphertext_plaintext_discarding_addition;
 mod lwe_ciphertext_plaintext_discarding_subtraction;
 mod lwe_ciphertext_plaintext_fusing_addition;
@@ -243,6 +278,7 @@ mod lwe_ciphertext_vector_decryption;
 mod lwe_ciphertext_vector_discarding_addition;
 mod lwe_ciphertext_vector_discarding_affine_transformation;
 mod lwe_ciphertext_vector_discarding_bootstrap;
+mod lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean_vertical_packing;
 mod lwe_ciphertext_vector_discarding_conversion;
 mod lwe_ciphertext_vector_discarding_decryption;
 mod lwe_ciphertext_vector_discarding_encryption;
@@ -254,14 +290,20 @@ mod lwe_ciphertext_vector_encryption;
 mod lwe_ciphertext_vector_fusing_addition;
 mod lwe_ciphertext_vector_fusing_opposite;
 mod lwe_ciphertext_vector_fusing_subtraction;
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_automorphism_packing;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
 mod lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_packing_keyswitch;
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_sample_extraction;
+mod lwe_ciphertext_vector_glwe_ciphertext_vector_discarding_sample_extraction;
 mod lwe_ciphertext_vector_loading;
+mod lwe_ciphertext_vector_modulus_switching;
 mod lwe_ciphertext_vector_trivial_decryption;
 mod lwe_ciphertext_vector_trivial_encryption;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_ciphertext_zero_encryption;
+mod lwe_ciphertexts_gsw_ciphertext_fusing_cmux;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
+mod lwe_compressed_ciphertext_vector_decryption;
 mod lwe_keyswitch_key_conversion;
 mod lwe_keyswitch_key_discarding_conversion;
 mod lwe_keyswitch_key_generation;
@@ -271,14 +313,26 @@ mod lwe_public_key_generation;
 mod lwe_secret_key_conversion;
 mod lwe_secret_key_discarding_conversion;
 mod lwe_secret_key_generation;
+mod lwe_secret_key_with_kind_generation;
 mod lwe_seeded_bootstrap_key_generation;
 mod lwe_seeded_bootstrap_key_to_lwe_bootstrap_key_transformation;
 mod lwe_seeded_ciphertext_encryption;
 mod lwe_seeded_ciphertext_to_lwe_ciphertext_transformation;
+mod lwe_seeded_ciphertext_vector_ciphertext_expansion;
 mod lwe_seeded_ciphertext_vector_encryption;
+mod lwe_seeded_ciphertext_vector_range_expansion;
 mod lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_transformation;
+mod lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
+mod lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_transformation;
 mod lwe_seeded_keyswitch_key_generation;
+mod lwe_seeded_keyswitch_key_range_expansion;
 mod lwe_seeded_keyswitch_key_to_lwe_keyswitch_key_transformation;
+mod lwe_seeded_packing_keyswitch_key_generation;
+mod lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_transformation;
+mod lwe_seeded_private_functional_packing_keyswitch_key_generation;
+mod lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key_transformation;
+mod lwe_seeded_public_key_generation;
+mod lwe_seeded_public_key_to_lwe_public_key_transformation;
 mod lwe_to_glwe_secret_key_transformation;
 mod plaintext_conversion;
 mod plaintext_creation;
@@ -309,22 +363,44 @@ pub use encoder_creation::*;
 pub use encoder_vector_creation::*;
 pub use entity_deserialization::*;
 pub use entity_serialization::*;
+pub use entity_stream_deserialization::*;
+pub use entity_stream_serialization::*;
 pub use ggsw_ciphertext_conversion::*;
 pub use ggsw_ciphertext_discarding_conversion::*;
 pub use ggsw_ciphertext_scalar_discarding_encryption::*;
 pub use ggsw_ciphertext_scalar_encryption::*;
 pub use ggsw_ciphertext_scalar_trivial_encryption::*;
+pub use ggsw_ciphertext_seeded_scalar_encryption::*;
+pub use ggsw_seeded_ciphertext_to_ggsw_ciphertext_transformation::*;
+pub use glwe_automorphism_key_generation::*;
+pub use glwe_ciphertext_cleartext_vector_discarding_multiplication::*;
+pub use glwe_ciphertext_cleartext_vector_fusing_multiplication::*;
 pub use glwe_ciphertext_consuming_retrieval::*;
 pub use glwe_ciphertext_conversion::*;
 pub use glwe_ciphertext_creation::*;
 pub use glwe_ciphertext_decryption::*;
+pub use glwe_ciphertext_discarding_addition::*;
+pub use glwe_ciphertext_discarding_automorphism::*;
 pub use glwe_ciphertext_discarding_conversion::*;
 pub use glwe_ciphertext_discarding_decryption::*;
 pub use glwe_ciphertext_discarding_encryption::*;
+pub use glwe_ciphertext_discarding_keyswitch::*;
+pub use glwe_ciphertext_discarding_multiplication::*;
+pub use glwe_ciphertext_discarding_opposite::*;
+pub use glwe_ciphertext_discarding_public_key_encryption::*;
+pub use glwe_ciphertext_discarding_relinearization::*;
+pub use glwe_ciphertext_discarding_subtraction::*;
+pub use glwe_ciphertext_discarding_trace::*;
 pub use glwe_ciphertext_discarding_trivial_encryption::*;
 pub use glwe_ciphertext_encryption::*;
+pub use glwe_ciphertext_fusing_addition::*;
+pub use glwe_ciphertext_fusing_opposite::*;
+pub use glwe_ciphertext_fusing_subtraction::*;
 pub use glwe_ciphertext_ggsw_ciphertext_discarding_external_product::*;
 pub use glwe_ciphertext_ggsw_ciphertext_external_product::*;
+pub use glwe_ciphertext_plaintext_vector_discarding_addition::*;
+pub use glwe_ciphertext_plaintext_vector_fusing_addition::*;
+pub use glwe_ciphertext_tensor_product::*;
 pub use glwe_ciphertext_trivial_decryption::*;
 pub use glwe_ciphertext_trivial_encryption::*;
 pub use glwe_ciphertext_vector_conversion::*;
@@ -338,14 +414,22 @@ pub use glwe_ciphertext_vector_trivial_encryption::*;
 pub use glwe_ciphertext_vector_zero_encryption::*;
 pub use glwe_ciphertext_zero_encryption::*;
 pub use glwe_ciphertexts_ggsw_ciphertext_fusing_cmux::*;
+pub use glwe_keyswitch_key_generation::*;
+pub use glwe_public_key_generation::*;
+pub use glwe_relinearization_key_conversion::*;
+pub use glwe_relinearization_key_generation::*;
 pub use glwe_secret_key_conversion::*;
 pub use glwe_secret_key_discarding_conversion::*;
 pub use glwe_secret_key_generation::*;
+pub use glwe_secret_key_with_kind_generation::*;
 pub use glwe_seeded_ciphertext_encryption::*;
 pub use glwe_seeded_ciphertext_to_glwe_ciphertext_transformation::*;
 pub use glwe_seeded_ciphertext_vector_encryption::*;
 pub use glwe_seeded_ciphertext_vector_to_glwe_ciphertext_vector_transformation::*;
+pub use glwe_seeded_keyswitch_key_generation::*;
+pub use glwe_seeded_keyswitch_key_to_glwe_keyswitch_key_transformation::*;
 pub use glwe_to_lwe_secret_key_transformation::*;
+pub use gsw_ciphertext_scalar_encryption::*;
 pub use lwe_bootstrap_key_consuming_retrieval::*;
 pub use lwe_bootstrap_key_conversion::*;
 pub use lwe_bootstrap_key_creation::*;
@@ -360,12 +444,15 @@ pub use lwe_ciphertext_decryption::*;
 pub use lwe_ciphertext_discarding_addition::*;
 pub use lwe_ciphertext_discarding_bit_extraction::*;
 pub use lwe_ciphertext_discarding_bootstrap::*;
+pub use lwe_ciphertext_discarding_circuit_bootstrap_boolean::*;
 pub use lwe_ciphertext_discarding_conversion::*;
 pub use lwe_ciphertext_discarding_decryption::*;
 pub use lwe_ciphertext_discarding_encryption::*;
 pub use lwe_ciphertext_discarding_extraction::*;
+pub use lwe_ciphertext_discarding_glwe_public_key_encryption::*;
 pub use lwe_ciphertext_discarding_keyswitch::*;
 pub use lwe_ciphertext_discarding_loading::*;
+pub use lwe_ciphertext_discarding_multi_bootstrap::*;
 pub use lwe_ciphertext_discarding_opposite::*;
 pub use lwe_ciphertext_discarding_public_key_encryption::*;
 pub use lwe_ciphertext_discarding_storing::*;
@@ -374,7 +461,9 @@ pub use lwe_ciphertext_encryption::*;
 pub use lwe_ciphertext_fusing_addition::*;
 pub use lwe_ciphertext_fusing_opposite::*;
 pub use lwe_ciphertext_fusing_subtraction::*;
+pub use lwe_ciphertext_gsw_ciphertext_discarding_external_product::*;
 pub use lwe_ciphertext_loading::*;
+pub use lwe_ciphertext_modulus_switching::*;
 pub use lwe_ciphertext_plaintext_discarding_addition::*;
 pub use lwe_ciphertext_plaintext_discarding_subtraction::*;
 pub use lwe_ciphertext_plaintext_fusing_addition::*;
@@ -388,6 +477,7 @@ pub use lwe_ciphertext_vector_decryption::*;
 pub use lwe_ciphertext_vector_discarding_addition::*;
 pub use lwe_ciphertext_vector_discarding_affine_transformation::*;
 pub use lwe_ciphertext_vector_discarding_bootstrap::*;
+pub use lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean_vertical_packing::*;
 pub use lwe_ciphertext_vector_discarding_conversion::*;
 pub use lwe_ciphertext_vector_discarding_decryption::*;
 pub use lwe_ciphertext_vector_discarding_encryption::*;
@@ -399,14 +489,20 @@ pub use lwe_ciphertext_vector_encryption::*;
 pub use lwe_ciphertext_vector_fusing_addition::*;
 pub use lwe_ciphertext_vector_fusing_opposite::*;
 pub use lwe_ciphertext_vector_fusing_subtraction::*;
+pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_automorphism_packing::*;
 pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch::*;
 pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_packing_keyswitch::*;
+pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_sample_extraction::*;
+pub use lwe_ciphertext_vector_glwe_ciphertext_vector_discarding_sample_extraction::*;
 pub use lwe_ciphertext_vector_loading::*;
+pub use lwe_ciphertext_vector_modulus_switching::*;
 pub use lwe_ciphertext_vector_trivial_decryption::*;
 pub use lwe_ciphertext_vector_trivial_encryption::*;
 pub use lwe_ciphertext_vector_zero_encryption::*;
 pub use lwe_ciphertext_zero_encryption::*;
+pub use lwe_ciphertexts_gsw_ciphertext_fusing_cmux::*;
 pub use lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation::*;
+pub use lwe_compressed_ciphertext_vector_decryption::*;
 pub use lwe_keyswitch_key_conversion::*;
 pub use lwe_keyswitch_key_discarding_conversion::*;
 pub use lwe_keyswitch_key_generation::*;
@@ -416,14 +512,26 @@ pub use lwe_public_key_generation::*;
 pub use lwe_secret_key_conversion::*;
 pub use lwe_secret_key_discarding_conversion::*;
 pub use lwe_secret_key_generation::*;
+pub use lwe_secret_key_with_kind_generation::*;
 pub use lwe_seeded_bootstrap_key_generation::*;
 pub use lwe_seeded_bootstrap_key_to_lwe_bootstrap_key_transformation::*;
 pub use lwe_seeded_ciphertext_encryption::*;
 pub use lwe_seeded_ciphertext_to_lwe_ciphertext_transformation::*;
+pub use lwe_seeded_ciphertext_vector_ciphertext_expansion::*;
 pub use lwe_seeded_ciphertext_vector_encryption::*;
+pub use lwe_seeded_ciphertext_vector_range_expansion::*;
 pub use lwe_seeded_ciphertext_vector_to_lwe_ciphertext_vector_transformation::*;
+pub use lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation::*;
+pub use lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys_to_lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_transformation::*;
 pub use lwe_seeded_keyswitch_key_generation::*;
+pub use lwe_seeded_keyswitch_key_range_expansion::*;
 pub use lwe_seeded_keyswitch_key_to_lwe_keyswitch_key_transformation::*;
+pub use lwe_seeded_packing_keyswitch_key_generation::*;
+pub use lwe_seeded_packing_keyswitch_key_to_lwe_packing_keyswitch_key_transformation::*;
+pub use lwe_seeded_private_functional_packing_keyswitch_key_generation::*;
+pub use lwe_seeded_private_functional_packing_keyswitch_key_to_lwe_private_functional_packing_keyswitch_key_transformation::*;
+pub use lwe_seeded_public_key_generation::*;
+pub use lwe_seeded_public_key_to_lwe_public_key_transformation::*;
 pub use lwe_to_glwe_secret_key_transformation::*;
 pub use plaintext_conversion::*;
 pub use plaintext_creation::*;
@@ -0,0 +1,35 @@
+use crate::specification::entities::markers::GlweAutomorphismKeyKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{
+    AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    PolynomialSize,
+};
+
+/// A trait implemented by types embodying a GLWE automorphism key.
+///
+/// A GLWE automorphism key allows to apply ring automorphisms $X \mapsto X^k$ to GLWE
+/// ciphertexts, without changing the secret key they are encrypted under.
+///
+/// # Formal Definition
+///
+/// Given a GLWE secret key $\vec{S}$ and an odd exponent $k$, the automorphism
+/// $\tau\_k: X \mapsto X^k$ maps a GLWE ciphertext encrypted under $\vec{S}$ to a GLWE ciphertext
+/// encrypted under $\tau\_k(\vec{S})$. For every exponent $k$ it supports, a GLWE automorphism key
+/// contains a [`GLWE keyswitch key`](`crate::specification::entities::GlweKeyswitchKeyEntity`)
+/// from $\tau\_k(\vec{S})$ to $\vec{S}$.
+pub trait GlweAutomorphismKeyEntity: AbstractEntity<Kind = GlweAutomorphismKeyKind> {
+    /// Returns the GLWE dimension of the key.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the key.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+
+    /// Returns the exponents of the automorphisms supported by the key.
+    fn automorphism_exponents(&self) -> &[AutomorphismExponent];
+}
@@ -0,0 +1,33 @@
+use crate::specification::entities::markers::GlweKeyswitchKeyKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A trait implemented by types embodying a GLWE keyswitch key.
+///
+/// A GLWE keyswitch key allows to switch a GLWE ciphertext from an input GLWE secret key to an
+/// output GLWE secret key with the same polynomial size, but possibly a different GLWE dimension.
+///
+/// # Formal Definition
+///
+/// Given an input GLWE secret key $\vec{S} = (S\_0, \cdots, S\_{k-1})$ and an output GLWE secret
+/// key $\vec{S'}$, a GLWE keyswitch key is made of the GLWE encryptions under $\vec{S'}$ of
+/// $S\_i \cdot \frac{q}{B^l}$, for every $0 \leq i < k$ and every level $1 \leq l \leq \ell$ of
+/// the decomposition.
+pub trait GlweKeyswitchKeyEntity: AbstractEntity<Kind = GlweKeyswitchKeyKind> {
+    /// Returns the input GLWE dimension of the key.
+    fn input_glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the output GLWE dimension of the key.
+    fn output_glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the key.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+}
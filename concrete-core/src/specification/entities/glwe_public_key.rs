@@ -0,0 +1,32 @@
+use crate::specification::entities::markers::GlwePublicKeyKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{
+    GlweDimension, GlwePublicKeyZeroEncryptionCount, PolynomialSize,
+};
+
+/// A trait implemented by types embodying a GLWE public key.
+///
+/// # Formal Definition
+///
+/// ## GLWE Public Key
+///
+/// A GLWE public key contains $m$ GLWE encryptions of 0 under a secret key
+/// $\vec{S}\in\mathcal{R}\_q^k$ where $k$ is the GLWE dimension of the ciphertexts contained in
+/// the public key.
+///
+/// Encrypting a plaintext polynomial $M$ with the public key is done by sampling, for each
+/// encryption of zero $\left(\vec{A}\_j, B\_j\right)$, a randomness polynomial $U\_j$ with small
+/// (e.g. binary or ternary) coefficients, and computing:
+/// $$\left(\sum\_{j=0}^{m-1} U\_j \cdot \vec{A}\_j + \vec{E}, E' + M +
+/// \sum\_{j=0}^{m-1} U\_j \cdot B\_j\right)$$
+/// where $\vec{E}$ and $E'$ are fresh noise polynomials.
+pub trait GlwePublicKeyEntity: AbstractEntity<Kind = GlwePublicKeyKind> {
+    /// Returns the GLWE dimension of the key.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the key.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of GLWE encryption of 0 in the key.
+    fn glwe_zero_encryption_count(&self) -> GlwePublicKeyZeroEncryptionCount;
+}
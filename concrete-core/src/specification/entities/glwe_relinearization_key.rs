@@ -0,0 +1,29 @@
+use crate::specification::entities::markers::GlweRelinearizationKeyKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A trait implemented by types embodying a GLWE relinearization key.
+///
+/// A GLWE relinearization key allows to turn the tensor product of two GLWE ciphertexts back
+/// into a GLWE ciphertext encrypted under the original secret key.
+///
+/// # Formal Definition
+///
+/// Given a GLWE secret key $\vec{S} = (S\_0, \cdots, S\_{k-1})$, a relinearization key is made of
+/// the GLWE encryptions under $\vec{S}$ of $-S\_i S\_j \cdot \frac{q}{B^l}$, for every pair
+/// $0 \leq i \leq j < k$ and every level $1 \leq l \leq \ell$ of the decomposition.
+pub trait GlweRelinearizationKeyEntity: AbstractEntity<Kind = GlweRelinearizationKeyKind> {
+    /// Returns the GLWE dimension of the key.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the key.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+}
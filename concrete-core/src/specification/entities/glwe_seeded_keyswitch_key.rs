@@ -0,0 +1,34 @@
+use crate::commons::math::random::CompressionSeed;
+use crate::specification::entities::markers::GlweSeededKeyswitchKeyKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A trait implemented by types embodying a seeded GLWE keyswitch key.
+///
+/// # Formal Definition
+///
+/// A seeded GLWE keyswitch key only stores the bodies of the GLWE ciphertexts making a
+/// [`GlweKeyswitchKeyEntity`](`crate::specification::entities::GlweKeyswitchKeyEntity`), along
+/// with the seed used to generate their masks.
+pub trait GlweSeededKeyswitchKeyEntity: AbstractEntity<Kind = GlweSeededKeyswitchKeyKind> {
+    /// Returns the input GLWE dimension of the key.
+    fn input_glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the output GLWE dimension of the key.
+    fn output_glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the key.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+
+    /// Returns the compression seed used to generate the masks of the GLWE ciphertexts during
+    /// encryption.
+    fn compression_seed(&self) -> CompressionSeed;
+}
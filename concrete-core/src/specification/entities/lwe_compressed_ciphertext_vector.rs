@@ -0,0 +1,25 @@
+use crate::specification::entities::markers::LweCompressedCiphertextVectorKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{CiphertextModulusLog, LweCiphertextCount, LweDimension};
+
+/// A trait implemented by types embodying a compressed LWE ciphertext vector.
+///
+/// A compressed LWE ciphertext vector holds LWE ciphertexts whose coefficients were switched to
+/// a smaller power-of-two modulus $2^{q}$, where $q$ is the ciphertext modulus log. Each
+/// coefficient only takes $q$ bits in the compressed representation.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::specification::entities::LweCiphertextEntity`)
+pub trait LweCompressedCiphertextVectorEntity:
+    AbstractEntity<Kind = LweCompressedCiphertextVectorKind>
+{
+    /// Returns the LWE dimension of the ciphertexts.
+    fn lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the number of ciphertexts contained in the vector.
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount;
+
+    /// Returns the logarithm of the modulus of the ciphertexts.
+    fn ciphertext_modulus_log(&self) -> CiphertextModulusLog;
+}
@@ -0,0 +1,32 @@
+use crate::commons::math::random::CompressionSeed;
+use crate::specification::entities::markers::LweSeededPackingKeyswitchKeyKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// A trait implemented by types embodying a seeded packing keyswitch key.
+///
+/// # Formal Definition
+pub trait LweSeededPackingKeyswitchKeyEntity:
+    AbstractEntity<Kind = LweSeededPackingKeyswitchKeyKind>
+{
+    /// Returns the input LWE dimension of the key.
+    fn input_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the output GLWE dimension of the key.
+    fn output_glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the output polynomial degree of the key.
+    fn output_polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+
+    /// Returns the compression seed used to generate the masks of the GLWE ciphertexts during
+    /// encryption.
+    fn compression_seed(&self) -> CompressionSeed;
+}
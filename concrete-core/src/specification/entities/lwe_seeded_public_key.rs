@@ -0,0 +1,25 @@
+use crate::commons::math::random::CompressionSeed;
+use crate::specification::entities::markers::LweSeededPublicKeyKind;
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+
+/// A trait implemented by types embodying a seeded LWE public key.
+///
+/// # Formal Definition
+///
+/// ## Seeded LWE Public Key
+///
+/// A seeded LWE public key contains the bodies of $m$ LWE encryptions of 0 under a secret key
+/// $\vec{s}\in\mathbb{Z}\_q^n$, along with the seed used to sample their masks. The masks can be
+/// regenerated from the seed to recover a standard
+/// [`LWE public key`](`crate::specification::entities::LwePublicKeyEntity`).
+pub trait LweSeededPublicKeyEntity: AbstractEntity<Kind = LweSeededPublicKeyKind> {
+    /// Returns the LWE dimension of the key.
+    fn lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the number of LWE encryption of 0 in the key.
+    fn lwe_zero_encryption_count(&self) -> LwePublicKeyZeroEncryptionCount;
+
+    /// Returns the seed used to compress the LWE encryptions of 0.
+    fn compression_seed(&self) -> CompressionSeed;
+}
@@ -38,6 +38,9 @@ entity_kind_marker! {
             => "An empty type representing the LWE ciphertext kind in the type system.",
         LweCiphertextVectorKind
             => "An empty type representing the LWE ciphertext vector kind in the type system.",
+        LweCompressedCiphertextVectorKind
+            => "An empty type representing the compressed LWE ciphertext vector kind in the \
+            type system.",
         LweSeededCiphertextKind
             => "An empty type representing the seeded LWE ciphertext kind in the type system.",
         LweSeededCiphertextVectorKind
@@ -62,6 +65,10 @@ entity_kind_marker! {
             => "An empty type representing the GSW ciphertext vector kind in the type system.",
         LwePublicKeyKind
             => "An empty type representing the LWE public key kind in the type system.",
+        LweSeededPublicKeyKind
+            => "An empty type representing the seeded LWE public key kind in the type system.",
+        GlwePublicKeyKind
+            => "An empty type representing the GLWE public key kind in the type system.",
         LweSecretKeyKind
             => "An empty type representing the LWE secret key kind in the type system.",
         GlweSecretKeyKind
@@ -72,12 +79,29 @@ entity_kind_marker! {
             => "An empty type representing the seeded LWE keyswitch key kind in the type system.",
         LwePackingKeyswitchKeyKind
             => "An empty type representing the packing keyswitch key kind in the type system.",
+        LweSeededPackingKeyswitchKeyKind
+            => "An empty type representing the seeded packing keyswitch key kind in the type \
+            system.",
+        GlweRelinearizationKeyKind
+            => "An empty type representing the GLWE relinearization key kind in the type system.",
+        GlweKeyswitchKeyKind
+            => "An empty type representing the GLWE keyswitch key kind in the type system.",
+        GlweSeededKeyswitchKeyKind
+            => "An empty type representing the seeded GLWE keyswitch key kind in the type system.",
+        GlweAutomorphismKeyKind
+            => "An empty type representing the GLWE automorphism key kind in the type system.",
         LwePrivateFunctionalPackingKeyswitchKeyKind
             => "An empty type representing the private functional packing keyswitch key in the \
             type system.",
         LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysKind
             => "An empty type representing the private functional packing keyswitch key vector \
             used for a circuit bootstrap in the type system.",
+        LweSeededPrivateFunctionalPackingKeyswitchKeyKind
+            => "An empty type representing the seeded private functional packing keyswitch key \
+            in the type system.",
+        LweSeededCircuitBootstrapPrivateFunctionalPackingKeyswitchKeysKind
+            => "An empty type representing the seeded private functional packing keyswitch key \
+            vector used for a circuit bootstrap in the type system.",
         LweBootstrapKeyKind
             => "An empty type representing the LWE bootstrap key kind in the type system.",
         LweSeededBootstrapKeyKind
@@ -41,17 +41,23 @@ mod encoder_vector;
 mod ggsw_ciphertext;
 mod ggsw_ciphertext_vector;
 mod ggsw_seeded_ciphertext;
+mod glwe_automorphism_key;
 mod glwe_ciphertext;
 mod glwe_ciphertext_vector;
+mod glwe_keyswitch_key;
+mod glwe_public_key;
+mod glwe_relinearization_key;
 mod glwe_secret_key;
 mod glwe_seeded_ciphertext;
 mod glwe_seeded_ciphertext_vector;
+mod glwe_seeded_keyswitch_key;
 mod gsw_ciphertext;
 mod gsw_ciphertext_vector;
 mod lwe_bootstrap_key;
 mod lwe_ciphertext;
 mod lwe_ciphertext_vector;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys;
+mod lwe_compressed_ciphertext_vector;
 mod lwe_keyswitch_key;
 mod lwe_packing_keyswitch_key;
 mod lwe_private_functional_packing_keyswitch_key;
@@ -60,7 +66,11 @@ mod lwe_secret_key;
 mod lwe_seeded_bootstrap_key;
 mod lwe_seeded_ciphertext;
 mod lwe_seeded_ciphertext_vector;
+mod lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys;
 mod lwe_seeded_keyswitch_key;
+mod lwe_seeded_packing_keyswitch_key;
+mod lwe_seeded_private_functional_packing_keyswitch_key;
+mod lwe_seeded_public_key;
 mod plaintext;
 mod plaintext_vector;
 
@@ -71,17 +81,23 @@ pub use encoder_vector::*;
 pub use ggsw_ciphertext::*;
 pub use ggsw_ciphertext_vector::*;
 pub use ggsw_seeded_ciphertext::*;
+pub use glwe_automorphism_key::*;
 pub use glwe_ciphertext::*;
 pub use glwe_ciphertext_vector::*;
+pub use glwe_keyswitch_key::*;
+pub use glwe_public_key::*;
+pub use glwe_relinearization_key::*;
 pub use glwe_secret_key::*;
 pub use glwe_seeded_ciphertext::*;
 pub use glwe_seeded_ciphertext_vector::*;
+pub use glwe_seeded_keyswitch_key::*;
 pub use gsw_ciphertext::*;
 pub use gsw_ciphertext_vector::*;
 pub use lwe_bootstrap_key::*;
 pub use lwe_ciphertext::*;
 pub use lwe_ciphertext_vector::*;
 pub use lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys::*;
+pub use lwe_compressed_ciphertext_vector::*;
 pub use lwe_keyswitch_key::*;
 pub use lwe_packing_keyswitch_key::*;
 pub use lwe_private_functional_packing_keyswitch_key::*;
@@ -90,6 +106,10 @@ pub use lwe_secret_key::*;
 pub use lwe_seeded_bootstrap_key::*;
 pub use lwe_seeded_ciphertext::*;
 pub use lwe_seeded_ciphertext_vector::*;
+pub use lwe_seeded_circuit_bootstrap_private_functional_packing_keyswitch_keys::*;
 pub use lwe_seeded_keyswitch_key::*;
+pub use lwe_seeded_packing_keyswitch_key::*;
+pub use lwe_seeded_private_functional_packing_keyswitch_key::*;
+pub use lwe_seeded_public_key::*;
 pub use plaintext::*;
 pub use plaintext_vector::*;
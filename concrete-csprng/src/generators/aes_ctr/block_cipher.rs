@@ -15,6 +15,8 @@ pub struct AesKey(pub u128);
 pub trait AesBlockCipher: Clone + Send + Sync {
     /// Instantiate a new generator from a secret key.
     fn new(key: AesKey) -> Self;
+    /// Returns the secret key the block cipher was instantiated from.
+    fn key(&self) -> AesKey;
     /// Generates the batch corresponding to the given index.
     fn generate_batch(&mut self, index: AesIndex) -> [u8; BYTES_PER_BATCH];
 }
@@ -1,8 +1,12 @@
 use crate::generators::aes_ctr::block_cipher::{AesBlockCipher, AesKey};
-use crate::generators::aes_ctr::index::TableIndex;
+use crate::generators::aes_ctr::index::{AesIndex, TableIndex};
 use crate::generators::aes_ctr::states::{BufferPointer, ShiftAction, State};
-use crate::generators::aes_ctr::BYTES_PER_BATCH;
-use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError};
+use crate::generators::aes_ctr::{BYTES_PER_AES_CALL, BYTES_PER_BATCH};
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, SeekError, StateError,
+};
+use crate::seeders::Seed;
+use std::convert::TryInto;
 
 // Usually, to work with iterators and parallel iterators, we would use opaque types such as
 // `impl Iterator<..>`. Unfortunately, it is not yet possible to return existential types in
@@ -22,6 +26,23 @@ pub type ChildrenIterator<BlockCipher> = std::iter::Map<
     ChildrenClosure<BlockCipher>,
 >;
 
+/// The most significant byte of the aes indices used to derive seeds.
+const DERIVATION_TAG: u8 = 0xFF;
+
+/// The number of label bytes encoded in a single aes index when deriving seeds.
+///
+/// The least significant byte of the index is left to zero, so that the batch starting at this
+/// index can not overflow.
+const DERIVATION_BYTES_PER_INDEX: usize = BYTES_PER_AES_CALL - 2;
+
+// Encodes at most `DERIVATION_BYTES_PER_INDEX` bytes in an aes index used to derive seeds.
+fn derivation_index(bytes: &[u8]) -> AesIndex {
+    let mut index = [0u8; BYTES_PER_AES_CALL];
+    index[1..=bytes.len()].copy_from_slice(bytes);
+    index[BYTES_PER_AES_CALL - 1] = DERIVATION_TAG;
+    AesIndex(u128::from_le_bytes(index))
+}
+
 /// A type implementing the `RandomGenerator` api using the AES block cipher in counter mode.
 #[derive(Clone)]
 pub struct AesCtrGenerator<BlockCipher: AesBlockCipher> {
@@ -29,6 +50,8 @@ pub struct AesCtrGenerator<BlockCipher: AesBlockCipher> {
     pub(crate) block_cipher: Box<BlockCipher>,
     // The state corresponding to the latest outputted byte.
     pub(crate) state: State,
+    // The first legal index. The generator can not seek before it.
+    pub(crate) first: TableIndex,
     // The last legal index. This makes bound check faster.
     pub(crate) last: TableIndex,
     // The buffer containing the current batch of aes calls.
@@ -77,6 +100,7 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
         AesCtrGenerator {
             block_cipher,
             state,
+            first: start_index,
             last,
             buffer,
         }
@@ -87,6 +111,13 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
         self.state.table_index()
     }
 
+    /// Returns the first table index the generator is allowed to output.
+    ///
+    /// After a fork, this is the first index following the bytes given to the children.
+    pub fn get_first(&self) -> TableIndex {
+        self.first
+    }
+
     /// Returns the bound of the generator if any.
     ///
     /// The bound is the table index of the first byte that can not be outputted by the generator.
@@ -117,6 +148,87 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
             .expect("Tried to generate a byte after the bound.")
     }
 
+    /// Moves the generator so that the next outputted byte is the one at `index`.
+    ///
+    /// The index must lie between the first index of the generator and its bound, both included.
+    pub fn seek(&mut self, index: TableIndex) -> Result<(), SeekError> {
+        if index < self.first {
+            return Err(SeekError::BeforeStart);
+        }
+        if index > self.get_bound() {
+            return Err(SeekError::AfterBound);
+        }
+        self.state = State::new(index);
+        Ok(())
+    }
+
+    /// Moves the generator so that the next outputted byte is the one located `byte` bytes after
+    /// its first index.
+    pub fn jump_to_byte(&mut self, byte: ByteCount) -> Result<(), SeekError> {
+        let index = self
+            .first
+            .checked_increased(byte)
+            .ok_or(SeekError::AfterBound)?;
+        self.seek(index)
+    }
+
+    /// Returns the state of the generator.
+    pub fn get_state(&self) -> GeneratorState {
+        GeneratorState {
+            seed: Seed(self.block_cipher.key().0),
+            first: self.first,
+            next: self.state.table_index().incremented(),
+            bound: self.get_bound(),
+        }
+    }
+
+    /// Tries to restore a generator from a state.
+    ///
+    /// The table indices of the state must satisfy `first <= next <= bound`. As for generators
+    /// created with [`AesCtrGenerator::new`], the first table index can not be used as `first`.
+    pub fn try_from_state(
+        state: GeneratorState,
+    ) -> Result<AesCtrGenerator<BlockCipher>, StateError> {
+        if !(TableIndex::FIRST < state.first
+            && state.first <= state.next
+            && state.next <= state.bound)
+        {
+            return Err(StateError::UnorderedIndices);
+        }
+        Ok(AesCtrGenerator {
+            block_cipher: Box::new(BlockCipher::new(AesKey(state.seed.0))),
+            state: State::new(state.next),
+            first: state.first,
+            last: state.bound.decremented(),
+            buffer: [0u8; BYTES_PER_BATCH],
+        })
+    }
+
+    /// Derives a new seed from a master seed and a label.
+    ///
+    /// Note:
+    /// -----
+    ///
+    /// The derivation is a cascade of block cipher calls. The label is encoded as a sequence of aes
+    /// indices, the first one containing its length, and each index is encrypted using the output
+    /// of the previous call as key, the first key being the master seed. The length prefix makes
+    /// the encodings of distinct labels prefix-free, which makes the cascade a pseudo-random
+    /// function of the label.
+    ///
+    /// The most significant byte of the encoded indices is set to `0xFF`, so that deriving a seed
+    /// never evaluates the block cipher on an index a generator would reach in practice.
+    pub fn derive_seed(master: Seed, label: &[u8]) -> Seed {
+        let length = derivation_index(&(label.len() as u64).to_le_bytes());
+        let chunks = label.chunks(DERIVATION_BYTES_PER_INDEX);
+        let seed = std::iter::once(length)
+            .chain(chunks.map(derivation_index))
+            .fold(master.0, |key, index| {
+                let batch = BlockCipher::new(AesKey(key)).generate_batch(index);
+                u128::from_le_bytes(batch[..BYTES_PER_AES_CALL].try_into().unwrap())
+            });
+        Seed(seed)
+    }
+
     /// Tries to fork the current generator into `n_child` generators each able to output
     /// `child_bytes` random bytes.
     pub fn try_fork(
@@ -161,9 +273,11 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
                     )
                 }) as ChildrenClosure<BlockCipher>,
             );
-        // The parent next index is the bound of the last child.
+        // The parent next index is the bound of the last child. The parent can no longer seek
+        // before it, to avoid outputting the bytes given to the children.
         let next_index = first_index.increased(n_bytes.0 * n_children.0);
         self.state = State::new(next_index);
+        self.first = next_index;
 
         Ok(output)
     }
@@ -376,4 +490,160 @@ pub mod aes_ctr_generic_test {
             );
         }
     }
+
+    /// Check the property:
+    ///     After outputting n bytes, seeking back to the k-th byte (k < n) makes the generator
+    ///     output the same bytes as before, starting from the k-th one.
+    pub fn prop_seek<G: AesBlockCipher>() {
+        for _ in 0..1000 {
+            let (t, _, _, i) = any_valid_fork().next().unwrap();
+            let k = any_key().next().unwrap();
+            let n_bytes = thread_rng().gen::<usize>() % 4096 + 1;
+            let skipped = thread_rng().gen::<usize>() % n_bytes;
+            let mut generator =
+                AesCtrGenerator::<G>::new(k, Some(t), Some(t.increased(n_bytes + i % 4096)));
+            let output: Vec<u8> = generator.by_ref().take(n_bytes).collect();
+            generator.seek(t.increased(skipped)).unwrap();
+            let seeked_output: Vec<u8> = generator.by_ref().take(n_bytes - skipped).collect();
+            assert_eq!(output[skipped..], seeked_output);
+        }
+    }
+
+    /// Check the property:
+    ///     Jumping to the k-th byte of a fresh generator is the same as outputting k bytes.
+    pub fn prop_jump_to_byte<G: AesBlockCipher>() {
+        for _ in 0..1000 {
+            let (t, _, _, i) = any_valid_fork().next().unwrap();
+            let k = any_key().next().unwrap();
+            let n_bytes = thread_rng().gen::<usize>() % 4096 + 1;
+            let skipped = thread_rng().gen::<usize>() % n_bytes;
+            let bound = t.increased(n_bytes + i % 4096);
+            let mut generator = AesCtrGenerator::<G>::new(k, Some(t), Some(bound));
+            let mut jumped_generator = generator.clone();
+            generator.by_ref().take(skipped).for_each(drop);
+            jumped_generator
+                .jump_to_byte(ByteCount(skipped as u128))
+                .unwrap();
+            assert_eq!(generator.table_index(), jumped_generator.table_index());
+            assert_eq!(
+                generator.remaining_bytes(),
+                jumped_generator.remaining_bytes()
+            );
+            assert!(generator.zip(jumped_generator).all(|(a, b)| a == b));
+        }
+    }
+
+    /// Check the property:
+    ///     A generator can not seek before its first index, nor after its bound.
+    pub fn prop_seek_out_of_bounds<G: AesBlockCipher>() {
+        for _ in 0..REPEATS {
+            let (t, nc, nb, i) = any_valid_fork().next().unwrap();
+            let k = any_key().next().unwrap();
+            let bound = t.increased(nc.0 * nb.0 + i);
+            let mut generator = AesCtrGenerator::<G>::new(k, Some(t), Some(bound));
+            assert!(matches!(
+                generator.seek(t.decremented()),
+                Err(SeekError::BeforeStart)
+            ));
+            assert!(matches!(
+                generator.seek(bound.incremented()),
+                Err(SeekError::AfterBound)
+            ));
+            generator.seek(bound).unwrap();
+            assert_eq!(generator.remaining_bytes(), ByteCount(0));
+        }
+    }
+
+    /// Check the property:
+    ///     On a valid fork, the parent can not seek back to the bytes given to its children, and
+    ///     jumping to its first byte leads to the bound of the last child.
+    pub fn prop_fork_parent_seek<G: AesBlockCipher>() {
+        for _ in 0..REPEATS {
+            let (t, nc, nb, i) = any_valid_fork().next().unwrap();
+            let k = any_key().next().unwrap();
+            let mut generator =
+                AesCtrGenerator::<G>::new(k, Some(t), Some(t.increased(nc.0 * nb.0 + i)));
+            let last_child = generator.try_fork(nc, nb).unwrap().last().unwrap();
+            assert!(matches!(generator.seek(t), Err(SeekError::BeforeStart)));
+            generator.jump_to_byte(ByteCount(0)).unwrap();
+            assert_eq!(generator.get_first(), last_child.get_bound());
+            assert_eq!(
+                generator.table_index().incremented(),
+                last_child.get_bound()
+            );
+        }
+    }
+
+    /// Check the property:
+    ///     A generator restored from the state of another one outputs the same bytes.
+    pub fn prop_state_round_trip<G: AesBlockCipher>() {
+        for _ in 0..1000 {
+            let (t, _, _, i) = any_valid_fork().next().unwrap();
+            let k = any_key().next().unwrap();
+            let n_bytes = thread_rng().gen::<usize>() % 4096 + 1;
+            let skipped = thread_rng().gen::<usize>() % n_bytes;
+            let bound = t.increased(n_bytes + i % 4096);
+            let mut generator = AesCtrGenerator::<G>::new(k, Some(t), Some(bound));
+            generator.by_ref().take(skipped).for_each(drop);
+            let restored = AesCtrGenerator::<G>::try_from_state(generator.get_state()).unwrap();
+            assert_eq!(generator.get_state(), restored.get_state());
+            assert_eq!(generator.remaining_bytes(), restored.remaining_bytes());
+            assert!(generator.zip(restored).all(|(a, b)| a == b));
+        }
+    }
+
+    /// Check the property:
+    ///     A generator can not be restored from a state with unordered indices.
+    pub fn prop_invalid_state<G: AesBlockCipher>() {
+        for _ in 0..REPEATS {
+            let (t, nc, nb, i) = any_valid_fork().next().unwrap();
+            let k = any_key().next().unwrap();
+            let bound = t.increased(nc.0 * nb.0 + i);
+            let state = AesCtrGenerator::<G>::new(k, Some(t), Some(bound)).get_state();
+            let before_first = GeneratorState {
+                next: t.decremented(),
+                ..state
+            };
+            assert!(matches!(
+                AesCtrGenerator::<G>::try_from_state(before_first),
+                Err(StateError::UnorderedIndices)
+            ));
+            let after_bound = GeneratorState {
+                next: bound.incremented(),
+                ..state
+            };
+            assert!(matches!(
+                AesCtrGenerator::<G>::try_from_state(after_bound),
+                Err(StateError::UnorderedIndices)
+            ));
+        }
+    }
+
+    /// Check the property:
+    ///     Seeds derived from the same master seed and label are equal, and seeds derived from
+    ///     distinct labels are distinct.
+    pub fn prop_derive_seed<G: AesBlockCipher>() {
+        for _ in 0..REPEATS {
+            let master = Seed(any_key().next().unwrap().0);
+            let length = thread_rng().gen::<usize>() % 64;
+            let label: Vec<u8> = (0..length).map(|_| thread_rng().gen()).collect();
+            let seed = AesCtrGenerator::<G>::derive_seed(master, &label);
+            assert_eq!(seed, AesCtrGenerator::<G>::derive_seed(master, &label));
+            assert_ne!(seed, master);
+            let mut padded_label = label.clone();
+            padded_label.push(0);
+            assert_ne!(
+                seed,
+                AesCtrGenerator::<G>::derive_seed(master, &padded_label)
+            );
+            if let Some((_, prefix)) = label.split_last() {
+                assert_ne!(seed, AesCtrGenerator::<G>::derive_seed(master, prefix));
+            }
+            let other_master = Seed(master.0 ^ 1);
+            assert_ne!(
+                seed,
+                AesCtrGenerator::<G>::derive_seed(other_master, &label)
+            );
+        }
+    }
 }
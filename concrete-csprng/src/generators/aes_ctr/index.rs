@@ -37,7 +37,6 @@ impl TableIndex {
     };
 
     /// Creates a table index from an aes index and a byte index.
-    #[allow(unused)] // to please clippy when tests are not activated
     pub fn new(aes_index: AesIndex, byte_index: ByteIndex) -> Self {
         assert!(byte_index.0 < BYTES_PER_AES_CALL);
         TableIndex {
@@ -46,6 +45,24 @@ impl TableIndex {
         }
     }
 
+    /// Creates a table index from an aes index and a byte index, if the byte index is valid.
+    pub fn try_new(aes_index: AesIndex, byte_index: ByteIndex) -> Option<Self> {
+        (byte_index.0 < BYTES_PER_AES_CALL).then_some(TableIndex {
+            aes_index,
+            byte_index,
+        })
+    }
+
+    /// Returns the aes index of the table index.
+    pub fn aes_index(&self) -> AesIndex {
+        self.aes_index
+    }
+
+    /// Returns the byte index of the table index.
+    pub fn byte_index(&self) -> ByteIndex {
+        self.byte_index
+    }
+
     /// Shifts the table index forward of `shift` bytes.
     pub fn increase(&mut self, shift: usize) {
         let total = self.byte_index.0 + shift;
@@ -90,6 +107,21 @@ impl TableIndex {
         self
     }
 
+    /// Returns the table index shifted forward by `shift` bytes, or `None` if the shifted index
+    /// would exceed the last table index.
+    pub fn checked_increased(self, shift: ByteCount) -> Option<Self> {
+        let total = self.byte_index.0 as u128 + shift.0 % BYTES_PER_AES_CALL as u128;
+        let aes_index = self
+            .aes_index
+            .0
+            .checked_add(shift.0 / BYTES_PER_AES_CALL as u128)?
+            .checked_add(total / BYTES_PER_AES_CALL as u128)?;
+        Some(TableIndex {
+            aes_index: AesIndex(aes_index),
+            byte_index: ByteIndex((total % BYTES_PER_AES_CALL as u128) as usize),
+        })
+    }
+
     /// Returns the table index shifted backward by `shift` bytes.
     #[allow(unused)] // to please clippy when tests are not activated
     pub fn decreased(mut self, shift: usize) -> Self {
@@ -319,6 +351,42 @@ mod test {
         }
     }
 
+    #[test]
+    /// Check the property:
+    ///     For all table indices t, positive integer i such that t.increased(i) does not wrap,
+    ///         t.checked_increased(i) = Some(t.increased(i)).
+    fn prop_table_index_checked_increased() {
+        for _ in 0..REPEATS {
+            let (t, inc) = any_table_index()
+                .zip(any_usize())
+                .find(|(t, inc)| {
+                    (*inc as u128) <= TableIndex::distance(&TableIndex::LAST, t).unwrap().0
+                })
+                .unwrap();
+            assert_eq!(
+                t.checked_increased(ByteCount(inc as u128)),
+                Some(t.increased(inc))
+            );
+        }
+    }
+
+    #[test]
+    /// Verifies that `checked_increased` returns `None` instead of wrapping past the last index.
+    fn test_table_index_checked_increased_edge() {
+        assert_eq!(
+            TableIndex::LAST.checked_increased(ByteCount(0)),
+            Some(TableIndex::LAST)
+        );
+        assert_eq!(TableIndex::LAST.checked_increased(ByteCount(1)), None);
+        assert_eq!(
+            TableIndex::FIRST.checked_increased(ByteCount(u128::MAX)),
+            Some(TableIndex::new(
+                AesIndex(u128::MAX / BYTES_PER_AES_CALL as u128),
+                ByteIndex(BYTES_PER_AES_CALL - 1)
+            ))
+        );
+    }
+
     #[test]
     /// Check the property:
     ///     For all table indices t,
@@ -65,9 +65,11 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
                     )
                 }) as ChildrenClosure<BlockCipher>,
             );
-        // The parent next index is the bound of the last child.
+        // The parent next index is the bound of the last child. The parent can no longer seek
+        // before it, to avoid outputting the bytes given to the children.
         let next_index = first_index.increased(n_bytes.0 * n_children.0);
         self.state = State::new(next_index);
+        self.first = next_index;
 
         Ok(output)
     }
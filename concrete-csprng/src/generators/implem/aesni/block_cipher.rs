@@ -8,6 +8,8 @@ use std::mem::transmute;
 /// An aes block cipher implementation which uses `aesni` instructions.
 #[derive(Clone)]
 pub struct AesniBlockCipher {
+    // The key the round keys were generated from
+    key: AesKey,
     // The set of round keys used for the aes encryption
     round_keys: [__m128i; 11],
 }
@@ -26,7 +28,11 @@ impl AesBlockCipher for AesniBlockCipher {
         }
 
         let round_keys = generate_round_keys(key);
-        AesniBlockCipher { round_keys }
+        AesniBlockCipher { key, round_keys }
+    }
+
+    fn key(&self) -> AesKey {
+        self.key
     }
 
     fn generate_batch(&mut self, AesIndex(aes_ctr): AesIndex) -> [u8; BYTES_PER_BATCH] {
@@ -1,6 +1,9 @@
 use crate::generators::aes_ctr::{AesCtrGenerator, AesKey, ChildrenIterator};
 use crate::generators::implem::aesni::block_cipher::AesniBlockCipher;
-use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError, RandomGenerator};
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, RandomGenerator, SeekError,
+    StateError, TableIndex,
+};
 use crate::seeders::Seed;
 
 /// A random number generator using the `aesni` instructions.
@@ -36,6 +39,21 @@ impl RandomGenerator for AesniRandomGenerator {
             .try_fork(n_children, n_bytes)
             .map(AesniChildrenIterator)
     }
+    fn seek(&mut self, index: TableIndex) -> Result<(), SeekError> {
+        self.0.seek(index)
+    }
+    fn jump_to_byte(&mut self, byte: ByteCount) -> Result<(), SeekError> {
+        self.0.jump_to_byte(byte)
+    }
+    fn get_state(&self) -> GeneratorState {
+        self.0.get_state()
+    }
+    fn try_from_state(state: GeneratorState) -> Result<Self, StateError> {
+        AesCtrGenerator::try_from_state(state).map(AesniRandomGenerator)
+    }
+    fn derive_seed(master: Seed, label: &[u8]) -> Seed {
+        AesCtrGenerator::<AesniBlockCipher>::derive_seed(master, label)
+    }
 }
 
 impl Iterator for AesniRandomGenerator {
@@ -87,6 +105,41 @@ mod test {
         aes_ctr_generic_test::prop_fork_parent_remaining_bytes::<AesniBlockCipher>();
     }
 
+    #[test]
+    fn prop_seek() {
+        aes_ctr_generic_test::prop_seek::<AesniBlockCipher>();
+    }
+
+    #[test]
+    fn prop_jump_to_byte() {
+        aes_ctr_generic_test::prop_jump_to_byte::<AesniBlockCipher>();
+    }
+
+    #[test]
+    fn prop_seek_out_of_bounds() {
+        aes_ctr_generic_test::prop_seek_out_of_bounds::<AesniBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_seek() {
+        aes_ctr_generic_test::prop_fork_parent_seek::<AesniBlockCipher>();
+    }
+
+    #[test]
+    fn prop_state_round_trip() {
+        aes_ctr_generic_test::prop_state_round_trip::<AesniBlockCipher>();
+    }
+
+    #[test]
+    fn prop_invalid_state() {
+        aes_ctr_generic_test::prop_invalid_state::<AesniBlockCipher>();
+    }
+
+    #[test]
+    fn prop_derive_seed() {
+        aes_ctr_generic_test::prop_derive_seed::<AesniBlockCipher>();
+    }
+
     #[test]
     fn test_roughly_uniform() {
         generator_generic_test::test_roughly_uniform::<AesniRandomGenerator>();
@@ -97,6 +150,16 @@ mod test {
         generator_generic_test::test_generator_determinism::<AesniRandomGenerator>();
     }
 
+    #[test]
+    fn test_jump_to_byte() {
+        generator_generic_test::test_jump_to_byte::<AesniRandomGenerator>();
+    }
+
+    #[test]
+    fn test_state_round_trip() {
+        generator_generic_test::test_state_round_trip::<AesniRandomGenerator>();
+    }
+
     #[test]
     fn test_fork() {
         generator_generic_test::test_fork_children::<AesniRandomGenerator>();
@@ -0,0 +1,170 @@
+use crate::generators::aes_ctr::{
+    AesBlockCipher, AesIndex, AesKey, BYTES_PER_AES_CALL, BYTES_PER_BATCH,
+};
+use std::convert::TryInto;
+
+/// The number of bytes outputted by a single evaluation of the ChaCha20 block function.
+const BYTES_PER_CHACHA_BLOCK: usize = 64;
+
+/// The number of aes indices covered by the output of a single ChaCha20 block.
+const AES_INDICES_PER_CHACHA_BLOCK: u128 = (BYTES_PER_CHACHA_BLOCK / BYTES_PER_AES_CALL) as u128;
+
+/// The maximal number of ChaCha20 blocks needed to fill a batch starting at any aes index.
+const MAX_CHACHA_BLOCKS_PER_BATCH: usize = BYTES_PER_BATCH / BYTES_PER_CHACHA_BLOCK + 1;
+
+/// The "expand 16-byte k" constant used by ChaCha with 128 bits keys.
+const TAU: [u32; 4] = [0x61707865, 0x3120646e, 0x79622d36, 0x6b206574];
+
+/// A block cipher using the ChaCha20 block function in place of aes.
+///
+/// Note:
+/// -----
+///
+/// The ChaCha20 block function maps a 128 bits counter to 64 bytes, which is the output of four
+/// aes calls. The 16 bytes associated with an aes index `i` are the `i % 4`-th quarter of the
+/// ChaCha20 block of counter `i / 4`. This way, the table of pseudo-random bytes can be indexed the
+/// same way as with aes, and all the machinery of the `aes_ctr` module can be reused.
+#[derive(Clone)]
+pub struct ChaChaBlockCipher {
+    // The key the state was instantiated from.
+    key: AesKey,
+    // The first twelve words of the ChaCha20 state, containing the constant and the key.
+    key_state: [u32; 12],
+}
+
+impl AesBlockCipher for ChaChaBlockCipher {
+    fn new(key: AesKey) -> ChaChaBlockCipher {
+        let key_bytes = key.0.to_le_bytes();
+        let mut key_state = [0u32; 12];
+        key_state[..4].copy_from_slice(&TAU);
+        for (i, word) in key_bytes.chunks_exact(4).enumerate() {
+            let word = u32::from_le_bytes(word.try_into().unwrap());
+            // A 128 bits key is repeated to fill the eight key words of the state.
+            key_state[4 + i] = word;
+            key_state[8 + i] = word;
+        }
+        ChaChaBlockCipher { key, key_state }
+    }
+
+    fn key(&self) -> AesKey {
+        self.key
+    }
+
+    fn generate_batch(&mut self, AesIndex(aes_ctr): AesIndex) -> [u8; BYTES_PER_BATCH] {
+        let first_block = aes_ctr / AES_INDICES_PER_CHACHA_BLOCK;
+        let offset = (aes_ctr % AES_INDICES_PER_CHACHA_BLOCK) as usize * BYTES_PER_AES_CALL;
+        // An aligned batch is covered by one block less than an unaligned one.
+        let n_blocks = if offset == 0 {
+            MAX_CHACHA_BLOCKS_PER_BATCH - 1
+        } else {
+            MAX_CHACHA_BLOCKS_PER_BATCH
+        };
+        let mut blocks = [0u8; MAX_CHACHA_BLOCKS_PER_BATCH * BYTES_PER_CHACHA_BLOCK];
+        for (i, block) in blocks
+            .chunks_exact_mut(BYTES_PER_CHACHA_BLOCK)
+            .take(n_blocks)
+            .enumerate()
+        {
+            block.copy_from_slice(&chacha20_block(&self.key_state, first_block + i as u128));
+        }
+        blocks[offset..offset + BYTES_PER_BATCH].try_into().unwrap()
+    }
+}
+
+// Evaluates the ChaCha20 block function on the state made of the key state and the counter. The
+// counter fills the last four words of the state, which are the block counter and the nonce in
+// RFC 8439.
+fn chacha20_block(key_state: &[u32; 12], counter: u128) -> [u8; BYTES_PER_CHACHA_BLOCK] {
+    let mut input = [0u32; 16];
+    input[..12].copy_from_slice(key_state);
+    for (i, word) in input[12..].iter_mut().enumerate() {
+        *word = (counter >> (32 * i)) as u32;
+    }
+    chacha20_core(&input)
+}
+
+// Applies the 20 rounds of ChaCha to the input state, and adds the input state to the result.
+fn chacha20_core(input: &[u32; 16]) -> [u8; BYTES_PER_CHACHA_BLOCK] {
+    let mut state = *input;
+    for _ in 0..10 {
+        // Column rounds
+        quarter_round(&mut state, 0, 4, 8, 12);
+        quarter_round(&mut state, 1, 5, 9, 13);
+        quarter_round(&mut state, 2, 6, 10, 14);
+        quarter_round(&mut state, 3, 7, 11, 15);
+        // Diagonal rounds
+        quarter_round(&mut state, 0, 5, 10, 15);
+        quarter_round(&mut state, 1, 6, 11, 12);
+        quarter_round(&mut state, 2, 7, 8, 13);
+        quarter_round(&mut state, 3, 4, 9, 14);
+    }
+    let mut output = [0u8; BYTES_PER_CHACHA_BLOCK];
+    for ((bytes, word), input_word) in output.chunks_exact_mut(4).zip(state).zip(input) {
+        bytes.copy_from_slice(&word.wrapping_add(*input_word).to_le_bytes());
+    }
+    output
+}
+
+#[inline(always)]
+fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(7);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::{thread_rng, Rng};
+
+    // Test vector for the ChaCha20 block function, from the section 2.3.2 of RFC 8439
+    const INPUT: [u32; 16] = [
+        0x61707865, 0x3320646e, 0x79622d32, 0x6b206574, 0x03020100, 0x07060504, 0x0b0a0908,
+        0x0f0e0d0c, 0x13121110, 0x17161514, 0x1b1a1918, 0x1f1e1d1c, 0x00000001, 0x09000000,
+        0x4a000000, 0x00000000,
+    ];
+    const OUTPUT: [u8; BYTES_PER_CHACHA_BLOCK] = [
+        0x10, 0xf1, 0xe7, 0xe4, 0xd1, 0x3b, 0x59, 0x15, 0x50, 0x0f, 0xdd, 0x1f, 0xa3, 0x20, 0x71,
+        0xc4, 0xc7, 0xd1, 0xf4, 0xc7, 0x33, 0xc0, 0x68, 0x03, 0x04, 0x22, 0xaa, 0x9a, 0xc3, 0xd4,
+        0x6c, 0x4e, 0xd2, 0x82, 0x64, 0x46, 0x07, 0x9f, 0xaa, 0x09, 0x14, 0xc2, 0xd7, 0x05, 0xd9,
+        0x8b, 0x02, 0xa2, 0xb5, 0x12, 0x9c, 0xd1, 0xde, 0x16, 0x4e, 0xb9, 0xcb, 0xd0, 0x83, 0xe8,
+        0xa2, 0x50, 0x3c, 0x4e,
+    ];
+
+    #[test]
+    fn test_chacha20_core() {
+        assert_eq!(chacha20_core(&INPUT), OUTPUT);
+    }
+
+    #[test]
+    fn test_chacha20_block_counter() {
+        // Checks that the counter fills the block counter and nonce words of the state.
+        let key_state: [u32; 12] = INPUT[..12].try_into().unwrap();
+        let counter = 0x00000000_4a000000_09000000_00000001;
+        assert_eq!(chacha20_block(&key_state, counter), OUTPUT);
+    }
+
+    #[test]
+    fn prop_unaligned_batch() {
+        // Checks that a batch starting at any aes index is the shifted concatenation of the
+        // aligned batches.
+        for _ in 0..1000 {
+            let mut cipher = ChaChaBlockCipher::new(AesKey(thread_rng().gen()));
+            let aligned_index = thread_rng().gen::<u128>() >> 4 << 2;
+            let shift = thread_rng().gen_range(0..AES_INDICES_PER_CHACHA_BLOCK);
+            let first_batch = cipher.generate_batch(AesIndex(aligned_index));
+            let second_batch = cipher.generate_batch(AesIndex(aligned_index + 8));
+            let expected = [&first_batch[..], &second_batch[..]].concat();
+            let offset = shift as usize * BYTES_PER_AES_CALL;
+            assert_eq!(
+                &cipher.generate_batch(AesIndex(aligned_index + shift))[..],
+                &expected[offset..offset + BYTES_PER_BATCH]
+            );
+        }
+    }
+}
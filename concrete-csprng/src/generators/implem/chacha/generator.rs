@@ -0,0 +1,173 @@
+use crate::generators::aes_ctr::{AesCtrGenerator, AesKey, ChildrenIterator};
+use crate::generators::implem::chacha::block_cipher::ChaChaBlockCipher;
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, RandomGenerator, SeekError,
+    StateError, TableIndex,
+};
+use crate::seeders::Seed;
+
+/// A random number generator using a portable implementation of the ChaCha20 block function.
+pub struct ChaChaRandomGenerator(pub(super) AesCtrGenerator<ChaChaBlockCipher>);
+
+/// The children iterator used by [`ChaChaRandomGenerator`].
+///
+/// Outputs children generators one by one.
+pub struct ChaChaChildrenIterator(ChildrenIterator<ChaChaBlockCipher>);
+
+impl Iterator for ChaChaChildrenIterator {
+    type Item = ChaChaRandomGenerator;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(ChaChaRandomGenerator)
+    }
+}
+
+impl RandomGenerator for ChaChaRandomGenerator {
+    type ChildrenIter = ChaChaChildrenIterator;
+    fn new(seed: Seed) -> Self {
+        ChaChaRandomGenerator(AesCtrGenerator::new(AesKey(seed.0), None, None))
+    }
+    fn remaining_bytes(&self) -> ByteCount {
+        self.0.remaining_bytes()
+    }
+    fn try_fork(
+        &mut self,
+        n_children: ChildrenCount,
+        n_bytes: BytesPerChild,
+    ) -> Result<Self::ChildrenIter, ForkError> {
+        self.0
+            .try_fork(n_children, n_bytes)
+            .map(ChaChaChildrenIterator)
+    }
+    fn seek(&mut self, index: TableIndex) -> Result<(), SeekError> {
+        self.0.seek(index)
+    }
+    fn jump_to_byte(&mut self, byte: ByteCount) -> Result<(), SeekError> {
+        self.0.jump_to_byte(byte)
+    }
+    fn get_state(&self) -> GeneratorState {
+        self.0.get_state()
+    }
+    fn try_from_state(state: GeneratorState) -> Result<Self, StateError> {
+        AesCtrGenerator::try_from_state(state).map(ChaChaRandomGenerator)
+    }
+    fn derive_seed(master: Seed, label: &[u8]) -> Seed {
+        AesCtrGenerator::<ChaChaBlockCipher>::derive_seed(master, label)
+    }
+}
+
+impl Iterator for ChaChaRandomGenerator {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generators::aes_ctr::aes_ctr_generic_test;
+    use crate::generators::generator_generic_test;
+
+    #[test]
+    fn prop_fork_first_state_table_index() {
+        aes_ctr_generic_test::prop_fork_first_state_table_index::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_last_bound_table_index() {
+        aes_ctr_generic_test::prop_fork_last_bound_table_index::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_bound_table_index() {
+        aes_ctr_generic_test::prop_fork_parent_bound_table_index::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_state_table_index() {
+        aes_ctr_generic_test::prop_fork_parent_state_table_index::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork() {
+        aes_ctr_generic_test::prop_fork::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_children_remaining_bytes() {
+        aes_ctr_generic_test::prop_fork_children_remaining_bytes::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_remaining_bytes() {
+        aes_ctr_generic_test::prop_fork_parent_remaining_bytes::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_seek() {
+        aes_ctr_generic_test::prop_seek::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_jump_to_byte() {
+        aes_ctr_generic_test::prop_jump_to_byte::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_seek_out_of_bounds() {
+        aes_ctr_generic_test::prop_seek_out_of_bounds::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_seek() {
+        aes_ctr_generic_test::prop_fork_parent_seek::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_state_round_trip() {
+        aes_ctr_generic_test::prop_state_round_trip::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_invalid_state() {
+        aes_ctr_generic_test::prop_invalid_state::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_derive_seed() {
+        aes_ctr_generic_test::prop_derive_seed::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn test_roughly_uniform() {
+        generator_generic_test::test_roughly_uniform::<ChaChaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_fork() {
+        generator_generic_test::test_fork_children::<ChaChaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_generator_determinism() {
+        generator_generic_test::test_generator_determinism::<ChaChaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_jump_to_byte() {
+        generator_generic_test::test_jump_to_byte::<ChaChaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_state_round_trip() {
+        generator_generic_test::test_state_round_trip::<ChaChaRandomGenerator>();
+    }
+
+    #[test]
+    #[should_panic(expected = "expected test panic")]
+    fn test_bounded_panic() {
+        generator_generic_test::test_bounded_none_should_panic::<ChaChaRandomGenerator>();
+    }
+}
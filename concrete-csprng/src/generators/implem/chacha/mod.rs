@@ -0,0 +1,12 @@
+//! A module using a portable implementation of the ChaCha20 block function as random number
+//! generator.
+
+mod block_cipher;
+
+mod generator;
+pub use generator::*;
+
+#[cfg(feature = "parallel")]
+mod parallel;
+#[cfg(feature = "parallel")]
+pub use parallel::*;
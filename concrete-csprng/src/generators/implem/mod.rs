@@ -1,3 +1,8 @@
+#[cfg(feature = "generator_chacha")]
+mod chacha;
+#[cfg(feature = "generator_chacha")]
+pub use chacha::*;
+
 #[cfg(feature = "generator_x86_64_aesni")]
 mod aesni;
 #[cfg(feature = "generator_x86_64_aesni")]
@@ -7,16 +7,22 @@ use aes_soft::Aes128;
 
 #[derive(Clone)]
 pub struct SoftwareBlockCipher {
+    // The key the aes structure was instantiated from
+    key: AesKey,
     // Aes structure
     aes: Aes128,
 }
 
 impl AesBlockCipher for SoftwareBlockCipher {
     fn new(key: AesKey) -> SoftwareBlockCipher {
-        let key: [u8; BYTES_PER_AES_CALL] = key.0.to_ne_bytes();
-        let key = GenericArray::clone_from_slice(&key[..]);
-        let aes = Aes128::new(&key);
-        SoftwareBlockCipher { aes }
+        let key_bytes: [u8; BYTES_PER_AES_CALL] = key.0.to_ne_bytes();
+        let key_bytes = GenericArray::clone_from_slice(&key_bytes[..]);
+        let aes = Aes128::new(&key_bytes);
+        SoftwareBlockCipher { key, aes }
+    }
+
+    fn key(&self) -> AesKey {
+        self.key
     }
 
     fn generate_batch(&mut self, AesIndex(aes_ctr): AesIndex) -> [u8; BYTES_PER_BATCH] {
@@ -1,6 +1,9 @@
 use crate::generators::aes_ctr::{AesCtrGenerator, AesKey, ChildrenIterator};
 use crate::generators::implem::soft::block_cipher::SoftwareBlockCipher;
-use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError, RandomGenerator};
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, RandomGenerator, SeekError,
+    StateError, TableIndex,
+};
 use crate::seeders::Seed;
 
 /// A random number generator using a software implementation.
@@ -36,6 +39,21 @@ impl RandomGenerator for SoftwareRandomGenerator {
             .try_fork(n_children, n_bytes)
             .map(SoftwareChildrenIterator)
     }
+    fn seek(&mut self, index: TableIndex) -> Result<(), SeekError> {
+        self.0.seek(index)
+    }
+    fn jump_to_byte(&mut self, byte: ByteCount) -> Result<(), SeekError> {
+        self.0.jump_to_byte(byte)
+    }
+    fn get_state(&self) -> GeneratorState {
+        self.0.get_state()
+    }
+    fn try_from_state(state: GeneratorState) -> Result<Self, StateError> {
+        AesCtrGenerator::try_from_state(state).map(SoftwareRandomGenerator)
+    }
+    fn derive_seed(master: Seed, label: &[u8]) -> Seed {
+        AesCtrGenerator::<SoftwareBlockCipher>::derive_seed(master, label)
+    }
 }
 
 impl Iterator for SoftwareRandomGenerator {
@@ -87,6 +105,41 @@ mod test {
         aes_ctr_generic_test::prop_fork_parent_remaining_bytes::<SoftwareBlockCipher>();
     }
 
+    #[test]
+    fn prop_seek() {
+        aes_ctr_generic_test::prop_seek::<SoftwareBlockCipher>();
+    }
+
+    #[test]
+    fn prop_jump_to_byte() {
+        aes_ctr_generic_test::prop_jump_to_byte::<SoftwareBlockCipher>();
+    }
+
+    #[test]
+    fn prop_seek_out_of_bounds() {
+        aes_ctr_generic_test::prop_seek_out_of_bounds::<SoftwareBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_seek() {
+        aes_ctr_generic_test::prop_fork_parent_seek::<SoftwareBlockCipher>();
+    }
+
+    #[test]
+    fn prop_state_round_trip() {
+        aes_ctr_generic_test::prop_state_round_trip::<SoftwareBlockCipher>();
+    }
+
+    #[test]
+    fn prop_invalid_state() {
+        aes_ctr_generic_test::prop_invalid_state::<SoftwareBlockCipher>();
+    }
+
+    #[test]
+    fn prop_derive_seed() {
+        aes_ctr_generic_test::prop_derive_seed::<SoftwareBlockCipher>();
+    }
+
     #[test]
     fn test_roughly_uniform() {
         generator_generic_test::test_roughly_uniform::<SoftwareRandomGenerator>();
@@ -102,6 +155,16 @@ mod test {
         generator_generic_test::test_generator_determinism::<SoftwareRandomGenerator>();
     }
 
+    #[test]
+    fn test_jump_to_byte() {
+        generator_generic_test::test_jump_to_byte::<SoftwareRandomGenerator>();
+    }
+
+    #[test]
+    fn test_state_round_trip() {
+        generator_generic_test::test_state_round_trip::<SoftwareRandomGenerator>();
+    }
+
     #[test]
     #[should_panic(expected = "expected test panic")]
     fn test_bounded_panic() {
@@ -51,6 +51,66 @@ impl Display for ForkError {
 }
 impl Error for ForkError {}
 
+/// An error occuring during a generator seek.
+#[derive(Debug)]
+pub enum SeekError {
+    BeforeStart,
+    AfterBound,
+}
+
+impl Display for SeekError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SeekError::BeforeStart => {
+                write!(
+                    f,
+                    "The generator can not seek before the first byte it is allowed to output."
+                )
+            }
+            SeekError::AfterBound => {
+                write!(f, "The generator can not seek after its bound.")
+            }
+        }
+    }
+}
+impl Error for SeekError {}
+
+/// The state of a generator, from which the generator can be restored.
+///
+/// A generator restored from a state has the same bounds as the generator the state was taken
+/// from, and outputs the same bytes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GeneratorState {
+    /// The seed the generator was created from.
+    pub seed: Seed,
+    /// The first table index the generator is allowed to output.
+    pub first: TableIndex,
+    /// The table index of the next byte to be outputted by the generator.
+    pub next: TableIndex,
+    /// The table index of the first byte the generator is not allowed to output.
+    pub bound: TableIndex,
+}
+
+/// An error occuring when a generator is restored from a state.
+#[derive(Debug)]
+pub enum StateError {
+    UnorderedIndices,
+}
+
+impl Display for StateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StateError::UnorderedIndices => {
+                write!(
+                    f,
+                    "The table indices of the state are not ordered as first <= next <= bound."
+                )
+            }
+        }
+    }
+}
+impl Error for StateError {}
+
 /// A trait for cryptographically secure pseudo-random generators.
 ///
 /// See the [crate-level](#crate) documentation for details.
@@ -95,6 +155,65 @@ pub trait RandomGenerator: Iterator<Item = u8> {
         n_children: ChildrenCount,
         n_bytes: BytesPerChild,
     ) -> Result<Self::ChildrenIter, ForkError>;
+
+    /// Moves the generator so that the next outputted byte is the one at `index` in the table.
+    ///
+    /// Note:
+    /// -----
+    ///
+    /// The generator can only seek inside the region it is allowed to output. This region starts
+    /// at the first index of the generator (or after its children, if it was forked), and stops
+    /// at its bound. Seeking to the bound is allowed, and leaves the generator with no remaining
+    /// bytes.
+    fn seek(&mut self, index: TableIndex) -> Result<(), SeekError>;
+
+    /// Moves the generator so that the next outputted byte is the one located `byte` bytes after
+    /// the first byte it is allowed to output.
+    ///
+    /// Note:
+    /// -----
+    ///
+    /// For a generator created via [`new`](RandomGenerator::new), `jump_to_byte(ByteCount(n))`
+    /// makes the next call to [`next_byte`](RandomGenerator::next_byte) return the `n`-th byte of
+    /// the stream, as if the generator had output `n` bytes before. For a forked generator, the
+    /// byte count is taken from its first index. For a parent generator, it is taken from the
+    /// index following the bytes given to its children.
+    fn jump_to_byte(&mut self, byte: ByteCount) -> Result<(), SeekError>;
+
+    /// Returns the state of the generator.
+    ///
+    /// Note:
+    /// -----
+    ///
+    /// The state contains the seed of the generator. It must be kept as secret as the seed itself.
+    fn get_state(&self) -> GeneratorState;
+
+    /// Tries to restore a generator from a state returned by
+    /// [`get_state`](RandomGenerator::get_state).
+    ///
+    /// Note:
+    /// -----
+    ///
+    /// Like [`new`](RandomGenerator::new), this operation is usually costly to perform.
+    fn try_from_state(state: GeneratorState) -> Result<Self, StateError>
+    where
+        Self: Sized;
+
+    /// Derives a new seed from a master seed and a label.
+    ///
+    /// The derived seed is a pseudo-random function of the label, keyed by the master seed: seeds
+    /// derived from distinct labels are unrelated, and knowing some derived seeds does not reveal
+    /// the master seed or the other derived seeds. Since a derived seed can be used as the master
+    /// seed of further derivations, labels can be organized hierarchically, for instance as
+    /// paths like `"bsk/level/3"`.
+    ///
+    /// Note:
+    /// -----
+    ///
+    /// Seeds derived by distinct generator implementations differ.
+    fn derive_seed(master: Seed, label: &[u8]) -> Seed
+    where
+        Self: Sized;
 }
 
 /// A trait extending [`RandomGenerator`] to the parallel iterators of `rayon`.
@@ -119,6 +238,7 @@ pub trait ParallelRandomGenerator: RandomGenerator + Send {
 }
 
 mod aes_ctr;
+pub use aes_ctr::{AesIndex, ByteIndex, TableIndex};
 
 mod implem;
 pub use implem::*;
@@ -191,6 +311,51 @@ pub mod generator_generic_test {
         }
     }
 
+    /// Checks that jumping to a byte of a fresh generator yields the same bytes as generating the
+    /// skipped bytes first.
+    pub fn test_jump_to_byte<G: RandomGenerator>() {
+        for _ in 0..REPEATS {
+            let seed = any_seed().next().unwrap();
+            let skipped = rand::thread_rng().gen::<usize>() % 4096;
+            let mut first_generator = G::new(seed);
+            let mut second_generator = G::new(seed);
+            first_generator.by_ref().take(skipped).for_each(drop);
+            second_generator
+                .jump_to_byte(ByteCount(skipped as u128))
+                .unwrap();
+            for _ in 0..1024 {
+                assert_eq!(first_generator.next(), second_generator.next());
+            }
+        }
+    }
+
+    /// Checks that a generator restored from the state of another one, possibly forked, outputs
+    /// the same bytes.
+    pub fn test_state_round_trip<G: RandomGenerator>() {
+        for _ in 0..REPEATS {
+            let ((seed, n_children), n_bytes) = any_seed()
+                .zip(some_children_count())
+                .zip(some_bytes_per_child())
+                .next()
+                .unwrap();
+            let mut parent = G::new(seed);
+            parent.by_ref().take(n_bytes.0).for_each(drop);
+            let mut child = parent
+                .try_fork(n_children, n_bytes)
+                .unwrap()
+                .next()
+                .unwrap();
+            child.next();
+            for generator in [parent, child] {
+                let state = generator.get_state();
+                let restored = G::try_from_state(state).unwrap();
+                assert_eq!(restored.get_state(), state);
+                assert_eq!(restored.remaining_bytes(), generator.remaining_bytes());
+                assert!(generator.zip(restored).take(1024).all(|(a, b)| a == b));
+            }
+        }
+    }
+
     /// Checks that forks returns a bounded child, and that the proper number of bytes can be
     /// generated.
     pub fn test_fork_children<G: RandomGenerator>() {
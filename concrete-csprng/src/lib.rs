@@ -110,5 +110,9 @@
 //!
 //! The implementation is based on the AES blockcipher used in counter (CTR) mode, as presented
 //! in the ISO/IEC 18033-4 document.
+//!
+//! The `generator_chacha` feature provides an alternative implementation, which uses the ChaCha20
+//! block function in place of the AES blockcipher. It does not rely on any platform-specific
+//! instruction, and is faster than the software AES implementation.
 pub mod generators;
 pub mod seeders;
@@ -1,20 +1,368 @@
-//! This program uses the concrete csprng to generate an infinite stream of random bytes on
-//! the program stdout. For testing purpose.
-use concrete_csprng::generators::{AesniRandomGenerator, RandomGenerator};
-use concrete_csprng::seeders::{RdseedSeeder, Seeder};
+//! This program uses the concrete csprng to generate a stream of random bytes on the program
+//! stdout, or to run quick statistical checks on this stream. For testing purpose.
+//!
+//! The generator can be seeded with a fixed seed for reproducibility, and can be forked into
+//! children whose outputs are interleaved, to test the independence of the children. Run the
+//! program with `--help` to get the list of the available options.
+use clap::{value_parser, Arg, Command};
+#[cfg(feature = "generator_x86_64_aesni")]
+use concrete_csprng::generators::AesniRandomGenerator;
+#[cfg(feature = "generator_chacha")]
+use concrete_csprng::generators::ChaChaRandomGenerator;
+use concrete_csprng::generators::{
+    BytesPerChild, ChildrenCount, RandomGenerator, SoftwareRandomGenerator,
+};
+use concrete_csprng::seeders::{GetrandomSeeder, Seed, Seeder};
 use std::io::prelude::*;
-use std::io::stdout;
+use std::io::{stdout, BufWriter, ErrorKind};
+use std::process::exit;
+
+/// The generators which can be selected.
+const GENERATORS: &[&str] = &[
+    "soft",
+    #[cfg(feature = "generator_x86_64_aesni")]
+    "aesni",
+    #[cfg(feature = "generator_chacha")]
+    "chacha",
+];
+
+/// The number of bytes the statistical checks are run on, if not given.
+const DEFAULT_CHECKED_BYTES: usize = 1 << 20;
+
+/// The significance level of the statistical checks, as recommended in NIST SP 800-22.
+const SIGNIFICANCE_LEVEL: f64 = 0.01;
+
+/// The number of bytes printed per line in hexadecimal format.
+const HEX_BYTES_PER_LINE: usize = 32;
+
+#[derive(Clone, Copy)]
+enum Format {
+    Raw,
+    Hex,
+}
+
+struct Config {
+    seed: Seed,
+    bytes: Option<usize>,
+    fork: Option<(ChildrenCount, BytesPerChild)>,
+    format: Format,
+    check: bool,
+}
 
 pub fn main() {
-    let mut seeder = RdseedSeeder;
-    let mut generator = AesniRandomGenerator::new(seeder.seed());
-    let mut stdout = stdout();
-    let mut buffer = [0u8; 16];
-    loop {
-        buffer
-            .iter_mut()
-            .zip(&mut generator)
-            .for_each(|(b, g)| *b = g);
-        stdout.write_all(&buffer).unwrap();
+    let matches = Command::new("generate")
+        .about("Generates random bytes with the concrete csprng, for testing purpose")
+        .arg(
+            Arg::new("generator")
+                .long("generator")
+                .takes_value(true)
+                .possible_values(GENERATORS.iter().copied())
+                .default_value("soft")
+                .help("The generator used to produce the bytes"),
+        )
+        .arg(
+            Arg::new("seed")
+                .long("seed")
+                .takes_value(true)
+                .value_parser(parse_seed)
+                .help(
+                    "A fixed 128 bits seed, in decimal or in hexadecimal with a `0x` prefix. If \
+                    not given, the seed is drawn with the `getrandom` system call",
+                ),
+        )
+        .arg(
+            Arg::new("bytes")
+                .long("bytes")
+                .takes_value(true)
+                .value_parser(value_parser!(usize))
+                .help("The number of bytes to generate. If not given, the stream is infinite"),
+        )
+        .arg(
+            Arg::new("children")
+                .long("children")
+                .takes_value(true)
+                .value_parser(value_parser!(usize))
+                .requires("bytes-per-child")
+                .help(
+                    "Repeatedly forks the generator into this number of children, and \
+                    interleaves their outputs byte per byte",
+                ),
+        )
+        .arg(
+            Arg::new("bytes-per-child")
+                .long("bytes-per-child")
+                .takes_value(true)
+                .value_parser(value_parser!(usize))
+                .requires("children")
+                .help("The number of bytes generated by each child before forking again"),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .takes_value(true)
+                .possible_values(["raw", "hex"])
+                .default_value("raw")
+                .help("The format of the output"),
+        )
+        .arg(Arg::new("check").long("check").help(
+            "Runs the monobit, runs and chi-square over bytes checks on the generated bytes, \
+            instead of printing them. The checks are run on 1MiB if the number of bytes is not \
+            given",
+        ))
+        .get_matches();
+
+    let config = Config {
+        seed: matches
+            .get_one::<u128>("seed")
+            .map(|seed| Seed(*seed))
+            .unwrap_or_else(|| GetrandomSeeder.seed()),
+        bytes: matches.get_one::<usize>("bytes").copied(),
+        fork: matches
+            .get_one::<usize>("children")
+            .zip(matches.get_one::<usize>("bytes-per-child"))
+            .map(|(n_children, n_bytes)| (ChildrenCount(*n_children), BytesPerChild(*n_bytes))),
+        format: match matches.value_of("format").unwrap() {
+            "hex" => Format::Hex,
+            _ => Format::Raw,
+        },
+        check: matches.contains_id("check"),
+    };
+
+    let success = match matches.value_of("generator").unwrap() {
+        #[cfg(feature = "generator_x86_64_aesni")]
+        "aesni" => run::<AesniRandomGenerator>(&config),
+        #[cfg(feature = "generator_chacha")]
+        "chacha" => run::<ChaChaRandomGenerator>(&config),
+        _ => run::<SoftwareRandomGenerator>(&config),
+    };
+    if !success {
+        exit(1);
+    }
+}
+
+fn parse_seed(seed: &str) -> Result<u128, String> {
+    match seed.strip_prefix("0x") {
+        Some(hex) => u128::from_str_radix(hex, 16),
+        None => seed.parse(),
+    }
+    .map_err(|error| error.to_string())
+}
+
+// Generates the bytes, and either prints them or checks them. Returns whether the checks passed.
+fn run<G: RandomGenerator + 'static>(config: &Config) -> bool {
+    let stream = byte_stream(G::new(config.seed), config.fork);
+    if config.check {
+        let bytes: Vec<u8> = stream
+            .take(config.bytes.unwrap_or(DEFAULT_CHECKED_BYTES))
+            .collect();
+        check(&bytes)
+    } else {
+        let stream: Box<dyn Iterator<Item = u8>> = match config.bytes {
+            Some(bytes) => Box::new(stream.take(bytes)),
+            None => stream,
+        };
+        match write_stream(stream, config.format) {
+            Ok(()) => true,
+            // The reader of the output may stop reading an infinite stream at any point.
+            Err(error) if error.kind() == ErrorKind::BrokenPipe => true,
+            Err(error) => panic!("Failed to write to stdout: {}", error),
+        }
+    }
+}
+
+// Returns the stream of bytes of the generator. If a fork is given, the generator is repeatedly
+// forked into children, and the outputs of the children are interleaved byte per byte.
+fn byte_stream<G: RandomGenerator + 'static>(
+    mut generator: G,
+    fork: Option<(ChildrenCount, BytesPerChild)>,
+) -> Box<dyn Iterator<Item = u8>> {
+    let (n_children, n_bytes) = match fork {
+        Some(fork) => fork,
+        None => return Box::new(generator),
+    };
+    let rounds = std::iter::repeat_with(move || {
+        let mut children: Vec<G> = generator
+            .try_fork(n_children, n_bytes)
+            .expect("Failed to fork the generator.")
+            .collect();
+        let mut round = Vec::with_capacity(n_children.0 * n_bytes.0);
+        for _ in 0..n_bytes.0 {
+            round.extend(children.iter_mut().map(|child| child.next().unwrap()));
+        }
+        round
+    });
+    Box::new(rounds.flatten())
+}
+
+fn write_stream(stream: impl Iterator<Item = u8>, format: Format) -> std::io::Result<()> {
+    let mut stdout = BufWriter::new(stdout());
+    match format {
+        Format::Raw => {
+            for byte in stream {
+                stdout.write_all(&[byte])?;
+            }
+        }
+        Format::Hex => {
+            let mut stream = stream.peekable();
+            while stream.peek().is_some() {
+                for byte in stream.by_ref().take(HEX_BYTES_PER_LINE) {
+                    write!(stdout, "{:02x}", byte)?;
+                }
+                writeln!(stdout)?;
+            }
+        }
+    }
+    stdout.flush()
+}
+
+// Runs the statistical checks on the bytes, prints their results, and returns whether they all
+// passed.
+fn check(bytes: &[u8]) -> bool {
+    let bits = bits(bytes);
+    let checks = [
+        ("monobit", monobit_p_value(&bits)),
+        ("runs", runs_p_value(&bits)),
+        ("chi-square", chi_square_p_value(bytes)),
+    ];
+    println!("Checked {} bytes:", bytes.len());
+    for (name, p_value) in checks.iter() {
+        let result = if *p_value >= SIGNIFICANCE_LEVEL {
+            "PASS"
+        } else {
+            "FAIL"
+        };
+        println!("{:<12}p-value = {:.6}    {}", name, p_value, result);
+    }
+    checks
+        .iter()
+        .all(|(_, p_value)| *p_value >= SIGNIFICANCE_LEVEL)
+}
+
+// Returns the bits of the bytes, most significant bit first.
+fn bits(bytes: &[u8]) -> Vec<bool> {
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+        .collect()
+}
+
+// The frequency (monobit) test of NIST SP 800-22, section 2.1.
+fn monobit_p_value(bits: &[bool]) -> f64 {
+    let n = bits.len() as f64;
+    let sum: i64 = bits.iter().map(|bit| if *bit { 1 } else { -1 }).sum();
+    let statistic = (sum.abs() as f64) / n.sqrt();
+    erfc(statistic / 2f64.sqrt())
+}
+
+// The runs test of NIST SP 800-22, section 2.3.
+fn runs_p_value(bits: &[bool]) -> f64 {
+    let n = bits.len() as f64;
+    let proportion = bits.iter().filter(|bit| **bit).count() as f64 / n;
+    // The runs test is only meaningful if the frequency prerequisite passes.
+    if (proportion - 0.5).abs() >= 2. / n.sqrt() {
+        return 0.;
+    }
+    let runs = 1 + bits.windows(2).filter(|pair| pair[0] != pair[1]).count();
+    let variance = proportion * (1. - proportion);
+    erfc((runs as f64 - 2. * n * variance).abs() / (2. * (2. * n).sqrt() * variance))
+}
+
+// A chi-square goodness of fit test of the bytes against the uniform distribution.
+fn chi_square_p_value(bytes: &[u8]) -> f64 {
+    let mut counts = [0usize; 256];
+    bytes.iter().for_each(|byte| counts[*byte as usize] += 1);
+    let expected = bytes.len() as f64 / 256.;
+    let statistic: f64 = counts
+        .iter()
+        .map(|count| (*count as f64 - expected).powi(2) / expected)
+        .sum();
+    // The upper tail of the chi-square distribution with 255 degrees of freedom, using the
+    // Wilson-Hilferty approximation by a normal distribution.
+    let k = 255.;
+    let z = ((statistic / k).cbrt() - (1. - 2. / (9. * k))) / (2. / (9. * k)).sqrt();
+    erfc(z / 2f64.sqrt()) / 2.
+}
+
+// The complementary error function, using the approximation 7.1.26 of Abramowitz and Stegun,
+// whose absolute error is lower than 1.5e-7.
+fn erfc(x: f64) -> f64 {
+    if x < 0. {
+        return 2. - erfc(-x);
+    }
+    let t = 1. / (1. + 0.3275911 * x);
+    let polynomial = t
+        * (0.254829592
+            + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    polynomial * (-x * x).exp()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // The sequence of the examples of NIST SP 800-22, sections 2.1.8 and 2.3.8.
+    const REFERENCE_SEQUENCE: &str = "11001001000011111101101010100010001000010110100011\
+                                      00001000110100110001001100011001100010100010111000";
+
+    fn reference_bits() -> Vec<bool> {
+        REFERENCE_SEQUENCE
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c == '1')
+            .collect()
+    }
+
+    #[test]
+    fn test_erfc() {
+        for (x, expected) in [
+            (0., 1.),
+            (0.5, 0.4795001222),
+            (1., 0.1572992070),
+            (2., 0.0046777350),
+        ] {
+            assert!((erfc(x) - expected).abs() < 2e-7);
+            assert!((erfc(-x) - (2. - expected)).abs() < 2e-7);
+        }
+    }
+
+    #[test]
+    fn test_monobit_reference() {
+        let bits = reference_bits();
+        assert_eq!(bits.len(), 100);
+        assert!((monobit_p_value(&bits) - 0.109599).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_runs_reference() {
+        assert!((runs_p_value(&reference_bits()) - 0.500798).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_checks() {
+        let bytes: Vec<u8> = SoftwareRandomGenerator::new(Seed(0))
+            .take(DEFAULT_CHECKED_BYTES)
+            .collect();
+        assert!(check(&bytes));
+        assert!(!check(&[0xff; 4096]));
+        assert!(!check(&[0xaa; 4096]));
+    }
+
+    #[test]
+    fn test_fork_stream() {
+        let fork = Some((ChildrenCount(3), BytesPerChild(5)));
+        let stream: Vec<u8> = byte_stream(SoftwareRandomGenerator::new(Seed(0)), fork)
+            .take(30)
+            .collect();
+        let mut generator = SoftwareRandomGenerator::new(Seed(0));
+        for round in stream.chunks(15) {
+            let children: Vec<Vec<u8>> = generator
+                .try_fork(ChildrenCount(3), BytesPerChild(5))
+                .unwrap()
+                .map(|child| child.collect())
+                .collect();
+            for (i, byte) in round.iter().enumerate() {
+                assert_eq!(*byte, children[i % 3][i / 3]);
+            }
+        }
     }
 }
@@ -0,0 +1,292 @@
+use crate::seeders::{Seed, Seeder, SeederError};
+
+/// The probability for a test to fail on a healthy source, as recommended by NIST SP 800-90B.
+const FALSE_POSITIVE_PROBABILITY: f64 = 1. / (1u64 << 20) as f64;
+
+/// The size of the window of the adaptive proportion test, for sources of non-binary samples.
+const ADAPTIVE_PROPORTION_WINDOW_SIZE: usize = 512;
+
+/// The number of samples the tests are run on when the seeder is created.
+const STARTUP_SAMPLES_COUNT: usize = 1024;
+
+/// The number of samples contained in a seed.
+const SAMPLES_PER_SEED: usize = 16;
+
+/// A seeder checking the health of the entropy source of another seeder.
+///
+/// Every byte of the seeds generated by the wrapped seeder is considered as a sample of its
+/// entropy source, and goes through the continuous health tests of NIST SP 800-90B:
+///
+/// + The repetition count test, which detects a source stuck on a single value.
+/// + The adaptive proportion test, which detects a source producing a value too often.
+///
+/// The cutoffs of both tests are computed from the min-entropy per byte claimed for the source,
+/// and are set so that a healthy source fails a test with a probability of 2^-20 per sample.
+///
+/// Note:
+/// -----
+///
+/// The tests are first run on 1024 samples when the seeder is created. Then, the seeder fails
+/// closed: once a test failed, [`try_seed`](Seeder::try_seed) returns an error on every call, and
+/// [`seed`](Seeder::seed) panics. Callers which must recover from an unhealthy source should use
+/// [`try_seed`](Seeder::try_seed).
+pub struct HealthCheckedSeeder<S: Seeder> {
+    seeder: S,
+    repetition_count_test: RepetitionCountTest,
+    adaptive_proportion_test: AdaptiveProportionTest,
+    failure: Option<SeederError>,
+}
+
+impl<S: Seeder> HealthCheckedSeeder<S> {
+    /// Creates a new health checked seeder, and runs the startup tests on the wrapped seeder.
+    ///
+    /// The `min_entropy` is the min-entropy per byte claimed for the entropy source of the wrapped
+    /// seeder, in bits. An [`InvalidMinEntropy`](SeederError::InvalidMinEntropy) error is returned
+    /// if it does not lie in `]0, 8]`.
+    pub fn new(seeder: S, min_entropy: f64) -> Result<HealthCheckedSeeder<S>, SeederError> {
+        if !(min_entropy > 0. && min_entropy <= 8.) {
+            return Err(SeederError::InvalidMinEntropy);
+        }
+        let mut health_checked_seeder = HealthCheckedSeeder {
+            seeder,
+            repetition_count_test: RepetitionCountTest::new(min_entropy),
+            adaptive_proportion_test: AdaptiveProportionTest::new(min_entropy),
+            failure: None,
+        };
+        for _ in 0..STARTUP_SAMPLES_COUNT / SAMPLES_PER_SEED {
+            health_checked_seeder.try_seed()?;
+        }
+        Ok(health_checked_seeder)
+    }
+
+    fn test_seed(&mut self) -> Result<Seed, SeederError> {
+        let seed = self.seeder.try_seed()?;
+        for sample in seed.0.to_le_bytes() {
+            self.repetition_count_test.test(sample)?;
+            self.adaptive_proportion_test.test(sample)?;
+        }
+        Ok(seed)
+    }
+}
+
+impl<S: Seeder> Seeder for HealthCheckedSeeder<S> {
+    /// Generates a new seed.
+    ///
+    /// Panics if the entropy source failed a health test, now or on a previous call. Use
+    /// [`try_seed`](Seeder::try_seed) to get an error instead.
+    fn seed(&mut self) -> Seed {
+        self.try_seed()
+            .expect("The entropy source of the seeder is not healthy.")
+    }
+
+    fn try_seed(&mut self) -> Result<Seed, SeederError> {
+        if let Some(failure) = self.failure {
+            return Err(failure);
+        }
+        let output = self.test_seed();
+        self.failure = output.err();
+        output
+    }
+
+    fn is_available() -> bool {
+        S::is_available()
+    }
+}
+
+// The repetition count test of NIST SP 800-90B, section 4.4.1.
+struct RepetitionCountTest {
+    cutoff: usize,
+    last_sample: Option<u8>,
+    repetitions: usize,
+}
+
+impl RepetitionCountTest {
+    fn new(min_entropy: f64) -> RepetitionCountTest {
+        let cutoff = 1 + (-FALSE_POSITIVE_PROBABILITY.log2() / min_entropy).ceil() as usize;
+        RepetitionCountTest {
+            cutoff,
+            last_sample: None,
+            repetitions: 0,
+        }
+    }
+
+    fn test(&mut self, sample: u8) -> Result<(), SeederError> {
+        if self.last_sample == Some(sample) {
+            self.repetitions += 1;
+            if self.repetitions >= self.cutoff {
+                return Err(SeederError::RepetitionCountTestFailure);
+            }
+        } else {
+            self.last_sample = Some(sample);
+            self.repetitions = 1;
+        }
+        Ok(())
+    }
+}
+
+// The adaptive proportion test of NIST SP 800-90B, section 4.4.2.
+struct AdaptiveProportionTest {
+    cutoff: usize,
+    reference_sample: u8,
+    occurences: usize,
+    position: usize,
+}
+
+impl AdaptiveProportionTest {
+    fn new(min_entropy: f64) -> AdaptiveProportionTest {
+        let cutoff = 1 + binomial_quantile(
+            ADAPTIVE_PROPORTION_WINDOW_SIZE,
+            (-min_entropy).exp2(),
+            1. - FALSE_POSITIVE_PROBABILITY,
+        );
+        AdaptiveProportionTest {
+            cutoff,
+            reference_sample: 0,
+            occurences: 0,
+            position: 0,
+        }
+    }
+
+    fn test(&mut self, sample: u8) -> Result<(), SeederError> {
+        if self.position == 0 {
+            self.reference_sample = sample;
+            self.occurences = 1;
+        } else if sample == self.reference_sample {
+            self.occurences += 1;
+            if self.occurences >= self.cutoff {
+                return Err(SeederError::AdaptiveProportionTestFailure);
+            }
+        }
+        self.position = (self.position + 1) % ADAPTIVE_PROPORTION_WINDOW_SIZE;
+        Ok(())
+    }
+}
+
+// Returns the smallest `k` such that `P(X <= k) >= probability`, where `X` follows the binomial
+// distribution of `n` trials with success probability `p`.
+//
+// The probability masses are computed in log space, since the first ones underflow when `p` is
+// close to one.
+fn binomial_quantile(n: usize, p: f64, probability: f64) -> usize {
+    let log_odds = p.ln() - (-p).ln_1p();
+    let mut log_mass = n as f64 * (-p).ln_1p();
+    let mut cumulated = log_mass.exp();
+    let mut k = 0;
+    while cumulated < probability && k < n {
+        log_mass += ((n - k) as f64 / (k + 1) as f64).ln() + log_odds;
+        cumulated += log_mass.exp();
+        k += 1;
+    }
+    k
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::{thread_rng, Rng};
+
+    // A seeder outputting the seeds of a sequence, then random seeds.
+    struct SequenceSeeder(std::vec::IntoIter<u128>);
+
+    impl Seeder for SequenceSeeder {
+        fn seed(&mut self) -> Seed {
+            Seed(self.0.next().unwrap_or_else(|| thread_rng().gen()))
+        }
+
+        fn is_available() -> bool {
+            true
+        }
+    }
+
+    fn random_seeds(n: usize) -> Vec<u128> {
+        (0..n).map(|_| thread_rng().gen()).collect()
+    }
+
+    #[test]
+    fn test_cutoffs() {
+        // The cutoffs given in NIST SP 800-90B for a false positive probability of 2^-20.
+        for (min_entropy, repetition_count_cutoff, adaptive_proportion_cutoff) in [
+            (0.5, 41, 410),
+            (1., 21, 311),
+            (2., 11, 177),
+            (4., 6, 62),
+            (8., 4, 13),
+        ] {
+            assert_eq!(
+                RepetitionCountTest::new(min_entropy).cutoff,
+                repetition_count_cutoff
+            );
+            assert_eq!(
+                AdaptiveProportionTest::new(min_entropy).cutoff,
+                adaptive_proportion_cutoff
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_min_entropy() {
+        for min_entropy in [0., -1., 8.5, f64::NAN] {
+            assert!(matches!(
+                HealthCheckedSeeder::new(SequenceSeeder(vec![].into_iter()), min_entropy),
+                Err(SeederError::InvalidMinEntropy)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_low_entropy_adaptive_proportion() {
+        // The first masses of the binomial distribution underflow for such min-entropies.
+        for min_entropy in [0.1, 0.2, 0.3, 0.4] {
+            let mut test = AdaptiveProportionTest::new(min_entropy);
+            assert!(test.cutoff <= ADAPTIVE_PROPORTION_WINDOW_SIZE);
+            let failure = (0..ADAPTIVE_PROPORTION_WINDOW_SIZE).find_map(|_| test.test(0).err());
+            assert_eq!(failure, Some(SeederError::AdaptiveProportionTestFailure));
+        }
+    }
+
+    #[test]
+    fn test_healthy_source() {
+        let mut seeder = HealthCheckedSeeder::new(SequenceSeeder(vec![].into_iter()), 4.).unwrap();
+        for _ in 0..10_000 {
+            assert!(seeder.try_seed().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_stuck_source_startup() {
+        let mut seeds = random_seeds(10);
+        seeds.push(0);
+        assert!(matches!(
+            HealthCheckedSeeder::new(SequenceSeeder(seeds.into_iter()), 4.),
+            Err(SeederError::RepetitionCountTestFailure)
+        ));
+    }
+
+    #[test]
+    fn test_biased_source_startup() {
+        // Every other byte is zero, which never triggers the repetition count test.
+        let seeds = (1..=u8::MAX as u128)
+            .map(|i| (0..8).fold(0, |seed, j| seed | ((i * 8 + j) % 255 + 1) << (16 * j + 8)))
+            .collect::<Vec<_>>();
+        assert!(matches!(
+            HealthCheckedSeeder::new(SequenceSeeder(seeds.into_iter()), 8.),
+            Err(SeederError::AdaptiveProportionTestFailure)
+        ));
+    }
+
+    #[test]
+    fn test_fail_closed() {
+        let mut seeds = random_seeds(STARTUP_SAMPLES_COUNT / SAMPLES_PER_SEED + 10);
+        seeds.push(u128::MAX);
+        let mut seeder = HealthCheckedSeeder::new(SequenceSeeder(seeds.into_iter()), 4.).unwrap();
+        for _ in 0..10 {
+            assert!(seeder.try_seed().is_ok());
+        }
+        for _ in 0..10 {
+            assert_eq!(
+                seeder.try_seed(),
+                Err(SeederError::RepetitionCountTestFailure)
+            );
+        }
+    }
+}
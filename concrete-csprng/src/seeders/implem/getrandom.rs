@@ -0,0 +1,34 @@
+use crate::seeders::{Seed, Seeder, SeederError};
+
+/// A seeder which uses the `getrandom` system call, or its equivalent on the target platform.
+///
+/// Contrarily to a seeder reading the `/dev/random` file, it does not need any file descriptor,
+/// and only blocks until the entropy pool of the operating system is initialized.
+pub struct GetrandomSeeder;
+
+impl Seeder for GetrandomSeeder {
+    fn seed(&mut self) -> Seed {
+        self.try_seed().expect("Failed to call getrandom.")
+    }
+
+    fn try_seed(&mut self) -> Result<Seed, SeederError> {
+        let mut buf = [0u8; 16];
+        getrandom::getrandom(&mut buf[..]).map_err(|_| SeederError::EntropySourceFailure)?;
+        Ok(Seed(u128::from_ne_bytes(buf)))
+    }
+
+    fn is_available() -> bool {
+        getrandom::getrandom(&mut [0u8; 1][..]).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::seeders::generic_tests::check_seeder_fixed_sequences_different;
+
+    #[test]
+    fn check_bounded_sequence_difference() {
+        check_seeder_fixed_sequences_different(|_| GetrandomSeeder);
+    }
+}
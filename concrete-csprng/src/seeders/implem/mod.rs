@@ -1,3 +1,8 @@
+#[cfg(feature = "seeder_getrandom")]
+mod getrandom;
+#[cfg(feature = "seeder_getrandom")]
+pub use self::getrandom::GetrandomSeeder;
+
 #[cfg(feature = "seeder_x86_64_rdseed")]
 mod rdseed;
 #[cfg(feature = "seeder_x86_64_rdseed")]
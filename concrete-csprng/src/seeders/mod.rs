@@ -5,15 +5,61 @@
 //! conditioned by the quality of this seed. This module proposes different mechanisms to deliver
 //! seeds that can accomodate varying scenarios.
 
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
 /// A seed value, used to initialize a generator.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Seed(pub u128);
 
+/// An error occuring when a seeder fails to generate a seed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SeederError {
+    EntropySourceFailure,
+    RepetitionCountTestFailure,
+    AdaptiveProportionTestFailure,
+    InvalidMinEntropy,
+}
+
+impl Display for SeederError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SeederError::EntropySourceFailure => {
+                write!(f, "The entropy source of the seeder could not be read.")
+            }
+            SeederError::RepetitionCountTestFailure => {
+                write!(
+                    f,
+                    "The entropy source of the seeder failed the repetition count test."
+                )
+            }
+            SeederError::AdaptiveProportionTestFailure => {
+                write!(
+                    f,
+                    "The entropy source of the seeder failed the adaptive proportion test."
+                )
+            }
+            SeederError::InvalidMinEntropy => {
+                write!(f, "The min-entropy per byte must lie in ]0, 8].")
+            }
+        }
+    }
+}
+impl Error for SeederError {}
+
 /// A trait representing a seeding strategy.
 pub trait Seeder {
     /// Generates a new seed.
     fn seed(&mut self) -> Seed;
 
+    /// Tries to generate a new seed.
+    ///
+    /// Contrarily to [`seed`](Seeder::seed), which panics when the seeder fails, this method
+    /// returns an error. The default implementation forwards to [`seed`](Seeder::seed).
+    fn try_seed(&mut self) -> Result<Seed, SeederError> {
+        Ok(self.seed())
+    }
+
     /// Check whether the seeder can be used on the current machine. This function may check if some
     /// required CPU features are available or if some OS features are availble for example.
     fn is_available() -> bool
@@ -21,6 +67,9 @@ pub trait Seeder {
         Self: Sized;
 }
 
+mod health_checked;
+pub use health_checked::HealthCheckedSeeder;
+
 mod implem;
 pub use implem::*;
 
@@ -1,6 +1,10 @@
 use concrete_commons::dispersion::DispersionParameter;
+use std::f64::consts::{LOG2_E, PI, SQRT_2};
 use std::ops::Mul;
 
+/// The number of terms used to evaluate the series and continued fraction of [`log2_erfc`].
+const ERFC_TERMS: usize = 64;
+
 /// Computes the number of bits affected by the noise with a dispersion
 /// describing a normal distribution.
 pub fn estimate_number_of_noise_bits<D>(dispersion: D, log2_modulus: u32) -> usize
@@ -22,6 +26,51 @@ where
     }
 }
 
+/// Computes the logarithm in base 2 of the probability that a noise with a dispersion describing
+/// a centered normal distribution is larger than a bound, in absolute value.
+///
+/// The bound is expressed over the torus, as the dispersion. When decrypting a message encoded
+/// with a scaling factor $\Delta$, the decryption fails when the noise is larger than
+/// $\Delta / 2$.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::StandardDev;
+/// use concrete_npe::estimate_log2_noise_overflow_probability;
+/// // The noise exceeds 2^-6 with the probability of exceeding 4 standard deviations.
+/// let log2_probability =
+///     estimate_log2_noise_overflow_probability(StandardDev(2_f64.powi(-8)), 2_f64.powi(-6));
+/// assert!((log2_probability - (6.334e-5_f64).log2()).abs() < 0.01);
+/// ```
+pub fn estimate_log2_noise_overflow_probability<D>(dispersion: D, bound: f64) -> f64
+where
+    D: DispersionParameter,
+{
+    log2_erfc(bound / (dispersion.get_standard_dev() * SQRT_2))
+}
+
+/// Computes the logarithm in base 2 of the complementary error function, for a non-negative
+/// input.
+///
+/// Small inputs use the Taylor series of the error function, while large inputs use the
+/// continued fraction of the complementary error function, which does not underflow.
+fn log2_erfc(x: f64) -> f64 {
+    if x < 2. {
+        let mut term = x;
+        let mut erf = x;
+        for n in 1..ERFC_TERMS {
+            term *= -x * x / n as f64;
+            erf += term / (2 * n + 1) as f64;
+        }
+        (1. - 2. / PI.sqrt() * erf).log2()
+    } else {
+        let mut fraction = x;
+        for k in (1..=ERFC_TERMS).rev() {
+            fraction = x + (k as f64 / 2.) / fraction;
+        }
+        -x * x * LOG2_E - (PI.sqrt() * fraction).log2()
+    }
+}
+
 /// Computes the square of the input value.
 pub(super) fn square<T>(x: T) -> T
 where
@@ -45,4 +94,27 @@ pub mod tests {
         };
     }
     pub(crate) use assert_float_eq;
+
+    #[test]
+    fn erfc_is_continuous() {
+        let below = super::log2_erfc(2. - 1e-9);
+        let above = super::log2_erfc(2.);
+        assert_float_eq!(below, above, eps = 1e-6);
+    }
+
+    #[test]
+    fn erfc_known_values() {
+        // erfc(0) = 1, erfc(1) = 0.157299207..., erfc(5) = 1.537459794e-12
+        assert_float_eq!(super::log2_erfc(0.), 0., eps = 1e-12);
+        assert_float_eq!(
+            super::log2_erfc(1.),
+            0.157_299_207_050_285_f64.log2(),
+            eps = 1e-9
+        );
+        assert_float_eq!(
+            super::log2_erfc(5.),
+            1.537_459_794_428_035e-12_f64.log2(),
+            eps = 1e-9
+        );
+    }
 }
@@ -0,0 +1,46 @@
+#![deny(rustdoc::broken_intra_doc_links)]
+//! Welcome the the `concrete-optimizer` documentation!
+//!
+//! # Description
+//! This library makes it possible to choose the parameters of the `concrete` FHE library. Given a
+//! target security level and a message precision, it searches for the parameters of the
+//! bootstrapping and keyswitching atomic pattern with the smallest complexity, such that:
+//!
+//! + the LWE and GLWE secret keys reach the security level, according to a built-in estimation
+//!   of the cost of the primal uSVP and dual lattice attacks;
+//! + the probability that a bootstrap outputs a wrong message is below a threshold, according to
+//!   the noise formulas of the `concrete-npe` crate.
+//!
+//! The security estimation follows the approach of [On the concrete hardness of Learning with
+//! Errors](https://eprint.iacr.org/2015/046) by *Martin R. Albrecht, Rachel Player and Sam Scott*,
+//! and runs offline.
+//!
+//! # Quick Example
+//! The following piece of code shows how to find parameters for 2 bits messages, with 128 bits of
+//! security.
+//!
+//! # Example:
+//! ```rust
+//! use concrete_optimizer::{optimize_parameters, Constraints, SearchSpace};
+//! let constraints = Constraints::new(128, 2);
+//! let search_space = SearchSpace {
+//!     glwe_dimensions: 1..=1,
+//!     log2_polynomial_sizes: 10..=11,
+//!     lwe_dimensions: 600..=900,
+//!     lwe_dimension_step: 16,
+//!     ..SearchSpace::default()
+//! };
+//! let solution = optimize_parameters(&constraints, &search_space).unwrap();
+//! println!("{:?}", solution.parameters);
+//! assert!(solution.security_level >= 128.);
+//! ```
+
+mod noise;
+mod optimizer;
+mod parameters;
+mod security;
+
+pub use noise::*;
+pub use optimizer::*;
+pub use parameters::*;
+pub use security::*;
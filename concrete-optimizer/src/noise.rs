@@ -0,0 +1,138 @@
+//! Contains material needed to check the correctness of a parameter set, using the noise formulas
+//! of `concrete-npe`.
+use crate::Parameters;
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::parameters::LweDimension;
+use concrete_npe::{
+    estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms,
+    estimate_modulus_switching_noise_with_binary_key, estimate_pbs_noise,
+};
+use std::f64::consts::{LOG2_E, PI, SQRT_2};
+
+/// The number of terms used to evaluate the series and continued fraction of [`log2_erfc`].
+const ERFC_TERMS: usize = 64;
+
+/// Computes the dispersion of the noise at the input of the bootstrap of the atomic pattern.
+///
+/// The pattern is made of a bootstrap, a dot product of the bootstrapped ciphertexts with weights
+/// whose squared 2-norm is at most `norm2`, a keyswitch and the modulus switching performed at
+/// the beginning of the next bootstrap. The LWE and GLWE secret keys are binary.
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::{DispersionParameter, StandardDev};
+/// use concrete_commons::parameters::{
+///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+/// };
+/// use concrete_optimizer::{estimate_atomic_pattern_noise, Parameters};
+/// let parameters = Parameters {
+///     lwe_dimension: LweDimension(742),
+///     glwe_dimension: GlweDimension(1),
+///     polynomial_size: PolynomialSize(2048),
+///     lwe_std_dev: StandardDev(0.000007069849454709433),
+///     glwe_std_dev: StandardDev(0.00000000000000029403601535432533),
+///     pbs_base_log: DecompositionBaseLog(23),
+///     pbs_level: DecompositionLevelCount(1),
+///     ks_base_log: DecompositionBaseLog(3),
+///     ks_level: DecompositionLevelCount(5),
+/// };
+/// let noise = estimate_atomic_pattern_noise(&parameters, 1, 64);
+/// assert!(noise.get_standard_dev() < 2_f64.powi(-8));
+/// ```
+pub fn estimate_atomic_pattern_noise(
+    parameters: &Parameters,
+    norm2: u64,
+    log2_modulus: u32,
+) -> Variance {
+    let bootstrap_noise = estimate_pbs_noise::<_, BinaryKeyKind>(
+        parameters.lwe_dimension,
+        parameters.polynomial_size,
+        parameters.glwe_dimension,
+        parameters.pbs_base_log,
+        parameters.pbs_level,
+        parameters.glwe_std_dev,
+        log2_modulus,
+    );
+    let dot_product_noise = Variance(bootstrap_noise.get_variance() * norm2 as f64);
+    let keyswitch_noise =
+        estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<_, _, BinaryKeyKind>(
+            LweDimension(parameters.glwe_dimension.0 * parameters.polynomial_size.0),
+            dot_product_noise,
+            parameters.lwe_std_dev,
+            parameters.ks_base_log,
+            parameters.ks_level,
+            log2_modulus,
+        );
+    let switched_modulus_log = parameters.polynomial_size.log2().0 + 1;
+    estimate_modulus_switching_noise_with_binary_key(
+        parameters.lwe_dimension,
+        switched_modulus_log,
+        keyswitch_noise,
+        log2_modulus,
+    )
+}
+
+/// Computes the logarithm in base 2 of the probability that a ciphertext with a given noise
+/// decrypts to a wrong message.
+///
+/// The messages are encoded on `message_precision` bits, on top of a padding bit. The decryption
+/// fails when the noise is larger than half of the scaling factor of the messages.
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::StandardDev;
+/// use concrete_optimizer::estimate_log2_failure_probability;
+/// // The decryption fails when the noise exceeds 2^-6, that is 4 standard deviations.
+/// let log2_failure = estimate_log2_failure_probability(StandardDev(2_f64.powi(-8)), 4);
+/// assert!((log2_failure - (6.334e-5_f64).log2()).abs() < 0.01);
+/// ```
+pub fn estimate_log2_failure_probability<D>(dispersion: D, message_precision: usize) -> f64
+where
+    D: DispersionParameter,
+{
+    let half_delta = 2_f64.powi(-(message_precision as i32 + 2));
+    log2_erfc(half_delta / (dispersion.get_standard_dev() * SQRT_2))
+}
+
+/// Returns the logarithm in base 2 of the complementary error function, for a non-negative input.
+///
+/// Small inputs use the Taylor series of the error function, while large inputs use the
+/// continued fraction of the complementary error function, which does not underflow.
+fn log2_erfc(x: f64) -> f64 {
+    if x < 2. {
+        let mut term = x;
+        let mut erf = x;
+        for n in 1..ERFC_TERMS {
+            term *= -x * x / n as f64;
+            erf += term / (2 * n + 1) as f64;
+        }
+        (1. - 2. / PI.sqrt() * erf).log2()
+    } else {
+        let mut fraction = x;
+        for k in (1..=ERFC_TERMS).rev() {
+            fraction = x + (k as f64 / 2.) / fraction;
+        }
+        -x * x * LOG2_E - (PI.sqrt() * fraction).log2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::log2_erfc;
+
+    #[test]
+    fn erfc_is_continuous() {
+        let below = log2_erfc(2. - 1e-9);
+        let above = log2_erfc(2.);
+        assert!((below - above).abs() < 1e-6);
+    }
+
+    #[test]
+    fn erfc_known_values() {
+        // erfc(0) = 1, erfc(1) = 0.157299207..., erfc(5) = 1.537459794e-12
+        assert!(log2_erfc(0.).abs() < 1e-12);
+        assert!((log2_erfc(1.) - 0.157_299_207_050_285_f64.log2()).abs() < 1e-9);
+        assert!((log2_erfc(5.) - 1.537_459_794_428_035e-12_f64.log2()).abs() < 1e-9);
+    }
+}
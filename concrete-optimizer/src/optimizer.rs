@@ -0,0 +1,373 @@
+//! Contains the search for the cheapest parameter set meeting a set of constraints.
+use crate::{
+    estimate_atomic_pattern_noise, estimate_log2_failure_probability,
+    estimate_minimal_secure_noise, estimate_security, Parameters,
+};
+use concrete_commons::dispersion::{StandardDev, Variance};
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use concrete_npe::{estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms, estimate_pbs_noise};
+use std::ops::RangeInclusive;
+
+/// The constraints a parameter set must meet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constraints {
+    /// The minimal security level of the LWE and GLWE secret keys, in bits.
+    pub security_level: u32,
+    /// The number of bits of the messages, not counting the padding bit.
+    pub message_precision: usize,
+    /// The logarithm in base 2 of the maximal probability that a bootstrap outputs a wrong
+    /// message.
+    pub log2_failure_probability: f64,
+    /// The maximal squared 2-norm of the weights of the dot products evaluated between two
+    /// bootstraps.
+    pub norm2: u64,
+    /// The logarithm in base 2 of the ciphertext modulus.
+    pub log2_modulus: u32,
+}
+
+impl Constraints {
+    /// Creates constraints for a given security level and message precision.
+    ///
+    /// The failure probability is set to $2^{-40}$, the norm of the dot products to 1, and the
+    /// ciphertexts are taken over 64 bits.
+    ///
+    /// # Example
+    /// ```rust
+    /// use concrete_optimizer::Constraints;
+    /// let constraints = Constraints::new(128, 3);
+    /// assert_eq!(constraints.security_level, 128);
+    /// assert_eq!(constraints.message_precision, 3);
+    /// assert_eq!(constraints.log2_modulus, 64);
+    /// ```
+    pub fn new(security_level: u32, message_precision: usize) -> Constraints {
+        Constraints {
+            security_level,
+            message_precision,
+            log2_failure_probability: -40.,
+            norm2: 1,
+            log2_modulus: 64,
+        }
+    }
+}
+
+/// The parameter space explored by the optimizer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchSpace {
+    /// The GLWE dimensions to try.
+    pub glwe_dimensions: RangeInclusive<usize>,
+    /// The logarithms in base 2 of the polynomial sizes to try.
+    pub log2_polynomial_sizes: RangeInclusive<usize>,
+    /// The LWE dimensions to try, with a step of `lwe_dimension_step`.
+    pub lwe_dimensions: RangeInclusive<usize>,
+    /// The step between two LWE dimensions tried.
+    pub lwe_dimension_step: usize,
+    /// The maximal number of levels of the bootstrap and keyswitch decompositions.
+    pub maximal_level_count: usize,
+}
+
+impl Default for SearchSpace {
+    fn default() -> SearchSpace {
+        SearchSpace {
+            glwe_dimensions: 1..=3,
+            log2_polynomial_sizes: 8..=16,
+            lwe_dimensions: 400..=1600,
+            lwe_dimension_step: 8,
+            maximal_level_count: 10,
+        }
+    }
+}
+
+/// A parameter set found by the optimizer, along with its estimated characteristics.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Solution {
+    /// The parameters.
+    pub parameters: Parameters,
+    /// The estimated security level of the weakest secret key, in bits.
+    pub security_level: f64,
+    /// The logarithm in base 2 of the estimated probability that a bootstrap outputs a wrong
+    /// message.
+    pub log2_failure_probability: f64,
+    /// The estimated number of operations of the atomic pattern.
+    pub complexity: f64,
+}
+
+/// Estimates the number of operations of one bootstrap and one keyswitch.
+///
+/// The bootstrap performs one external product per coefficient of the LWE secret key, made of the
+/// forward Fourier transforms of the decomposed GLWE ciphertext, the products in the Fourier
+/// domain, and the backward Fourier transforms of the result.
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::StandardDev;
+/// use concrete_commons::parameters::{
+///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+/// };
+/// use concrete_optimizer::{estimate_atomic_pattern_complexity, Parameters};
+/// let mut parameters = Parameters {
+///     lwe_dimension: LweDimension(742),
+///     glwe_dimension: GlweDimension(1),
+///     polynomial_size: PolynomialSize(2048),
+///     lwe_std_dev: StandardDev(0.000007069849454709433),
+///     glwe_std_dev: StandardDev(0.00000000000000029403601535432533),
+///     pbs_base_log: DecompositionBaseLog(23),
+///     pbs_level: DecompositionLevelCount(1),
+///     ks_base_log: DecompositionBaseLog(3),
+///     ks_level: DecompositionLevelCount(5),
+/// };
+/// let complexity = estimate_atomic_pattern_complexity(&parameters);
+/// parameters.pbs_level = DecompositionLevelCount(2);
+/// assert!(complexity < estimate_atomic_pattern_complexity(&parameters));
+/// ```
+pub fn estimate_atomic_pattern_complexity(parameters: &Parameters) -> f64 {
+    let n = parameters.lwe_dimension.0 as f64;
+    let k = parameters.glwe_dimension.0 as f64;
+    let big_n = parameters.polynomial_size.0 as f64;
+    let pbs_level = parameters.pbs_level.0 as f64;
+    let ks_level = parameters.ks_level.0 as f64;
+
+    let fft = big_n * big_n.log2();
+    let external_product =
+        (k + 1.) * pbs_level * fft + (k + 1.) * (k + 1.) * pbs_level * big_n + (k + 1.) * fft;
+    let keyswitch = k * big_n * ks_level * (n + 1.);
+    n * external_product + keyswitch
+}
+
+/// Returns the decomposition base logarithm minimizing a noise, for every number of levels.
+fn best_base_logs(
+    maximal_level_count: usize,
+    log2_modulus: u32,
+    noise: impl Fn(DecompositionBaseLog, DecompositionLevelCount) -> f64,
+) -> Vec<(DecompositionLevelCount, DecompositionBaseLog)> {
+    (1..=maximal_level_count)
+        .map(DecompositionLevelCount)
+        .filter(|level| level.0 <= log2_modulus as usize)
+        .map(|level| {
+            let base_log = (1..=log2_modulus as usize / level.0)
+                .map(DecompositionBaseLog)
+                .min_by(|a, b| noise(*a, level).total_cmp(&noise(*b, level)))
+                .unwrap();
+            (level, base_log)
+        })
+        .collect()
+}
+
+/// Returns whether a parameter set is cheaper than the best solution found so far.
+fn is_cheaper(parameters: &Parameters, best: &Option<Solution>) -> bool {
+    match best {
+        Some(solution) => estimate_atomic_pattern_complexity(parameters) < solution.complexity,
+        None => true,
+    }
+}
+
+/// Searches for the parameter set with the smallest complexity meeting the constraints.
+///
+/// For every dimension of the search space, the noise is set to the smallest one reaching the
+/// security level, and the decomposition base logarithms are chosen to minimize the noise of the
+/// bootstrap and keyswitch. Returns `None` if no parameter set of the search space meets the
+/// constraints.
+///
+/// # Example
+/// ```rust
+/// use concrete_optimizer::{optimize_parameters, Constraints, SearchSpace};
+/// let constraints = Constraints::new(128, 2);
+/// let search_space = SearchSpace {
+///     glwe_dimensions: 1..=1,
+///     log2_polynomial_sizes: 10..=11,
+///     lwe_dimensions: 600..=900,
+///     lwe_dimension_step: 32,
+///     maximal_level_count: 5,
+/// };
+/// let solution = optimize_parameters(&constraints, &search_space).unwrap();
+/// assert!(solution.security_level >= 128.);
+/// assert!(solution.log2_failure_probability <= -40.);
+/// ```
+pub fn optimize_parameters(
+    constraints: &Constraints,
+    search_space: &SearchSpace,
+) -> Option<Solution> {
+    let log2_modulus = constraints.log2_modulus;
+    let secure_noise = |dimension: usize| {
+        estimate_minimal_secure_noise::<BinaryKeyKind>(
+            LweDimension(dimension),
+            constraints.security_level,
+            log2_modulus,
+        )
+    };
+    let lwe_noises: Vec<(LweDimension, StandardDev)> = search_space
+        .lwe_dimensions
+        .clone()
+        .step_by(search_space.lwe_dimension_step)
+        .map(|dimension| (LweDimension(dimension), secure_noise(dimension)))
+        .collect();
+
+    let mut best: Option<Solution> = None;
+    for glwe_dimension in search_space.glwe_dimensions.clone().map(GlweDimension) {
+        for log2_polynomial_size in search_space.log2_polynomial_sizes.clone() {
+            let polynomial_size = PolynomialSize(1 << log2_polynomial_size);
+            let glwe_std_dev = secure_noise(glwe_dimension.0 * polynomial_size.0);
+            for &(lwe_dimension, lwe_std_dev) in lwe_noises.iter() {
+                let mut parameters = Parameters {
+                    lwe_dimension,
+                    glwe_dimension,
+                    polynomial_size,
+                    lwe_std_dev,
+                    glwe_std_dev,
+                    pbs_base_log: DecompositionBaseLog(1),
+                    pbs_level: DecompositionLevelCount(1),
+                    ks_base_log: DecompositionBaseLog(1),
+                    ks_level: DecompositionLevelCount(1),
+                };
+                if !is_cheaper(&parameters, &best) {
+                    continue;
+                }
+
+                // The noises of the bootstrap and of the keyswitch add up, so their
+                // decompositions can be chosen independently.
+                let pbs_decompositions =
+                    best_base_logs(search_space.maximal_level_count, log2_modulus, |b, l| {
+                        estimate_pbs_noise::<_, BinaryKeyKind>(
+                            lwe_dimension,
+                            polynomial_size,
+                            glwe_dimension,
+                            b,
+                            l,
+                            glwe_std_dev,
+                            log2_modulus,
+                        )
+                        .0
+                    });
+                let ks_decompositions =
+                    best_base_logs(search_space.maximal_level_count, log2_modulus, |b, l| {
+                        estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+                            _,
+                            _,
+                            BinaryKeyKind,
+                        >(
+                            LweDimension(glwe_dimension.0 * polynomial_size.0),
+                            Variance(0.),
+                            lwe_std_dev,
+                            b,
+                            l,
+                            log2_modulus,
+                        )
+                        .0
+                    });
+
+                for &(pbs_level, pbs_base_log) in pbs_decompositions.iter() {
+                    for &(ks_level, ks_base_log) in ks_decompositions.iter() {
+                        parameters.pbs_level = pbs_level;
+                        parameters.pbs_base_log = pbs_base_log;
+                        parameters.ks_level = ks_level;
+                        parameters.ks_base_log = ks_base_log;
+                        if !is_cheaper(&parameters, &best) {
+                            continue;
+                        }
+                        let noise = estimate_atomic_pattern_noise(
+                            &parameters,
+                            constraints.norm2,
+                            log2_modulus,
+                        );
+                        let log2_failure_probability =
+                            estimate_log2_failure_probability(noise, constraints.message_precision);
+                        if log2_failure_probability <= constraints.log2_failure_probability {
+                            best = Some(Solution {
+                                parameters,
+                                security_level: f64::NAN,
+                                log2_failure_probability,
+                                complexity: estimate_atomic_pattern_complexity(&parameters),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    best.map(|mut solution| {
+        let parameters = &solution.parameters;
+        let lwe_security = estimate_security::<BinaryKeyKind, _>(
+            parameters.lwe_dimension,
+            parameters.lwe_std_dev,
+            log2_modulus,
+        );
+        let glwe_security = estimate_security::<BinaryKeyKind, _>(
+            LweDimension(parameters.glwe_dimension.0 * parameters.polynomial_size.0),
+            parameters.glwe_std_dev,
+            log2_modulus,
+        );
+        solution.security_level = lwe_security
+            .security_level
+            .min(glwe_security.security_level);
+        solution
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A widely used parameter set for 2 bits messages with 128 bits of security.
+    fn known_parameters() -> Parameters {
+        Parameters {
+            lwe_dimension: LweDimension(742),
+            glwe_dimension: GlweDimension(1),
+            polynomial_size: PolynomialSize(2048),
+            lwe_std_dev: StandardDev(0.000007069849454709433),
+            glwe_std_dev: StandardDev(0.00000000000000029403601535432533),
+            pbs_base_log: DecompositionBaseLog(23),
+            pbs_level: DecompositionLevelCount(1),
+            ks_base_log: DecompositionBaseLog(3),
+            ks_level: DecompositionLevelCount(5),
+        }
+    }
+
+    #[test]
+    fn known_parameters_are_secure() {
+        let parameters = known_parameters();
+        let lwe_security = estimate_security::<BinaryKeyKind, _>(
+            parameters.lwe_dimension,
+            parameters.lwe_std_dev,
+            64,
+        );
+        let glwe_security = estimate_security::<BinaryKeyKind, _>(
+            LweDimension(parameters.glwe_dimension.0 * parameters.polynomial_size.0),
+            parameters.glwe_std_dev,
+            64,
+        );
+        assert!(lwe_security.security_level >= 128.);
+        assert!(glwe_security.security_level >= 128.);
+    }
+
+    #[test]
+    fn known_parameters_are_correct() {
+        let constraints = Constraints::new(128, 2);
+        let noise = estimate_atomic_pattern_noise(
+            &known_parameters(),
+            constraints.norm2,
+            constraints.log2_modulus,
+        );
+        let log2_failure_probability =
+            estimate_log2_failure_probability(noise, constraints.message_precision);
+        assert!(log2_failure_probability <= constraints.log2_failure_probability);
+    }
+
+    #[test]
+    fn solution_is_not_more_expensive_than_known_parameters() {
+        let constraints = Constraints::new(128, 2);
+        let search_space = SearchSpace {
+            glwe_dimensions: 1..=1,
+            log2_polynomial_sizes: 11..=11,
+            lwe_dimensions: 600..=800,
+            lwe_dimension_step: 8,
+            maximal_level_count: 5,
+        };
+        let solution = optimize_parameters(&constraints, &search_space).unwrap();
+        assert!(solution.security_level >= 128.);
+        assert!(solution.log2_failure_probability <= constraints.log2_failure_probability);
+        assert!(solution.complexity <= estimate_atomic_pattern_complexity(&known_parameters()));
+    }
+}
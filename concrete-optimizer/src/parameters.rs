@@ -0,0 +1,34 @@
+//! Contains the parameter sets produced by the optimizer.
+use concrete_commons::dispersion::StandardDev;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// A set of parameters for the bootstrapping and keyswitching atomic pattern.
+///
+/// The pattern takes a linear combination of LWE ciphertexts encrypted under the LWE secret key
+/// extracted from the GLWE secret key, keyswitches it to the LWE secret key, and bootstraps it
+/// back to the extracted key.
+///
+/// The noise standard deviations are expressed over the torus, as in every `concrete` engine.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Parameters {
+    /// The dimension of the LWE secret key.
+    pub lwe_dimension: LweDimension,
+    /// The dimension of the GLWE secret key.
+    pub glwe_dimension: GlweDimension,
+    /// The polynomial size of the GLWE secret key.
+    pub polynomial_size: PolynomialSize,
+    /// The standard deviation of the noise of the LWE encryptions, and of the keyswitch key.
+    pub lwe_std_dev: StandardDev,
+    /// The standard deviation of the noise of the GLWE encryptions, and of the bootstrap key.
+    pub glwe_std_dev: StandardDev,
+    /// The base logarithm of the decomposition of the bootstrap key.
+    pub pbs_base_log: DecompositionBaseLog,
+    /// The number of levels of the decomposition of the bootstrap key.
+    pub pbs_level: DecompositionLevelCount,
+    /// The base logarithm of the decomposition of the keyswitch key.
+    pub ks_base_log: DecompositionBaseLog,
+    /// The number of levels of the decomposition of the keyswitch key.
+    pub ks_level: DecompositionLevelCount,
+}
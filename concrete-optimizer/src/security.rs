@@ -0,0 +1,368 @@
+//! Contains material needed to estimate the security of LWE instances against lattice attacks.
+//!
+//! The estimates follow the approach of the [lattice estimator](https://eprint.iacr.org/2015/046):
+//! the cost of an attack is the cost of the BKZ reduction it relies on, for the smallest block
+//! size that makes it succeed. The reduction is assumed to follow the geometric series assumption,
+//! and the cost of a BKZ tour is estimated with the sieving model of
+//! [BDGL16](https://eprint.iacr.org/2015/1128).
+use concrete_commons::dispersion::{DispersionParameter, StandardDev};
+use concrete_commons::parameters::LweDimension;
+use concrete_npe::KeyDispersion;
+use std::f64::consts::{E, LOG2_E, PI};
+
+/// The smallest block size considered by the attacks. Below it, the root-Hermite factor formula
+/// is no longer accurate.
+const MINIMAL_BLOCK_SIZE: usize = 40;
+
+/// The number of values of the number of samples tried by the attacks.
+const SAMPLES_GRID_SIZE: usize = 32;
+
+/// The number of block sizes tried by the coarse pass of the dual attack.
+const BLOCK_SIZE_GRID_SIZE: usize = 128;
+
+/// The smallest noise returned by [`estimate_minimal_secure_noise`], as the logarithm in base 2 of
+/// its modular standard deviation.
+const MINIMAL_LOG2_MODULAR_STD_DEV: f64 = 1.;
+
+/// The precision of the binary search of [`estimate_minimal_secure_noise`], in bits.
+const NOISE_SEARCH_PRECISION: f64 = 0.01;
+
+/// The lattice attacks taken into account by the estimator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LatticeAttack {
+    /// The primal attack, solving the unique-SVP instance obtained by embedding the LWE samples.
+    PrimalUsvp,
+    /// The dual attack, distinguishing the LWE samples from uniform using short dual vectors.
+    Dual,
+}
+
+/// The estimated cost of a lattice attack against an LWE instance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SecurityEstimate {
+    /// The attack this estimate corresponds to.
+    pub attack: LatticeAttack,
+    /// The logarithm in base 2 of the number of operations needed by the attack.
+    pub security_level: f64,
+    /// The BKZ block size used by the attack.
+    pub block_size: usize,
+    /// The number of LWE samples used by the attack.
+    pub number_of_samples: usize,
+}
+
+impl SecurityEstimate {
+    fn unreachable(attack: LatticeAttack) -> SecurityEstimate {
+        SecurityEstimate {
+            attack,
+            security_level: f64::INFINITY,
+            block_size: 0,
+            number_of_samples: 0,
+        }
+    }
+
+    fn cheapest(self, other: SecurityEstimate) -> SecurityEstimate {
+        if other.security_level < self.security_level {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+/// The parameters of an LWE instance, in logarithmic scale.
+struct Instance {
+    dimension: f64,
+    log2_modulus: f64,
+    log2_noise_std_dev: f64,
+    // The logarithm of the factor by which the secret coordinates are scaled, so that they have
+    // the same standard deviation as the noise.
+    log2_scaling: f64,
+}
+
+impl Instance {
+    fn new<K, D>(lwe_dimension: LweDimension, dispersion: D, log2_modulus: u32) -> Instance
+    where
+        K: KeyDispersion,
+        D: DispersionParameter,
+    {
+        let log2_noise_std_dev = dispersion.get_modular_standard_dev(log2_modulus).log2();
+        let log2_key_std_dev = K::variance_key_coefficient(log2_modulus)
+            .get_modular_standard_dev(log2_modulus)
+            .log2();
+        Instance {
+            dimension: lwe_dimension.0 as f64,
+            log2_modulus: log2_modulus as f64,
+            log2_noise_std_dev,
+            log2_scaling: log2_noise_std_dev - log2_key_std_dev,
+        }
+    }
+
+    fn samples_grid(&self) -> impl Iterator<Item = usize> {
+        let maximal_samples = 2 * self.dimension as usize;
+        (1..=SAMPLES_GRID_SIZE).map(move |i| (i * maximal_samples / SAMPLES_GRID_SIZE).max(1))
+    }
+}
+
+/// Returns the logarithm in base 2 of the root-Hermite factor reached by BKZ with a given block
+/// size.
+fn log2_root_hermite_factor(block_size: usize) -> f64 {
+    let beta = block_size as f64;
+    ((PI * beta).powf(1. / beta) * beta / (2. * PI * E)).log2() / (2. * (beta - 1.))
+}
+
+/// Returns the logarithm in base 2 of the cost of BKZ with a given block size, on a lattice of a
+/// given dimension.
+fn log2_bkz_cost(block_size: usize, lattice_dimension: usize) -> f64 {
+    0.292 * block_size as f64 + 16.4 + (8. * lattice_dimension as f64).log2()
+}
+
+/// Estimates the cost of the primal uSVP attack against an LWE instance.
+///
+/// The secret coordinates are scaled to match the standard deviation of the noise, and the attack
+/// succeeds as soon as the projection of the embedded short vector is shorter than the
+/// Gram-Schmidt norm of the last BKZ block.
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::StandardDev;
+/// use concrete_commons::key_kinds::BinaryKeyKind;
+/// use concrete_commons::parameters::LweDimension;
+/// use concrete_optimizer::{estimate_primal_usvp_security, LatticeAttack};
+/// let estimate = estimate_primal_usvp_security::<BinaryKeyKind, _>(
+///     LweDimension(1024),
+///     StandardDev(2_f64.powi(-25)),
+///     64,
+/// );
+/// assert_eq!(estimate.attack, LatticeAttack::PrimalUsvp);
+/// assert!(estimate.security_level > 128.);
+/// ```
+pub fn estimate_primal_usvp_security<K, D>(
+    lwe_dimension: LweDimension,
+    dispersion: D,
+    log2_modulus: u32,
+) -> SecurityEstimate
+where
+    K: KeyDispersion,
+    D: DispersionParameter,
+{
+    let instance = Instance::new::<K, D>(lwe_dimension, dispersion, log2_modulus);
+    let succeeds = |block_size: usize, lattice_dimension: usize, log2_volume: f64| {
+        let d = lattice_dimension as f64;
+        instance.log2_noise_std_dev + 0.5 * (block_size as f64).log2()
+            <= (2. * block_size as f64 - d) * log2_root_hermite_factor(block_size) + log2_volume / d
+    };
+    instance
+        .samples_grid()
+        .filter_map(|samples| {
+            let lattice_dimension = lwe_dimension.0 + samples + 1;
+            let log2_volume =
+                samples as f64 * instance.log2_modulus + instance.dimension * instance.log2_scaling;
+            if !succeeds(lattice_dimension, lattice_dimension, log2_volume) {
+                return None;
+            }
+            // The success condition is monotonic in the block size, so we look for the smallest
+            // successful block size with a binary search.
+            let (mut low, mut high) = (MINIMAL_BLOCK_SIZE, lattice_dimension);
+            while low < high {
+                let middle = (low + high) / 2;
+                if succeeds(middle, lattice_dimension, log2_volume) {
+                    high = middle;
+                } else {
+                    low = middle + 1;
+                }
+            }
+            Some(SecurityEstimate {
+                attack: LatticeAttack::PrimalUsvp,
+                security_level: log2_bkz_cost(low, lattice_dimension),
+                block_size: low,
+                number_of_samples: samples,
+            })
+        })
+        .fold(
+            SecurityEstimate::unreachable(LatticeAttack::PrimalUsvp),
+            SecurityEstimate::cheapest,
+        )
+}
+
+/// Estimates the cost of the dual attack against an LWE instance.
+///
+/// BKZ is used to find short vectors in the (scaled) dual lattice of the samples, and the attack
+/// is repeated until enough short vectors are available to distinguish the samples from uniform.
+/// Every call to the sieving oracle is assumed to output $2^{0.2075\beta}$ short vectors.
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::StandardDev;
+/// use concrete_commons::key_kinds::BinaryKeyKind;
+/// use concrete_commons::parameters::LweDimension;
+/// use concrete_optimizer::{estimate_dual_security, LatticeAttack};
+/// let estimate = estimate_dual_security::<BinaryKeyKind, _>(
+///     LweDimension(1024),
+///     StandardDev(2_f64.powi(-25)),
+///     64,
+/// );
+/// assert_eq!(estimate.attack, LatticeAttack::Dual);
+/// assert!(estimate.security_level > 128.);
+/// ```
+pub fn estimate_dual_security<K, D>(
+    lwe_dimension: LweDimension,
+    dispersion: D,
+    log2_modulus: u32,
+) -> SecurityEstimate
+where
+    K: KeyDispersion,
+    D: DispersionParameter,
+{
+    let instance = Instance::new::<K, D>(lwe_dimension, dispersion, log2_modulus);
+    let log2_cost = |block_size: usize, lattice_dimension: usize, log2_volume: f64| {
+        let d = lattice_dimension as f64;
+        let log2_length = d * log2_root_hermite_factor(block_size) + log2_volume / d;
+        let log2_tau = instance.log2_noise_std_dev + log2_length - instance.log2_modulus;
+        // The advantage of the distinguisher is exp(-2 pi^2 tau^2), and about 1 / advantage^2
+        // short vectors are needed.
+        let log2_needed_vectors = 4. * PI * PI * (2. * log2_tau).exp2() * LOG2_E;
+        let log2_repetitions = (log2_needed_vectors - 0.2075 * block_size as f64).max(0.);
+        log2_bkz_cost(block_size, lattice_dimension) + log2_repetitions
+    };
+    instance
+        .samples_grid()
+        .filter_map(|samples| {
+            let lattice_dimension = lwe_dimension.0 + samples;
+            if lattice_dimension < MINIMAL_BLOCK_SIZE {
+                return None;
+            }
+            let log2_volume = instance.dimension * (instance.log2_modulus - instance.log2_scaling);
+            let estimate = |block_size| SecurityEstimate {
+                attack: LatticeAttack::Dual,
+                security_level: log2_cost(block_size, lattice_dimension, log2_volume),
+                block_size,
+                number_of_samples: samples,
+            };
+            // A coarse pass over the block sizes, refined around its best value.
+            let step = ((lattice_dimension - MINIMAL_BLOCK_SIZE) / BLOCK_SIZE_GRID_SIZE).max(1);
+            let coarse = (MINIMAL_BLOCK_SIZE..=lattice_dimension)
+                .step_by(step)
+                .map(estimate)
+                .fold(
+                    SecurityEstimate::unreachable(LatticeAttack::Dual),
+                    SecurityEstimate::cheapest,
+                );
+            let refined = (coarse
+                .block_size
+                .saturating_sub(step)
+                .max(MINIMAL_BLOCK_SIZE)
+                ..=(coarse.block_size + step).min(lattice_dimension))
+                .map(estimate)
+                .fold(coarse, SecurityEstimate::cheapest);
+            Some(refined)
+        })
+        .fold(
+            SecurityEstimate::unreachable(LatticeAttack::Dual),
+            SecurityEstimate::cheapest,
+        )
+}
+
+/// Estimates the security of an LWE instance, as the cost of the cheapest attack against it.
+///
+/// The secret key coefficients follow the distribution given by `K`, and the noise has the
+/// dispersion given by `dispersion`.
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::StandardDev;
+/// use concrete_commons::key_kinds::BinaryKeyKind;
+/// use concrete_commons::parameters::LweDimension;
+/// use concrete_optimizer::estimate_security;
+/// let low_noise =
+///     estimate_security::<BinaryKeyKind, _>(LweDimension(630), StandardDev(2_f64.powi(-20)), 64);
+/// let high_noise =
+///     estimate_security::<BinaryKeyKind, _>(LweDimension(630), StandardDev(2_f64.powi(-10)), 64);
+/// assert!(low_noise.security_level < high_noise.security_level);
+/// ```
+pub fn estimate_security<K, D>(
+    lwe_dimension: LweDimension,
+    dispersion: D,
+    log2_modulus: u32,
+) -> SecurityEstimate
+where
+    K: KeyDispersion,
+    D: DispersionParameter,
+{
+    estimate_primal_usvp_security::<K, D>(lwe_dimension, dispersion, log2_modulus).cheapest(
+        estimate_dual_security::<K, D>(lwe_dimension, dispersion, log2_modulus),
+    )
+}
+
+/// Computes the smallest noise for which an LWE instance reaches a given security level.
+///
+/// The returned noise is never smaller than a modular standard deviation of 2, so that the
+/// rounding of the noise sampling remains negligible in front of it.
+///
+/// # Example
+/// ```rust
+/// use concrete_commons::key_kinds::BinaryKeyKind;
+/// use concrete_commons::parameters::LweDimension;
+/// use concrete_optimizer::{estimate_minimal_secure_noise, estimate_security};
+/// let std_dev = estimate_minimal_secure_noise::<BinaryKeyKind>(LweDimension(800), 128, 64);
+/// let estimate = estimate_security::<BinaryKeyKind, _>(LweDimension(800), std_dev, 64);
+/// assert!(estimate.security_level >= 128.);
+/// ```
+pub fn estimate_minimal_secure_noise<K>(
+    lwe_dimension: LweDimension,
+    security_level: u32,
+    log2_modulus: u32,
+) -> StandardDev
+where
+    K: KeyDispersion,
+{
+    let is_secure = |log2_modular_std_dev: f64| {
+        let std_dev =
+            StandardDev::from_modular_standard_dev(log2_modular_std_dev.exp2(), log2_modulus);
+        estimate_security::<K, _>(lwe_dimension, std_dev, log2_modulus).security_level
+            >= security_level as f64
+    };
+    let (mut low, mut high) = (MINIMAL_LOG2_MODULAR_STD_DEV, log2_modulus as f64 - 1.);
+    if is_secure(low) {
+        high = low;
+    }
+    while high - low > NOISE_SEARCH_PRECISION {
+        let middle = (low + high) / 2.;
+        if is_secure(middle) {
+            high = middle;
+        } else {
+            low = middle;
+        }
+    }
+    StandardDev::from_modular_standard_dev(high.exp2(), log2_modulus)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use concrete_commons::key_kinds::{BinaryKeyKind, GaussianKeyKind};
+
+    #[test]
+    fn security_of_known_parameters() {
+        // The LWE parameters of a widely used 128 bits parameter set.
+        let estimate = estimate_security::<BinaryKeyKind, _>(
+            LweDimension(742),
+            StandardDev(0.000007069849454709433),
+            64,
+        );
+        assert!(estimate.security_level > 128. && estimate.security_level < 140.);
+    }
+
+    #[test]
+    fn minimal_secure_noise_decreases_with_dimension() {
+        let small = estimate_minimal_secure_noise::<BinaryKeyKind>(LweDimension(600), 128, 64);
+        let large = estimate_minimal_secure_noise::<BinaryKeyKind>(LweDimension(900), 128, 64);
+        assert!(large.0 < small.0);
+    }
+
+    #[test]
+    fn gaussian_keys_are_more_secure() {
+        let std_dev = StandardDev(2_f64.powi(-20));
+        let binary = estimate_security::<BinaryKeyKind, _>(LweDimension(800), std_dev, 64);
+        let gaussian = estimate_security::<GaussianKeyKind, _>(LweDimension(800), std_dev, 64);
+        assert!(binary.security_level < gaussian.security_level);
+    }
+}
@@ -86,6 +86,10 @@ fn main() -> Result<(), std::io::Error> {
             Command::new("test_csprng").about("Tests the `concrete-csprng` crate in native mode"),
         )
         .subcommand(Command::new("test_npe").about("Tests the `concrete-npe` crate in native mode"))
+        .subcommand(
+            Command::new("test_optimizer")
+                .about("Tests the `concrete-optimizer` crate in native mode"),
+        )
         .subcommand(Command::new("test_crates").about("Tests all the crates in native mode"))
         .subcommand(
             Command::new("test_and_cov_crates")
@@ -174,6 +178,9 @@ fn main() -> Result<(), std::io::Error> {
     if matches.subcommand_matches("test_npe").is_some() {
         test::npe()?;
     }
+    if matches.subcommand_matches("test_optimizer").is_some() {
+        test::optimizer()?;
+    }
     if matches.subcommand_matches("test_crates").is_some() {
         test::crates()?;
     }
@@ -33,6 +33,10 @@ pub fn npe() -> Result<(), Error> {
     cmd!(<ENV_TARGET_NATIVE> "cargo test --release --no-fail-fast --all-features -p concrete-npe")
 }
 
+pub fn optimizer() -> Result<(), Error> {
+    cmd!(<ENV_TARGET_NATIVE> "cargo test --release --no-fail-fast --all-features -p concrete-optimizer")
+}
+
 pub fn ffi() -> Result<(), Error> {
     cmd!(<ENV_TARGET_NATIVE> "./concrete-core-ffi/build-ffi-and-run-c-tests.sh")
 }
@@ -55,6 +59,7 @@ pub fn crates() -> Result<(), Error> {
     core_test()?;
     csprng()?;
     npe()?;
+    optimizer()?;
     ffi()
 }
 